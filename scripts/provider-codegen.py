@@ -83,6 +83,30 @@ def to_pascal_case(identifier: str) -> str:
     return "".join(word.capitalize() for word in cleaned.split("_") if word)
 
 
+# Provider structs whose name is not the PascalCase of the provider ID.
+PROVIDER_STRUCT_NAMES = {
+    "elevenlabs": "ElevenLabs",
+    "llamacpp": "LlamaCpp",
+    "openai": "OpenAI",
+    "togetherai": "TogetherAI",
+    "vllm": "VLLM",
+    "xai": "XAI",
+}
+
+
+def get_provider_struct_name(provider_id: str) -> str:
+    """
+    Get the name of the provider struct for a provider ID.
+
+    Args:
+        provider_id: Provider identifier (kebab-case)
+
+    Returns:
+        PascalCase struct name, e.g. "OpenAI" for "openai"
+    """
+    return PROVIDER_STRUCT_NAMES.get(provider_id, to_pascal_case(provider_id))
+
+
 def provider_id_to_snake_case(provider_id: str) -> str:
     """
     Convert provider ID to snake_case for module paths.
@@ -378,6 +402,20 @@ MANUALLY_MANAGED_MODULES = {
     "amazon_bedrock",
     "togetherai",
     "xai",
+    "ollama",
+    "cohere",
+    "bedrock",
+    "vertex",
+    "perplexity",
+    "replicate",
+    "llamacpp",
+    "vllm",
+    "watsonx",
+    "voyage",
+    "jina",
+    "elevenlabs",
+    "deepgram",
+    "stability",
 }
 
 
@@ -604,6 +642,79 @@ def parse_model_id(model_id: str) -> tuple[str, str | None]:
     return model_id, None
 
 
+def format_int_literal(value: int) -> str:
+    """
+    Format an integer as a Rust literal, grouping the digits of large numbers.
+
+    Args:
+        value: Integer to format (e.g., 200000)
+
+    Returns:
+        Rust literal (e.g., "200_000"), or the plain digits below 10,000
+    """
+    digits = str(value)
+    if len(digits) < 5:
+        return digits
+    return f"{value:_}"
+
+
+def format_float_literal(value: float) -> str:
+    """
+    Format a number as a Rust float literal.
+
+    Args:
+        value: Number to format (e.g., 3 or 0.075)
+
+    Returns:
+        Rust literal with a decimal point (e.g., "3.0" or "0.075")
+    """
+    return repr(float(value))
+
+
+def get_model_info_fields(model_data: dict[str, Any]) -> list[str]:
+    """
+    Get the optional model info fields of a model_capabilities! entry.
+
+    Reads the context window and output limit from models.dev `limit.context` and
+    `limit.output`, and the price per million tokens from `cost.input` and
+    `cost.output`.
+
+    Args:
+        model_data: Model configuration from API
+
+    Returns:
+        List of Rust field lines, in the order the macro expects
+    """
+    fields = []
+    limit = model_data.get("limit") or {}
+    cost = model_data.get("cost") or {}
+
+    context = limit.get("context")
+    if isinstance(context, int) and context > 0:
+        fields.append(f"context_window: {format_int_literal(context)}")
+
+    output = limit.get("output")
+    if isinstance(output, int) and output > 0:
+        fields.append(f"max_output_tokens: {format_int_literal(output)}")
+
+    input_price = cost.get("input")
+    output_price = cost.get("output")
+    if isinstance(input_price, (int, float)) and isinstance(
+        output_price, (int, float)
+    ):
+        fields.append(f"input_price: {format_float_literal(input_price)}")
+        fields.append(f"output_price: {format_float_literal(output_price)}")
+
+    return fields
+
+
+# Providers that emulate structured output by forcing a tool call, so every model
+# that calls tools supports it.
+TOOL_CALL_STRUCTURED_OUTPUT_PROVIDERS = {
+    "anthropic",
+}
+
+
 def generate_capabilities_rs(provider_id: str, models: dict[str, Any]) -> str:
     """
     Generate the complete capabilities.rs content.
@@ -615,7 +726,7 @@ def generate_capabilities_rs(provider_id: str, models: dict[str, Any]) -> str:
     Returns:
         Generated Rust code as string
     """
-    provider_struct_name = to_pascal_case(provider_id)
+    provider_struct_name = get_provider_struct_name(provider_id)
     provider_module = provider_id_to_snake_case(provider_id)
 
     lines = [
@@ -667,17 +778,22 @@ def generate_capabilities_rs(provider_id: str, models: dict[str, Any]) -> str:
         constructor_name = get_model_constructor_name(base_name, folder_prefix)
         display_name = get_model_display_name(model_id, model_data)
         capabilities = get_model_capabilities(model_data)
+        if (
+            provider_id in TOOL_CALL_STRUCTURED_OUTPUT_PROVIDERS
+            and "ToolCallSupport" in capabilities
+        ):
+            capabilities = sorted(set(capabilities) | {"StructuredOutputSupport"})
+        fields = [
+            f'model_name: "{model_name}"',
+            f"constructor_name: {constructor_name}",
+            f'display_name: "{display_name}"',
+            f"capabilities: [{', '.join(capabilities)}]",
+            *get_model_info_fields(model_data),
+        ]
 
-        lines.extend(
-            [
-                f"        {model_type_name} {{",
-                f'            model_name: "{model_name}",',
-                f"            constructor_name: {constructor_name},",
-                f'            display_name: "{display_name}",',
-                f"            capabilities: [{', '.join(capabilities)}]",
-                "        },",
-            ]
-        )
+        lines.append(f"        {model_type_name} {{")
+        lines.append(",\n".join(f"            {field}" for field in fields))
+        lines.append("        },")
 
     lines.extend(
         [
@@ -689,6 +805,62 @@ def generate_capabilities_rs(provider_id: str, models: dict[str, Any]) -> str:
     return "\n".join(lines) + "\n"
 
 
+# Models a provider serves that models.dev does not list, in the models.dev schema,
+# keyed by provider ID. They are generated along with the provider's listed models,
+# which take precedence over them once models.dev lists them too.
+ADDITIONAL_MODELS: dict[str, dict[str, dict[str, Any]]] = {
+    "togetherai": {
+        "meta-llama/Llama-4-Maverick-17B-128E-Instruct-FP8": {
+            "name": "Llama 4 Maverick 17B 128E Instruct",
+            "tool_call": True,
+            "modalities": {"input": ["text", "image"], "output": ["text"]},
+        },
+        "meta-llama/Llama-4-Scout-17B-16E-Instruct": {
+            "name": "Llama 4 Scout 17B 16E Instruct",
+            "tool_call": True,
+            "modalities": {"input": ["text", "image"], "output": ["text"]},
+        },
+        "meta-llama/Meta-Llama-3.1-405B-Instruct-Turbo": {
+            "name": "Llama 3.1 405B Instruct Turbo",
+            "tool_call": True,
+            "modalities": {"input": ["text"], "output": ["text"]},
+        },
+        "meta-llama/Meta-Llama-3.1-8B-Instruct-Turbo": {
+            "name": "Llama 3.1 8B Instruct Turbo",
+            "tool_call": True,
+            "modalities": {"input": ["text"], "output": ["text"]},
+        },
+        "mistralai/Mistral-7B-Instruct-v0.3": {
+            "name": "Mistral 7B Instruct v0.3",
+            "modalities": {"input": ["text"], "output": ["text"]},
+        },
+        "mistralai/Mixtral-8x7B-Instruct-v0.1": {
+            "name": "Mixtral 8x7B Instruct v0.1",
+            "modalities": {"input": ["text"], "output": ["text"]},
+        },
+        "Qwen/Qwen2.5-72B-Instruct-Turbo": {
+            "name": "Qwen2.5 72B Instruct Turbo",
+            "tool_call": True,
+            "modalities": {"input": ["text"], "output": ["text"]},
+        },
+        "Qwen/Qwen2.5-Coder-32B-Instruct": {
+            "name": "Qwen2.5 Coder 32B Instruct",
+            "modalities": {"input": ["text"], "output": ["text"]},
+        },
+        "Qwen/Qwen3-235B-A22B-Instruct-2507-tput": {
+            "name": "Qwen3 235B A22B Instruct 2507",
+            "tool_call": True,
+            "modalities": {"input": ["text"], "output": ["text"]},
+        },
+        "Qwen/QwQ-32B": {
+            "name": "QwQ 32B",
+            "reasoning": True,
+            "modalities": {"input": ["text"], "output": ["text"]},
+        },
+    },
+}
+
+
 def generate_provider_capabilities_content(
     provider_id: str, provider_data: dict[str, Any]
 ) -> str | None:
@@ -702,7 +874,10 @@ def generate_provider_capabilities_content(
     Returns:
         Generated Rust code as string, or None if no models found
     """
-    models = provider_data.get("models", {})
+    models = {
+        **ADDITIONAL_MODELS.get(provider_id, {}),
+        **provider_data.get("models", {}),
+    }
     if not models:
         log(f"Warning: No models found for provider '{provider_id}'")
         return None
@@ -781,7 +956,7 @@ def generate_openai_compatible_content(
     Returns:
         Generated Rust code as string
     """
-    provider_struct_name = to_pascal_case(provider_id)
+    provider_struct_name = get_provider_struct_name(provider_id)
 
     # Get API endpoint from provider_data
     api_endpoint = provider_data.get("api", "")
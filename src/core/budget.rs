@@ -0,0 +1,438 @@
+//! Session-wide budgets that cap token usage and spend across requests.
+//!
+//! A [`Budget`] is a cheaply cloneable handle to shared usage counters. Attach the same
+//! budget to every request of a conversation or agent with
+//! [`budget`](crate::core::language_model::request::LanguageModelRequestBuilder::budget)
+//! and it accumulates usage (and cost, when [`TokenPricing`] is set) across all of them.
+//! Once a limit is hit, further model calls are rejected with [`Error::BudgetExceeded`],
+//! or, with [`BudgetExhaustedAction::Truncate`], their output is capped to what is left.
+//! To enforce a budget on every call of a model instead, whatever request makes it,
+//! wrap the model with the [`BudgetGuard`](crate::core::language_model::middleware::BudgetGuard)
+//! middleware.
+//!
+//! # Example
+//!
+//! ```
+//! use aisdk::core::budget::{Budget, TokenPricing};
+//! use aisdk::core::language_model::Usage;
+//!
+//! let budget = Budget::new()
+//!     .max_total_tokens(1_000)
+//!     .max_cost(0.50)
+//!     .pricing(TokenPricing::new(3.0, 15.0))
+//!     .on_warning(0.8, |status| println!("budget at {:.0}%", status.fraction_used * 100.0));
+//!
+//! budget.record(&Usage {
+//!     input_tokens: Some(400),
+//!     output_tokens: Some(100),
+//!     ..Default::default()
+//! });
+//!
+//! assert_eq!(budget.status().total_tokens, 500);
+//! assert_eq!(budget.remaining_tokens(), Some(500));
+//! ```
+
+use crate::core::cost::Cost;
+use crate::core::language_model::{LanguageModelOptions, Usage};
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+
+// ============================================================================
+// Section: types
+// ============================================================================
+
+/// Per-token prices used to convert usage into a dollar cost.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct TokenPricing {
+    /// Price in dollars per million input tokens.
+    pub input_per_million: f64,
+    /// Price in dollars per million output tokens.
+    pub output_per_million: f64,
+}
+
+impl TokenPricing {
+    /// Creates a new pricing from dollar prices per million input and output tokens.
+    pub fn new(input_per_million: f64, output_per_million: f64) -> Self {
+        Self {
+            input_per_million,
+            output_per_million,
+        }
+    }
+
+    /// Returns the dollar cost of the given usage.
+    pub fn cost(&self, usage: &Usage) -> f64 {
+        Cost::of(usage, self).total()
+    }
+}
+
+/// What a [`Budget`] does with a request once it can no longer be fully afforded.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BudgetExhaustedAction {
+    /// Reject the request with [`Error::BudgetExceeded`] once any limit is reached.
+    #[default]
+    Reject,
+    /// Cap the request's `max_output_tokens` to the remaining budget, and reject only
+    /// when nothing is left.
+    Truncate,
+}
+
+/// A snapshot of the usage recorded by a [`Budget`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BudgetStatus {
+    /// The cumulative usage across all recorded calls.
+    pub usage: Usage,
+    /// The cumulative input and output tokens.
+    pub total_tokens: usize,
+    /// The cumulative cost in dollars. Zero when no pricing is set.
+    pub cost: f64,
+    /// The largest fraction of any configured limit that has been used.
+    pub fraction_used: f64,
+}
+
+/// Type alias for the callback invoked when a budget is nearly exhausted.
+pub type BudgetWarningHook = Arc<dyn Fn(&BudgetStatus) + Send + Sync>;
+
+/// The recorded counters of a [`Budget`], detached from its limits so they can be
+/// persisted and restored later.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BudgetSnapshot {
+    /// The cumulative usage across all recorded calls.
+    pub usage: Usage,
+    /// The cumulative cost in dollars.
+    pub cost: f64,
+    /// Whether the warning callback has already fired.
+    pub warned: bool,
+}
+
+// ============================================================================
+// Section: budget
+// ============================================================================
+
+/// Tracks cumulative usage and cost and enforces limits across requests.
+///
+/// Clones share the same counters, so a single budget can be attached to many
+/// requests.
+#[derive(Clone, Default)]
+pub struct Budget {
+    max_total_tokens: Option<usize>,
+    max_input_tokens: Option<usize>,
+    max_output_tokens: Option<usize>,
+    max_cost: Option<f64>,
+    pricing: Option<TokenPricing>,
+    action: BudgetExhaustedAction,
+    warning: Option<(f64, BudgetWarningHook)>,
+    state: Arc<Mutex<BudgetSnapshot>>,
+}
+
+impl Debug for Budget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Budget")
+            .field("max_total_tokens", &self.max_total_tokens)
+            .field("max_input_tokens", &self.max_input_tokens)
+            .field("max_output_tokens", &self.max_output_tokens)
+            .field("max_cost", &self.max_cost)
+            .field("pricing", &self.pricing)
+            .field("action", &self.action)
+            .field("warning", &self.warning.as_ref().map(|(t, _)| t))
+            .field("status", &self.status())
+            .finish()
+    }
+}
+
+impl Budget {
+    /// Creates a budget without limits.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limits the cumulative input and output tokens.
+    pub fn max_total_tokens(mut self, tokens: usize) -> Self {
+        self.max_total_tokens = Some(tokens);
+        self
+    }
+
+    /// Limits the cumulative input tokens.
+    pub fn max_input_tokens(mut self, tokens: usize) -> Self {
+        self.max_input_tokens = Some(tokens);
+        self
+    }
+
+    /// Limits the cumulative output tokens.
+    pub fn max_output_tokens(mut self, tokens: usize) -> Self {
+        self.max_output_tokens = Some(tokens);
+        self
+    }
+
+    /// Limits the cumulative cost in dollars. Requires [`pricing`](Self::pricing)
+    /// to have any effect.
+    pub fn max_cost(mut self, dollars: f64) -> Self {
+        self.max_cost = Some(dollars);
+        self
+    }
+
+    /// Sets the prices used to compute the cost of recorded usage.
+    pub fn pricing(mut self, pricing: TokenPricing) -> Self {
+        self.pricing = Some(pricing);
+        self
+    }
+
+    /// Sets what happens to requests once a limit is reached.
+    pub fn on_exhausted(mut self, action: BudgetExhaustedAction) -> Self {
+        self.action = action;
+        self
+    }
+
+    /// Registers a callback that runs once, the first time the used fraction of any
+    /// limit reaches `threshold`.
+    ///
+    /// # Parameters
+    ///
+    /// * `threshold` - Fraction of the limit between `0.0` and `1.0`, e.g. `0.8`.
+    /// * `hook` - Called with the budget status when the threshold is crossed.
+    ///
+    /// # Returns
+    ///
+    /// The budget with the warning callback set.
+    pub fn on_warning<F>(mut self, threshold: f64, hook: F) -> Self
+    where
+        F: Fn(&BudgetStatus) + Send + Sync + 'static,
+    {
+        self.warning = Some((threshold, Arc::new(hook)));
+        self
+    }
+
+    /// Returns the usage and cost recorded so far.
+    pub fn status(&self) -> BudgetStatus {
+        let state = self.lock();
+        self.status_of(&state)
+    }
+
+    /// Returns the output tokens still affordable under the total, output, and cost
+    /// limits, or `None` if none of them is set.
+    pub fn remaining_tokens(&self) -> Option<usize> {
+        let state = self.lock();
+        self.remaining_output_tokens(&state)
+    }
+
+    /// Returns `true` once any limit has been reached.
+    pub fn is_exhausted(&self) -> bool {
+        self.status().fraction_used >= 1.0
+    }
+
+    /// Records the usage of a model call and fires the warning callback if the
+    /// threshold was crossed.
+    ///
+    /// The request loops call this automatically. Call it directly to account for
+    /// usage that did not go through a request, e.g. an embedding call.
+    pub fn record(&self, usage: &Usage) {
+        let status = {
+            let mut state = self.lock();
+            state.usage = &state.usage + usage;
+            if let Some(pricing) = &self.pricing {
+                state.cost += pricing.cost(usage);
+            }
+
+            let status = self.status_of(&state);
+            match &self.warning {
+                Some((threshold, _)) if !state.warned && status.fraction_used >= *threshold => {
+                    state.warned = true;
+                    status
+                }
+                _ => return,
+            }
+        };
+
+        if let Some((_, hook)) = &self.warning {
+            hook(&status);
+        }
+    }
+
+    /// Returns the recorded counters so they can be persisted.
+    pub fn snapshot(&self) -> BudgetSnapshot {
+        self.lock().clone()
+    }
+
+    /// Replaces the recorded counters with a previously taken snapshot.
+    pub fn restore(&self, snapshot: &BudgetSnapshot) {
+        *self.lock() = snapshot.clone();
+    }
+
+    /// Clears the recorded usage and re-arms the warning callback.
+    pub fn reset(&self) {
+        *self.lock() = BudgetSnapshot::default();
+    }
+
+    /// Checks the budget before a model call, capping `max_output_tokens` when
+    /// truncation is enabled.
+    pub(crate) fn enforce(&self, options: &mut LanguageModelOptions) -> Result<()> {
+        let state = self.lock();
+        let status = self.status_of(&state);
+        let remaining = self.remaining_output_tokens(&state);
+
+        if status.fraction_used >= 1.0 || remaining == Some(0) {
+            return Err(Error::BudgetExceeded(format!(
+                "{} tokens and ${:.4} used",
+                status.total_tokens, status.cost
+            )));
+        }
+
+        if self.action == BudgetExhaustedAction::Truncate
+            && let Some(remaining) = remaining
+        {
+            let remaining = u32::try_from(remaining).unwrap_or(u32::MAX);
+            options.max_output_tokens = Some(
+                options
+                    .max_output_tokens
+                    .map_or(remaining, |max| max.min(remaining)),
+            );
+        }
+
+        Ok(())
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, BudgetSnapshot> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn status_of(&self, state: &BudgetSnapshot) -> BudgetStatus {
+        let input = state.usage.input_tokens.unwrap_or(0);
+        let output = state.usage.output_tokens.unwrap_or(0);
+        let total = input + output;
+
+        let fraction = |used: f64, limit: Option<f64>| match limit {
+            Some(limit) if limit > 0.0 => used / limit,
+            Some(_) => 1.0,
+            None => 0.0,
+        };
+        let cost_limit = self.max_cost.filter(|_| self.pricing.is_some());
+        let fraction_used = [
+            fraction(total as f64, self.max_total_tokens.map(|l| l as f64)),
+            fraction(input as f64, self.max_input_tokens.map(|l| l as f64)),
+            fraction(output as f64, self.max_output_tokens.map(|l| l as f64)),
+            fraction(state.cost, cost_limit),
+        ]
+        .into_iter()
+        .fold(0.0, f64::max);
+
+        BudgetStatus {
+            usage: state.usage.clone(),
+            total_tokens: total,
+            cost: state.cost,
+            fraction_used,
+        }
+    }
+
+    /// Returns the output tokens still affordable under the total, output, and cost limits.
+    fn remaining_output_tokens(&self, state: &BudgetSnapshot) -> Option<usize> {
+        let input = state.usage.input_tokens.unwrap_or(0);
+        let output = state.usage.output_tokens.unwrap_or(0);
+
+        let by_cost = match (self.max_cost, &self.pricing) {
+            (Some(max), Some(pricing)) if pricing.output_per_million > 0.0 => {
+                let left = (max - state.cost).max(0.0);
+                Some((left * 1_000_000.0 / pricing.output_per_million) as usize)
+            }
+            _ => None,
+        };
+
+        [
+            self.max_total_tokens
+                .map(|l| l.saturating_sub(input + output)),
+            self.max_output_tokens.map(|l| l.saturating_sub(output)),
+            by_cost,
+        ]
+        .into_iter()
+        .flatten()
+        .min()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn usage(input: usize, output: usize) -> Usage {
+        Usage {
+            input_tokens: Some(input),
+            output_tokens: Some(output),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_clones_share_usage() {
+        let budget = Budget::new().max_total_tokens(100);
+        let other = budget.clone();
+        other.record(&usage(30, 20));
+        assert_eq!(budget.status().total_tokens, 50);
+        assert_eq!(budget.remaining_tokens(), Some(50));
+        budget.reset();
+        assert_eq!(other.status().total_tokens, 0);
+    }
+
+    #[test]
+    fn test_cost_is_computed_from_pricing() {
+        let budget = Budget::new()
+            .max_cost(1.0)
+            .pricing(TokenPricing::new(2.0, 10.0));
+        budget.record(&usage(100_000, 50_000));
+        let status = budget.status();
+        assert!((status.cost - 0.7).abs() < 1e-9);
+        assert!((status.fraction_used - 0.7).abs() < 1e-9);
+        assert_eq!(budget.remaining_tokens(), Some(30_000));
+    }
+
+    #[test]
+    fn test_reject_when_exhausted() {
+        let budget = Budget::new().max_total_tokens(100);
+        let mut options = LanguageModelOptions::default();
+        assert!(budget.enforce(&mut options).is_ok());
+
+        budget.record(&usage(80, 20));
+        assert!(budget.is_exhausted());
+        assert!(matches!(
+            budget.enforce(&mut options),
+            Err(Error::BudgetExceeded(_))
+        ));
+    }
+
+    #[test]
+    fn test_truncate_caps_output_tokens() {
+        let budget = Budget::new()
+            .max_output_tokens(100)
+            .on_exhausted(BudgetExhaustedAction::Truncate);
+        budget.record(&usage(0, 70));
+
+        let mut options = LanguageModelOptions {
+            max_output_tokens: Some(500),
+            ..Default::default()
+        };
+        budget.enforce(&mut options).unwrap();
+        assert_eq!(options.max_output_tokens, Some(30));
+
+        budget.record(&usage(0, 30));
+        assert!(budget.enforce(&mut options).is_err());
+    }
+
+    #[test]
+    fn test_warning_fires_once() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = calls.clone();
+        let budget = Budget::new()
+            .max_total_tokens(100)
+            .on_warning(0.5, move |_| {
+                counter.fetch_add(1, Ordering::SeqCst);
+            });
+
+        budget.record(&usage(20, 10));
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+        budget.record(&usage(20, 10));
+        budget.record(&usage(5, 5));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,152 @@
+//! Cancellation and timeouts for generation calls.
+//!
+//! A request can be given a [`CancellationToken`] with
+//! [`cancellation_token`](crate::core::language_model::request::LanguageModelRequestBuilder::cancellation_token)
+//! and an overall deadline with
+//! [`timeout`](crate::core::language_model::request::LanguageModelRequestBuilder::timeout).
+//! Both are checked around every model call, while a response is streamed, and while
+//! tools run. When one fires, the in-flight HTTP request or SSE stream is dropped, which
+//! closes the connection, running tools are aborted, and the call fails with
+//! [`Error::Cancelled`] or [`Error::Timeout`].
+//!
+//! # Example
+//!
+//! ```
+//! use aisdk::core::cancellation::CancellationToken;
+//!
+//! let token = CancellationToken::new();
+//! let for_request = token.clone();
+//!
+//! // Pass `for_request` to the request builder, then later:
+//! token.cancel();
+//! assert!(for_request.is_cancelled());
+//! ```
+
+#[cfg(feature = "language-model-request")]
+use crate::core::runtime::Instant;
+#[cfg(feature = "language-model-request")]
+use crate::error::{Error, Result};
+#[cfg(feature = "language-model-request")]
+use std::future::Future;
+#[cfg(feature = "language-model-request")]
+use std::time::Duration;
+
+pub use tokio_util::sync::CancellationToken;
+
+/// Races operations against a request's cancellation token and deadline.
+#[derive(Debug, Clone, Default)]
+#[cfg(feature = "language-model-request")]
+pub(crate) struct Interrupt {
+    /// Token that cancels the request when triggered.
+    token: Option<CancellationToken>,
+    /// The configured timeout and the instant it elapses.
+    deadline: Option<(Duration, Instant)>,
+}
+
+#[cfg(feature = "language-model-request")]
+impl Interrupt {
+    /// Creates an interrupt whose deadline starts now.
+    ///
+    /// # Parameters
+    ///
+    /// * `token` - Optional token that cancels the operation.
+    /// * `timeout` - Optional time allowed for the whole operation.
+    pub(crate) fn new(token: Option<CancellationToken>, timeout: Option<Duration>) -> Self {
+        Self {
+            token,
+            deadline: timeout.map(|timeout| (timeout, Instant::now() + timeout)),
+        }
+    }
+
+    /// Returns an error if the request was cancelled or its deadline has passed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Cancelled`] or [`Error::Timeout`].
+    pub(crate) fn check(&self) -> Result<()> {
+        if self.token.as_ref().is_some_and(|t| t.is_cancelled()) {
+            return Err(Error::Cancelled);
+        }
+        match self.deadline {
+            Some((timeout, deadline)) if Instant::now() >= deadline => Err(Error::Timeout(timeout)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Runs `operation` until it completes, the token is cancelled, or the deadline passes.
+    ///
+    /// The operation is dropped when interrupted.
+    ///
+    /// # Errors
+    ///
+    /// Returns the operation's error, [`Error::Cancelled`] or [`Error::Timeout`].
+    pub(crate) async fn run<T, F>(&self, operation: F) -> Result<T>
+    where
+        F: Future<Output = Result<T>>,
+    {
+        self.check()?;
+
+        let cancelled = async {
+            match &self.token {
+                Some(token) => token.cancelled().await,
+                None => std::future::pending().await,
+            }
+        };
+        let timed_out = async {
+            match self.deadline {
+                Some((_, deadline)) => crate::core::runtime::sleep_until(deadline).await,
+                None => std::future::pending().await,
+            }
+        };
+
+        tokio::select! {
+            biased;
+            _ = cancelled => Err(Error::Cancelled),
+            _ = timed_out => Err(Error::Timeout(self.deadline.map(|(t, _)| t).unwrap_or_default())),
+            result = operation => result,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "language-model-request"))]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_run_without_limits_completes() {
+        let interrupt = Interrupt::default();
+        assert_eq!(interrupt.run(async { Ok(1) }).await, Ok(1));
+    }
+
+    #[tokio::test]
+    async fn test_run_cancelled() {
+        let token = CancellationToken::new();
+        let interrupt = Interrupt::new(Some(token.clone()), None);
+
+        let canceller = async { token.cancel() };
+        let (result, _) = tokio::join!(
+            interrupt.run(std::future::pending::<Result<()>>()),
+            canceller
+        );
+
+        assert_eq!(result, Err(Error::Cancelled));
+        assert_eq!(interrupt.check(), Err(Error::Cancelled));
+    }
+
+    #[tokio::test]
+    async fn test_run_timeout() {
+        let timeout = Duration::from_millis(10);
+        let interrupt = Interrupt::new(None, Some(timeout));
+
+        let result = interrupt.run(std::future::pending::<Result<()>>()).await;
+
+        assert_eq!(result, Err(Error::Timeout(timeout)));
+        assert_eq!(interrupt.check(), Err(Error::Timeout(timeout)));
+    }
+
+    #[tokio::test]
+    async fn test_run_completes_before_timeout() {
+        let interrupt = Interrupt::new(None, Some(Duration::from_secs(60)));
+        assert_eq!(interrupt.run(async { Ok("done") }).await, Ok("done"));
+    }
+}
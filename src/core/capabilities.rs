@@ -6,12 +6,229 @@
 //! This ensures that selected models are capable of doing the tasks they are intended for.
 //! For example, only models that support tool calls can be used for tool usage.
 
+use crate::core::budget::TokenPricing;
+
 /// A trait that represents a model name.
 /// struct name to actual model name
 /// e.g. struct Gpt4 {}, impl ModelName for Gpt3 { const MODEL_NAME: &'static str = "gpt-4"; }
 pub trait ModelName: Send + Sync + std::fmt::Debug + Clone + 'static {
     /// The underlying API model name.
     const MODEL_NAME: &'static str;
+
+    /// The capabilities of the model, unknown unless set by [`model_capabilities!`].
+    const CAPABILITIES: Capabilities = Capabilities::UNKNOWN;
+
+    /// The limits and pricing of the model, unknown unless set by
+    /// [`model_capabilities!`].
+    const INFO: ModelInfo = ModelInfo::UNKNOWN;
+}
+
+/// The limits and pricing of a model, queried at runtime with
+/// [`LanguageModel::info`](crate::core::LanguageModel::info), e.g. to estimate costs,
+/// enforce budgets or fit a conversation into the context window.
+///
+/// Only the catalogs of the `anthropic`, `google` and `openai` providers record limits
+/// and pricing so far. The other generated catalogs predate them, and their models
+/// return [`ModelInfo::UNKNOWN`] until they are regenerated with
+/// `scripts/provider-codegen.py capabilities`.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "anthropic")]
+/// # {
+/// use aisdk::core::{DynamicModel, LanguageModel};
+/// use aisdk::providers::Anthropic;
+///
+/// let info = Anthropic::claude_sonnet_4_5().info();
+/// assert_eq!(info.context_window, Some(200_000));
+/// assert_eq!(info.pricing.unwrap().output_per_million, 15.0);
+///
+/// // A dynamic model has the info of the listed model of the same name
+/// let model = Anthropic::<DynamicModel>::model_name("claude-sonnet-4-5");
+/// assert_eq!(model.context_length(), Some(200_000));
+/// assert_eq!(
+///     Anthropic::<DynamicModel>::model_name("my-fine-tune").info(),
+///     aisdk::core::capabilities::ModelInfo::UNKNOWN
+/// );
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ModelInfo {
+    /// The context window of the model in tokens, input and output included.
+    pub context_window: Option<usize>,
+    /// The maximum number of tokens the model generates in a response.
+    pub max_output_tokens: Option<usize>,
+    /// The price of the model in dollars per million input and output tokens.
+    pub pricing: Option<TokenPricing>,
+}
+
+impl ModelInfo {
+    /// Unknown limits and pricing.
+    pub const UNKNOWN: Self = Self {
+        context_window: None,
+        max_output_tokens: None,
+        pricing: None,
+    };
+}
+
+/// A capability of a model, the runtime counterpart of a marker trait such as
+/// [`ToolCallSupport`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Capability {
+    /// See [`ToolCallSupport`].
+    ToolCall,
+    /// See [`ReasoningSupport`].
+    Reasoning,
+    /// See [`StructuredOutputSupport`].
+    StructuredOutput,
+    /// See [`TextInputSupport`].
+    TextInput,
+    /// See [`VideoInputSupport`].
+    VideoInput,
+    /// See [`AudioInputSupport`].
+    AudioInput,
+    /// See [`ImageInputSupport`].
+    ImageInput,
+    /// See [`TextOutputSupport`].
+    TextOutput,
+    /// See [`VideoOutputSupport`].
+    VideoOutput,
+    /// See [`AudioOutputSupport`].
+    AudioOutput,
+    /// See [`ImageOutputSupport`].
+    ImageOutput,
+}
+
+impl Capability {
+    /// Every capability.
+    pub const ALL: [Capability; 11] = [
+        Capability::ToolCall,
+        Capability::Reasoning,
+        Capability::StructuredOutput,
+        Capability::TextInput,
+        Capability::VideoInput,
+        Capability::AudioInput,
+        Capability::ImageInput,
+        Capability::TextOutput,
+        Capability::VideoOutput,
+        Capability::AudioOutput,
+        Capability::ImageOutput,
+    ];
+
+    const fn bit(self) -> u16 {
+        1 << self as u16
+    }
+}
+
+/// The capabilities of a model, queried at runtime with
+/// [`LanguageModel::capabilities`](crate::core::LanguageModel::capabilities), e.g. for
+/// a model chosen from a configuration file.
+///
+/// The capabilities of a [`DynamicModel`] are unknown, as the model is only known to
+/// the provider's API.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "openai")]
+/// # {
+/// use aisdk::core::capabilities::Capability;
+/// use aisdk::core::{DynamicModel, LanguageModel};
+/// use aisdk::providers::OpenAI;
+///
+/// let capabilities = OpenAI::gpt_5().capabilities();
+/// assert_eq!(capabilities.supports(Capability::ToolCall), Some(true));
+///
+/// let capabilities = OpenAI::<DynamicModel>::model_name("gpt-5").capabilities();
+/// assert_eq!(capabilities.supports(Capability::ToolCall), None);
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Capabilities {
+    /// The set capabilities, or `None` if they are unknown.
+    bits: Option<u16>,
+}
+
+impl Capabilities {
+    /// Unknown capabilities.
+    pub const UNKNOWN: Self = Self { bits: None };
+
+    /// Creates the known capabilities of a model.
+    pub const fn new(capabilities: &[Capability]) -> Self {
+        let mut bits = 0;
+        let mut i = 0;
+        while i < capabilities.len() {
+            bits |= capabilities[i].bit();
+            i += 1;
+        }
+        Self { bits: Some(bits) }
+    }
+
+    /// Returns true if the capabilities are known.
+    pub const fn is_known(&self) -> bool {
+        self.bits.is_some()
+    }
+
+    /// Returns whether the model has `capability`, or `None` if its capabilities are
+    /// unknown.
+    pub fn supports(&self, capability: Capability) -> Option<bool> {
+        self.bits.map(|bits| bits & capability.bit() != 0)
+    }
+
+    /// Returns the capabilities the model has, none if they are unknown.
+    pub fn iter(&self) -> impl Iterator<Item = Capability> + '_ {
+        Capability::ALL
+            .into_iter()
+            .filter(|&capability| self.supports(capability) == Some(true))
+    }
+
+    /// Returns the capabilities both models have, unknown if either is unknown.
+    pub fn intersection(self, other: Self) -> Self {
+        match (self.bits, other.bits) {
+            (Some(a), Some(b)) => Self { bits: Some(a & b) },
+            _ => Self::UNKNOWN,
+        }
+    }
+}
+
+/// Maps a capability marker trait to its [`Capability`], for [`model_capabilities!`].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __capability {
+    (ToolCallSupport) => {
+        $crate::core::capabilities::Capability::ToolCall
+    };
+    (ReasoningSupport) => {
+        $crate::core::capabilities::Capability::Reasoning
+    };
+    (StructuredOutputSupport) => {
+        $crate::core::capabilities::Capability::StructuredOutput
+    };
+    (TextInputSupport) => {
+        $crate::core::capabilities::Capability::TextInput
+    };
+    (VideoInputSupport) => {
+        $crate::core::capabilities::Capability::VideoInput
+    };
+    (AudioInputSupport) => {
+        $crate::core::capabilities::Capability::AudioInput
+    };
+    (ImageInputSupport) => {
+        $crate::core::capabilities::Capability::ImageInput
+    };
+    (TextOutputSupport) => {
+        $crate::core::capabilities::Capability::TextOutput
+    };
+    (VideoOutputSupport) => {
+        $crate::core::capabilities::Capability::VideoOutput
+    };
+    (AudioOutputSupport) => {
+        $crate::core::capabilities::Capability::AudioOutput
+    };
+    (ImageOutputSupport) => {
+        $crate::core::capabilities::Capability::ImageOutput
+    };
 }
 
 /// Marker trait for models that support tool calls.
@@ -65,10 +282,25 @@ impl ModelName for DynamicModel {
     const MODEL_NAME: &'static str = ""; // model name injected at runtime
 }
 
+/// Wraps an optional field of [`model_capabilities!`] into an [`Option`].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __optional {
+    () => {
+        None
+    };
+    ($value:expr) => {
+        Some($value)
+    };
+}
+
 /// Macro to define model capabilities for a provider.
 ///
 /// This macro generates model struct definitions, trait implementations,
-/// and constructor methods for a provider's supported models.
+/// and constructor methods for a provider's supported models. The capabilities are
+/// also recorded in [`ModelName::CAPABILITIES`], for runtime queries, along with the
+/// optional `context_window`, `max_output_tokens`, and `input_price` and
+/// `output_price` per million tokens of a model in [`ModelName::INFO`].
 #[macro_export]
 macro_rules! model_capabilities {
     (
@@ -80,6 +312,9 @@ macro_rules! model_capabilities {
                     constructor_name: $constructor_name:ident,
                     display_name: $display_name:literal,
                     capabilities: [$($capability:ident),* $(,)?]
+                    $(, context_window: $context_window:literal)?
+                    $(, max_output_tokens: $max_output_tokens:literal)?
+                    $(, input_price: $input_price:literal, output_price: $output_price:literal)?
                 }
             ),* $(,)?
         }
@@ -104,6 +339,25 @@ macro_rules! model_capabilities {
             impl ModelName for $model {
                 /// The underlying API model name.
                 const MODEL_NAME: &'static str = $model_name;
+
+                /// The capabilities of the model.
+                const CAPABILITIES: $crate::core::capabilities::Capabilities =
+                    $crate::core::capabilities::Capabilities::new(&[
+                        $($crate::__capability!($capability)),*
+                    ]);
+
+                /// The limits and pricing of the model.
+                const INFO: $crate::core::capabilities::ModelInfo =
+                    $crate::core::capabilities::ModelInfo {
+                        context_window: $crate::__optional!($($context_window)?),
+                        max_output_tokens: $crate::__optional!($($max_output_tokens)?),
+                        pricing: $crate::__optional!($(
+                            $crate::core::budget::TokenPricing {
+                                input_per_million: $input_price,
+                                output_per_million: $output_price,
+                            }
+                        )?),
+                    };
             }
 
             $(
@@ -133,6 +387,36 @@ macro_rules! model_capabilities {
             }
         )*
 
+        impl<M: $crate::core::capabilities::ModelName>
+            From<$provider<M>> for $crate::core::language_model::boxed::BoxedLanguageModel
+        where
+            $provider<M>: $crate::core::LanguageModel,
+        {
+            #[doc = concat!(
+                "Boxes a [`",
+                stringify!($provider),
+                "`] language model, to be held with models of other providers."
+            )]
+            fn from(model: $provider<M>) -> Self {
+                Box::new(model)
+            }
+        }
+
+        impl<M: $crate::core::capabilities::ModelName> $provider<M> {
+            /// Returns the limits and pricing of the model named `model_name`: those of
+            /// `M`, or for a [`DynamicModel`], those of the listed model of the same
+            /// name, if any.
+            pub fn model_info(model_name: &str) -> $crate::core::capabilities::ModelInfo {
+                if M::INFO != $crate::core::capabilities::ModelInfo::UNKNOWN {
+                    return M::INFO;
+                }
+                match model_name {
+                    $($model_name => <$model as $crate::core::capabilities::ModelName>::INFO,)*
+                    _ => $crate::core::capabilities::ModelInfo::UNKNOWN,
+                }
+            }
+        }
+
         // Auto-generate capability implementations for Provider<DynamicModel>
         // This allows runtime model selection with API-validated capabilities
 
@@ -149,3 +433,29 @@ macro_rules! model_capabilities {
         impl AudioOutputSupport for $provider<DynamicModel> {}
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capabilities() {
+        let tools = Capabilities::new(&[Capability::ToolCall, Capability::TextInput]);
+        let vision = Capabilities::new(&[Capability::ImageInput, Capability::TextInput]);
+
+        assert_eq!(tools.supports(Capability::ToolCall), Some(true));
+        assert_eq!(tools.supports(Capability::ImageInput), Some(false));
+        assert_eq!(Capabilities::UNKNOWN.supports(Capability::ToolCall), None);
+        assert_eq!(
+            tools.intersection(vision).iter().collect::<Vec<_>>(),
+            [Capability::TextInput]
+        );
+        assert!(!tools.intersection(Capabilities::UNKNOWN).is_known());
+    }
+
+    #[test]
+    fn test_dynamic_model_is_unknown() {
+        assert!(!DynamicModel::CAPABILITIES.is_known());
+        assert_eq!(DynamicModel::INFO, ModelInfo::UNKNOWN);
+    }
+}
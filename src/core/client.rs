@@ -1,253 +1,2155 @@
 //! This module provides the client for interacting with the AI providers.
 //! It is a thin wrapper around the `reqwest` crate.
 
+use crate::core::provider::ModelDescriptor;
+use crate::core::rate_limit::{RateLimitInfo, RateLimiter};
+use crate::core::runtime::{Instant, assume_send};
 use crate::core::utils::join_url;
 use crate::error::{Error, Result};
+use async_trait::async_trait;
+use bytes::Bytes;
+use eventsource_stream::Eventsource;
 use futures::Stream;
 use futures::StreamExt;
 use reqwest;
 use reqwest::IntoUrl;
-use reqwest_eventsource::{Event, RequestBuilderExt};
+use reqwest_eventsource::Event;
 use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
 use std::pin::Pin;
+use std::sync::{Arc, PoisonError, RwLock};
 use std::time::Duration;
 
-/// Configuration for retry behavior on API requests.
-#[derive(Debug, Clone)]
-struct RetryConfig {
+/// Retry behavior for API requests.
+///
+/// Requests are retried on rate limiting (429), request timeouts (408), server errors
+/// (5xx other than 501), and connection failures, with exponential backoff. A
+/// `Retry-After` header sent by the server takes precedence over the computed backoff.
+/// Set it on a provider with its builder's `retry_policy` method.
+///
+/// # Example
+///
+/// ```
+/// use aisdk::core::client::RetryPolicy;
+/// use std::time::Duration;
+///
+/// let policy = RetryPolicy::new()
+///     .max_retries(3)
+///     .initial_wait(Duration::from_millis(500))
+///     .max_elapsed_time(Duration::from_secs(20));
+///
+/// assert_eq!(policy.max_retries, 3);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RetryPolicy {
     /// Maximum number of retry attempts (default: 5)
-    max_retries: u32,
+    pub max_retries: u32,
     /// Initial wait time before first retry (default: 1 second)
-    initial_wait: Duration,
+    pub initial_wait: Duration,
     /// Maximum wait time between retries (default: 30 seconds)
-    max_wait: Duration,
+    pub max_wait: Duration,
+    /// Maximum total time spent on a request including retries. No retry is started
+    /// that would end after this limit. (default: none)
+    pub max_elapsed_time: Option<Duration>,
     /// Whether to add jitter to backoff (default: true)
-    use_jitter: bool,
+    pub use_jitter: bool,
+    /// Reconnection of streams whose connection drops mid-generation
+    #[serde(default)]
+    pub stream_recovery: StreamRecovery,
 }
 
-impl Default for RetryConfig {
+impl Default for RetryPolicy {
     fn default() -> Self {
         Self {
             max_retries: 5,
             initial_wait: Duration::from_secs(1),
             max_wait: Duration::from_secs(30),
+            max_elapsed_time: None,
             use_jitter: true,
+            stream_recovery: StreamRecovery::default(),
         }
     }
 }
 
-/// Checks if a status code is retryable.
-fn is_retryable_status(status: reqwest::StatusCode) -> bool {
-    matches!(
-        status,
-        reqwest::StatusCode::TOO_MANY_REQUESTS
-            | reqwest::StatusCode::BAD_GATEWAY
-            | reqwest::StatusCode::SERVICE_UNAVAILABLE
-            | reqwest::StatusCode::GATEWAY_TIMEOUT
-    )
+impl RetryPolicy {
+    /// Creates a policy with the default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a policy that never retries.
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            ..Self::default()
+        }
+    }
+
+    /// Sets the maximum number of retry attempts.
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the wait time before the first retry.
+    pub fn initial_wait(mut self, initial_wait: Duration) -> Self {
+        self.initial_wait = initial_wait;
+        self
+    }
+
+    /// Sets the maximum wait time between retries.
+    pub fn max_wait(mut self, max_wait: Duration) -> Self {
+        self.max_wait = max_wait;
+        self
+    }
+
+    /// Sets the maximum total time spent on a request including retries.
+    pub fn max_elapsed_time(mut self, max_elapsed_time: Duration) -> Self {
+        self.max_elapsed_time = Some(max_elapsed_time);
+        self
+    }
+
+    /// Sets whether to add jitter to the backoff.
+    pub fn jitter(mut self, use_jitter: bool) -> Self {
+        self.use_jitter = use_jitter;
+        self
+    }
+
+    /// Sets how streams whose connection drops mid-generation are reconnected.
+    pub fn stream_recovery(mut self, stream_recovery: StreamRecovery) -> Self {
+        self.stream_recovery = stream_recovery;
+        self
+    }
+
+    /// Returns the wait before the next retry, or `None` if the request should not be
+    /// retried again.
+    fn next_wait(
+        &self,
+        retry_count: u32,
+        started: Instant,
+        retry_after: Option<Duration>,
+    ) -> Option<Duration> {
+        if retry_count >= self.max_retries {
+            return None;
+        }
+        let wait = calculate_backoff(retry_count, self, retry_after);
+        match self.max_elapsed_time {
+            Some(max) if started.elapsed().saturating_add(wait) > max => None,
+            _ => Some(wait),
+        }
+    }
 }
 
-/// Parses the Retry-After header to get the wait duration.
-fn parse_retry_after(headers: &reqwest::header::HeaderMap) -> Option<Duration> {
-    headers
-        .get(reqwest::header::RETRY_AFTER)
-        .and_then(|v| v.to_str().ok())
-        .and_then(|s| {
-            // Try parsing as seconds (integer)
-            if let Ok(seconds) = s.parse::<u64>() {
-                return Some(Duration::from_secs(seconds));
-            }
-            None
+/// Reconnection behavior for streams whose connection drops mid-generation.
+///
+/// A dropped stream is only reconnected where it can be resumed without generating
+/// the response again: by repeating the request with a `Last-Event-ID` header once the
+/// server has sent SSE event ids, or, for OpenAI background responses, by streaming the
+/// response again from the last received event. Other streams fail as before.
+///
+/// Set it on a provider as part of its [`RetryPolicy`].
+///
+/// # Example
+///
+/// ```
+/// use aisdk::core::client::{RetryPolicy, StreamRecovery};
+/// use std::time::Duration;
+///
+/// let policy = RetryPolicy::new().stream_recovery(
+///     StreamRecovery::new()
+///         .max_reconnects(5)
+///         .wait(Duration::from_millis(200)),
+/// );
+///
+/// assert_eq!(policy.stream_recovery.max_reconnects, 5);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StreamRecovery {
+    /// Maximum number of reconnects per stream (default: 3)
+    pub max_reconnects: u32,
+    /// Wait time before each reconnect (default: 1 second)
+    pub wait: Duration,
+}
+
+impl Default for StreamRecovery {
+    fn default() -> Self {
+        Self {
+            max_reconnects: 3,
+            wait: Duration::from_secs(1),
+        }
+    }
+}
+
+impl StreamRecovery {
+    /// Creates a policy with the default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a policy that never reconnects.
+    pub fn none() -> Self {
+        Self {
+            max_reconnects: 0,
+            ..Self::default()
+        }
+    }
+
+    /// Sets the maximum number of reconnects per stream.
+    pub fn max_reconnects(mut self, max_reconnects: u32) -> Self {
+        self.max_reconnects = max_reconnects;
+        self
+    }
+
+    /// Sets the wait time before each reconnect.
+    pub fn wait(mut self, wait: Duration) -> Self {
+        self.wait = wait;
+        self
+    }
+}
+
+/// The HTTP client shared by all providers, created on first use.
+static HTTP_CLIENT: RwLock<Option<reqwest::Client>> = RwLock::new(None);
+
+/// Returns the HTTP client shared by all providers.
+///
+/// All requests go through this client, so connections and TLS sessions are reused
+/// across requests and providers. Unless one was set with [`set_http_client`], it is
+/// created with the default [`HttpClientConfig`] on first use.
+pub fn http_client() -> reqwest::Client {
+    if let Some(client) = HTTP_CLIENT
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .as_ref()
+    {
+        return client.clone();
+    }
+    HTTP_CLIENT
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get_or_insert_with(|| {
+            HttpClientConfig::default()
+                .build()
+                .unwrap_or_else(|_| reqwest::Client::new())
         })
+        .clone()
 }
 
-/// Calculates the next wait duration with exponential backoff and optional jitter.
-fn calculate_backoff(
-    retry_count: u32,
-    config: &RetryConfig,
-    retry_after: Option<Duration>,
-) -> Duration {
-    // If server provides Retry-After, respect it
-    if let Some(duration) = retry_after {
-        return duration.min(config.max_wait);
+/// Replaces the HTTP client shared by all providers, e.g. with one built with
+/// [`HttpClientConfig::build`] or configured by the application.
+///
+/// Requests started afterwards use the new client; streams already open keep theirs.
+pub fn set_http_client(client: reqwest::Client) {
+    *HTTP_CLIENT.write().unwrap_or_else(PoisonError::into_inner) = Some(client);
+}
+
+/// Connection pool, proxy and TLS settings of an HTTP client.
+///
+/// Build a client with these settings and either share it with [`set_http_client`] or
+/// give it to a single provider with its builder's `http_client` method.
+///
+/// Settings left unset keep the defaults of `reqwest`. They are ignored on `wasm32`,
+/// where the browser manages connections and certificates.
+///
+/// # Example
+///
+/// ```
+/// use aisdk::core::client::{HttpClientConfig, set_http_client};
+/// use std::time::Duration;
+///
+/// let client = HttpClientConfig::new()
+///     .pool_max_idle_per_host(16)
+///     .pool_idle_timeout(Duration::from_secs(30))
+///     .proxy("http://proxy.internal:8080")
+///     .build()
+///     .unwrap();
+/// set_http_client(client);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HttpClientConfig {
+    /// Maximum number of idle connections kept per host (default: unlimited)
+    pub pool_max_idle_per_host: Option<usize>,
+    /// How long an idle connection is kept open (default: 90 seconds)
+    pub pool_idle_timeout: Option<Duration>,
+    /// Timeout for establishing a connection (default: none)
+    pub connect_timeout: Option<Duration>,
+    /// Interval of TCP keepalive probes on open connections (default: 15 seconds)
+    pub tcp_keepalive: Option<Duration>,
+    /// URL of the proxy all requests go through, e.g. `http://proxy.internal:8080`
+    /// (default: the proxy set by the `HTTP_PROXY` and `HTTPS_PROXY` environment
+    /// variables)
+    pub proxy: Option<String>,
+    /// PEM encoded certificates trusted in addition to the system's root certificates
+    pub root_certificates: Vec<Vec<u8>>,
+    /// Whether to accept invalid TLS certificates, e.g. self-signed ones (default:
+    /// false). This makes connections open to man-in-the-middle attacks; prefer adding
+    /// the certificate with [`root_certificate`](Self::root_certificate).
+    pub danger_accept_invalid_certs: bool,
+}
+
+impl HttpClientConfig {
+    /// Creates a configuration with the default settings.
+    pub fn new() -> Self {
+        Self::default()
     }
 
-    // Calculate exponential backoff: initial_wait * 2^retry_count
-    let backoff = config
-        .initial_wait
-        .saturating_mul(2_u32.saturating_pow(retry_count));
-    let backoff = backoff.min(config.max_wait);
+    /// Sets the maximum number of idle connections kept per host.
+    pub fn pool_max_idle_per_host(mut self, pool_max_idle_per_host: usize) -> Self {
+        self.pool_max_idle_per_host = Some(pool_max_idle_per_host);
+        self
+    }
 
-    // Add jitter to prevent thundering herd (±10% of backoff time)
-    if config.use_jitter {
-        let now = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_nanos();
-        let jitter_pct = ((now % 200) as i64 - 100) as f64 / 1000.0; // Range: -0.1 to +0.1
-        let jitter_ms = (backoff.as_millis() as f64 * jitter_pct) as i64;
+    /// Sets how long an idle connection is kept open.
+    pub fn pool_idle_timeout(mut self, pool_idle_timeout: Duration) -> Self {
+        self.pool_idle_timeout = Some(pool_idle_timeout);
+        self
+    }
 
-        if jitter_ms >= 0 {
-            backoff.saturating_add(Duration::from_millis(jitter_ms as u64))
-        } else {
-            backoff.saturating_sub(Duration::from_millis((-jitter_ms) as u64))
+    /// Sets the timeout for establishing a connection.
+    pub fn connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.connect_timeout = Some(connect_timeout);
+        self
+    }
+
+    /// Sets the interval of TCP keepalive probes on open connections.
+    pub fn tcp_keepalive(mut self, tcp_keepalive: Duration) -> Self {
+        self.tcp_keepalive = Some(tcp_keepalive);
+        self
+    }
+
+    /// Sets the URL of the proxy all requests go through.
+    pub fn proxy(mut self, proxy: impl Into<String>) -> Self {
+        self.proxy = Some(proxy.into());
+        self
+    }
+
+    /// Adds a PEM encoded certificate to trust, e.g. the one of a corporate proxy or a
+    /// self-hosted gateway.
+    pub fn root_certificate(mut self, pem: impl Into<Vec<u8>>) -> Self {
+        self.root_certificates.push(pem.into());
+        self
+    }
+
+    /// Sets whether to accept invalid TLS certificates.
+    pub fn danger_accept_invalid_certs(mut self, danger_accept_invalid_certs: bool) -> Self {
+        self.danger_accept_invalid_certs = danger_accept_invalid_certs;
+        self
+    }
+
+    /// Builds a client with these settings.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidInput`] if the proxy URL or a certificate is invalid, or
+    /// the client cannot be built, e.g. when the TLS backend fails to initialize.
+    pub fn build(&self) -> Result<reqwest::Client> {
+        #[cfg_attr(target_arch = "wasm32", allow(unused_mut))]
+        let mut builder = reqwest::Client::builder();
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            if let Some(max_idle) = self.pool_max_idle_per_host {
+                builder = builder.pool_max_idle_per_host(max_idle);
+            }
+            if let Some(idle_timeout) = self.pool_idle_timeout {
+                builder = builder.pool_idle_timeout(idle_timeout);
+            }
+            if let Some(connect_timeout) = self.connect_timeout {
+                builder = builder.connect_timeout(connect_timeout);
+            }
+            if let Some(tcp_keepalive) = self.tcp_keepalive {
+                builder = builder.tcp_keepalive(tcp_keepalive);
+            }
+            if let Some(proxy) = &self.proxy {
+                let proxy = reqwest::Proxy::all(proxy)
+                    .map_err(|e| Error::InvalidInput(format!("Invalid proxy URL: {e}")))?;
+                builder = builder.proxy(proxy);
+            }
+            for pem in &self.root_certificates {
+                let certificate = reqwest::Certificate::from_pem(pem)
+                    .map_err(|e| Error::InvalidInput(format!("Invalid root certificate: {e}")))?;
+                builder = builder.add_root_certificate(certificate);
+            }
+            builder = builder.danger_accept_invalid_certs(self.danger_accept_invalid_certs);
         }
-    } else {
-        backoff
+        builder
+            .build()
+            .map_err(|e| Error::InvalidInput(format!("Failed to build HTTP client: {e}")))
     }
 }
 
-/// Shared retry logic for HTTP requests.
-///
-/// This function handles:
-/// - Exponential backoff with configurable limits
-/// - Jitter to prevent thundering herd
-/// - Retry-After header parsing
-/// - Retryable error detection (429, 502, 503, 504)
-/// - Request body reconstruction on each retry
-async fn retry_request<F, T>(
-    url: reqwest::Url,
-    method: reqwest::Method,
-    headers: reqwest::header::HeaderMap,
-    query_params: Vec<(&str, &str)>,
-    body_fn: F,
-    config: RetryConfig,
-) -> Result<T>
-where
-    F: Fn() -> reqwest::Body,
-    T: DeserializeOwned + std::fmt::Debug,
-{
-    let client = reqwest::Client::new();
-    let mut retry_count = 0;
-
-    loop {
-        // Reconstruct body for each attempt to avoid consumption issues
-        let body = body_fn();
-
-        let resp = client
-            .request(method.clone(), url.clone())
-            .headers(headers.clone())
-            .query(&query_params)
-            .body(body)
-            .send()
-            .await
-            .map_err(|e| {
-                // Check if error is retryable (timeout, connection error, etc.)
-                if e.is_timeout() || e.is_connect() {
-                    log::warn!(
-                        "Request failed with retryable error (attempt {}/{}): {}",
-                        retry_count + 1,
-                        config.max_retries + 1,
-                        e
-                    );
-                } else {
-                    log::error!("Request failed: {e}");
-                }
+/// A response from a provider API, as seen by [`Middleware::on_response`] or returned
+/// by [`Middleware::respond`].
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    /// The status code of the response
+    pub status: reqwest::StatusCode,
+    /// The headers of the response
+    pub headers: reqwest::header::HeaderMap,
+    /// The body of the response
+    pub body: Bytes,
+}
 
-                Error::ApiError {
-                    status_code: e.status(),
-                    details: e.to_string(),
-                }
-            })?;
+impl HttpResponse {
+    /// Creates a response with `status` and `body`, and no headers.
+    pub fn new(status: reqwest::StatusCode, body: impl Into<Bytes>) -> Self {
+        Self {
+            status,
+            headers: reqwest::header::HeaderMap::new(),
+            body: body.into(),
+        }
+    }
 
-        let status = resp.status();
-        let response_headers = resp.headers().clone();
-        let resp_text = resp.text().await.map_err(|e| Error::ApiError {
-            status_code: e.status(),
-            details: format!("Failed to read response: {e}"),
-        })?;
-
-        if status.is_success() {
-            log::debug!("Request succeeded on attempt {}", retry_count + 1);
-            return serde_json::from_str(&resp_text).map_err(|e| Error::ApiError {
-                status_code: Some(status),
-                details: format!("Failed to parse response: {e}"),
-            });
+    /// Converts the response into a `reqwest` response, as if it had been received.
+    fn into_reqwest(self) -> Result<reqwest::Response> {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let mut response = http::Response::new(self.body);
+            *response.status_mut() = self.status;
+            *response.headers_mut() = self.headers;
+            Ok(response.into())
         }
+        #[cfg(target_arch = "wasm32")]
+        {
+            Err(Error::Other(
+                "Responses returned by middleware are not supported on wasm32".to_string(),
+            ))
+        }
+    }
+}
+
+/// Intercepts the requests a provider sends and the responses it receives, e.g. to log
+/// them, inject headers, sign requests, or mock the provider in tests.
+///
+/// Register middleware on a provider with its builder's `middleware` method. Requests
+/// go through the middleware in the order it was registered, and responses and stream
+/// events in the reverse order. Every hook defaults to doing nothing.
+///
+/// # Example
+///
+/// ```
+/// use aisdk::core::client::Middleware;
+/// use aisdk::Result;
+///
+/// struct TenantHeader;
+///
+/// #[async_trait::async_trait]
+/// impl Middleware for TenantHeader {
+///     async fn on_request(&self, request: &mut reqwest::Request) -> Result<()> {
+///         request
+///             .headers_mut()
+///             .insert("x-tenant", "acme".parse().unwrap());
+///         Ok(())
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait Middleware: Send + Sync {
+    /// Inspects or modifies a request before it is sent. Runs before every attempt,
+    /// including retries and reconnections of streams, after the provider has added
+    /// its own headers.
+    ///
+    /// # Errors
+    ///
+    /// An error fails the request without sending it.
+    async fn on_request(&self, _request: &mut reqwest::Request) -> Result<()> {
+        Ok(())
+    }
 
-        // Check if error is retryable and we have retries left
-        if is_retryable_status(status) && retry_count < config.max_retries {
-            retry_count += 1;
+    /// Returns a response to use instead of sending `request`, e.g. to mock the
+    /// provider. The response to a streaming request is read as server-sent events.
+    async fn respond(&self, _request: &reqwest::Request) -> Option<HttpResponse> {
+        None
+    }
 
-            // Parse Retry-After header if present
-            let retry_after = parse_retry_after(&response_headers);
-            let wait_time = calculate_backoff(retry_count - 1, &config, retry_after);
+    /// Inspects or modifies the successful response to a non-streaming request before
+    /// it is parsed.
+    ///
+    /// # Errors
+    ///
+    /// An error fails the request.
+    async fn on_response(&self, _response: &mut HttpResponse) -> Result<()> {
+        Ok(())
+    }
 
-            log::warn!(
-                "Request failed with status {} (attempt {}/{}). Retrying after {:?}...",
-                status,
-                retry_count,
-                config.max_retries + 1,
-                wait_time
-            );
+    /// Inspects or modifies the data of a server-sent event of a streaming response
+    /// before it is parsed.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned from the stream in place of the event.
+    fn on_stream_event(&self, _data: &mut String) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// An ordered list of [`Middleware`].
+#[derive(Clone, Default)]
+pub struct MiddlewareChain(Vec<Arc<dyn Middleware>>);
+
+impl std::fmt::Debug for MiddlewareChain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "MiddlewareChain({} middleware)", self.0.len())
+    }
+}
+
+impl MiddlewareChain {
+    /// Creates an empty chain.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends `middleware` to the chain.
+    pub fn push(&mut self, middleware: impl Middleware + 'static) {
+        self.0.push(Arc::new(middleware));
+    }
+
+    /// Returns the number of middleware in the chain.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns true if the chain holds no middleware.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// The HTTP client, middleware and rate limiter a provider's settings configure.
+///
+/// Every provider's settings embed one, and its builder sets it with the
+/// `http_client`, `middleware` and `rate_limiter` methods.
+#[derive(Debug, Clone, Default)]
+pub struct TransportSettings {
+    /// The HTTP client requests are sent with. Defaults to the shared client
+    /// returned by [`http_client`].
+    pub http_client: Option<reqwest::Client>,
+
+    /// The middleware requests and responses go through, in order.
+    pub middleware: MiddlewareChain,
+
+    /// The rate limiter requests wait for, if any.
+    pub rate_limiter: Option<RateLimiter>,
+}
 
-            tokio::time::sleep(wait_time).await;
-            continue;
+impl TransportSettings {
+    /// Returns the HTTP client requests are sent with.
+    pub(crate) fn client(&self) -> reqwest::Client {
+        self.http_client.clone().unwrap_or_else(http_client)
+    }
+}
+
+/// Generates the `http_client`, `middleware` and `rate_limiter` methods of a provider
+/// builder whose `settings` embed a [`TransportSettings`] as `transport`.
+#[allow(unused_macros)]
+macro_rules! transport_builder_methods {
+    () => {
+        /// Sets the HTTP client requests made by this provider are sent with, e.g. one
+        /// built with [`HttpClientConfig`]($crate::core::client::HttpClientConfig) to go
+        /// through a proxy or trust a custom root certificate.
+        ///
+        /// # Parameters
+        ///
+        /// * `http_client` - The `reqwest` client to send requests with.
+        ///
+        /// # Returns
+        ///
+        /// The builder with the HTTP client set.
+        pub fn http_client(mut self, http_client: reqwest::Client) -> Self {
+            self.settings.transport.http_client = Some(http_client);
+            self
         }
 
-        // Non-retryable error or exhausted retries
-        if retry_count >= config.max_retries {
-            log::error!(
-                "Request failed after {} retries with status {}: {}",
-                retry_count + 1,
-                status,
-                resp_text
-            );
-        } else {
-            log::error!("Request failed with non-retryable status {status}: {resp_text}");
+        /// Adds a middleware to the end of the chain the requests and responses of this
+        /// provider go through, e.g. to log them or inject headers.
+        ///
+        /// # Parameters
+        ///
+        /// * `middleware` - The middleware to add.
+        ///
+        /// # Returns
+        ///
+        /// The builder with the middleware added.
+        pub fn middleware(
+            mut self,
+            middleware: impl $crate::core::client::Middleware + 'static,
+        ) -> Self {
+            self.settings.transport.middleware.push(middleware);
+            self
         }
 
-        return Err(Error::ApiError {
-            status_code: Some(status),
-            details: resp_text,
-        });
-    }
+        /// Sets the rate limiter the requests of this provider wait for, e.g. to stay
+        /// within the provider's requests and tokens per minute.
+        ///
+        /// # Parameters
+        ///
+        /// * `rate_limiter` - The rate limiter, which may be shared with other providers.
+        ///
+        /// # Returns
+        ///
+        /// The builder with the rate limiter set.
+        pub fn rate_limiter(mut self, rate_limiter: $crate::core::rate_limit::RateLimiter) -> Self {
+            self.settings.transport.rate_limiter = Some(rate_limiter);
+            self
+        }
+    };
 }
+#[allow(unused_imports)]
+pub(crate) use transport_builder_methods;
 
-#[allow(dead_code)]
-pub(crate) trait LanguageModelClient {
-    type Response: DeserializeOwned + std::fmt::Debug + Clone;
-    type StreamEvent: DeserializeOwned + std::fmt::Debug + Clone;
+/// The client, middleware and rate limiter the requests of a provider go through.
+#[derive(Debug, Clone)]
+pub(crate) struct Transport {
+    pub(crate) client: reqwest::Client,
+    middleware: MiddlewareChain,
+    rate_limiter: Option<RateLimiter>,
+}
 
-    fn path(&self) -> String;
-    fn method(&self) -> reqwest::Method;
-    fn query_params(&self) -> Vec<(&str, &str)>;
-    fn body(&self) -> reqwest::Body;
-    fn headers(&self) -> reqwest::header::HeaderMap;
+impl Transport {
+    pub(crate) fn new(settings: TransportSettings) -> Self {
+        Self {
+            client: settings.client(),
+            middleware: settings.middleware,
+            rate_limiter: settings.rate_limiter,
+        }
+    }
 
-    async fn send(&self, base_url: impl IntoUrl) -> Result<Self::Response> {
-        let url = join_url(base_url, &self.path())?;
+    /// Builds `request`, runs the middleware on it, and waits for the rate limiter.
+    async fn prepare(&self, request: reqwest::RequestBuilder) -> Result<reqwest::Request> {
+        let mut request = request
+            .build()
+            .map_err(|e| Error::InvalidInput(format!("Invalid request: {e}")))?;
+        for middleware in &self.middleware.0 {
+            middleware.on_request(&mut request).await?;
+        }
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.acquire_for(request.body()).await;
+        }
+        Ok(request)
+    }
 
-        // Serialize body once to avoid consumption issues on retries
-        let body_bytes = {
-            let body = self.body();
-            // Convert Body to bytes - this is the critical fix for retry body consumption
-            // We need to get the bytes from the body to be able to reconstruct it
-            match body.as_bytes() {
-                Some(bytes) => bytes.to_vec(),
-                None => {
-                    // If body doesn't have as_bytes (streaming body), we can't retry it
-                    // This should rarely happen for our use case (JSON bodies)
-                    log::warn!("Request body is not retryable (streaming body)");
-                    vec![]
-                }
+    /// Returns the response of the first middleware responding to `request`.
+    async fn respond(&self, request: &reqwest::Request) -> Option<HttpResponse> {
+        for middleware in &self.middleware.0 {
+            if let Some(response) = middleware.respond(request).await {
+                return Some(response);
             }
-        };
+        }
+        None
+    }
 
-        let method = self.method();
-        let headers = self.headers();
-        let query_params = self.query_params();
-        let config = RetryConfig::default();
+    /// Sends `request` through the middleware, returning the response before its body is
+    /// read.
+    ///
+    /// The outer error is a request that could not be built or was rejected by a
+    /// middleware, the inner one a failure to reach the server.
+    pub(crate) async fn send(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<std::result::Result<reqwest::Response, reqwest::Error>> {
+        let request = self.prepare(request).await?;
+        match self.respond(&request).await {
+            Some(response) => Ok(Ok(response.into_reqwest()?)),
+            None => Ok(assume_send(self.client.execute(request)).await),
+        }
+    }
 
-        retry_request(
-            url,
-            method,
-            headers,
-            query_params,
-            move || reqwest::Body::from(body_bytes.clone()),
-            config,
-        )
-        .await
+    /// Reads the body of a successful response and runs the middleware on it.
+    pub(crate) fn read(
+        &self,
+        response: reqwest::Response,
+    ) -> impl Future<Output = Result<HttpResponse>> + Send + '_ {
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = assume_send(response.bytes());
+        async move {
+            let body = body.await.map_err(|e| Error::ApiError {
+                status_code: Some(status),
+                details: format!("Failed to read response: {e}"),
+            })?;
+
+            let mut response = HttpResponse {
+                status,
+                headers,
+                body,
+            };
+            for middleware in self.middleware.0.iter().rev() {
+                middleware.on_response(&mut response).await?;
+            }
+            Ok(response)
+        }
+    }
+
+    /// Opens the server-sent events stream answering `request`, returning it with the
+    /// headers of the response. The stream is not reconnected by itself, and a failure
+    /// to connect or a rejected request is its first event.
+    // The error type is the one of the event source
+    #[allow(clippy::result_large_err)]
+    pub(crate) async fn open_events(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<(SseStream, reqwest::header::HeaderMap)> {
+        let mut request = self.prepare(request).await?;
+        let Some(response) = self.respond(&request).await else {
+            request
+                .headers_mut()
+                .entry(reqwest::header::ACCEPT)
+                .or_insert(reqwest::header::HeaderValue::from_static(
+                    "text/event-stream",
+                ));
+            return Ok(match assume_send(self.client.execute(request)).await {
+                Ok(response) => event_source(response),
+                Err(e) => (
+                    Box::pin(assume_send(futures::stream::iter([Err(
+                        reqwest_eventsource::Error::Transport(e),
+                    )]))),
+                    reqwest::header::HeaderMap::new(),
+                ),
+            });
+        };
+
+        let status = response.status;
+        let headers = response.headers.clone();
+        let response = response.into_reqwest()?;
+        if !status.is_success() {
+            let error = reqwest_eventsource::Error::InvalidStatusCode(status, response);
+            let events = futures::stream::iter([Err(error)]);
+            return Ok((Box::pin(assume_send(events)), headers));
+        }
+        let events = response
+            .bytes_stream()
+            .eventsource()
+            .map(|event| event.map(Event::Message).map_err(Into::into));
+        Ok((Box::pin(assume_send(events)), headers))
+    }
+
+    /// Runs the middleware on the data of a stream event.
+    pub(crate) fn stream_event(&self, data: &mut String) -> Result<()> {
+        for middleware in self.middleware.0.iter().rev() {
+            middleware.on_stream_event(data)?;
+        }
+        Ok(())
+    }
+}
+
+/// How far a provider stream got, used to resume it after its connection drops.
+#[derive(Debug, Clone)]
+pub(crate) struct StreamPosition<E> {
+    /// The id of the last SSE event received, if the server sends event ids.
+    pub(crate) last_event_id: Option<String>,
+    /// The first event received.
+    pub(crate) first_event: Option<E>,
+    /// The last event received.
+    pub(crate) last_event: Option<E>,
+}
+
+impl<E> Default for StreamPosition<E> {
+    fn default() -> Self {
+        Self {
+            last_event_id: None,
+            first_event: None,
+            last_event: None,
+        }
+    }
+}
+
+impl<E: Clone> StreamPosition<E> {
+    /// Records a received event.
+    fn observe(&mut self, event: &E) {
+        if self.first_event.is_none() {
+            self.first_event = Some(event.clone());
+        }
+        self.last_event = Some(event.clone());
+    }
+}
+
+/// Reads `response` as server-sent events the way `reqwest_eventsource` does, with an
+/// open event first and a [`StreamEnded`](reqwest_eventsource::Error::StreamEnded)
+/// error when the body ends, returning the events with the headers of the response.
+// The error type is the one of the event source
+#[allow(clippy::result_large_err)]
+fn event_source(response: reqwest::Response) -> (SseStream, reqwest::header::HeaderMap) {
+    let headers = response.headers().clone();
+    let content_type = headers
+        .get(reqwest::header::CONTENT_TYPE)
+        .cloned()
+        .unwrap_or(reqwest::header::HeaderValue::from_static(""));
+    let is_event_stream = content_type.to_str().is_ok_and(|content_type| {
+        content_type
+            .trim_start()
+            .to_ascii_lowercase()
+            .starts_with("text/event-stream")
+    });
+
+    let rejected = if response.status() != reqwest::StatusCode::OK {
+        let status = response.status();
+        reqwest_eventsource::Error::InvalidStatusCode(status, response)
+    } else if !is_event_stream {
+        reqwest_eventsource::Error::InvalidContentType(content_type, response)
+    } else {
+        let events = futures::stream::iter([Ok(Event::Open)])
+            .chain(
+                response
+                    .bytes_stream()
+                    .eventsource()
+                    .map(|event| event.map(Event::Message).map_err(Into::into)),
+            )
+            .chain(futures::stream::iter([Err(
+                reqwest_eventsource::Error::StreamEnded,
+            )]));
+        return (Box::pin(assume_send(events)), headers);
+    };
+    let events = futures::stream::iter([Err(rejected)]);
+    (Box::pin(assume_send(events)), headers)
+}
+
+/// Checks if a stream error is a dropped connection rather than a rejected request.
+fn is_dropped_stream(error: &reqwest_eventsource::Error) -> bool {
+    matches!(
+        error,
+        reqwest_eventsource::Error::Transport(_) | reqwest_eventsource::Error::StreamEnded
+    )
+}
+
+/// Headers carrying credentials, whose values are redacted when requests are logged.
+const SENSITIVE_HEADERS: [&str; 5] = [
+    "authorization",
+    "proxy-authorization",
+    "x-api-key",
+    "x-goog-api-key",
+    "api-key",
+];
+
+/// Formats request headers for logging, with the values of credential headers redacted.
+pub(crate) struct RedactedHeaders<'a>(pub(crate) &'a reqwest::header::HeaderMap);
+
+impl std::fmt::Debug for RedactedHeaders<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_map()
+            .entries(self.0.iter().map(|(name, value)| {
+                let value = if SENSITIVE_HEADERS.contains(&name.as_str()) {
+                    "<redacted>"
+                } else {
+                    value.to_str().unwrap_or("<binary>")
+                };
+                (name.as_str(), value)
+            }))
+            .finish()
+    }
+}
+
+/// Checks if a status code is retryable.
+pub(crate) fn is_retryable_status(status: reqwest::StatusCode) -> bool {
+    status == reqwest::StatusCode::TOO_MANY_REQUESTS
+        || status == reqwest::StatusCode::REQUEST_TIMEOUT
+        || (status.is_server_error() && status != reqwest::StatusCode::NOT_IMPLEMENTED)
+}
+
+/// Returns the error for a failed response of the given status, classifying it from
+/// the error codes of the provider's error body: the OpenAI `error.code`, the
+/// Anthropic `error.type` and the Google `error.status`. Responses that match no known
+/// error are returned as [`Error::ApiError`] with the whole body.
+pub(crate) fn api_error(
+    status: reqwest::StatusCode,
+    headers: &reqwest::header::HeaderMap,
+    body: String,
+) -> Error {
+    let error = serde_json::from_str::<serde_json::Value>(&body)
+        .ok()
+        .map(|value| value.get("error").cloned().unwrap_or(value));
+    let field = |name: &str| {
+        error
+            .as_ref()
+            .and_then(|error| error.get(name))
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or_default()
+            .to_string()
+    };
+    let codes = [field("code"), field("type"), field("status")];
+    let is = |known: &[&str]| codes.iter().any(|code| known.contains(&code.as_str()));
+
+    // Ollama and llama.cpp send the message as the error itself
+    let message = match error.as_ref().and_then(serde_json::Value::as_str) {
+        Some(message) => message.to_string(),
+        None => field("message"),
+    };
+    let lowercase = message.to_lowercase();
+    let details = if message.is_empty() {
+        body.clone()
+    } else {
+        message
+    };
+
+    if is(&["content_filter", "content_policy_violation"]) {
+        Error::ContentFiltered {
+            details,
+            status_code: Some(status),
+        }
+    } else if is(&["context_length_exceeded", "exceed_context_size_error"])
+        || lowercase.contains("prompt is too long")
+        || lowercase.contains("maximum context length")
+        || lowercase.contains("exceeds the maximum number of tokens")
+    {
+        Error::ContextLengthExceeded {
+            details,
+            status_code: Some(status),
+        }
+    } else if status == reqwest::StatusCode::TOO_MANY_REQUESTS
+        || is(&[
+            "rate_limit_exceeded",
+            "insufficient_quota",
+            "rate_limit_error",
+            "RESOURCE_EXHAUSTED",
+        ])
+    {
+        Error::RateLimited {
+            retry_after: parse_retry_after(headers),
+            details,
+            status_code: Some(status),
+            rate_limit: RateLimitInfo::from_headers(headers).map(Box::new),
+        }
+    } else if status.as_u16() == 529
+        || status == reqwest::StatusCode::SERVICE_UNAVAILABLE
+        || is(&["overloaded_error", "UNAVAILABLE"])
+    {
+        Error::Overloaded {
+            details,
+            status_code: Some(status),
+        }
+    } else if status == reqwest::StatusCode::UNAUTHORIZED
+        || is(&[
+            "invalid_api_key",
+            "authentication_error",
+            "permission_error",
+            "UNAUTHENTICATED",
+            "PERMISSION_DENIED",
+        ])
+    {
+        Error::AuthenticationFailed {
+            details,
+            status_code: Some(status),
+        }
+    } else if is(&["model_not_found", "not_found_error", "NOT_FOUND"])
+        || (status == reqwest::StatusCode::NOT_FOUND && lowercase.contains("model"))
+    {
+        Error::ModelNotFound {
+            details,
+            status_code: Some(status),
+        }
+    } else {
+        Error::ApiError {
+            status_code: Some(status),
+            details: body,
+        }
+    }
+}
+
+/// Returns the error for a failed response, read and classified with [`api_error`].
+pub(crate) fn response_error(response: reqwest::Response) -> impl Future<Output = Error> + Send {
+    let status = response.status();
+    let headers = response.headers().clone();
+    let body = assume_send(response.text());
+    async move {
+        let body = body
+            .await
+            .unwrap_or_else(|e| format!("<failed to read body: {e}>"));
+        log::error!("Request failed with status {status}: {body}");
+        api_error(status, &headers, body)
+    }
+}
+
+/// Returns the error for a failed event of a provider stream. Rejected responses are
+/// classified by their status and headers like [`api_error`].
+#[allow(dead_code)]
+pub(crate) fn sse_error(error: reqwest_eventsource::Error) -> Error {
+    match &error {
+        reqwest_eventsource::Error::InvalidStatusCode(status, response) => {
+            api_error(*status, response.headers(), error.to_string())
+        }
+        _ => Error::ApiError {
+            status_code: None,
+            details: error.to_string(),
+        },
+    }
+}
+
+/// Checks if a transport error is retryable.
+fn is_retryable_error(error: &reqwest::Error) -> bool {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        error.is_connect() || error.is_timeout()
+    }
+    // The fetch backend reports failures to connect as request errors
+    #[cfg(target_arch = "wasm32")]
+    {
+        error.is_request() || error.is_timeout()
+    }
+}
+
+/// Parses the Retry-After header to get the wait duration, preferring the
+/// millisecond `retry-after-ms` header OpenAI sends alongside it.
+fn parse_retry_after(headers: &reqwest::header::HeaderMap) -> Option<Duration> {
+    let retry_after_ms = headers
+        .get("retry-after-ms")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|ms| ms.trim().parse::<f64>().ok())
+        .filter(|ms| ms.is_finite())
+        .map(|ms| Duration::from_secs_f64(ms.max(0.0) / 1000.0));
+    if retry_after_ms.is_some() {
+        return retry_after_ms;
+    }
+
+    headers
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|s| {
+            // Try parsing as seconds (integer)
+            if let Ok(seconds) = s.parse::<u64>() {
+                return Some(Duration::from_secs(seconds));
+            }
+            None
+        })
+}
+
+/// Calculates the next wait duration with exponential backoff and optional jitter.
+fn calculate_backoff(
+    retry_count: u32,
+    config: &RetryPolicy,
+    retry_after: Option<Duration>,
+) -> Duration {
+    // If server provides Retry-After, respect it
+    if let Some(duration) = retry_after {
+        return duration.min(config.max_wait);
+    }
+
+    // Calculate exponential backoff: initial_wait * 2^retry_count
+    let backoff = config
+        .initial_wait
+        .saturating_mul(2_u32.saturating_pow(retry_count));
+    let backoff = backoff.min(config.max_wait);
+
+    // Add jitter to prevent thundering herd (±10% of backoff time)
+    if config.use_jitter {
+        let now = crate::core::runtime::SystemTime::now()
+            .duration_since(crate::core::runtime::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos();
+        let jitter_pct = ((now % 200) as i64 - 100) as f64 / 1000.0; // Range: -0.1 to +0.1
+        let jitter_ms = (backoff.as_millis() as f64 * jitter_pct) as i64;
+
+        if jitter_ms >= 0 {
+            backoff.saturating_add(Duration::from_millis(jitter_ms as u64))
+        } else {
+            backoff.saturating_sub(Duration::from_millis((-jitter_ms) as u64))
+        }
+    } else {
+        backoff
+    }
+}
+
+/// Sends a request with [`retry_send`] and parses the JSON response.
+async fn retry_request<F, T>(
+    transport: &Transport,
+    url: reqwest::Url,
+    method: reqwest::Method,
+    headers: reqwest::header::HeaderMap,
+    query_params: Vec<(&str, &str)>,
+    body_fn: F,
+    config: RetryPolicy,
+) -> Result<T>
+where
+    F: Fn() -> reqwest::Body,
+    T: DeserializeOwned + std::fmt::Debug,
+{
+    retry_request_with_headers(
+        transport,
+        url,
+        method,
+        headers,
+        query_params,
+        body_fn,
+        config,
+    )
+    .await
+    .map(|(response, _)| response)
+}
+
+/// Sends a request with [`retry_send`] and parses the JSON response, returned with the
+/// headers of the response.
+async fn retry_request_with_headers<F, T>(
+    transport: &Transport,
+    url: reqwest::Url,
+    method: reqwest::Method,
+    headers: reqwest::header::HeaderMap,
+    query_params: Vec<(&str, &str)>,
+    body_fn: F,
+    config: RetryPolicy,
+) -> Result<(T, reqwest::header::HeaderMap)>
+where
+    F: Fn() -> reqwest::Body,
+    T: DeserializeOwned + std::fmt::Debug,
+{
+    let resp = retry_send(
+        transport,
+        url,
+        method,
+        headers,
+        query_params,
+        body_fn,
+        config,
+    )
+    .await?;
+    let response = transport.read(resp).await?;
+
+    serde_json::from_slice(&response.body)
+        .map(|parsed| (parsed, response.headers))
+        .map_err(|e| Error::ApiError {
+            status_code: Some(response.status),
+            details: format!("Failed to parse response: {e}"),
+        })
+}
+
+/// Shared retry logic for HTTP requests, returning the successful response before its
+/// body is read.
+///
+/// This function handles:
+/// - Exponential backoff with configurable limits
+/// - Jitter to prevent thundering herd
+/// - Retry-After header parsing
+/// - Retryable error detection (429, 502, 503, 504)
+/// - Request body reconstruction on each retry
+async fn retry_send<F>(
+    transport: &Transport,
+    url: reqwest::Url,
+    method: reqwest::Method,
+    headers: reqwest::header::HeaderMap,
+    query_params: Vec<(&str, &str)>,
+    body_fn: F,
+    config: RetryPolicy,
+) -> Result<reqwest::Response>
+where
+    F: Fn() -> reqwest::Body,
+{
+    // A rejected response is held while its body is read, and is not `Send` on wasm32
+    assume_send(async move {
+        let started = Instant::now();
+        let mut retry_count = 0;
+
+        loop {
+            // Reconstruct body for each attempt to avoid consumption issues
+            let body = body_fn();
+            log::debug!(
+                "Sending {method} {url} (attempt {}) with headers {:?}",
+                retry_count + 1,
+                RedactedHeaders(&headers)
+            );
+
+            let request = transport
+                .client
+                .request(method.clone(), url.clone())
+                .headers(headers.clone())
+                .query(&query_params)
+                .body(body);
+            let resp = match transport.send(request).await? {
+                Ok(resp) => resp,
+                Err(e) => {
+                    // Retry timeouts and connection errors
+                    if is_retryable_error(&e)
+                        && let Some(wait_time) = config.next_wait(retry_count, started, None)
+                    {
+                        retry_count += 1;
+                        log::warn!(
+                            "Request failed with retryable error (attempt {}/{}): {}. Retrying after {:?}...",
+                            retry_count,
+                            config.max_retries + 1,
+                            e,
+                            wait_time
+                        );
+                        crate::core::runtime::sleep(wait_time).await;
+                        continue;
+                    }
+
+                    log::error!("Request failed: {e}");
+                    return Err(Error::ApiError {
+                        status_code: e.status(),
+                        details: e.to_string(),
+                    });
+                }
+            };
+
+            let status = resp.status();
+            if status.is_success() {
+                log::debug!("Request succeeded on attempt {}", retry_count + 1);
+                return Ok(resp);
+            }
+
+            let response_headers = resp.headers().clone();
+            let resp_text = assume_send(resp.text())
+                .await
+                .map_err(|e| Error::ApiError {
+                    status_code: e.status(),
+                    details: format!("Failed to read response: {e}"),
+                })?;
+
+            // Check if error is retryable and the policy allows another attempt
+            if is_retryable_status(status)
+                && let Some(wait_time) =
+                    config.next_wait(retry_count, started, parse_retry_after(&response_headers))
+            {
+                retry_count += 1;
+
+                log::warn!(
+                    "Request failed with status {} (attempt {}/{}). Retrying after {:?}...",
+                    status,
+                    retry_count,
+                    config.max_retries + 1,
+                    wait_time
+                );
+
+                crate::core::runtime::sleep(wait_time).await;
+                continue;
+            }
+
+            // Non-retryable error or exhausted retries
+            if is_retryable_status(status) {
+                log::error!(
+                    "Request failed after {} attempts with status {}: {}",
+                    retry_count + 1,
+                    status,
+                    resp_text
+                );
+            } else {
+                log::error!("Request failed with non-retryable status {status}: {resp_text}");
+            }
+
+            return Err(api_error(status, &response_headers, resp_text));
+        }
+    })
+    .await
+}
+
+#[allow(dead_code)]
+pub(crate) trait LanguageModelClient {
+    type Response: DeserializeOwned + std::fmt::Debug + Clone;
+    type StreamEvent: DeserializeOwned + std::fmt::Debug + Clone;
+
+    fn path(&self) -> String;
+    fn method(&self) -> reqwest::Method;
+    fn query_params(&self) -> Vec<(&str, &str)>;
+    fn body(&self) -> reqwest::Body;
+    fn headers(&self) -> reqwest::header::HeaderMap;
+
+    /// The retry policy applied to requests. Defaults to [`RetryPolicy::default`].
+    fn retry_policy(&self) -> RetryPolicy {
+        RetryPolicy::default()
+    }
+
+    /// The HTTP client, middleware and rate limiter requests go through. Defaults to
+    /// the shared [`http_client`] without middleware or a rate limiter.
+    fn transport(&self) -> TransportSettings {
+        TransportSettings::default()
+    }
+
+    async fn send(&self, base_url: impl IntoUrl) -> Result<Self::Response> {
+        self.send_with_rate_limit(base_url)
+            .await
+            .map(|(response, _)| response)
+    }
+
+    /// Sends the request like [`send`](Self::send), returning the response with the
+    /// rate limits reported in its headers.
+    async fn send_with_rate_limit(
+        &self,
+        base_url: impl IntoUrl,
+    ) -> Result<(Self::Response, Option<RateLimitInfo>)> {
+        let url = join_url(base_url, &self.path())?;
+
+        // Serialize body once to avoid consumption issues on retries
+        let body_bytes = {
+            let body = self.body();
+            // Convert Body to bytes - this is the critical fix for retry body consumption
+            // We need to get the bytes from the body to be able to reconstruct it
+            match body.as_bytes() {
+                Some(bytes) => bytes.to_vec(),
+                None => {
+                    // If body doesn't have as_bytes (streaming body), we can't retry it
+                    // This should rarely happen for our use case (JSON bodies)
+                    log::warn!("Request body is not retryable (streaming body)");
+                    vec![]
+                }
+            }
+        };
+
+        let method = self.method();
+        let headers = self.headers();
+        let query_params = self.query_params();
+        let config = self.retry_policy();
+
+        retry_request_with_headers(
+            &Transport::new(self.transport()),
+            url,
+            method,
+            headers,
+            query_params,
+            move || reqwest::Body::from(body_bytes.clone()),
+            config,
+        )
+        .await
+        .map(|(response, headers)| (response, RateLimitInfo::from_headers(&headers)))
+    }
+
+    /// Parses an SSE event into a StreamEvent ( ProviderStreamEvent )
+    fn parse_stream_sse(
+        event: std::result::Result<Event, reqwest_eventsource::Error>,
+    ) -> Result<Self::StreamEvent>;
+
+    /// Returns true to mark the stream as ended
+    fn end_stream(event: &Self::StreamEvent) -> bool;
+
+    /// Returns the request that resumes a stream to `url` whose connection dropped at
+    /// `position`, or `None` if it cannot be resumed without generating the response
+    /// again.
+    ///
+    /// Defaults to repeating the request with a `Last-Event-ID` header once the server
+    /// has sent SSE event ids.
+    fn resume_request(
+        &self,
+        client: &reqwest::Client,
+        url: &reqwest::Url,
+        position: &StreamPosition<Self::StreamEvent>,
+    ) -> Option<reqwest::RequestBuilder> {
+        let last_event_id = position.last_event_id.as_deref()?;
+        Some(
+            client
+                .request(self.method(), url.clone())
+                .headers(self.headers())
+                .header("Last-Event-ID", last_event_id)
+                .query(&self.query_params())
+                .body(self.body()),
+        )
+    }
+
+    async fn send_and_stream(
+        &self,
+        base_url: impl IntoUrl,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<Self::StreamEvent>> + Send>>>
+    where
+        Self::StreamEvent: Send + 'static,
+        Self: Clone + Send + Sync + 'static,
+    {
+        self.send_and_stream_with_rate_limit(base_url)
+            .await
+            .map(|(stream, _)| stream)
+    }
+
+    /// Opens the stream like [`send_and_stream`](Self::send_and_stream), returning it
+    /// with the rate limits reported in the headers of the response that opened it.
+    async fn send_and_stream_with_rate_limit(
+        &self,
+        base_url: impl IntoUrl,
+    ) -> Result<(
+        Pin<Box<dyn Stream<Item = Result<Self::StreamEvent>> + Send>>,
+        Option<RateLimitInfo>,
+    )>
+    where
+        Self::StreamEvent: Send + 'static,
+        Self: Clone + Send + Sync + 'static,
+    {
+        let transport = Transport::new(self.transport());
+
+        let url = join_url(base_url, &self.path())?;
+        let policy = self.retry_policy();
+        let started = Instant::now();
+        let mut retry_count = 0;
+
+        // Establish the event source stream, retrying failures to connect according to
+        // the retry policy. Errors after the stream has opened are surfaced as events.
+        // The first event may hold a rejected response, which is not `Send` on wasm32
+        let (events_stream, rate_limit) = assume_send(async {
+            loop {
+                let headers = self.headers();
+                log::debug!(
+                    "Streaming {} {url} (attempt {}) with headers {:?}",
+                    self.method(),
+                    retry_count + 1,
+                    RedactedHeaders(&headers)
+                );
+                // Reconnects are driven by the retry policy rather than the event source
+                let request = transport
+                    .client
+                    .request(self.method(), url.clone())
+                    .headers(headers)
+                    .query(&self.query_params())
+                    .body(self.body());
+                let (mut events, response_headers) = transport.open_events(request).await?;
+
+                let first = events.next().await;
+                let retry_after = match &first {
+                    Some(Err(reqwest_eventsource::Error::InvalidStatusCode(status, response)))
+                        if is_retryable_status(*status) =>
+                    {
+                        Some(parse_retry_after(response.headers()))
+                    }
+                    Some(Err(reqwest_eventsource::Error::Transport(e)))
+                        if is_retryable_error(e) =>
+                    {
+                        Some(None)
+                    }
+                    _ => None,
+                };
+
+                if let Some(retry_after) = retry_after
+                    && let Some(wait_time) = policy.next_wait(retry_count, started, retry_after)
+                {
+                    retry_count += 1;
+                    log::warn!(
+                        "Stream request failed (attempt {}/{}). Retrying after {:?}...",
+                        retry_count,
+                        policy.max_retries + 1,
+                        wait_time
+                    );
+                    crate::core::runtime::sleep(wait_time).await;
+                    continue;
+                }
+
+                if let Some(Err(reqwest_eventsource::Error::InvalidStatusCode(_, response))) = first
+                {
+                    return Err(response_error(response).await);
+                }
+
+                break Ok((
+                    assume_send(futures::stream::iter(first)).chain(events),
+                    RateLimitInfo::from_headers(&response_headers),
+                ));
+            }
+        })
+        .await?;
+
+        // Map events to deserialized StreamEvent ( ProviderStreamEvent ), reconnecting
+        // dropped connections according to the stream recovery policy
+        let state = RecoveringStream {
+            events: Box::pin(events_stream),
+            position: StreamPosition::default(),
+            reconnects: 0,
+            transport,
+            url,
+            provider: self.clone(),
+            recovery: policy.stream_recovery,
+        };
+        let mapped_stream = futures::stream::unfold(state, |mut state| async move {
+            let event = state.next().await?;
+            Some((event, state))
+        });
+
+        Ok((end_stream_on(mapped_stream, Self::end_stream), rate_limit))
+    }
+}
+
+/// The raw SSE events of a provider stream.
+type SseStream = Pin<
+    Box<dyn Stream<Item = std::result::Result<Event, reqwest_eventsource::Error>> + Send + 'static>,
+>;
+
+/// A provider stream that resumes its connection when it drops mid-generation.
+struct RecoveringStream<C: LanguageModelClient> {
+    events: SseStream,
+    position: StreamPosition<C::StreamEvent>,
+    reconnects: u32,
+    transport: Transport,
+    url: reqwest::Url,
+    provider: C,
+    recovery: StreamRecovery,
+}
+
+impl<C: LanguageModelClient> RecoveringStream<C> {
+    /// Returns the next parsed event, reconnecting first if the connection dropped.
+    async fn next(&mut self) -> Option<Result<C::StreamEvent>> {
+        loop {
+            // The error is parsed before reconnecting, as it is not `Send` on wasm32
+            let (details, event) = match self.events.next().await? {
+                Ok(Event::Message(mut message)) => {
+                    if let Err(e) = self.transport.stream_event(&mut message.data) {
+                        return Some(Err(e));
+                    }
+                    if !message.id.is_empty() {
+                        self.position.last_event_id = Some(message.id.clone());
+                    }
+                    record_raw_event(&message.data);
+                    let event = C::parse_stream_sse(Ok(Event::Message(message)));
+                    if let Ok(event) = &event
+                        && self.recovery.max_reconnects > 0
+                    {
+                        self.position.observe(event);
+                    }
+                    return Some(event);
+                }
+                Err(e) if is_dropped_stream(&e) => (e.to_string(), C::parse_stream_sse(Err(e))),
+                event => return Some(C::parse_stream_sse(event)),
+            };
+            if !self.reconnect().await {
+                return Some(event);
+            }
+            log::warn!(
+                "Stream connection dropped: {details}. Resumed (reconnect {}/{})",
+                self.reconnects,
+                self.recovery.max_reconnects
+            );
+        }
+    }
+
+    /// Opens a connection that resumes the stream, returning `false` if the stream
+    /// cannot be resumed or the policy allows no more reconnects.
+    async fn reconnect(&mut self) -> bool {
+        if self.reconnects >= self.recovery.max_reconnects {
+            return false;
+        }
+        let Some(request) =
+            self.provider
+                .resume_request(&self.transport.client, &self.url, &self.position)
+        else {
+            return false;
+        };
+
+        self.reconnects += 1;
+        crate::core::runtime::sleep(self.recovery.wait).await;
+        let Ok((events, _)) = self.transport.open_events(request).await else {
+            return false;
+        };
+        self.events = Box::pin(events);
+        true
+    }
+}
+
+/// Ends `stream` after the first event `end_stream` returns true for, or after the first
+/// error.
+fn end_stream_on<S, T>(
+    stream: S,
+    end_stream: fn(&T) -> bool,
+) -> Pin<Box<dyn Stream<Item = Result<T>> + Send>>
+where
+    S: Stream<Item = Result<T>> + Send + 'static,
+    T: Send + 'static,
+{
+    // State that indicates if the stream has ended
+    let ended = std::sync::Arc::new(std::sync::Mutex::new(false));
+
+    // Scan to end or mark the stream as ended
+    let stream = stream.scan(ended, move |ended, res| {
+        let mut ended = ended.lock().unwrap();
+
+        if *ended {
+            return futures::future::ready(None); // Stop the stream after end event
+        }
+
+        *ended = res.as_ref().map_or(true, end_stream); // Mark the stream as ended on api error or end event
+
+        futures::future::ready(Some(res)) // Emit the event
+    });
+
+    Box::pin(stream)
+}
+/// Trait for embedding model clients to interact with embedding APIs.
+#[allow(dead_code)]
+pub(crate) trait EmbeddingClient {
+    type Response: DeserializeOwned + std::fmt::Debug + Clone;
+
+    fn path(&self) -> String;
+    fn method(&self) -> reqwest::Method;
+    fn query_params(&self) -> Vec<(&str, &str)>;
+    fn body(&self) -> reqwest::Body;
+    fn headers(&self) -> reqwest::header::HeaderMap;
+
+    /// The retry policy applied to requests. Defaults to [`RetryPolicy::default`].
+    fn retry_policy(&self) -> RetryPolicy {
+        RetryPolicy::default()
+    }
+
+    /// The HTTP client, middleware and rate limiter requests go through. Defaults to
+    /// the shared [`http_client`] without middleware or a rate limiter.
+    fn transport(&self) -> TransportSettings {
+        TransportSettings::default()
+    }
+
+    async fn send(&self, base_url: impl IntoUrl) -> Result<Self::Response> {
+        let base_url = base_url
+            .into_url()
+            .map_err(|_| Error::InvalidInput("Invalid base URL".into()))?;
+
+        let url = join_url(base_url, &self.path())?;
+
+        // Serialize body once to avoid consumption issues on retries
+        let body_bytes = {
+            let body = self.body();
+            // Convert Body to bytes - this is the critical fix for retry body consumption
+            match body.as_bytes() {
+                Some(bytes) => bytes.to_vec(),
+                None => {
+                    // If body doesn't have as_bytes (streaming body), we can't retry it
+                    log::warn!("Request body is not retryable (streaming body)");
+                    vec![]
+                }
+            }
+        };
+
+        let method = self.method();
+        let headers = self.headers();
+        let query_params = self.query_params();
+        let config = self.retry_policy();
+
+        retry_request(
+            &Transport::new(self.transport()),
+            url,
+            method,
+            headers,
+            query_params,
+            move || reqwest::Body::from(body_bytes.clone()),
+            config,
+        )
+        .await
+    }
+}
+
+/// Trait for rerank model clients to interact with rerank APIs.
+#[allow(dead_code)]
+pub(crate) trait RerankClient {
+    type Response: DeserializeOwned + std::fmt::Debug + Clone;
+
+    fn path(&self) -> String;
+    fn method(&self) -> reqwest::Method;
+    fn query_params(&self) -> Vec<(&str, &str)>;
+    fn body(&self) -> reqwest::Body;
+    fn headers(&self) -> reqwest::header::HeaderMap;
+
+    /// The retry policy applied to requests. Defaults to [`RetryPolicy::default`].
+    fn retry_policy(&self) -> RetryPolicy {
+        RetryPolicy::default()
+    }
+
+    /// The HTTP client, middleware and rate limiter requests go through. Defaults to
+    /// the shared [`http_client`] without middleware or a rate limiter.
+    fn transport(&self) -> TransportSettings {
+        TransportSettings::default()
+    }
+
+    async fn send(&self, base_url: impl IntoUrl) -> Result<Self::Response> {
+        let url = join_url(base_url, &self.path())?;
+
+        // Serialize body once so it can be resent on retries
+        let body_bytes = self
+            .body()
+            .as_bytes()
+            .map(<[u8]>::to_vec)
+            .unwrap_or_default();
+
+        retry_request(
+            &Transport::new(self.transport()),
+            url,
+            self.method(),
+            self.headers(),
+            self.query_params(),
+            move || reqwest::Body::from(body_bytes.clone()),
+            self.retry_policy(),
+        )
+        .await
+    }
+}
+
+/// Trait for moderation model clients to interact with content moderation APIs.
+#[allow(dead_code)]
+pub(crate) trait ModerationClient {
+    type Response: DeserializeOwned + std::fmt::Debug + Clone;
+
+    fn path(&self) -> String;
+    fn method(&self) -> reqwest::Method;
+    fn query_params(&self) -> Vec<(&str, &str)>;
+    fn body(&self) -> reqwest::Body;
+    fn headers(&self) -> reqwest::header::HeaderMap;
+
+    /// The retry policy applied to requests. Defaults to [`RetryPolicy::default`].
+    fn retry_policy(&self) -> RetryPolicy {
+        RetryPolicy::default()
+    }
+
+    /// The HTTP client, middleware and rate limiter requests go through. Defaults to
+    /// the shared [`http_client`] without middleware or a rate limiter.
+    fn transport(&self) -> TransportSettings {
+        TransportSettings::default()
+    }
+
+    async fn send(&self, base_url: impl IntoUrl) -> Result<Self::Response> {
+        let url = join_url(base_url, &self.path())?;
+
+        // Serialize body once so it can be resent on retries
+        let body_bytes = self
+            .body()
+            .as_bytes()
+            .map(<[u8]>::to_vec)
+            .unwrap_or_default();
+
+        retry_request(
+            &Transport::new(self.transport()),
+            url,
+            self.method(),
+            self.headers(),
+            self.query_params(),
+            move || reqwest::Body::from(body_bytes.clone()),
+            self.retry_policy(),
+        )
+        .await
+    }
+}
+
+/// Trait for language model clients to interact with token counting APIs.
+#[allow(dead_code)]
+pub(crate) trait TokenCountClient {
+    type Response: DeserializeOwned + std::fmt::Debug + Clone;
+
+    fn path(&self) -> String;
+    fn method(&self) -> reqwest::Method;
+    fn query_params(&self) -> Vec<(&str, &str)>;
+    fn body(&self) -> reqwest::Body;
+    fn headers(&self) -> reqwest::header::HeaderMap;
+
+    /// The retry policy applied to requests. Defaults to [`RetryPolicy::default`].
+    fn retry_policy(&self) -> RetryPolicy {
+        RetryPolicy::default()
+    }
+
+    /// The HTTP client, middleware and rate limiter requests go through. Defaults to
+    /// the shared [`http_client`] without middleware or a rate limiter.
+    fn transport(&self) -> TransportSettings {
+        TransportSettings::default()
+    }
+
+    async fn send(&self, base_url: impl IntoUrl) -> Result<Self::Response> {
+        let url = join_url(base_url, &self.path())?;
+
+        // Serialize body once so it can be resent on retries
+        let body_bytes = self
+            .body()
+            .as_bytes()
+            .map(<[u8]>::to_vec)
+            .unwrap_or_default();
+
+        retry_request(
+            &Transport::new(self.transport()),
+            url,
+            self.method(),
+            self.headers(),
+            self.query_params(),
+            move || reqwest::Body::from(body_bytes.clone()),
+            self.retry_policy(),
+        )
+        .await
+    }
+}
+
+/// Trait for provider clients to list the models of their API, following pages.
+#[allow(dead_code)]
+pub(crate) trait ModelListClient {
+    type Response: DeserializeOwned + std::fmt::Debug + Clone;
+
+    fn path(&self) -> String;
+    fn query_params(&self) -> Vec<(&str, &str)>;
+    fn headers(&self) -> reqwest::header::HeaderMap;
+
+    /// The retry policy applied to requests. Defaults to [`RetryPolicy::default`].
+    fn retry_policy(&self) -> RetryPolicy {
+        RetryPolicy::default()
+    }
+
+    /// The HTTP client, middleware and rate limiter requests go through. Defaults to
+    /// the shared [`http_client`] without middleware or a rate limiter.
+    fn transport(&self) -> TransportSettings {
+        TransportSettings::default()
+    }
+
+    /// Returns the query parameter and value requesting the page after `response`, or
+    /// `None` on the last page. Defaults to a single page.
+    fn next_page(_response: &Self::Response) -> Option<(&'static str, String)> {
+        None
+    }
+
+    /// Converts a page of models into descriptors.
+    fn descriptors(response: Self::Response) -> Vec<ModelDescriptor>;
+
+    async fn list(&self, base_url: impl IntoUrl) -> Result<Vec<ModelDescriptor>> {
+        let url = join_url(base_url, &self.path())?;
+        let transport = Transport::new(self.transport());
+
+        let mut models = Vec::new();
+        let mut page: Option<(&str, String)> = None;
+        loop {
+            let mut query_params = self.query_params();
+            if let Some((key, value)) = &page {
+                query_params.push((key, value));
+            }
+            let response: Self::Response = retry_request(
+                &transport,
+                url.clone(),
+                reqwest::Method::GET,
+                self.headers(),
+                query_params,
+                || reqwest::Body::from(Vec::new()),
+                self.retry_policy(),
+            )
+            .await?;
+
+            page = Self::next_page(&response);
+            models.extend(Self::descriptors(response));
+            if page.is_none() {
+                return Ok(models);
+            }
+        }
+    }
+}
+
+/// Trait for image model clients to interact with image generation APIs, which may
+/// respond with the encoded image rather than JSON.
+#[allow(dead_code)]
+pub(crate) trait ImageClient {
+    fn path(&self) -> String;
+    fn method(&self) -> reqwest::Method;
+    fn query_params(&self) -> Vec<(&str, &str)>;
+    fn body(&self) -> reqwest::Body;
+    fn headers(&self) -> reqwest::header::HeaderMap;
+
+    /// The retry policy applied to requests. Defaults to [`RetryPolicy::default`].
+    fn retry_policy(&self) -> RetryPolicy {
+        RetryPolicy::default()
+    }
+
+    /// The HTTP client, middleware and rate limiter requests go through. Defaults to
+    /// the shared [`http_client`] without middleware or a rate limiter.
+    fn transport(&self) -> TransportSettings {
+        TransportSettings::default()
+    }
+
+    /// Sends the request and returns the response body with the response headers,
+    /// which carry the metadata of binary responses.
+    async fn send(&self, base_url: impl IntoUrl) -> Result<(Vec<u8>, reqwest::header::HeaderMap)> {
+        let url = join_url(base_url, &self.path())?;
+
+        // Serialize body once so it can be resent on retries
+        let body_bytes = self
+            .body()
+            .as_bytes()
+            .map(<[u8]>::to_vec)
+            .unwrap_or_default();
+
+        let transport = Transport::new(self.transport());
+        let resp = retry_send(
+            &transport,
+            url,
+            self.method(),
+            self.headers(),
+            self.query_params(),
+            move || reqwest::Body::from(body_bytes.clone()),
+            self.retry_policy(),
+        )
+        .await?;
+        let response = transport.read(resp).await?;
+
+        Ok((response.body.to_vec(), response.headers))
+    }
+}
+
+/// Trait for speech model clients to interact with text-to-speech APIs, which respond
+/// with audio rather than JSON.
+#[allow(dead_code)]
+pub(crate) trait SpeechClient {
+    fn path(&self) -> String;
+    fn method(&self) -> reqwest::Method;
+    fn query_params(&self) -> Vec<(&str, &str)>;
+    fn body(&self) -> reqwest::Body;
+    fn headers(&self) -> reqwest::header::HeaderMap;
+
+    /// The retry policy applied to requests. Defaults to [`RetryPolicy::default`].
+    fn retry_policy(&self) -> RetryPolicy {
+        RetryPolicy::default()
+    }
+
+    /// The HTTP client, middleware and rate limiter requests go through. Defaults to
+    /// the shared [`http_client`] without middleware or a rate limiter.
+    fn transport(&self) -> TransportSettings {
+        TransportSettings::default()
+    }
+
+    /// Sends the request and returns the audio, with its media type if the server
+    /// reported one.
+    async fn send(&self, base_url: impl IntoUrl) -> Result<(Vec<u8>, Option<String>)> {
+        let resp = self.open(base_url).await?;
+        let response = Transport::new(self.transport()).read(resp).await?;
+        let media_type = response
+            .headers
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+
+        Ok((response.body.to_vec(), media_type))
+    }
+
+    /// Sends the request and streams the audio as it is received.
+    async fn send_and_stream(
+        &self,
+        base_url: impl IntoUrl,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<Vec<u8>>> + Send>>> {
+        let resp = self.open(base_url).await?;
+        let status = resp.status();
+
+        Ok(Box::pin(assume_send(resp.bytes_stream()).map(
+            move |chunk| {
+                chunk
+                    .map(|chunk| chunk.to_vec())
+                    .map_err(|e| Error::ApiError {
+                        status_code: Some(status),
+                        details: format!("Failed to read audio stream: {e}"),
+                    })
+            },
+        )))
+    }
+
+    /// Sends the request, retrying until the server accepts it, and returns the
+    /// response before its body is read.
+    async fn open(&self, base_url: impl IntoUrl) -> Result<reqwest::Response> {
+        let url = join_url(base_url, &self.path())?;
+
+        // Serialize body once so it can be resent on retries
+        let body_bytes = self
+            .body()
+            .as_bytes()
+            .map(<[u8]>::to_vec)
+            .unwrap_or_default();
+
+        retry_send(
+            &Transport::new(self.transport()),
+            url,
+            self.method(),
+            self.headers(),
+            self.query_params(),
+            move || reqwest::Body::from(body_bytes.clone()),
+            self.retry_policy(),
+        )
+        .await
+    }
+}
+
+/// Trait for transcription model clients to interact with speech-to-text APIs.
+#[allow(dead_code)]
+pub(crate) trait TranscriptionClient {
+    type Response: DeserializeOwned + std::fmt::Debug + Clone;
+
+    fn path(&self) -> String;
+    fn method(&self) -> reqwest::Method;
+    fn query_params(&self) -> Vec<(&str, &str)>;
+    fn body(&self) -> reqwest::Body;
+    fn headers(&self) -> reqwest::header::HeaderMap;
+
+    /// The retry policy applied to requests. Defaults to [`RetryPolicy::default`].
+    fn retry_policy(&self) -> RetryPolicy {
+        RetryPolicy::default()
+    }
+
+    /// The HTTP client, middleware and rate limiter requests go through. Defaults to
+    /// the shared [`http_client`] without middleware or a rate limiter.
+    fn transport(&self) -> TransportSettings {
+        TransportSettings::default()
+    }
+
+    async fn send(&self, base_url: impl IntoUrl) -> Result<Self::Response> {
+        let url = join_url(base_url, &self.path())?;
+
+        // Serialize body once so it can be resent on retries
+        let body_bytes = self
+            .body()
+            .as_bytes()
+            .map(<[u8]>::to_vec)
+            .unwrap_or_default();
+
+        retry_request(
+            &Transport::new(self.transport()),
+            url,
+            self.method(),
+            self.headers(),
+            self.query_params(),
+            move || reqwest::Body::from(body_bytes.clone()),
+            self.retry_policy(),
+        )
+        .await
+    }
+}
+
+/// Trait for video model clients, which submit a generation job, poll its status, and
+/// download the finished video.
+#[allow(dead_code)]
+pub(crate) trait VideoClient {
+    type Job: DeserializeOwned + std::fmt::Debug + Clone;
+
+    fn path(&self) -> String;
+    fn method(&self) -> reqwest::Method;
+    fn query_params(&self) -> Vec<(&str, &str)>;
+    fn body(&self) -> reqwest::Body;
+    fn headers(&self) -> reqwest::header::HeaderMap;
+
+    /// The retry policy applied to requests. Defaults to [`RetryPolicy::default`].
+    fn retry_policy(&self) -> RetryPolicy {
+        RetryPolicy::default()
+    }
+
+    /// The HTTP client, middleware and rate limiter requests go through. Defaults to
+    /// the shared [`http_client`] without middleware or a rate limiter.
+    fn transport(&self) -> TransportSettings {
+        TransportSettings::default()
+    }
+
+    /// The headers sent when polling a job or downloading a video, which carry the
+    /// credentials but no request body metadata.
+    fn auth_headers(&self) -> reqwest::header::HeaderMap;
+
+    /// Submits the generation job and returns it in its initial state.
+    async fn submit(&self, base_url: impl IntoUrl) -> Result<Self::Job> {
+        let url = join_url(base_url, &self.path())?;
+
+        // Serialize body once so it can be resent on retries
+        let body_bytes = self
+            .body()
+            .as_bytes()
+            .map(<[u8]>::to_vec)
+            .unwrap_or_default();
+
+        retry_request(
+            &Transport::new(self.transport()),
+            url,
+            self.method(),
+            self.headers(),
+            self.query_params(),
+            move || reqwest::Body::from(body_bytes.clone()),
+            self.retry_policy(),
+        )
+        .await
+    }
+
+    /// Fetches the state of a job from its status URL.
+    async fn fetch_job(&self, url: reqwest::Url) -> Result<Self::Job> {
+        retry_request(
+            &Transport::new(self.transport()),
+            url,
+            reqwest::Method::GET,
+            self.auth_headers(),
+            Vec::new(),
+            reqwest::Body::default,
+            self.retry_policy(),
+        )
+        .await
+    }
+
+    /// Downloads a generated video.
+    async fn download(&self, url: reqwest::Url) -> Result<Vec<u8>> {
+        let transport = Transport::new(self.transport());
+        let resp = retry_send(
+            &transport,
+            url,
+            reqwest::Method::GET,
+            self.auth_headers(),
+            Vec::new(),
+            reqwest::Body::default,
+            self.retry_policy(),
+        )
+        .await?;
+        let response = transport.read(resp).await?;
+
+        Ok(response.body.to_vec())
+    }
+}
+
+/// Trait for clients of APIs that run requests as asynchronous jobs, such as Replicate
+/// predictions, instead of answering them directly.
+///
+/// A job is created with a single request. Its status is then polled until it settles,
+/// or its output is read as it is produced from a separate server-sent events stream.
+#[allow(dead_code)]
+pub(crate) trait PollingClient {
+    type Job: DeserializeOwned + std::fmt::Debug + Clone;
+    type StreamEvent: DeserializeOwned + std::fmt::Debug + Clone;
+
+    fn path(&self) -> String;
+    fn method(&self) -> reqwest::Method;
+    fn query_params(&self) -> Vec<(&str, &str)>;
+    fn body(&self) -> reqwest::Body;
+    fn headers(&self) -> reqwest::header::HeaderMap;
+
+    /// The retry policy applied to requests. Defaults to [`RetryPolicy::default`].
+    fn retry_policy(&self) -> RetryPolicy {
+        RetryPolicy::default()
+    }
+
+    /// The HTTP client, middleware and rate limiter requests go through. Defaults to
+    /// the shared [`http_client`] without middleware or a rate limiter.
+    fn transport(&self) -> TransportSettings {
+        TransportSettings::default()
+    }
+
+    /// The time waited between two polls of a pending job. Defaults to one second.
+    fn poll_interval(&self) -> Duration {
+        Duration::from_secs(1)
+    }
+
+    /// Returns the URL the status of `job` is polled from.
+    fn poll_url(job: &Self::Job) -> Option<String>;
+
+    /// Returns the URL the output of `job` is streamed from, if it was created for
+    /// streaming.
+    fn stream_url(job: &Self::Job) -> Option<String>;
+
+    /// Returns true once `job` has settled, whether it succeeded or not.
+    fn is_settled(job: &Self::Job) -> bool;
+
+    /// Creates a job and returns it in its initial state.
+    async fn create(&self, base_url: impl IntoUrl) -> Result<Self::Job> {
+        let url = join_url(base_url, &self.path())?;
+
+        // Serialize body once so it can be resent on retries
+        let body_bytes = self
+            .body()
+            .as_bytes()
+            .map(<[u8]>::to_vec)
+            .unwrap_or_default();
+
+        retry_request(
+            &Transport::new(self.transport()),
+            url,
+            self.method(),
+            self.headers(),
+            self.query_params(),
+            move || reqwest::Body::from(body_bytes.clone()),
+            self.retry_policy(),
+        )
+        .await
+    }
+
+    /// Creates a job and polls it until it settles.
+    async fn send_and_poll(&self, base_url: impl IntoUrl) -> Result<Self::Job> {
+        let mut job = self.create(base_url).await?;
+
+        while !Self::is_settled(&job) {
+            let url = Self::poll_url(&job)
+                .ok_or_else(|| Error::Other(format!("Pending job has no status URL: {job:?}")))?;
+            let url = reqwest::Url::parse(&url)
+                .map_err(|_| Error::InvalidInput(format!("Invalid job status URL: {url}")))?;
+
+            crate::core::runtime::sleep(self.poll_interval()).await;
+            job = retry_request(
+                &Transport::new(self.transport()),
+                url,
+                reqwest::Method::GET,
+                self.headers(),
+                Vec::new(),
+                reqwest::Body::default,
+                self.retry_policy(),
+            )
+            .await?;
+        }
+
+        Ok(job)
     }
 
     /// Parses an SSE event into a StreamEvent ( ProviderStreamEvent )
@@ -258,6 +2160,7 @@ pub(crate) trait LanguageModelClient {
     /// Returns true to mark the stream as ended
     fn end_stream(event: &Self::StreamEvent) -> bool;
 
+    /// Creates a job and streams its output.
     async fn send_and_stream(
         &self,
         base_url: impl IntoUrl,
@@ -266,111 +2169,92 @@ pub(crate) trait LanguageModelClient {
         Self::StreamEvent: Send + 'static,
         Self: Sync,
     {
-        let client = reqwest::Client::new();
-
-        let url = join_url(base_url, &self.path())?;
-
-        // Establish the event source stream directly
-        // Note: Status code errors (including 429) will be surfaced as stream events
-        // and should be handled by retry logic in the provider's stream_text() method
-        let events_stream = client
-            .request(self.method(), url.clone())
+        let job = self.create(base_url).await?;
+        let url = Self::stream_url(&job)
+            .ok_or_else(|| Error::Other(format!("Job has no stream URL: {job:?}")))?;
+
+        // The job was created once, its stream is not reopened
+        let transport = Transport::new(self.transport());
+        let request = transport
+            .client
+            .get(url)
             .headers(self.headers())
-            .query(&self.query_params())
-            .body(self.body())
-            .eventsource()
-            .map_err(|e| Error::ApiError {
-                status_code: None,
-                details: format!("SSE stream error: {e}"),
-            })?;
-
-        // Map events to deserialized StreamEvent ( ProviderStreamEvent )
-        let mapped_stream = events_stream.map(|event_result| Self::parse_stream_sse(event_result));
-
-        // State that indicates if the stream has ended
-        let ended = std::sync::Arc::new(std::sync::Mutex::new(false));
-
-        // Scan to end or mark the stream as ended
-        let stream = mapped_stream.scan(ended, |ended, res| {
-            let mut ended = ended.lock().unwrap();
-
-            if *ended {
-                return futures::future::ready(None); // Stop the stream after end event
+            .header(reqwest::header::ACCEPT, "text/event-stream");
+        let (events, _) = transport.open_events(request).await?;
+
+        let mapped_stream = events.map(move |event_result| {
+            if let Ok(Event::Message(mut message)) = event_result {
+                transport.stream_event(&mut message.data)?;
+                record_raw_event(&message.data);
+                return Self::parse_stream_sse(Ok(Event::Message(message)));
             }
-
-            *ended = res.as_ref().map_or(true, |evt| Self::end_stream(evt)); // Mark the stream as ended on api error or end event
-
-            futures::future::ready(Some(res)) // Emit the event
+            Self::parse_stream_sse(event_result)
         });
 
-        Ok(Box::pin(stream))
+        Ok(end_stream_on(mapped_stream, Self::end_stream))
     }
 }
-/// Trait for embedding model clients to interact with embedding APIs.
-#[allow(dead_code)]
-pub(crate) trait EmbeddingClient {
-    type Response: DeserializeOwned + std::fmt::Debug + Clone;
-
-    fn path(&self) -> String;
-    fn method(&self) -> reqwest::Method;
-    fn query_params(&self) -> Vec<(&str, &str)>;
-    fn body(&self) -> reqwest::Body;
-    fn headers(&self) -> reqwest::header::HeaderMap;
-
-    async fn send(&self, base_url: impl IntoUrl) -> Result<Self::Response> {
-        let base_url = base_url
-            .into_url()
-            .map_err(|_| Error::InvalidInput("Invalid base URL".into()))?;
-
-        let url = join_url(base_url, &self.path())?;
 
-        // Serialize body once to avoid consumption issues on retries
-        let body_bytes = {
-            let body = self.body();
-            // Convert Body to bytes - this is the critical fix for retry body consumption
-            match body.as_bytes() {
-                Some(bytes) => bytes.to_vec(),
-                None => {
-                    // If body doesn't have as_bytes (streaming body), we can't retry it
-                    log::warn!("Request body is not retryable (streaming body)");
-                    vec![]
-                }
-            }
-        };
+tokio::task_local! {
+    /// The payloads of the provider stream events received while they are captured.
+    static RAW_EVENTS: RefCell<Vec<serde_json::Value>>;
+}
 
-        let method = self.method();
-        let headers = self.headers();
-        let query_params = self.query_params();
-        let config = RetryConfig::default();
+/// Records the payload of a provider stream event, if [`capture_raw_events`] is
+/// capturing them. Payloads that are not JSON, e.g. `[DONE]`, are recorded as strings.
+pub(crate) fn record_raw_event(data: &str) {
+    let _ = RAW_EVENTS.try_with(|events| {
+        let event = serde_json::from_str(data)
+            .unwrap_or_else(|_| serde_json::Value::String(data.to_string()));
+        events.borrow_mut().push(event);
+    });
+}
 
-        retry_request(
-            url,
-            method,
-            headers,
-            query_params,
-            move || reqwest::Body::from(body_bytes.clone()),
-            config,
-        )
+/// Runs `future`, returning its output with the payloads of the provider stream events
+/// received while it ran.
+#[cfg(feature = "language-model-request")]
+pub(crate) async fn capture_raw_events<F: Future>(
+    future: F,
+) -> (F::Output, Vec<serde_json::Value>) {
+    RAW_EVENTS
+        .scope(RefCell::new(Vec::new()), async {
+            let output = future.await;
+            (output, RAW_EVENTS.with(RefCell::take))
+        })
         .await
-    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    /// Helper to create a custom RetryConfig for testing
+    #[test]
+    fn test_redacted_headers() {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert("authorization", "Bearer sk-secret".parse().unwrap());
+        headers.insert("x-goog-api-key", "goog-secret".parse().unwrap());
+        headers.insert("content-type", "application/json".parse().unwrap());
+
+        let formatted = format!("{:?}", RedactedHeaders(&headers));
+
+        assert!(!formatted.contains("secret"));
+        assert!(formatted.contains("\"content-type\": \"application/json\""));
+    }
+
+    /// Helper to create a custom RetryPolicy for testing
     fn test_config(
         max_retries: u32,
         initial_wait_ms: u64,
         max_wait_ms: u64,
         use_jitter: bool,
-    ) -> RetryConfig {
-        RetryConfig {
+    ) -> RetryPolicy {
+        RetryPolicy {
             max_retries,
             initial_wait: Duration::from_millis(initial_wait_ms),
             max_wait: Duration::from_millis(max_wait_ms),
+            max_elapsed_time: None,
             use_jitter,
+            stream_recovery: StreamRecovery::default(),
         }
     }
 
@@ -642,11 +2526,13 @@ mod tests {
 
     #[test]
     fn test_calculate_backoff_very_large_initial_wait() {
-        let config = RetryConfig {
+        let config = RetryPolicy {
             max_retries: 5,
             initial_wait: Duration::from_secs(1_000_000),
             max_wait: Duration::from_secs(2_000_000),
+            max_elapsed_time: None,
             use_jitter: false,
+            stream_recovery: StreamRecovery::default(),
         };
 
         let result = calculate_backoff(0, &config, None);
@@ -655,11 +2541,13 @@ mod tests {
 
     #[test]
     fn test_calculate_backoff_overflow_protection() {
-        let config = RetryConfig {
+        let config = RetryPolicy {
             max_retries: 100,
             initial_wait: Duration::from_millis(u64::MAX / 2),
             max_wait: Duration::from_secs(60),
+            max_elapsed_time: None,
             use_jitter: false,
+            stream_recovery: StreamRecovery::default(),
         };
 
         // This should saturate multiplication and get capped at max_wait
@@ -787,13 +2675,213 @@ mod tests {
     }
 
     #[test]
-    fn test_is_retryable_status_500_not_retryable() {
-        // 500 Internal Server Error is usually not retryable
-        assert!(!is_retryable_status(
+    fn test_is_retryable_status_500() {
+        assert!(is_retryable_status(
             reqwest::StatusCode::INTERNAL_SERVER_ERROR
         ));
     }
 
+    #[test]
+    fn test_is_retryable_status_408() {
+        assert!(is_retryable_status(reqwest::StatusCode::REQUEST_TIMEOUT));
+    }
+
+    #[test]
+    fn test_is_retryable_status_501_not_retryable() {
+        assert!(!is_retryable_status(reqwest::StatusCode::NOT_IMPLEMENTED));
+    }
+
+    // ========================================================================
+    // Tests for api_error
+    // ========================================================================
+
+    fn classify(status: u16, body: &str) -> Error {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(reqwest::header::RETRY_AFTER, "7".parse().unwrap());
+        api_error(
+            reqwest::StatusCode::from_u16(status).unwrap(),
+            &headers,
+            body.to_string(),
+        )
+    }
+
+    #[test]
+    fn test_api_error_classifies_openai_codes() {
+        assert_eq!(
+            classify(
+                429,
+                r#"{"error":{"message":"Slow down","type":"requests","code":"rate_limit_exceeded"}}"#
+            ),
+            Error::RateLimited {
+                retry_after: Some(Duration::from_secs(7)),
+                details: "Slow down".to_string(),
+                status_code: Some(reqwest::StatusCode::TOO_MANY_REQUESTS),
+                rate_limit: None,
+            }
+        );
+        assert_eq!(
+            classify(
+                400,
+                r#"{"error":{"message":"Too long","code":"context_length_exceeded"}}"#
+            ),
+            Error::ContextLengthExceeded {
+                details: "Too long".to_string(),
+                status_code: Some(reqwest::StatusCode::from_u16(400).unwrap())
+            }
+        );
+        assert_eq!(
+            classify(
+                404,
+                r#"{"error":{"message":"No such model","code":"model_not_found"}}"#
+            ),
+            Error::ModelNotFound {
+                details: "No such model".to_string(),
+                status_code: Some(reqwest::StatusCode::from_u16(404).unwrap())
+            }
+        );
+        assert_eq!(
+            classify(
+                400,
+                r#"{"error":{"message":"Filtered","code":"content_filter"}}"#
+            ),
+            Error::ContentFiltered {
+                details: "Filtered".to_string(),
+                status_code: Some(reqwest::StatusCode::from_u16(400).unwrap())
+            }
+        );
+    }
+
+    #[test]
+    fn test_api_error_classifies_anthropic_types() {
+        assert_eq!(
+            classify(
+                529,
+                r#"{"type":"error","error":{"type":"overloaded_error","message":"Overloaded"}}"#
+            ),
+            Error::Overloaded {
+                details: "Overloaded".to_string(),
+                status_code: Some(reqwest::StatusCode::from_u16(529).unwrap())
+            }
+        );
+        assert_eq!(
+            classify(
+                401,
+                r#"{"type":"error","error":{"type":"authentication_error","message":"invalid x-api-key"}}"#
+            ),
+            Error::AuthenticationFailed {
+                details: "invalid x-api-key".to_string(),
+                status_code: Some(reqwest::StatusCode::from_u16(401).unwrap())
+            }
+        );
+        assert_eq!(
+            classify(
+                400,
+                r#"{"type":"error","error":{"type":"invalid_request_error","message":"prompt is too long: 210000 tokens > 200000 maximum"}}"#
+            ),
+            Error::ContextLengthExceeded {
+                details: "prompt is too long: 210000 tokens > 200000 maximum".to_string(),
+                status_code: Some(reqwest::StatusCode::from_u16(400).unwrap())
+            }
+        );
+    }
+
+    #[test]
+    fn test_api_error_classifies_google_statuses() {
+        assert_eq!(
+            classify(
+                403,
+                r#"{"error":{"code":403,"message":"Denied","status":"PERMISSION_DENIED"}}"#
+            ),
+            Error::AuthenticationFailed {
+                details: "Denied".to_string(),
+                status_code: Some(reqwest::StatusCode::from_u16(403).unwrap())
+            }
+        );
+        assert_eq!(
+            classify(
+                404,
+                r#"{"error":{"code":404,"message":"models/x is not found","status":"NOT_FOUND"}}"#
+            ),
+            Error::ModelNotFound {
+                details: "models/x is not found".to_string(),
+                status_code: Some(reqwest::StatusCode::from_u16(404).unwrap())
+            }
+        );
+        assert!(matches!(
+            classify(
+                429,
+                r#"{"error":{"code":429,"status":"RESOURCE_EXHAUSTED"}}"#
+            ),
+            Error::RateLimited { .. }
+        ));
+    }
+
+    #[test]
+    fn test_api_error_classifies_local_server_errors() {
+        assert_eq!(
+            classify(404, r#"{"error":"model 'llama9' not found"}"#),
+            Error::ModelNotFound {
+                details: "model 'llama9' not found".to_string(),
+                status_code: Some(reqwest::StatusCode::from_u16(404).unwrap())
+            }
+        );
+        assert_eq!(
+            classify(
+                400,
+                r#"{"error":{"code":400,"message":"request exceeds the available context size","type":"exceed_context_size_error"}}"#
+            ),
+            Error::ContextLengthExceeded {
+                details: "request exceeds the available context size".to_string(),
+                status_code: Some(reqwest::StatusCode::from_u16(400).unwrap())
+            }
+        );
+    }
+
+    #[test]
+    fn test_api_error_keeps_unknown_errors() {
+        assert_eq!(
+            classify(400, "bad request"),
+            Error::ApiError {
+                status_code: Some(reqwest::StatusCode::BAD_REQUEST),
+                details: "bad request".to_string(),
+            }
+        );
+    }
+
+    // ========================================================================
+    // Tests for RetryPolicy
+    // ========================================================================
+
+    #[test]
+    fn test_next_wait_stops_after_max_retries() {
+        let policy = test_config(2, 10, 100, false);
+        let started = Instant::now();
+        assert_eq!(
+            policy.next_wait(0, started, None),
+            Some(Duration::from_millis(10))
+        );
+        assert_eq!(
+            policy.next_wait(1, started, None),
+            Some(Duration::from_millis(20))
+        );
+        assert_eq!(policy.next_wait(2, started, None), None);
+    }
+
+    #[test]
+    fn test_next_wait_respects_max_elapsed_time() {
+        let policy =
+            test_config(5, 1000, 30000, false).max_elapsed_time(Duration::from_millis(1500));
+        let started = Instant::now();
+        assert!(policy.next_wait(0, started, None).is_some());
+        // The second retry would wait 2s, past the 1.5s budget
+        assert_eq!(policy.next_wait(1, started, None), None);
+    }
+
+    #[test]
+    fn test_none_policy_never_retries() {
+        assert_eq!(RetryPolicy::none().next_wait(0, Instant::now(), None), None);
+    }
+
     // ========================================================================
     // Tests for parse_retry_after
     // ========================================================================
@@ -890,4 +2978,381 @@ mod tests {
         let result = parse_retry_after(&headers);
         assert_eq!(result, None); // Should fail to parse as u64
     }
+
+    #[test]
+    fn test_parse_retry_after_prefers_milliseconds() {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(
+            reqwest::header::RETRY_AFTER,
+            reqwest::header::HeaderValue::from_static("2"),
+        );
+        headers.insert(
+            "retry-after-ms",
+            reqwest::header::HeaderValue::from_static("1500"),
+        );
+
+        let result = parse_retry_after(&headers);
+        assert_eq!(result, Some(Duration::from_millis(1500)));
+    }
+
+    // ========================================================================
+    // Tests for Stream Recovery
+    // ========================================================================
+
+    /// A client for a local SSE server, whose events are the `data` of each message.
+    #[derive(Clone, Default)]
+    struct SseClient {
+        middleware: MiddlewareChain,
+        recovery_wait: Duration,
+    }
+
+    impl LanguageModelClient for SseClient {
+        type Response = String;
+        type StreamEvent = String;
+
+        fn path(&self) -> String {
+            "/stream".to_string()
+        }
+
+        fn method(&self) -> reqwest::Method {
+            reqwest::Method::POST
+        }
+
+        fn query_params(&self) -> Vec<(&str, &str)> {
+            Vec::new()
+        }
+
+        fn body(&self) -> reqwest::Body {
+            reqwest::Body::from("{}")
+        }
+
+        fn headers(&self) -> reqwest::header::HeaderMap {
+            reqwest::header::HeaderMap::new()
+        }
+
+        fn retry_policy(&self) -> RetryPolicy {
+            RetryPolicy::none().stream_recovery(StreamRecovery::new().wait(self.recovery_wait))
+        }
+
+        fn transport(&self) -> TransportSettings {
+            TransportSettings {
+                middleware: self.middleware.clone(),
+                ..Default::default()
+            }
+        }
+
+        fn parse_stream_sse(
+            event: std::result::Result<Event, reqwest_eventsource::Error>,
+        ) -> Result<Self::StreamEvent> {
+            match event {
+                Ok(Event::Open) => Ok(String::new()),
+                Ok(Event::Message(message)) => Ok(message.data),
+                Err(e) => Err(Error::Other(e.to_string())),
+            }
+        }
+
+        fn end_stream(event: &Self::StreamEvent) -> bool {
+            event == "done"
+        }
+    }
+
+    #[cfg(feature = "language-model-request")]
+    #[tokio::test]
+    async fn test_capture_raw_events() {
+        record_raw_event(r#"{"ignored":true}"#);
+
+        let ((), events) = capture_raw_events(async {
+            record_raw_event(r#"{"type":"response.created"}"#);
+            record_raw_event("[DONE]");
+        })
+        .await;
+
+        assert_eq!(
+            events,
+            vec![
+                serde_json::json!({"type": "response.created"}),
+                serde_json::json!("[DONE]"),
+            ]
+        );
+    }
+
+    /// Accepts one connection, returns its request head, and responds with `events`
+    /// before closing the connection.
+    async fn serve_sse(listener: &tokio::net::TcpListener, events: &str) -> String {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let mut request = Vec::new();
+        let mut buf = [0; 1024];
+        let head_len = loop {
+            let n = socket.read(&mut buf).await.unwrap();
+            request.extend_from_slice(&buf[..n]);
+            if let Some(i) = request.windows(4).position(|w| w == b"\r\n\r\n") {
+                break i + 4;
+            }
+        };
+        let head = String::from_utf8_lossy(&request[..head_len]).to_lowercase();
+        let content_length = head
+            .lines()
+            .find_map(|line| line.strip_prefix("content-length: "))
+            .map_or(0, |len| len.trim().parse().unwrap());
+        while request.len() < head_len + content_length {
+            let n = socket.read(&mut buf).await.unwrap();
+            request.extend_from_slice(&buf[..n]);
+        }
+
+        let response = format!(
+            "HTTP/1.1 200 OK\r\ncontent-type: text/event-stream\r\nconnection: close\r\n\r\n{events}"
+        );
+        socket.write_all(response.as_bytes()).await.unwrap();
+        head
+    }
+
+    #[tokio::test]
+    async fn test_send_and_stream_resumes_with_last_event_id() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let server = tokio::spawn(async move {
+            // The first connection drops after one event
+            serve_sse(&listener, "id: 1\ndata: hello\n\n").await;
+            serve_sse(&listener, "id: 2\ndata: done\n\n").await
+        });
+
+        let events: Vec<String> = SseClient::default()
+            .send_and_stream(base_url.as_str())
+            .await
+            .unwrap()
+            .map(|event| event.unwrap())
+            .filter(|event| futures::future::ready(!event.is_empty()))
+            .collect()
+            .await;
+
+        assert_eq!(events, vec!["hello", "done"]);
+        assert!(server.await.unwrap().contains("last-event-id: 1"));
+    }
+
+    #[tokio::test]
+    async fn test_send_and_stream_waits_before_resuming() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let server = tokio::spawn(async move {
+            serve_sse(&listener, "id: 1\ndata: hello\n\n").await;
+            let dropped = std::time::Instant::now();
+            serve_sse(&listener, "id: 2\ndata: done\n\n").await;
+            dropped.elapsed()
+        });
+
+        let client = SseClient {
+            recovery_wait: Duration::from_millis(200),
+            ..Default::default()
+        };
+        let _: Vec<_> = client
+            .send_and_stream(base_url.as_str())
+            .await
+            .unwrap()
+            .collect()
+            .await;
+
+        assert!(server.await.unwrap() >= Duration::from_millis(200));
+    }
+
+    #[tokio::test]
+    async fn test_send_and_stream_without_event_ids_fails() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        tokio::spawn(async move { serve_sse(&listener, "data: hello\n\n").await });
+
+        let events: Vec<Result<String>> = SseClient::default()
+            .send_and_stream(base_url.as_str())
+            .await
+            .unwrap()
+            .collect()
+            .await;
+
+        assert!(matches!(events.last(), Some(Err(_))));
+    }
+
+    #[tokio::test]
+    async fn test_requests_use_shared_http_client() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move { serve_sse(&listener, "data: hello\n\n").await });
+
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert("x-shared-client", "1".parse().unwrap());
+        set_http_client(
+            reqwest::Client::builder()
+                .default_headers(headers)
+                .build()
+                .unwrap(),
+        );
+        let _ = SseClient::default()
+            .send_and_stream(base_url.as_str())
+            .await;
+
+        assert!(server.await.unwrap().contains("x-shared-client: 1"));
+    }
+
+    #[test]
+    fn test_http_client_config_builds() {
+        let config = HttpClientConfig::new()
+            .pool_max_idle_per_host(4)
+            .pool_idle_timeout(Duration::from_secs(30))
+            .connect_timeout(Duration::from_secs(5))
+            .tcp_keepalive(Duration::from_secs(60));
+
+        assert_eq!(config.pool_max_idle_per_host, Some(4));
+        assert!(config.build().is_ok());
+    }
+
+    #[test]
+    fn test_http_client_config_rejects_invalid_proxy_and_certificate() {
+        let proxy = HttpClientConfig::new().proxy("not a url").build();
+        assert!(matches!(proxy, Err(Error::InvalidInput(msg)) if msg.starts_with("Invalid proxy")));
+
+        let certificate = HttpClientConfig::new()
+            .root_certificate("not a certificate")
+            .build();
+        assert!(
+            matches!(certificate, Err(Error::InvalidInput(msg)) if msg.starts_with("Invalid root"))
+        );
+    }
+
+    // ========================================================================
+    // Tests for Middleware
+    // ========================================================================
+
+    /// Adds a header to requests, and expands "hi" in stream events.
+    struct Greeter;
+
+    #[async_trait]
+    impl Middleware for Greeter {
+        async fn on_request(&self, request: &mut reqwest::Request) -> Result<()> {
+            request
+                .headers_mut()
+                .insert("x-greeting", "hi".parse().unwrap());
+            Ok(())
+        }
+
+        fn on_stream_event(&self, data: &mut String) -> Result<()> {
+            *data = data.replace("hi", "hello");
+            Ok(())
+        }
+    }
+
+    /// Answers every request with `body`, and appends `item` to JSON array responses.
+    struct Mock {
+        body: &'static str,
+        item: &'static str,
+    }
+
+    #[async_trait]
+    impl Middleware for Mock {
+        async fn respond(&self, _request: &reqwest::Request) -> Option<HttpResponse> {
+            Some(HttpResponse::new(reqwest::StatusCode::OK, self.body))
+        }
+
+        async fn on_response(&self, response: &mut HttpResponse) -> Result<()> {
+            let body = String::from_utf8_lossy(&response.body);
+            response.body = body.replace(']', &format!(",{}]", self.item)).into();
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_middleware_modifies_requests_and_stream_events() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            serve_sse(&listener, "id: 1\ndata: hi\n\nid: 2\ndata: done\n\n").await
+        });
+
+        let mut client = SseClient::default();
+        client.middleware.push(Greeter);
+        let events: Vec<String> = client
+            .send_and_stream(base_url.as_str())
+            .await
+            .unwrap()
+            .map(|event| event.unwrap())
+            .filter(|event| futures::future::ready(!event.is_empty()))
+            .collect()
+            .await;
+
+        assert_eq!(events, vec!["hello", "done"]);
+        assert!(server.await.unwrap().contains("x-greeting: hi"));
+    }
+
+    #[tokio::test]
+    async fn test_middleware_mocks_streams() {
+        let mut client = SseClient::default();
+        client.middleware.push(Mock {
+            body: "data: mocked\n\ndata: done\n\n",
+            item: "",
+        });
+
+        // Nothing listens on the discard port, the request must not be sent
+        let events: Vec<String> = client
+            .send_and_stream("http://127.0.0.1:9")
+            .await
+            .unwrap()
+            .map(|event| event.unwrap())
+            .collect()
+            .await;
+
+        assert_eq!(events, vec!["mocked", "done"]);
+    }
+
+    #[tokio::test]
+    async fn test_middleware_responses_run_in_reverse_order() {
+        let mut middleware = MiddlewareChain::new();
+        middleware.push(Mock {
+            body: "[1]",
+            item: "2",
+        });
+        middleware.push(Mock {
+            body: "[]",
+            item: "3",
+        });
+        let transport = Transport::new(TransportSettings {
+            middleware,
+            ..Default::default()
+        });
+
+        let response: serde_json::Value = retry_request(
+            &transport,
+            reqwest::Url::parse("http://127.0.0.1:9").unwrap(),
+            reqwest::Method::GET,
+            reqwest::header::HeaderMap::new(),
+            Vec::new(),
+            reqwest::Body::default,
+            RetryPolicy::none(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(response, serde_json::json!([1, 3, 2]));
+    }
+
+    #[tokio::test]
+    async fn test_requests_wait_for_rate_limiter() {
+        let mut middleware = MiddlewareChain::new();
+        middleware.push(Mock {
+            body: "[]",
+            item: "1",
+        });
+        let transport = Transport::new(TransportSettings {
+            middleware,
+            rate_limiter: Some(RateLimiter::new().requests_per_minute(1)),
+            ..Default::default()
+        });
+        let request = || transport.client.get("http://127.0.0.1:9");
+
+        assert!(transport.send(request()).await.is_ok());
+        let second =
+            tokio::time::timeout(Duration::from_millis(50), transport.send(request())).await;
+        assert!(second.is_err());
+    }
 }
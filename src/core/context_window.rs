@@ -0,0 +1,470 @@
+//! Context-window policies that keep long conversations within a model's context.
+//!
+//! A [`ContextWindowPolicy`] is applied to the messages of every model call before it
+//! is sent. The conversation itself is left untouched, so the response still holds the
+//! full history. Messages are only dropped from the start of a user turn, so tool calls
+//! are never separated from their results, and system and developer messages are always
+//! kept.
+//!
+//! Token-based policies fall back to the model's
+//! [`context_length`](crate::core::LanguageModel::context_length), minus the tokens
+//! reserved for the output, when no explicit budget is given.
+//!
+//! # Example
+//!
+//! ```
+//! use aisdk::core::context_window::ContextWindowPolicy;
+//!
+//! // Keep at most the last 20 messages
+//! let policy = ContextWindowPolicy::TruncateOldest { max_messages: 20 };
+//!
+//! // Drop the oldest turns once the conversation exceeds 50k tokens
+//! let policy = ContextWindowPolicy::SlidingWindow {
+//!     max_tokens: Some(50_000),
+//! };
+//! ```
+
+use crate::core::language_model::LanguageModelResponseContentType;
+use crate::core::language_model::{LanguageModel, LanguageModelOptions};
+#[cfg(feature = "language-model-request")]
+use crate::core::messages::SystemMessage;
+use crate::core::messages::{Message, TaggedMessage};
+#[cfg(feature = "language-model-request")]
+use crate::core::tokens::{self, estimate_tokens};
+use crate::error::Result;
+use async_trait::async_trait;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+/// The output tokens reserved from the context length when the request does not set
+/// `max_output_tokens`.
+pub const DEFAULT_OUTPUT_RESERVE: usize = 4_096;
+
+/// The system prompt used by [`ModelSummarizer`].
+pub const DEFAULT_SUMMARY_PROMPT: &str = "Summarize the following conversation between a user and an assistant. \
+Keep every fact, decision, and open question needed to continue the conversation. \
+Reply with the summary only.";
+
+/// Summarizes messages dropped by [`ContextWindowPolicy::SummarizeOldest`].
+#[async_trait]
+pub trait Summarizer: Send + Sync {
+    /// Returns a summary of `messages`.
+    async fn summarize(&self, messages: &[Message]) -> Result<String>;
+}
+
+/// A [`Summarizer`] that asks a language model, usually a small and cheap one, for the
+/// summary.
+#[derive(Debug, Clone)]
+pub struct ModelSummarizer<M: LanguageModel> {
+    model: M,
+    prompt: String,
+}
+
+impl<M: LanguageModel> ModelSummarizer<M> {
+    /// Creates a summarizer using `model` with the [`DEFAULT_SUMMARY_PROMPT`].
+    pub fn new(model: M) -> Self {
+        Self {
+            model,
+            prompt: DEFAULT_SUMMARY_PROMPT.to_string(),
+        }
+    }
+
+    /// Replaces the system prompt sent with the conversation to summarize.
+    pub fn prompt(mut self, prompt: impl Into<String>) -> Self {
+        self.prompt = prompt.into();
+        self
+    }
+}
+
+#[async_trait]
+impl<M: LanguageModel> Summarizer for ModelSummarizer<M> {
+    async fn summarize(&self, messages: &[Message]) -> Result<String> {
+        let options = LanguageModelOptions {
+            system: Some(self.prompt.clone()),
+            messages: vec![TaggedMessage::initial_step_msg(Message::User(
+                transcript(messages).into(),
+            ))],
+            ..Default::default()
+        };
+        let response = self.model.clone().generate_text(options).await?;
+
+        Ok(response
+            .contents
+            .into_iter()
+            .filter_map(|content| match content {
+                LanguageModelResponseContentType::Text(text) => Some(text),
+                _ => None,
+            })
+            .collect())
+    }
+}
+
+/// Renders messages as a plain-text transcript, one message per paragraph.
+fn transcript(messages: &[Message]) -> String {
+    messages
+        .iter()
+        .filter_map(|message| match message {
+            Message::System(m) => Some(format!("System: {}", m.content)),
+            Message::Developer(text) => Some(format!("Developer: {text}")),
+            Message::User(m) => Some(format!("User: {}", m.content)),
+            Message::Assistant(m) => match &m.content {
+                LanguageModelResponseContentType::Text(text) => Some(format!("Assistant: {text}")),
+                LanguageModelResponseContentType::ToolCall(call) => Some(format!(
+                    "Assistant called {} with {}",
+                    call.tool.name, call.input
+                )),
+                _ => None,
+            },
+            Message::Tool(result) => Some(match &result.output {
+                Ok(output) => format!("Tool {} returned {output}", result.tool.name),
+                Err(e) => format!("Tool {} failed: {e}", result.tool.name),
+            }),
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// How the messages of a request are trimmed to fit the model's context.
+#[derive(Clone)]
+pub enum ContextWindowPolicy {
+    /// Keeps at most the last `max_messages` messages, not counting system and
+    /// developer messages.
+    TruncateOldest {
+        /// The number of messages to keep.
+        max_messages: usize,
+    },
+    /// Drops the oldest turns until the conversation fits in `max_tokens`.
+    SlidingWindow {
+        /// The token budget of the prompt. Defaults to the model's context length
+        /// minus the output tokens.
+        max_tokens: Option<usize>,
+    },
+    /// Replaces the oldest turns with a summary once the conversation no longer fits
+    /// in `max_tokens`.
+    ///
+    /// The summary is requested on every model call that exceeds the budget.
+    SummarizeOldest {
+        /// The summarizer, usually a [`ModelSummarizer`] with a cheap model.
+        summarizer: Arc<dyn Summarizer>,
+        /// The token budget of the prompt. Defaults to the model's context length
+        /// minus the output tokens.
+        max_tokens: Option<usize>,
+    },
+}
+
+impl Debug for ContextWindowPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TruncateOldest { max_messages } => f
+                .debug_struct("TruncateOldest")
+                .field("max_messages", max_messages)
+                .finish(),
+            Self::SlidingWindow { max_tokens } => f
+                .debug_struct("SlidingWindow")
+                .field("max_tokens", max_tokens)
+                .finish(),
+            Self::SummarizeOldest { max_tokens, .. } => f
+                .debug_struct("SummarizeOldest")
+                .field("max_tokens", max_tokens)
+                .finish_non_exhaustive(),
+        }
+    }
+}
+
+impl ContextWindowPolicy {
+    /// Creates a [`SummarizeOldest`](Self::SummarizeOldest) policy that summarizes with
+    /// `model` and defaults to the context length of the requested model.
+    pub fn summarize_oldest<M: LanguageModel>(model: M) -> Self {
+        Self::SummarizeOldest {
+            summarizer: Arc::new(ModelSummarizer::new(model)),
+            max_tokens: None,
+        }
+    }
+
+    /// Trims the messages of `options` in place.
+    ///
+    /// Token budgets are checked against the model's own
+    /// [`count_tokens`](LanguageModel::count_tokens), falling back to an estimate if
+    /// counting fails. Per-message estimates, scaled to the counted total, decide which
+    /// turns are dropped.
+    ///
+    /// # Parameters
+    ///
+    /// * `options` - The options of the model call about to be sent.
+    /// * `model` - The model the call is sent to.
+    ///
+    /// # Errors
+    ///
+    /// Returns the summarizer's error if a summary is needed and cannot be produced.
+    #[cfg(feature = "language-model-request")]
+    pub(crate) async fn apply<M: LanguageModel>(
+        &self,
+        options: &mut LanguageModelOptions,
+        model: &M,
+    ) -> Result<()> {
+        let start = match self {
+            Self::TruncateOldest { max_messages } => {
+                self.cut(&options.messages, |kept, _| kept.len() <= *max_messages)
+            }
+            Self::SlidingWindow { max_tokens } | Self::SummarizeOldest { max_tokens, .. } => {
+                let budget = max_tokens.or_else(|| {
+                    let reserve = options
+                        .max_output_tokens
+                        .map_or(DEFAULT_OUTPUT_RESERVE, |max| max as usize);
+                    model
+                        .context_length()
+                        .map(|length| length.saturating_sub(reserve))
+                });
+                let Some(budget) = budget else {
+                    return Ok(());
+                };
+
+                let estimated = tokens::estimate(options);
+                let counted = model.count_tokens(options).await.unwrap_or_else(|e| {
+                    log::warn!("Token counting failed, using an estimate: {e}");
+                    estimated
+                });
+                if counted <= budget {
+                    return Ok(());
+                }
+
+                let scale = counted as f64 / estimated.max(1) as f64;
+                let system = options.system.as_ref().map_or(0, |s| s.len().div_ceil(4));
+                self.cut(&options.messages, |kept, pinned| {
+                    let tokens: usize = kept
+                        .iter()
+                        .chain(pinned)
+                        .map(|m| estimate_tokens(&m.message))
+                        .sum();
+                    ((system + tokens) as f64 * scale) as usize <= budget
+                })
+            }
+        };
+        if start == 0 {
+            return Ok(());
+        }
+
+        let rest = options.messages.split_off(start);
+        let (mut pinned, dropped): (Vec<_>, Vec<_>) = options
+            .messages
+            .drain(..)
+            .partition(|m| is_pinned(&m.message));
+
+        if let Self::SummarizeOldest { summarizer, .. } = self {
+            let dropped: Vec<Message> = dropped.into_iter().map(|m| m.message).collect();
+            let summary = summarizer.summarize(&dropped).await?;
+            pinned.push(TaggedMessage::initial_step_msg(Message::System(
+                SystemMessage::new(format!("Summary of the earlier conversation:\n{summary}")),
+            )));
+        }
+
+        options.messages = pinned;
+        options.messages.extend(rest);
+        Ok(())
+    }
+
+    /// Returns the earliest user turn from which the messages fit, or the last user
+    /// turn if none does. Returns 0 when there is nothing to drop.
+    #[cfg(feature = "language-model-request")]
+    fn cut(
+        &self,
+        messages: &[TaggedMessage],
+        fits: impl Fn(&[&TaggedMessage], &[&TaggedMessage]) -> bool,
+    ) -> usize {
+        let mut last = 0;
+        for (i, tagged) in messages.iter().enumerate() {
+            if i > 0 && !matches!(tagged.message, Message::User(_)) {
+                continue;
+            }
+            let pinned: Vec<&TaggedMessage> = messages[..i]
+                .iter()
+                .filter(|m| is_pinned(&m.message))
+                .collect();
+            let kept: Vec<&TaggedMessage> = messages[i..]
+                .iter()
+                .filter(|m| !is_pinned(&m.message))
+                .collect();
+            if fits(&kept, &pinned) {
+                return i;
+            }
+            last = i;
+        }
+        last
+    }
+}
+
+/// Returns true for messages that are never dropped.
+#[cfg(feature = "language-model-request")]
+fn is_pinned(message: &Message) -> bool {
+    matches!(message, Message::System(_) | Message::Developer(_))
+}
+
+#[cfg(all(test, feature = "language-model-request"))]
+mod tests {
+    use super::*;
+    use crate::core::capabilities::ModelInfo;
+    use crate::core::test_model::ScriptedModel;
+    use crate::core::{AssistantMessage, ToolCallInfo, ToolResultInfo};
+
+    fn conversation() -> Vec<TaggedMessage> {
+        let call = ToolCallInfo::new("search");
+        let mut result = ToolResultInfo::new("search");
+        result.output(serde_json::json!("found"));
+        [
+            Message::System("Be brief".into()),
+            Message::User("first".into()),
+            Message::Assistant("answer one".to_string().into()),
+            Message::User("second".into()),
+            Message::Assistant(AssistantMessage::new(
+                LanguageModelResponseContentType::ToolCall(call),
+                None,
+            )),
+            Message::Tool(result),
+            Message::Assistant("answer two".to_string().into()),
+            Message::User("third".into()),
+        ]
+        .into_iter()
+        .map(TaggedMessage::initial_step_msg)
+        .collect()
+    }
+
+    fn conversation_options() -> LanguageModelOptions {
+        LanguageModelOptions {
+            messages: conversation(),
+            ..Default::default()
+        }
+    }
+
+    fn texts(options: &LanguageModelOptions) -> Vec<String> {
+        options
+            .messages
+            .iter()
+            .map(|m| match &m.message {
+                Message::System(s) => s.content.clone(),
+                Message::User(u) => u.content.clone(),
+                Message::Assistant(a) => match &a.content {
+                    LanguageModelResponseContentType::Text(t) => t.clone(),
+                    _ => "call".to_string(),
+                },
+                Message::Tool(_) => "result".to_string(),
+                Message::Developer(d) => d.clone(),
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_truncate_keeps_tool_results_with_their_calls() {
+        let mut options = conversation_options();
+        ContextWindowPolicy::TruncateOldest { max_messages: 4 }
+            .apply(&mut options, &ScriptedModel::new("test"))
+            .await
+            .unwrap();
+
+        // Keeping the last four messages would orphan the tool result, so the whole
+        // second turn is dropped
+        assert_eq!(texts(&options), ["Be brief", "third"]);
+
+        let mut options = conversation_options();
+        ContextWindowPolicy::TruncateOldest { max_messages: 5 }
+            .apply(&mut options, &ScriptedModel::new("test"))
+            .await
+            .unwrap();
+        assert_eq!(
+            texts(&options),
+            [
+                "Be brief",
+                "second",
+                "call",
+                "result",
+                "answer two",
+                "third"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sliding_window_uses_context_length() {
+        let mut options = conversation_options();
+        ContextWindowPolicy::SlidingWindow { max_tokens: None }
+            .apply(
+                &mut options,
+                &ScriptedModel::new("test").info(ModelInfo {
+                    context_window: Some(1_000),
+                    ..ModelInfo::UNKNOWN
+                }),
+            )
+            .await
+            .unwrap();
+        // Reserving the default output tokens leaves no room, so only the last turn is kept
+        assert_eq!(texts(&options), ["Be brief", "third"]);
+
+        // Without a budget nothing is dropped
+        let mut options = conversation_options();
+        ContextWindowPolicy::SlidingWindow { max_tokens: None }
+            .apply(&mut options, &ScriptedModel::new("test"))
+            .await
+            .unwrap();
+        assert_eq!(options.messages.len(), 8);
+    }
+
+    struct FixedSummarizer;
+
+    #[async_trait]
+    impl Summarizer for FixedSummarizer {
+        async fn summarize(&self, messages: &[Message]) -> Result<String> {
+            Ok(format!("{} messages", messages.len()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_summarize_replaces_dropped_turns() {
+        let mut options = conversation_options();
+        let tokens: usize = options.messages[3..]
+            .iter()
+            .chain(&options.messages[..1])
+            .map(|m| estimate_tokens(&m.message))
+            .sum();
+
+        ContextWindowPolicy::SummarizeOldest {
+            summarizer: Arc::new(FixedSummarizer),
+            max_tokens: Some(tokens),
+        }
+        .apply(&mut options, &ScriptedModel::new("test"))
+        .await
+        .unwrap();
+
+        assert_eq!(
+            texts(&options),
+            [
+                "Be brief",
+                "Summary of the earlier conversation:\n2 messages",
+                "second",
+                "call",
+                "result",
+                "answer two",
+                "third"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sliding_window_trusts_the_model_count() {
+        let mut options = conversation_options();
+        let estimated = tokens::estimate(&options);
+        let policy = ContextWindowPolicy::SlidingWindow {
+            max_tokens: Some(estimated),
+        };
+
+        policy
+            .apply(&mut options, &ScriptedModel::new("test"))
+            .await
+            .unwrap();
+        assert_eq!(options.messages.len(), 8);
+
+        // The model counts twice the estimate, so the conversation no longer fits
+        let model = ScriptedModel::new("test").token_factor(2);
+        policy.apply(&mut options, &model).await.unwrap();
+        assert!(options.messages.len() < 8);
+        assert!(matches!(options.messages[1].message, Message::User(_)));
+    }
+}
@@ -0,0 +1,288 @@
+//! Per-request cost tracking in dollars.
+//!
+//! A [`CostCalculator`] converts the [`Usage`] of a model call into a [`Cost`] with the
+//! price of the model, taken from its [`ModelInfo`](crate::core::capabilities::ModelInfo)
+//! unless overridden. Attach it to a request with
+//! [`cost_calculator`](crate::core::language_model::request::LanguageModelRequestBuilder::cost_calculator)
+//! and the response reports the cost of the call, summed over its steps, with `cost()`.
+//!
+//! A [`CostAccumulator`] totals the costs of every call made with the calculators it is
+//! attached to. Its clones share the totals, so one accumulator per session or tenant
+//! collects their spend across requests and models.
+//!
+//! # Example
+//!
+//! ```
+//! use aisdk::core::budget::TokenPricing;
+//! use aisdk::core::cost::{CostAccumulator, CostCalculator};
+//! use aisdk::core::language_model::Usage;
+//!
+//! let tenant = CostAccumulator::new();
+//! let calculator = CostCalculator::new()
+//!     .price("my-model", TokenPricing::new(3.0, 15.0))
+//!     .accumulator(tenant.clone());
+//!
+//! let usage = Usage {
+//!     input_tokens: Some(1_000_000),
+//!     output_tokens: Some(100_000),
+//!     ..Default::default()
+//! };
+//! let cost = calculator.record("my-model", None, &usage).unwrap();
+//!
+//! assert_eq!(cost.total(), 4.5);
+//! assert_eq!(tenant.total().total(), 4.5);
+//! ```
+
+use crate::core::budget::TokenPricing;
+use crate::core::language_model::Usage;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::ops::{Add, AddAssign};
+use std::sync::{Arc, Mutex};
+
+/// The dollar cost of one or more model calls.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct Cost {
+    /// The cost of the input tokens, in dollars.
+    pub input: f64,
+    /// The cost of the output tokens, in dollars.
+    pub output: f64,
+}
+
+impl Cost {
+    /// Returns the cost of the given usage at the given pricing.
+    pub fn of(usage: &Usage, pricing: &TokenPricing) -> Self {
+        let input = usage.input_tokens.unwrap_or(0) as f64;
+        let output = usage.output_tokens.unwrap_or(0) as f64;
+        Self {
+            input: input * pricing.input_per_million / 1_000_000.0,
+            output: output * pricing.output_per_million / 1_000_000.0,
+        }
+    }
+
+    /// Returns the total cost, in dollars.
+    pub fn total(&self) -> f64 {
+        self.input + self.output
+    }
+}
+
+impl Add for Cost {
+    type Output = Cost;
+
+    fn add(self, other: Cost) -> Cost {
+        Cost {
+            input: self.input + other.input,
+            output: self.output + other.output,
+        }
+    }
+}
+
+impl AddAssign for Cost {
+    fn add_assign(&mut self, other: Cost) {
+        *self = *self + other;
+    }
+}
+
+/// The totals of a [`CostAccumulator`].
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Totals {
+    calls: usize,
+    total: Cost,
+    by_model: HashMap<String, Cost>,
+}
+
+/// Totals the costs of model calls, shared by its clones.
+#[derive(Debug, Clone, Default)]
+pub struct CostAccumulator {
+    totals: Arc<Mutex<Totals>>,
+}
+
+impl CostAccumulator {
+    /// Creates an empty accumulator.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the cost of a call to `model`.
+    pub fn add(&self, model: &str, cost: Cost) {
+        let mut totals = self.lock();
+        totals.calls += 1;
+        totals.total += cost;
+        *totals.by_model.entry(model.to_string()).or_default() += cost;
+    }
+
+    /// Returns the cost of all recorded calls.
+    pub fn total(&self) -> Cost {
+        self.lock().total
+    }
+
+    /// Returns the number of recorded calls.
+    pub fn calls(&self) -> usize {
+        self.lock().calls
+    }
+
+    /// Returns the cost of the recorded calls of each model.
+    pub fn by_model(&self) -> HashMap<String, Cost> {
+        self.lock().by_model.clone()
+    }
+
+    /// Clears the recorded costs.
+    pub fn reset(&self) {
+        *self.lock() = Totals::default();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Totals> {
+        self.totals.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Computes the cost of model calls and adds it to an optional [`CostAccumulator`].
+///
+/// The price of a model is looked up, in order, in the prices set with
+/// [`price`](Self::price), in the model's own info, and in the
+/// [`default_price`](Self::default_price). Calls without a price or usage have no cost.
+#[derive(Debug, Clone, Default)]
+pub struct CostCalculator {
+    prices: HashMap<String, TokenPricing>,
+    default_price: Option<TokenPricing>,
+    accumulator: Option<CostAccumulator>,
+}
+
+impl CostCalculator {
+    /// Creates a calculator using the prices of the models' info.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the price of the model named `model`, overriding its info.
+    pub fn price(mut self, model: impl Into<String>, pricing: TokenPricing) -> Self {
+        self.prices.insert(model.into(), pricing);
+        self
+    }
+
+    /// Sets the price of models without a price of their own, e.g. a
+    /// [`DynamicModel`](crate::core::DynamicModel).
+    pub fn default_price(mut self, pricing: TokenPricing) -> Self {
+        self.default_price = Some(pricing);
+        self
+    }
+
+    /// Adds the cost of every recorded call to `accumulator`.
+    pub fn accumulator(mut self, accumulator: CostAccumulator) -> Self {
+        self.accumulator = Some(accumulator);
+        self
+    }
+
+    /// Returns the price of the model named `model`, whose info has `pricing`.
+    pub fn pricing_for(&self, model: &str, pricing: Option<TokenPricing>) -> Option<TokenPricing> {
+        self.prices
+            .get(model)
+            .copied()
+            .or(pricing)
+            .or(self.default_price)
+    }
+
+    /// Returns the cost of `usage` for the model named `model`, whose info has
+    /// `pricing`, or `None` when the model has no price.
+    pub fn cost(&self, model: &str, pricing: Option<TokenPricing>, usage: &Usage) -> Option<Cost> {
+        self.pricing_for(model, pricing)
+            .map(|pricing| Cost::of(usage, &pricing))
+    }
+
+    /// Computes the cost like [`cost`](Self::cost) and adds it to the accumulator.
+    ///
+    /// The request loops call this automatically.
+    pub fn record(
+        &self,
+        model: &str,
+        pricing: Option<TokenPricing>,
+        usage: &Usage,
+    ) -> Option<Cost> {
+        let cost = self.cost(model, pricing, usage)?;
+        if let Some(accumulator) = &self.accumulator {
+            accumulator.add(model, cost);
+        }
+        Some(cost)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn usage(input: usize, output: usize) -> Usage {
+        Usage {
+            input_tokens: Some(input),
+            output_tokens: Some(output),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_price_lookup_order() {
+        let calculator = CostCalculator::new().price("a", TokenPricing::new(1.0, 1.0));
+        let info = Some(TokenPricing::new(2.0, 2.0));
+
+        assert_eq!(
+            calculator.pricing_for("a", info),
+            Some(TokenPricing::new(1.0, 1.0))
+        );
+        assert_eq!(calculator.pricing_for("b", info), info);
+        assert_eq!(calculator.pricing_for("b", None), None);
+
+        let calculator = calculator.default_price(TokenPricing::new(3.0, 3.0));
+        assert_eq!(
+            calculator.pricing_for("b", None),
+            Some(TokenPricing::new(3.0, 3.0))
+        );
+    }
+
+    #[test]
+    fn test_accumulator_totals_calls_by_model() {
+        let totals = CostAccumulator::new();
+        let calculator = CostCalculator::new()
+            .default_price(TokenPricing::new(2.0, 10.0))
+            .accumulator(totals.clone());
+
+        let cost = calculator
+            .record("a", None, &usage(500_000, 100_000))
+            .unwrap();
+        assert_eq!(
+            cost,
+            Cost {
+                input: 1.0,
+                output: 1.0
+            }
+        );
+        calculator.record("b", None, &usage(1_000_000, 0));
+        calculator.record("a", None, &usage(0, 100_000));
+
+        assert_eq!(totals.calls(), 3);
+        assert_eq!(
+            totals.total(),
+            Cost {
+                input: 3.0,
+                output: 2.0
+            }
+        );
+        assert_eq!(
+            totals.by_model()["a"],
+            Cost {
+                input: 1.0,
+                output: 2.0
+            }
+        );
+
+        totals.reset();
+        assert_eq!(totals.calls(), 0);
+    }
+
+    #[test]
+    fn test_unpriced_calls_are_not_recorded() {
+        let totals = CostAccumulator::new();
+        let calculator = CostCalculator::new().accumulator(totals.clone());
+
+        assert_eq!(calculator.record("a", None, &usage(10, 10)), None);
+        assert_eq!(totals.calls(), 0);
+    }
+}
@@ -0,0 +1,320 @@
+//! Hooks for running the agent loop under a durable-execution framework.
+//!
+//! The side-effectful operations of the agent loop, model calls and tool calls, are
+//! routed through an [`Effect`] when one is registered with
+//! [`effect`](crate::core::language_model::request::LanguageModelRequestBuilder::effect).
+//! An effect receives a deterministic [`EffectContext`] describing the operation and the
+//! operation itself as a future, and decides how to run it. An adapter for Temporal,
+//! Restate, or a similar framework wraps the future in an activity keyed by
+//! [`EffectContext::key`], so every step becomes an idempotent, retryable unit whose
+//! result is journaled by the framework.
+//!
+//! Results cross the effect boundary as JSON so they can be persisted. [`ReplayEffect`]
+//! is the built-in implementation: it records every result in an [`EffectJournal`] and,
+//! when constructed from an existing journal, replays recorded results instead of
+//! running the operation again.
+//!
+//! Model calls made by `stream_text` are not routed through the effect, since a stream
+//! cannot be replayed as a single result. Its tool calls are.
+//!
+//! # Example
+//!
+//! ```
+//! use aisdk::core::effect::{Effect, EffectContext, EffectKind, ReplayEffect};
+//!
+//! # tokio_test_block_on(async {
+//! let effect = ReplayEffect::new();
+//! let ctx = EffectContext::new(1, EffectKind::ToolCall {
+//!     name: "search".to_string(),
+//!     id: "call_1".to_string(),
+//! });
+//!
+//! let live = effect
+//!     .execute(&ctx, Box::pin(async { Ok(serde_json::json!("42")) }))
+//!     .await
+//!     .unwrap();
+//!
+//! // Replaying the journal returns the recorded result without running the operation.
+//! let replay = ReplayEffect::from_journal(effect.journal());
+//! let replayed = replay
+//!     .execute(&ctx, Box::pin(async { unreachable!() }))
+//!     .await
+//!     .unwrap();
+//! assert_eq!(live, replayed);
+//! # });
+//! # fn tokio_test_block_on<F: std::future::Future>(f: F) -> F::Output {
+//! #     tokio::runtime::Runtime::new().unwrap().block_on(f)
+//! # }
+//! ```
+
+#[cfg(feature = "language-model-request")]
+use crate::error::Error;
+use crate::error::Result;
+use async_trait::async_trait;
+#[cfg(feature = "language-model-request")]
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+// ============================================================================
+// Section: traits
+// ============================================================================
+
+/// A side-effectful operation of the agent loop, producing a JSON result.
+pub type EffectFuture<'a> = Pin<Box<dyn Future<Output = Result<Value>> + Send + 'a>>;
+
+/// Runs the side-effectful operations of the agent loop.
+#[async_trait]
+pub trait Effect: Send + Sync {
+    /// Runs `operation`, or returns a previously recorded result for `ctx`.
+    ///
+    /// Implementations must return exactly what `operation` would have returned for
+    /// the same context, so that replays are deterministic.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the operation fails. Failed operations are not expected
+    /// to be recorded, so that they can be retried.
+    async fn execute(&self, ctx: &EffectContext, operation: EffectFuture<'_>) -> Result<Value>;
+}
+
+// ============================================================================
+// Section: context
+// ============================================================================
+
+/// The kind of operation being run through an [`Effect`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EffectKind {
+    /// A request to the language model.
+    ModelCall {
+        /// The name of the model.
+        model: String,
+    },
+    /// The execution of a tool requested by the model.
+    ToolCall {
+        /// The name of the tool.
+        name: String,
+        /// The id of the tool call, as assigned by the provider.
+        id: String,
+    },
+}
+
+/// Describes an operation run through an [`Effect`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EffectContext {
+    /// The step of the agent loop the operation belongs to.
+    pub step: usize,
+    /// The kind of operation.
+    pub kind: EffectKind,
+}
+
+impl EffectContext {
+    /// Creates a new context for an operation in the given step.
+    pub fn new(step: usize, kind: EffectKind) -> Self {
+        Self { step, kind }
+    }
+
+    /// Returns a key that identifies the operation across runs of the same loop,
+    /// e.g. `3/model` or `3/tool/call_abc`. Suitable as an activity or idempotency id.
+    pub fn key(&self) -> String {
+        match &self.kind {
+            EffectKind::ModelCall { .. } => format!("{}/model", self.step),
+            EffectKind::ToolCall { id, name } if id.is_empty() => {
+                format!("{}/tool/{name}", self.step)
+            }
+            EffectKind::ToolCall { id, .. } => format!("{}/tool/{id}", self.step),
+        }
+    }
+}
+
+// ============================================================================
+// Section: replay
+// ============================================================================
+
+/// Results recorded by a [`ReplayEffect`], keyed by [`EffectContext::key`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EffectJournal {
+    /// The recorded results.
+    pub entries: HashMap<String, Value>,
+}
+
+/// An [`Effect`] that journals results and replays them deterministically.
+///
+/// Clones share the same journal.
+#[derive(Debug, Clone, Default)]
+pub struct ReplayEffect {
+    journal: Arc<Mutex<EffectJournal>>,
+}
+
+impl ReplayEffect {
+    /// Creates an effect with an empty journal.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an effect that replays the results recorded in `journal`.
+    pub fn from_journal(journal: EffectJournal) -> Self {
+        Self {
+            journal: Arc::new(Mutex::new(journal)),
+        }
+    }
+
+    /// Returns a copy of the recorded results.
+    pub fn journal(&self) -> EffectJournal {
+        self.lock().clone()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, EffectJournal> {
+        self.journal
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[async_trait]
+impl Effect for ReplayEffect {
+    async fn execute(&self, ctx: &EffectContext, operation: EffectFuture<'_>) -> Result<Value> {
+        let key = ctx.key();
+        if let Some(recorded) = self.lock().entries.get(&key).cloned() {
+            return Ok(recorded);
+        }
+
+        let value = operation.await?;
+        self.lock().entries.insert(key, value.clone());
+        Ok(value)
+    }
+}
+
+// ============================================================================
+// Section: helpers
+// ============================================================================
+
+/// Runs `operation` through `effect`, converting its result to and from JSON.
+///
+/// When the operation actually runs, its original result is returned rather than the
+/// JSON round trip, so data that does not serialize (such as provider extensions) is
+/// only lost on replay.
+#[cfg(feature = "language-model-request")]
+pub(crate) async fn run_effect<T, F>(
+    effect: Option<&Arc<dyn Effect>>,
+    ctx: EffectContext,
+    operation: F,
+) -> Result<T>
+where
+    T: Serialize + DeserializeOwned + Send,
+    F: Future<Output = Result<T>> + Send,
+{
+    let Some(effect) = effect else {
+        return operation.await;
+    };
+
+    let live = Mutex::new(None);
+    let value = effect
+        .execute(
+            &ctx,
+            Box::pin(async {
+                let output = operation.await?;
+                let value = serde_json::to_value(&output)
+                    .map_err(|e| Error::Other(format!("Failed to record effect result: {e}")))?;
+                *live.lock().unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(output);
+                Ok(value)
+            }),
+        )
+        .await?;
+
+    match live
+        .into_inner()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+    {
+        Some(output) => Ok(output),
+        None => serde_json::from_value(value)
+            .map_err(|e| Error::Other(format!("Failed to replay effect result: {e}"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+    #[cfg(feature = "language-model-request")]
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn tool_ctx(id: &str) -> EffectContext {
+        EffectContext::new(
+            2,
+            EffectKind::ToolCall {
+                name: "search".to_string(),
+                id: id.to_string(),
+            },
+        )
+    }
+
+    #[test]
+    fn test_context_keys() {
+        let model = EffectContext::new(
+            1,
+            EffectKind::ModelCall {
+                model: "gpt-5".to_string(),
+            },
+        );
+        assert_eq!(model.key(), "1/model");
+        assert_eq!(tool_ctx("call_1").key(), "2/tool/call_1");
+        assert_eq!(tool_ctx("").key(), "2/tool/search");
+    }
+
+    #[cfg(feature = "language-model-request")]
+    #[tokio::test]
+    async fn test_run_effect_without_effect_runs_operation() {
+        let out: String = run_effect(None, tool_ctx("a"), async { Ok("done".to_string()) })
+            .await
+            .unwrap();
+        assert_eq!(out, "done");
+    }
+
+    #[cfg(feature = "language-model-request")]
+    #[tokio::test]
+    async fn test_replay_effect_runs_each_key_once() {
+        let calls = AtomicUsize::new(0);
+        let effect: Arc<dyn Effect> = Arc::new(ReplayEffect::new());
+
+        for _ in 0..2 {
+            let out: String = run_effect(Some(&effect), tool_ctx("a"), async {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Ok("done".to_string())
+            })
+            .await
+            .unwrap();
+            assert_eq!(out, "done");
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_failures_are_not_recorded() {
+        let effect = ReplayEffect::new();
+        let failed = effect
+            .execute(
+                &tool_ctx("a"),
+                Box::pin(async { Err(Error::ToolCallError("boom".to_string())) }),
+            )
+            .await;
+        assert!(failed.is_err());
+        assert!(effect.journal().entries.is_empty());
+    }
+
+    #[test]
+    fn test_journal_round_trip() {
+        let mut journal = EffectJournal::default();
+        journal
+            .entries
+            .insert("1/model".to_string(), serde_json::json!({ "contents": [] }));
+        let json = serde_json::to_string(&journal).unwrap();
+        let restored: EffectJournal = serde_json::from_str(&json).unwrap();
+        assert_eq!(journal, restored);
+    }
+}
@@ -28,7 +28,21 @@ impl<M: EmbeddingModel> EmbeddingModelRequest<M> {
     /// A Result containing a vector of embedding vectors, where each embedding is a vector of floats,
     /// or an error if the embedding request fails.
     pub async fn embed(&self) -> Result<EmbeddingModelResponse> {
-        self.model.embed(self.options.clone()).await
+        #[cfg(feature = "metrics")]
+        let metrics = crate::core::metrics::RequestMetrics::embeddings::<M>();
+
+        #[cfg(feature = "otel")]
+        let result = crate::core::telemetry::GenAiSpan::embeddings::<M>(&self.options)
+            .instrument(self.model.embed(self.options.clone()), |_, _| {})
+            .await;
+
+        #[cfg(not(feature = "otel"))]
+        let result = self.model.embed(self.options.clone()).await;
+
+        #[cfg(feature = "metrics")]
+        metrics.record(&result, |_| None);
+
+        result
     }
 }
 
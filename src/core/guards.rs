@@ -0,0 +1,678 @@
+//! Guards inspect and transform the text exchanged with a language model.
+//!
+//! An [`InputGuard`] runs on every message right before it is sent to the provider,
+//! and an [`OutputGuard`] runs on every piece of content the provider returns before
+//! it is recorded in the conversation history. While output guards are registered,
+//! streamed deltas are held back and each piece of content is sent as one guarded delta
+//! once the provider finishes it. Guards are registered on a request with
+//! [`with_input_guard`](crate::core::language_model::request::LanguageModelRequestBuilder::with_input_guard)
+//! and [`with_output_guard`](crate::core::language_model::request::LanguageModelRequestBuilder::with_output_guard).
+//!
+//! [`PiiGuard`] is the built-in guard. It detects emails, phone numbers, credit card
+//! numbers, and person names, swaps them for stable placeholders before the request
+//! leaves the process, and optionally restores the original values in the model output.
+//!
+//! # Example
+//!
+//! ```
+//! use aisdk::core::guards::{InputGuard, OutputGuard, PiiGuard};
+//!
+//! # tokio_test_block_on(async {
+//! let guard = PiiGuard::new().restore_output(true);
+//!
+//! let redacted = guard.guard_input("Mail jane@example.com").await.unwrap();
+//! assert_eq!(redacted, "Mail <EMAIL_1>");
+//!
+//! let restored = guard.guard_output("Sent to <EMAIL_1>").unwrap();
+//! assert_eq!(restored, "Sent to jane@example.com");
+//! # });
+//! # fn tokio_test_block_on<F: std::future::Future>(f: F) -> F::Output {
+//! #     tokio::runtime::Runtime::new().unwrap().block_on(f)
+//! # }
+//! ```
+
+#[cfg(feature = "language-model-request")]
+use crate::core::language_model::{LanguageModelOptions, LanguageModelResponseContentType};
+#[cfg(feature = "language-model-request")]
+use crate::core::messages::{ContentPart, Message, text_of};
+#[cfg(feature = "language-model-request")]
+use crate::core::tools::ToolCallInfo;
+#[cfg(feature = "language-model-request")]
+use crate::error::Error;
+use crate::error::Result;
+use async_trait::async_trait;
+use regex::Regex;
+#[cfg(feature = "language-model-request")]
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, Mutex};
+
+// ============================================================================
+// Section: traits
+// ============================================================================
+
+/// Transforms text before it is sent to the provider.
+#[async_trait]
+pub trait InputGuard: Send + Sync {
+    /// Returns the text that should be sent to the provider in place of `text`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the guard rejects the input or fails to process it.
+    async fn guard_input(&self, text: &str) -> Result<String>;
+}
+
+/// Transforms text returned by the provider before it is recorded.
+pub trait OutputGuard: Send + Sync {
+    /// Returns the text that should be recorded in place of `text`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the guard rejects the output.
+    fn guard_output(&self, text: &str) -> Result<String>;
+}
+
+/// Detects entities that regular expressions cannot reliably find, e.g. person names.
+///
+/// Implementations usually call a named-entity recognition model.
+#[async_trait]
+pub trait EntityDetector: Send + Sync {
+    /// Returns the literal entity strings found in `text`.
+    async fn detect(&self, text: &str) -> Result<Vec<String>>;
+}
+
+// ============================================================================
+// Section: pii guard
+// ============================================================================
+
+/// The categories of personally identifiable information a [`PiiGuard`] can detect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PiiKind {
+    /// Email addresses.
+    Email,
+    /// Phone numbers with 10 to 15 digits.
+    Phone,
+    /// Credit card numbers that pass the Luhn check.
+    CreditCard,
+    /// Person names, from the configured name list or an [`EntityDetector`].
+    Name,
+}
+
+impl PiiKind {
+    /// The label used inside placeholders, e.g. `EMAIL` in `<EMAIL_1>`.
+    fn label(&self) -> &'static str {
+        match self {
+            PiiKind::Email => "EMAIL",
+            PiiKind::Phone => "PHONE",
+            PiiKind::CreditCard => "CREDIT_CARD",
+            PiiKind::Name => "NAME",
+        }
+    }
+}
+
+static EMAIL_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Za-z]{2,}").expect("valid email regex")
+});
+
+static CREDIT_CARD_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\b(?:\d[ -]?){12,18}\d\b").expect("valid credit card regex"));
+
+static PHONE_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\+?\(?\d[\d\s().-]{7,}\d").expect("valid phone regex"));
+
+static PLACEHOLDER_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"<(?:EMAIL|PHONE|CREDIT_CARD|NAME)_\d+>").expect("valid placeholder regex")
+});
+
+/// The bidirectional mapping between original values and their placeholders.
+#[derive(Debug, Default)]
+struct PiiVault {
+    by_value: HashMap<String, String>,
+    by_placeholder: HashMap<String, String>,
+    counters: HashMap<PiiKind, usize>,
+}
+
+impl PiiVault {
+    /// Returns the placeholder for `value`, allocating a new one on first sight.
+    fn placeholder(&mut self, kind: PiiKind, value: &str) -> String {
+        if let Some(existing) = self.by_value.get(value) {
+            return existing.clone();
+        }
+        let counter = self.counters.entry(kind).or_default();
+        *counter += 1;
+        let placeholder = format!("<{}_{}>", kind.label(), counter);
+        self.by_value.insert(value.to_string(), placeholder.clone());
+        self.by_placeholder
+            .insert(placeholder.clone(), value.to_string());
+        placeholder
+    }
+}
+
+/// Redacts personally identifiable information with reversible placeholders.
+///
+/// Each distinct value is replaced with the same placeholder for the lifetime of the
+/// guard (e.g. `<EMAIL_1>`), so the model can still reason about which entity is which.
+/// Clones share the same placeholder mapping, which makes it possible to register a
+/// single guard as both the input and the output guard of a request.
+///
+/// Output restoration is disabled by default, in which case the model output keeps
+/// the placeholders.
+#[derive(Clone)]
+pub struct PiiGuard {
+    kinds: Vec<PiiKind>,
+    names: Vec<String>,
+    detector: Option<Arc<dyn EntityDetector>>,
+    restore: bool,
+    vault: Arc<Mutex<PiiVault>>,
+}
+
+impl std::fmt::Debug for PiiGuard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PiiGuard")
+            .field("kinds", &self.kinds)
+            .field("names", &self.names.len())
+            .field("detector", &self.detector.is_some())
+            .field("restore", &self.restore)
+            .finish()
+    }
+}
+
+impl Default for PiiGuard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PiiGuard {
+    /// Creates a guard that detects emails, phone numbers, credit cards, and names.
+    ///
+    /// Names are only detected once a name list or an [`EntityDetector`] is configured.
+    pub fn new() -> Self {
+        Self {
+            kinds: vec![
+                PiiKind::Email,
+                PiiKind::CreditCard,
+                PiiKind::Phone,
+                PiiKind::Name,
+            ],
+            names: Vec::new(),
+            detector: None,
+            restore: false,
+            vault: Arc::new(Mutex::new(PiiVault::default())),
+        }
+    }
+
+    /// Restricts detection to the given kinds of PII.
+    pub fn kinds(mut self, kinds: impl Into<Vec<PiiKind>>) -> Self {
+        self.kinds = kinds.into();
+        self
+    }
+
+    /// Adds known person names that should always be redacted.
+    pub fn names<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.names.extend(names.into_iter().map(Into::into));
+        self
+    }
+
+    /// Sets a detector used to find person names, e.g. a NER model.
+    pub fn with_detector(mut self, detector: impl EntityDetector + 'static) -> Self {
+        self.detector = Some(Arc::new(detector));
+        self
+    }
+
+    /// Sets whether placeholders are restored to the original values in the output.
+    pub fn restore_output(mut self, restore: bool) -> Self {
+        self.restore = restore;
+        self
+    }
+
+    /// Returns the original value behind a placeholder, if it was issued by this guard.
+    pub fn original(&self, placeholder: &str) -> Option<String> {
+        self.vault
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .by_placeholder
+            .get(placeholder)
+            .cloned()
+    }
+
+    /// Replaces every detected value in `text` with its placeholder.
+    fn redact(&self, text: &str, detected_names: &[String]) -> String {
+        let mut matches: Vec<(usize, usize, PiiKind)> = Vec::new();
+
+        for kind in &self.kinds {
+            match kind {
+                PiiKind::Email => matches.extend(
+                    EMAIL_RE
+                        .find_iter(text)
+                        .map(|m| (m.start(), m.end(), *kind)),
+                ),
+                PiiKind::CreditCard => matches.extend(
+                    CREDIT_CARD_RE
+                        .find_iter(text)
+                        .filter(|m| luhn_valid(m.as_str()))
+                        .map(|m| (m.start(), m.end(), *kind)),
+                ),
+                PiiKind::Phone => matches.extend(
+                    PHONE_RE
+                        .find_iter(text)
+                        .filter(|m| (10..=15).contains(&digit_count(m.as_str())))
+                        .map(|m| (m.start(), m.end(), *kind)),
+                ),
+                PiiKind::Name => {
+                    for name in self.names.iter().chain(detected_names) {
+                        if name.is_empty() {
+                            continue;
+                        }
+                        matches.extend(
+                            text.match_indices(name.as_str())
+                                .map(|(start, m)| (start, start + m.len(), *kind)),
+                        );
+                    }
+                }
+            }
+        }
+
+        // Longer matches win when two start at the same position; overlaps are skipped.
+        matches.sort_by_key(|(start, end, _)| (*start, std::cmp::Reverse(*end)));
+
+        let mut vault = self
+            .vault
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let mut redacted = String::with_capacity(text.len());
+        let mut cursor = 0;
+        for (start, end, kind) in matches {
+            if start < cursor || is_placeholder_at(text, start, end) {
+                continue;
+            }
+            redacted.push_str(&text[cursor..start]);
+            redacted.push_str(&vault.placeholder(kind, &text[start..end]));
+            cursor = end;
+        }
+        redacted.push_str(&text[cursor..]);
+        redacted
+    }
+}
+
+#[async_trait]
+impl InputGuard for PiiGuard {
+    async fn guard_input(&self, text: &str) -> Result<String> {
+        let detected = match (&self.detector, self.kinds.contains(&PiiKind::Name)) {
+            (Some(detector), true) => detector.detect(text).await?,
+            _ => Vec::new(),
+        };
+        Ok(self.redact(text, &detected))
+    }
+}
+
+impl OutputGuard for PiiGuard {
+    fn guard_output(&self, text: &str) -> Result<String> {
+        if !self.restore {
+            return Ok(text.to_string());
+        }
+        let vault = self
+            .vault
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        Ok(PLACEHOLDER_RE
+            .replace_all(text, |caps: &regex::Captures| {
+                vault
+                    .by_placeholder
+                    .get(&caps[0])
+                    .cloned()
+                    .unwrap_or_else(|| caps[0].to_string())
+            })
+            .into_owned())
+    }
+}
+
+/// Returns `true` if the match is itself part of a placeholder issued earlier.
+fn is_placeholder_at(text: &str, start: usize, end: usize) -> bool {
+    PLACEHOLDER_RE
+        .find_iter(text)
+        .any(|p| p.start() <= start && end <= p.end())
+}
+
+fn digit_count(s: &str) -> usize {
+    s.chars().filter(char::is_ascii_digit).count()
+}
+
+/// Validates a card number with the Luhn checksum.
+fn luhn_valid(s: &str) -> bool {
+    let digits: Vec<u32> = s.chars().filter_map(|c| c.to_digit(10)).collect();
+    if !(13..=19).contains(&digits.len()) {
+        return false;
+    }
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, d)| {
+            if i % 2 == 1 {
+                let doubled = d * 2;
+                if doubled > 9 { doubled - 9 } else { doubled }
+            } else {
+                *d
+            }
+        })
+        .sum();
+    sum.is_multiple_of(10)
+}
+
+// ============================================================================
+// Section: model backed detector
+// ============================================================================
+
+/// An [`EntityDetector`] that asks a language model to extract person names.
+#[cfg(feature = "language-model-request")]
+#[derive(Debug, Clone)]
+pub struct LanguageModelEntityDetector<M: crate::core::LanguageModel> {
+    model: M,
+}
+
+#[cfg(feature = "language-model-request")]
+impl<M: crate::core::LanguageModel> LanguageModelEntityDetector<M> {
+    /// Creates a detector backed by the given model.
+    pub fn new(model: M) -> Self {
+        Self { model }
+    }
+}
+
+#[cfg(feature = "language-model-request")]
+#[async_trait]
+impl<M> EntityDetector for LanguageModelEntityDetector<M>
+where
+    M: crate::core::LanguageModel + crate::core::capabilities::TextInputSupport,
+{
+    async fn detect(&self, text: &str) -> Result<Vec<String>> {
+        let response = crate::core::LanguageModelRequest::builder()
+            .model(self.model.clone())
+            .system(
+                "Extract every person name that appears in the user's text. \
+                 Reply with a JSON array of the names exactly as written, and nothing else. \
+                 Reply with [] if there are none.",
+            )
+            .prompt(text)
+            .build()
+            .generate_text()
+            .await?;
+        let reply = response.text().unwrap_or_default();
+        let start = reply.find('[').unwrap_or(0);
+        let end = reply.rfind(']').map(|i| i + 1).unwrap_or(reply.len());
+        serde_json::from_str(&reply[start..end])
+            .map_err(|e| Error::GuardError(format!("Invalid entity detector reply: {e}")))
+    }
+}
+
+// ============================================================================
+// Section: helpers
+// ============================================================================
+
+#[cfg(feature = "language-model-request")]
+impl LanguageModelOptions {
+    /// Returns a copy of the options with every input guard applied to the
+    /// system prompt and all messages.
+    pub(crate) async fn apply_input_guards(&self) -> Result<LanguageModelOptions> {
+        let mut options = self.clone();
+        if self.input_guards.is_empty() {
+            return Ok(options);
+        }
+
+        let guards = self.input_guards.clone();
+        let guard = |text: String| {
+            let guards = guards.clone();
+            async move {
+                let mut text = text;
+                for guard in &guards {
+                    text = guard.guard_input(&text).await?;
+                }
+                Ok::<String, Error>(text)
+            }
+        };
+
+        if let Some(system) = options.system.take() {
+            options.system = Some(guard(system).await?);
+        }
+
+        for tagged in options.messages.iter_mut() {
+            match &mut tagged.message {
+                Message::System(m) => m.content = guard(std::mem::take(&mut m.content)).await?,
+                Message::User(m) if m.parts.is_empty() => {
+                    m.content = guard(std::mem::take(&mut m.content)).await?
+                }
+                Message::User(m) => {
+                    for part in m.parts.iter_mut() {
+                        if let ContentPart::Text(text) = part {
+                            *text = guard(std::mem::take(text)).await?;
+                        }
+                    }
+                    m.content = text_of(&m.parts);
+                }
+                Message::Developer(d) => *d = guard(std::mem::take(d)).await?,
+                Message::Assistant(m) => match &mut m.content {
+                    LanguageModelResponseContentType::Text(t) => {
+                        *t = guard(std::mem::take(t)).await?
+                    }
+                    LanguageModelResponseContentType::Reasoning { content, .. } => {
+                        *content = guard(std::mem::take(content)).await?
+                    }
+                    LanguageModelResponseContentType::Refusal(refusal) => {
+                        *refusal = guard(std::mem::take(refusal)).await?
+                    }
+                    LanguageModelResponseContentType::ToolCall(info) => {
+                        let input = std::mem::take(&mut info.input);
+                        info.input = map_json_strings_async(input, &guard).await?;
+                    }
+                    LanguageModelResponseContentType::Source(_)
+                    | LanguageModelResponseContentType::Image(_)
+                    | LanguageModelResponseContentType::NotSupported(_) => {}
+                },
+                Message::Tool(info) => {
+                    if let Ok(output) = &mut info.output {
+                        let value = std::mem::take(output);
+                        *output = map_json_strings_async(value, &guard).await?;
+                    }
+                }
+            }
+        }
+
+        Ok(options)
+    }
+
+    /// Applies every output guard to a piece of generated content.
+    pub(crate) fn apply_output_guards(
+        &self,
+        content: LanguageModelResponseContentType,
+    ) -> Result<LanguageModelResponseContentType> {
+        if self.output_guards.is_empty() {
+            return Ok(content);
+        }
+        let guard = |text: &str| {
+            self.output_guards
+                .iter()
+                .try_fold(text.to_string(), |acc, g| g.guard_output(&acc))
+        };
+        Ok(match content {
+            LanguageModelResponseContentType::Text(text) => {
+                LanguageModelResponseContentType::Text(guard(&text)?)
+            }
+            LanguageModelResponseContentType::Refusal(refusal) => {
+                LanguageModelResponseContentType::Refusal(guard(&refusal)?)
+            }
+            LanguageModelResponseContentType::Reasoning {
+                content,
+                extensions,
+            } => LanguageModelResponseContentType::Reasoning {
+                content: guard(&content)?,
+                extensions,
+            },
+            LanguageModelResponseContentType::ToolCall(info) => {
+                LanguageModelResponseContentType::ToolCall(ToolCallInfo {
+                    input: map_json_strings(info.input, &guard)?,
+                    ..info
+                })
+            }
+            other => other,
+        })
+    }
+}
+
+/// Applies `f` to every string inside a JSON value.
+#[cfg(feature = "language-model-request")]
+fn map_json_strings<F>(value: Value, f: &F) -> Result<Value>
+where
+    F: Fn(&str) -> Result<String>,
+{
+    Ok(match value {
+        Value::String(s) => Value::String(f(&s)?),
+        Value::Array(items) => Value::Array(
+            items
+                .into_iter()
+                .map(|v| map_json_strings(v, f))
+                .collect::<Result<_>>()?,
+        ),
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .map(|(k, v)| Ok((k, map_json_strings(v, f)?)))
+                .collect::<Result<_>>()?,
+        ),
+        other => other,
+    })
+}
+
+/// Applies the async `f` to every string inside a JSON value.
+#[cfg(feature = "language-model-request")]
+async fn map_json_strings_async<F, Fut>(value: Value, f: &F) -> Result<Value>
+where
+    F: Fn(String) -> Fut,
+    Fut: std::future::Future<Output = Result<String>>,
+{
+    Ok(match value {
+        Value::String(s) => Value::String(f(s).await?),
+        Value::Array(items) => {
+            let mut out = Vec::with_capacity(items.len());
+            for item in items {
+                out.push(Box::pin(map_json_strings_async(item, f)).await?);
+            }
+            Value::Array(out)
+        }
+        Value::Object(map) => {
+            let mut out = serde_json::Map::with_capacity(map.len());
+            for (k, v) in map {
+                out.insert(k, Box::pin(map_json_strings_async(v, f)).await?);
+            }
+            Value::Object(out)
+        }
+        other => other,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_pii_guard_redacts_email_phone_and_card() {
+        let guard = PiiGuard::new();
+        let redacted = guard
+            .guard_input("jane@example.com, +1 (555) 123-4567, card 4111 1111 1111 1111")
+            .await
+            .unwrap();
+        assert_eq!(redacted, "<EMAIL_1>, <PHONE_1>, card <CREDIT_CARD_1>");
+    }
+
+    #[tokio::test]
+    async fn test_pii_guard_placeholders_are_stable() {
+        let guard = PiiGuard::new();
+        let first = guard.guard_input("a@b.io and c@d.io").await.unwrap();
+        let second = guard.guard_input("c@d.io again").await.unwrap();
+        assert_eq!(first, "<EMAIL_1> and <EMAIL_2>");
+        assert_eq!(second, "<EMAIL_2> again");
+    }
+
+    #[tokio::test]
+    async fn test_pii_guard_ignores_invalid_card_numbers() {
+        let guard = PiiGuard::new().kinds(vec![PiiKind::CreditCard]);
+        let redacted = guard.guard_input("4111 1111 1111 1112").await.unwrap();
+        assert_eq!(redacted, "4111 1111 1111 1112");
+    }
+
+    #[tokio::test]
+    async fn test_pii_guard_redacts_known_names() {
+        let guard = PiiGuard::new().names(["Ada Lovelace"]);
+        let redacted = guard.guard_input("Ask Ada Lovelace").await.unwrap();
+        assert_eq!(redacted, "Ask <NAME_1>");
+        assert_eq!(guard.original("<NAME_1>"), Some("Ada Lovelace".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_pii_guard_uses_entity_detector() {
+        struct Fixed;
+        #[async_trait]
+        impl EntityDetector for Fixed {
+            async fn detect(&self, _: &str) -> Result<Vec<String>> {
+                Ok(vec!["Grace".to_string()])
+            }
+        }
+
+        let guard = PiiGuard::new().with_detector(Fixed);
+        let redacted = guard.guard_input("Hello Grace").await.unwrap();
+        assert_eq!(redacted, "Hello <NAME_1>");
+    }
+
+    #[tokio::test]
+    async fn test_pii_guard_restores_output_only_when_enabled() {
+        let guard = PiiGuard::new();
+        guard.guard_input("x@y.com").await.unwrap();
+        assert_eq!(guard.guard_output("<EMAIL_1>").unwrap(), "<EMAIL_1>");
+
+        let restoring = guard.clone().restore_output(true);
+        assert_eq!(restoring.guard_output("<EMAIL_1>").unwrap(), "x@y.com");
+        assert_eq!(restoring.guard_output("<EMAIL_9>").unwrap(), "<EMAIL_9>");
+    }
+
+    #[test]
+    fn test_luhn_valid() {
+        assert!(luhn_valid("4111111111111111"));
+        assert!(luhn_valid("5500-0000-0000-0004"));
+        assert!(!luhn_valid("1234567812345678"));
+        assert!(!luhn_valid("4111"));
+    }
+
+    #[cfg(feature = "language-model-request")]
+    #[tokio::test]
+    async fn test_apply_input_guards_redacts_messages_and_tool_inputs() {
+        let guard = PiiGuard::new();
+        let mut call = ToolCallInfo::new("send");
+        call.input(serde_json::json!({ "to": "a@b.io" }));
+        let options = LanguageModelOptions {
+            system: Some("Reply to a@b.io".to_string()),
+            messages: vec![
+                Message::User("from a@b.io".into()).into(),
+                Message::Assistant(crate::core::AssistantMessage::new(
+                    LanguageModelResponseContentType::ToolCall(call),
+                    None,
+                ))
+                .into(),
+            ],
+            input_guards: vec![Arc::new(guard)],
+            ..Default::default()
+        };
+
+        let guarded = options.apply_input_guards().await.unwrap();
+        assert_eq!(guarded.system.as_deref(), Some("Reply to <EMAIL_1>"));
+        let calls = guarded.tool_calls().unwrap();
+        assert_eq!(calls[0].input["to"], "<EMAIL_1>");
+        match &guarded.messages[0].message {
+            Message::User(u) => assert_eq!(u.content, "from <EMAIL_1>"),
+            _ => panic!("expected user message"),
+        }
+        // The original options are untouched.
+        assert_eq!(options.system.as_deref(), Some("Reply to a@b.io"));
+    }
+}
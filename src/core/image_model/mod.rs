@@ -0,0 +1,127 @@
+//! Image model
+//!
+//! An image generation model creates images from a text prompt.
+
+use crate::error::Result;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use derive_builder::Builder;
+
+/// The ratio of the width to the height of an image, e.g. `16:9`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct AspectRatio {
+    /// The width part of the ratio.
+    pub width: u32,
+    /// The height part of the ratio.
+    pub height: u32,
+}
+
+impl AspectRatio {
+    /// Creates an aspect ratio of `width:height`.
+    pub const fn new(width: u32, height: u32) -> Self {
+        Self { width, height }
+    }
+}
+
+impl std::fmt::Display for AspectRatio {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.width, self.height)
+    }
+}
+
+/// The dimensions of an image in pixels, e.g. `1024x1024`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ImageSize {
+    /// The width in pixels.
+    pub width: u32,
+    /// The height in pixels.
+    pub height: u32,
+}
+
+impl ImageSize {
+    /// Creates an image size of `width` by `height` pixels.
+    pub const fn new(width: u32, height: u32) -> Self {
+        Self { width, height }
+    }
+}
+
+impl std::fmt::Display for ImageSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}x{}", self.width, self.height)
+    }
+}
+
+/// The options for image generation requests.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(into))]
+pub struct ImageModelOptions {
+    /// A description of the image to generate.
+    pub prompt: String,
+    /// A description of what the image should not contain.
+    #[builder(default)]
+    pub negative_prompt: Option<String>,
+    /// The aspect ratio of the image. The provider's default is used when unset.
+    #[builder(default)]
+    pub aspect_ratio: Option<AspectRatio>,
+    /// The seed of the generation, to reproduce an image. A random seed is used when
+    /// unset.
+    #[builder(default)]
+    pub seed: Option<u64>,
+    /// The size of the image, for providers that take exact dimensions rather than an
+    /// aspect ratio.
+    #[builder(default)]
+    pub size: Option<ImageSize>,
+    /// The quality of the image, e.g. `"high"` or `"hd"`. The accepted values are
+    /// provider specific.
+    #[builder(default)]
+    pub quality: Option<String>,
+    /// The number of images to generate. Defaults to one.
+    #[builder(default)]
+    pub n: Option<u32>,
+}
+
+impl ImageModelOptions {
+    /// Returns the image options builder.
+    pub fn builder() -> ImageModelOptionsBuilder {
+        ImageModelOptionsBuilder::default()
+    }
+}
+
+/// The core trait abstracting the capabilities of an image generation model.
+#[async_trait]
+pub trait ImageGenerationModel: Clone + Send + Sync + std::fmt::Debug + 'static {
+    /// Generates images from a text prompt.
+    async fn generate_image(&self, input: ImageModelOptions) -> Result<ImageModelResponse>;
+}
+
+/// The data of a generated image.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ImageData {
+    /// The encoded image.
+    Bytes(Vec<u8>),
+    /// The encoded image, base64 encoded.
+    Base64(String),
+    /// A URL the image can be downloaded from.
+    Url(String),
+}
+
+/// A generated image.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GeneratedImage {
+    /// The image data.
+    pub data: ImageData,
+    /// The media type of the image, e.g. `image/png`, if known.
+    pub media_type: Option<String>,
+    /// The seed the image was generated with, if the provider reports it.
+    pub seed: Option<u64>,
+    /// The prompt the provider rewrote the request's prompt into, if it reports it.
+    pub revised_prompt: Option<String>,
+}
+
+/// The response type for image generation requests.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImageModelResponse {
+    /// The generated images.
+    pub images: Vec<GeneratedImage>,
+}
@@ -0,0 +1,309 @@
+//! Language models chosen at runtime.
+//!
+//! [`LanguageModel`] is generic over its clones and async methods, so it cannot be used
+//! as a trait object. [`DynLanguageModel`] is its object safe counterpart, implemented
+//! by every language model, and [`BoxedLanguageModel`] a boxed one, which is itself a
+//! language model.
+//!
+//! The model in a box is only known at runtime, so a boxed model has every capability
+//! marker trait, like a [`DynamicModel`](crate::core::DynamicModel), and any request
+//! builder method is available. Its calls check the
+//! [`capabilities`](LanguageModel::capabilities) of the model instead, and are
+//! rejected before they are sent if they use tools, structured output, reasoning or
+//! image or audio input the model is known not to support. When its capabilities are
+//! unknown, it is left to the provider to reject the call.
+//!
+//! Every provider, and the [`fallback`](super::fallback), [`router`](super::router) and
+//! [`middleware`](super::middleware) wrappers, converts into a boxed model with
+//! [`From`], so models of different providers can be stored, swapped and chained.
+//!
+//! # Example
+//!
+//! ```rust
+//! # #[cfg(all(feature = "openai", feature = "anthropic"))]
+//! # {
+//! use aisdk::core::language_model::boxed::BoxedLanguageModel;
+//! use aisdk::core::language_model::fallback::fallback;
+//! use aisdk::providers::{Anthropic, OpenAI};
+//!
+//! struct Assistant {
+//!     model: BoxedLanguageModel,
+//! }
+//!
+//! let mut assistant = Assistant {
+//!     model: OpenAI::gpt_5().into(),
+//! };
+//! let chain = fallback::<BoxedLanguageModel>(
+//!     OpenAI::gpt_5().into(),
+//!     [Anthropic::claude_sonnet_4_5().into()],
+//! );
+//! assistant.model = chain.into();
+//! # }
+//! ```
+
+use crate::core::Message;
+use crate::core::capabilities::{
+    AudioInputSupport, AudioOutputSupport, Capabilities, Capability, ImageInputSupport,
+    ImageOutputSupport, ModelInfo, ReasoningSupport, StructuredOutputSupport, TextInputSupport,
+    TextOutputSupport, ToolCallSupport, VideoInputSupport, VideoOutputSupport,
+};
+use crate::core::language_model::{
+    LanguageModel, LanguageModelOptions, LanguageModelResponse, ProviderStream, SamplingOption,
+};
+use crate::core::messages::ContentPart;
+use crate::error::{Error, Result};
+use async_trait::async_trait;
+
+/// A boxed language model, chosen at runtime.
+pub type BoxedLanguageModel = Box<dyn DynLanguageModel>;
+
+/// The object safe counterpart of [`LanguageModel`], implemented by every language
+/// model.
+#[async_trait]
+pub trait DynLanguageModel: Send + Sync + std::fmt::Debug + 'static {
+    /// See [`LanguageModel::name`].
+    fn name(&self) -> String;
+
+    /// See [`LanguageModel::context_length`].
+    fn context_length(&self) -> Option<usize>;
+
+    /// See [`LanguageModel::unsupported_options`].
+    fn unsupported_options(&self) -> &'static [SamplingOption];
+
+    /// See [`LanguageModel::capabilities`].
+    fn capabilities(&self) -> Capabilities;
+
+    /// See [`LanguageModel::info`].
+    fn info(&self) -> ModelInfo;
+
+    /// See [`LanguageModel::count_tokens`].
+    async fn count_tokens(&self, options: &LanguageModelOptions) -> Result<usize>;
+
+    /// See [`LanguageModel::generate_text`].
+    async fn generate_text(
+        &mut self,
+        options: LanguageModelOptions,
+    ) -> Result<LanguageModelResponse>;
+
+    /// See [`LanguageModel::stream_text`].
+    async fn stream_text(&mut self, options: LanguageModelOptions) -> Result<ProviderStream>;
+
+    /// Clones the model into a box.
+    fn clone_box(&self) -> BoxedLanguageModel;
+}
+
+#[async_trait]
+impl<M: LanguageModel> DynLanguageModel for M {
+    fn name(&self) -> String {
+        LanguageModel::name(self)
+    }
+
+    fn context_length(&self) -> Option<usize> {
+        LanguageModel::context_length(self)
+    }
+
+    fn unsupported_options(&self) -> &'static [SamplingOption] {
+        LanguageModel::unsupported_options(self)
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        LanguageModel::capabilities(self)
+    }
+
+    fn info(&self) -> ModelInfo {
+        LanguageModel::info(self)
+    }
+
+    async fn count_tokens(&self, options: &LanguageModelOptions) -> Result<usize> {
+        LanguageModel::count_tokens(self, options).await
+    }
+
+    async fn generate_text(
+        &mut self,
+        options: LanguageModelOptions,
+    ) -> Result<LanguageModelResponse> {
+        LanguageModel::generate_text(self, options).await
+    }
+
+    async fn stream_text(&mut self, options: LanguageModelOptions) -> Result<ProviderStream> {
+        LanguageModel::stream_text(self, options).await
+    }
+
+    fn clone_box(&self) -> BoxedLanguageModel {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for BoxedLanguageModel {
+    fn clone(&self) -> Self {
+        self.as_ref().clone_box()
+    }
+}
+
+#[async_trait]
+impl LanguageModel for BoxedLanguageModel {
+    fn name(&self) -> String {
+        self.as_ref().name()
+    }
+
+    fn context_length(&self) -> Option<usize> {
+        self.as_ref().context_length()
+    }
+
+    fn unsupported_options(&self) -> &'static [SamplingOption] {
+        self.as_ref().unsupported_options()
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        self.as_ref().capabilities()
+    }
+
+    fn info(&self) -> ModelInfo {
+        self.as_ref().info()
+    }
+
+    async fn count_tokens(&self, options: &LanguageModelOptions) -> Result<usize> {
+        self.as_ref().count_tokens(options).await
+    }
+
+    async fn generate_text(
+        &mut self,
+        options: LanguageModelOptions,
+    ) -> Result<LanguageModelResponse> {
+        check_capabilities(self.as_ref(), &options)?;
+        self.as_mut().generate_text(options).await
+    }
+
+    async fn stream_text(&mut self, options: LanguageModelOptions) -> Result<ProviderStream> {
+        check_capabilities(self.as_ref(), &options)?;
+        self.as_mut().stream_text(options).await
+    }
+}
+
+/// Rejects `options` if they use a capability `model` is known not to have.
+fn check_capabilities(model: &dyn DynLanguageModel, options: &LanguageModelOptions) -> Result<()> {
+    let mut used = Vec::new();
+    if options.tools.is_some() {
+        used.push(Capability::ToolCall);
+    }
+    if options.schema.is_some() {
+        used.push(Capability::StructuredOutput);
+    }
+    if options.reasoning_effort.is_some() {
+        used.push(Capability::Reasoning);
+    }
+    for tagged in &options.messages {
+        let Message::User(message) = &tagged.message else {
+            continue;
+        };
+        for part in &message.parts {
+            match part {
+                ContentPart::Image(_) => used.push(Capability::ImageInput),
+                ContentPart::Audio(_) => used.push(Capability::AudioInput),
+                _ => {}
+            }
+        }
+    }
+
+    let capabilities = model.capabilities();
+    match used
+        .into_iter()
+        .find(|capability| capabilities.supports(*capability) == Some(false))
+    {
+        Some(capability) => Err(Error::InvalidInput(format!(
+            "model `{}` does not support {capability:?}",
+            model.name()
+        ))),
+        None => Ok(()),
+    }
+}
+
+impl ToolCallSupport for BoxedLanguageModel {}
+impl ReasoningSupport for BoxedLanguageModel {}
+impl StructuredOutputSupport for BoxedLanguageModel {}
+impl TextInputSupport for BoxedLanguageModel {}
+impl VideoInputSupport for BoxedLanguageModel {}
+impl AudioInputSupport for BoxedLanguageModel {}
+impl ImageInputSupport for BoxedLanguageModel {}
+impl TextOutputSupport for BoxedLanguageModel {}
+impl VideoOutputSupport for BoxedLanguageModel {}
+impl AudioOutputSupport for BoxedLanguageModel {}
+impl ImageOutputSupport for BoxedLanguageModel {}
+
+#[cfg(all(test, feature = "language-model-request"))]
+mod tests {
+    use super::*;
+    use crate::core::language_model::LanguageModelResponseContentType;
+    use crate::core::test_model::ScriptedModel;
+    use futures::StreamExt;
+    use schemars::json_schema;
+
+    fn boxed(model: &ScriptedModel) -> BoxedLanguageModel {
+        Box::new(model.clone())
+    }
+
+    #[tokio::test]
+    async fn test_dispatches_to_the_boxed_model() {
+        let scripted = ScriptedModel::new("scripted").text("Hi");
+        let mut model = boxed(&scripted);
+
+        assert_eq!(LanguageModel::name(&model), "scripted");
+        let response = LanguageModel::generate_text(&mut model, LanguageModelOptions::default())
+            .await
+            .unwrap();
+        assert!(matches!(
+            &response.contents[..],
+            [LanguageModelResponseContentType::Text(text)] if text == "Hi"
+        ));
+        let stream = LanguageModel::stream_text(&mut model, LanguageModelOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(stream.count().await, 1);
+        assert_eq!(scripted.calls(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_clones_the_boxed_model() {
+        let scripted = ScriptedModel::new("scripted");
+        let model = boxed(&scripted);
+        let mut clone = model.clone();
+
+        assert_eq!(LanguageModel::name(&clone), "scripted");
+        LanguageModel::generate_text(&mut clone, LanguageModelOptions::default())
+            .await
+            .unwrap();
+        // The clone is a clone of the boxed model, sharing its count of calls
+        assert_eq!(scripted.calls(), 1);
+        assert!(!std::ptr::addr_eq(model.as_ref(), clone.as_ref()));
+    }
+
+    #[tokio::test]
+    async fn test_rejects_calls_using_missing_capabilities() {
+        let options = || LanguageModelOptions {
+            schema: Some(json_schema!({ "type": "object" })),
+            ..Default::default()
+        };
+        let text_only = ScriptedModel::new("text").capabilities(Capabilities::new(&[
+            Capability::TextInput,
+            Capability::TextOutput,
+        ]));
+        let mut model = boxed(&text_only);
+
+        let result = LanguageModel::generate_text(&mut model, options()).await;
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+        assert!(
+            LanguageModel::stream_text(&mut model, options())
+                .await
+                .is_err()
+        );
+        assert_eq!(text_only.calls(), 0);
+
+        // Calls to models of unknown capabilities are sent
+        let unknown = ScriptedModel::new("unknown");
+        assert!(
+            LanguageModel::generate_text(&mut boxed(&unknown), options())
+                .await
+                .is_ok()
+        );
+    }
+}
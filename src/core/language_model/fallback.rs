@@ -0,0 +1,365 @@
+//! Fallback across language models.
+//!
+//! [`fallback`] chains a primary model with backups into a [`FallbackModel`], which is
+//! itself a language model. Each call goes to the primary model first, and to the next
+//! model when one fails with an error worth another attempt, see
+//! [`should_fall_back`]: a rate limit, an overloaded or failing server, a timeout, or a
+//! content filter. Other errors are returned as is.
+//!
+//! The model that served a generation is recorded in the response extensions as
+//! [`ServedBy`]. A stream falls back when it cannot be opened or its first event is
+//! an error, and not once it has started.
+//!
+//! The models of a chain share a type. To chain models of different providers, convert
+//! them into a [`BoxedLanguageModel`].
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # #[cfg(feature = "openai")]
+//! # {
+//! use aisdk::core::{DynamicModel, LanguageModelRequest};
+//! use aisdk::core::language_model::fallback::{ServedBy, fallback};
+//! use aisdk::providers::OpenAI;
+//!
+//! # async fn run() -> aisdk::Result<()> {
+//! // The models of a chain share a type, here with the model picked by name
+//! let model = fallback(
+//!     OpenAI::<DynamicModel>::model_name("gpt-5"),
+//!     [OpenAI::<DynamicModel>::model_name("gpt-5-mini")],
+//! );
+//!
+//! let response = LanguageModelRequest::builder()
+//!     .model(model)
+//!     .prompt("Tell me a joke")
+//!     .build()
+//!     .generate_text()
+//!     .await?;
+//! println!("Served by {}", response.response_extensions().get::<ServedBy>().model);
+//! # Ok(())
+//! # }
+//! # }
+//! ```
+
+use crate::core::capabilities::{
+    AudioInputSupport, AudioOutputSupport, Capabilities, ImageInputSupport, ImageOutputSupport,
+    ModelInfo, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport,
+    ToolCallSupport, VideoInputSupport, VideoOutputSupport,
+};
+use crate::core::client::is_retryable_status;
+use crate::core::language_model::boxed::BoxedLanguageModel;
+use crate::core::language_model::{
+    FinishReason, LanguageModel, LanguageModelOptions, LanguageModelResponse, ProviderStream,
+    SamplingOption,
+};
+use crate::error::{Error, Result};
+use crate::extensions::Extension;
+use async_trait::async_trait;
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+
+/// The model that served a response of a [`FallbackModel`], stored in the response
+/// extensions.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ServedBy {
+    /// The name of the model.
+    pub model: String,
+    /// The position of the model in the chain, `0` for the primary model.
+    pub index: usize,
+}
+
+impl Extension for ServedBy {
+    const NAME: &'static str = "aisdk.served_by";
+}
+
+/// Returns true if a call failing with `error` is worth another attempt with a
+/// different model: a rate limit, a server error such as an overloaded model, a
+/// connection failure, a timeout, or a content filter.
+pub fn should_fall_back(error: &Error) -> bool {
+    match error {
+        Error::ApiError {
+            status_code: Some(status),
+            details,
+        } => is_retryable_status(*status) || is_content_filter(details),
+        Error::ApiError {
+            status_code: None, ..
+        }
+        | Error::RateLimited { .. }
+        | Error::Overloaded { .. }
+        | Error::ContentFiltered { .. }
+        | Error::Timeout(_)
+        | Error::StreamStalled(_) => true,
+        _ => false,
+    }
+}
+
+/// Returns true if an error message reports a content filter, e.g. the Azure OpenAI
+/// `content_filter` error code.
+fn is_content_filter(details: &str) -> bool {
+    let details = details.to_lowercase();
+    details.contains("content_filter") || details.contains("content management policy")
+}
+
+/// A language model trying a chain of models in order, returned by [`fallback`].
+///
+/// It has the capabilities of the models it chains.
+#[derive(Debug, Clone)]
+pub struct FallbackModel<M> {
+    models: Vec<M>,
+}
+
+/// Chains `primary` with `backups`, tried in order when a call fails with an error
+/// worth another attempt.
+pub fn fallback<M: LanguageModel>(
+    primary: M,
+    backups: impl IntoIterator<Item = M>,
+) -> FallbackModel<M> {
+    let mut models = vec![primary];
+    models.extend(backups);
+    FallbackModel { models }
+}
+
+impl<M> FallbackModel<M> {
+    /// Returns the models of the chain, the primary model first.
+    pub fn models(&self) -> &[M] {
+        &self.models
+    }
+}
+
+#[async_trait]
+impl<M: LanguageModel> LanguageModel for FallbackModel<M> {
+    fn name(&self) -> String {
+        self.models[0].name()
+    }
+
+    fn context_length(&self) -> Option<usize> {
+        self.models[0].context_length()
+    }
+
+    fn unsupported_options(&self) -> &'static [SamplingOption] {
+        self.models[0].unsupported_options()
+    }
+
+    /// Returns the capabilities all the models have.
+    fn capabilities(&self) -> Capabilities {
+        self.models
+            .iter()
+            .map(|model| model.capabilities())
+            .reduce(Capabilities::intersection)
+            .unwrap_or_default()
+    }
+
+    fn info(&self) -> ModelInfo {
+        self.models[0].info()
+    }
+
+    async fn count_tokens(&self, options: &LanguageModelOptions) -> Result<usize> {
+        self.models[0].count_tokens(options).await
+    }
+
+    async fn generate_text(
+        &mut self,
+        options: LanguageModelOptions,
+    ) -> Result<LanguageModelResponse> {
+        let last = self.models.len() - 1;
+        for (index, model) in self.models.iter_mut().enumerate() {
+            let error = match model.generate_text(options.clone()).await {
+                // A filtered response is retried like a filtering error
+                Ok(response)
+                    if index < last
+                        && response.finish_reason == Some(FinishReason::ContentFilter) =>
+                {
+                    Error::ContentFiltered {
+                        details: "Response withheld by a content filter".to_string(),
+                        status_code: None,
+                    }
+                }
+                Ok(response) => {
+                    response.extensions.insert(ServedBy {
+                        model: model.name(),
+                        index,
+                    });
+                    return Ok(response);
+                }
+                Err(e) => e,
+            };
+            if index == last || !should_fall_back(&error) {
+                return Err(error);
+            }
+            log::warn!(
+                "{} failed, falling back to the next model: {error}",
+                model.name()
+            );
+        }
+        unreachable!("a fallback chain holds at least one model")
+    }
+
+    async fn stream_text(&mut self, options: LanguageModelOptions) -> Result<ProviderStream> {
+        let last = self.models.len() - 1;
+        for (index, model) in self.models.iter_mut().enumerate() {
+            let error = match model.stream_text(options.clone()).await {
+                Ok(mut stream) => match stream.next().await {
+                    Some(Err(e)) => e,
+                    first => {
+                        let first = futures::stream::iter(first);
+                        return Ok(Box::pin(first.chain(stream)));
+                    }
+                },
+                Err(e) => e,
+            };
+            if index == last || !should_fall_back(&error) {
+                return Err(error);
+            }
+            log::warn!(
+                "{} failed, falling back to the next model: {error}",
+                model.name()
+            );
+        }
+        unreachable!("a fallback chain holds at least one model")
+    }
+}
+
+impl<M: LanguageModel> From<FallbackModel<M>> for BoxedLanguageModel {
+    fn from(model: FallbackModel<M>) -> Self {
+        Box::new(model)
+    }
+}
+
+impl<M: ToolCallSupport> ToolCallSupport for FallbackModel<M> {}
+impl<M: ReasoningSupport> ReasoningSupport for FallbackModel<M> {}
+impl<M: StructuredOutputSupport> StructuredOutputSupport for FallbackModel<M> {}
+impl<M: TextInputSupport> TextInputSupport for FallbackModel<M> {}
+impl<M: VideoInputSupport> VideoInputSupport for FallbackModel<M> {}
+impl<M: AudioInputSupport> AudioInputSupport for FallbackModel<M> {}
+impl<M: ImageInputSupport> ImageInputSupport for FallbackModel<M> {}
+impl<M: TextOutputSupport> TextOutputSupport for FallbackModel<M> {}
+impl<M: VideoOutputSupport> VideoOutputSupport for FallbackModel<M> {}
+impl<M: AudioOutputSupport> AudioOutputSupport for FallbackModel<M> {}
+impl<M: ImageOutputSupport> ImageOutputSupport for FallbackModel<M> {}
+
+#[cfg(all(test, feature = "language-model-request"))]
+mod tests {
+    use super::*;
+    use crate::core::language_model::{
+        LanguageModelResponseContentType, LanguageModelStreamChunk, LanguageModelStreamChunkType,
+    };
+    use crate::core::test_model::ScriptedModel;
+
+    /// A model answering with its name.
+    fn model(name: &'static str) -> ScriptedModel {
+        ScriptedModel::new(name).text(name)
+    }
+
+    /// A model failing with the given status, as the first event of its streams.
+    fn failing(name: &'static str, status: u16) -> ScriptedModel {
+        model(name).failing_in_stream(Error::ApiError {
+            status_code: Some(reqwest::StatusCode::from_u16(status).unwrap()),
+            details: "failed".to_string(),
+        })
+    }
+
+    fn text(response: &LanguageModelResponse) -> &str {
+        match response.contents.as_slice() {
+            [LanguageModelResponseContentType::Text(text)] => text,
+            other => panic!("unexpected contents: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_falls_back_on_retryable_errors() {
+        let mut chain = fallback(
+            failing("primary", 529),
+            [failing("backup", 429), model("last")],
+        );
+
+        let response = chain
+            .generate_text(LanguageModelOptions::default())
+            .await
+            .unwrap();
+
+        assert_eq!(text(&response), "last");
+        assert_eq!(
+            *response.extensions.get::<ServedBy>(),
+            ServedBy {
+                model: "last".to_string(),
+                index: 2,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_returns_errors_not_worth_a_fallback() {
+        let mut chain = fallback(failing("primary", 401), [model("backup")]);
+
+        let error = chain
+            .generate_text(LanguageModelOptions::default())
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error, Error::ApiError { status_code: Some(s), .. } if s == 401));
+    }
+
+    #[tokio::test]
+    async fn test_falls_back_on_content_filter() {
+        let filtered = model("primary").finish_reason(Some(FinishReason::ContentFilter));
+        let mut chain = fallback(filtered, [model("backup")]);
+
+        let response = chain
+            .generate_text(LanguageModelOptions::default())
+            .await
+            .unwrap();
+
+        assert_eq!(text(&response), "backup");
+    }
+
+    #[tokio::test]
+    async fn test_stream_falls_back_on_first_event_error() {
+        let mut chain = fallback(failing("primary", 503), [model("backup")]);
+
+        let chunks: Vec<_> = chain
+            .stream_text(LanguageModelOptions::default())
+            .await
+            .unwrap()
+            .collect()
+            .await;
+
+        assert!(matches!(
+            chunks.as_slice(),
+            [Ok(c)] if matches!(
+                c.first(),
+                Some(LanguageModelStreamChunk::Delta(LanguageModelStreamChunkType::Text(t))) if t == "backup"
+            )
+        ));
+    }
+
+    #[test]
+    fn test_should_fall_back() {
+        let api_error = |status: Option<u16>, details: &str| Error::ApiError {
+            status_code: status.map(|s| reqwest::StatusCode::from_u16(s).unwrap()),
+            details: details.to_string(),
+        };
+
+        assert!(should_fall_back(&api_error(Some(500), "")));
+        assert!(should_fall_back(&api_error(None, "connection refused")));
+        assert!(should_fall_back(&api_error(
+            Some(400),
+            r#"{"error":{"code":"content_filter"}}"#
+        )));
+        assert!(should_fall_back(&Error::Overloaded {
+            details: "busy".to_string(),
+            status_code: None,
+        }));
+        assert!(should_fall_back(&Error::RateLimited {
+            retry_after: None,
+            details: String::new(),
+            status_code: None,
+            rate_limit: None,
+        }));
+        assert!(!should_fall_back(&api_error(Some(400), "bad request")));
+        assert!(!should_fall_back(&Error::AuthenticationFailed {
+            details: String::new(),
+            status_code: None,
+        }));
+        assert!(!should_fall_back(&Error::Cancelled));
+    }
+}
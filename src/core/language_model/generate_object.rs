@@ -0,0 +1,152 @@
+//! Structured object generation impl for the `LanguageModelRequest` trait.
+
+use crate::core::capabilities::StructuredOutputSupport;
+use crate::core::language_model::{
+    LanguageModel, LanguageModelOptions, request::LanguageModelRequest,
+};
+use crate::error::{Error, Result};
+use schemars::{JsonSchema, schema_for};
+use serde::de::DeserializeOwned;
+use std::ops::Deref;
+
+impl<M: LanguageModel + StructuredOutputSupport> LanguageModelRequest<M> {
+    /// Generates a structured object of type `T` using the language model.
+    ///
+    /// The JSON schema of `T` is sent to the provider using its native structured
+    /// output mode (a JSON schema response format for OpenAI, Google, and the
+    /// OpenAI-compatible providers, and a forced tool call for Anthropic), and the
+    /// final response is deserialized into `T`. Tools registered on the request are
+    /// executed as with [`generate_text`](Self::generate_text).
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - The type to generate, which must implement [`JsonSchema`] and [`DeserializeOwned`].
+    ///
+    /// # Returns
+    ///
+    /// A [`GenerateObjectResponse`] containing the deserialized object and the final
+    /// conversation state.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] if generation fails or if the response cannot be
+    /// deserialized into `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    ///# #[cfg(feature = "openai")]
+    ///# {
+    ///    use aisdk::{core::LanguageModelRequest, providers::OpenAI};
+    ///    use schemars::JsonSchema;
+    ///    use serde::Deserialize;
+    ///
+    ///    #[derive(JsonSchema, Deserialize)]
+    ///    struct City {
+    ///        name: String,
+    ///        country: String,
+    ///    }
+    ///
+    ///    async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///        let city = LanguageModelRequest::builder()
+    ///            .model(OpenAI::gpt_5())
+    ///            .prompt("Name the capital of France.")
+    ///            .build()
+    ///            .generate_object::<City>()
+    ///            .await?
+    ///            .object;
+    ///
+    ///        println!("{} is in {}", city.name, city.country);
+    ///        Ok(())
+    ///    }
+    ///# }
+    /// ```
+    pub async fn generate_object<T: JsonSchema + DeserializeOwned>(
+        &mut self,
+    ) -> Result<GenerateObjectResponse<T>> {
+        self.options.schema = Some(schema_for!(T));
+
+        let response = self.generate_text().await?;
+        let text = response
+            .text()
+            .ok_or_else(|| Error::ObjectGenerationError("No text response found".to_string()))?;
+        let object = parse_object(&text)?;
+
+        Ok(GenerateObjectResponse {
+            object,
+            options: response.options,
+        })
+    }
+}
+
+/// Deserializes a JSON object from model output, tolerating surrounding prose or
+/// markdown code fences added by providers without a strict JSON mode.
+pub(crate) fn parse_object<T: DeserializeOwned>(text: &str) -> Result<T> {
+    let err = match serde_json::from_str(text.trim()) {
+        Ok(object) => return Ok(object),
+        Err(err) => err,
+    };
+
+    let start = text.find(['{', '[']);
+    let end = text.rfind(['}', ']']);
+    if let (Some(start), Some(end)) = (start, end)
+        && start < end
+        && let Ok(object) = serde_json::from_str(&text[start..=end])
+    {
+        return Ok(object);
+    }
+
+    Err(Error::ObjectGenerationError(format!(
+        "Response does not match the schema: {err}"
+    )))
+}
+
+// ============================================================================
+// Section: response types
+// ============================================================================
+
+/// Response from a `generate_object` call.
+#[derive(Debug, Clone)]
+pub struct GenerateObjectResponse<T> {
+    /// The generated object.
+    pub object: T,
+    /// The options that generated this response.
+    pub options: LanguageModelOptions,
+}
+
+impl<T> Deref for GenerateObjectResponse<T> {
+    type Target = LanguageModelOptions;
+
+    fn deref(&self) -> &Self::Target {
+        &self.options
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct City {
+        name: String,
+    }
+
+    #[test]
+    fn test_parse_object_plain_json() {
+        let city: City = parse_object(r#"{"name": "Paris"}"#).unwrap();
+        assert_eq!(city.name, "Paris");
+    }
+
+    #[test]
+    fn test_parse_object_code_fence() {
+        let city: City = parse_object("Sure!\n```json\n{\"name\": \"Paris\"}\n```").unwrap();
+        assert_eq!(city.name, "Paris");
+    }
+
+    #[test]
+    fn test_parse_object_mismatch() {
+        let result: Result<City> = parse_object(r#"{"title": "Paris"}"#);
+        assert!(matches!(result, Err(Error::ObjectGenerationError(_))));
+    }
+}
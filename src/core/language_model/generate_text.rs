@@ -1,10 +1,12 @@
 //! Text Generation impl for the `LanguageModelRequest` trait.
 
+use crate::core::observability::GenerationRecorder;
 use crate::error::Result;
 use crate::{
     Error,
     core::{
         AssistantMessage, Message,
+        effect::{EffectContext, EffectKind, run_effect},
         language_model::{
             LanguageModel, LanguageModelOptions, LanguageModelResponse,
             LanguageModelResponseContentType, StopReason, request::LanguageModelRequest,
@@ -64,6 +66,34 @@ impl<M: LanguageModel> LanguageModelRequest<M> {
     /// ```
     ///
     pub async fn generate_text(&mut self) -> Result<GenerateTextResponse> {
+        #[cfg(feature = "metrics")]
+        let metrics = crate::core::metrics::RequestMetrics::chat(&self.model);
+        let recorder = GenerationRecorder::start(&self.model, &self.options, &self.prompt);
+
+        #[cfg(feature = "otel")]
+        let result = crate::core::telemetry::GenAiSpan::chat(&self.model, &self.options)
+            .instrument(self.run_generate_text(), |span, response| {
+                span.finish(&response.options, &response.options.usage())
+            })
+            .await;
+
+        #[cfg(not(feature = "otel"))]
+        let result = self.run_generate_text().await;
+
+        #[cfg(feature = "metrics")]
+        metrics.record(&result, |response| Some(response.options.usage()));
+        if let Some(recorder) = recorder {
+            match &result {
+                Ok(response) => recorder.finish(&response.options, &response.options.usage()),
+                Err(error) => recorder.fail(error),
+            }
+        }
+
+        result
+    }
+
+    /// Runs the steps of [`generate_text`](Self::generate_text).
+    async fn run_generate_text(&mut self) -> Result<GenerateTextResponse> {
         let (system_prompt, messages) = resolve_message(&self.options, &self.prompt);
 
         let mut options = LanguageModelOptions {
@@ -72,13 +102,40 @@ impl<M: LanguageModel> LanguageModelRequest<M> {
             schema: self.options.schema.to_owned(),
             stop_sequences: self.options.stop_sequences.to_owned(),
             tools: self.options.tools.to_owned(),
+            tool_choice: self.options.tool_choice.clone(),
+            provider_options: self.options.provider_options.clone(),
+            input_guards: self.options.input_guards.clone(),
+            output_guards: self.options.output_guards.clone(),
+            budget: self.options.budget.clone(),
+            cost_calculator: self.options.cost_calculator.clone(),
+            context_window: self.options.context_window.clone(),
+            effect: self.options.effect.clone(),
+            cancellation_token: self.options.cancellation_token.clone(),
+            telemetry: self.options.telemetry.clone(),
             stop_when: self.options.stop_when.clone(),
             on_step_start: self.options.on_step_start.clone(),
             on_step_finish: self.options.on_step_finish.clone(),
+            on_chunk: self.options.on_chunk.clone(),
+            on_finish: self.options.on_finish.clone(),
+            on_error: self.options.on_error.clone(),
             stop_reason: None,
+            finish_reason: None,
+            response_extensions: Default::default(),
+            warnings: Vec::new(),
+            response_logprobs: Vec::new(),
+            cost: None,
+            resume: self.options.resume.clone(),
             ..self.options
         };
 
+        let interrupt = options.interrupt();
+
+        // Execute tool calls left pending by a restored snapshot
+        options
+            .resume(&interrupt)
+            .await
+            .inspect_err(|e| options.fail(e.clone()))?;
+
         loop {
             // Update the current step
             options.current_step_id += 1;
@@ -88,19 +145,51 @@ impl<M: LanguageModel> LanguageModelRequest<M> {
                 hook(&mut options);
             }
 
-            let response: LanguageModelResponse = self
-                .model
-                .generate_text(options.clone())
+            options.warn_unsupported(&self.model);
+            let request_options = options
+                .prepare_request(&self.model)
                 .await
-                .inspect_err(|e| {
-                options.stop_reason = Some(StopReason::Error(e.clone()));
-            })?;
+                .inspect_err(|e| options.fail(e.clone()))?;
 
-            for output in response.contents.iter() {
+            let ctx = EffectContext::new(
+                options.current_step_id,
+                EffectKind::ModelCall {
+                    model: self.model.name(),
+                },
+            );
+            let response: LanguageModelResponse = interrupt
+                .run(run_effect(
+                    options.effect.as_ref(),
+                    ctx,
+                    self.model.generate_text(request_options),
+                ))
+                .await
+                .inspect_err(|e| options.fail(e.clone()))?;
+
+            if let (Some(budget), Some(usage)) = (&options.budget, &response.usage) {
+                budget.record(usage);
+            }
+            if let Some(usage) = &response.usage {
+                options.record_cost(&self.model, usage);
+            }
+            options.finish_reason = response.finish_reason.clone();
+            options.response_extensions = response.extensions.clone();
+            options.response_logprobs = response.logprobs.clone();
+
+            let contents = response
+                .contents
+                .iter()
+                .cloned()
+                .map(|content| options.apply_output_guards(content))
+                .collect::<Result<Vec<_>>>()
+                .inspect_err(|e| options.fail(e.clone()))?;
+
+            for output in contents.iter() {
                 match output {
-                    LanguageModelResponseContentType::Text(text) => {
+                    LanguageModelResponseContentType::Text(_)
+                    | LanguageModelResponseContentType::Refusal(_) => {
                         let assistant_msg = Message::Assistant(AssistantMessage {
-                            content: text.clone().into(),
+                            content: output.clone(),
                             usage: response.usage.clone(),
                         });
                         options
@@ -122,6 +211,17 @@ impl<M: LanguageModel> LanguageModelRequest<M> {
                             .messages
                             .push(TaggedMessage::new(options.current_step_id, assistant_msg));
                     }
+                    LanguageModelResponseContentType::Source(_)
+                    | LanguageModelResponseContentType::Image(_) => {
+                        // The usage is recorded once, on the text of the response
+                        let assistant_msg = Message::Assistant(AssistantMessage {
+                            content: output.clone(),
+                            usage: None,
+                        });
+                        options
+                            .messages
+                            .push(TaggedMessage::new(options.current_step_id, assistant_msg));
+                    }
                     LanguageModelResponseContentType::ToolCall(tool_info) => {
                         // add tool message
                         let usage = response.usage.clone();
@@ -132,7 +232,10 @@ impl<M: LanguageModel> LanguageModelRequest<M> {
                                 usage,
                             )),
                         ));
-                        options.handle_tool_call(tool_info).await;
+                        options
+                            .handle_tool_call(tool_info, &interrupt)
+                            .await
+                            .inspect_err(|e| options.fail(e.clone()))?;
                     }
                     _ => (),
                 }
@@ -144,9 +247,9 @@ impl<M: LanguageModel> LanguageModelRequest<M> {
             };
 
             if response.contents.is_empty() {
-                options.stop_reason = Some(StopReason::Error(Error::Other(
+                options.fail(Error::Other(
                     "Language model returned empty response".to_string(),
-                )));
+                ));
                 break;
             }
 
@@ -159,14 +262,26 @@ impl<M: LanguageModel> LanguageModelRequest<M> {
             }
 
             match response.contents.last() {
+                Some(LanguageModelResponseContentType::ToolCall(_))
+                    if options.max_steps_reached() =>
+                {
+                    options.stop_reason = Some(StopReason::MaxSteps);
+                    break;
+                }
                 Some(LanguageModelResponseContentType::ToolCall(_)) => (),
                 _ => {
-                    options.stop_reason = Some(StopReason::Finish);
+                    options.stop_reason = Some(
+                        response
+                            .stop_sequence
+                            .map_or(StopReason::Finish, StopReason::StopSequence),
+                    );
                     break;
                 }
             };
         }
 
+        options.finish(&options.usage());
+
         Ok(GenerateTextResponse { options })
     }
 }
@@ -227,10 +342,14 @@ mod tests {
     use super::*;
     use crate::core::{
         AssistantMessage,
-        language_model::{LanguageModelResponseContentType, Usage},
+        language_model::{
+            FinishReason, LanguageModelResponseContentType, SamplingOption, Usage, Warning,
+        },
         messages::TaggedMessage,
+        test_model::ScriptedModel,
         tools::{ToolCallInfo, ToolResultInfo},
     };
+    use std::sync::{Arc, Mutex};
 
     #[test]
     fn test_generate_text_response_step() {
@@ -612,4 +731,327 @@ mod tests {
             assert_eq!(result.tool.name, format!("tool{i}"));
         }
     }
+
+    /// A model that keeps calling the `echo` tool.
+    fn looping_model() -> ScriptedModel {
+        ScriptedModel::new("looping")
+            .respond(|options| {
+                let mut call = ToolCallInfo::new("echo");
+                call.id(format!("call_{}", options.current_step_id));
+                LanguageModelResponseContentType::ToolCall(call)
+            })
+            .usage(None)
+            .finish_reason(Some(FinishReason::ToolCalls))
+    }
+
+    #[tokio::test]
+    async fn test_generate_text_stops_at_max_steps() {
+        let echo = crate::core::tools::Tool {
+            name: "echo".to_string(),
+            execute: crate::core::tools::ToolExecute::new(Box::new(|_| Ok("ok".to_string()))),
+            ..Default::default()
+        };
+
+        let response = LanguageModelRequest::builder()
+            .model(looping_model())
+            .prompt("loop")
+            .with_tool(echo)
+            .max_steps(3)
+            .build()
+            .generate_text()
+            .await
+            .unwrap();
+
+        assert_eq!(response.stop_reason(), Some(StopReason::MaxSteps));
+        assert_eq!(response.tool_calls().unwrap().len(), 3);
+        assert_eq!(response.tool_results().unwrap().len(), 3);
+        assert!(response.pending_tool_calls().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_generate_text_resumes_restored_snapshot() {
+        use crate::core::budget::Budget;
+        use crate::core::language_model::snapshot::AgentSnapshot;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let mut pending = ToolCallInfo::new("echo");
+        pending.id("call_1");
+        let budget = Budget::new().max_total_tokens(1_000);
+        budget.record(&Usage {
+            input_tokens: Some(10),
+            output_tokens: Some(5),
+            ..Default::default()
+        });
+        let snapshot = LanguageModelOptions {
+            current_step_id: 1,
+            messages: vec![
+                TaggedMessage::new(0, Message::User("loop".into())),
+                TaggedMessage::new(
+                    1,
+                    Message::Assistant(AssistantMessage::new(
+                        LanguageModelResponseContentType::ToolCall(pending),
+                        None,
+                    )),
+                ),
+            ],
+            budget: Some(budget),
+            ..Default::default()
+        }
+        .snapshot();
+        let snapshot = AgentSnapshot::from_json(&snapshot.to_json().unwrap()).unwrap();
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = calls.clone();
+        let echo = crate::core::tools::Tool {
+            name: "echo".to_string(),
+            execute: crate::core::tools::ToolExecute::new(Box::new(move |_| {
+                counter.fetch_add(1, Ordering::SeqCst);
+                Ok("ok".to_string())
+            })),
+            ..Default::default()
+        };
+        let budget = Budget::new().max_total_tokens(1_000);
+
+        let response = LanguageModelRequest::builder()
+            .model(looping_model())
+            .restore(snapshot)
+            .with_tool(echo)
+            .budget(budget.clone())
+            .max_steps(2)
+            .build()
+            .generate_text()
+            .await
+            .unwrap();
+
+        let results = response.tool_results().unwrap();
+        let ids: Vec<_> = results.iter().map(|r| r.tool.id.as_str()).collect();
+        assert_eq!(ids, ["call_1", "call_2"]);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert_eq!(budget.snapshot().usage.input_tokens, Some(10));
+    }
+
+    /// A model that stops at a stop sequence.
+    fn stop_sequence_model() -> ScriptedModel {
+        ScriptedModel::new("stop-sequence")
+            .text("1, 2")
+            .usage(None)
+            .reports_stop_sequence()
+            .unsupported_options(&[SamplingOption::Seed, SamplingOption::TopK])
+    }
+
+    #[tokio::test]
+    async fn test_generate_text_reports_stop_sequence() {
+        let response = LanguageModelRequest::builder()
+            .model(stop_sequence_model())
+            .prompt("count")
+            .stop_sequences(vec![", 3".to_string()])
+            .build()
+            .generate_text()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            response.stop_reason(),
+            Some(StopReason::StopSequence(", 3".to_string()))
+        );
+        assert_eq!(response.text().unwrap(), "1, 2");
+        assert_eq!(response.finish_reason(), Some(FinishReason::Stop));
+    }
+
+    #[tokio::test]
+    async fn test_generate_text_calls_on_finish() {
+        let finished = Arc::new(Mutex::new(None));
+        let hook_finished = finished.clone();
+
+        LanguageModelRequest::builder()
+            .model(stop_sequence_model())
+            .prompt("count")
+            .on_finish(move |options, _usage| {
+                *hook_finished.lock().unwrap() = options.text();
+            })
+            .on_error(|e| panic!("unexpected error: {e}"))
+            .build()
+            .generate_text()
+            .await
+            .unwrap();
+
+        assert_eq!(finished.lock().unwrap().as_deref(), Some("1, 2"));
+    }
+
+    /// A model with a price that reports the usage of its calls.
+    fn priced_model() -> ScriptedModel {
+        ScriptedModel::new("priced")
+            .text("done")
+            .usage(Some(Usage {
+                input_tokens: Some(500_000),
+                output_tokens: Some(100_000),
+                ..Default::default()
+            }))
+            .info(crate::core::capabilities::ModelInfo {
+                pricing: Some(crate::core::budget::TokenPricing::new(2.0, 10.0)),
+                ..crate::core::capabilities::ModelInfo::UNKNOWN
+            })
+    }
+
+    #[tokio::test]
+    async fn test_generate_text_reports_cost() {
+        use crate::core::cost::{Cost, CostAccumulator, CostCalculator};
+
+        let tenant = CostAccumulator::new();
+        let calculator = CostCalculator::new().accumulator(tenant.clone());
+        for _ in 0..2 {
+            let response = LanguageModelRequest::builder()
+                .model(priced_model())
+                .prompt("hi")
+                .cost_calculator(calculator.clone())
+                .build()
+                .generate_text()
+                .await
+                .unwrap();
+
+            assert_eq!(
+                response.cost(),
+                Some(Cost {
+                    input: 1.0,
+                    output: 1.0
+                })
+            );
+        }
+        assert_eq!(tenant.calls(), 2);
+        assert_eq!(tenant.total().total(), 4.0);
+
+        let response = LanguageModelRequest::builder()
+            .model(priced_model())
+            .prompt("hi")
+            .build()
+            .generate_text()
+            .await
+            .unwrap();
+        assert_eq!(response.cost(), None);
+    }
+
+    /// A model that refuses to answer.
+    fn refusing_model() -> ScriptedModel {
+        ScriptedModel::new("refusing")
+            .content(LanguageModelResponseContentType::Refusal(
+                "I can't help with that".to_string(),
+            ))
+            .usage(None)
+            .finish_reason(Some(FinishReason::ContentFilter))
+    }
+
+    #[tokio::test]
+    async fn test_generate_text_reports_refusal() {
+        let response = LanguageModelRequest::builder()
+            .model(refusing_model())
+            .prompt("do something bad")
+            .build()
+            .generate_text()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            response.refusal().as_deref(),
+            Some("I can't help with that")
+        );
+        assert_eq!(response.text(), None);
+        assert_eq!(response.finish_reason(), Some(FinishReason::ContentFilter));
+    }
+
+    #[tokio::test]
+    async fn test_generate_text_warns_about_unsupported_options() {
+        let response = LanguageModelRequest::builder()
+            .model(stop_sequence_model())
+            .prompt("count")
+            .top_k(40u32)
+            .temperature(50u32)
+            .build()
+            .generate_text()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            response.warnings(),
+            [Warning::UnsupportedOption(SamplingOption::TopK)]
+        );
+    }
+
+    /// A model that never responds.
+    fn hanging_model() -> ScriptedModel {
+        ScriptedModel::new("hanging").stalling()
+    }
+
+    #[tokio::test]
+    async fn test_generate_text_timeout() {
+        let timeout = std::time::Duration::from_millis(10);
+        let result = LanguageModelRequest::builder()
+            .model(hanging_model())
+            .prompt("hello")
+            .timeout(timeout)
+            .build()
+            .generate_text()
+            .await;
+
+        assert_eq!(result.unwrap_err(), Error::Timeout(timeout));
+    }
+
+    #[tokio::test]
+    async fn test_generate_text_timeout_bounds_tools() {
+        let timeout = std::time::Duration::from_millis(10);
+        let hanging = crate::core::tools::Tool {
+            name: "echo".to_string(),
+            execute: crate::core::tools::ToolExecute::new_async(Box::new(|_| {
+                Box::pin(std::future::pending())
+            })),
+            ..Default::default()
+        };
+
+        let result = LanguageModelRequest::builder()
+            .model(looping_model())
+            .prompt("loop")
+            .with_tool(hanging)
+            .timeout(timeout)
+            .build()
+            .generate_text()
+            .await;
+
+        assert_eq!(result.unwrap_err(), Error::Timeout(timeout));
+    }
+
+    #[tokio::test]
+    async fn test_generate_text_cancelled() {
+        let token = crate::core::cancellation::CancellationToken::new();
+        token.cancel();
+
+        let result = LanguageModelRequest::builder()
+            .model(hanging_model())
+            .prompt("hello")
+            .cancellation_token(token)
+            .build()
+            .generate_text()
+            .await;
+
+        assert_eq!(result.unwrap_err(), Error::Cancelled);
+    }
+
+    #[tokio::test]
+    async fn test_generate_text_calls_on_error() {
+        let errors = Arc::new(Mutex::new(Vec::new()));
+        let hook_errors = errors.clone();
+        let token = crate::core::cancellation::CancellationToken::new();
+        token.cancel();
+
+        let result = LanguageModelRequest::builder()
+            .model(hanging_model())
+            .prompt("hello")
+            .cancellation_token(token)
+            .on_error(move |e| hook_errors.lock().unwrap().push(e.clone()))
+            .on_finish(|_, _| panic!("generation should fail"))
+            .build()
+            .generate_text()
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(*errors.lock().unwrap(), vec![Error::Cancelled]);
+    }
 }
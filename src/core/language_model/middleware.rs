@@ -0,0 +1,1180 @@
+//! Middleware wrapping language models.
+//!
+//! A [`LanguageModelMiddleware`] intercepts the calls made to a language model: it can
+//! transform the options of each call, and wrap the generation and the stream the
+//! model returns. [`wrap_language_model`] applies a middleware to any model, returning
+//! a model that can be used wherever the original one is, so behavior such as default
+//! settings or logging is written once and composed over every provider.
+//!
+//! Middleware is applied to each model call, so once per step of a tool loop. Wrap a
+//! wrapped model again to apply several middleware: the outermost one sees the call
+//! first.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # #[cfg(feature = "openai")]
+//! # {
+//! use aisdk::core::LanguageModelRequest;
+//! use aisdk::core::language_model::LanguageModelOptions;
+//! use aisdk::core::language_model::middleware::{DefaultSettings, Logging, wrap_language_model};
+//! use aisdk::providers::OpenAI;
+//!
+//! # async fn run() -> aisdk::Result<()> {
+//! let mut defaults = LanguageModelOptions::default();
+//! defaults.temperature = Some(20);
+//! defaults.max_output_tokens = Some(1024);
+//! let model = wrap_language_model(
+//!     wrap_language_model(OpenAI::gpt_5(), DefaultSettings::new(defaults)),
+//!     Logging,
+//! );
+//!
+//! let response = LanguageModelRequest::builder()
+//!     .model(model)
+//!     .prompt("Tell me a joke")
+//!     .build()
+//!     .generate_text()
+//!     .await?;
+//! # Ok(())
+//! # }
+//! # }
+//! ```
+
+use crate::core::AssistantMessage;
+use crate::core::budget::Budget;
+use crate::core::capabilities::{
+    AudioInputSupport, AudioOutputSupport, Capabilities, ImageInputSupport, ImageOutputSupport,
+    ModelInfo, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport,
+    ToolCallSupport, VideoInputSupport, VideoOutputSupport,
+};
+use crate::core::language_model::boxed::BoxedLanguageModel;
+use crate::core::language_model::{
+    LanguageModel, LanguageModelOptions, LanguageModelResponse, LanguageModelResponseContentType,
+    LanguageModelStreamChunk, LanguageModelStreamChunkType, ProviderStream, SamplingOption,
+    usage_accumulator::UsageAccumulator,
+};
+use crate::core::runtime::{Instant, sleep};
+use crate::error::Result;
+use async_trait::async_trait;
+use futures::StreamExt;
+use std::fmt::Debug;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// The kind of model call a middleware intercepts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CallType {
+    /// A call to [`LanguageModel::generate_text`].
+    Generate,
+    /// A call to [`LanguageModel::stream_text`].
+    Stream,
+}
+
+/// Intercepts the calls made to a language model wrapped with [`wrap_language_model`].
+///
+/// Every hook defaults to passing the call through unchanged.
+#[async_trait]
+pub trait LanguageModelMiddleware: Send + Sync + Debug + 'static {
+    /// Transforms the options of a call before it is made.
+    ///
+    /// # Errors
+    ///
+    /// An error fails the call without making it.
+    async fn transform_options(
+        &self,
+        options: LanguageModelOptions,
+        _call_type: CallType,
+    ) -> Result<LanguageModelOptions> {
+        Ok(options)
+    }
+
+    /// Wraps a non-streaming generation, with the options returned by
+    /// [`transform_options`](Self::transform_options). Call `model.generate_text` to make
+    /// the call, or return a response without making it, e.g. from a cache.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the call fails.
+    async fn wrap_generate<M: LanguageModel>(
+        &self,
+        model: &mut M,
+        options: LanguageModelOptions,
+    ) -> Result<LanguageModelResponse> {
+        model.generate_text(options).await
+    }
+
+    /// Wraps a streaming generation, with the options returned by
+    /// [`transform_options`](Self::transform_options). Call `model.stream_text` to make
+    /// the call, and map the returned stream to inspect or change its chunks.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the call fails.
+    async fn wrap_stream<M: LanguageModel>(
+        &self,
+        model: &mut M,
+        options: LanguageModelOptions,
+    ) -> Result<ProviderStream> {
+        model.stream_text(options).await
+    }
+}
+
+/// A language model whose calls go through a [`LanguageModelMiddleware`], returned by
+/// [`wrap_language_model`].
+///
+/// It has the capabilities of the model it wraps.
+#[derive(Debug)]
+pub struct WrappedLanguageModel<M, W> {
+    model: M,
+    middleware: Arc<W>,
+}
+
+impl<M: Clone, W> Clone for WrappedLanguageModel<M, W> {
+    fn clone(&self) -> Self {
+        Self {
+            model: self.model.clone(),
+            middleware: Arc::clone(&self.middleware),
+        }
+    }
+}
+
+impl<M, W> WrappedLanguageModel<M, W> {
+    /// Returns the wrapped model.
+    pub fn model(&self) -> &M {
+        &self.model
+    }
+
+    /// Returns the middleware the calls go through.
+    pub fn middleware(&self) -> &W {
+        &self.middleware
+    }
+}
+
+/// Wraps `model` so that its calls go through `middleware`.
+pub fn wrap_language_model<M, W>(model: M, middleware: W) -> WrappedLanguageModel<M, W>
+where
+    M: LanguageModel,
+    W: LanguageModelMiddleware,
+{
+    WrappedLanguageModel {
+        model,
+        middleware: Arc::new(middleware),
+    }
+}
+
+#[async_trait]
+impl<M, W> LanguageModel for WrappedLanguageModel<M, W>
+where
+    M: LanguageModel,
+    W: LanguageModelMiddleware,
+{
+    fn name(&self) -> String {
+        self.model.name()
+    }
+
+    fn context_length(&self) -> Option<usize> {
+        self.model.context_length()
+    }
+
+    fn unsupported_options(&self) -> &'static [SamplingOption] {
+        self.model.unsupported_options()
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        self.model.capabilities()
+    }
+
+    fn info(&self) -> ModelInfo {
+        self.model.info()
+    }
+
+    async fn count_tokens(&self, options: &LanguageModelOptions) -> Result<usize> {
+        self.model.count_tokens(options).await
+    }
+
+    async fn generate_text(
+        &mut self,
+        options: LanguageModelOptions,
+    ) -> Result<LanguageModelResponse> {
+        let options = self
+            .middleware
+            .transform_options(options, CallType::Generate)
+            .await?;
+        self.middleware
+            .wrap_generate(&mut self.model, options)
+            .await
+    }
+
+    async fn stream_text(&mut self, options: LanguageModelOptions) -> Result<ProviderStream> {
+        let options = self
+            .middleware
+            .transform_options(options, CallType::Stream)
+            .await?;
+        self.middleware.wrap_stream(&mut self.model, options).await
+    }
+}
+
+impl<M, W> From<WrappedLanguageModel<M, W>> for BoxedLanguageModel
+where
+    M: LanguageModel,
+    W: LanguageModelMiddleware,
+{
+    fn from(model: WrappedLanguageModel<M, W>) -> Self {
+        Box::new(model)
+    }
+}
+
+impl<M: ToolCallSupport, W> ToolCallSupport for WrappedLanguageModel<M, W> {}
+impl<M: ReasoningSupport, W> ReasoningSupport for WrappedLanguageModel<M, W> {}
+impl<M: StructuredOutputSupport, W> StructuredOutputSupport for WrappedLanguageModel<M, W> {}
+impl<M: TextInputSupport, W> TextInputSupport for WrappedLanguageModel<M, W> {}
+impl<M: VideoInputSupport, W> VideoInputSupport for WrappedLanguageModel<M, W> {}
+impl<M: AudioInputSupport, W> AudioInputSupport for WrappedLanguageModel<M, W> {}
+impl<M: ImageInputSupport, W> ImageInputSupport for WrappedLanguageModel<M, W> {}
+impl<M: TextOutputSupport, W> TextOutputSupport for WrappedLanguageModel<M, W> {}
+impl<M: VideoOutputSupport, W> VideoOutputSupport for WrappedLanguageModel<M, W> {}
+impl<M: AudioOutputSupport, W> AudioOutputSupport for WrappedLanguageModel<M, W> {}
+impl<M: ImageOutputSupport, W> ImageOutputSupport for WrappedLanguageModel<M, W> {}
+
+// ============================================================================
+// Section: built-in middleware
+// ============================================================================
+
+/// Middleware setting the sampling options a call leaves unset to defaults.
+///
+/// The defaults are the system prompt, seed, sampling, token limit, stop sequence,
+/// penalty, log probability, reasoning effort, tool choice and parallel tool call
+/// options of a [`LanguageModelOptions`]. Provider options are merged, with those of
+/// the call taking precedence.
+#[derive(Debug, Clone, Default)]
+pub struct DefaultSettings {
+    defaults: LanguageModelOptions,
+}
+
+impl DefaultSettings {
+    /// Creates the middleware applying the options set in `defaults`.
+    pub fn new(defaults: LanguageModelOptions) -> Self {
+        Self { defaults }
+    }
+
+    /// Sets the options of `options` that are unset to their defaults.
+    fn apply(&self, mut options: LanguageModelOptions) -> LanguageModelOptions {
+        let defaults = &self.defaults;
+        macro_rules! default_fields {
+            ($($field:ident),* $(,)?) => {
+                $(
+                    if options.$field.is_none() {
+                        options.$field = defaults.$field.clone();
+                    }
+                )*
+            };
+        }
+        default_fields!(
+            system,
+            seed,
+            temperature,
+            top_p,
+            top_k,
+            max_output_tokens,
+            stop_sequences,
+            presence_penalty,
+            frequency_penalty,
+            logprobs,
+            top_logprobs,
+            reasoning_effort,
+            tool_choice,
+            parallel_tool_calls,
+        );
+        for (key, value) in &defaults.provider_options {
+            options
+                .provider_options
+                .entry(key.clone())
+                .or_insert_with(|| value.clone());
+        }
+        options
+    }
+}
+
+#[async_trait]
+impl LanguageModelMiddleware for DefaultSettings {
+    async fn transform_options(
+        &self,
+        options: LanguageModelOptions,
+        _call_type: CallType,
+    ) -> Result<LanguageModelOptions> {
+        Ok(self.apply(options))
+    }
+}
+
+/// Middleware logging each call with the [`log`] crate: its start at debug level, and
+/// its duration, token usage and finish reason, or its error, once it completes.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Logging;
+
+#[async_trait]
+impl LanguageModelMiddleware for Logging {
+    async fn wrap_generate<M: LanguageModel>(
+        &self,
+        model: &mut M,
+        options: LanguageModelOptions,
+    ) -> Result<LanguageModelResponse> {
+        let name = model.name();
+        log::debug!("Generating with {name}, step {}", options.current_step_id);
+        let start = Instant::now();
+        let result = model.generate_text(options).await;
+        match &result {
+            Ok(response) => log::info!(
+                "Generated with {name} in {:?}, usage: {:?}, finish reason: {:?}",
+                start.elapsed(),
+                response.usage,
+                response.finish_reason
+            ),
+            Err(e) => log::error!("Generation with {name} failed: {e}"),
+        }
+        result
+    }
+
+    async fn wrap_stream<M: LanguageModel>(
+        &self,
+        model: &mut M,
+        options: LanguageModelOptions,
+    ) -> Result<ProviderStream> {
+        let name = model.name();
+        log::debug!("Streaming with {name}, step {}", options.current_step_id);
+        let start = Instant::now();
+        let stream = model.stream_text(options).await.inspect_err(|e| {
+            log::error!("Stream with {name} failed: {e}");
+        })?;
+
+        let mut usage = None;
+        let mut finish_reason = None;
+        let stream = stream.inspect(move |chunks| match chunks {
+            Ok(chunks) => {
+                for chunk in chunks {
+                    match chunk {
+                        LanguageModelStreamChunk::Usage(u) => usage = Some(u.clone()),
+                        LanguageModelStreamChunk::FinishReason(r) => {
+                            finish_reason = Some(r.clone())
+                        }
+                        LanguageModelStreamChunk::Done(_) => log::info!(
+                            "Streamed with {name} in {:?}, usage: {usage:?}, finish reason: {finish_reason:?}",
+                            start.elapsed()
+                        ),
+                        _ => {}
+                    }
+                }
+            }
+            Err(e) => log::error!("Stream with {name} failed: {e}"),
+        });
+        Ok(Box::pin(stream))
+    }
+}
+
+/// Middleware charging a [`Budget`] with the usage of every call, and rejecting or
+/// truncating calls once it is exhausted.
+///
+/// Calls are checked against the budget before they are made, failing with
+/// [`Error::BudgetExceeded`](crate::Error::BudgetExceeded) once a limit is reached, or
+/// with their `max_output_tokens` capped to what is left under
+/// [`BudgetExhaustedAction::Truncate`](crate::core::budget::BudgetExhaustedAction::Truncate).
+/// The usage of a stream is charged when it completes.
+///
+/// Clones of a budget share its counters: guard each session's model with its own
+/// budget to limit sessions, or every model with clones of one budget to limit them
+/// globally.
+#[derive(Debug, Clone, Default)]
+pub struct BudgetGuard {
+    budget: Budget,
+}
+
+impl BudgetGuard {
+    /// Creates the middleware enforcing `budget`.
+    pub fn new(budget: Budget) -> Self {
+        Self { budget }
+    }
+
+    /// Returns the budget the calls are charged to.
+    pub fn budget(&self) -> &Budget {
+        &self.budget
+    }
+}
+
+#[async_trait]
+impl LanguageModelMiddleware for BudgetGuard {
+    async fn transform_options(
+        &self,
+        mut options: LanguageModelOptions,
+        _call_type: CallType,
+    ) -> Result<LanguageModelOptions> {
+        self.budget.enforce(&mut options)?;
+        Ok(options)
+    }
+
+    async fn wrap_generate<M: LanguageModel>(
+        &self,
+        model: &mut M,
+        options: LanguageModelOptions,
+    ) -> Result<LanguageModelResponse> {
+        let response = model.generate_text(options).await?;
+        if let Some(usage) = &response.usage {
+            self.budget.record(usage);
+        }
+        Ok(response)
+    }
+
+    async fn wrap_stream<M: LanguageModel>(
+        &self,
+        model: &mut M,
+        options: LanguageModelOptions,
+    ) -> Result<ProviderStream> {
+        let stream = model.stream_text(options).await?;
+
+        // Usage reports are cumulative, and charged once, with the first final chunk
+        let budget = self.budget.clone();
+        let mut usage = UsageAccumulator::new();
+        let mut charged = false;
+        let stream = stream.inspect(move |chunks| {
+            for chunk in chunks.iter().flatten() {
+                match chunk {
+                    LanguageModelStreamChunk::Usage(u) => usage.observe(u),
+                    LanguageModelStreamChunk::Done(message) if !charged => {
+                        if let Some(u) = &message.usage {
+                            usage.observe(u);
+                        }
+                        if let Some(u) = usage.finish_response() {
+                            budget.record(&u);
+                        }
+                        charged = true;
+                    }
+                    _ => {}
+                }
+            }
+        });
+        Ok(Box::pin(stream))
+    }
+}
+
+/// Middleware moving the reasoning that models emit inside tags in their text, such as
+/// the `<think>...</think>` of DeepSeek-R1 distills and Qwen3, to reasoning content.
+///
+/// The tagged text is removed from the text of responses and returned as
+/// [`Reasoning`](LanguageModelResponseContentType::Reasoning) content instead, and
+/// streamed as reasoning deltas. Whitespace following a closing tag is dropped.
+#[derive(Debug, Clone)]
+pub struct ExtractReasoning {
+    tag: String,
+    separator: String,
+    start_with_reasoning: bool,
+}
+
+impl Default for ExtractReasoning {
+    fn default() -> Self {
+        Self {
+            tag: "think".to_string(),
+            separator: "\n".to_string(),
+            start_with_reasoning: false,
+        }
+    }
+}
+
+impl ExtractReasoning {
+    /// Creates the middleware extracting the reasoning in `<think>` tags.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the name of the tag holding the reasoning, without angle brackets.
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        self.tag = tag.into();
+        self
+    }
+
+    /// Sets the separator joining the reasoning of several tags, and the text around a
+    /// tag. Defaults to a line break.
+    pub fn separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Sets whether the text starts in reasoning, for models whose chat template opens
+    /// the tag in the prompt so that only the closing tag is generated.
+    pub fn start_with_reasoning(mut self, start_with_reasoning: bool) -> Self {
+        self.start_with_reasoning = start_with_reasoning;
+        self
+    }
+
+    fn open_tag(&self) -> String {
+        format!("<{}>", self.tag)
+    }
+
+    fn close_tag(&self) -> String {
+        format!("</{}>", self.tag)
+    }
+
+    /// Splits `text` into its reasoning, if it has any, and the text outside the tags.
+    fn extract(&self, text: &str) -> (Option<String>, String) {
+        let (open, close) = (self.open_tag(), self.close_tag());
+        let mut reasoning = Vec::new();
+        let mut outside = Vec::new();
+        let mut rest = text;
+        let mut in_reasoning = self.start_with_reasoning;
+        loop {
+            let tag = if in_reasoning { &close } else { &open };
+            let (segment, next) = match rest.find(tag.as_str()) {
+                Some(i) => (&rest[..i], Some(&rest[i + tag.len()..])),
+                None => (rest, None),
+            };
+            if in_reasoning {
+                reasoning.push(segment);
+            } else {
+                let segment = if reasoning.is_empty() {
+                    segment
+                } else {
+                    segment.trim_start()
+                };
+                if !segment.is_empty() {
+                    outside.push(segment);
+                }
+            }
+            match next {
+                Some(next) => rest = next,
+                None => break,
+            }
+            in_reasoning = !in_reasoning;
+        }
+
+        let reasoning = (!reasoning.is_empty()).then(|| reasoning.join(&self.separator));
+        (reasoning, outside.join(&self.separator))
+    }
+
+    /// Moves the reasoning of the text contents of `response` to reasoning contents.
+    fn extract_response(&self, mut response: LanguageModelResponse) -> LanguageModelResponse {
+        let mut contents = Vec::with_capacity(response.contents.len());
+        for content in response.contents {
+            match content {
+                LanguageModelResponseContentType::Text(text) => {
+                    let (reasoning, text) = self.extract(&text);
+                    if let Some(reasoning) = reasoning {
+                        contents.push(LanguageModelResponseContentType::Reasoning {
+                            content: reasoning,
+                            extensions: Default::default(),
+                        });
+                    }
+                    contents.push(LanguageModelResponseContentType::Text(text));
+                }
+                other => contents.push(other),
+            }
+        }
+        response.contents = contents;
+        response
+    }
+}
+
+#[async_trait]
+impl LanguageModelMiddleware for ExtractReasoning {
+    async fn wrap_generate<M: LanguageModel>(
+        &self,
+        model: &mut M,
+        options: LanguageModelOptions,
+    ) -> Result<LanguageModelResponse> {
+        let response = model.generate_text(options).await?;
+        Ok(self.extract_response(response))
+    }
+
+    async fn wrap_stream<M: LanguageModel>(
+        &self,
+        model: &mut M,
+        options: LanguageModelOptions,
+    ) -> Result<ProviderStream> {
+        let stream = model.stream_text(options).await?;
+        let middleware = self.clone();
+        let mut parser = TagParser::new(self);
+        let stream = stream.map(move |chunks| {
+            let mut output = Vec::new();
+            for chunk in chunks? {
+                match chunk {
+                    LanguageModelStreamChunk::Delta(LanguageModelStreamChunkType::Text(text)) => {
+                        output.extend(parser.push(&text));
+                    }
+                    LanguageModelStreamChunk::Done(AssistantMessage {
+                        content: LanguageModelResponseContentType::Text(text),
+                        usage,
+                    }) => {
+                        output.extend(parser.flush());
+                        let (reasoning, text) = middleware.extract(&text);
+                        if let Some(reasoning) = reasoning {
+                            output.push(LanguageModelStreamChunk::Done(AssistantMessage::new(
+                                LanguageModelResponseContentType::Reasoning {
+                                    content: reasoning,
+                                    extensions: Default::default(),
+                                },
+                                usage.clone(),
+                            )));
+                        }
+                        output.push(LanguageModelStreamChunk::Done(AssistantMessage::new(
+                            LanguageModelResponseContentType::Text(text),
+                            usage,
+                        )));
+                    }
+                    other => output.push(other),
+                }
+            }
+            Ok(output)
+        });
+        Ok(Box::pin(stream))
+    }
+}
+
+/// Splits streamed text into text and reasoning deltas at the tags of
+/// [`ExtractReasoning`], holding back text that may be the start of a tag.
+struct TagParser {
+    open: String,
+    close: String,
+    in_reasoning: bool,
+    after_reasoning: bool,
+    buffer: String,
+}
+
+impl TagParser {
+    fn new(middleware: &ExtractReasoning) -> Self {
+        Self {
+            open: middleware.open_tag(),
+            close: middleware.close_tag(),
+            in_reasoning: middleware.start_with_reasoning,
+            after_reasoning: false,
+            buffer: String::new(),
+        }
+    }
+
+    /// Returns the deltas of `text` that can be attributed.
+    fn push(&mut self, text: &str) -> Vec<LanguageModelStreamChunk> {
+        self.buffer.push_str(text);
+        let mut deltas = Vec::new();
+        loop {
+            let tag = if self.in_reasoning {
+                &self.close
+            } else {
+                &self.open
+            };
+            if let Some(i) = self.buffer.find(tag.as_str()) {
+                let tag_len = tag.len();
+                let segment: String = self.buffer.drain(..i).collect();
+                self.buffer.drain(..tag_len);
+                self.emit(segment, &mut deltas);
+                self.in_reasoning = !self.in_reasoning;
+                self.after_reasoning |= !self.in_reasoning;
+                continue;
+            }
+
+            // Hold back the longest suffix that starts a tag
+            let held = tag
+                .char_indices()
+                .skip(1)
+                .map(|(i, _)| i)
+                .filter(|&len| self.buffer.ends_with(&tag[..len]))
+                .max()
+                .unwrap_or(0);
+            let segment: String = self.buffer.drain(..self.buffer.len() - held).collect();
+            self.emit(segment, &mut deltas);
+            return deltas;
+        }
+    }
+
+    /// Returns the deltas of the text held back.
+    fn flush(&mut self) -> Vec<LanguageModelStreamChunk> {
+        let mut deltas = Vec::new();
+        let segment = std::mem::take(&mut self.buffer);
+        self.emit(segment, &mut deltas);
+        deltas
+    }
+
+    fn emit(&mut self, segment: String, deltas: &mut Vec<LanguageModelStreamChunk>) {
+        let delta = if self.in_reasoning {
+            LanguageModelStreamChunkType::Reasoning(segment)
+        } else if self.after_reasoning {
+            let text = segment.trim_start();
+            if text.is_empty() {
+                return;
+            }
+            self.after_reasoning = false;
+            LanguageModelStreamChunkType::Text(text.to_string())
+        } else {
+            LanguageModelStreamChunkType::Text(segment)
+        };
+        if !matches!(&delta, LanguageModelStreamChunkType::Reasoning(s) | LanguageModelStreamChunkType::Text(s) if s.is_empty())
+        {
+            deltas.push(LanguageModelStreamChunk::Delta(delta));
+        }
+    }
+}
+
+/// The number of characters in each text chunk of [`SimulateStreaming`] when none is
+/// set.
+pub const DEFAULT_SIMULATED_CHUNK_SIZE: usize = 16;
+
+/// Middleware serving streams with non-streaming generations, for models or providers
+/// that do not support streaming.
+///
+/// The generated text and reasoning are streamed in chunks of a fixed number of
+/// characters, with an optional delay between them, followed by the usage, the finish
+/// reason and the final contents, as a provider stream would.
+#[derive(Debug, Clone)]
+pub struct SimulateStreaming {
+    chunk_size: usize,
+    delay: Duration,
+}
+
+impl Default for SimulateStreaming {
+    fn default() -> Self {
+        Self {
+            chunk_size: DEFAULT_SIMULATED_CHUNK_SIZE,
+            delay: Duration::ZERO,
+        }
+    }
+}
+
+impl SimulateStreaming {
+    /// Creates the middleware streaming chunks of
+    /// [`DEFAULT_SIMULATED_CHUNK_SIZE`] characters without delay.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the number of characters in each chunk. A size of zero streams each
+    /// content in one chunk.
+    pub fn chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = chunk_size;
+        self
+    }
+
+    /// Sets the delay between chunks.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Splits `text` into chunks of at most `chunk_size` characters.
+    fn split<'a>(&self, text: &'a str) -> Vec<&'a str> {
+        if self.chunk_size == 0 {
+            return vec![text];
+        }
+        let mut chunks = Vec::new();
+        let mut rest = text;
+        while !rest.is_empty() {
+            let end = rest
+                .char_indices()
+                .nth(self.chunk_size)
+                .map_or(rest.len(), |(i, _)| i);
+            chunks.push(&rest[..end]);
+            rest = &rest[end..];
+        }
+        chunks
+    }
+
+    /// Returns the batches of chunks streaming `response`.
+    fn chunks(&self, response: LanguageModelResponse) -> Vec<Vec<LanguageModelStreamChunk>> {
+        let mut batches = vec![vec![LanguageModelStreamChunk::Delta(
+            LanguageModelStreamChunkType::Start,
+        )]];
+        for content in &response.contents {
+            let (text, delta): (_, fn(String) -> _) = match content {
+                LanguageModelResponseContentType::Text(text) => {
+                    (text, LanguageModelStreamChunkType::Text)
+                }
+                LanguageModelResponseContentType::Reasoning { content, .. } => {
+                    (content, LanguageModelStreamChunkType::Reasoning)
+                }
+                _ => continue,
+            };
+            batches.extend(
+                self.split(text)
+                    .into_iter()
+                    .map(|chunk| vec![LanguageModelStreamChunk::Delta(delta(chunk.to_string()))]),
+            );
+        }
+
+        let mut last = Vec::new();
+        if !response.logprobs.is_empty() {
+            last.push(LanguageModelStreamChunk::Delta(
+                LanguageModelStreamChunkType::Logprobs(response.logprobs),
+            ));
+        }
+        if let Some(usage) = &response.usage {
+            last.push(LanguageModelStreamChunk::Usage(usage.clone()));
+        }
+        if let Some(sequence) = response.stop_sequence {
+            last.push(LanguageModelStreamChunk::StopSequence(sequence));
+        }
+        if let Some(reason) = response.finish_reason {
+            last.push(LanguageModelStreamChunk::FinishReason(reason));
+        }
+        last.extend(response.contents.into_iter().map(|content| {
+            LanguageModelStreamChunk::Done(AssistantMessage::new(content, response.usage.clone()))
+        }));
+        batches.push(last);
+        batches
+    }
+}
+
+#[async_trait]
+impl LanguageModelMiddleware for SimulateStreaming {
+    async fn wrap_stream<M: LanguageModel>(
+        &self,
+        model: &mut M,
+        options: LanguageModelOptions,
+    ) -> Result<ProviderStream> {
+        let response = model.generate_text(options).await?;
+        let delay = self.delay;
+        let stream = futures::stream::iter(self.chunks(response).into_iter().enumerate()).then(
+            move |(i, batch)| async move {
+                if i > 0 && !delay.is_zero() {
+                    sleep(delay).await;
+                }
+                Ok(batch)
+            },
+        );
+        Ok(Box::pin(stream))
+    }
+}
+
+#[cfg(all(test, feature = "language-model-request"))]
+mod tests {
+    use super::*;
+    use crate::core::budget::BudgetExhaustedAction;
+    use crate::core::language_model::Usage;
+    use crate::core::test_model::ScriptedModel;
+    use crate::core::{LanguageModelRequest, Message};
+    use crate::error::Error;
+    use std::sync::Mutex;
+
+    /// A model answering with the temperature and the system prompt it was called with.
+    fn echo_model() -> ScriptedModel {
+        ScriptedModel::new("echo").respond(|options| {
+            LanguageModelResponseContentType::new(format!(
+                "{:?} {:?}",
+                options.temperature, options.system
+            ))
+        })
+    }
+
+    /// Records the calls it sees, in the order its hooks run.
+    #[derive(Debug, Default)]
+    struct Recorder {
+        calls: Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl LanguageModelMiddleware for Recorder {
+        async fn transform_options(
+            &self,
+            options: LanguageModelOptions,
+            call_type: CallType,
+        ) -> Result<LanguageModelOptions> {
+            self.calls
+                .lock()
+                .unwrap()
+                .push(format!("transform {call_type:?}"));
+            Ok(options)
+        }
+
+        async fn wrap_generate<M: LanguageModel>(
+            &self,
+            model: &mut M,
+            options: LanguageModelOptions,
+        ) -> Result<LanguageModelResponse> {
+            self.calls
+                .lock()
+                .unwrap()
+                .push(format!("generate {}", model.name()));
+            model.generate_text(options).await
+        }
+    }
+
+    fn defaults() -> DefaultSettings {
+        DefaultSettings::new(LanguageModelOptions {
+            temperature: Some(20),
+            system: Some("Be brief".to_string()),
+            ..Default::default()
+        })
+    }
+
+    #[tokio::test]
+    async fn test_default_settings_fill_unset_options() {
+        let model = wrap_language_model(echo_model(), defaults());
+
+        let response = LanguageModelRequest::builder()
+            .model(model)
+            .prompt("hi")
+            .temperature(70u32)
+            .build()
+            .generate_text()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            response.text().as_deref(),
+            Some(r#"Some(70) Some("Be brief")"#)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_default_settings_apply_to_streams() {
+        let model = wrap_language_model(wrap_language_model(echo_model(), defaults()), Logging);
+
+        let mut response = LanguageModelRequest::builder()
+            .model(model)
+            .prompt("hi")
+            .build()
+            .stream_text()
+            .await
+            .unwrap();
+
+        let texts: Vec<String> = response.text_stream().collect().await;
+        assert_eq!(texts, vec![r#"Some(20) Some("Be brief")"#]);
+    }
+
+    #[tokio::test]
+    async fn test_middleware_hooks_run_in_order() {
+        let mut model = wrap_language_model(echo_model(), Recorder::default());
+
+        model
+            .generate_text(LanguageModelOptions::default())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            *model.middleware().calls.lock().unwrap(),
+            vec!["transform Generate", "generate echo"]
+        );
+    }
+
+    #[test]
+    fn test_wrapped_model_keeps_capabilities() {
+        fn supports_tools<M: ToolCallSupport>(_: &M) {}
+
+        let model = wrap_language_model(echo_model(), Logging);
+        supports_tools(&model);
+        assert_eq!(model.name(), "echo");
+    }
+
+    #[test]
+    fn test_extract_reasoning_from_text() {
+        let middleware = ExtractReasoning::new();
+
+        assert_eq!(
+            middleware.extract("<think>Greet back</think>\n\nHello"),
+            (Some("Greet back".to_string()), "Hello".to_string())
+        );
+        assert_eq!(
+            middleware.extract("Hi <think>a</think> there <think>b</think>"),
+            (Some("a\nb".to_string()), "Hi \nthere ".to_string())
+        );
+        assert_eq!(middleware.extract("Hello"), (None, "Hello".to_string()));
+        assert_eq!(
+            middleware
+                .clone()
+                .start_with_reasoning(true)
+                .extract("Greet back</think>Hello"),
+            (Some("Greet back".to_string()), "Hello".to_string())
+        );
+    }
+
+    fn deltas(chunks: Vec<LanguageModelStreamChunk>) -> Vec<String> {
+        chunks
+            .into_iter()
+            .map(|chunk| match chunk {
+                LanguageModelStreamChunk::Delta(LanguageModelStreamChunkType::Text(t)) => {
+                    format!("text:{t}")
+                }
+                LanguageModelStreamChunk::Delta(LanguageModelStreamChunkType::Reasoning(r)) => {
+                    format!("reasoning:{r}")
+                }
+                other => format!("{other:?}"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_tag_parser_splits_tags_across_chunks() {
+        let mut parser = TagParser::new(&ExtractReasoning::new());
+
+        let mut chunks = Vec::new();
+        for text in [
+            "<thi",
+            "nk>Greet",
+            " back</th",
+            "ink>\n\n",
+            "Hel",
+            "lo <",
+            "3",
+        ] {
+            chunks.extend(parser.push(text));
+        }
+        chunks.extend(parser.flush());
+
+        assert_eq!(
+            deltas(chunks),
+            vec![
+                "reasoning:Greet",
+                "reasoning: back",
+                "text:Hel",
+                "text:lo ",
+                "text:<3"
+            ]
+        );
+    }
+
+    /// A model answering with its reasoning in `<think>` tags.
+    fn thinking_model() -> ScriptedModel {
+        ScriptedModel::new("thinking").text_deltas(&["<think>Greet", " back</think>", "Hello"])
+    }
+
+    #[tokio::test]
+    async fn test_extract_reasoning_from_responses() {
+        let response = LanguageModelRequest::builder()
+            .model(wrap_language_model(
+                thinking_model(),
+                ExtractReasoning::new(),
+            ))
+            .prompt("hi")
+            .build()
+            .generate_text()
+            .await
+            .unwrap();
+
+        assert_eq!(response.text().as_deref(), Some("Hello"));
+        let reasoning = response.messages().into_iter().find_map(|m| match m {
+            Message::Assistant(AssistantMessage {
+                content: LanguageModelResponseContentType::Reasoning { content, .. },
+                ..
+            }) => Some(content),
+            _ => None,
+        });
+        assert_eq!(reasoning.as_deref(), Some("Greet back"));
+    }
+
+    #[tokio::test]
+    async fn test_extract_reasoning_from_streams() {
+        let mut model = wrap_language_model(thinking_model(), ExtractReasoning::new());
+
+        let chunks: Vec<LanguageModelStreamChunk> = model
+            .stream_text(LanguageModelOptions::default())
+            .await
+            .unwrap()
+            .flat_map(|chunks| futures::stream::iter(chunks.unwrap()))
+            .collect()
+            .await;
+
+        assert_eq!(
+            deltas(chunks),
+            vec![
+                "reasoning:Greet".to_string(),
+                "reasoning: back".to_string(),
+                "text:Hello".to_string(),
+                format!(
+                    "{:?}",
+                    LanguageModelStreamChunk::Done(AssistantMessage::new(
+                        LanguageModelResponseContentType::Reasoning {
+                            content: "Greet back".to_string(),
+                            extensions: Default::default(),
+                        },
+                        None,
+                    ))
+                ),
+                format!(
+                    "{:?}",
+                    LanguageModelStreamChunk::Done(AssistantMessage::new(
+                        LanguageModelResponseContentType::Text("Hello".to_string()),
+                        None,
+                    ))
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_simulate_streaming_splits_by_characters() {
+        let middleware = SimulateStreaming::new().chunk_size(3);
+
+        assert_eq!(middleware.split("héllo!"), vec!["hél", "lo!"]);
+        assert_eq!(middleware.split(""), Vec::<&str>::new());
+        assert_eq!(middleware.chunk_size(0).split("hello"), vec!["hello"]);
+    }
+
+    /// A model using 60 input and 40 output tokens per call, answering with the
+    /// output limit it was called with.
+    fn metered_model() -> ScriptedModel {
+        ScriptedModel::new("metered")
+            .respond(|options| {
+                LanguageModelResponseContentType::new(format!("{:?}", options.max_output_tokens))
+            })
+            .usage(Some(Usage {
+                input_tokens: Some(60),
+                output_tokens: Some(40),
+                ..Default::default()
+            }))
+    }
+
+    #[tokio::test]
+    async fn test_budget_guard_rejects_calls_once_exhausted() {
+        let budget = Budget::new().max_total_tokens(150);
+        let mut model = wrap_language_model(metered_model(), BudgetGuard::new(budget.clone()));
+
+        for _ in 0..2 {
+            model
+                .generate_text(LanguageModelOptions::default())
+                .await
+                .unwrap();
+        }
+        assert_eq!(budget.status().total_tokens, 200);
+
+        let error = model
+            .generate_text(LanguageModelOptions::default())
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::BudgetExceeded(_)));
+    }
+
+    #[tokio::test]
+    async fn test_budget_guard_truncates_and_charges_streams() {
+        let budget = Budget::new()
+            .max_output_tokens(100)
+            .on_exhausted(BudgetExhaustedAction::Truncate);
+        let model = wrap_language_model(
+            wrap_language_model(metered_model(), SimulateStreaming::new().chunk_size(0)),
+            BudgetGuard::new(budget.clone()),
+        );
+
+        for expected in ["Some(100)", "Some(60)"] {
+            let mut response = LanguageModelRequest::builder()
+                .model(model.clone())
+                .prompt("hi")
+                .build()
+                .stream_text()
+                .await
+                .unwrap();
+
+            let texts: Vec<String> = response.text_stream().collect().await;
+            assert_eq!(texts, vec![expected]);
+        }
+        assert_eq!(budget.status().total_tokens, 200);
+    }
+
+    #[tokio::test]
+    async fn test_simulate_streaming_streams_generations() {
+        let model = wrap_language_model(echo_model(), SimulateStreaming::new().chunk_size(4));
+
+        let mut response = LanguageModelRequest::builder()
+            .model(model)
+            .prompt("hi")
+            .temperature(5u32)
+            .build()
+            .stream_text()
+            .await
+            .unwrap();
+
+        let texts: Vec<String> = response.text_stream().collect().await;
+        assert_eq!(texts, vec!["Some", "(5) ", "None"]);
+        assert_eq!(response.text().await.as_deref(), Some("Some(5) None"));
+    }
+}
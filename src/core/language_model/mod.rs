@@ -5,31 +5,65 @@
 //! underlying implementation details of different AI providers, offering a
 //! unified interface for various operations like text generation or streaming.
 
+pub mod boxed;
+pub mod fallback;
+#[cfg(feature = "language-model-request")]
+pub mod generate_object;
 #[cfg(feature = "language-model-request")]
 pub mod generate_text;
+pub mod middleware;
 #[cfg(feature = "language-model-request")]
 pub mod request;
+pub mod router;
+pub mod smooth_stream;
+pub mod snapshot;
+#[cfg(feature = "language-model-request")]
+pub mod stream_array;
+#[cfg(feature = "language-model-request")]
+pub mod stream_object;
 #[cfg(feature = "language-model-request")]
 pub mod stream_text;
+pub mod tool_call_assembler;
+pub mod usage_accumulator;
 
+use crate::core::budget::Budget;
+use crate::core::cancellation::CancellationToken;
+#[cfg(feature = "language-model-request")]
+use crate::core::cancellation::Interrupt;
+use crate::core::capabilities::{Capabilities, ModelInfo};
+use crate::core::context_window::ContextWindowPolicy;
+use crate::core::cost::{Cost, CostCalculator};
+use crate::core::effect::Effect;
+#[cfg(feature = "language-model-request")]
+use crate::core::effect::{EffectContext, EffectKind, run_effect};
+use crate::core::guards::{InputGuard, OutputGuard};
+use crate::core::image_model::GeneratedImage;
 use crate::core::messages::{AssistantMessage, TaggedMessage, TaggedMessageHelpers};
-use crate::core::tools::ToolList;
+#[cfg(feature = "language-model-request")]
+use crate::core::observability::TelemetryOptions;
+use crate::core::rate_limit::RateLimitInfo;
+#[cfg(feature = "language-model-request")]
+use crate::core::runtime::AbortOnDrop;
+use crate::core::tokens;
+use crate::core::tools::{ToolChoice, ToolList};
 use crate::core::{
     Message,
-    tools::{ToolCallInfo, ToolResultInfo},
+    tools::{ToolCallDelta, ToolCallInfo, ToolResultInfo},
 };
 use crate::core::{Messages, utils};
 use crate::error::{Error, Result};
 use async_trait::async_trait;
 use derive_builder::Builder;
-use futures::Stream;
+use futures::{Stream, StreamExt};
 use schemars::Schema;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt::Debug;
 use std::ops::Add;
 use std::pin::Pin;
 use std::sync::Arc;
 use std::task::{Context, Poll};
+use std::time::Duration;
 use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
 
 // ============================================================================
@@ -61,6 +95,49 @@ pub trait LanguageModel: Send + Sync + std::fmt::Debug + Clone + 'static {
     ///
     /// A string representing the model's name (e.g., "gpt-4", "claude-3").
     fn name(&self) -> String;
+
+    /// Returns the context length of the model in tokens, if known.
+    ///
+    /// Defaults to the context window of the model's [`info`](Self::info).
+    fn context_length(&self) -> Option<usize> {
+        self.info().context_window
+    }
+
+    /// Returns the sampling options the model does not support. When one of them is
+    /// set, it is not sent and the response carries a
+    /// [`Warning::UnsupportedOption`] instead.
+    fn unsupported_options(&self) -> &'static [SamplingOption] {
+        &[]
+    }
+
+    /// Returns the capabilities of the model, known at runtime.
+    ///
+    /// Defaults to unknown capabilities. Providers return the capabilities of their
+    /// model, see [`ModelName::CAPABILITIES`](crate::core::capabilities::ModelName::CAPABILITIES).
+    fn capabilities(&self) -> Capabilities {
+        Capabilities::UNKNOWN
+    }
+
+    /// Returns the limits and pricing of the model, known at runtime.
+    ///
+    /// Defaults to unknown. Providers return those of their model, see
+    /// [`ModelName::INFO`](crate::core::capabilities::ModelName::INFO).
+    fn info(&self) -> ModelInfo {
+        ModelInfo::UNKNOWN
+    }
+
+    /// Counts the input tokens of a request with the model's tokenizer.
+    ///
+    /// Defaults to [`estimate`](tokens::estimate). Providers with a tokenizer or a
+    /// counting endpoint override it, see [`tokens`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the counting request fails.
+    async fn count_tokens(&self, options: &LanguageModelOptions) -> Result<usize> {
+        Ok(tokens::estimate(options))
+    }
+
     /// Performs a single, non-streaming text generation request.
     ///
     /// This method sends a prompt to the model and returns the entire response at once.
@@ -98,6 +175,16 @@ pub type OnStepStartHook = Arc<dyn Fn(&mut LanguageModelOptions) + Send + Sync>;
 /// Type alias for a hook function called after each generation step.
 pub type OnStepFinishHook = Arc<dyn Fn(&LanguageModelOptions) + Send + Sync>;
 
+/// Type alias for a hook function called with each event of a stream.
+pub type OnChunkHook = Arc<dyn Fn(&StreamEvent) + Send + Sync>;
+
+/// Type alias for a hook function called once generation completes, with the final
+/// options and the total token usage.
+pub type OnFinishHook = Arc<dyn Fn(&LanguageModelOptions, &Usage) + Send + Sync>;
+
+/// Type alias for a hook function called when generation fails.
+pub type OnErrorHook = Arc<dyn Fn(&Error) + Send + Sync>;
+
 // ============================================================================
 // Section: structs and impls
 // ============================================================================
@@ -218,6 +305,25 @@ pub struct LanguageModelOptions {
     /// to repeatedly use the same words or phrases.
     pub frequency_penalty: Option<f32>,
 
+    /// Whether to return the log probabilities of the generated tokens.
+    pub logprobs: Option<bool>,
+
+    /// Number of most likely tokens to return, with their log probabilities, at each
+    /// position. Implies `logprobs`.
+    pub top_logprobs: Option<u32>,
+
+    /// Maximum number of model calls in the tool execution loop. Unlimited when unset.
+    pub max_steps: Option<usize>,
+
+    /// Maximum time allowed for the whole call, including every step and tool call.
+    pub timeout: Option<Duration>,
+
+    /// Maximum time to wait for the next event of a provider stream.
+    pub stream_idle_timeout: Option<Duration>,
+
+    /// What a stream does when its idle timeout elapses.
+    pub stall_action: StallAction,
+
     /// Hook to conditionally stop generation.
     pub stop_when: Option<StopWhenHook>,
 
@@ -227,12 +333,65 @@ pub struct LanguageModelOptions {
     /// Hook called after each generation step.
     pub on_step_finish: Option<OnStepFinishHook>,
 
+    /// Hook called with each event of a stream, except errors.
+    pub on_chunk: Option<OnChunkHook>,
+
+    /// Hook called once generation completes successfully.
+    pub on_finish: Option<OnFinishHook>,
+
+    /// Hook called when generation fails.
+    pub on_error: Option<OnErrorHook>,
+
+    /// Whether streams also yield the chunks sent by the provider, as
+    /// [`StreamEvent::Raw`].
+    pub include_raw_chunks: bool,
+
+    /// Whether streams also yield the payloads of the provider's server-sent events, as
+    /// [`StreamEvent::ProviderEvent`].
+    pub raw_events: bool,
+
     /// Level of reasoning effort for the model.
     pub reasoning_effort: Option<ReasoningEffort>,
 
     /// List of tools to use.
     pub(crate) tools: Option<ToolList>,
 
+    /// Whether, and which, tools the model calls.
+    pub tool_choice: Option<ToolChoice>,
+
+    /// Whether the model may call several tools in one response. Disable it when
+    /// tools must run one after another, e.g. because they mutate shared state.
+    pub parallel_tool_calls: Option<bool>,
+
+    /// Provider-specific fields merged into the request body, for fields the SDK does
+    /// not model yet (e.g. `service_tier`).
+    pub provider_options: serde_json::Map<String, serde_json::Value>,
+
+    /// Guards applied to the conversation before each request is sent.
+    pub(crate) input_guards: Vec<Arc<dyn InputGuard>>,
+
+    /// Guards applied to generated content before it is recorded.
+    pub(crate) output_guards: Vec<Arc<dyn OutputGuard>>,
+
+    /// Budget charged with the usage of every model call.
+    pub(crate) budget: Option<Budget>,
+
+    /// Calculator pricing the usage of every model call.
+    pub(crate) cost_calculator: Option<CostCalculator>,
+
+    /// Policy trimming the messages of every model call to the context window.
+    pub(crate) context_window: Option<ContextWindowPolicy>,
+
+    /// Effect that model and tool calls are routed through.
+    pub(crate) effect: Option<Arc<dyn Effect>>,
+
+    /// Token that cancels the call when triggered.
+    pub(crate) cancellation_token: Option<CancellationToken>,
+
+    /// Whether and how the call is recorded for observability platforms.
+    #[cfg(feature = "language-model-request")]
+    pub(crate) telemetry: Option<TelemetryOptions>,
+
     /// Current step ID for tracking multi-step interactions.
     pub(crate) current_step_id: usize,
 
@@ -241,12 +400,31 @@ pub struct LanguageModelOptions {
 
     /// The reason why generation stopped.
     pub(crate) stop_reason: Option<StopReason>,
+
+    /// Why the model stopped generating the last response.
+    pub(crate) finish_reason: Option<FinishReason>,
+
+    /// Provider-specific extensions of the last model response.
+    pub(crate) response_extensions: crate::extensions::Extensions,
+
+    /// Warnings about the call, such as options the model does not support.
+    pub(crate) warnings: Vec<Warning>,
+
+    /// Log probabilities of the tokens of the last model response.
+    pub(crate) response_logprobs: Vec<TokenLogprob>,
+
+    /// Cost of the model calls made so far, when priced by the cost calculator.
+    pub(crate) cost: Option<Cost>,
+
+    /// The snapshot the options were restored from, applied when the request runs.
+    #[cfg(feature = "language-model-request")]
+    pub(crate) resume: Option<snapshot::Resume>,
 }
 
 impl Debug for LanguageModelOptions {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("LanguageModelOptions")
-            .field("system", &self.system)
+        let mut s = f.debug_struct("LanguageModelOptions");
+        s.field("system", &self.system)
             .field("messages", &self.messages)
             .field("schema", &self.schema)
             .field("seed", &self.seed)
@@ -258,11 +436,32 @@ impl Debug for LanguageModelOptions {
             .field("stop_sequences", &self.stop_sequences)
             .field("presence_penalty", &self.presence_penalty)
             .field("frequency_penalty", &self.frequency_penalty)
+            .field("logprobs", &self.logprobs)
+            .field("top_logprobs", &self.top_logprobs)
             .field("tools", &self.tools)
+            .field("tool_choice", &self.tool_choice)
+            .field("parallel_tool_calls", &self.parallel_tool_calls)
+            .field("provider_options", &self.provider_options)
+            .field("input_guards", &self.input_guards.len())
+            .field("output_guards", &self.output_guards.len())
+            .field("budget", &self.budget)
+            .field("cost_calculator", &self.cost_calculator)
+            .field("context_window", &self.context_window)
+            .field("effect", &self.effect.is_some())
             .field("current_step_id", &self.current_step_id)
-            .field("stop_when", &self.stop_when.is_some())
+            .field("max_steps", &self.max_steps)
+            .field("timeout", &self.timeout)
+            .field("stream_idle_timeout", &self.stream_idle_timeout)
+            .field("stall_action", &self.stall_action)
+            .field("cancellation_token", &self.cancellation_token.is_some());
+        #[cfg(feature = "language-model-request")]
+        s.field("telemetry", &self.telemetry);
+        s.field("stop_when", &self.stop_when.is_some())
             .field("on_step_start", &self.on_step_start.is_some())
             .field("on_step_finish", &self.on_step_finish.is_some())
+            .field("on_chunk", &self.on_chunk.is_some())
+            .field("on_finish", &self.on_finish.is_some())
+            .field("on_error", &self.on_error.is_some())
             .finish()
     }
 }
@@ -278,20 +477,64 @@ impl LanguageModelOptions {
         self.messages.iter().map(|m| m.message.clone()).collect()
     }
 
+    /// Returns the options sent with the next model call: input guards applied, the
+    /// messages fitted to the context window, and the budget enforced.
+    ///
+    /// Guards run first so the summarizer of a context-window policy never sees
+    /// unguarded content.
+    #[cfg(feature = "language-model-request")]
+    pub(crate) async fn prepare_request<M: LanguageModel>(
+        &self,
+        model: &M,
+    ) -> Result<LanguageModelOptions> {
+        let mut request_options = self.apply_input_guards().await?;
+        if let Some(policy) = &self.context_window {
+            policy.apply(&mut request_options, model).await?;
+        }
+        if let Some(budget) = &self.budget {
+            budget.enforce(&mut request_options)?;
+        }
+        Ok(request_options)
+    }
+
     /// Executes a tool call and adds the result to the message history.
-    pub(crate) async fn handle_tool_call(&mut self, input: &ToolCallInfo) -> &mut Self {
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Cancelled`] or [`Error::Timeout`], without adding a result, if
+    /// `interrupt` fires while the tool runs.
+    #[cfg(feature = "language-model-request")]
+    pub(crate) async fn handle_tool_call(
+        &mut self,
+        input: &ToolCallInfo,
+        interrupt: &Interrupt,
+    ) -> Result<()> {
         if let Some(tools) = &self.tools {
-            let tool_result_task = tools.execute(input.clone()).await;
-            let tool_result = tool_result_task
-                .await
-                .map_err(|err| Error::ToolCallError(format!("Error executing tool: {err}")))
-                .and_then(|result| result);
+            let ctx = EffectContext::new(
+                self.current_step_id,
+                EffectKind::ToolCall {
+                    name: input.tool.name.clone(),
+                    id: input.tool.id.clone(),
+                },
+            );
+            // The tool is aborted when the call is interrupted
+            let execution = run_effect(self.effect.as_ref(), ctx, async {
+                AbortOnDrop(tools.execute(input.clone()).await)
+                    .await
+                    .map_err(|err| Error::ToolCallError(format!("Error executing tool: {err}")))
+                    .and_then(|result| result)
+            });
+            let tool_result = interrupt.run(async { Ok(execution.await) }).await?;
 
             let mut tool_output_infos = Vec::new();
 
             let mut tool_output_info = ToolResultInfo::new(&input.tool.name);
-            let output = match tool_result {
-                Ok(result) => serde_json::Value::String(result),
+            let policy = tools.policy(&input.tool.name);
+            let output = match tool_result.and_then(|result| policy.apply(result)) {
+                Ok(result) => {
+                    tool_output_info.images = result.images;
+                    serde_json::Value::String(result.text)
+                }
                 Err(err) => serde_json::Value::String(format!("Error: {err}")),
             };
             tool_output_info.output(output);
@@ -303,11 +546,8 @@ impl LanguageModelOptions {
                 self.current_step_id,
                 Message::Tool(tool_output_info),
             ));
-
-            self
-        } else {
-            self
         }
+        Ok(())
     }
 
     /// Returns the step with the given index, if it exists.
@@ -391,6 +631,22 @@ impl LanguageModelOptions {
         }
     }
 
+    /// Returns the refusal of the last response, if the model refused to answer or the
+    /// provider blocked it.
+    pub fn refusal(&self) -> Option<String> {
+        self.messages
+            .iter()
+            .rev()
+            .take_while(|msg| msg.step_id == self.current_step_id)
+            .find_map(|msg| match msg.message {
+                Message::Assistant(AssistantMessage {
+                    content: LanguageModelResponseContentType::Refusal(ref refusal),
+                    ..
+                }) => Some(refusal.clone()),
+                _ => None,
+            })
+    }
+
     /// Extracts all tool results from the conversation.
     pub fn tool_results(&self) -> Option<Vec<ToolResultInfo>> {
         self.messages.as_slice().extract_tool_results()
@@ -401,10 +657,107 @@ impl LanguageModelOptions {
         self.messages.as_slice().extract_tool_calls()
     }
 
+    /// Extracts all sources the responses in the conversation were grounded in.
+    pub fn sources(&self) -> Option<Vec<Source>> {
+        self.messages.as_slice().extract_sources()
+    }
+
+    /// Extracts all images generated in the conversation.
+    pub fn images(&self) -> Option<Vec<GeneratedImage>> {
+        self.messages.as_slice().extract_images()
+    }
+
+    /// Starts the timeout and returns the interrupt for this call.
+    #[cfg(feature = "language-model-request")]
+    pub(crate) fn interrupt(&self) -> Interrupt {
+        Interrupt::new(self.cancellation_token.clone(), self.timeout)
+    }
+
+    /// Records `error` as the reason generation stopped and passes it to the `on_error`
+    /// hook.
+    #[cfg(feature = "language-model-request")]
+    pub(crate) fn fail(&mut self, error: Error) {
+        if let Some(hook) = &self.on_error {
+            hook(&error);
+        }
+        self.stop_reason = Some(StopReason::Error(error));
+    }
+
+    /// Calls the `on_finish` hook with the total `usage`, unless generation failed.
+    #[cfg(feature = "language-model-request")]
+    pub(crate) fn finish(&self, usage: &Usage) {
+        if let Some(hook) = &self.on_finish
+            && !matches!(self.stop_reason, Some(StopReason::Error(_)))
+        {
+            hook(self, usage);
+        }
+    }
+
+    /// Returns `true` once the loop has made `max_steps` model calls.
+    #[cfg(feature = "language-model-request")]
+    pub(crate) fn max_steps_reached(&self) -> bool {
+        self.max_steps
+            .is_some_and(|max_steps| self.current_step_id >= max_steps)
+    }
+
     /// Returns the reason why generation stopped.
     pub fn stop_reason(&self) -> Option<StopReason> {
         self.stop_reason.clone()
     }
+
+    /// Returns why the model stopped generating the last response, if the provider
+    /// reports it.
+    pub fn finish_reason(&self) -> Option<FinishReason> {
+        self.finish_reason.clone()
+    }
+
+    /// Returns the provider-specific extensions of the last model response, such as
+    /// the OpenAI `system_fingerprint`.
+    pub fn response_extensions(&self) -> &crate::extensions::Extensions {
+        &self.response_extensions
+    }
+
+    /// Returns the log probabilities of the tokens of the last model response, if
+    /// requested with [`logprobs`](Self::logprobs) and returned by the provider.
+    pub fn response_logprobs(&self) -> &[TokenLogprob] {
+        &self.response_logprobs
+    }
+
+    /// Returns the warnings about the call, such as options the model does not
+    /// support.
+    pub fn warnings(&self) -> &[Warning] {
+        &self.warnings
+    }
+
+    /// Returns the cost of the model calls, summed over the steps, if a
+    /// [`cost_calculator`](crate::core::language_model::request::LanguageModelRequestBuilder::cost_calculator)
+    /// is attached and priced them.
+    pub fn cost(&self) -> Option<Cost> {
+        self.cost
+    }
+
+    /// Prices the usage of a model call with the cost calculator and adds it to the
+    /// cost of the request.
+    #[cfg(feature = "language-model-request")]
+    pub(crate) fn record_cost<M: LanguageModel>(&mut self, model: &M, usage: &Usage) {
+        let Some(calculator) = &self.cost_calculator else {
+            return;
+        };
+        if let Some(cost) = calculator.record(&model.name(), model.info().pricing, usage) {
+            *self.cost.get_or_insert_default() += cost;
+        }
+    }
+
+    /// Records a warning for every set option the model does not support.
+    #[cfg(feature = "language-model-request")]
+    pub(crate) fn warn_unsupported<M: LanguageModel>(&mut self, model: &M) {
+        for option in model.unsupported_options() {
+            let warning = Warning::UnsupportedOption(*option);
+            if option.is_set(self) && !self.warnings.contains(&warning) {
+                self.warnings.push(warning);
+            }
+        }
+    }
 }
 
 // ============================================================================
@@ -412,7 +765,8 @@ impl LanguageModelOptions {
 // ============================================================================
 
 /// The different types of content that can be generated by a language model.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum LanguageModelResponseContentType {
     /// Plain text response.
     Text(String),
@@ -423,12 +777,61 @@ pub enum LanguageModelResponseContentType {
         /// The reasoning/thinking content
         content: String,
         /// Provider-specific extensions
+        #[serde(
+            default,
+            skip_serializing_if = "crate::extensions::Extensions::is_empty"
+        )]
         extensions: crate::extensions::Extensions,
     },
+    /// A source the response was grounded in, such as a web search result.
+    Source(Source),
+    /// An image generated inline with the response, such as by Gemini image models.
+    Image(GeneratedImage),
+    /// The model refused to answer, or the provider blocked the response, with the
+    /// refusal message or reason.
+    Refusal(String),
     /// Feature not supported by the provider.
     NotSupported(String),
 }
 
+/// The log probability of a generated token.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TokenLogprob {
+    /// The token.
+    pub token: String,
+    /// The log probability of the token.
+    pub logprob: f64,
+    /// The most likely tokens at this position, if requested with
+    /// [`LanguageModelOptions::top_logprobs`].
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub top_logprobs: Vec<TokenLogprob>,
+}
+
+/// A source a response was grounded in, such as a web page found by a search.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Source {
+    /// The URL of the source.
+    pub url: String,
+    /// The title of the source, if known.
+    pub title: Option<String>,
+    /// Provider-specific extensions
+    #[serde(
+        default,
+        skip_serializing_if = "crate::extensions::Extensions::is_empty"
+    )]
+    pub extensions: crate::extensions::Extensions,
+}
+
+impl Source {
+    /// Creates a source for a URL.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            ..Default::default()
+        }
+    }
+}
+
 impl Default for LanguageModelResponseContentType {
     fn default() -> Self {
         Self::Text(String::new())
@@ -449,7 +852,7 @@ impl LanguageModelResponseContentType {
 }
 
 /// Token usage statistics for a language model operation.
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Usage {
     /// Number of input tokens processed.
     pub input_tokens: Option<usize>,
@@ -475,13 +878,33 @@ impl Add for &Usage {
 }
 
 /// Response from a language model.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LanguageModelResponse {
     /// The generated contents (supports multiple outputs).
     pub contents: Vec<LanguageModelResponseContentType>,
 
     /// Usage information
     pub usage: Option<Usage>,
+
+    /// The stop sequence that ended generation, if the provider reports it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stop_sequence: Option<String>,
+
+    /// Why the model stopped generating, if the provider reports it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub finish_reason: Option<FinishReason>,
+
+    /// Log probabilities of the generated tokens, if requested with
+    /// [`LanguageModelOptions::logprobs`].
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub logprobs: Vec<TokenLogprob>,
+
+    /// Provider-specific extensions, such as the OpenAI `system_fingerprint`.
+    #[serde(
+        default,
+        skip_serializing_if = "crate::extensions::Extensions::is_empty"
+    )]
+    pub extensions: crate::extensions::Extensions,
 }
 
 impl LanguageModelResponse {
@@ -490,11 +913,27 @@ impl LanguageModelResponse {
         Self {
             contents: vec![LanguageModelResponseContentType::new(text.into())],
             usage: None,
+            stop_sequence: None,
+            finish_reason: None,
+            logprobs: Vec::new(),
+            extensions: Default::default(),
         }
     }
+
+    /// Stores the rate limits the provider reported in the headers of the response, if
+    /// any, in the extensions of the response.
+    #[allow(dead_code)]
+    pub(crate) fn with_rate_limit(self, rate_limit: Option<RateLimitInfo>) -> Self {
+        if let Some(rate_limit) = rate_limit {
+            self.extensions.insert(rate_limit);
+        }
+        self
+    }
 }
 
-/// Types of chunks that can be emitted during streaming text generation.
+/// Types of chunks that providers emit during streaming text generation.
+///
+/// The core turns them into the [`StreamEvent`]s of a [`LanguageModelStream`].
 #[derive(Default, Debug, Clone)]
 pub enum LanguageModelStreamChunkType {
     /// Indicates the start of generation.
@@ -506,6 +945,10 @@ pub enum LanguageModelStreamChunkType {
     Reasoning(String),
     /// Tool call argument chunk
     ToolCall(String),
+    /// Log probabilities of the tokens of the preceding text chunk.
+    Logprobs(Vec<TokenLogprob>),
+    /// A tool call whose arguments have been fully streamed and parsed.
+    ToolCallReady(ToolCallInfo),
     /// Successful completion of generation.
     End(AssistantMessage),
     /// Generation failed with an error message.
@@ -521,17 +964,101 @@ pub enum LanguageModelStreamChunkType {
 pub enum LanguageModelStreamChunk {
     /// An incremental update during streaming.
     Delta(LanguageModelStreamChunkType),
+    /// A fragment of tool call arguments, attributed to its tool call so the core
+    /// can assemble it.
+    ToolCallDelta(ToolCallDelta),
+    /// A usage report for the response being streamed. Reports are cumulative
+    /// snapshots of the same response, not increments.
+    Usage(Usage),
+    /// The stop sequence that ended the response being streamed. Sent before
+    /// [`Done`](Self::Done).
+    StopSequence(String),
+    /// Why the model stopped generating the response being streamed. Sent before
+    /// [`Done`](Self::Done).
+    FinishReason(FinishReason),
+    /// The rate limits the provider reported in the headers of the response that
+    /// opened the stream. Sent first.
+    RateLimit(RateLimitInfo),
     /// The final result when streaming is complete.
     Done(AssistantMessage),
 }
 
 /// A common interface for stream responses generated by providers (e.g. OpenAI)
-pub(crate) type ProviderStream =
-    Pin<Box<dyn Stream<Item = Result<Vec<LanguageModelStreamChunk>>> + Send>>;
+pub type ProviderStream = Pin<Box<dyn Stream<Item = Result<Vec<LanguageModelStreamChunk>>> + Send>>;
+
+/// Starts `stream` with a [`RateLimit`](LanguageModelStreamChunk::RateLimit) chunk
+/// holding `rate_limit`, if the provider reported any.
+#[allow(dead_code)]
+pub(crate) fn stream_with_rate_limit(
+    rate_limit: Option<RateLimitInfo>,
+    stream: ProviderStream,
+) -> ProviderStream {
+    let chunk = rate_limit.map(|info| Ok(vec![LanguageModelStreamChunk::RateLimit(info)]));
+    Box::pin(futures::stream::iter(chunk).chain(stream))
+}
+
+/// An event of a streamed text generation, yielded by [`LanguageModelStream`].
+#[derive(Debug, Clone)]
+pub enum StreamEvent {
+    /// The stream started. Sent once, before the first step.
+    StreamStart,
+    /// A model call started.
+    StepStart,
+    /// A chunk of generated text.
+    TextDelta(String),
+    /// A chunk of reasoning text.
+    ReasoningDelta(String),
+    /// A fragment of tool call arguments. The tool details are empty when the provider
+    /// does not attribute fragments to a tool call.
+    ToolCallDelta(ToolCallDelta),
+    /// A tool call whose arguments have been fully streamed and parsed.
+    ToolCall(ToolCallInfo),
+    /// The result of executing a tool call.
+    ToolResult(ToolResultInfo),
+    /// A source the response is grounded in, e.g. a web search result.
+    Source(Source),
+    /// A file generated by the model, e.g. an image.
+    File(GeneratedImage),
+    /// Log probabilities of the tokens of the preceding text delta.
+    Logprobs(Vec<TokenLogprob>),
+    /// A model call finished.
+    StepFinish {
+        /// The token usage of the model call, if the provider reported it.
+        usage: Option<Usage>,
+        /// Why the model stopped generating, if the provider reported it.
+        finish_reason: Option<FinishReason>,
+    },
+    /// The stream finished. Sent once, last.
+    Finish {
+        /// The token usage of all model calls.
+        usage: Usage,
+        /// Why generation stopped.
+        stop_reason: StopReason,
+    },
+    /// The provider stream sent no event for the given time. Only sent with
+    /// [`StallAction::Notify`]; the stream keeps waiting.
+    Stalled(Duration),
+    /// Generation failed. Followed by [`Finish`](Self::Finish).
+    Error(Error),
+    /// A chunk as sent by the provider. Only sent when requested with
+    /// [`include_raw_chunks`](LanguageModelOptions::include_raw_chunks).
+    Raw(LanguageModelStreamChunk),
+    /// The payload of a server-sent event of the provider, sent before the events
+    /// normalized from it. Only sent when requested with
+    /// [`raw_events`](LanguageModelOptions::raw_events).
+    ProviderEvent(serde_json::Value),
+}
 
 /// A stream wrapper that provides a channel-based interface for language model streaming.
 pub struct LanguageModelStream {
-    receiver: UnboundedReceiver<LanguageModelStreamChunkType>,
+    receiver: StreamReceiver,
+}
+
+/// The channel a [`LanguageModelStream`] receives its events from.
+enum StreamReceiver {
+    Unbounded(UnboundedReceiver<StreamEvent>),
+    #[cfg(feature = "language-model-request")]
+    Bounded(mpsc::Receiver<StreamEvent>),
 }
 
 impl LanguageModelStream {
@@ -542,42 +1069,132 @@ impl LanguageModelStream {
     ///
     /// # Returns
     ///
-    /// A tuple of `(UnboundedSender<LanguageModelStreamChunkType>, LanguageModelStream)`.
-    pub fn new() -> (
-        UnboundedSender<LanguageModelStreamChunkType>,
-        LanguageModelStream,
-    ) {
+    /// A tuple of `(UnboundedSender<StreamEvent>, LanguageModelStream)`.
+    pub fn new() -> (UnboundedSender<StreamEvent>, LanguageModelStream) {
         let (tx, rx) = mpsc::unbounded_channel();
-        (tx, LanguageModelStream { receiver: rx })
+        let receiver = StreamReceiver::Unbounded(rx);
+        (tx, LanguageModelStream { receiver })
+    }
+
+    /// Creates a new stream whose sender waits while `capacity` chunks are buffered.
+    #[cfg(feature = "language-model-request")]
+    pub(crate) fn bounded(capacity: usize) -> (mpsc::Sender<StreamEvent>, LanguageModelStream) {
+        let (tx, rx) = mpsc::channel(capacity);
+        let receiver = StreamReceiver::Bounded(rx);
+        (tx, LanguageModelStream { receiver })
     }
 }
 
 impl Stream for LanguageModelStream {
-    type Item = LanguageModelStreamChunkType;
+    type Item = StreamEvent;
     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        self.receiver.poll_recv(cx)
+        match &mut self.receiver {
+            StreamReceiver::Unbounded(receiver) => receiver.poll_recv(cx),
+            #[cfg(feature = "language-model-request")]
+            StreamReceiver::Bounded(receiver) => receiver.poll_recv(cx),
+        }
     }
 }
 
+/// What a stream does when its provider sends no event within the
+/// [`stream_idle_timeout`](LanguageModelOptions::stream_idle_timeout).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StallAction {
+    /// Abort the stream with [`Error::StreamStalled`].
+    #[default]
+    Abort,
+    /// Yield [`StreamEvent::Stalled`] and keep waiting.
+    Notify,
+}
+
 /// Reasons why text generation might stop.
 #[derive(Debug, Clone, PartialEq, Default)]
 pub enum StopReason {
     /// Generation completed successfully.
     #[default]
     Finish,
+    /// Generation completed at one of the
+    /// [`stop_sequences`](LanguageModelOptions::stop_sequences), which is given.
+    StopSequence(String),
     /// Provider-specific stop reason (e.g., timeout, rate limit).
     Provider(String),
     /// The user has explicitly provided a hook causing to stop
     Hook,
+    /// The tool execution loop reached `max_steps` while the model still had tool calls.
+    MaxSteps,
     /// Stopped due to an error.
     Error(Error),
     /// Other unspecified reason.
     Other(String),
 }
 
+/// Why a model stopped generating a response, normalized across providers.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FinishReason {
+    /// The model finished its response, or generated a stop sequence.
+    Stop,
+    /// The response reached the maximum number of output tokens.
+    Length,
+    /// The model stopped to call tools.
+    ToolCalls,
+    /// The response was withheld or cut by a content filter.
+    ContentFilter,
+    /// The provider failed to generate the response.
+    Error,
+    /// A provider-specific reason, which is given as reported.
+    Other(String),
+}
+
+/// A warning about a call that still succeeded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Warning {
+    /// A set option the model does not support, which was not sent.
+    UnsupportedOption(SamplingOption),
+}
+
+/// A sampling option of [`LanguageModelOptions`] that not every model supports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SamplingOption {
+    /// [`LanguageModelOptions::seed`].
+    Seed,
+    /// [`LanguageModelOptions::temperature`].
+    Temperature,
+    /// [`LanguageModelOptions::top_p`].
+    TopP,
+    /// [`LanguageModelOptions::top_k`].
+    TopK,
+    /// [`LanguageModelOptions::stop_sequences`].
+    StopSequences,
+    /// [`LanguageModelOptions::presence_penalty`].
+    PresencePenalty,
+    /// [`LanguageModelOptions::frequency_penalty`].
+    FrequencyPenalty,
+}
+
+impl SamplingOption {
+    /// Returns `true` if the option is set.
+    pub fn is_set(self, options: &LanguageModelOptions) -> bool {
+        match self {
+            Self::Seed => options.seed.is_some(),
+            Self::Temperature => options.temperature.is_some(),
+            Self::TopP => options.top_p.is_some(),
+            Self::TopK => options.top_k.is_some(),
+            Self::StopSequences => options.stop_sequences.is_some(),
+            Self::PresencePenalty => options.presence_penalty.is_some(),
+            Self::FrequencyPenalty => options.frequency_penalty.is_some(),
+        }
+    }
+}
+
 /// Levels of reasoning effort for language models that support it.
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum ReasoningEffort {
+    /// Minimal reasoning effort, for the fastest responses.
+    Minimal,
     /// Low reasoning effort.
     #[default]
     Low,
@@ -4,14 +4,27 @@
 //! and options for generating text or streaming responses. It includes a type-state builder
 //! pattern to ensure requests are constructed correctly and safely.
 
+use crate::Error;
 use crate::core::Messages;
+use crate::core::budget::Budget;
+use crate::core::cancellation::CancellationToken;
 use crate::core::capabilities::*;
-use crate::core::language_model::{LanguageModel, LanguageModelOptions};
-use crate::core::tools::Tool;
+use crate::core::context_window::ContextWindowPolicy;
+use crate::core::cost::CostCalculator;
+use crate::core::effect::Effect;
+use crate::core::guards::{InputGuard, OutputGuard};
+use crate::core::language_model::snapshot::AgentSnapshot;
+use crate::core::language_model::{
+    LanguageModel, LanguageModelOptions, StallAction, StreamEvent, Usage,
+};
+use crate::core::messages::{ContentPart, Message, UserMessage};
+use crate::core::observability::TelemetryOptions;
+use crate::core::tools::{Tool, ToolChoice, ToolResultPolicy};
 use schemars::{JsonSchema, schema_for};
 use std::fmt::Debug;
 use std::ops::{Deref, DerefMut};
 use std::sync::Arc;
+use std::time::Duration;
 
 /// Options for text generation requests such as `generate_text` and `stream_text`.
 #[derive(Debug)]
@@ -220,6 +233,35 @@ impl<M: LanguageModel> LanguageModelRequestBuilder<M, SystemStage> {
     }
 }
 
+impl<M: LanguageModel> LanguageModelRequestBuilder<M, SystemStage> {
+    /// Restores the agent loop state from a snapshot.
+    ///
+    /// The conversation history, step count, system prompt, and request settings are
+    /// taken from the snapshot. Tools, hooks, guards, and the budget must be registered
+    /// again. When the request runs, the snapshot's budget counters are loaded into the
+    /// attached budget and the tool calls that were still pending are executed first.
+    ///
+    /// # Parameters
+    ///
+    /// * `snapshot` - A snapshot taken with [`LanguageModelOptions::snapshot`].
+    ///
+    /// # Returns
+    ///
+    /// The builder in the [`OptionsStage`] state.
+    pub fn restore(
+        mut self,
+        snapshot: AgentSnapshot,
+    ) -> LanguageModelRequestBuilder<M, OptionsStage> {
+        self.options.restore(snapshot);
+        LanguageModelRequestBuilder {
+            model: self.model,
+            prompt: None,
+            options: self.options,
+            state: std::marker::PhantomData,
+        }
+    }
+}
+
 /// Methods available in the [`ConversationStage`] state.
 impl<M: LanguageModel> LanguageModelRequestBuilder<M, ConversationStage> {
     /// Sets a simple text prompt for the request.
@@ -246,6 +288,64 @@ impl<M: LanguageModel> LanguageModelRequestBuilder<M, ConversationStage> {
         }
     }
 
+    /// Sets a multi-part prompt for the request, e.g. text with images or files.
+    ///
+    /// Only available for models that accept image input.
+    ///
+    /// # Parameters
+    ///
+    /// * `parts` - The ordered parts of the user message.
+    ///
+    /// # Returns
+    ///
+    /// The builder in the [`OptionsStage`] state.
+    pub fn prompt_parts(
+        self,
+        parts: impl IntoIterator<Item = ContentPart>,
+    ) -> LanguageModelRequestBuilder<M, OptionsStage>
+    where
+        M: TextInputSupport + ImageInputSupport,
+    {
+        LanguageModelRequestBuilder {
+            model: self.model,
+            prompt: None,
+            options: LanguageModelOptions {
+                messages: vec![Message::User(UserMessage::with_parts(parts)).into()],
+                ..self.options
+            },
+            state: std::marker::PhantomData,
+        }
+    }
+
+    /// Sets a multi-part prompt that includes audio, e.g. a question with a voice note.
+    ///
+    /// Only available for models that accept audio input.
+    ///
+    /// # Parameters
+    ///
+    /// * `parts` - The ordered parts of the user message.
+    ///
+    /// # Returns
+    ///
+    /// The builder in the [`OptionsStage`] state.
+    pub fn prompt_with_audio(
+        self,
+        parts: impl IntoIterator<Item = ContentPart>,
+    ) -> LanguageModelRequestBuilder<M, OptionsStage>
+    where
+        M: TextInputSupport + AudioInputSupport,
+    {
+        LanguageModelRequestBuilder {
+            model: self.model,
+            prompt: None,
+            options: LanguageModelOptions {
+                messages: vec![Message::User(UserMessage::with_parts(parts)).into()],
+                ..self.options
+            },
+            state: std::marker::PhantomData,
+        }
+    }
+
     /// Sets conversation messages for the request.
     ///
     /// This method allows providing a full conversation history as a vector of messages,
@@ -396,20 +496,353 @@ impl<M: LanguageModel> LanguageModelRequestBuilder<M, OptionsStage> {
         self
     }
 
+    /// Requests the log probabilities of the generated tokens.
+    ///
+    /// # Parameters
+    ///
+    /// * `logprobs` - Whether to return log probabilities.
+    ///
+    /// # Returns
+    ///
+    /// The builder with log probabilities requested.
+    pub fn logprobs(mut self, logprobs: bool) -> Self {
+        self.logprobs = Some(logprobs);
+        self
+    }
+
+    /// Requests the most likely tokens, with their log probabilities, at each
+    /// position of the generated text.
+    ///
+    /// # Parameters
+    ///
+    /// * `top_logprobs` - The number of tokens to return at each position.
+    ///
+    /// # Returns
+    ///
+    /// The builder with top log probabilities requested.
+    pub fn top_logprobs(mut self, top_logprobs: impl Into<u32>) -> Self {
+        self.top_logprobs = Some(top_logprobs.into());
+        self
+    }
+
     /// Adds a tool to the request.
     ///
     /// # Arguments
     ///
-    /// * `tool` - The tool to add.
+    /// * `tool` - The tool to add, either a [`Tool`] or a
+    ///   [`TypedTool`](crate::core::tools::TypedTool).
     ///
     /// # Returns
     ///
     /// The builder with the tool added.
-    pub fn with_tool(mut self, tool: Tool) -> Self
+    pub fn with_tool(mut self, tool: impl Into<Tool>) -> Self
+    where
+        M: ToolCallSupport,
+    {
+        self.tools.get_or_insert_default().add_tool(tool.into());
+        self
+    }
+
+    /// Sets whether, and which, tools the model calls.
+    ///
+    /// # Arguments
+    ///
+    /// * `tool_choice` - The [`ToolChoice`].
+    ///
+    /// # Returns
+    ///
+    /// The builder with the tool choice set.
+    pub fn tool_choice(mut self, tool_choice: ToolChoice) -> Self
     where
         M: ToolCallSupport,
     {
-        self.tools.get_or_insert_default().add_tool(tool);
+        self.tool_choice = Some(tool_choice);
+        self
+    }
+
+    /// Sets whether the model may call several tools in one response.
+    ///
+    /// # Arguments
+    ///
+    /// * `parallel_tool_calls` - `false` to have the model call at most one tool per
+    ///   response.
+    ///
+    /// # Returns
+    ///
+    /// The builder with parallel tool calls set.
+    pub fn parallel_tool_calls(mut self, parallel_tool_calls: bool) -> Self
+    where
+        M: ToolCallSupport,
+    {
+        self.parallel_tool_calls = Some(parallel_tool_calls);
+        self
+    }
+
+    /// Sets a provider-specific field of the request body.
+    ///
+    /// Use it for fields the SDK does not model yet, the field is sent as is. Fields
+    /// the SDK models should be set through their own options instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The name of the field in the provider request body.
+    /// * `value` - The JSON value of the field.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the provider option set.
+    pub fn provider_option(
+        mut self,
+        key: impl Into<String>,
+        value: impl Into<serde_json::Value>,
+    ) -> Self {
+        self.provider_options.insert(key.into(), value.into());
+        self
+    }
+
+    /// Sets the result policy for the tool with the given name.
+    ///
+    /// The policy is enforced on every result of that tool before it is sent back
+    /// to the model.
+    ///
+    /// # Arguments
+    ///
+    /// * `tool_name` - The name of the tool the policy applies to.
+    /// * `policy` - The policy to enforce.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the policy set.
+    pub fn tool_result_policy(
+        mut self,
+        tool_name: impl Into<String>,
+        policy: ToolResultPolicy,
+    ) -> Self
+    where
+        M: ToolCallSupport,
+    {
+        self.tools
+            .get_or_insert_default()
+            .set_policy(tool_name, policy);
+        self
+    }
+
+    /// Sets the result policy for tools without a dedicated one.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - The policy to enforce.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the default policy set.
+    pub fn default_tool_result_policy(mut self, policy: ToolResultPolicy) -> Self
+    where
+        M: ToolCallSupport,
+    {
+        self.tools
+            .get_or_insert_default()
+            .set_default_policy(policy);
+        self
+    }
+
+    /// Attaches a budget that is charged with the usage of every model call.
+    ///
+    /// Pass clones of the same [`Budget`](crate::core::budget::Budget) to several
+    /// requests to enforce a limit across a whole conversation or agent.
+    ///
+    /// # Arguments
+    ///
+    /// * `budget` - The budget to charge and enforce.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the budget attached.
+    pub fn budget(mut self, budget: Budget) -> Self {
+        self.options.budget = Some(budget);
+        self
+    }
+
+    /// Attaches a calculator that prices the usage of every model call.
+    ///
+    /// The response reports the cost of the request with `cost()`, and the
+    /// calculator's [`CostAccumulator`](crate::core::cost::CostAccumulator), if any,
+    /// totals it with the other requests it is attached to.
+    ///
+    /// # Arguments
+    ///
+    /// * `calculator` - The calculator to price model calls with.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the calculator attached.
+    pub fn cost_calculator(mut self, calculator: CostCalculator) -> Self {
+        self.options.cost_calculator = Some(calculator);
+        self
+    }
+
+    /// Trims the conversation sent with every model call to fit the model's context.
+    ///
+    /// The policy applies to the request only, so the response still holds the full
+    /// conversation.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - The policy to apply, e.g.
+    ///   [`ContextWindowPolicy::SlidingWindow`](crate::core::context_window::ContextWindowPolicy::SlidingWindow).
+    ///
+    /// # Returns
+    ///
+    /// The builder with the policy set.
+    pub fn context_window(mut self, policy: ContextWindowPolicy) -> Self {
+        self.options.context_window = Some(policy);
+        self
+    }
+
+    /// Routes model and tool calls through an effect, e.g. to run each step as an
+    /// activity of a durable-execution framework.
+    ///
+    /// # Arguments
+    ///
+    /// * `effect` - The effect to run operations through, e.g. a
+    ///   [`ReplayEffect`](crate::core::effect::ReplayEffect).
+    ///
+    /// # Returns
+    ///
+    /// The builder with the effect set.
+    pub fn effect(mut self, effect: impl Effect + 'static) -> Self {
+        self.options.effect = Some(Arc::new(effect));
+        self
+    }
+
+    /// Records the call for an observability platform, e.g. Langfuse.
+    ///
+    /// # Arguments
+    ///
+    /// * `telemetry` - The exporter to record to, and what to record.
+    ///
+    /// # Returns
+    ///
+    /// The builder with telemetry set.
+    pub fn telemetry(mut self, telemetry: TelemetryOptions) -> Self {
+        self.options.telemetry = Some(telemetry);
+        self
+    }
+
+    /// Adds a guard that transforms the conversation before each request is sent.
+    ///
+    /// Guards run in the order they are added.
+    ///
+    /// # Arguments
+    ///
+    /// * `guard` - The input guard to add, e.g. a [`PiiGuard`](crate::core::guards::PiiGuard).
+    ///
+    /// # Returns
+    ///
+    /// The builder with the guard added.
+    pub fn with_input_guard(mut self, guard: impl InputGuard + 'static) -> Self {
+        self.input_guards.push(Arc::new(guard));
+        self
+    }
+
+    /// Adds a guard that transforms generated content before it is recorded.
+    ///
+    /// Guards run in the order they are added. When streaming, text, reasoning and
+    /// tool call deltas are held back until their content is finished and guarded.
+    ///
+    /// # Arguments
+    ///
+    /// * `guard` - The output guard to add, e.g. a [`PiiGuard`](crate::core::guards::PiiGuard).
+    ///
+    /// # Returns
+    ///
+    /// The builder with the guard added.
+    pub fn with_output_guard(mut self, guard: impl OutputGuard + 'static) -> Self {
+        self.output_guards.push(Arc::new(guard));
+        self
+    }
+
+    /// Limits the number of model calls in the tool execution loop.
+    ///
+    /// When the model responds with tool calls, the registered tools are executed and
+    /// their results sent back to the model until it answers with text or `max_steps`
+    /// model calls have been made, in which case generation stops with
+    /// [`StopReason::MaxSteps`](crate::core::language_model::StopReason::MaxSteps).
+    ///
+    /// # Parameters
+    ///
+    /// * `max_steps` - The maximum number of model calls, e.g. [`DEFAULT_TOOL_STEP_COUNT`](crate::core::language_model::DEFAULT_TOOL_STEP_COUNT).
+    ///
+    /// # Returns
+    ///
+    /// The builder with the step limit set.
+    pub fn max_steps(mut self, max_steps: usize) -> Self {
+        self.options.max_steps = Some(max_steps);
+        self
+    }
+
+    /// Sets the maximum time allowed for the whole call, including every step and
+    /// tool call. When it elapses, the in-flight request or stream and any running tool
+    /// are aborted and the call fails with [`Error::Timeout`](crate::Error::Timeout).
+    ///
+    /// # Parameters
+    ///
+    /// * `timeout` - The time allowed for the call.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the timeout set.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.options.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the maximum time to wait for the next event of a provider stream. When it
+    /// elapses, the stream fails with [`Error::StreamStalled`](crate::Error::StreamStalled),
+    /// or yields a [`StreamEvent::Stalled`] event and keeps waiting, depending on the
+    /// [`stall_action`](Self::stall_action).
+    ///
+    /// # Parameters
+    ///
+    /// * `timeout` - The time allowed between two events.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the idle timeout set.
+    pub fn stream_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.options.stream_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets what a stream does when its
+    /// [`stream_idle_timeout`](Self::stream_idle_timeout) elapses. Defaults to
+    /// [`StallAction::Abort`].
+    ///
+    /// # Parameters
+    ///
+    /// * `action` - The [`StallAction`] to take.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the stall action set.
+    pub fn stall_action(mut self, action: StallAction) -> Self {
+        self.options.stall_action = action;
+        self
+    }
+
+    /// Sets a token that cancels the call when triggered. The in-flight request or
+    /// stream and any running tool are aborted and the call fails with
+    /// [`Error::Cancelled`](crate::Error::Cancelled).
+    ///
+    /// # Parameters
+    ///
+    /// * `token` - The [`CancellationToken`] to observe. Keep a clone to cancel the call.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the cancellation token set.
+    pub fn cancellation_token(mut self, token: CancellationToken) -> Self {
+        self.options.cancellation_token = Some(token);
         self
     }
 
@@ -464,6 +897,91 @@ impl<M: LanguageModel> LanguageModelRequestBuilder<M, OptionsStage> {
         self
     }
 
+    /// Sets a hook to run for each event of a stream, e.g. to log text as it arrives
+    /// without consuming the stream. Errors are passed to
+    /// [`on_error`](Self::on_error) instead.
+    ///
+    /// # Parameters
+    ///
+    /// * `hook` - A function called with each streamed event.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the hook set.
+    pub fn on_chunk<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&StreamEvent) + Send + Sync + 'static,
+    {
+        self.on_chunk = Some(Arc::new(hook));
+        self
+    }
+
+    /// Sets a hook to run once generation completes successfully, e.g. to persist the
+    /// conversation.
+    ///
+    /// # Parameters
+    ///
+    /// * `hook` - A function called with the final options and the total token usage.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the hook set.
+    pub fn on_finish<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&LanguageModelOptions, &Usage) + Send + Sync + 'static,
+    {
+        self.on_finish = Some(Arc::new(hook));
+        self
+    }
+
+    /// Sets a hook to run when generation fails.
+    ///
+    /// # Parameters
+    ///
+    /// * `hook` - A function called with the error that stopped generation.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the hook set.
+    pub fn on_error<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&Error) + Send + Sync + 'static,
+    {
+        self.on_error = Some(Arc::new(hook));
+        self
+    }
+
+    /// Makes streams also yield the chunks sent by the provider, as
+    /// [`StreamEvent::Raw`](crate::core::language_model::StreamEvent::Raw), for
+    /// debugging or for provider events the typed events do not cover.
+    ///
+    /// # Parameters
+    ///
+    /// * `include_raw_chunks` - Whether to yield the raw chunks.
+    ///
+    /// # Returns
+    ///
+    /// The builder with raw chunks included or not.
+    pub fn include_raw_chunks(mut self, include_raw_chunks: bool) -> Self {
+        self.options.include_raw_chunks = include_raw_chunks;
+        self
+    }
+
+    /// Makes streams also yield the payload of each server-sent event of the provider,
+    /// as [`StreamEvent::ProviderEvent`](crate::core::language_model::StreamEvent::ProviderEvent),
+    /// before the events normalized from it. Use it to read provider-specific fields
+    /// the typed events do not model, e.g. OpenAI `response.output_item.added` events.
+    ///
+    /// Payloads that are not JSON, e.g. `[DONE]`, are yielded as JSON strings.
+    ///
+    /// # Returns
+    ///
+    /// The builder with provider events included.
+    pub fn with_raw_events(mut self) -> Self {
+        self.options.raw_events = true;
+        self
+    }
+
     /// Sets the reasoning effort level.
     ///
     /// # Parameters
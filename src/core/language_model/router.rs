@@ -0,0 +1,465 @@
+//! Load balancing across language models.
+//!
+//! [`router`] spreads calls across backends into a [`RouterModel`], which is itself a
+//! language model. Backends are picked by weight, with a smooth weighted round robin,
+//! or by the lowest observed latency, see [`RoutingStrategy`].
+//!
+//! A backend failing with an error worth another attempt, see
+//! [`should_fall_back`], is retried on the next backend. After
+//! [`failure_threshold`](RouterModel::failure_threshold) consecutive failures it is
+//! ejected for [`ejection_duration`](RouterModel::ejection_duration): calls skip it
+//! while other backends are healthy, and it is tried again once the duration is over.
+//! Clones of a router share its health and latency records.
+//!
+//! The backend that served a generation is recorded in the response extensions as
+//! [`ServedBy`].
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # #[cfg(feature = "openai")]
+//! # {
+//! use aisdk::core::language_model::router::{RoutingStrategy, router};
+//! use aisdk::core::{DynamicModel, LanguageModelRequest};
+//! use aisdk::providers::OpenAI;
+//!
+//! # async fn run() -> aisdk::Result<()> {
+//! // Three calls out of four go to the first backend
+//! let model = router([
+//!     (OpenAI::<DynamicModel>::model_name("gpt-5"), 3),
+//!     (OpenAI::<DynamicModel>::model_name("gpt-5-mini"), 1),
+//! ])
+//! .strategy(RoutingStrategy::Weighted);
+//!
+//! let response = LanguageModelRequest::builder()
+//!     .model(model)
+//!     .prompt("Tell me a joke")
+//!     .build()
+//!     .generate_text()
+//!     .await?;
+//! # Ok(())
+//! # }
+//! # }
+//! ```
+
+use crate::core::capabilities::{
+    AudioInputSupport, AudioOutputSupport, Capabilities, ImageInputSupport, ImageOutputSupport,
+    ModelInfo, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport,
+    ToolCallSupport, VideoInputSupport, VideoOutputSupport,
+};
+use crate::core::language_model::boxed::BoxedLanguageModel;
+use crate::core::language_model::fallback::{ServedBy, should_fall_back};
+use crate::core::language_model::{
+    LanguageModel, LanguageModelOptions, LanguageModelResponse, ProviderStream, SamplingOption,
+};
+use crate::core::runtime::Instant;
+use crate::error::{Error, Result};
+use async_trait::async_trait;
+use futures::StreamExt;
+use parking_lot::Mutex;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// The default number of consecutive failures ejecting a backend.
+pub const DEFAULT_FAILURE_THRESHOLD: u32 = 3;
+
+/// The default time an ejected backend is skipped for.
+pub const DEFAULT_EJECTION_DURATION: Duration = Duration::from_secs(30);
+
+/// How a [`RouterModel`] picks the backend of a call.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RoutingStrategy {
+    /// Spreads calls in proportion to the weights of the backends.
+    #[default]
+    Weighted,
+    /// Picks the backend with the lowest average latency, trying each backend once
+    /// first. The latency of a stream is the time to its first event.
+    LeastLatency,
+}
+
+/// The health and latency record of a backend.
+#[derive(Debug, Clone, Default)]
+struct Backend {
+    /// The weight of the backend.
+    weight: u32,
+    /// The current weight of the smooth weighted round robin.
+    current: i64,
+    /// The moving average of the latency of the backend, if it has served a call.
+    latency: Option<Duration>,
+    /// The consecutive failures of the backend.
+    failures: u32,
+    /// The time until which the backend is ejected, if any.
+    ejected_until: Option<Instant>,
+}
+
+impl Backend {
+    fn is_ejected(&self, now: Instant) -> bool {
+        self.ejected_until.is_some_and(|until| now < until)
+    }
+}
+
+/// The records of the backends of a [`RouterModel`].
+#[derive(Debug, Default)]
+struct Health {
+    backends: Vec<Backend>,
+}
+
+impl Health {
+    /// Returns the backends to try for a call, in order: the backend picked by
+    /// `strategy`, the other healthy backends, then the ejected ones by the end of
+    /// their ejection.
+    fn order(&mut self, strategy: RoutingStrategy, now: Instant) -> Vec<usize> {
+        let (mut healthy, mut ejected): (Vec<usize>, Vec<usize>) =
+            (0..self.backends.len()).partition(|&i| !self.backends[i].is_ejected(now));
+
+        match strategy {
+            RoutingStrategy::Weighted => {
+                // Smooth weighted round robin, as in nginx
+                let total: i64 = healthy
+                    .iter()
+                    .map(|&i| i64::from(self.backends[i].weight))
+                    .sum();
+                for &i in &healthy {
+                    self.backends[i].current += i64::from(self.backends[i].weight);
+                }
+                let picked = healthy
+                    .iter()
+                    .copied()
+                    .max_by_key(|&i| (self.backends[i].current, std::cmp::Reverse(i)));
+                if let Some(picked) = picked {
+                    self.backends[picked].current -= total;
+                    healthy.retain(|&i| i != picked);
+                    healthy.sort_by_key(|&i| std::cmp::Reverse(self.backends[i].weight));
+                    healthy.insert(0, picked);
+                }
+            }
+            RoutingStrategy::LeastLatency => {
+                healthy.sort_by_key(|&i| self.backends[i].latency.unwrap_or_default());
+            }
+        }
+        ejected.sort_by_key(|&i| self.backends[i].ejected_until);
+        healthy.extend(ejected);
+        healthy
+    }
+
+    fn succeeded(&mut self, index: usize, latency: Duration) {
+        let backend = &mut self.backends[index];
+        backend.failures = 0;
+        backend.ejected_until = None;
+        backend.latency = Some(match backend.latency {
+            Some(average) => average.mul_f64(0.7) + latency.mul_f64(0.3),
+            None => latency,
+        });
+    }
+
+    /// Records a failure, ejecting the backend once it reaches `threshold` consecutive
+    /// failures. A backend back from ejection is ejected again on its next failure.
+    fn failed(&mut self, index: usize, threshold: u32, ejection: Duration, now: Instant) {
+        let backend = &mut self.backends[index];
+        backend.failures += 1;
+        if backend.failures >= threshold {
+            backend.ejected_until = Some(now + ejection);
+        }
+    }
+}
+
+/// A language model spreading calls across backends, returned by [`router`].
+///
+/// It has the capabilities of the models it routes to.
+#[derive(Debug, Clone)]
+pub struct RouterModel<M> {
+    models: Vec<M>,
+    strategy: RoutingStrategy,
+    failure_threshold: u32,
+    ejection_duration: Duration,
+    health: Arc<Mutex<Health>>,
+}
+
+/// Routes calls across `backends`, given with their weights.
+pub fn router<M: LanguageModel>(backends: impl IntoIterator<Item = (M, u32)>) -> RouterModel<M> {
+    let (models, backends): (Vec<M>, Vec<Backend>) = backends
+        .into_iter()
+        .map(|(model, weight)| {
+            let backend = Backend {
+                weight,
+                ..Default::default()
+            };
+            (model, backend)
+        })
+        .unzip();
+    RouterModel {
+        models,
+        strategy: RoutingStrategy::default(),
+        failure_threshold: DEFAULT_FAILURE_THRESHOLD,
+        ejection_duration: DEFAULT_EJECTION_DURATION,
+        health: Arc::new(Mutex::new(Health { backends })),
+    }
+}
+
+impl<M> RouterModel<M> {
+    /// Sets how backends are picked. Defaults to [`RoutingStrategy::Weighted`].
+    pub fn strategy(mut self, strategy: RoutingStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Sets the consecutive failures ejecting a backend. Defaults to
+    /// [`DEFAULT_FAILURE_THRESHOLD`].
+    pub fn failure_threshold(mut self, failures: u32) -> Self {
+        self.failure_threshold = failures.max(1);
+        self
+    }
+
+    /// Sets the time an ejected backend is skipped for. Defaults to
+    /// [`DEFAULT_EJECTION_DURATION`].
+    pub fn ejection_duration(mut self, duration: Duration) -> Self {
+        self.ejection_duration = duration;
+        self
+    }
+
+    /// Returns the backends of the router, in the order they were given.
+    pub fn models(&self) -> &[M] {
+        &self.models
+    }
+
+    /// Returns true if the backend at `index` is currently ejected.
+    pub fn is_ejected(&self, index: usize) -> bool {
+        self.health
+            .lock()
+            .backends
+            .get(index)
+            .is_some_and(|backend| backend.is_ejected(Instant::now()))
+    }
+
+    /// Returns the backends to try for a call, or an error if there is none.
+    fn order(&self) -> Result<Vec<usize>> {
+        let order = self.health.lock().order(self.strategy, Instant::now());
+        if order.is_empty() {
+            return Err(Error::InvalidInput(
+                "A router needs at least one backend".to_string(),
+            ));
+        }
+        Ok(order)
+    }
+
+    fn failed(&self, index: usize) {
+        self.health.lock().failed(
+            index,
+            self.failure_threshold,
+            self.ejection_duration,
+            Instant::now(),
+        );
+    }
+}
+
+#[async_trait]
+impl<M: LanguageModel> LanguageModel for RouterModel<M> {
+    fn name(&self) -> String {
+        self.models
+            .first()
+            .map_or_else(|| "router".to_string(), |model| model.name())
+    }
+
+    fn context_length(&self) -> Option<usize> {
+        self.models.iter().filter_map(|m| m.context_length()).min()
+    }
+
+    fn unsupported_options(&self) -> &'static [SamplingOption] {
+        self.models
+            .first()
+            .map_or(&[], |model| model.unsupported_options())
+    }
+
+    /// Returns the capabilities all the models have.
+    fn capabilities(&self) -> Capabilities {
+        self.models
+            .iter()
+            .map(|model| model.capabilities())
+            .reduce(Capabilities::intersection)
+            .unwrap_or_default()
+    }
+
+    fn info(&self) -> ModelInfo {
+        self.models
+            .first()
+            .map_or_else(ModelInfo::default, |model| model.info())
+    }
+
+    async fn count_tokens(&self, options: &LanguageModelOptions) -> Result<usize> {
+        match self.models.first() {
+            Some(model) => model.count_tokens(options).await,
+            None => self.order().map(|_| 0),
+        }
+    }
+
+    async fn generate_text(
+        &mut self,
+        options: LanguageModelOptions,
+    ) -> Result<LanguageModelResponse> {
+        let mut error = None;
+        for index in self.order()? {
+            let start = Instant::now();
+            match self.models[index].generate_text(options.clone()).await {
+                Ok(response) => {
+                    self.health.lock().succeeded(index, start.elapsed());
+                    response.extensions.insert(ServedBy {
+                        model: self.models[index].name(),
+                        index,
+                    });
+                    return Ok(response);
+                }
+                Err(e) if should_fall_back(&e) => {
+                    log::warn!(
+                        "{} failed, routing to the next backend: {e}",
+                        self.models[index].name()
+                    );
+                    self.failed(index);
+                    error = Some(e);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        Err(error.expect("a failed call records its error"))
+    }
+
+    async fn stream_text(&mut self, options: LanguageModelOptions) -> Result<ProviderStream> {
+        let mut error = None;
+        for index in self.order()? {
+            let start = Instant::now();
+            let e = match self.models[index].stream_text(options.clone()).await {
+                Ok(mut stream) => match stream.next().await {
+                    Some(Err(e)) => e,
+                    first => {
+                        self.health.lock().succeeded(index, start.elapsed());
+                        let first = futures::stream::iter(first);
+                        return Ok(Box::pin(first.chain(stream)));
+                    }
+                },
+                Err(e) => e,
+            };
+            if !should_fall_back(&e) {
+                return Err(e);
+            }
+            log::warn!(
+                "{} failed, routing to the next backend: {e}",
+                self.models[index].name()
+            );
+            self.failed(index);
+            error = Some(e);
+        }
+        Err(error.expect("a failed call records its error"))
+    }
+}
+
+impl<M: LanguageModel> From<RouterModel<M>> for BoxedLanguageModel {
+    fn from(model: RouterModel<M>) -> Self {
+        Box::new(model)
+    }
+}
+
+impl<M: ToolCallSupport> ToolCallSupport for RouterModel<M> {}
+impl<M: ReasoningSupport> ReasoningSupport for RouterModel<M> {}
+impl<M: StructuredOutputSupport> StructuredOutputSupport for RouterModel<M> {}
+impl<M: TextInputSupport> TextInputSupport for RouterModel<M> {}
+impl<M: VideoInputSupport> VideoInputSupport for RouterModel<M> {}
+impl<M: AudioInputSupport> AudioInputSupport for RouterModel<M> {}
+impl<M: ImageInputSupport> ImageInputSupport for RouterModel<M> {}
+impl<M: TextOutputSupport> TextOutputSupport for RouterModel<M> {}
+impl<M: VideoOutputSupport> VideoOutputSupport for RouterModel<M> {}
+impl<M: AudioOutputSupport> AudioOutputSupport for RouterModel<M> {}
+impl<M: ImageOutputSupport> ImageOutputSupport for RouterModel<M> {}
+
+#[cfg(all(test, feature = "language-model-request"))]
+mod tests {
+    use super::*;
+    use crate::core::test_model::ScriptedModel;
+
+    fn backend() -> ScriptedModel {
+        ScriptedModel::new("counting")
+    }
+
+    fn failing(status: u16) -> ScriptedModel {
+        backend().failing(Error::ApiError {
+            status_code: Some(reqwest::StatusCode::from_u16(status).unwrap()),
+            details: "failed".to_string(),
+        })
+    }
+
+    async fn call(model: &mut RouterModel<ScriptedModel>, times: usize) {
+        for _ in 0..times {
+            model
+                .generate_text(LanguageModelOptions::default())
+                .await
+                .unwrap();
+        }
+    }
+
+    fn calls(model: &RouterModel<ScriptedModel>) -> Vec<usize> {
+        model.models().iter().map(ScriptedModel::calls).collect()
+    }
+
+    #[tokio::test]
+    async fn test_weighted_spreads_calls_by_weight() {
+        let mut model = router([(backend(), 3), (backend(), 1)]);
+
+        call(&mut model, 8).await;
+
+        assert_eq!(calls(&model), [6, 2]);
+    }
+
+    #[tokio::test]
+    async fn test_failing_backend_is_ejected() {
+        let mut model = router([(failing(503), 1), (backend(), 1)])
+            .failure_threshold(2)
+            .ejection_duration(Duration::from_secs(60));
+
+        call(&mut model, 6).await;
+
+        // Two failures eject the backend, and its calls are served by the other one
+        assert_eq!(calls(&model), [2, 6]);
+        assert!(model.is_ejected(0));
+        assert!(!model.is_ejected(1));
+    }
+
+    #[tokio::test]
+    async fn test_errors_not_worth_a_retry_are_returned() {
+        let mut model = router([(failing(400), 1), (backend(), 0)]);
+
+        let error = model
+            .generate_text(LanguageModelOptions::default())
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error, Error::ApiError { status_code: Some(s), .. } if s == 400));
+        assert_eq!(calls(&model), [1, 0]);
+        assert!(!model.is_ejected(0));
+    }
+
+    #[tokio::test]
+    async fn test_stream_routes_to_the_next_backend() {
+        let mut model = router([(failing(429), 1), (backend(), 0)]);
+
+        let stream = model.stream_text(LanguageModelOptions::default()).await;
+
+        assert!(stream.is_ok());
+
+        assert_eq!(calls(&model), [1, 1]);
+    }
+
+    #[test]
+    fn test_least_latency_prefers_the_fastest_backend() {
+        let now = Instant::now();
+        let mut health = Health {
+            backends: vec![Backend::default(); 3],
+        };
+        health.succeeded(0, Duration::from_millis(300));
+        health.succeeded(1, Duration::from_millis(100));
+
+        // The backend without a latency is tried first
+        assert_eq!(health.order(RoutingStrategy::LeastLatency, now), [2, 1, 0]);
+
+        health.succeeded(2, Duration::from_millis(200));
+        health.failed(1, 1, Duration::from_secs(60), now);
+        assert_eq!(health.order(RoutingStrategy::LeastLatency, now), [2, 0, 1]);
+    }
+}
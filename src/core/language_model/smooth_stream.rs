@@ -0,0 +1,228 @@
+//! Smoothing of streamed text.
+//!
+//! Providers often deliver text in bursts: several words in one server-sent event,
+//! then nothing for a while. [`SmoothStream`] re-chunks the text deltas of a
+//! [`LanguageModelStream`] into words or lines and emits them with a fixed delay
+//! between them, so a UI types at an even cadence. Every other event is passed through
+//! unchanged, after any buffered text.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # #[cfg(feature = "openai")]
+//! # {
+//! use aisdk::core::language_model::smooth_stream::{Chunking, SmoothStream};
+//! use aisdk::core::LanguageModelRequest;
+//! use aisdk::providers::OpenAI;
+//! use std::time::Duration;
+//!
+//! # async fn run() -> aisdk::Result<()> {
+//! let response = LanguageModelRequest::builder()
+//!     .model(OpenAI::gpt_5())
+//!     .prompt("Tell me a story")
+//!     .build()
+//!     .stream_text()
+//!     .await?
+//!     .smooth_stream(
+//!         SmoothStream::new()
+//!             .chunking(Chunking::Word)
+//!             .delay(Duration::from_millis(20)),
+//!     );
+//! # Ok(())
+//! # }
+//! # }
+//! ```
+
+use crate::core::language_model::{LanguageModelStream, StreamEvent};
+use futures::StreamExt;
+use std::time::Duration;
+
+/// The delay between smoothed chunks when none is set.
+pub const DEFAULT_SMOOTH_STREAM_DELAY: Duration = Duration::from_millis(10);
+
+/// How [`SmoothStream`] splits text into chunks.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Chunking {
+    /// One word per chunk, with the whitespace that follows it.
+    #[default]
+    Word,
+    /// One line per chunk, with the line breaks that end it.
+    Line,
+}
+
+impl Chunking {
+    /// Returns the length of the first complete chunk of `text`, or `None` if `text`
+    /// does not hold one yet.
+    fn split(self, text: &str) -> Option<usize> {
+        match self {
+            Chunking::Word => {
+                let word = text.find(|c: char| !c.is_whitespace())?;
+                let space = word + text[word..].find(char::is_whitespace)?;
+                Some(
+                    text[space..]
+                        .find(|c: char| !c.is_whitespace())
+                        .map_or(text.len(), |end| space + end),
+                )
+            }
+            Chunking::Line => {
+                let newline = text.find('\n')?;
+                Some(
+                    text[newline..]
+                        .find(|c: char| c != '\n')
+                        .map_or(text.len(), |end| newline + end),
+                )
+            }
+        }
+    }
+}
+
+/// Re-chunks streamed text by word or line and emits the chunks with a fixed delay.
+///
+/// Apply it with
+/// [`StreamTextResponse::smooth_stream`](crate::core::StreamTextResponse::smooth_stream),
+/// or to any [`LanguageModelStream`] with [`apply`](Self::apply).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SmoothStream {
+    chunking: Chunking,
+    delay: Duration,
+}
+
+impl Default for SmoothStream {
+    fn default() -> Self {
+        Self {
+            chunking: Chunking::default(),
+            delay: DEFAULT_SMOOTH_STREAM_DELAY,
+        }
+    }
+}
+
+impl SmoothStream {
+    /// Creates a smoother that emits one word every
+    /// [`DEFAULT_SMOOTH_STREAM_DELAY`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how the text is split into chunks.
+    pub fn chunking(mut self, chunking: Chunking) -> Self {
+        self.chunking = chunking;
+        self
+    }
+
+    /// Sets the delay after each emitted text chunk. A zero delay only re-chunks the
+    /// text.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Returns a stream that yields the events of `stream`, with its text deltas
+    /// re-chunked and delayed.
+    ///
+    /// Text that does not complete a chunk is held back until more text arrives, and is
+    /// flushed before the next non-text event or when `stream` ends.
+    pub fn apply(self, mut stream: LanguageModelStream) -> LanguageModelStream {
+        let (tx, smoothed) = LanguageModelStream::new();
+
+        crate::core::runtime::spawn(async move {
+            let mut buffer = String::new();
+
+            while let Some(event) = stream.next().await {
+                if let StreamEvent::TextDelta(delta) = event {
+                    buffer.push_str(&delta);
+                    while let Some(end) = self.chunking.split(&buffer) {
+                        let text = buffer.drain(..end).collect();
+                        if tx.send(StreamEvent::TextDelta(text)).is_err() {
+                            return;
+                        }
+                        if !self.delay.is_zero() {
+                            crate::core::runtime::sleep(self.delay).await;
+                        }
+                    }
+                    continue;
+                }
+
+                if !buffer.is_empty() {
+                    let text = std::mem::take(&mut buffer);
+                    let _ = tx.send(StreamEvent::TextDelta(text));
+                }
+                if tx.send(event).is_err() {
+                    return;
+                }
+            }
+
+            if !buffer.is_empty() {
+                let _ = tx.send(StreamEvent::TextDelta(buffer));
+            }
+        });
+
+        smoothed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::language_model::{StopReason, Usage};
+
+    async fn smooth(chunking: Chunking, deltas: &[&str]) -> Vec<StreamEvent> {
+        let (tx, stream) = LanguageModelStream::new();
+        for delta in deltas {
+            tx.send(StreamEvent::TextDelta(delta.to_string())).unwrap();
+        }
+        tx.send(StreamEvent::Finish {
+            usage: Usage::default(),
+            stop_reason: StopReason::Finish,
+        })
+        .unwrap();
+        drop(tx);
+
+        SmoothStream::new()
+            .chunking(chunking)
+            .delay(Duration::ZERO)
+            .apply(stream)
+            .collect()
+            .await
+    }
+
+    fn texts(chunks: &[StreamEvent]) -> Vec<&str> {
+        chunks
+            .iter()
+            .filter_map(|chunk| match chunk {
+                StreamEvent::TextDelta(text) => Some(text.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_split_by_word() {
+        assert_eq!(Chunking::Word.split("Hello"), None);
+        assert_eq!(Chunking::Word.split("Hello "), Some(6));
+        assert_eq!(Chunking::Word.split("  Hello  world"), Some(9));
+    }
+
+    #[test]
+    fn test_split_by_line() {
+        assert_eq!(Chunking::Line.split("Hello world"), None);
+        assert_eq!(Chunking::Line.split("Hello\n\nworld"), Some(7));
+    }
+
+    #[tokio::test]
+    async fn test_smooth_stream_by_word() {
+        let chunks = smooth(Chunking::Word, &["Hel", "lo wor", "ld, how are", " you?"]).await;
+
+        assert_eq!(
+            texts(&chunks),
+            vec!["Hello ", "world, ", "how ", "are ", "you?"]
+        );
+        assert!(matches!(chunks.last(), Some(StreamEvent::Finish { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_smooth_stream_by_line() {
+        let chunks = smooth(Chunking::Line, &["First li", "ne\nSecond", " line"]).await;
+
+        assert_eq!(texts(&chunks), vec!["First line\n", "Second line"]);
+    }
+}
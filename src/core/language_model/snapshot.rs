@@ -0,0 +1,353 @@
+//! Portable snapshots of the agent loop state.
+//!
+//! An [`AgentSnapshot`] captures everything needed to pick an agent loop back up later,
+//! possibly in another process or on another machine: the tagged conversation history,
+//! the current step, tool calls that have not produced a result yet, the budget
+//! counters, and the serializable request settings. Take one with
+//! [`LanguageModelOptions::snapshot`] (for example from an `on_step_finish` hook or on a
+//! finished response), persist it with [`AgentSnapshot::to_json`], and resume with
+//! [`restore`](crate::core::language_model::request::LanguageModelRequestBuilder::restore).
+//!
+//! Tools, hooks, guards, and budget limits are code rather than data, so they are not
+//! part of a snapshot and must be registered again on the restored request. The budget
+//! counters of the snapshot are loaded into the budget attached to the restored request
+//! when it runs. Provider extensions attached to messages, such as reasoning
+//! signatures, are not preserved.
+
+use crate::core::budget::BudgetSnapshot;
+#[cfg(feature = "language-model-request")]
+use crate::core::cancellation::Interrupt;
+use crate::core::language_model::{LanguageModelOptions, ReasoningEffort};
+use crate::core::messages::{Message, Messages, TaggedMessage, TaggedMessageHelpers};
+use crate::core::tools::{ToolCallInfo, ToolChoice};
+use crate::error::{Error, Result};
+use schemars::Schema;
+use serde::{Deserialize, Serialize};
+
+// ============================================================================
+// Section: constants
+// ============================================================================
+
+/// The snapshot format version written by this version of the SDK.
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+// ============================================================================
+// Section: snapshot
+// ============================================================================
+
+/// Serializable request settings carried by an [`AgentSnapshot`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SnapshotSettings {
+    /// Output format schema.
+    pub schema: Option<Schema>,
+    /// The seed used for random sampling.
+    pub seed: Option<u32>,
+    /// Sampling temperature (0-100).
+    pub temperature: Option<u32>,
+    /// Nucleus sampling parameter (0-100).
+    pub top_p: Option<u32>,
+    /// Top-k sampling parameter.
+    pub top_k: Option<u32>,
+    /// Maximum number of retries for failed requests.
+    pub max_retries: Option<u32>,
+    /// Maximum number of output tokens to generate.
+    pub max_output_tokens: Option<u32>,
+    /// Maximum number of model calls in the tool execution loop.
+    pub max_steps: Option<usize>,
+    /// Stop sequences.
+    pub stop_sequences: Option<Vec<String>>,
+    /// Presence penalty setting.
+    pub presence_penalty: Option<f32>,
+    /// Frequency penalty setting.
+    pub frequency_penalty: Option<f32>,
+    /// Whether log probabilities of the generated tokens are returned.
+    pub logprobs: Option<bool>,
+    /// Number of most likely tokens returned at each position.
+    pub top_logprobs: Option<u32>,
+    /// Level of reasoning effort for the model.
+    pub reasoning_effort: Option<ReasoningEffort>,
+    /// Whether, and which, tools the model calls.
+    pub tool_choice: Option<ToolChoice>,
+    /// Whether the model may call several tools in one response.
+    pub parallel_tool_calls: Option<bool>,
+    /// Provider-specific fields merged into the request body.
+    #[serde(default, skip_serializing_if = "serde_json::Map::is_empty")]
+    pub provider_options: serde_json::Map<String, serde_json::Value>,
+}
+
+/// A portable snapshot of the agent loop state.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgentSnapshot {
+    /// The snapshot format version.
+    pub version: u32,
+    /// The system prompt.
+    pub system: Option<String>,
+    /// The id of the last started step.
+    pub step: usize,
+    /// Tool calls that were requested by the model but have no result yet. They are
+    /// executed first when the loop resumes.
+    pub pending_tool_calls: Vec<ToolCallInfo>,
+    /// The budget counters, if a budget was attached.
+    pub budget: Option<BudgetSnapshot>,
+    /// The serializable request settings.
+    pub settings: SnapshotSettings,
+    /// The conversation history, tagged with step ids.
+    messages: Vec<TaggedMessage>,
+}
+
+impl AgentSnapshot {
+    /// Returns the conversation history.
+    pub fn messages(&self) -> Messages {
+        self.messages.iter().map(|m| m.message.clone()).collect()
+    }
+
+    /// Appends a message to the history, e.g. a new user turn to answer on resume.
+    pub fn push_message(&mut self, message: Message) {
+        self.messages.push(TaggedMessage::new(self.step, message));
+    }
+
+    /// Serializes the snapshot to JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the snapshot cannot be serialized.
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string(self).map_err(|e| Error::Other(format!("Invalid snapshot: {e}")))
+    }
+
+    /// Deserializes a snapshot from JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the JSON is not a valid snapshot or was written by a
+    /// newer, incompatible version of the SDK.
+    pub fn from_json(json: &str) -> Result<Self> {
+        let snapshot: Self = serde_json::from_str(json)
+            .map_err(|e| Error::InvalidInput(format!("Invalid snapshot: {e}")))?;
+        if snapshot.version > SNAPSHOT_VERSION {
+            return Err(Error::InvalidInput(format!(
+                "Unsupported snapshot version {}, expected at most {SNAPSHOT_VERSION}",
+                snapshot.version
+            )));
+        }
+        Ok(snapshot)
+    }
+}
+
+/// The parts of a restored snapshot applied when the request runs.
+#[derive(Debug, Clone, Default)]
+#[cfg(feature = "language-model-request")]
+pub(crate) struct Resume {
+    pending_tool_calls: Vec<ToolCallInfo>,
+    budget: Option<BudgetSnapshot>,
+}
+
+impl LanguageModelOptions {
+    /// Captures the current agent loop state in a portable snapshot.
+    pub fn snapshot(&self) -> AgentSnapshot {
+        AgentSnapshot {
+            version: SNAPSHOT_VERSION,
+            system: self.system.clone(),
+            step: self.current_step_id,
+            pending_tool_calls: self.pending_tool_calls(),
+            budget: self.budget.as_ref().map(|b| b.snapshot()),
+            settings: SnapshotSettings {
+                schema: self.schema.clone(),
+                seed: self.seed,
+                temperature: self.temperature,
+                top_p: self.top_p,
+                top_k: self.top_k,
+                max_retries: self.max_retries,
+                max_output_tokens: self.max_output_tokens,
+                max_steps: self.max_steps,
+                stop_sequences: self.stop_sequences.clone(),
+                presence_penalty: self.presence_penalty,
+                frequency_penalty: self.frequency_penalty,
+                logprobs: self.logprobs,
+                top_logprobs: self.top_logprobs,
+                reasoning_effort: self.reasoning_effort,
+                tool_choice: self.tool_choice.clone(),
+                parallel_tool_calls: self.parallel_tool_calls,
+                provider_options: self.provider_options.clone(),
+            },
+            messages: self.messages.clone(),
+        }
+    }
+
+    /// Returns the tool calls in the conversation that have no matching tool result.
+    pub fn pending_tool_calls(&self) -> Vec<ToolCallInfo> {
+        let results = self.messages.extract_tool_results().unwrap_or_default();
+        self.messages
+            .extract_tool_calls()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|call| !results.iter().any(|r| r.tool.id == call.tool.id))
+            .collect()
+    }
+
+    /// Applies the snapshot the options were restored from, if any: loads its budget
+    /// counters into the attached budget and executes the tool calls it left pending.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Cancelled`] or [`Error::Timeout`] if `interrupt` fires while a
+    /// tool runs.
+    #[cfg(feature = "language-model-request")]
+    pub(crate) async fn resume(&mut self, interrupt: &Interrupt) -> Result<()> {
+        let Some(Resume {
+            pending_tool_calls,
+            budget,
+        }) = self.resume.take()
+        else {
+            return Ok(());
+        };
+        if let (Some(budget), Some(snapshot)) = (&self.budget, &budget) {
+            budget.restore(snapshot);
+        }
+        for call in &pending_tool_calls {
+            self.handle_tool_call(call, interrupt).await?;
+        }
+        Ok(())
+    }
+
+    /// Replaces the loop state with the contents of a snapshot.
+    #[cfg(feature = "language-model-request")]
+    pub(crate) fn restore(&mut self, snapshot: AgentSnapshot) {
+        let AgentSnapshot {
+            system,
+            step,
+            pending_tool_calls,
+            budget,
+            settings,
+            messages,
+            ..
+        } = snapshot;
+
+        self.system = system;
+        self.current_step_id = step;
+        self.messages = messages;
+        self.stop_reason = None;
+        self.resume = Some(Resume {
+            pending_tool_calls,
+            budget,
+        });
+
+        self.schema = settings.schema;
+        self.seed = settings.seed;
+        self.temperature = settings.temperature;
+        self.top_p = settings.top_p;
+        self.top_k = settings.top_k;
+        self.max_retries = settings.max_retries;
+        self.max_output_tokens = settings.max_output_tokens;
+        self.max_steps = settings.max_steps;
+        self.stop_sequences = settings.stop_sequences;
+        self.presence_penalty = settings.presence_penalty;
+        self.frequency_penalty = settings.frequency_penalty;
+        self.logprobs = settings.logprobs;
+        self.top_logprobs = settings.top_logprobs;
+        self.reasoning_effort = settings.reasoning_effort;
+        self.tool_choice = settings.tool_choice;
+        self.parallel_tool_calls = settings.parallel_tool_calls;
+        self.provider_options = settings.provider_options;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::budget::Budget;
+    use crate::core::language_model::{LanguageModelResponseContentType, Usage};
+    use crate::core::messages::AssistantMessage;
+    use crate::core::tools::ToolResultInfo;
+
+    fn tool_call(id: &str) -> Message {
+        let mut info = ToolCallInfo::new("search");
+        info.id(id);
+        info.input(serde_json::json!({ "q": "rust" }));
+        Message::Assistant(AssistantMessage::new(
+            LanguageModelResponseContentType::ToolCall(info),
+            Some(Usage {
+                input_tokens: Some(10),
+                output_tokens: Some(5),
+                ..Default::default()
+            }),
+        ))
+    }
+
+    fn tool_result(id: &str) -> Message {
+        let mut info = ToolResultInfo::new("search");
+        info.id(id);
+        info.output(serde_json::json!("found"));
+        Message::Tool(info)
+    }
+
+    fn options() -> LanguageModelOptions {
+        LanguageModelOptions {
+            system: Some("Be brief".to_string()),
+            temperature: Some(20),
+            current_step_id: 2,
+            messages: vec![
+                TaggedMessage::new(0, Message::User("hi".into())),
+                TaggedMessage::new(1, tool_call("a")),
+                TaggedMessage::new(1, tool_result("a")),
+                TaggedMessage::new(2, tool_call("b")),
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_pending_tool_calls() {
+        let pending = options().pending_tool_calls();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].tool.id, "b");
+    }
+
+    #[test]
+    fn test_snapshot_json_round_trip() {
+        let budget = Budget::new().max_total_tokens(100);
+        budget.record(&Usage {
+            input_tokens: Some(10),
+            output_tokens: Some(5),
+            ..Default::default()
+        });
+        let snapshot = LanguageModelOptions {
+            budget: Some(budget),
+            ..options()
+        }
+        .snapshot();
+
+        let json = snapshot.to_json().unwrap();
+        let restored = AgentSnapshot::from_json(&json).unwrap();
+
+        assert_eq!(restored.step, 2);
+        assert_eq!(restored.system.as_deref(), Some("Be brief"));
+        assert_eq!(restored.settings.temperature, Some(20));
+        assert_eq!(restored.pending_tool_calls.len(), 1);
+        assert_eq!(restored.messages().len(), 4);
+        assert_eq!(restored.budget.unwrap().usage.input_tokens, Some(10));
+    }
+
+    #[cfg(feature = "language-model-request")]
+    #[test]
+    fn test_restore_into_options() {
+        let mut restored = LanguageModelOptions::default();
+        restored.restore(options().snapshot());
+
+        assert_eq!(restored.current_step_id, 2);
+        assert_eq!(restored.system.as_deref(), Some("Be brief"));
+        assert_eq!(restored.step(1).unwrap().messages.len(), 2);
+        assert_eq!(restored.pending_tool_calls().len(), 1);
+    }
+
+    #[test]
+    fn test_rejects_newer_version() {
+        let mut snapshot = options().snapshot();
+        snapshot.version = SNAPSHOT_VERSION + 1;
+        let json = serde_json::to_string(&snapshot).unwrap();
+        assert!(matches!(
+            AgentSnapshot::from_json(&json),
+            Err(Error::InvalidInput(_))
+        ));
+    }
+}
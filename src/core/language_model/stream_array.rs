@@ -0,0 +1,335 @@
+//! Array output mode: streams the elements of a generated JSON array one by one.
+
+use crate::core::capabilities::StructuredOutputSupport;
+use crate::core::language_model::{
+    LanguageModel, LanguageModelOptions, StreamEvent, Usage, generate_object::parse_object,
+    request::LanguageModelRequest, usage_accumulator::UsageAccumulator,
+};
+use crate::error::Result;
+use futures::{Stream, StreamExt};
+use schemars::{JsonSchema, schema_for};
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tokio::sync::Mutex;
+use tokio::sync::mpsc::{self, UnboundedReceiver};
+
+/// The object requested by [`stream_array`](LanguageModelRequest::stream_array).
+/// Structured output modes require an object at the root, so the array is wrapped.
+#[derive(JsonSchema, Deserialize)]
+struct Elements<T> {
+    elements: Vec<T>,
+}
+
+impl<M: LanguageModel + StructuredOutputSupport> LanguageModelRequest<M> {
+    /// Streams a JSON array of `T`, yielding each element as soon as it is complete.
+    ///
+    /// The model is asked for an object whose `elements` field is an array of `T`, using
+    /// the structured output mode of [`generate_object`](Self::generate_object). Each
+    /// element is deserialized as soon as its closing bracket arrives, instead of once
+    /// the whole response is complete.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - The element type, which must implement [`JsonSchema`] and [`DeserializeOwned`].
+    ///
+    /// # Returns
+    ///
+    /// A [`StreamArrayResponse`] whose stream yields the elements.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the stream cannot be started. Failures
+    /// while streaming, including elements that do not match the schema, are yielded as
+    /// errors.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    ///# #[cfg(feature = "openai")]
+    ///# {
+    ///    use aisdk::{core::LanguageModelRequest, providers::OpenAI};
+    ///    use futures::StreamExt;
+    ///    use schemars::JsonSchema;
+    ///    use serde::Deserialize;
+    ///
+    ///    #[derive(Debug, JsonSchema, Deserialize)]
+    ///    struct Hero {
+    ///        name: String,
+    ///        class: String,
+    ///    }
+    ///
+    ///    async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///        let mut heroes = LanguageModelRequest::builder()
+    ///            .model(OpenAI::gpt_5())
+    ///            .prompt("Generate three fantasy heroes.")
+    ///            .build()
+    ///            .stream_array::<Hero>()
+    ///            .await?
+    ///            .stream;
+    ///
+    ///        while let Some(hero) = heroes.next().await {
+    ///            println!("{:?}", hero?);
+    ///        }
+    ///        Ok(())
+    ///    }
+    ///# }
+    /// ```
+    pub async fn stream_array<T>(&mut self) -> Result<StreamArrayResponse<T>>
+    where
+        T: JsonSchema + DeserializeOwned + Send + 'static,
+    {
+        self.options.schema = Some(schema_for!(Elements<T>));
+
+        let response = self.stream_text().await?;
+        let mut events = response.stream;
+        let (tx, receiver) = mpsc::unbounded_channel();
+
+        crate::core::runtime::spawn(async move {
+            let mut splitter = ElementSplitter::default();
+            let mut yielded = 0;
+
+            while let Some(event) = events.next().await {
+                let elements: Vec<Result<T>> = match event {
+                    // Only the JSON of the final step holds the elements
+                    StreamEvent::StepStart => {
+                        splitter = ElementSplitter::default();
+                        yielded = 0;
+                        continue;
+                    }
+                    StreamEvent::TextDelta(delta) => splitter
+                        .push(&delta)
+                        .into_iter()
+                        .map(|element| parse_object(&element))
+                        .collect(),
+                    StreamEvent::Error(e) => vec![Err(e)],
+                    // Validate the whole response, and yield any element the splitter missed
+                    StreamEvent::Finish { .. } => {
+                        match parse_object::<Elements<T>>(&splitter.text) {
+                            Ok(array) => array.elements.into_iter().skip(yielded).map(Ok).collect(),
+                            Err(e) => vec![Err(e)],
+                        }
+                    }
+                    _ => continue,
+                };
+                for element in elements {
+                    if element.is_ok() {
+                        yielded += 1;
+                    }
+                    if tx.send(element).is_err() {
+                        return;
+                    }
+                }
+            }
+        });
+
+        Ok(StreamArrayResponse {
+            stream: ElementStream { receiver },
+            options: response.options,
+            usage: response.usage,
+        })
+    }
+}
+
+/// Splits the elements out of the first JSON array of a streamed text.
+#[derive(Debug, Default)]
+struct ElementSplitter {
+    /// The text received so far.
+    text: String,
+    /// Byte offset up to which `text` has been scanned.
+    scanned: usize,
+    /// Nesting depth of objects and arrays at `scanned`.
+    depth: usize,
+    /// Depth inside the array, once it has started.
+    array_depth: Option<usize>,
+    /// Byte offset where the element being received starts.
+    element_start: Option<usize>,
+    in_string: bool,
+    escaped: bool,
+    /// Whether the array has been closed.
+    closed: bool,
+}
+
+impl ElementSplitter {
+    /// Appends `delta` and returns the JSON of the elements it completes.
+    fn push(&mut self, delta: &str) -> Vec<String> {
+        self.text.push_str(delta);
+        let mut elements = Vec::new();
+
+        // Structural characters are ASCII, so scanning bytes is safe for UTF-8 text
+        while self.scanned < self.text.len() && !self.closed {
+            let i = self.scanned;
+            let byte = self.text.as_bytes()[i];
+            self.scanned += 1;
+
+            if self.in_string {
+                match byte {
+                    _ if self.escaped => self.escaped = false,
+                    b'\\' => self.escaped = true,
+                    b'"' => self.in_string = false,
+                    _ => {}
+                }
+                continue;
+            }
+
+            let at_element_level = self.array_depth == Some(self.depth);
+            if at_element_level
+                && self.element_start.is_none()
+                && !byte.is_ascii_whitespace()
+                && !matches!(byte, b',' | b']')
+            {
+                self.element_start = Some(i);
+            }
+
+            match byte {
+                b'"' => self.in_string = true,
+                b'{' | b'[' => {
+                    self.depth += 1;
+                    if byte == b'[' && self.array_depth.is_none() {
+                        self.array_depth = Some(self.depth);
+                    }
+                }
+                b'}' | b']' if at_element_level => {
+                    // Closes the array, ending a trailing primitive element
+                    elements.extend(self.take_element(i));
+                    self.closed = true;
+                }
+                b'}' | b']' => {
+                    self.depth = self.depth.saturating_sub(1);
+                    if self.array_depth == Some(self.depth) {
+                        elements.extend(self.take_element(i + 1));
+                    }
+                }
+                b',' if at_element_level => elements.extend(self.take_element(i)),
+                _ => {}
+            }
+        }
+
+        elements
+    }
+
+    /// Ends the element being received at byte offset `end`, and returns its JSON.
+    fn take_element(&mut self, end: usize) -> Option<String> {
+        let start = self.element_start.take()?;
+        Some(self.text[start..end].trim().to_string())
+    }
+}
+
+// ============================================================================
+// Section: response types
+// ============================================================================
+
+/// A stream of the elements of a [`stream_array`](LanguageModelRequest::stream_array)
+/// call, or of the errors that stopped it.
+pub struct ElementStream<T> {
+    receiver: UnboundedReceiver<Result<T>>,
+}
+
+impl<T> Stream for ElementStream<T> {
+    type Item = Result<T>;
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_recv(cx)
+    }
+}
+
+/// Response from a `stream_array` call.
+pub struct StreamArrayResponse<T> {
+    /// The stream of elements, each yielded as soon as it is complete.
+    pub stream: ElementStream<T>,
+    // The conversation state, updated as the stream progresses.
+    options: Arc<Mutex<LanguageModelOptions>>,
+    // Token usage reported by the provider across all chunks and steps.
+    usage: Arc<Mutex<UsageAccumulator>>,
+}
+
+impl<T> StreamArrayResponse<T> {
+    /// Consumes the stream and returns all elements.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first [`Error`](crate::Error) of the stream.
+    pub async fn elements(self) -> Result<Vec<T>> {
+        self.stream.collect::<Vec<_>>().await.into_iter().collect()
+    }
+
+    /// Returns the conversation state, including the streamed response once the stream
+    /// completes.
+    pub async fn options(&self) -> LanguageModelOptions {
+        self.options.lock().await.clone()
+    }
+
+    /// Returns the token usage reported by the provider across the whole run. The total
+    /// is final once the stream completes.
+    pub async fn total_usage(&self) -> Usage {
+        self.usage.lock().await.total()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::test_model::ScriptedModel;
+
+    #[test]
+    fn test_element_splitter_objects() {
+        let mut splitter = ElementSplitter::default();
+
+        assert!(splitter.push(r#"{"elements": [{"name": "A"#).is_empty());
+        assert_eq!(
+            splitter.push(r#"ria", "tags": ["a]"]}, {"name""#),
+            vec![r#"{"name": "Aria", "tags": ["a]"]}"#]
+        );
+        assert_eq!(splitter.push(r#": "Bo"}]}"#), vec![r#"{"name": "Bo"}"#]);
+        assert!(splitter.push(r#"{"ignored": 1}"#).is_empty());
+    }
+
+    #[test]
+    fn test_element_splitter_primitives() {
+        let mut splitter = ElementSplitter::default();
+
+        assert_eq!(splitter.push(r#"{"elements": [1, 2"#), vec!["1"]);
+        assert_eq!(splitter.push(r#"3 , "x,y"]}"#), vec!["23", r#""x,y""#]);
+    }
+
+    #[derive(Debug, JsonSchema, Deserialize, PartialEq)]
+    struct Hero {
+        name: String,
+    }
+
+    /// A model that streams a list of heroes in small pieces.
+    fn hero_model() -> ScriptedModel {
+        ScriptedModel::new("hero").text_deltas(&[
+            r#"{"elements": [{"na"#,
+            r#"me": "Aria"}, {"name": "#,
+            r#""Bo"}]}"#,
+        ])
+    }
+
+    #[tokio::test]
+    async fn test_stream_array_yields_elements() {
+        let heroes = LanguageModelRequest::builder()
+            .model(hero_model())
+            .prompt("Generate heroes")
+            .build()
+            .stream_array::<Hero>()
+            .await
+            .unwrap()
+            .elements()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            heroes,
+            vec![
+                Hero {
+                    name: "Aria".to_string()
+                },
+                Hero {
+                    name: "Bo".to_string()
+                },
+            ]
+        );
+    }
+}
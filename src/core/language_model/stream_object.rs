@@ -0,0 +1,383 @@
+//! Structured object streaming impl for the `LanguageModelRequest` trait.
+
+use crate::core::capabilities::StructuredOutputSupport;
+use crate::core::language_model::{
+    LanguageModel, LanguageModelOptions, StreamEvent, Usage, generate_object::parse_object,
+    request::LanguageModelRequest, usage_accumulator::UsageAccumulator,
+};
+use crate::error::{Error, Result};
+use futures::{Stream, StreamExt};
+use schemars::{JsonSchema, schema_for};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tokio::sync::Mutex;
+use tokio::sync::mpsc::{self, UnboundedReceiver};
+
+impl<M: LanguageModel + StructuredOutputSupport> LanguageModelRequest<M> {
+    /// Streams a structured object of type `T` using the language model.
+    ///
+    /// The JSON schema of `T` is sent as with [`generate_object`](Self::generate_object).
+    /// While the JSON streams in, it is parsed into [`PartialObject`] snapshots, with
+    /// unfinished strings, arrays, and objects closed, and the complete response is
+    /// finally deserialized into `T`.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - The type to generate, which must implement [`JsonSchema`] and [`DeserializeOwned`].
+    ///
+    /// # Returns
+    ///
+    /// A [`StreamObjectResponse`] whose stream yields the snapshots and the final object.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] if the stream cannot be started. Failures while streaming are
+    /// yielded as [`ObjectStreamEvent::Error`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    ///# #[cfg(feature = "openai")]
+    ///# {
+    ///    use aisdk::{
+    ///        core::{LanguageModelRequest, language_model::stream_object::ObjectStreamEvent},
+    ///        providers::OpenAI,
+    ///    };
+    ///    use futures::StreamExt;
+    ///    use schemars::JsonSchema;
+    ///    use serde::Deserialize;
+    ///
+    ///    #[derive(Debug, JsonSchema, Deserialize)]
+    ///    struct Recipe {
+    ///        name: Option<String>,
+    ///        steps: Option<Vec<String>>,
+    ///    }
+    ///
+    ///    async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///        let mut stream = LanguageModelRequest::builder()
+    ///            .model(OpenAI::gpt_5())
+    ///            .prompt("Write a pancake recipe.")
+    ///            .build()
+    ///            .stream_object::<Recipe>()
+    ///            .await?
+    ///            .stream;
+    ///
+    ///        while let Some(event) = stream.next().await {
+    ///            match event {
+    ///                ObjectStreamEvent::Partial(partial) => println!("{:?}", partial.object),
+    ///                ObjectStreamEvent::Object(recipe) => println!("Done: {recipe:?}"),
+    ///                ObjectStreamEvent::Error(e) => return Err(e.into()),
+    ///            }
+    ///        }
+    ///        Ok(())
+    ///    }
+    ///# }
+    /// ```
+    pub async fn stream_object<T>(&mut self) -> Result<StreamObjectResponse<T>>
+    where
+        T: JsonSchema + DeserializeOwned + Send + 'static,
+    {
+        self.options.schema = Some(schema_for!(T));
+
+        let response = self.stream_text().await?;
+        let mut events = response.stream;
+        let (tx, receiver) = mpsc::unbounded_channel();
+
+        crate::core::runtime::spawn(async move {
+            let mut text = String::new();
+            let mut last = None;
+
+            while let Some(event) = events.next().await {
+                let event = match event {
+                    // Only the JSON of the final step is the object
+                    StreamEvent::StepStart => {
+                        text.clear();
+                        last = None;
+                        continue;
+                    }
+                    StreamEvent::TextDelta(delta) => {
+                        text.push_str(&delta);
+                        let Some(value) = parse_partial_json(&text) else {
+                            continue;
+                        };
+                        if last.as_ref() == Some(&value) {
+                            continue;
+                        }
+                        last = Some(value.clone());
+                        ObjectStreamEvent::Partial(PartialObject::new(value))
+                    }
+                    StreamEvent::Error(e) => ObjectStreamEvent::Error(e),
+                    StreamEvent::Finish { .. } => match parse_object(&text) {
+                        Ok(object) => ObjectStreamEvent::Object(object),
+                        Err(e) => ObjectStreamEvent::Error(e),
+                    },
+                    _ => continue,
+                };
+                if tx.send(event).is_err() {
+                    return;
+                }
+            }
+        });
+
+        Ok(StreamObjectResponse {
+            stream: ObjectStream { receiver },
+            options: response.options,
+            usage: response.usage,
+        })
+    }
+}
+
+/// Parses JSON that may be cut off mid-stream, closing unfinished strings, arrays, and
+/// objects, and dropping trailing keys or values that cannot be completed yet.
+///
+/// Returns `None` until an object or array has started.
+pub(crate) fn parse_partial_json(text: &str) -> Option<Value> {
+    let start = text.find(['{', '['])?;
+    let mut json = text[start..].trim_end();
+
+    loop {
+        if let Ok(value) = serde_json::from_str(json) {
+            return Some(value);
+        }
+        if let Ok(value) = serde_json::from_str(&complete(json)) {
+            return Some(value);
+        }
+        // Drop the last character, e.g. of a partial key, literal, or escape sequence
+        let mut chars = json.chars();
+        chars.next_back()?;
+        json = chars.as_str().trim_end();
+        if json.is_empty() {
+            return None;
+        }
+    }
+}
+
+/// Appends the closing quote and brackets that `json` is missing.
+fn complete(json: &str) -> String {
+    let mut closers = Vec::new();
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for c in json.chars() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            '"' => in_string = true,
+            '{' => closers.push('}'),
+            '[' => closers.push(']'),
+            '}' | ']' => {
+                closers.pop();
+            }
+            _ => {}
+        }
+    }
+
+    let mut completed = json.to_string();
+    if in_string {
+        completed.push('"');
+    }
+    completed.extend(closers.iter().rev());
+    completed
+}
+
+// ============================================================================
+// Section: response types
+// ============================================================================
+
+/// A snapshot of a streamed object, parsed from the JSON received so far.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartialObject<T> {
+    /// The JSON received so far, with unfinished strings, arrays, and objects closed.
+    pub value: Value,
+    /// The snapshot deserialized into `T`, if it fits. Types whose fields are all
+    /// optional fit every snapshot, with fields filled in as they arrive.
+    pub object: Option<T>,
+}
+
+impl<T: DeserializeOwned> PartialObject<T> {
+    fn new(value: Value) -> Self {
+        let object = serde_json::from_value(value.clone()).ok();
+        Self { value, object }
+    }
+}
+
+/// An event of a [`stream_object`](LanguageModelRequest::stream_object) call.
+#[derive(Debug, Clone)]
+pub enum ObjectStreamEvent<T> {
+    /// The object parsed from the JSON received so far. Yielded whenever it changes.
+    Partial(PartialObject<T>),
+    /// The complete object, deserialized from the final response.
+    Object(T),
+    /// Generation failed, or the final response does not match the schema.
+    Error(Error),
+}
+
+/// A stream of [`ObjectStreamEvent`]s.
+pub struct ObjectStream<T> {
+    receiver: UnboundedReceiver<ObjectStreamEvent<T>>,
+}
+
+impl<T> Stream for ObjectStream<T> {
+    type Item = ObjectStreamEvent<T>;
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_recv(cx)
+    }
+}
+
+/// Response from a `stream_object` call.
+pub struct StreamObjectResponse<T> {
+    /// The stream of partial objects, followed by the final object or an error.
+    pub stream: ObjectStream<T>,
+    // The conversation state, updated as the stream progresses.
+    options: Arc<Mutex<LanguageModelOptions>>,
+    // Token usage reported by the provider across all chunks and steps.
+    usage: Arc<Mutex<UsageAccumulator>>,
+}
+
+impl<T> StreamObjectResponse<T> {
+    /// Consumes the stream and returns the final object.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] if generation fails or if the response cannot be
+    /// deserialized into `T`.
+    pub async fn object(mut self) -> Result<T> {
+        while let Some(event) = self.stream.next().await {
+            match event {
+                ObjectStreamEvent::Partial(_) => {}
+                ObjectStreamEvent::Object(object) => return Ok(object),
+                ObjectStreamEvent::Error(e) => return Err(e),
+            }
+        }
+        Err(Error::ObjectGenerationError(
+            "Stream ended without an object".to_string(),
+        ))
+    }
+
+    /// Returns the conversation state, including the streamed response once the stream
+    /// completes.
+    pub async fn options(&self) -> LanguageModelOptions {
+        self.options.lock().await.clone()
+    }
+
+    /// Returns the token usage reported by the provider across the whole run. The total
+    /// is final once the stream completes.
+    pub async fn total_usage(&self) -> Usage {
+        self.usage.lock().await.total()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::test_model::ScriptedModel;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_partial_json() {
+        assert_eq!(parse_partial_json("Sure"), None);
+        assert_eq!(parse_partial_json("{"), Some(json!({})));
+        assert_eq!(
+            parse_partial_json(r#"{"name": "Pan"#),
+            Some(json!({"name": "Pan"}))
+        );
+        assert_eq!(
+            parse_partial_json(r#"{"name": "Pancakes", "ste"#),
+            Some(json!({"name": "Pancakes"}))
+        );
+        assert_eq!(
+            parse_partial_json(r#"{"name": "Pancakes", "steps": ["Mix", "#),
+            Some(json!({"name": "Pancakes", "steps": ["Mix"]}))
+        );
+        assert_eq!(parse_partial_json(r#"{"done": tr"#), Some(json!({})));
+        assert_eq!(
+            parse_partial_json(r#"{"name": "a\"#),
+            Some(json!({"name": "a"}))
+        );
+    }
+
+    #[derive(Debug, JsonSchema, Deserialize, PartialEq)]
+    struct Recipe {
+        name: Option<String>,
+        steps: Option<Vec<String>>,
+    }
+
+    /// A model that streams a recipe as JSON in small pieces.
+    fn recipe_model() -> ScriptedModel {
+        ScriptedModel::new("recipe").text_deltas(&[
+            r#"{"na"#,
+            r#"me": "Pan"#,
+            r#"cakes", "#,
+            r#""steps": ["Mix", "Fr"#,
+            r#"y"]}"#,
+        ])
+    }
+
+    fn request() -> LanguageModelRequest<ScriptedModel> {
+        LanguageModelRequest::builder()
+            .model(recipe_model())
+            .prompt("Write a pancake recipe")
+            .build()
+    }
+
+    #[tokio::test]
+    async fn test_stream_object_yields_partials_and_object() {
+        let events: Vec<ObjectStreamEvent<Recipe>> = request()
+            .stream_object::<Recipe>()
+            .await
+            .unwrap()
+            .stream
+            .collect()
+            .await;
+
+        let partials: Vec<Value> = events
+            .iter()
+            .filter_map(|event| match event {
+                ObjectStreamEvent::Partial(partial) => Some(partial.value.clone()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            partials,
+            vec![
+                json!({}),
+                json!({"name": "Pan"}),
+                json!({"name": "Pancakes"}),
+                json!({"name": "Pancakes", "steps": ["Mix", "Fr"]}),
+                json!({"name": "Pancakes", "steps": ["Mix", "Fry"]}),
+            ]
+        );
+        assert!(matches!(
+            events.last(),
+            Some(ObjectStreamEvent::Object(Recipe {
+                name: Some(_),
+                steps: Some(_),
+            }))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_stream_object_object() {
+        let recipe = request()
+            .stream_object::<Recipe>()
+            .await
+            .unwrap()
+            .object()
+            .await
+            .unwrap();
+
+        assert_eq!(recipe.name.as_deref(), Some("Pancakes"));
+        assert_eq!(recipe.steps.unwrap(), vec!["Mix", "Fry"]);
+    }
+}
@@ -1,18 +1,27 @@
 //! Text Streaming impl for the `LanguageModelRequest` trait.
 
+use crate::core::observability::GenerationRecorder;
 use crate::core::{
     AssistantMessage, LanguageModelStreamChunkType, Message, Messages, ToolCallInfo,
     ToolResultInfo,
+    client::capture_raw_events,
+    cost::Cost,
+    image_model::GeneratedImage,
     language_model::{
-        LanguageModel, LanguageModelOptions, LanguageModelResponseContentType, LanguageModelStream,
-        LanguageModelStreamChunk, Step, StopReason, Usage, request::LanguageModelRequest,
+        FinishReason, LanguageModel, LanguageModelOptions, LanguageModelResponseContentType,
+        LanguageModelStream, LanguageModelStreamChunk, Source, StallAction, Step, StopReason,
+        StreamEvent, Usage, Warning, request::LanguageModelRequest, smooth_stream::SmoothStream,
+        snapshot::AgentSnapshot, tool_call_assembler::ToolCallAssembler,
+        usage_accumulator::UsageAccumulator,
     },
     messages::TaggedMessage,
+    tools::ToolCallDelta,
     utils::resolve_message,
 };
-use crate::error::Result;
-use futures::StreamExt;
+use crate::error::{Error, Result};
+use futures::{Stream, StreamExt};
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::Mutex;
 
 impl<M: LanguageModel> LanguageModelRequest<M> {
@@ -39,7 +48,7 @@ impl<M: LanguageModel> LanguageModelRequest<M> {
     ///# #[cfg(feature = "openai")]
     ///# {
     ///    use aisdk::{
-    ///        core::{LanguageModelRequest, LanguageModelStreamChunkType},
+    ///        core::{LanguageModelRequest, StreamEvent},
     ///        providers::OpenAI,
     ///    };
     ///    use futures::StreamExt;
@@ -57,7 +66,7 @@ impl<M: LanguageModel> LanguageModelRequest<M> {
     ///            .stream;
     ///
     ///         while let Some(chunk) = stream.next().await {
-    ///             if let LanguageModelStreamChunkType::Text(text) = chunk {
+    ///             if let StreamEvent::TextDelta(text) = chunk {
     ///                 println!("{}", text);
     ///             }
     ///         }
@@ -75,10 +84,29 @@ impl<M: LanguageModel> LanguageModelRequest<M> {
             schema: self.options.schema.to_owned(),
             stop_sequences: self.options.stop_sequences.to_owned(),
             tools: self.options.tools.to_owned(),
+            tool_choice: self.options.tool_choice.clone(),
+            provider_options: self.options.provider_options.clone(),
+            input_guards: self.options.input_guards.clone(),
+            output_guards: self.options.output_guards.clone(),
+            budget: self.options.budget.clone(),
+            cost_calculator: self.options.cost_calculator.clone(),
+            context_window: self.options.context_window.clone(),
+            effect: self.options.effect.clone(),
+            cancellation_token: self.options.cancellation_token.clone(),
+            telemetry: self.options.telemetry.clone(),
             stop_when: self.options.stop_when.clone(),
             on_step_start: self.options.on_step_start.clone(),
             on_step_finish: self.options.on_step_finish.clone(),
+            on_chunk: self.options.on_chunk.clone(),
+            on_finish: self.options.on_finish.clone(),
+            on_error: self.options.on_error.clone(),
             stop_reason: None,
+            finish_reason: None,
+            response_extensions: Default::default(),
+            warnings: Vec::new(),
+            response_logprobs: Vec::new(),
+            cost: None,
+            resume: self.options.resume.clone(),
             ..self.options
         }));
 
@@ -86,9 +114,58 @@ impl<M: LanguageModel> LanguageModelRequest<M> {
 
         let mut model = self.model.clone();
 
+        let usage = Arc::new(Mutex::new(UsageAccumulator::new()));
+
+        #[cfg(feature = "otel")]
+        let span = crate::core::telemetry::GenAiSpan::chat(&self.model, &self.options);
+        #[cfg(feature = "otel")]
+        let span_context = span.context();
+        #[cfg(feature = "metrics")]
+        let metrics = crate::core::metrics::RequestMetrics::chat(&self.model);
+        let recorder = GenerationRecorder::start(&self.model, &self.options, &self.prompt);
+
         let thread_options = options.clone();
-        tokio::spawn(async move {
-            loop {
+        let thread_usage = usage.clone();
+        let generation = async move {
+            let interrupt = thread_options.lock().await.interrupt();
+            let include_raw_chunks = thread_options.lock().await.include_raw_chunks;
+            let raw_events = thread_options.lock().await.raw_events;
+            let on_chunk = thread_options.lock().await.on_chunk.clone();
+            let idle_timeout = thread_options.lock().await.stream_idle_timeout;
+            let stall_action = thread_options.lock().await.stall_action;
+            let emit = |event: StreamEvent| {
+                if matches!(
+                    event,
+                    StreamEvent::TextDelta(_)
+                        | StreamEvent::ReasoningDelta(_)
+                        | StreamEvent::ToolCallDelta(_)
+                        | StreamEvent::ToolCall(_)
+                ) {
+                    #[cfg(feature = "otel")]
+                    span.first_token();
+                    #[cfg(feature = "metrics")]
+                    metrics.first_token();
+                    if let Some(recorder) = &recorder {
+                        recorder.first_token();
+                    }
+                }
+                if let Some(hook) = &on_chunk
+                    && !matches!(event, StreamEvent::Error(_))
+                {
+                    hook(&event);
+                }
+                let _ = tx.send(event);
+            };
+            emit(StreamEvent::StreamStart);
+
+            // Execute tool calls left pending by a restored snapshot
+            let resumed = thread_options.lock().await.resume(&interrupt).await;
+            if let Err(e) = resumed {
+                thread_options.lock().await.fail(e.clone());
+                emit(StreamEvent::Error(e));
+            }
+
+            while thread_options.lock().await.stop_reason.is_none() {
                 let mut options = thread_options.lock().await;
                 // Update the current step
                 options.current_step_id += 1;
@@ -99,27 +176,136 @@ impl<M: LanguageModel> LanguageModelRequest<M> {
                     hook(&mut options);
                 }
 
-                let _ = tx.send(LanguageModelStreamChunkType::Start);
-                let response_result = model.stream_text(options.clone()).await;
+                emit(StreamEvent::StepStart);
+                options.warn_unsupported(&model);
+                let request_options = options.prepare_request(&model).await;
+                let response_result = match request_options {
+                    Ok(request_options) => interrupt.run(model.stream_text(request_options)).await,
+                    Err(e) => Err(e),
+                };
                 let mut response = match response_result {
                     Ok(r) => r,
                     Err(e) => {
-                        options.stop_reason = Some(StopReason::Error(e.clone()));
-                        let _ = tx.send(LanguageModelStreamChunkType::Failed(format!(
-                            "Model streaming failed: {e}"
-                        )));
-                        return Err(e);
+                        options.fail(e.clone());
+                        emit(StreamEvent::Error(e));
+                        break;
                     }
                 };
 
-                while let Some(ref chunk) = response.next().await {
+                let mut assembler = ToolCallAssembler::new();
+                let mut stop_sequence = None;
+                // Deltas are held back while output guards are registered, and sent
+                // once guarded when the provider finishes their content
+                let guarded = !options.output_guards.is_empty();
+                let mut held_text = String::new();
+                let mut held_reasoning = String::new();
+                options.finish_reason = None;
+                options.response_extensions = Default::default();
+                options.response_logprobs.clear();
+                // Dropping the response on interruption closes the underlying stream
+                while let Some(ref chunk) = interrupt
+                    .run(async {
+                        let next = async {
+                            if !raw_events {
+                                return response.next().await;
+                            }
+                            let (chunk, payloads) = capture_raw_events(response.next()).await;
+                            for payload in payloads {
+                                emit(StreamEvent::ProviderEvent(payload));
+                            }
+                            chunk
+                        };
+                        let Some(idle_timeout) = idle_timeout else {
+                            return Ok(next.await);
+                        };
+                        tokio::pin!(next);
+                        let mut idle = Duration::ZERO;
+                        loop {
+                            match crate::core::runtime::timeout(idle_timeout, &mut next).await {
+                                Some(chunk) => return Ok(chunk),
+                                None if stall_action == StallAction::Notify => {
+                                    idle += idle_timeout;
+                                    emit(StreamEvent::Stalled(idle));
+                                }
+                                None => return Err(Error::StreamStalled(idle_timeout)),
+                            }
+                        }
+                    })
+                    .await
+                    .unwrap_or_else(|e| Some(Err(e)))
+                {
                     match chunk {
                         Ok(chunk) => {
                             for output in chunk {
+                                if include_raw_chunks {
+                                    emit(StreamEvent::Raw(output.clone()));
+                                }
                                 match output {
                                     LanguageModelStreamChunk::Done(final_msg) => {
+                                        if let Some(usage) = &final_msg.usage {
+                                            thread_usage.lock().await.observe(usage);
+                                        }
+                                        // Reasoning the provider does not finish precedes
+                                        // the content that follows it
+                                        if guarded
+                                            && !held_reasoning.is_empty()
+                                            && !matches!(
+                                                final_msg.content,
+                                                LanguageModelResponseContentType::Reasoning { .. }
+                                            )
+                                        {
+                                            match options.apply_output_guards(
+                                                LanguageModelResponseContentType::Reasoning {
+                                                    content: std::mem::take(&mut held_reasoning),
+                                                    extensions: Default::default(),
+                                                },
+                                            ) {
+                                                Ok(
+                                                    LanguageModelResponseContentType::Reasoning {
+                                                        content,
+                                                        ..
+                                                    },
+                                                ) => emit(StreamEvent::ReasoningDelta(content)),
+                                                Ok(_) => {}
+                                                Err(e) => {
+                                                    options.fail(e.clone());
+                                                    emit(StreamEvent::Error(e));
+                                                    break;
+                                                }
+                                            }
+                                        }
+                                        let final_msg = match options
+                                            .apply_output_guards(final_msg.content.clone())
+                                        {
+                                            Ok(content) => AssistantMessage {
+                                                content,
+                                                usage: final_msg.usage.clone(),
+                                            },
+                                            Err(e) => {
+                                                options.fail(e.clone());
+                                                emit(StreamEvent::Error(e));
+                                                break;
+                                            }
+                                        };
+                                        match &final_msg.content {
+                                            LanguageModelResponseContentType::Text(text)
+                                                if guarded =>
+                                            {
+                                                held_text.clear();
+                                                emit(StreamEvent::TextDelta(text.clone()));
+                                            }
+                                            LanguageModelResponseContentType::Reasoning {
+                                                content,
+                                                ..
+                                            } if guarded => {
+                                                held_reasoning.clear();
+                                                emit(StreamEvent::ReasoningDelta(content.clone()));
+                                            }
+                                            _ => {}
+                                        }
                                         match final_msg.content {
-                                            LanguageModelResponseContentType::Text(_) => {
+                                            LanguageModelResponseContentType::Text(_)
+                                            | LanguageModelResponseContentType::Refusal(_) => {
                                                 let assistant_msg =
                                                     Message::Assistant(AssistantMessage {
                                                         content: final_msg.content.clone(),
@@ -129,13 +315,11 @@ impl<M: LanguageModel> LanguageModelRequest<M> {
                                                     current_step_id,
                                                     assistant_msg,
                                                 ));
-                                                let _ = tx.send(LanguageModelStreamChunkType::End(
-                                                    AssistantMessage {
-                                                        content: final_msg.content.clone(),
-                                                        usage: final_msg.usage.clone(),
-                                                    },
-                                                ));
-                                                options.stop_reason = Some(StopReason::Finish);
+                                                options.stop_reason =
+                                                    Some(stop_sequence.take().map_or(
+                                                        StopReason::Finish,
+                                                        StopReason::StopSequence,
+                                                    ));
                                             }
                                             LanguageModelResponseContentType::Reasoning {
                                                 ref content,
@@ -153,6 +337,22 @@ impl<M: LanguageModel> LanguageModelRequest<M> {
                                                     }),
                                                 ));
                                             }
+                                            LanguageModelResponseContentType::Source(
+                                                ref source,
+                                            ) => {
+                                                emit(StreamEvent::Source(source.clone()));
+                                                options.messages.push(TaggedMessage::new(
+                                                    current_step_id,
+                                                    Message::Assistant(final_msg.clone()),
+                                                ));
+                                            }
+                                            LanguageModelResponseContentType::Image(ref image) => {
+                                                emit(StreamEvent::File(image.clone()));
+                                                options.messages.push(TaggedMessage::new(
+                                                    current_step_id,
+                                                    Message::Assistant(final_msg.clone()),
+                                                ));
+                                            }
                                             LanguageModelResponseContentType::ToolCall(
                                                 ref tool_info,
                                             ) => {
@@ -167,7 +367,23 @@ impl<M: LanguageModel> LanguageModelRequest<M> {
                                                         usage,
                                                     )),
                                                 ));
-                                                options.handle_tool_call(tool_info).await;
+                                                if let Some(ready) = assembler.complete(tool_info) {
+                                                    emit(StreamEvent::ToolCall(ready));
+                                                }
+                                                if let Err(e) = options
+                                                    .handle_tool_call(tool_info, &interrupt)
+                                                    .await
+                                                {
+                                                    options.fail(e.clone());
+                                                    emit(StreamEvent::Error(e));
+                                                    break;
+                                                }
+                                                if let Some(Message::Tool(result)) =
+                                                    options.messages.last().map(|m| &m.message)
+                                                    && result.tool.id == tool_info.tool.id
+                                                {
+                                                    emit(StreamEvent::ToolResult(result.clone()));
+                                                }
                                             }
                                             _ => {}
                                         }
@@ -181,31 +397,67 @@ impl<M: LanguageModel> LanguageModelRequest<M> {
                                         if let Some(hook) = &options.stop_when.clone()
                                             && hook(&options)
                                         {
-                                            let _ =
-                                                tx.send(LanguageModelStreamChunkType::Incomplete(
-                                                    "Stopped by hook".to_string(),
-                                                ));
                                             options.stop_reason = Some(StopReason::Hook);
                                             break;
                                         }
                                     }
-                                    LanguageModelStreamChunk::Delta(other) => {
-                                        match other {
-                                            // Propagate text, reasoning, and tool call chunks
-                                            LanguageModelStreamChunkType::Text(_)
-                                            | LanguageModelStreamChunkType::Reasoning(_)
-                                            | LanguageModelStreamChunkType::ToolCall(_) => {
-                                                let _ = tx.send(other.clone());
-                                            }
-                                            _ => {}
+                                    LanguageModelStreamChunk::Delta(other) => match other {
+                                        LanguageModelStreamChunkType::Text(text) if guarded => {
+                                            held_text.push_str(text);
+                                        }
+                                        LanguageModelStreamChunkType::Text(text) => {
+                                            emit(StreamEvent::TextDelta(text.clone()));
+                                        }
+                                        LanguageModelStreamChunkType::Reasoning(text)
+                                            if guarded =>
+                                        {
+                                            held_reasoning.push_str(text);
+                                        }
+                                        LanguageModelStreamChunkType::Reasoning(text) => {
+                                            emit(StreamEvent::ReasoningDelta(text.clone()));
+                                        }
+                                        // Fragments the provider does not attribute to a tool call
+                                        LanguageModelStreamChunkType::ToolCall(_) if guarded => {}
+                                        LanguageModelStreamChunkType::ToolCall(delta) => {
+                                            emit(StreamEvent::ToolCallDelta(ToolCallDelta {
+                                                tool: Default::default(),
+                                                delta: delta.clone(),
+                                            }));
+                                        }
+                                        LanguageModelStreamChunkType::Logprobs(logprobs) => {
+                                            options
+                                                .response_logprobs
+                                                .extend(logprobs.iter().cloned());
+                                            emit(StreamEvent::Logprobs(logprobs.clone()));
+                                        }
+                                        _ => {}
+                                    },
+                                    LanguageModelStreamChunk::Usage(usage) => {
+                                        thread_usage.lock().await.observe(usage);
+                                    }
+                                    LanguageModelStreamChunk::StopSequence(sequence) => {
+                                        stop_sequence = Some(sequence.clone());
+                                    }
+                                    LanguageModelStreamChunk::FinishReason(reason) => {
+                                        options.finish_reason = Some(reason.clone());
+                                    }
+                                    LanguageModelStreamChunk::RateLimit(info) => {
+                                        options.response_extensions.insert(info.clone());
+                                    }
+                                    // Guarded tool calls are sent whole once finished
+                                    LanguageModelStreamChunk::ToolCallDelta(_) if guarded => {}
+                                    LanguageModelStreamChunk::ToolCallDelta(delta) => {
+                                        emit(StreamEvent::ToolCallDelta(delta.clone()));
+                                        if let Some(ready) = assembler.push(delta) {
+                                            emit(StreamEvent::ToolCall(ready));
                                         }
                                     }
                                 }
                             }
                         }
                         Err(e) => {
-                            let _ = tx.send(LanguageModelStreamChunkType::Failed(e.to_string()));
-                            options.stop_reason = Some(StopReason::Error(e.clone()));
+                            options.fail(e.clone());
+                            emit(StreamEvent::Error(e.clone()));
                             break;
                         }
                     }
@@ -216,18 +468,91 @@ impl<M: LanguageModel> LanguageModelRequest<M> {
                     };
                 }
 
+                // Send what the provider streamed without finishing, guarded
+                let unfinished = [
+                    LanguageModelResponseContentType::Reasoning {
+                        content: held_reasoning,
+                        extensions: Default::default(),
+                    },
+                    LanguageModelResponseContentType::Text(held_text),
+                ];
+                for content in unfinished {
+                    if matches!(options.stop_reason, Some(StopReason::Error(_))) {
+                        break;
+                    }
+                    match options.apply_output_guards(content) {
+                        Ok(LanguageModelResponseContentType::Reasoning { content, .. })
+                            if !content.is_empty() =>
+                        {
+                            emit(StreamEvent::ReasoningDelta(content));
+                        }
+                        Ok(LanguageModelResponseContentType::Text(text)) if !text.is_empty() => {
+                            emit(StreamEvent::TextDelta(text));
+                        }
+                        Ok(_) => {}
+                        Err(e) => {
+                            options.fail(e.clone());
+                            emit(StreamEvent::Error(e));
+                        }
+                    }
+                }
+
+                // Charge the budget once per response, with the merged usage reports
+                let step_usage = thread_usage.lock().await.finish_response();
+                if let (Some(usage), Some(budget)) = (&step_usage, &options.budget) {
+                    budget.record(usage);
+                }
+                if let Some(usage) = &step_usage {
+                    options.record_cost(&model, usage);
+                }
+
+                if !matches!(options.stop_reason, Some(StopReason::Error(_))) {
+                    emit(StreamEvent::StepFinish {
+                        usage: step_usage,
+                        finish_reason: options.finish_reason.clone(),
+                    });
+                }
+
+                if options.stop_reason.is_none() && options.max_steps_reached() {
+                    options.stop_reason = Some(StopReason::MaxSteps);
+                }
+
                 match options.stop_reason {
                     None => {}
                     _ => break,
                 };
             }
 
-            drop(tx);
-
-            Ok(())
-        });
+            let usage = thread_usage.lock().await.total();
+            let options = thread_options.lock().await;
+            options.finish(&usage);
+            #[cfg(feature = "otel")]
+            {
+                span.finish(&options, &usage);
+                span.end();
+            }
+            #[cfg(feature = "metrics")]
+            {
+                metrics.finish(&options, &usage);
+                metrics.end();
+            }
+            if let Some(recorder) = &recorder {
+                recorder.finish(&options, &usage);
+            }
+            emit(StreamEvent::Finish {
+                usage,
+                stop_reason: options.stop_reason.clone().unwrap_or_default(),
+            });
+        };
+        #[cfg(feature = "otel")]
+        let generation = opentelemetry::trace::FutureExt::with_context(generation, span_context);
+        crate::core::runtime::spawn(generation);
 
-        let result = StreamTextResponse { stream, options };
+        let result = StreamTextResponse {
+            stream,
+            options,
+            usage,
+        };
 
         Ok(result)
     }
@@ -242,10 +567,13 @@ impl<M: LanguageModel> LanguageModelRequest<M> {
 /// This struct contains the streaming response from a language model,
 /// including the stream of chunks and the final options state.
 pub struct StreamTextResponse {
-    /// The stream of response chunks from the language model.
+    /// The stream of events of the generation, such as text deltas, tool calls and step
+    /// boundaries.
     pub stream: LanguageModelStream,
     // The reason the model stopped generating text.
-    options: Arc<Mutex<LanguageModelOptions>>,
+    pub(crate) options: Arc<Mutex<LanguageModelOptions>>,
+    // Token usage reported by the provider across all chunks and steps.
+    pub(crate) usage: Arc<Mutex<UsageAccumulator>>,
 }
 
 impl StreamTextResponse {
@@ -265,6 +593,85 @@ impl StreamTextResponse {
 }
 
 impl StreamTextResponse {
+    /// Re-chunks the text of [`stream`](Self::stream) by word or line and emits it with
+    /// a fixed delay, so the text arrives at an even cadence however bursty the
+    /// provider is.
+    ///
+    /// See [`SmoothStream`] for the chunking and delay options.
+    pub fn smooth_stream(mut self, smoothing: SmoothStream) -> Self {
+        self.stream = smoothing.apply(self.stream);
+        self
+    }
+
+    /// The number of events [`tee`](Self::tee) buffers for the slower stream.
+    pub const TEE_CAPACITY: usize = 256;
+
+    /// Splits the response into two that yield the same events, e.g. to forward one to
+    /// a UI while the other is consumed to build the final message for storage.
+    ///
+    /// Each stream is consumed independently, and dropping one does not stop the
+    /// other. Both share the conversation state of this response. Up to
+    /// [`TEE_CAPACITY`](Self::TEE_CAPACITY) events are buffered for the slower stream,
+    /// see [`tee_with_capacity`](Self::tee_with_capacity).
+    pub fn tee(self) -> (Self, Self) {
+        self.tee_with_capacity(Self::TEE_CAPACITY)
+    }
+
+    /// Splits the response like [`tee`](Self::tee), buffering up to `capacity` events
+    /// for the slower stream. Once its buffer is full, the faster stream waits for it,
+    /// so longer responses need both streams to be consumed concurrently.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is 0.
+    pub fn tee_with_capacity(self, capacity: usize) -> (Self, Self) {
+        let (first_tx, first) = LanguageModelStream::bounded(capacity);
+        let (second_tx, second) = LanguageModelStream::bounded(capacity);
+        let mut stream = self.stream;
+
+        crate::core::runtime::spawn(async move {
+            while let Some(event) = stream.next().await {
+                let (first_sent, second_sent) =
+                    futures::join!(first_tx.send(event.clone()), second_tx.send(event));
+                if first_sent.is_err() && second_sent.is_err() {
+                    return;
+                }
+            }
+        });
+
+        (
+            Self {
+                stream: first,
+                options: self.options.clone(),
+                usage: self.usage.clone(),
+            },
+            Self {
+                stream: second,
+                options: self.options,
+                usage: self.usage,
+            },
+        )
+    }
+
+    /// Returns the text deltas of [`stream`](Self::stream), skipping reasoning, tool call
+    /// and other chunks.
+    ///
+    /// The stream is borrowed, so the conversation state can still be read once it
+    /// ends.
+    pub fn text_stream(&mut self) -> impl Stream<Item = String> + '_ {
+        (&mut self.stream).filter_map(|chunk| async move {
+            match chunk {
+                StreamEvent::TextDelta(text) => Some(text),
+                _ => None,
+            }
+        })
+    }
+
+    /// Consumes the response and collects the streamed text into a single string.
+    pub async fn into_text(mut self) -> String {
+        self.text_stream().collect().await
+    }
+
     /// Returns all messages from the conversation.
     ///
     /// This includes system prompts, user inputs, assistant responses,
@@ -277,6 +684,15 @@ impl StreamTextResponse {
         self.options.lock().await.messages()
     }
 
+    /// Captures the current agent loop state in a portable snapshot.
+    ///
+    /// # Returns
+    ///
+    /// An [`AgentSnapshot`] that can be persisted and restored later.
+    pub async fn snapshot(&self) -> AgentSnapshot {
+        self.options.lock().await.snapshot()
+    }
+
     /// Returns the conversation step with the specified index.
     ///
     /// A step represents all messages exchanged during one cycle of model interaction,
@@ -327,6 +743,27 @@ impl StreamTextResponse {
         self.options.lock().await.usage()
     }
 
+    /// Returns the token usage reported by the provider across the whole run.
+    ///
+    /// Unlike [`usage`](Self::usage), which sums the usage attached to the stored
+    /// assistant messages, this accumulates every usage report seen on the stream,
+    /// including intermediate reports and those of tool call steps. The total is
+    /// final once the stream completes.
+    ///
+    /// # Returns
+    ///
+    /// A [`Usage`] struct containing the accumulated token statistics.
+    pub async fn total_usage(&self) -> Usage {
+        self.usage.lock().await.total()
+    }
+
+    /// Returns the cost of the model calls, summed over the steps, if a
+    /// [`cost_calculator`](crate::core::language_model::request::LanguageModelRequestBuilder::cost_calculator)
+    /// is attached and priced them. The cost is final once the stream completes.
+    pub async fn cost(&self) -> Option<Cost> {
+        self.options.lock().await.cost()
+    }
+
     /// Returns the content of the last assistant message, excluding reasoning.
     ///
     /// This provides access to the final output content from the language model,
@@ -374,6 +811,27 @@ impl StreamTextResponse {
     pub async fn tool_calls(&self) -> Option<Vec<ToolCallInfo>> {
         self.options.lock().await.tool_calls()
     }
+
+    /// Extracts all sources the streamed responses were grounded in.
+    ///
+    /// Sources are reported by providers that ground responses in documents or web
+    /// search results, such as Perplexity.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Vec<Source>>` containing all sources if any exist.
+    pub async fn sources(&self) -> Option<Vec<Source>> {
+        self.options.lock().await.sources()
+    }
+
+    /// Extracts all images generated inline with the streamed responses.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Vec<GeneratedImage>>` containing all images if any exist.
+    pub async fn images(&self) -> Option<Vec<GeneratedImage>> {
+        self.options.lock().await.images()
+    }
     /// Returns the reason why text generation stopped.
     ///
     /// This indicates how and why the streaming process terminated,
@@ -385,4 +843,372 @@ impl StreamTextResponse {
     pub async fn stop_reason(&self) -> Option<StopReason> {
         self.options.lock().await.stop_reason()
     }
+
+    /// Returns why the model stopped generating the last response, if the provider
+    /// reports it.
+    pub async fn finish_reason(&self) -> Option<FinishReason> {
+        self.options.lock().await.finish_reason()
+    }
+
+    /// Returns the warnings about the call, such as options the model does not
+    /// support.
+    pub async fn warnings(&self) -> Vec<Warning> {
+        self.options.lock().await.warnings().to_vec()
+    }
+
+    /// Returns the provider-specific extensions of the last model response, such as
+    /// the [`RateLimitInfo`](crate::core::rate_limit::RateLimitInfo) reported when
+    /// its stream opened.
+    pub async fn response_extensions(&self) -> crate::extensions::Extensions {
+        self.options.lock().await.response_extensions().clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+    use crate::core::test_model::ScriptedModel;
+
+    /// A model that streams a reasoning chunk and a greeting in two text chunks, among
+    /// provider events the core does not forward.
+    fn greeting_model() -> ScriptedModel {
+        ScriptedModel::new("greeting").chunks(vec![vec![
+            LanguageModelStreamChunk::Delta(LanguageModelStreamChunkType::Reasoning(
+                "The user wants a greeting".to_string(),
+            )),
+            LanguageModelStreamChunk::Delta(LanguageModelStreamChunkType::Text(
+                "Hello ".to_string(),
+            )),
+            LanguageModelStreamChunk::Delta(LanguageModelStreamChunkType::Text(
+                "world".to_string(),
+            )),
+            LanguageModelStreamChunk::Delta(LanguageModelStreamChunkType::NotSupported(
+                "response.in_progress".to_string(),
+            )),
+            LanguageModelStreamChunk::Usage(Usage {
+                input_tokens: Some(3),
+                output_tokens: Some(2),
+                ..Default::default()
+            }),
+            LanguageModelStreamChunk::FinishReason(FinishReason::Stop),
+            LanguageModelStreamChunk::Done(AssistantMessage::new(
+                LanguageModelResponseContentType::Text("Hello world".to_string()),
+                None,
+            )),
+        ]])
+    }
+
+    fn request() -> LanguageModelRequest<ScriptedModel> {
+        LanguageModelRequest::builder()
+            .model(greeting_model())
+            .prompt("greet me")
+            .build()
+    }
+
+    #[tokio::test]
+    async fn test_text_stream_yields_only_text() {
+        let mut response = request().stream_text().await.unwrap();
+
+        let texts: Vec<String> = response.text_stream().collect().await;
+
+        assert_eq!(texts, vec!["Hello ", "world"]);
+        assert_eq!(response.text().await.as_deref(), Some("Hello world"));
+    }
+
+    /// An output guard replacing greetings with farewells.
+    struct FarewellGuard;
+
+    impl crate::core::guards::OutputGuard for FarewellGuard {
+        fn guard_output(&self, text: &str) -> Result<String> {
+            Ok(text.replace("Hello", "Bye"))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_output_guards_hold_back_deltas() {
+        let response = LanguageModelRequest::builder()
+            .model(greeting_model())
+            .prompt("greet me")
+            .with_output_guard(FarewellGuard)
+            .build()
+            .stream_text()
+            .await
+            .unwrap();
+
+        let events: Vec<StreamEvent> = response.stream.collect().await;
+
+        let deltas: Vec<&StreamEvent> = events
+            .iter()
+            .filter(|event| {
+                matches!(
+                    event,
+                    StreamEvent::TextDelta(_) | StreamEvent::ReasoningDelta(_)
+                )
+            })
+            .collect();
+        assert!(matches!(
+            deltas.as_slice(),
+            [StreamEvent::ReasoningDelta(reasoning), StreamEvent::TextDelta(text)]
+                if reasoning == "The user wants a greeting" && text == "Bye world"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_into_text_collects_text() {
+        let response = request().stream_text().await.unwrap();
+
+        assert_eq!(response.into_text().await, "Hello world");
+    }
+
+    #[tokio::test]
+    async fn test_stream_events() {
+        let response = request().stream_text().await.unwrap();
+
+        let events: Vec<StreamEvent> = response.stream.collect().await;
+
+        assert!(matches!(
+            events.as_slice(),
+            [
+                StreamEvent::StreamStart,
+                StreamEvent::StepStart,
+                StreamEvent::ReasoningDelta(_),
+                StreamEvent::TextDelta(_),
+                StreamEvent::TextDelta(_),
+                StreamEvent::StepFinish {
+                    usage: Some(_),
+                    finish_reason: Some(FinishReason::Stop),
+                },
+                StreamEvent::Finish {
+                    stop_reason: StopReason::Finish,
+                    ..
+                },
+            ]
+        ));
+        let Some(StreamEvent::Finish { usage, .. }) = events.last() else {
+            unreachable!()
+        };
+        assert_eq!(usage.output_tokens, Some(2));
+    }
+
+    #[tokio::test]
+    async fn test_stream_events_with_raw_chunks() {
+        let response = LanguageModelRequest::builder()
+            .model(greeting_model())
+            .prompt("greet me")
+            .include_raw_chunks(true)
+            .build()
+            .stream_text()
+            .await
+            .unwrap();
+
+        let events: Vec<StreamEvent> = response.stream.collect().await;
+
+        let raw = events
+            .iter()
+            .filter(|event| matches!(event, StreamEvent::Raw(_)))
+            .count();
+        assert_eq!(raw, 7);
+        assert!(events.iter().any(|event| matches!(
+            event,
+            StreamEvent::Raw(LanguageModelStreamChunk::Delta(
+                LanguageModelStreamChunkType::NotSupported(_)
+            ))
+        )));
+    }
+
+    /// A model that streams a greeting from two provider events, recording their
+    /// payloads as a provider client does.
+    fn raw_event_model() -> ScriptedModel {
+        ScriptedModel::new("raw-event")
+            .chunks(vec![
+                Vec::new(),
+                vec![
+                    LanguageModelStreamChunk::Delta(LanguageModelStreamChunkType::Text(
+                        "Hello".to_string(),
+                    )),
+                    LanguageModelStreamChunk::Done(AssistantMessage::new(
+                        LanguageModelResponseContentType::Text("Hello".to_string()),
+                        None,
+                    )),
+                ],
+            ])
+            .raw_events(vec![
+                r#"{"type":"response.output_item.added","output_index":0}"#,
+                r#"{"type":"response.output_text.delta","delta":"Hello"}"#,
+            ])
+    }
+
+    #[tokio::test]
+    async fn test_stream_with_raw_events() {
+        let events: Vec<StreamEvent> = LanguageModelRequest::builder()
+            .model(raw_event_model())
+            .prompt("greet me")
+            .with_raw_events()
+            .build()
+            .stream_text()
+            .await
+            .unwrap()
+            .stream
+            .collect()
+            .await;
+
+        assert!(matches!(
+            events.as_slice(),
+            [
+                StreamEvent::StreamStart,
+                StreamEvent::StepStart,
+                StreamEvent::ProviderEvent(added),
+                StreamEvent::ProviderEvent(delta),
+                StreamEvent::TextDelta(_),
+                StreamEvent::StepFinish { .. },
+                StreamEvent::Finish { .. },
+            ] if added["type"] == "response.output_item.added"
+                && delta["delta"] == "Hello"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_stream_without_raw_events() {
+        let events: Vec<StreamEvent> = LanguageModelRequest::builder()
+            .model(raw_event_model())
+            .prompt("greet me")
+            .build()
+            .stream_text()
+            .await
+            .unwrap()
+            .stream
+            .collect()
+            .await;
+
+        assert!(
+            !events
+                .iter()
+                .any(|event| matches!(event, StreamEvent::ProviderEvent(_)))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stream_hooks() {
+        let chunks = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let finished = Arc::new(std::sync::Mutex::new(None));
+        let hook_chunks = chunks.clone();
+        let hook_finished = finished.clone();
+
+        let response = LanguageModelRequest::builder()
+            .model(greeting_model())
+            .prompt("greet me")
+            .on_chunk(move |event| {
+                if let StreamEvent::TextDelta(text) = event {
+                    hook_chunks.lock().unwrap().push(text.clone());
+                }
+            })
+            .on_finish(move |options, usage| {
+                *hook_finished.lock().unwrap() = Some((options.text(), usage.output_tokens));
+            })
+            .on_error(|e| panic!("unexpected error: {e}"))
+            .build()
+            .stream_text()
+            .await
+            .unwrap();
+
+        assert_eq!(response.into_text().await, "Hello world");
+        assert_eq!(*chunks.lock().unwrap(), vec!["Hello ", "world"]);
+        assert_eq!(
+            *finished.lock().unwrap(),
+            Some((Some("Hello world".to_string()), Some(2)))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_tee_yields_events_to_both_streams() {
+        let (ui, storage) = request().stream_text().await.unwrap().tee();
+
+        let ui_events: Vec<StreamEvent> = ui.stream.collect().await;
+
+        assert!(matches!(ui_events.last(), Some(StreamEvent::Finish { .. })));
+        assert_eq!(storage.into_text().await, "Hello world");
+    }
+
+    #[tokio::test]
+    async fn test_tee_buffers_up_to_capacity() {
+        let (mut ui, storage) = request().stream_text().await.unwrap().tee_with_capacity(1);
+
+        // The storage stream is not read, so the UI stream gets one event ahead of it
+        assert!(ui.stream.next().await.is_some());
+        assert!(ui.stream.next().await.is_some());
+        let stalled = tokio::time::timeout(Duration::from_millis(50), ui.stream.next()).await;
+        assert!(stalled.is_err());
+
+        let (ui_events, text) = futures::join!(ui.stream.collect::<Vec<_>>(), storage.into_text());
+        assert!(matches!(ui_events.last(), Some(StreamEvent::Finish { .. })));
+        assert_eq!(text, "Hello world");
+    }
+
+    #[tokio::test]
+    async fn test_tee_survives_dropped_stream() {
+        let (ui, storage) = request().stream_text().await.unwrap().tee();
+        drop(ui);
+
+        assert_eq!(storage.into_text().await, "Hello world");
+    }
+
+    /// A model that streams one text chunk and then goes silent.
+    fn stalling_model() -> ScriptedModel {
+        ScriptedModel::new("stalling")
+            .chunks(vec![vec![LanguageModelStreamChunk::Delta(
+                LanguageModelStreamChunkType::Text("Hello".to_string()),
+            )]])
+            .stalling()
+    }
+
+    #[tokio::test]
+    async fn test_stream_idle_timeout_aborts() {
+        let idle_timeout = Duration::from_millis(10);
+        let response = LanguageModelRequest::builder()
+            .model(stalling_model())
+            .prompt("greet me")
+            .stream_idle_timeout(idle_timeout)
+            .build()
+            .stream_text()
+            .await
+            .unwrap();
+
+        let events: Vec<StreamEvent> = response.stream.collect().await;
+
+        assert!(events.iter().any(|event| matches!(
+            event,
+            StreamEvent::Error(Error::StreamStalled(idle)) if *idle == idle_timeout
+        )));
+    }
+
+    #[tokio::test]
+    async fn test_stream_idle_timeout_notifies() {
+        let response = LanguageModelRequest::builder()
+            .model(stalling_model())
+            .prompt("greet me")
+            .stream_idle_timeout(Duration::from_millis(10))
+            .stall_action(StallAction::Notify)
+            .build()
+            .stream_text()
+            .await
+            .unwrap();
+
+        let stalls: Vec<Duration> = response
+            .stream
+            .filter_map(|event| async move {
+                match event {
+                    StreamEvent::Stalled(idle) => Some(idle),
+                    _ => None,
+                }
+            })
+            .take(2)
+            .collect()
+            .await;
+
+        assert_eq!(
+            stalls,
+            vec![Duration::from_millis(10), Duration::from_millis(20)]
+        );
+    }
 }
@@ -0,0 +1,122 @@
+//! Assembly of streamed tool call arguments.
+//!
+//! Providers stream tool call arguments as partial JSON fragments. The
+//! [`ToolCallAssembler`] accumulates the fragments of each tool call by id and yields a
+//! complete [`ToolCallInfo`] as soon as its arguments parse, so consumers of the stream
+//! receive a single [`ToolCall`](crate::core::language_model::StreamEvent::ToolCall)
+//! event per tool call instead of reassembling provider-specific fragments themselves.
+
+use crate::core::tools::{ToolCallDelta, ToolCallInfo};
+use std::collections::{HashMap, HashSet};
+
+/// Accumulates partial tool call arguments per tool call id.
+#[derive(Debug, Clone, Default)]
+pub struct ToolCallAssembler {
+    /// Arguments accumulated so far, keyed by tool call id.
+    pending: HashMap<String, String>,
+    /// Ids of tool calls that have already been yielded.
+    ready: HashSet<String>,
+}
+
+impl ToolCallAssembler {
+    /// Creates an empty assembler.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a fragment of tool call arguments.
+    ///
+    /// # Returns
+    ///
+    /// The assembled tool call, the first time its accumulated arguments parse as a
+    /// JSON object.
+    pub fn push(&mut self, delta: &ToolCallDelta) -> Option<ToolCallInfo> {
+        let id = &delta.tool.id;
+        if self.ready.contains(id) {
+            return None;
+        }
+
+        let arguments = self.pending.entry(id.clone()).or_default();
+        arguments.push_str(&delta.delta);
+
+        match serde_json::from_str::<serde_json::Value>(arguments) {
+            Ok(input @ serde_json::Value::Object(_)) => {
+                self.pending.remove(id);
+                self.ready.insert(id.clone());
+
+                let mut info = ToolCallInfo::new(delta.tool.name.clone());
+                info.id(id.clone());
+                info.input(input);
+                Some(info)
+            }
+            _ => None,
+        }
+    }
+
+    /// Marks a tool call reported complete by the provider as assembled.
+    ///
+    /// Covers providers that do not stream arguments, or whose fragments never
+    /// formed a complete object, such as tools called without arguments.
+    ///
+    /// # Returns
+    ///
+    /// The tool call, unless it was already yielded by [`push`](Self::push).
+    pub fn complete(&mut self, info: &ToolCallInfo) -> Option<ToolCallInfo> {
+        let id = &info.tool.id;
+        self.pending.remove(id);
+        if !id.is_empty() && !self.ready.insert(id.clone()) {
+            return None;
+        }
+        Some(info.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::tools::ToolDetails;
+
+    fn delta(id: &str, fragment: &str) -> ToolCallDelta {
+        ToolCallDelta {
+            tool: ToolDetails {
+                name: "search".to_string(),
+                id: id.to_string(),
+            },
+            delta: fragment.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_assembles_fragments_per_id() {
+        let mut assembler = ToolCallAssembler::new();
+        assert!(assembler.push(&delta("a", r#"{"q": "ru"#)).is_none());
+        assert!(assembler.push(&delta("b", r#"{"q": "#)).is_none());
+
+        let a = assembler.push(&delta("a", r#"st"}"#)).unwrap();
+        assert_eq!(a.tool.id, "a");
+        assert_eq!(a.tool.name, "search");
+        assert_eq!(a.input, serde_json::json!({ "q": "rust" }));
+
+        let b = assembler.push(&delta("b", r#""go"}"#)).unwrap();
+        assert_eq!(b.input, serde_json::json!({ "q": "go" }));
+    }
+
+    #[test]
+    fn test_yields_each_call_once() {
+        let mut assembler = ToolCallAssembler::new();
+        let info = assembler.push(&delta("a", "{}")).unwrap();
+        assert!(assembler.push(&delta("a", " ")).is_none());
+        assert!(assembler.complete(&info).is_none());
+    }
+
+    #[test]
+    fn test_complete_yields_unstreamed_calls() {
+        let mut assembler = ToolCallAssembler::new();
+        assert!(assembler.push(&delta("a", r#"{"q": "#)).is_none());
+
+        let mut info = ToolCallInfo::new("search");
+        info.id("a");
+        info.input(serde_json::json!({}));
+        assert_eq!(assembler.complete(&info), Some(info));
+    }
+}
@@ -0,0 +1,118 @@
+//! Accumulation of token usage across a streamed, multi-step run.
+//!
+//! Providers report usage at different points of a stream: some once on the final
+//! chunk, others incrementally (e.g. input tokens when the message starts and output
+//! tokens when it ends). Those reports are cumulative snapshots of the same response,
+//! so the [`UsageAccumulator`] merges them per response and sums the merged usage of
+//! every response in the run.
+
+use crate::core::language_model::Usage;
+
+/// Sums token usage across the responses of a streamed run.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UsageAccumulator {
+    /// Usage of all completed responses.
+    total: Usage,
+    /// Usage reported so far by the response currently being streamed.
+    current: Option<Usage>,
+}
+
+impl UsageAccumulator {
+    /// Creates an empty accumulator.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a usage report for the response currently being streamed.
+    ///
+    /// Reports within a response are cumulative, so fields present in `usage`
+    /// replace earlier values and missing fields keep them.
+    pub fn observe(&mut self, usage: &Usage) {
+        let current = self.current.get_or_insert_with(Usage::default);
+        current.input_tokens = usage.input_tokens.or(current.input_tokens);
+        current.output_tokens = usage.output_tokens.or(current.output_tokens);
+        current.reasoning_tokens = usage.reasoning_tokens.or(current.reasoning_tokens);
+        current.cached_tokens = usage.cached_tokens.or(current.cached_tokens);
+    }
+
+    /// Completes the response currently being streamed and adds its usage to the total.
+    ///
+    /// # Returns
+    ///
+    /// The usage of the completed response, if any was reported.
+    pub fn finish_response(&mut self) -> Option<Usage> {
+        let current = self.current.take()?;
+        self.total = &self.total + &current;
+        Some(current)
+    }
+
+    /// Returns the usage accumulated so far, including the response in progress.
+    pub fn total(&self) -> Usage {
+        match &self.current {
+            Some(current) => &self.total + current,
+            None => self.total.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn usage(input: Option<usize>, output: Option<usize>) -> Usage {
+        Usage {
+            input_tokens: input,
+            output_tokens: output,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_merges_reports_within_a_response() {
+        let mut acc = UsageAccumulator::new();
+        acc.observe(&usage(Some(10), Some(1)));
+        acc.observe(&usage(None, Some(5)));
+
+        assert_eq!(acc.finish_response(), Some(usage(Some(10), Some(5))));
+        assert_eq!(acc.total(), usage(Some(10), Some(5)));
+    }
+
+    #[test]
+    fn test_repeated_reports_are_not_double_counted() {
+        let mut acc = UsageAccumulator::new();
+        acc.observe(&usage(Some(10), Some(5)));
+        acc.observe(&usage(Some(10), Some(5)));
+        acc.finish_response();
+
+        assert_eq!(acc.total(), usage(Some(10), Some(5)));
+    }
+
+    #[test]
+    fn test_sums_across_responses() {
+        let mut acc = UsageAccumulator::new();
+        acc.observe(&usage(Some(10), Some(5)));
+        acc.finish_response();
+        acc.observe(&usage(Some(20), Some(7)));
+        acc.finish_response();
+
+        assert_eq!(acc.total(), usage(Some(30), Some(12)));
+    }
+
+    #[test]
+    fn test_total_includes_response_in_progress() {
+        let mut acc = UsageAccumulator::new();
+        acc.observe(&usage(Some(10), Some(5)));
+        acc.finish_response();
+        acc.observe(&usage(Some(3), None));
+
+        assert_eq!(acc.total(), usage(Some(13), Some(5)));
+    }
+
+    #[test]
+    fn test_finish_without_reports() {
+        let mut acc = UsageAccumulator::new();
+
+        assert_eq!(acc.finish_response(), None);
+        assert_eq!(acc.total(), Usage::default());
+    }
+}
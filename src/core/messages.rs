@@ -1,12 +1,20 @@
 //! Message types for the `aisdk` library.
 
 use crate::core::{
-    language_model::{LanguageModelResponseContentType, Usage},
+    image_model::GeneratedImage,
+    language_model::{LanguageModelResponseContentType, Source, Usage},
     tools::{ToolCallInfo, ToolResultInfo},
 };
+use crate::error::{Error, Result};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
 
 /// The role of a participant in a conversation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum Role {
     /// System-level instructions or context.
     System,
@@ -17,7 +25,8 @@ pub enum Role {
 }
 
 /// A message in a conversation with a language model.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum Message {
     /// A system message providing context or instructions.
     System(SystemMessage),
@@ -77,7 +86,7 @@ impl Message {
 }
 
 /// A system message that provides context or instructions to the model.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SystemMessage {
     /// The text content of the system message.
     pub content: String,
@@ -105,10 +114,14 @@ impl From<&str> for SystemMessage {
 }
 
 /// A user message containing input from the human participant.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UserMessage {
-    /// The text content of the user message.
+    /// The text content of the user message. For multi-part messages, the text of all
+    /// text parts, so providers without multi-part support still receive the text.
     pub content: String,
+    /// The ordered parts of a multi-part message. Empty for plain text messages.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub parts: Vec<ContentPart>,
 }
 
 impl UserMessage {
@@ -116,6 +129,36 @@ impl UserMessage {
     pub fn new(content: impl Into<String>) -> Self {
         Self {
             content: content.into(),
+            parts: Vec::new(),
+        }
+    }
+
+    /// Creates a multi-part user message from an ordered list of parts.
+    ///
+    /// # Example
+    /// ```
+    /// use aisdk::core::messages::{ContentPart, ImagePart, UserMessage};
+    ///
+    /// let message = UserMessage::with_parts([
+    ///     ContentPart::text("What is in this image?"),
+    ///     ImagePart::url("https://example.com/cat.png").into(),
+    /// ]);
+    /// assert_eq!(message.content, "What is in this image?");
+    /// ```
+    pub fn with_parts(parts: impl IntoIterator<Item = ContentPart>) -> Self {
+        let parts: Vec<ContentPart> = parts.into_iter().collect();
+        Self {
+            content: text_of(&parts),
+            parts,
+        }
+    }
+
+    /// Returns the parts of the message. A plain text message is a single text part.
+    pub fn content_parts(&self) -> Vec<ContentPart> {
+        if self.parts.is_empty() {
+            vec![ContentPart::Text(self.content.clone())]
+        } else {
+            self.parts.clone()
         }
     }
 }
@@ -132,8 +175,394 @@ impl From<&str> for UserMessage {
     }
 }
 
+impl From<Vec<ContentPart>> for UserMessage {
+    fn from(value: Vec<ContentPart>) -> Self {
+        Self::with_parts(value)
+    }
+}
+
+/// Joins the text parts of a multi-part message.
+pub(crate) fn text_of(parts: &[ContentPart]) -> String {
+    parts
+        .iter()
+        .filter_map(|part| match part {
+            ContentPart::Text(text) => Some(text.as_str()),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// A part of a multi-part user message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ContentPart {
+    /// Plain text.
+    Text(String),
+    /// An image.
+    Image(ImagePart),
+    /// A file, such as a PDF or a text document.
+    File(FilePart),
+    /// A document the model reads, such as a PDF.
+    Document(DocumentPart),
+    /// Audio, such as a voice note.
+    Audio(AudioPart),
+}
+
+impl ContentPart {
+    /// Creates a text part.
+    pub fn text(text: impl Into<String>) -> Self {
+        Self::Text(text.into())
+    }
+}
+
+impl From<String> for ContentPart {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<&str> for ContentPart {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+impl From<ImagePart> for ContentPart {
+    fn from(value: ImagePart) -> Self {
+        Self::Image(value)
+    }
+}
+
+impl From<FilePart> for ContentPart {
+    fn from(value: FilePart) -> Self {
+        Self::File(value)
+    }
+}
+
+impl From<DocumentPart> for ContentPart {
+    fn from(value: DocumentPart) -> Self {
+        Self::Document(value)
+    }
+}
+
+impl From<AudioPart> for ContentPart {
+    fn from(value: AudioPart) -> Self {
+        Self::Audio(value)
+    }
+}
+
+/// Where the data of an image or file part comes from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MediaSource {
+    /// A URL the provider downloads the data from.
+    Url(String),
+    /// The base64 encoded data.
+    Base64(String),
+}
+
+impl MediaSource {
+    /// Returns the source as a URL, encoding base64 data as a `data:` URL.
+    pub fn to_url(&self, media_type: &str) -> String {
+        match self {
+            Self::Url(url) => url.clone(),
+            Self::Base64(data) => format!("data:{media_type};base64,{data}"),
+        }
+    }
+}
+
+/// An image in a user message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImagePart {
+    /// The image data.
+    pub source: MediaSource,
+    /// The media type of the image, e.g. `image/png`. Required by most providers for
+    /// base64 data.
+    pub media_type: Option<String>,
+}
+
+impl ImagePart {
+    /// Creates an image part from a URL.
+    pub fn url(url: impl Into<String>) -> Self {
+        Self {
+            source: MediaSource::Url(url.into()),
+            media_type: None,
+        }
+    }
+
+    /// Creates an image part from base64 encoded data.
+    pub fn base64(data: impl Into<String>, media_type: impl Into<String>) -> Self {
+        Self {
+            source: MediaSource::Base64(data.into()),
+            media_type: Some(media_type.into()),
+        }
+    }
+
+    /// Creates an image part from encoded image bytes.
+    pub fn bytes(data: impl AsRef<[u8]>, media_type: impl Into<String>) -> Self {
+        Self::base64(STANDARD.encode(data), media_type)
+    }
+
+    /// Reads an image from a local file. The media type is guessed from the extension.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidInput` if the file cannot be read.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        Ok(Self::bytes(read_file(path)?, media_type_of(path)))
+    }
+
+    /// Returns the media type of the image, defaulting to `image/jpeg`.
+    pub fn media_type(&self) -> &str {
+        self.media_type.as_deref().unwrap_or("image/jpeg")
+    }
+}
+
+/// A file in a user message, such as a PDF or a text document.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FilePart {
+    /// The file data.
+    pub source: MediaSource,
+    /// The media type of the file, e.g. `application/pdf`.
+    pub media_type: String,
+    /// The name of the file, if known.
+    pub filename: Option<String>,
+}
+
+impl FilePart {
+    /// Creates a file part from a URL.
+    pub fn url(url: impl Into<String>, media_type: impl Into<String>) -> Self {
+        Self {
+            source: MediaSource::Url(url.into()),
+            media_type: media_type.into(),
+            filename: None,
+        }
+    }
+
+    /// Creates a file part from base64 encoded data.
+    pub fn base64(data: impl Into<String>, media_type: impl Into<String>) -> Self {
+        Self {
+            source: MediaSource::Base64(data.into()),
+            media_type: media_type.into(),
+            filename: None,
+        }
+    }
+
+    /// Creates a file part from the file's bytes.
+    pub fn bytes(data: impl AsRef<[u8]>, media_type: impl Into<String>) -> Self {
+        Self::base64(STANDARD.encode(data), media_type)
+    }
+
+    /// Reads a local file. The media type is guessed from the extension, and the file
+    /// name is kept.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidInput` if the file cannot be read.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let mut part = Self::bytes(read_file(path)?, media_type_of(path));
+        part.filename = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned());
+        Ok(part)
+    }
+
+    /// Sets the name of the file.
+    pub fn filename(mut self, filename: impl Into<String>) -> Self {
+        self.filename = Some(filename.into());
+        self
+    }
+}
+
+/// Audio in a user message, such as a voice note.
+///
+/// OpenAI receives it as `input_audio`, which takes base64 encoded `wav` or `mp3` data
+/// only. Gemini also accepts a URL.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AudioPart {
+    /// The audio data.
+    pub source: MediaSource,
+    /// The media type of the audio, e.g. `audio/wav` or `audio/mpeg`.
+    pub media_type: String,
+}
+
+impl AudioPart {
+    /// Creates an audio part from a URL.
+    pub fn url(url: impl Into<String>, media_type: impl Into<String>) -> Self {
+        Self {
+            source: MediaSource::Url(url.into()),
+            media_type: media_type.into(),
+        }
+    }
+
+    /// Creates an audio part from base64 encoded data.
+    pub fn base64(data: impl Into<String>, media_type: impl Into<String>) -> Self {
+        Self {
+            source: MediaSource::Base64(data.into()),
+            media_type: media_type.into(),
+        }
+    }
+
+    /// Creates an audio part from encoded audio bytes.
+    pub fn bytes(data: impl AsRef<[u8]>, media_type: impl Into<String>) -> Self {
+        Self::base64(STANDARD.encode(data), media_type)
+    }
+
+    /// Creates an audio part from a wav file's bytes.
+    pub fn wav(data: impl AsRef<[u8]>) -> Self {
+        Self::bytes(data, "audio/wav")
+    }
+
+    /// Creates an audio part from an mp3 file's bytes.
+    pub fn mp3(data: impl AsRef<[u8]>) -> Self {
+        Self::bytes(data, "audio/mpeg")
+    }
+
+    /// Reads audio from a local file. The media type is guessed from the extension.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidInput` if the file cannot be read.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        Ok(Self::bytes(read_file(path)?, media_type_of(path)))
+    }
+
+    /// Returns the audio format name used by OpenAI, `wav` or `mp3`.
+    pub fn format(&self) -> &str {
+        match self.media_type.as_str() {
+            "audio/mpeg" | "audio/mp3" => "mp3",
+            _ => "wav",
+        }
+    }
+}
+
+/// The largest document accepted by [`DocumentPart`], 32 MiB, which is Anthropic's
+/// request size limit. Gemini accepts up to 20 MiB of inline data per request.
+pub const MAX_DOCUMENT_SIZE: usize = 32 * 1024 * 1024;
+
+/// A document in a user message, such as a PDF.
+///
+/// Anthropic receives it as a `document` content block, Gemini as inline data or a file
+/// URI, and OpenAI as an input file.
+///
+/// # Example
+/// ```
+/// use aisdk::core::messages::{ContentPart, DocumentPart, UserMessage};
+///
+/// let pdf = bytes::Bytes::from_static(b"%PDF-1.7");
+/// let message = UserMessage::with_parts([
+///     DocumentPart::pdf(pdf).unwrap().title("Report").into(),
+///     ContentPart::text("Summarize the report."),
+/// ]);
+/// assert_eq!(message.parts.len(), 2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DocumentPart {
+    /// The document data.
+    pub source: MediaSource,
+    /// The media type of the document, e.g. `application/pdf` or `text/plain`.
+    pub media_type: String,
+    /// The title of the document, if known.
+    pub title: Option<String>,
+}
+
+impl DocumentPart {
+    /// Creates a document part from a URL.
+    pub fn url(url: impl Into<String>, media_type: impl Into<String>) -> Self {
+        Self {
+            source: MediaSource::Url(url.into()),
+            media_type: media_type.into(),
+            title: None,
+        }
+    }
+
+    /// Creates a document part from the document's bytes, base64 encoding them.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidInput` if the document is larger than
+    /// [`MAX_DOCUMENT_SIZE`].
+    pub fn bytes(data: impl Into<Bytes>, media_type: impl Into<String>) -> Result<Self> {
+        let data = data.into();
+        if data.len() > MAX_DOCUMENT_SIZE {
+            return Err(Error::InvalidInput(format!(
+                "Document of {} bytes exceeds the limit of {MAX_DOCUMENT_SIZE} bytes",
+                data.len()
+            )));
+        }
+        Ok(Self {
+            source: MediaSource::Base64(STANDARD.encode(&data)),
+            media_type: media_type.into(),
+            title: None,
+        })
+    }
+
+    /// Creates a PDF document part from the document's bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidInput` if the document is larger than
+    /// [`MAX_DOCUMENT_SIZE`].
+    pub fn pdf(data: impl Into<Bytes>) -> Result<Self> {
+        Self::bytes(data, "application/pdf")
+    }
+
+    /// Reads a local document. The media type is guessed from the extension, and the
+    /// file name becomes the title.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidInput` if the file cannot be read or is larger than
+    /// [`MAX_DOCUMENT_SIZE`].
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let mut part = Self::bytes(read_file(path)?, media_type_of(path))?;
+        part.title = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned());
+        Ok(part)
+    }
+
+    /// Sets the title of the document.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+}
+
+fn read_file(path: &Path) -> Result<Vec<u8>> {
+    std::fs::read(path)
+        .map_err(|e| Error::InvalidInput(format!("Failed to read {}: {e}", path.display())))
+}
+
+/// Guesses the media type of a file from its extension.
+fn media_type_of(path: &Path) -> &'static str {
+    let extension = path
+        .extension()
+        .map(|extension| extension.to_string_lossy().to_ascii_lowercase());
+    match extension.as_deref() {
+        Some("png") => "image/png",
+        Some("jpg" | "jpeg") => "image/jpeg",
+        Some("gif") => "image/gif",
+        Some("webp") => "image/webp",
+        Some("pdf") => "application/pdf",
+        Some("txt") => "text/plain",
+        Some("md") => "text/markdown",
+        Some("csv") => "text/csv",
+        Some("html" | "htm") => "text/html",
+        Some("json") => "application/json",
+        Some("mp3") => "audio/mpeg",
+        Some("wav") => "audio/wav",
+        _ => "application/octet-stream",
+    }
+}
+
 /// A message generated by the language model assistant.
-#[derive(Default, Debug, Clone)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
 pub struct AssistantMessage {
     /// The content of the assistant's response.
     pub content: LanguageModelResponseContentType,
@@ -241,6 +670,28 @@ impl MessageBuilder<Initial> {
             state: std::marker::PhantomData,
         }
     }
+
+    /// Adds a multi-part user message, e.g. text with images, and transitions to the
+    /// conversation state.
+    ///
+    /// # Parameters
+    ///
+    /// * `parts` - The ordered parts of the message.
+    ///
+    /// # Returns
+    ///
+    /// The builder in the conversation state.
+    pub fn user_with_parts(
+        mut self,
+        parts: impl IntoIterator<Item = ContentPart>,
+    ) -> MessageBuilder<Conversation> {
+        self.messages
+            .push(Message::User(UserMessage::with_parts(parts)));
+        MessageBuilder {
+            messages: self.messages,
+            state: std::marker::PhantomData,
+        }
+    }
 }
 
 impl MessageBuilder<Conversation> {
@@ -261,6 +712,27 @@ impl MessageBuilder<Conversation> {
         }
     }
 
+    /// Adds a multi-part user message, e.g. text with images, to the conversation.
+    ///
+    /// # Parameters
+    ///
+    /// * `parts` - The ordered parts of the message.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the message added.
+    pub fn user_with_parts(
+        mut self,
+        parts: impl IntoIterator<Item = ContentPart>,
+    ) -> MessageBuilder<Conversation> {
+        self.messages
+            .push(Message::User(UserMessage::with_parts(parts)));
+        MessageBuilder {
+            messages: self.messages,
+            state: std::marker::PhantomData,
+        }
+    }
+
     /// Adds an assistant message to the conversation.
     ///
     /// # Parameters
@@ -282,7 +754,7 @@ impl MessageBuilder<Conversation> {
 
 /// A message tagged with its step id in a list of messages
 /// used for tracking steps in a conversation
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub(crate) struct TaggedMessage {
     pub step_id: usize,
     pub message: Message,
@@ -319,6 +791,8 @@ impl From<TaggedMessage> for Message {
 pub(crate) trait TaggedMessageHelpers {
     fn extract_tool_calls(&self) -> Option<Vec<ToolCallInfo>>;
     fn extract_tool_results(&self) -> Option<Vec<ToolResultInfo>>;
+    fn extract_sources(&self) -> Option<Vec<Source>>;
+    fn extract_images(&self) -> Option<Vec<GeneratedImage>>;
 }
 
 impl TaggedMessageHelpers for [TaggedMessage] {
@@ -350,4 +824,239 @@ impl TaggedMessageHelpers for [TaggedMessage] {
             Some(results)
         }
     }
+
+    fn extract_sources(&self) -> Option<Vec<Source>> {
+        let sources: Vec<Source> = self
+            .iter()
+            .filter_map(|msg| match msg.message {
+                Message::Assistant(AssistantMessage {
+                    content: LanguageModelResponseContentType::Source(ref source),
+                    ..
+                }) => Some(source.clone()),
+                _ => None,
+            })
+            .collect();
+        if sources.is_empty() {
+            None
+        } else {
+            Some(sources)
+        }
+    }
+
+    fn extract_images(&self) -> Option<Vec<GeneratedImage>> {
+        let images: Vec<GeneratedImage> = self
+            .iter()
+            .filter_map(|msg| match msg.message {
+                Message::Assistant(AssistantMessage {
+                    content: LanguageModelResponseContentType::Image(ref image),
+                    ..
+                }) => Some(image.clone()),
+                _ => None,
+            })
+            .collect();
+        if images.is_empty() {
+            None
+        } else {
+            Some(images)
+        }
+    }
+}
+
+/// Builds a list of [`Message`]s.
+///
+/// Each element is a message, usually built with [`system!`], [`user!`], or
+/// [`assistant!`].
+///
+/// # Example
+/// ```
+/// use aisdk::{assistant, image_part, messages, system, user};
+///
+/// let name = "Ada";
+/// let messages = messages![
+///     system!("You are a helpful assistant."),
+///     user!("Hi, I am {name}."),
+///     assistant!("Hello {name}, how can I help?"),
+///     user!(["What is in this image?", image_part!("https://example.com/cat.png")]),
+/// ];
+/// assert_eq!(messages.len(), 4);
+/// ```
+#[macro_export]
+macro_rules! messages {
+    ($($message:expr),* $(,)?) => {
+        <$crate::core::messages::Messages>::from([$($message),*])
+    };
+}
+
+/// Builds a system [`Message`]. Takes the same arguments as [`format!`].
+#[macro_export]
+macro_rules! system {
+    ($($arg:tt)+) => {
+        $crate::core::messages::Message::System($crate::core::messages::SystemMessage::new(
+            ::std::format!($($arg)+),
+        ))
+    };
+}
+
+/// Builds a user [`Message`].
+///
+/// Takes the same arguments as [`format!`] for a text message, or a list of parts for
+/// a multi-part message. Each part is text or anything that converts into a
+/// [`ContentPart`], such as the result of [`image_part!`] or [`file_part!`].
+#[macro_export]
+macro_rules! user {
+    ([$($part:expr),* $(,)?]) => {
+        $crate::core::messages::Message::User($crate::core::messages::UserMessage::with_parts([
+            $(<$crate::core::messages::ContentPart>::from($part)),*
+        ]))
+    };
+    ($($arg:tt)+) => {
+        $crate::core::messages::Message::User($crate::core::messages::UserMessage::new(
+            ::std::format!($($arg)+),
+        ))
+    };
+}
+
+/// Builds an assistant [`Message`]. Takes the same arguments as [`format!`].
+#[macro_export]
+macro_rules! assistant {
+    ($($arg:tt)+) => {
+        $crate::core::messages::Message::Assistant(
+            $crate::core::messages::AssistantMessage::from(::std::format!($($arg)+)),
+        )
+    };
+}
+
+/// Builds an image [`ContentPart`] from a URL, or from bytes and a media type.
+#[macro_export]
+macro_rules! image_part {
+    ($url:expr $(,)?) => {
+        $crate::core::messages::ContentPart::Image($crate::core::messages::ImagePart::url($url))
+    };
+    ($data:expr, $media_type:expr $(,)?) => {
+        $crate::core::messages::ContentPart::Image($crate::core::messages::ImagePart::bytes(
+            $data,
+            $media_type,
+        ))
+    };
+}
+
+/// Builds a file [`ContentPart`] from a URL and a media type, optionally with a
+/// filename.
+#[macro_export]
+macro_rules! file_part {
+    ($url:expr, $media_type:expr $(,)?) => {
+        $crate::core::messages::ContentPart::File($crate::core::messages::FilePart::url(
+            $url,
+            $media_type,
+        ))
+    };
+    ($url:expr, $media_type:expr, filename = $filename:expr $(,)?) => {
+        $crate::core::messages::ContentPart::File(
+            $crate::core::messages::FilePart::url($url, $media_type).filename($filename),
+        )
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extensions::{Extension, Extensions};
+
+    #[derive(Debug, Default, Serialize, Deserialize)]
+    struct Signature(String);
+
+    impl Extension for Signature {
+        const NAME: &'static str = "test.signature";
+    }
+
+    #[test]
+    fn test_conversation_round_trip() {
+        let extensions = Extensions::default();
+        extensions.insert(Signature("sig".to_string()));
+        let mut call = ToolCallInfo::new("weather");
+        call.id("call_1");
+        call.input(serde_json::json!({"city": "Paris"}));
+        call.extensions.insert(Signature("call-sig".to_string()));
+
+        let messages: Messages = vec![
+            Message::User(UserMessage::with_parts([
+                ContentPart::text("Weather?"),
+                ImagePart::url("https://example.com/sky.png").into(),
+            ])),
+            Message::Assistant(AssistantMessage::new(
+                LanguageModelResponseContentType::Reasoning {
+                    content: "Look it up".to_string(),
+                    extensions,
+                },
+                None,
+            )),
+            Message::Assistant(AssistantMessage::new(
+                LanguageModelResponseContentType::ToolCall(call),
+                None,
+            )),
+        ];
+
+        let json = serde_json::to_string(&messages).unwrap();
+        let restored: Messages = serde_json::from_str(&json).unwrap();
+
+        let Message::User(user) = &restored[0] else {
+            panic!("expected a user message");
+        };
+        assert_eq!(user.parts.len(), 2);
+        let Message::Assistant(AssistantMessage {
+            content: LanguageModelResponseContentType::Reasoning { extensions, .. },
+            ..
+        }) = &restored[1]
+        else {
+            panic!("expected reasoning");
+        };
+        assert_eq!(extensions.get::<Signature>().0, "sig");
+        let Message::Assistant(AssistantMessage {
+            content: LanguageModelResponseContentType::ToolCall(call),
+            ..
+        }) = &restored[2]
+        else {
+            panic!("expected a tool call");
+        };
+        assert_eq!(call.input["city"], "Paris");
+        assert_eq!(call.extensions.get::<Signature>().0, "call-sig");
+    }
+
+    #[test]
+    fn test_message_macros() {
+        let topic = "cats";
+        let messages = crate::messages![
+            crate::system!("You know about {topic}."),
+            crate::user!([
+                "Compare these",
+                crate::image_part!("https://example.com/cat.png"),
+                crate::file_part!(
+                    "https://example.com/cats.pdf",
+                    "application/pdf",
+                    filename = "cats.pdf"
+                ),
+            ]),
+            crate::assistant!("They are both {}.", topic),
+        ];
+
+        assert_eq!(messages.len(), 3);
+        let Message::System(system) = &messages[0] else {
+            panic!("expected a system message");
+        };
+        assert_eq!(system.content, "You know about cats.");
+        let Message::User(user) = &messages[1] else {
+            panic!("expected a user message");
+        };
+        assert_eq!(user.content, "Compare these");
+        assert!(
+            matches!(&user.parts[2], ContentPart::File(file) if file.filename.as_deref() == Some("cats.pdf"))
+        );
+        let Message::Assistant(assistant) = &messages[2] else {
+            panic!("expected an assistant message");
+        };
+        assert!(matches!(
+            &assistant.content,
+            LanguageModelResponseContentType::Text(text) if text == "They are both cats."
+        ));
+    }
 }
@@ -0,0 +1,300 @@
+//! Metrics for model calls.
+//!
+//! With the `metrics` feature, `generate_text`, `stream_text` and `embed` record their
+//! calls through the [`metrics`](https://docs.rs/metrics) facade, so any recorder
+//! installed by the application, e.g. a Prometheus or StatsD exporter, collects them.
+//! Nothing is recorded until a recorder is installed.
+//!
+//! Each metric has the labels `provider`, e.g. `openai`, `model`, empty for embedding
+//! calls, and `operation`, `chat` or `embeddings`:
+//!
+//! - `aisdk_requests_total`: the number of calls
+//! - `aisdk_request_errors_total`: the number of failed calls, with a `status` label
+//!   holding the HTTP status code of errors from a provider response, e.g. `429` for
+//!   [`Error::RateLimited`], or the name of the error otherwise, e.g. `Timeout`
+//! - `aisdk_request_duration_seconds`: a histogram of the duration of calls, up to the
+//!   end of the stream for `stream_text`
+//! - `aisdk_time_to_first_token_seconds`: a histogram of the time until the first delta
+//!   of a stream
+//! - `aisdk_tokens_total`: the number of tokens used, with a `type` label, `input` or
+//!   `output`
+//!
+//! Call [`describe_metrics`] once after installing the recorder to register the units
+//! and descriptions of the metrics.
+
+use crate::core::language_model::{LanguageModel, LanguageModelOptions, StopReason, Usage};
+use crate::core::runtime::Instant;
+use crate::core::utils::{error_type, provider_name};
+use crate::error::{Error, Result};
+use metrics::{Unit, counter, describe_counter, describe_histogram, histogram};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// The number of calls.
+pub const REQUESTS_TOTAL: &str = "aisdk_requests_total";
+/// The number of failed calls.
+pub const REQUEST_ERRORS_TOTAL: &str = "aisdk_request_errors_total";
+/// The duration of calls, in seconds.
+pub const REQUEST_DURATION_SECONDS: &str = "aisdk_request_duration_seconds";
+/// The time until the first delta of a stream, in seconds.
+pub const TIME_TO_FIRST_TOKEN_SECONDS: &str = "aisdk_time_to_first_token_seconds";
+/// The number of tokens used.
+pub const TOKENS_TOTAL: &str = "aisdk_tokens_total";
+
+/// Registers the units and descriptions of the metrics with the installed recorder.
+pub fn describe_metrics() {
+    describe_counter!(REQUESTS_TOTAL, Unit::Count, "The number of model calls");
+    describe_counter!(
+        REQUEST_ERRORS_TOTAL,
+        Unit::Count,
+        "The number of failed model calls"
+    );
+    describe_histogram!(
+        REQUEST_DURATION_SECONDS,
+        Unit::Seconds,
+        "The duration of model calls"
+    );
+    describe_histogram!(
+        TIME_TO_FIRST_TOKEN_SECONDS,
+        Unit::Seconds,
+        "The time until the first delta of a stream"
+    );
+    describe_counter!(TOKENS_TOTAL, Unit::Count, "The number of tokens used");
+}
+
+/// The metrics of a model call.
+pub(crate) struct RequestMetrics {
+    provider: String,
+    model: String,
+    operation: &'static str,
+    started: Instant,
+    first_token: AtomicBool,
+}
+
+impl RequestMetrics {
+    /// Starts recording a `chat` call to `model`.
+    pub(crate) fn chat<M: LanguageModel>(model: &M) -> Self {
+        Self::start(provider_name::<M>(), model.name(), "chat")
+    }
+
+    /// Starts recording an `embeddings` call to a model of type `M`.
+    #[cfg(feature = "embedding-model-request")]
+    pub(crate) fn embeddings<M>() -> Self {
+        Self::start(provider_name::<M>(), String::new(), "embeddings")
+    }
+
+    fn start(provider: String, model: String, operation: &'static str) -> Self {
+        let metrics = Self {
+            provider,
+            model,
+            operation,
+            started: Instant::now(),
+            first_token: AtomicBool::new(false),
+        };
+        counter!(REQUESTS_TOTAL, &metrics.labels()).increment(1);
+        metrics
+    }
+
+    fn labels(&self) -> [(&'static str, String); 3] {
+        [
+            ("provider", self.provider.clone()),
+            ("model", self.model.clone()),
+            ("operation", self.operation.to_string()),
+        ]
+    }
+
+    /// Records the outcome of a call from its `result`, with the `usage` of its output.
+    pub(crate) fn record<T>(&self, result: &Result<T>, usage: impl FnOnce(&T) -> Option<Usage>) {
+        match result {
+            Ok(output) => {
+                if let Some(usage) = usage(output) {
+                    self.tokens(&usage);
+                }
+            }
+            Err(error) => self.fail(error),
+        }
+        self.end();
+    }
+
+    /// Records the time to the first token, unless it was already recorded.
+    pub(crate) fn first_token(&self) {
+        if !self.first_token.swap(true, Ordering::Relaxed) {
+            histogram!(TIME_TO_FIRST_TOKEN_SECONDS, &self.labels())
+                .record(self.started.elapsed().as_secs_f64());
+        }
+    }
+
+    /// Records the outcome of a `chat` call from its final `options` and total `usage`.
+    pub(crate) fn finish(&self, options: &LanguageModelOptions, usage: &Usage) {
+        self.tokens(usage);
+        if let Some(StopReason::Error(error)) = &options.stop_reason {
+            self.fail(error);
+        }
+    }
+
+    fn tokens(&self, usage: &Usage) {
+        let kinds = [
+            ("input", usage.input_tokens),
+            ("output", usage.output_tokens),
+        ];
+        for (kind, tokens) in kinds {
+            if let Some(tokens) = tokens {
+                let mut labels = self.labels().to_vec();
+                labels.push(("type", kind.to_string()));
+                counter!(TOKENS_TOTAL, &labels).increment(tokens as u64);
+            }
+        }
+    }
+
+    /// Records `error` as the reason the call failed.
+    pub(crate) fn fail(&self, error: &Error) {
+        let status = match error.status_code() {
+            Some(status_code) => status_code.as_u16().to_string(),
+            None => error_type(error),
+        };
+        let mut labels = self.labels().to_vec();
+        labels.push(("status", status));
+        counter!(REQUEST_ERRORS_TOTAL, &labels).increment(1);
+    }
+
+    /// Records the duration of the call.
+    pub(crate) fn end(&self) {
+        histogram!(REQUEST_DURATION_SECONDS, &self.labels())
+            .record(self.started.elapsed().as_secs_f64());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::LanguageModelRequest;
+    use crate::core::test_model::ScriptedModel;
+    use futures::StreamExt;
+    use metrics_util::debugging::{DebugValue, DebuggingRecorder};
+    use std::future::Future;
+
+    /// A recorded metric: its name, labels and value.
+    type Metric = (String, Vec<(String, String)>, DebugValue);
+
+    /// Runs `call` with a recorder of its own, returning the recorded metrics.
+    fn record(call: impl Future<Output = ()>) -> Vec<Metric> {
+        let recorder = DebuggingRecorder::new();
+        let snapshotter = recorder.snapshotter();
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        metrics::with_local_recorder(&recorder, || runtime.block_on(call));
+        snapshotter
+            .snapshot()
+            .into_vec()
+            .into_iter()
+            .map(|(key, _, _, value)| {
+                let labels = key
+                    .key()
+                    .labels()
+                    .map(|label| (label.key().to_string(), label.value().to_string()))
+                    .collect();
+                (key.key().name().to_string(), labels, value)
+            })
+            .collect()
+    }
+
+    /// Returns the values of the metric `name`, with the value of its label `key`.
+    fn values<'a>(metrics: &'a [Metric], name: &str, key: &str) -> Vec<(String, &'a DebugValue)> {
+        let mut values: Vec<_> = metrics
+            .iter()
+            .filter(|metric| metric.0 == name)
+            .map(|(_, labels, value)| {
+                let label = labels
+                    .iter()
+                    .find(|label| label.0 == key)
+                    .map(|label| label.1.clone())
+                    .unwrap_or_default();
+                (label, value)
+            })
+            .collect();
+        values.sort_by(|a, b| a.0.cmp(&b.0));
+        values
+    }
+
+    #[test]
+    fn test_generate_text_metrics() {
+        let metrics = record(async {
+            LanguageModelRequest::builder()
+                .model(ScriptedModel::new("generating"))
+                .prompt("hi")
+                .build()
+                .generate_text()
+                .await
+                .unwrap();
+        });
+
+        assert_eq!(
+            values(&metrics, REQUESTS_TOTAL, "provider"),
+            vec![("scriptedmodel".to_string(), &DebugValue::Counter(1))]
+        );
+        assert_eq!(
+            values(&metrics, REQUESTS_TOTAL, "model"),
+            vec![("generating".to_string(), &DebugValue::Counter(1))]
+        );
+        assert_eq!(
+            values(&metrics, TOKENS_TOTAL, "type"),
+            vec![
+                ("input".to_string(), &DebugValue::Counter(5)),
+                ("output".to_string(), &DebugValue::Counter(1)),
+            ]
+        );
+        assert_eq!(values(&metrics, REQUEST_DURATION_SECONDS, "model").len(), 1);
+        assert!(values(&metrics, REQUEST_ERRORS_TOTAL, "status").is_empty());
+    }
+
+    #[test]
+    fn test_failed_call_metrics() {
+        let metrics = record(async {
+            let result = LanguageModelRequest::builder()
+                .model(ScriptedModel::new("failing").failing(Error::RateLimited {
+                    retry_after: None,
+                    details: "rate limited".to_string(),
+                    status_code: Some(reqwest::StatusCode::TOO_MANY_REQUESTS),
+                    rate_limit: None,
+                }))
+                .prompt("hi")
+                .build()
+                .generate_text()
+                .await;
+            assert!(result.is_err());
+        });
+
+        assert_eq!(
+            values(&metrics, REQUEST_ERRORS_TOTAL, "status"),
+            vec![("429".to_string(), &DebugValue::Counter(1))]
+        );
+        assert!(values(&metrics, TOKENS_TOTAL, "type").is_empty());
+    }
+
+    #[test]
+    fn test_stream_text_metrics() {
+        let metrics = record(async {
+            let mut response = LanguageModelRequest::builder()
+                .model(ScriptedModel::new("streaming"))
+                .prompt("hi")
+                .build()
+                .stream_text()
+                .await
+                .unwrap();
+            while response.stream.next().await.is_some() {}
+        });
+
+        assert_eq!(
+            values(&metrics, TIME_TO_FIRST_TOKEN_SECONDS, "model").len(),
+            1
+        );
+        assert_eq!(values(&metrics, REQUEST_DURATION_SECONDS, "model").len(), 1);
+        assert_eq!(
+            values(&metrics, TOKENS_TOTAL, "type"),
+            vec![
+                ("input".to_string(), &DebugValue::Counter(5)),
+                ("output".to_string(), &DebugValue::Counter(1)),
+            ]
+        );
+    }
+}
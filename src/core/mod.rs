@@ -7,28 +7,67 @@
 //! Key types like `GenerateTextCallOptions` and `GenerateTextResponse` are also
 //! re-exported for convenient access.
 
+pub mod budget;
+pub mod cancellation;
 pub mod capabilities;
 pub mod client;
+pub mod context_window;
+pub mod cost;
+pub mod effect;
 pub mod embedding_model;
+pub mod guards;
+pub mod image_model;
 pub mod language_model;
 pub mod messages;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+pub mod moderation_model;
+#[cfg(feature = "language-model-request")]
+pub mod observability;
+pub mod prompt;
 pub mod provider;
+pub mod rate_limit;
+pub mod rerank_model;
+pub mod runtime;
+pub mod speech_model;
+#[cfg(feature = "otel")]
+pub mod telemetry;
+#[cfg(all(test, feature = "language-model-request"))]
+pub(crate) mod test_model;
+pub mod tokens;
 pub mod tools;
+pub mod transcription_model;
 pub mod utils;
+pub mod video_model;
 
 // Re-export key components to provide a clean public API.
 pub use capabilities::DynamicModel;
-pub use language_model::{LanguageModel, LanguageModelStreamChunkType};
+pub use language_model::{LanguageModel, LanguageModelStreamChunkType, StreamEvent};
 #[cfg(feature = "language-model-request")]
 pub use language_model::{
-    generate_text::GenerateTextResponse, request::LanguageModelRequest,
-    stream_text::StreamTextResponse,
+    generate_object::GenerateObjectResponse, generate_text::GenerateTextResponse,
+    request::LanguageModelRequest, stream_array::StreamArrayResponse,
+    stream_object::StreamObjectResponse, stream_text::StreamTextResponse,
 };
 
 pub use embedding_model::EmbeddingModel;
 #[cfg(feature = "embedding-model-request")]
 pub use embedding_model::EmbeddingModelRequest;
 
+pub use rerank_model::RerankModel;
+#[cfg(feature = "rerank-model-request")]
+pub use rerank_model::RerankModelRequest;
+
+pub use image_model::ImageGenerationModel;
+
+pub use moderation_model::ModerationModel;
+
+pub use speech_model::SpeechModel;
+
+pub use transcription_model::TranscriptionModel;
+
+pub use video_model::VideoModel;
+
 pub use messages::{AssistantMessage, Message, Messages, Role, SystemMessage, UserMessage};
 pub use provider::Provider;
-pub use tools::{Tool, ToolCallInfo, ToolResultInfo};
+pub use tools::{Tool, ToolCallInfo, ToolResultInfo, TypedTool};
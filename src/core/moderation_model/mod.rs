@@ -0,0 +1,178 @@
+//! Moderation model
+//!
+//! A moderation model classifies text as potentially harmful, e.g. harassment, hate, or
+//! violence, with a score per category. [`ModerationGuard`] runs a moderation model as
+//! an input guard on a language model request, and checks generated text afterwards.
+
+use crate::core::guards::InputGuard;
+use crate::error::{Error, Result};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use derive_builder::Builder;
+
+/// The options for moderation requests.
+#[derive(Debug, Clone, Builder)]
+pub struct ModerationModelOptions {
+    /// The texts to classify.
+    pub input: Vec<String>,
+}
+
+impl ModerationModelOptions {
+    /// Returns the moderation options builder.
+    pub fn builder() -> ModerationModelOptionsBuilder {
+        ModerationModelOptionsBuilder::default()
+    }
+}
+
+/// The core trait abstracting the capabilities of a moderation model.
+#[async_trait]
+pub trait ModerationModel: Clone + Send + Sync + std::fmt::Debug + 'static {
+    /// Classifies each input text.
+    async fn moderate(&self, input: ModerationModelOptions) -> Result<ModerationModelResponse>;
+}
+
+/// The classification of a single text.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ModerationResult {
+    /// Whether the provider considers the text harmful in any category.
+    pub flagged: bool,
+    /// Whether the text is flagged in each category, keyed by the provider's category
+    /// name, e.g. `harassment` or `violence/graphic`.
+    pub categories: BTreeMap<String, bool>,
+    /// The score of each category, from 0 to 1. Higher is more likely harmful.
+    pub category_scores: BTreeMap<String, f32>,
+}
+
+impl ModerationResult {
+    /// Returns the names of the categories the text is flagged in.
+    pub fn flagged_categories(&self) -> Vec<&str> {
+        self.categories
+            .iter()
+            .filter(|(_, flagged)| **flagged)
+            .map(|(category, _)| category.as_str())
+            .collect()
+    }
+}
+
+/// The response type for moderation requests, with one result per input text in
+/// request order.
+pub type ModerationModelResponse = Vec<ModerationResult>;
+
+/// Rejects text a [`ModerationModel`] flags as harmful.
+///
+/// Register it with
+/// [`with_input_guard`](crate::core::language_model::request::LanguageModelRequestBuilder::with_input_guard)
+/// to moderate every message before it is sent, and call [`ModerationGuard::check`] on
+/// the generated text to moderate the output. Flagged text is rejected with
+/// [`Error::GuardError`].
+#[derive(Debug, Clone)]
+pub struct ModerationGuard<M: ModerationModel> {
+    model: M,
+    threshold: Option<f32>,
+}
+
+impl<M: ModerationModel> ModerationGuard<M> {
+    /// Creates a guard that rejects text the model flags.
+    pub fn new(model: M) -> Self {
+        Self {
+            model,
+            threshold: None,
+        }
+    }
+
+    /// Also rejects text with any category score at or above `threshold`, even if the
+    /// provider does not flag it.
+    pub fn threshold(mut self, threshold: f32) -> Self {
+        self.threshold = Some(threshold);
+        self
+    }
+
+    /// Moderates `text`, returning it unchanged if it passes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::GuardError`] if the text is rejected, or the model's error if
+    /// the moderation request fails.
+    pub async fn check(&self, text: &str) -> Result<String> {
+        let options = ModerationModelOptions {
+            input: vec![text.to_string()],
+        };
+        let results = self.model.moderate(options).await?;
+
+        for result in &results {
+            let mut categories: Vec<&str> = result.flagged_categories();
+            if let Some(threshold) = self.threshold {
+                for (category, score) in &result.category_scores {
+                    if *score >= threshold && !categories.contains(&category.as_str()) {
+                        categories.push(category);
+                    }
+                }
+            }
+            if result.flagged || !categories.is_empty() {
+                return Err(Error::GuardError(format!(
+                    "text flagged by moderation: {}",
+                    categories.join(", ")
+                )));
+            }
+        }
+        Ok(text.to_string())
+    }
+}
+
+#[async_trait]
+impl<M: ModerationModel> InputGuard for ModerationGuard<M> {
+    async fn guard_input(&self, text: &str) -> Result<String> {
+        self.check(text).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Flags text containing "attack", and scores "threat" at 0.6.
+    #[derive(Debug, Clone)]
+    struct KeywordModel;
+
+    #[async_trait]
+    impl ModerationModel for KeywordModel {
+        async fn moderate(&self, input: ModerationModelOptions) -> Result<ModerationModelResponse> {
+            Ok(input
+                .input
+                .iter()
+                .map(|text| {
+                    let flagged = text.contains("attack");
+                    let score = if text.contains("threat") { 0.6 } else { 0.0 };
+                    ModerationResult {
+                        flagged,
+                        categories: BTreeMap::from([("violence".to_string(), flagged)]),
+                        category_scores: BTreeMap::from([("violence".to_string(), score)]),
+                    }
+                })
+                .collect())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_guard_rejects_flagged_input() {
+        let guard = ModerationGuard::new(KeywordModel);
+
+        assert_eq!(guard.guard_input("hello").await.unwrap(), "hello");
+        let err = guard.guard_input("attack now").await.unwrap_err();
+        assert!(matches!(err, Error::GuardError(message) if message.ends_with("violence")));
+    }
+
+    #[tokio::test]
+    async fn test_guard_threshold() {
+        let guard = ModerationGuard::new(KeywordModel);
+        assert!(guard.check("a threat").await.is_ok());
+
+        let guard = guard.threshold(0.5);
+        assert!(matches!(
+            guard.check("a threat").await,
+            Err(Error::GuardError(_))
+        ));
+    }
+}
@@ -0,0 +1,378 @@
+//! Exporting generations to LLM observability platforms.
+//!
+//! Attach [`TelemetryOptions`] to a request with
+//! [`telemetry`](crate::core::language_model::request::LanguageModelRequestBuilder::telemetry)
+//! and each `generate_text` or `stream_text` call is recorded as a [`Generation`], with
+//! its prompt, completion, model, parameters, usage, latency and tool calls, and
+//! handed to the [`GenerationExporter`] of the options once it completes. Exporting
+//! runs in the background, so it never delays or fails the call itself.
+//!
+//! Exporters for hosted platforms live in [`integrations`](crate::integrations), e.g.
+//! `integrations::langfuse::Langfuse` with the `langfuse` feature.
+//!
+//! # Example
+//!
+//! ```
+//! use aisdk::core::observability::{Generation, GenerationExporter, TelemetryOptions};
+//! use aisdk::error::Result;
+//!
+//! struct PrintExporter;
+//!
+//! #[async_trait::async_trait]
+//! impl GenerationExporter for PrintExporter {
+//!     async fn export(&self, generation: Generation) -> Result<()> {
+//!         println!("{} took {:?}", generation.model, generation.latency());
+//!         Ok(())
+//!     }
+//! }
+//!
+//! let telemetry = TelemetryOptions::new(PrintExporter)
+//!     .function_id("summarize")
+//!     .metadata("tenant", "acme")
+//!     .record_inputs(false);
+//! ```
+
+use crate::core::Messages;
+use crate::core::language_model::{
+    FinishReason, LanguageModel, LanguageModelOptions, StopReason, Usage,
+};
+use crate::core::runtime::SystemTime;
+use crate::core::tools::ToolCallInfo;
+use crate::core::utils::resolve_message;
+use crate::error::{Error, Result};
+use async_trait::async_trait;
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+// ============================================================================
+// Section: types
+// ============================================================================
+
+/// A destination for recorded generations, e.g. the ingestion API of an
+/// observability platform.
+#[async_trait]
+pub trait GenerationExporter: Send + Sync {
+    /// Exports one generation. Errors are logged and otherwise ignored.
+    async fn export(&self, generation: Generation) -> Result<()>;
+}
+
+/// A completed `generate_text` or `stream_text` call.
+#[derive(Debug, Clone)]
+pub struct Generation {
+    /// A unique id of the generation.
+    pub id: String,
+    /// The function id of the [`TelemetryOptions`], naming what the call is for.
+    pub name: Option<String>,
+    /// The name of the model.
+    pub model: String,
+    /// The sampling parameters that were set, e.g. `temperature`.
+    pub parameters: serde_json::Map<String, serde_json::Value>,
+    /// The messages sent, unless inputs are not recorded.
+    pub input: Option<Messages>,
+    /// The text of the last response, unless outputs are not recorded.
+    pub output: Option<String>,
+    /// The tool calls made, unless outputs are not recorded.
+    pub tool_calls: Vec<ToolCallInfo>,
+    /// The token usage, summed over all steps.
+    pub usage: Usage,
+    /// Why the model stopped generating the last response.
+    pub finish_reason: Option<FinishReason>,
+    /// The error that stopped generation, if it failed.
+    pub error: Option<Error>,
+    /// When the call started.
+    pub start_time: SystemTime,
+    /// When the first delta of a stream arrived.
+    pub completion_start_time: Option<SystemTime>,
+    /// When the call completed.
+    pub end_time: SystemTime,
+    /// The metadata of the [`TelemetryOptions`].
+    pub metadata: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Generation {
+    /// Returns the time the call took.
+    pub fn latency(&self) -> Duration {
+        self.end_time
+            .duration_since(self.start_time)
+            .unwrap_or_default()
+    }
+}
+
+/// Whether and how the calls of a request are recorded.
+#[derive(Clone)]
+pub struct TelemetryOptions {
+    /// Whether calls are recorded.
+    pub enabled: bool,
+    /// A name for what the call is for, e.g. `summarize-ticket`.
+    pub function_id: Option<String>,
+    /// Metadata attached to every generation, e.g. a user or session id.
+    pub metadata: serde_json::Map<String, serde_json::Value>,
+    /// Whether the messages sent are recorded.
+    pub record_inputs: bool,
+    /// Whether the text and tool calls generated are recorded.
+    pub record_outputs: bool,
+    exporter: Arc<dyn GenerationExporter>,
+}
+
+impl Debug for TelemetryOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TelemetryOptions")
+            .field("enabled", &self.enabled)
+            .field("function_id", &self.function_id)
+            .field("metadata", &self.metadata)
+            .field("record_inputs", &self.record_inputs)
+            .field("record_outputs", &self.record_outputs)
+            .finish()
+    }
+}
+
+impl TelemetryOptions {
+    /// Creates options recording inputs and outputs to `exporter`.
+    pub fn new(exporter: impl GenerationExporter + 'static) -> Self {
+        Self {
+            enabled: true,
+            function_id: None,
+            metadata: serde_json::Map::new(),
+            record_inputs: true,
+            record_outputs: true,
+            exporter: Arc::new(exporter),
+        }
+    }
+
+    /// Sets whether calls are recorded.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Sets the name of what the call is for.
+    pub fn function_id(mut self, function_id: impl Into<String>) -> Self {
+        self.function_id = Some(function_id.into());
+        self
+    }
+
+    /// Adds a metadata entry.
+    pub fn metadata(mut self, key: impl Into<String>, value: impl Into<serde_json::Value>) -> Self {
+        self.metadata.insert(key.into(), value.into());
+        self
+    }
+
+    /// Sets whether the messages sent are recorded, e.g. to keep sensitive prompts out
+    /// of the platform.
+    pub fn record_inputs(mut self, record_inputs: bool) -> Self {
+        self.record_inputs = record_inputs;
+        self
+    }
+
+    /// Sets whether the text and tool calls generated are recorded.
+    pub fn record_outputs(mut self, record_outputs: bool) -> Self {
+        self.record_outputs = record_outputs;
+        self
+    }
+}
+
+// ============================================================================
+// Section: recording
+// ============================================================================
+
+/// Records one call of a request, and exports it once it completes.
+pub(crate) struct GenerationRecorder {
+    options: TelemetryOptions,
+    model: String,
+    parameters: serde_json::Map<String, serde_json::Value>,
+    input: Messages,
+    start_time: SystemTime,
+    completion_start_time: Mutex<Option<SystemTime>>,
+}
+
+impl GenerationRecorder {
+    /// Starts recording a call to `model` with the `options` and `prompt` of a request,
+    /// if it has enabled telemetry options.
+    pub(crate) fn start<M: LanguageModel>(
+        model: &M,
+        options: &LanguageModelOptions,
+        prompt: &Option<String>,
+    ) -> Option<Self> {
+        let telemetry = options.telemetry.clone().filter(|t| t.enabled)?;
+        let (_, messages) = resolve_message(options, prompt);
+
+        let mut parameters = serde_json::Map::new();
+        let values = [
+            (
+                "temperature",
+                options.temperature.map(|t| f64::from(t) / 100.0),
+            ),
+            ("top_p", options.top_p.map(|p| f64::from(p) / 100.0)),
+            ("top_k", options.top_k.map(f64::from)),
+            ("max_tokens", options.max_output_tokens.map(f64::from)),
+            ("seed", options.seed.map(f64::from)),
+        ];
+        for (key, value) in values {
+            if let Some(value) = value {
+                parameters.insert(key.to_string(), value.into());
+            }
+        }
+
+        Some(Self {
+            options: telemetry,
+            model: model.name(),
+            parameters,
+            input: messages.into_iter().map(|m| m.message).collect(),
+            start_time: SystemTime::now(),
+            completion_start_time: Mutex::new(None),
+        })
+    }
+
+    /// Records the arrival of the first delta of a stream, unless it was already
+    /// recorded.
+    pub(crate) fn first_token(&self) {
+        let mut completion_start_time = self.completion_start_time.lock().unwrap();
+        if completion_start_time.is_none() {
+            *completion_start_time = Some(SystemTime::now());
+        }
+    }
+
+    /// Exports the call from its final `options` and total `usage`.
+    pub(crate) fn finish(&self, options: &LanguageModelOptions, usage: &Usage) {
+        let error = match &options.stop_reason {
+            Some(StopReason::Error(error)) => Some(error.clone()),
+            _ => None,
+        };
+        let (output, tool_calls) = if self.options.record_outputs {
+            (options.text(), options.tool_calls().unwrap_or_default())
+        } else {
+            (None, Vec::new())
+        };
+        self.export(
+            output,
+            tool_calls,
+            usage.clone(),
+            options.finish_reason.clone(),
+            error,
+        );
+    }
+
+    /// Exports the call as failed with `error`.
+    pub(crate) fn fail(&self, error: &Error) {
+        self.export(
+            None,
+            Vec::new(),
+            Usage::default(),
+            None,
+            Some(error.clone()),
+        );
+    }
+
+    fn export(
+        &self,
+        output: Option<String>,
+        tool_calls: Vec<ToolCallInfo>,
+        usage: Usage,
+        finish_reason: Option<FinishReason>,
+        error: Option<Error>,
+    ) {
+        let generation = Generation {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: self.options.function_id.clone(),
+            model: self.model.clone(),
+            parameters: self.parameters.clone(),
+            input: self.options.record_inputs.then(|| self.input.clone()),
+            output,
+            tool_calls,
+            usage,
+            finish_reason,
+            error,
+            start_time: self.start_time,
+            completion_start_time: *self.completion_start_time.lock().unwrap(),
+            end_time: SystemTime::now(),
+            metadata: self.options.metadata.clone(),
+        };
+        let exporter = self.options.exporter.clone();
+        crate::core::runtime::spawn(async move {
+            if let Err(error) = exporter.export(generation).await {
+                log::warn!("failed to export generation: {error}");
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::LanguageModelRequest;
+    use crate::core::test_model::ScriptedModel;
+    use tokio::sync::mpsc::{self, UnboundedSender};
+
+    /// An exporter sending generations to a channel.
+    struct ChannelExporter(UnboundedSender<Generation>);
+
+    #[async_trait]
+    impl GenerationExporter for ChannelExporter {
+        async fn export(&self, generation: Generation) -> Result<()> {
+            self.0.send(generation).unwrap();
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_generate_text_is_exported() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+
+        LanguageModelRequest::builder()
+            .model(ScriptedModel::new("hello"))
+            .prompt("hi")
+            .temperature(50u32)
+            .telemetry(
+                TelemetryOptions::new(ChannelExporter(tx))
+                    .function_id("greet")
+                    .metadata("user", "ada"),
+            )
+            .build()
+            .generate_text()
+            .await
+            .unwrap();
+
+        let generation = rx.recv().await.unwrap();
+        assert_eq!(generation.name.as_deref(), Some("greet"));
+        assert_eq!(generation.model, "hello");
+        assert_eq!(generation.output.as_deref(), Some("Hello"));
+        assert_eq!(generation.input.unwrap().len(), 1);
+        assert_eq!(generation.usage.input_tokens, Some(5));
+        assert_eq!(generation.parameters["temperature"], 0.5);
+        assert_eq!(generation.metadata["user"], "ada");
+        assert!(generation.error.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_disabled_telemetry_and_inputs() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+
+        LanguageModelRequest::builder()
+            .model(ScriptedModel::new("hello"))
+            .prompt("hi")
+            .telemetry(TelemetryOptions::new(ChannelExporter(tx.clone())).enabled(false))
+            .build()
+            .generate_text()
+            .await
+            .unwrap();
+        LanguageModelRequest::builder()
+            .model(ScriptedModel::new("hello"))
+            .prompt("hi")
+            .telemetry(
+                TelemetryOptions::new(ChannelExporter(tx))
+                    .record_inputs(false)
+                    .record_outputs(false),
+            )
+            .build()
+            .generate_text()
+            .await
+            .unwrap();
+
+        // Only the second request is exported, without its prompt or completion
+        let generation = rx.recv().await.unwrap();
+        assert!(generation.input.is_none());
+        assert!(generation.output.is_none());
+        assert!(rx.recv().await.is_none());
+    }
+}
@@ -0,0 +1,371 @@
+//! Prompt templates.
+//!
+//! A minimal, dependency-free template language for prompts, rendered into system and
+//! user messages. For full Tera templates, see the `prompt` feature.
+//!
+//! Templates interpolate variables with `{{name}}`, reach into nested values with
+//! `{{user.name}}`, and include other templates of a [`PromptRegistry`] with
+//! `{{> partial}}`. A literal `{{` is written `\{{`. Variables come from any
+//! [`Serialize`] value, usually a struct or a `serde_json::json!` object, and a
+//! variable missing from it is an error rather than an empty string.
+//!
+//! # Example
+//!
+//! ```
+//! use aisdk::core::prompt::PromptRegistry;
+//! use serde::Serialize;
+//!
+//! #[derive(Serialize)]
+//! struct Review {
+//!     language: &'static str,
+//!     diff: &'static str,
+//! }
+//!
+//! let mut prompts = PromptRegistry::new();
+//! prompts.register("tone", "Be concise and kind.")?;
+//! prompts.register("reviewer", "You review {{language}} code. {{> tone}}")?;
+//! prompts.register("review", "Review this diff:\n{{diff}}")?;
+//!
+//! let review = Review { language: "Rust", diff: "- a\n+ b" };
+//! let system = prompts.render("reviewer", &review)?;
+//! assert_eq!(system, "You review Rust code. Be concise and kind.");
+//!
+//! let messages = vec![
+//!     prompts.system("reviewer", &review)?,
+//!     prompts.user("review", &review)?,
+//! ];
+//! # Ok::<(), aisdk::Error>(())
+//! ```
+
+use crate::core::messages::{Message, SystemMessage, UserMessage};
+use crate::error::{Error, Result};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// The deepest partials may be nested, which also stops partials that include
+/// themselves.
+pub const MAX_PARTIAL_DEPTH: usize = 16;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    Text(String),
+    Variable(String),
+    Partial(String),
+}
+
+/// A parsed prompt template.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PromptTemplate {
+    segments: Vec<Segment>,
+}
+
+impl PromptTemplate {
+    /// Parses a template.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::PromptError`] if a tag is not closed or names an invalid
+    /// variable.
+    pub fn new(source: &str) -> Result<Self> {
+        let mut segments = Vec::new();
+        let mut text = String::new();
+        let mut rest = source;
+
+        while let Some(open) = rest.find("{{") {
+            // An escaped tag is kept as text, without its backslash
+            if rest[..open].ends_with('\\') {
+                text.push_str(&rest[..open - 1]);
+                text.push_str("{{");
+                rest = &rest[open + 2..];
+                continue;
+            }
+            text.push_str(&rest[..open]);
+
+            let close = rest[open..].find("}}").ok_or_else(|| {
+                Error::PromptError(format!("unclosed tag at `{}`", &rest[open..]))
+            })?;
+            let tag = rest[open + 2..open + close].trim();
+            rest = &rest[open + close + 2..];
+
+            if !text.is_empty() {
+                segments.push(Segment::Text(std::mem::take(&mut text)));
+            }
+            segments.push(match tag.strip_prefix('>') {
+                Some(partial) => Segment::Partial(checked_name(partial.trim())?),
+                None => Segment::Variable(checked_name(tag)?),
+            });
+        }
+        text.push_str(rest);
+        if !text.is_empty() {
+            segments.push(Segment::Text(text));
+        }
+
+        Ok(Self { segments })
+    }
+
+    /// Returns the names of the variables used by the template, not counting its
+    /// partials.
+    pub fn variables(&self) -> Vec<&str> {
+        self.segments
+            .iter()
+            .filter_map(|segment| match segment {
+                Segment::Variable(name) => Some(name.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the names of the partials included by the template.
+    pub fn partials(&self) -> Vec<&str> {
+        self.segments
+            .iter()
+            .filter_map(|segment| match segment {
+                Segment::Partial(name) => Some(name.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Renders a template without partials.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::PromptError`] if a variable is missing, or the template
+    /// includes a partial.
+    pub fn render(&self, variables: &impl Serialize) -> Result<String> {
+        let variables = to_value(variables)?;
+        let mut out = String::new();
+        self.render_into(&mut out, &variables, &HashMap::new(), 0)?;
+        Ok(out)
+    }
+
+    fn render_into(
+        &self,
+        out: &mut String,
+        variables: &Value,
+        partials: &HashMap<String, PromptTemplate>,
+        depth: usize,
+    ) -> Result<()> {
+        for segment in &self.segments {
+            match segment {
+                Segment::Text(text) => out.push_str(text),
+                Segment::Variable(name) => out.push_str(&lookup(variables, name)?),
+                Segment::Partial(name) => {
+                    if depth >= MAX_PARTIAL_DEPTH {
+                        return Err(Error::PromptError(format!(
+                            "partials nested more than {MAX_PARTIAL_DEPTH} levels deep at `{name}`"
+                        )));
+                    }
+                    let partial = partials
+                        .get(name)
+                        .ok_or_else(|| Error::PromptError(format!("unknown partial `{name}`")))?;
+                    partial.render_into(out, variables, partials, depth + 1)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for PromptTemplate {
+    type Err = Error;
+
+    fn from_str(source: &str) -> Result<Self> {
+        Self::new(source)
+    }
+}
+
+/// A set of named templates that can include each other as partials.
+#[derive(Debug, Clone, Default)]
+pub struct PromptRegistry {
+    templates: HashMap<String, PromptTemplate>,
+}
+
+impl PromptRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads every file of a directory as a template named after its file stem, e.g.
+    /// `reviewer.txt` as `reviewer`. Subdirectories are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::PromptError`] if the directory or a file cannot be read, or a
+    /// template cannot be parsed.
+    pub fn from_dir(path: impl AsRef<Path>) -> Result<Self> {
+        let read_error =
+            |e: std::io::Error| Error::PromptError(format!("error reading prompts: {e}"));
+        let mut registry = Self::new();
+        for entry in std::fs::read_dir(path).map_err(read_error)? {
+            let path = entry.map_err(read_error)?.path();
+            let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                continue;
+            };
+            if path.is_file() {
+                let source = std::fs::read_to_string(&path).map_err(read_error)?;
+                registry.register(name, &source)?;
+            }
+        }
+        Ok(registry)
+    }
+
+    /// Parses a template and registers it under `name`, replacing any template of the
+    /// same name.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::PromptError`] if the template cannot be parsed.
+    pub fn register(&mut self, name: impl Into<String>, source: &str) -> Result<()> {
+        self.templates
+            .insert(name.into(), PromptTemplate::new(source)?);
+        Ok(())
+    }
+
+    /// Returns the template registered under `name`.
+    pub fn get(&self, name: &str) -> Option<&PromptTemplate> {
+        self.templates.get(name)
+    }
+
+    /// Renders the template registered under `name`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::PromptError`] if the template or one of its partials is
+    /// unknown, or a variable is missing.
+    pub fn render(&self, name: &str, variables: &impl Serialize) -> Result<String> {
+        let template = self
+            .get(name)
+            .ok_or_else(|| Error::PromptError(format!("unknown template `{name}`")))?;
+        let variables = to_value(variables)?;
+        let mut out = String::new();
+        template.render_into(&mut out, &variables, &self.templates, 0)?;
+        Ok(out)
+    }
+
+    /// Renders the template registered under `name` as a system message.
+    ///
+    /// # Errors
+    ///
+    /// See [`render`](Self::render).
+    pub fn system(&self, name: &str, variables: &impl Serialize) -> Result<Message> {
+        Ok(Message::System(SystemMessage::new(
+            self.render(name, variables)?,
+        )))
+    }
+
+    /// Renders the template registered under `name` as a user message.
+    ///
+    /// # Errors
+    ///
+    /// See [`render`](Self::render).
+    pub fn user(&self, name: &str, variables: &impl Serialize) -> Result<Message> {
+        Ok(Message::User(UserMessage::new(
+            self.render(name, variables)?,
+        )))
+    }
+}
+
+/// Returns the name if it is a valid variable or partial name, i.e. dot-separated
+/// words of letters, digits, `_`, and `-`.
+fn checked_name(name: &str) -> Result<String> {
+    let valid = !name.is_empty()
+        && name.split('.').all(|part| {
+            !part.is_empty()
+                && part
+                    .chars()
+                    .all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+        });
+    if valid {
+        Ok(name.to_string())
+    } else {
+        Err(Error::PromptError(format!("invalid name `{name}` in tag")))
+    }
+}
+
+fn to_value(variables: &impl Serialize) -> Result<Value> {
+    serde_json::to_value(variables)
+        .map_err(|e| Error::PromptError(format!("error serializing variables: {e}")))
+}
+
+/// Returns the text of a variable. Strings are inserted as is, other values as JSON.
+fn lookup(variables: &Value, name: &str) -> Result<String> {
+    let value = name
+        .split('.')
+        .try_fold(variables, |value, key| match value {
+            Value::Object(map) => map.get(key),
+            Value::Array(items) => key.parse::<usize>().ok().and_then(|i| items.get(i)),
+            _ => None,
+        })
+        .filter(|value| !value.is_null())
+        .ok_or_else(|| Error::PromptError(format!("missing variable `{name}`")))?;
+
+    Ok(match value {
+        Value::String(text) => text.clone(),
+        value => value.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_render_variables() {
+        let template =
+            PromptTemplate::new("Hi {{ user.name }}, you have {{count}} tasks: {{tasks.0}}")
+                .unwrap();
+        let rendered = template
+            .render(&json!({"user": {"name": "Ada"}, "count": 2, "tasks": ["write"]}))
+            .unwrap();
+
+        assert_eq!(rendered, "Hi Ada, you have 2 tasks: write");
+        assert_eq!(template.variables(), ["user.name", "count", "tasks.0"]);
+    }
+
+    #[test]
+    fn test_missing_variable_is_an_error() {
+        let template = PromptTemplate::new("Hi {{name}}").unwrap();
+        assert_eq!(
+            template.render(&json!({"name": null})).unwrap_err(),
+            Error::PromptError("missing variable `name`".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_errors_and_escapes() {
+        assert!(PromptTemplate::new("Hi {{name").is_err());
+        assert!(PromptTemplate::new("Hi {{first name}}").is_err());
+
+        let template = PromptTemplate::new(r"Use \{{name}} for {{what}}").unwrap();
+        assert_eq!(
+            template.render(&json!({"what": "variables"})).unwrap(),
+            "Use {{name}} for variables"
+        );
+    }
+
+    #[test]
+    fn test_partials() {
+        let mut registry = PromptRegistry::new();
+        registry.register("sign", "-- {{name}}").unwrap();
+        registry
+            .register("letter", "Dear {{to}},\n{{> sign}}")
+            .unwrap();
+
+        let message = registry
+            .user("letter", &json!({"to": "Bob", "name": "Ada"}))
+            .unwrap();
+        let Message::User(message) = message else {
+            panic!("expected a user message");
+        };
+        assert_eq!(message.content, "Dear Bob,\n-- Ada");
+
+        registry.register("loop", "{{> loop}}").unwrap();
+        assert!(registry.render("loop", &json!({})).is_err());
+        assert!(registry.render("missing", &json!({})).is_err());
+    }
+}
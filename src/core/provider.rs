@@ -1,9 +1,16 @@
 //! Defines the `Provider` trait, a core abstraction for AI model providers.
 //!
 //! This module contains the `Provider` trait, which unifies the behavior of
-//! different AI providers like OpenAI, Anthropic, or Google.
+//! different AI providers like OpenAI, Anthropic, or Google, the
+//! [`ProviderRegistry`], resolving models from strings such as `"openai:gpt-5"`, and
+//! the [`ModelDescriptor`]s providers list their models as.
 
 use crate::core::language_model::LanguageModel;
+use crate::core::language_model::boxed::BoxedLanguageModel;
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
 
 /// A marker trait representing a fully configured AI provider.
 ///
@@ -13,3 +20,155 @@ use crate::core::language_model::LanguageModel;
 /// By implementing `Provider`, a type signals that it is a complete and ready-to-use
 /// client for interacting with a specific AI service.
 pub trait Provider: Send + Sync + LanguageModel {}
+
+/// A model listed by the API of a provider, e.g. with `OpenAI::list_models`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModelDescriptor {
+    /// The id of the model, as passed to `model_name`.
+    pub id: String,
+    /// The display name of the model.
+    pub display_name: Option<String>,
+    /// A short description of the model.
+    pub description: Option<String>,
+    /// The organization owning the model.
+    pub owned_by: Option<String>,
+    /// The context window of the model in tokens.
+    pub context_window: Option<usize>,
+    /// The maximum number of tokens the model generates in a response.
+    pub max_output_tokens: Option<usize>,
+}
+
+/// Creates the language model of a provider from a model name.
+type LanguageModelFactory = Arc<dyn Fn(&str) -> Result<BoxedLanguageModel> + Send + Sync>;
+
+/// A set of providers, registered under a prefix, resolving language models from ids
+/// such as `"anthropic:claude-sonnet-4-0"`, e.g. read from a configuration file.
+///
+/// The id of a model is the prefix of its provider and the name of the model,
+/// separated by the first `:`, so model names may hold colons, e.g.
+/// `"ollama:llama3:8b"`.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(all(feature = "openai", feature = "anthropic"))]
+/// # {
+/// use aisdk::core::DynamicModel;
+/// use aisdk::core::provider::ProviderRegistry;
+/// use aisdk::providers::{Anthropic, OpenAI};
+///
+/// # fn run() -> aisdk::Result<()> {
+/// let mut registry = ProviderRegistry::new();
+/// registry.register("openai", |name| Ok(OpenAI::<DynamicModel>::model_name(name)));
+/// registry.register("anthropic", |name| {
+///     Anthropic::<DynamicModel>::builder()
+///         .model_name(name)
+///         .api_key(std::env::var("ANTHROPIC_KEY").unwrap_or_default())
+///         .build()
+/// });
+///
+/// let model = registry.language_model("anthropic:claude-sonnet-4-0")?;
+/// # Ok(())
+/// # }
+/// # }
+/// ```
+#[derive(Clone, Default)]
+pub struct ProviderRegistry {
+    providers: HashMap<String, LanguageModelFactory>,
+}
+
+impl std::fmt::Debug for ProviderRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ProviderRegistry")
+            .field("providers", &self.providers.keys())
+            .finish()
+    }
+}
+
+impl ProviderRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a provider under `prefix`, replacing any provider of the same prefix.
+    /// `factory` creates the language model of the provider from a model name.
+    pub fn register<M, F>(&mut self, prefix: impl Into<String>, factory: F)
+    where
+        M: LanguageModel,
+        F: Fn(&str) -> Result<M> + Send + Sync + 'static,
+    {
+        let factory: LanguageModelFactory =
+            Arc::new(move |name| Ok(Box::new(factory(name)?) as BoxedLanguageModel));
+        self.providers.insert(prefix.into(), factory);
+    }
+
+    /// Returns the prefixes of the registered providers, in no particular order.
+    pub fn providers(&self) -> impl Iterator<Item = &str> {
+        self.providers.keys().map(String::as_str)
+    }
+
+    /// Resolves the language model of an id such as `"openai:gpt-5"`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidInput`] if the id has no provider prefix or its provider
+    /// is not registered, and the error of the provider if it fails to create the
+    /// model.
+    pub fn language_model(&self, id: &str) -> Result<BoxedLanguageModel> {
+        let (prefix, name) = id.split_once(':').ok_or_else(|| {
+            Error::InvalidInput(format!(
+                "model id `{id}` must be of the form `provider:model`"
+            ))
+        })?;
+        let factory = self.providers.get(prefix).ok_or_else(|| {
+            Error::InvalidInput(format!("unknown provider `{prefix}` in model id `{id}`"))
+        })?;
+        factory(name)
+    }
+}
+
+#[cfg(all(test, feature = "language-model-request"))]
+mod tests {
+    use super::*;
+    use crate::core::language_model::LanguageModelOptions;
+    use crate::core::test_model::ScriptedModel;
+
+    fn registry() -> ProviderRegistry {
+        let mut registry = ProviderRegistry::new();
+        registry.register("echo", |name| Ok(ScriptedModel::new(name).text(name)));
+        registry.register("broken", |_| -> Result<ScriptedModel> {
+            Err(Error::MissingField("api_key".to_string()))
+        });
+        registry
+    }
+
+    #[tokio::test]
+    async fn test_resolves_models_by_id() {
+        let mut model = registry().language_model("echo:llama3:8b").unwrap();
+
+        assert_eq!(model.name(), "llama3:8b");
+        let response = model
+            .generate_text(LanguageModelOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(response.contents.len(), 1);
+        assert_eq!(model.clone().name(), "llama3:8b");
+    }
+
+    #[test]
+    fn test_rejects_unknown_ids() {
+        let registry = registry();
+
+        for id in ["echo", "other:model"] {
+            assert!(matches!(
+                registry.language_model(id),
+                Err(Error::InvalidInput(_))
+            ));
+        }
+        assert!(matches!(
+            registry.language_model("broken:model"),
+            Err(Error::MissingField(_))
+        ));
+    }
+}
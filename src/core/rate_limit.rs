@@ -0,0 +1,503 @@
+//! Client-side rate limiting of provider requests.
+//!
+//! A [`RateLimiter`] holds token buckets for the requests and the tokens a provider
+//! accepts per minute. Set on a provider builder with `rate_limiter`, it is acquired
+//! before every request the provider sends, including retries and stream reconnections,
+//! so bulk jobs wait for capacity instead of running into the provider's 429s.
+//!
+//! The tokens of a request are estimated from the size of its body, at four bytes per
+//! token. Tasks waiting for capacity are served in the order they started waiting, and
+//! clones of a limiter share its buckets, so one limiter can be set on several
+//! providers sharing a quota.
+//!
+//! Providers also report their own limits in the headers of their responses. These are
+//! parsed into a [`RateLimitInfo`], stored in the response extensions of the hosted
+//! language model providers, including the responses of streams, which report the
+//! headers of the response that opened them, and carried by
+//! [`Error::RateLimited`](crate::Error::RateLimited), so schedulers can pace requests
+//! before they are rejected.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # #[cfg(feature = "openai")]
+//! # {
+//! use aisdk::core::DynamicModel;
+//! use aisdk::core::rate_limit::RateLimiter;
+//! use aisdk::providers::OpenAI;
+//!
+//! # fn run() -> aisdk::Result<()> {
+//! let openai = OpenAI::<DynamicModel>::builder()
+//!     .model_name("gpt-5")
+//!     .rate_limiter(
+//!         RateLimiter::new()
+//!             .requests_per_minute(500)
+//!             .tokens_per_minute(30_000),
+//!     )
+//!     .build()?;
+//! # Ok(())
+//! # }
+//! # }
+//! ```
+
+use crate::core::runtime::{Instant, SystemTime, UNIX_EPOCH, sleep};
+use crate::extensions::Extension;
+use reqwest::header::HeaderMap;
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// A token bucket refilling continuously up to its capacity.
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+    capacity: f64,
+    available: f64,
+}
+
+impl Bucket {
+    fn per_minute(limit: u32) -> Self {
+        assert!(limit > 0, "a rate limit must allow at least one per minute");
+        Self {
+            capacity: f64::from(limit),
+            available: f64::from(limit),
+        }
+    }
+
+    /// Sets the limit of `bucket`. A bucket in use keeps what was taken from it, up to
+    /// its new capacity.
+    fn set_limit(bucket: &mut Option<Self>, limit: u32) {
+        let mut new = Self::per_minute(limit);
+        if let Some(old) = bucket {
+            new.available = old.available.min(new.capacity);
+        }
+        *bucket = Some(new);
+    }
+
+    fn limit(&self) -> u32 {
+        self.capacity as u32
+    }
+
+    /// Refills the bucket with what it gained over `elapsed`.
+    fn refill(&mut self, elapsed: Duration) {
+        let gained = self.capacity * elapsed.as_secs_f64() / 60.0;
+        self.available = (self.available + gained).min(self.capacity);
+    }
+
+    /// Returns how long until `amount` is available. Amounts above the capacity wait
+    /// for a full bucket.
+    fn wait(&self, amount: f64) -> Duration {
+        let missing = amount.min(self.capacity) - self.available;
+        if missing <= 0.0 {
+            return Duration::ZERO;
+        }
+        Duration::from_secs_f64(missing * 60.0 / self.capacity)
+    }
+
+    fn take(&mut self, amount: f64) {
+        self.available -= amount.min(self.capacity);
+    }
+}
+
+/// The buckets of a [`RateLimiter`].
+#[derive(Debug)]
+struct Buckets {
+    requests: Option<Bucket>,
+    tokens: Option<Bucket>,
+    updated: Instant,
+}
+
+impl Buckets {
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.updated);
+        self.updated = now;
+        for bucket in [&mut self.requests, &mut self.tokens].into_iter().flatten() {
+            bucket.refill(elapsed);
+        }
+    }
+
+    /// Returns how long until a request of `tokens` tokens can be sent.
+    fn wait(&self, tokens: u32) -> Duration {
+        let requests = self.requests.map_or(Duration::ZERO, |b| b.wait(1.0));
+        let tokens = self
+            .tokens
+            .map_or(Duration::ZERO, |b| b.wait(f64::from(tokens)));
+        requests.max(tokens)
+    }
+
+    fn take(&mut self, tokens: u32) {
+        if let Some(bucket) = &mut self.requests {
+            bucket.take(1.0);
+        }
+        if let Some(bucket) = &mut self.tokens {
+            bucket.take(f64::from(tokens));
+        }
+    }
+}
+
+/// A limit on the requests and tokens sent per minute, shared by its clones.
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    buckets: Arc<Mutex<Buckets>>,
+    queue: Arc<tokio::sync::Mutex<()>>,
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self {
+            buckets: Arc::new(Mutex::new(Buckets {
+                requests: None,
+                tokens: None,
+                updated: Instant::now(),
+            })),
+            queue: Arc::default(),
+        }
+    }
+}
+
+impl RateLimiter {
+    /// Creates a limiter without limits.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Buckets> {
+        self.buckets.lock().unwrap()
+    }
+
+    /// Limits the requests sent per minute. Up to `limit` requests are sent at once
+    /// when the limiter is idle. The limit applies to every clone of the limiter.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `limit` is 0.
+    pub fn requests_per_minute(self, limit: u32) -> Self {
+        let mut buckets = self.lock();
+        buckets.refill(Instant::now());
+        Bucket::set_limit(&mut buckets.requests, limit);
+        drop(buckets);
+        self
+    }
+
+    /// Limits the tokens sent per minute, estimated from the size of request bodies.
+    /// The limit applies to every clone of the limiter.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `limit` is 0.
+    pub fn tokens_per_minute(self, limit: u32) -> Self {
+        let mut buckets = self.lock();
+        buckets.refill(Instant::now());
+        Bucket::set_limit(&mut buckets.tokens, limit);
+        drop(buckets);
+        self
+    }
+
+    /// Returns the limit on requests per minute, if any.
+    pub fn requests_limit(&self) -> Option<u32> {
+        self.lock().requests.as_ref().map(Bucket::limit)
+    }
+
+    /// Returns the limit on tokens per minute, if any.
+    pub fn tokens_limit(&self) -> Option<u32> {
+        self.lock().tokens.as_ref().map(Bucket::limit)
+    }
+
+    /// Waits until a request of `tokens` tokens can be sent within the limits, and
+    /// takes its share of them. Waiting tasks are served in order.
+    pub async fn acquire(&self, tokens: u32) {
+        // The queue is held while waiting, so that later tasks queue behind this one
+        let _turn = self.queue.lock().await;
+        loop {
+            let wait = {
+                let mut buckets = self.lock();
+                buckets.refill(Instant::now());
+                let wait = buckets.wait(tokens);
+                if wait.is_zero() {
+                    buckets.take(tokens);
+                    return;
+                }
+                wait
+            };
+            sleep(wait).await;
+        }
+    }
+
+    /// Waits for capacity to send `body`, estimating its tokens from its size.
+    pub(crate) async fn acquire_for(&self, body: Option<&reqwest::Body>) {
+        let bytes = body.and_then(|body| body.as_bytes()).map_or(0, <[u8]>::len);
+        self.acquire(u32::try_from(bytes / 4).unwrap_or(u32::MAX))
+            .await;
+    }
+}
+
+/// The rate limits a provider reported in the headers of a response.
+///
+/// Parsed from the OpenAI style `x-ratelimit-*` headers and the Anthropic
+/// `anthropic-ratelimit-*` headers. Resets are relative to the time the response was
+/// received. The `retry-after` wait of a rejected request is carried by
+/// [`Error::RateLimited`](crate::Error::RateLimited) itself.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RateLimitInfo {
+    /// The maximum number of requests allowed in the window.
+    pub requests_limit: Option<u64>,
+    /// The number of requests left in the window.
+    pub requests_remaining: Option<u64>,
+    /// The time until the request limit resets.
+    pub requests_reset: Option<Duration>,
+    /// The maximum number of tokens allowed in the window.
+    pub tokens_limit: Option<u64>,
+    /// The number of tokens left in the window.
+    pub tokens_remaining: Option<u64>,
+    /// The time until the token limit resets.
+    pub tokens_reset: Option<Duration>,
+}
+
+impl Extension for RateLimitInfo {
+    const NAME: &'static str = "aisdk.rate_limit";
+}
+
+impl RateLimitInfo {
+    /// Parses the rate limit headers of a response, returning `None` when it has none.
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        Self::parse(headers, SystemTime::now())
+    }
+
+    fn parse(headers: &HeaderMap, now: SystemTime) -> Option<Self> {
+        let header = |name: &str| headers.get(name)?.to_str().ok().map(str::trim);
+        let number = |openai: &str, anthropic: &str| {
+            header(openai)
+                .or_else(|| header(anthropic))
+                .and_then(|value| value.parse().ok())
+        };
+        // OpenAI sends the time left, e.g. `6m0s`, and Anthropic the time of the reset
+        let reset = |openai: &str, anthropic: &str| {
+            header(openai).and_then(parse_duration).or_else(|| {
+                let at = parse_timestamp(header(anthropic)?)?;
+                Some(at.duration_since(now).unwrap_or_default())
+            })
+        };
+        let info = Self {
+            requests_limit: number(
+                "x-ratelimit-limit-requests",
+                "anthropic-ratelimit-requests-limit",
+            ),
+            requests_remaining: number(
+                "x-ratelimit-remaining-requests",
+                "anthropic-ratelimit-requests-remaining",
+            ),
+            requests_reset: reset(
+                "x-ratelimit-reset-requests",
+                "anthropic-ratelimit-requests-reset",
+            ),
+            tokens_limit: number(
+                "x-ratelimit-limit-tokens",
+                "anthropic-ratelimit-tokens-limit",
+            ),
+            tokens_remaining: number(
+                "x-ratelimit-remaining-tokens",
+                "anthropic-ratelimit-tokens-remaining",
+            ),
+            tokens_reset: reset(
+                "x-ratelimit-reset-tokens",
+                "anthropic-ratelimit-tokens-reset",
+            ),
+        };
+        (info != Self::default()).then_some(info)
+    }
+}
+
+/// Parses a Go style duration, such as `1s`, `6m0s`, `20ms` or `1h2m3.5s`.
+fn parse_duration(value: &str) -> Option<Duration> {
+    let mut total = 0.0;
+    let mut rest = value;
+    while !rest.is_empty() {
+        let number_end = rest
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .unwrap_or(rest.len());
+        let number: f64 = rest[..number_end].parse().ok()?;
+        rest = &rest[number_end..];
+        let unit_end = rest
+            .find(|c: char| c.is_ascii_digit() || c == '.')
+            .unwrap_or(rest.len());
+        let seconds = match &rest[..unit_end] {
+            "h" => 3600.0,
+            "m" => 60.0,
+            "s" => 1.0,
+            "ms" => 0.001,
+            _ => return None,
+        };
+        total += number * seconds;
+        rest = &rest[unit_end..];
+    }
+    Some(Duration::from_secs_f64(total))
+}
+
+/// Parses an RFC 3339 timestamp, such as `2025-01-01T12:00:30Z`.
+fn parse_timestamp(value: &str) -> Option<SystemTime> {
+    let (date, time) = value.split_once(['T', 't'])?;
+    let mut date = date.splitn(3, '-').map(|part| part.parse::<i64>().ok());
+    let (year, month, day) = (date.next()??, date.next()??, date.next()??);
+
+    let (time, offset) = match time.find(['Z', 'z', '+', '-']) {
+        Some(i) => time.split_at(i),
+        None => return None,
+    };
+    let mut time = time.splitn(3, ':');
+    let hours: i64 = time.next()?.parse().ok()?;
+    let minutes: i64 = time.next()?.parse().ok()?;
+    let seconds: f64 = time.next()?.parse().ok()?;
+    let offset = match offset.split_at(1) {
+        ("Z" | "z", "") => 0,
+        (sign, offset) => {
+            let (h, m) = offset.split_once(':')?;
+            let offset = h.parse::<i64>().ok()? * 3600 + m.parse::<i64>().ok()? * 60;
+            if sign == "-" { -offset } else { offset }
+        }
+    };
+
+    // Days since the epoch of the civil date, from Howard Hinnant's algorithm
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146_097 + day_of_era - 719_468;
+
+    let whole = days * 86_400 + hours * 3600 + minutes * 60 - offset;
+    let seconds = whole as f64 + seconds;
+    (seconds >= 0.0).then(|| UNIX_EPOCH + Duration::from_secs_f64(seconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bucket_waits_for_missing_amount() {
+        let mut bucket = Bucket::per_minute(60);
+        assert_eq!(bucket.wait(60.0), Duration::ZERO);
+
+        bucket.take(60.0);
+        assert_eq!(bucket.wait(1.0), Duration::from_secs(1));
+        // Amounts above the capacity wait for a full bucket
+        assert_eq!(bucket.wait(100.0), Duration::from_secs(60));
+
+        bucket.refill(Duration::from_secs(30));
+        assert_eq!(bucket.available, 30.0);
+        bucket.refill(Duration::from_secs(60));
+        assert_eq!(bucket.available, 60.0);
+    }
+
+    #[test]
+    fn test_buckets_wait_for_the_slowest_limit() {
+        let limiter = RateLimiter::new()
+            .requests_per_minute(60)
+            .tokens_per_minute(600);
+        let mut buckets = limiter.lock();
+
+        buckets.take(600);
+        assert_eq!(buckets.wait(300), Duration::from_secs(30));
+        assert_eq!(buckets.wait(0), Duration::ZERO);
+    }
+
+    #[tokio::test]
+    async fn test_acquire_waits_for_capacity() {
+        let limiter = RateLimiter::new().tokens_per_minute(6_000);
+
+        let start = Instant::now();
+        limiter.acquire(6_000).await;
+        assert!(start.elapsed() < Duration::from_millis(50));
+
+        // 100 tokens per second are refilled
+        limiter.acquire(10).await;
+        assert!(start.elapsed() >= Duration::from_millis(90));
+    }
+
+    fn headers(pairs: &[(&'static str, &str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.insert(*name, value.parse().unwrap());
+        }
+        headers
+    }
+
+    #[test]
+    fn test_rate_limit_info_from_openai_headers() {
+        let info = RateLimitInfo::from_headers(&headers(&[
+            ("x-ratelimit-limit-requests", "500"),
+            ("x-ratelimit-remaining-requests", "499"),
+            ("x-ratelimit-reset-requests", "120ms"),
+            ("x-ratelimit-limit-tokens", "30000"),
+            ("x-ratelimit-remaining-tokens", "29000"),
+            ("x-ratelimit-reset-tokens", "6m0s"),
+        ]))
+        .unwrap();
+
+        assert_eq!(info.requests_limit, Some(500));
+        assert_eq!(info.requests_remaining, Some(499));
+        assert_eq!(info.requests_reset, Some(Duration::from_millis(120)));
+        assert_eq!(info.tokens_remaining, Some(29_000));
+        assert_eq!(info.tokens_reset, Some(Duration::from_secs(360)));
+    }
+
+    #[test]
+    fn test_rate_limit_info_from_anthropic_headers() {
+        let now = UNIX_EPOCH + Duration::from_secs(1_735_732_800); // 2025-01-01T12:00:00Z
+        let info = RateLimitInfo::parse(
+            &headers(&[
+                ("anthropic-ratelimit-requests-limit", "50"),
+                ("anthropic-ratelimit-requests-remaining", "0"),
+                ("anthropic-ratelimit-requests-reset", "2025-01-01T12:00:30Z"),
+                (
+                    "anthropic-ratelimit-tokens-reset",
+                    "2025-01-01T13:00:00+01:00",
+                ),
+            ]),
+            now,
+        )
+        .unwrap();
+
+        assert_eq!(info.requests_limit, Some(50));
+        assert_eq!(info.requests_remaining, Some(0));
+        assert_eq!(info.requests_reset, Some(Duration::from_secs(30)));
+        assert_eq!(info.tokens_reset, Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_rate_limit_info_requires_headers() {
+        assert_eq!(RateLimitInfo::from_headers(&HeaderMap::new()), None);
+        assert_eq!(
+            parse_duration("1h2m3.5s"),
+            Some(Duration::from_secs_f64(3723.5))
+        );
+        assert_eq!(parse_duration("soon"), None);
+    }
+
+    #[test]
+    fn test_limits_are_kept_by_setters() {
+        let limiter = RateLimiter::new()
+            .requests_per_minute(10)
+            .tokens_per_minute(1_000);
+
+        assert_eq!(limiter.requests_limit(), Some(10));
+        assert_eq!(limiter.tokens_limit(), Some(1_000));
+        assert_eq!(RateLimiter::new().requests_limit(), None);
+    }
+
+    #[test]
+    fn test_limits_are_shared_by_clones() {
+        let limiter = RateLimiter::new().requests_per_minute(10);
+        let clone = limiter.clone();
+        let limiter = limiter.tokens_per_minute(1_000);
+
+        assert_eq!(clone.tokens_limit(), Some(1_000));
+        limiter.lock().take(100);
+        assert!(!clone.lock().wait(1_000).is_zero());
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one per minute")]
+    fn test_zero_limit_is_rejected() {
+        let _ = RateLimiter::new().requests_per_minute(0);
+    }
+}
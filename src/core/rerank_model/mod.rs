@@ -0,0 +1,57 @@
+//! Rerank model
+//!
+//! A rerank model scores a list of documents by their relevance to a query, e.g. to
+//! reorder the candidates returned by a vector search before passing the best ones
+//! to a language model.
+
+/// Rerank request builder and related types.
+#[cfg(feature = "rerank-model-request")]
+pub mod request;
+
+use crate::error::Result;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use derive_builder::Builder;
+#[cfg(feature = "rerank-model-request")]
+pub use request::RerankModelRequest;
+
+/// The options for rerank requests.
+#[derive(Debug, Clone, Builder)]
+pub struct RerankModelOptions {
+    /// The query to rank the documents against.
+    pub query: String,
+    /// The documents to rank.
+    pub documents: Vec<String>,
+    /// The number of most relevant documents to return. All documents are returned
+    /// when unset.
+    pub top_n: Option<usize>,
+}
+
+impl RerankModelOptions {
+    /// Returns the rerank options builder.
+    pub fn builder() -> RerankModelOptionsBuilder {
+        RerankModelOptionsBuilder::default()
+    }
+}
+
+/// The core trait abstracting the capabilities of a rerank model.
+#[async_trait]
+pub trait RerankModel: Clone + Send + Sync + std::fmt::Debug + 'static {
+    /// Ranks documents by their relevance to a query.
+    async fn rerank(&self, input: RerankModelOptions) -> Result<RerankModelResponse>;
+}
+
+/// A document scored by a rerank model.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RerankResult {
+    /// The position of the document in the request's `documents`.
+    pub index: usize,
+    /// The relevance of the document to the query. Higher is more relevant.
+    pub relevance_score: f32,
+    /// The document text.
+    pub document: String,
+}
+
+/// The response type for rerank requests, ordered from most to least relevant.
+pub type RerankModelResponse = Vec<RerankResult>;
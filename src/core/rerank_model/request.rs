@@ -0,0 +1,191 @@
+use std::ops::{Deref, DerefMut};
+
+use crate::core::rerank_model::{RerankModel, RerankModelOptions, RerankModelResponse};
+use crate::error::Result;
+
+/// Options for rerank requests to be used by `rerank`.
+#[derive(Debug, Clone)]
+pub struct RerankModelRequest<M: RerankModel> {
+    /// The rerank model to use for ranking documents.
+    pub model: M,
+    /// Input and Configuration options for the rerank model request.
+    pub(crate) options: RerankModelOptions,
+}
+
+impl<M: RerankModel> RerankModelRequest<M> {
+    /// Creates a new builder for constructing a `RerankModelRequest`.
+    ///
+    /// This method initiates the type-state builder pattern, starting with the
+    /// [`ModelStage`] where you must specify the rerank model.
+    pub fn builder() -> RerankModelRequestBuilder<M> {
+        RerankModelRequestBuilder::default()
+    }
+
+    /// Ranks the documents by their relevance to the query.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the scored documents ordered from most to least relevant,
+    /// or an error if the rerank request fails.
+    pub async fn rerank(&self) -> Result<RerankModelResponse> {
+        self.model.rerank(self.options.clone()).await
+    }
+}
+
+impl<M: RerankModel> Deref for RerankModelRequest<M> {
+    type Target = RerankModelOptions;
+
+    fn deref(&self) -> &Self::Target {
+        &self.options
+    }
+}
+
+impl<M: RerankModel> DerefMut for RerankModelRequest<M> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.options
+    }
+}
+
+/// Type-state markers for the `RerankModelRequestBuilder`.
+///
+/// The initial builder state where the rerank model must be set.
+///
+/// Transitions to [`OptionsStage`] after calling [`model`](RerankModelRequestBuilder::model).
+pub struct ModelStage {}
+
+/// The final state where the query, documents, and result count can be configured
+/// before building.
+///
+/// Transitions to the completed `RerankModelRequest` after calling [`build`](RerankModelRequestBuilder::build).
+pub struct OptionsStage {}
+
+/// A type-state builder for constructing `RerankModelRequest` instances.
+///
+/// # Type Parameters
+///
+/// * `M` - The rerank model type.
+/// * `State` - The current builder state, determining available methods.
+pub struct RerankModelRequestBuilder<M: RerankModel, State = ModelStage> {
+    model: Option<M>,
+    options: RerankModelOptions,
+    state: std::marker::PhantomData<State>,
+}
+
+impl<M: RerankModel, State> Deref for RerankModelRequestBuilder<M, State> {
+    type Target = RerankModelOptions;
+
+    /// Dereferences to the underlying `RerankModelOptions`.
+    fn deref(&self) -> &Self::Target {
+        &self.options
+    }
+}
+
+impl<M: RerankModel, State> DerefMut for RerankModelRequestBuilder<M, State> {
+    /// Mutably dereferences to the underlying `RerankModelOptions`.
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.options
+    }
+}
+
+impl<M: RerankModel> RerankModelRequestBuilder<M> {
+    fn default() -> Self {
+        RerankModelRequestBuilder {
+            model: None,
+            options: RerankModelOptions::builder()
+                .query(String::new())
+                .documents(vec![])
+                .top_n(None)
+                .build()
+                .unwrap(),
+            state: std::marker::PhantomData,
+        }
+    }
+}
+
+/// Methods available in the [`ModelStage`] state.
+impl<M: RerankModel> RerankModelRequestBuilder<M, ModelStage> {
+    /// Sets the rerank model for the request.
+    ///
+    /// This is the first required step in building a request.
+    ///
+    /// # Parameters
+    ///
+    /// * `model` - The rerank model instance to use.
+    ///
+    /// # Returns
+    ///
+    /// The builder in the [`OptionsStage`] state.
+    pub fn model(self, model: M) -> RerankModelRequestBuilder<M, OptionsStage> {
+        RerankModelRequestBuilder {
+            model: Some(model),
+            options: self.options,
+            state: std::marker::PhantomData,
+        }
+    }
+}
+
+/// Methods available in the [`OptionsStage`] state.
+impl<M: RerankModel> RerankModelRequestBuilder<M, OptionsStage> {
+    /// Sets the query to rank the documents against.
+    ///
+    /// # Parameters
+    ///
+    /// * `query` - The search query.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the query set.
+    pub fn query(mut self, query: impl Into<String>) -> RerankModelRequestBuilder<M, OptionsStage> {
+        self.options.query = query.into();
+        self
+    }
+
+    /// Sets the documents to rank.
+    ///
+    /// # Parameters
+    ///
+    /// * `documents` - A vector of document texts.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the documents set.
+    pub fn documents(
+        mut self,
+        documents: impl Into<Vec<String>>,
+    ) -> RerankModelRequestBuilder<M, OptionsStage> {
+        self.options.documents = documents.into();
+        self
+    }
+
+    /// Limits the response to the most relevant documents.
+    ///
+    /// # Parameters
+    ///
+    /// * `top_n` - The number of documents to return.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the result count set.
+    pub fn top_n(mut self, top_n: usize) -> RerankModelRequestBuilder<M, OptionsStage> {
+        self.options.top_n = Some(top_n);
+        self
+    }
+
+    /// Builds the `RerankModelRequest`.
+    ///
+    /// This method consumes the builder and returns the configured request.
+    ///
+    /// # Returns
+    ///
+    /// The constructed `RerankModelRequest`.
+    pub fn build(self) -> RerankModelRequest<M> {
+        let model = self
+            .model
+            .unwrap_or_else(|| unreachable!("Model must be set"));
+
+        RerankModelRequest {
+            model,
+            options: self.options,
+        }
+    }
+}
@@ -0,0 +1,189 @@
+//! Runtime-agnostic tasks and timers.
+//!
+//! The SDK spawns background tasks, e.g. to drive a stream, and waits between retries.
+//! On native targets these run on tokio. On `wasm32` targets, with the `wasm` feature,
+//! tasks are spawned on the browser's event loop with `wasm-bindgen-futures`, timers
+//! are backed by `setTimeout`, and clocks by `performance.now()` and `Date.now()`, so
+//! the SDK runs in the browser without a tokio runtime.
+
+use std::future::Future;
+use std::time::Duration;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use std::time::{Instant, SystemTime, UNIX_EPOCH};
+#[cfg(target_arch = "wasm32")]
+pub use web_time::{Instant, SystemTime, UNIX_EPOCH};
+
+/// A handle awaiting the output of a spawned task.
+///
+/// Dropping the handle detaches the task, which keeps running.
+#[cfg(not(target_arch = "wasm32"))]
+pub type JoinHandle<T> = tokio::task::JoinHandle<T>;
+
+/// A handle awaiting the output of a spawned task.
+///
+/// Dropping the handle detaches the task, which keeps running.
+#[cfg(target_arch = "wasm32")]
+pub struct JoinHandle<T>(futures::channel::oneshot::Receiver<T>);
+
+#[cfg(target_arch = "wasm32")]
+impl<T> Future for JoinHandle<T> {
+    type Output = std::result::Result<T, futures::channel::oneshot::Canceled>;
+
+    fn poll(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Self::Output> {
+        std::pin::Pin::new(&mut self.0).poll(cx)
+    }
+}
+
+/// Spawns `future` as a background task.
+pub(crate) fn spawn<F>(future: F) -> JoinHandle<F::Output>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        tokio::spawn(future)
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        let (tx, rx) = futures::channel::oneshot::channel();
+        wasm_bindgen_futures::spawn_local(async move {
+            let _ = tx.send(future.await);
+        });
+        JoinHandle(rx)
+    }
+}
+
+/// A spawned task that is aborted when the handle is dropped before it completes.
+///
+/// On `wasm32` targets tasks cannot be aborted, and the task is detached instead.
+#[cfg(feature = "language-model-request")]
+pub(crate) struct AbortOnDrop<T>(pub(crate) JoinHandle<T>);
+
+#[cfg(feature = "language-model-request")]
+impl<T> Future for AbortOnDrop<T> {
+    type Output = <JoinHandle<T> as Future>::Output;
+
+    fn poll(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Self::Output> {
+        std::pin::Pin::new(&mut self.0).poll(cx)
+    }
+}
+
+#[cfg(feature = "language-model-request")]
+impl<T> Drop for AbortOnDrop<T> {
+    fn drop(&mut self) {
+        #[cfg(not(target_arch = "wasm32"))]
+        self.0.abort();
+    }
+}
+
+/// Lets `value` be held where the SDK requires `Send`, when it is only `Send` on native
+/// targets, such as a response of `reqwest` or a future or stream reading its body.
+///
+/// On `wasm32` targets these wrap browser objects, and are wrapped in turn so they can
+/// be held across the `Send` bounds of the SDK. They never leave the browser's only
+/// thread, which the wrapper checks. On other targets `value` is returned as is.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn assume_send<T>(value: T) -> T {
+    value
+}
+
+/// Lets `value` be held where the SDK requires `Send`, when it is only `Send` on native
+/// targets, such as a response of `reqwest` or a future or stream reading its body.
+///
+/// On `wasm32` targets these wrap browser objects, and are wrapped in turn so they can
+/// be held across the `Send` bounds of the SDK. They never leave the browser's only
+/// thread, which the wrapper checks. On other targets `value` is returned as is.
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn assume_send<T>(value: T) -> send_wrapper::SendWrapper<T> {
+    send_wrapper::SendWrapper::new(value)
+}
+
+/// Waits until `duration` has elapsed.
+pub(crate) fn sleep(duration: Duration) -> impl Future<Output = ()> + Send + 'static {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        tokio::time::sleep(duration)
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        futures_timer::Delay::new(duration)
+    }
+}
+
+/// Waits until `deadline` is reached.
+#[cfg(feature = "language-model-request")]
+pub(crate) fn sleep_until(deadline: Instant) -> impl Future<Output = ()> + Send + 'static {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        tokio::time::sleep_until(deadline.into())
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        sleep(deadline.saturating_duration_since(Instant::now()))
+    }
+}
+
+/// Runs `future` for at most `duration`, returning `None` if it did not complete in time.
+#[cfg(feature = "language-model-request")]
+pub(crate) async fn timeout<F: Future>(duration: Duration, future: F) -> Option<F::Output> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        tokio::time::timeout(duration, future).await.ok()
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        let future = std::pin::pin!(future);
+        match futures::future::select(future, std::pin::pin!(sleep(duration))).await {
+            futures::future::Either::Left((output, _)) => Some(output),
+            futures::future::Either::Right(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_spawn_returns_output() {
+        assert_eq!(spawn(async { 42 }).await.unwrap(), 42);
+    }
+
+    #[cfg(feature = "language-model-request")]
+    #[tokio::test]
+    async fn test_timeout() {
+        assert_eq!(timeout(Duration::from_secs(1), async { 1 }).await, Some(1));
+        assert_eq!(
+            timeout(Duration::from_millis(1), std::future::pending::<()>()).await,
+            None
+        );
+    }
+
+    #[cfg(feature = "language-model-request")]
+    #[tokio::test]
+    async fn test_abort_on_drop() {
+        let (tx, rx) = futures::channel::oneshot::channel::<()>();
+        let task = AbortOnDrop(spawn(async move {
+            std::future::pending::<()>().await;
+            drop(tx);
+        }));
+
+        drop(task);
+
+        assert!(rx.await.is_err());
+    }
+
+    #[cfg(feature = "language-model-request")]
+    #[tokio::test]
+    async fn test_sleep_until_past_deadline() {
+        sleep_until(Instant::now() - Duration::from_millis(1)).await;
+    }
+}
@@ -0,0 +1,56 @@
+//! Speech model
+//!
+//! A speech model synthesizes spoken audio from text, either returning the complete
+//! audio at once or streaming it in chunks as it is generated.
+
+use crate::error::Result;
+use async_trait::async_trait;
+use futures::Stream;
+use std::pin::Pin;
+
+use derive_builder::Builder;
+
+/// The options for speech requests.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(into))]
+pub struct SpeechModelOptions {
+    /// The text to speak.
+    pub text: String,
+    /// The voice to speak with. The provider's default voice is used when unset.
+    #[builder(default)]
+    pub voice: Option<String>,
+    /// The format of the returned audio, e.g. `mp3` or `mp3_44100_128`. Accepted values
+    /// depend on the provider. The provider's default format is used when unset.
+    #[builder(default)]
+    pub format: Option<String>,
+}
+
+impl SpeechModelOptions {
+    /// Returns the speech options builder.
+    pub fn builder() -> SpeechModelOptionsBuilder {
+        SpeechModelOptionsBuilder::default()
+    }
+}
+
+/// The core trait abstracting the capabilities of a speech model.
+#[async_trait]
+pub trait SpeechModel: Clone + Send + Sync + std::fmt::Debug + 'static {
+    /// Synthesizes the text into audio.
+    async fn generate_speech(&self, input: SpeechModelOptions) -> Result<SpeechModelResponse>;
+
+    /// Synthesizes the text into audio, streaming the audio in chunks as it is
+    /// generated.
+    async fn stream_speech(&self, input: SpeechModelOptions) -> Result<SpeechStream>;
+}
+
+/// The response type for speech requests.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpeechModelResponse {
+    /// The encoded audio.
+    pub audio: Vec<u8>,
+    /// The media type of the audio, e.g. `audio/mpeg`, if the provider reported it.
+    pub media_type: Option<String>,
+}
+
+/// A stream of encoded audio chunks. Concatenated, the chunks form the complete audio.
+pub type SpeechStream = Pin<Box<dyn Stream<Item = Result<Vec<u8>>> + Send>>;
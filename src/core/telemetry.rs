@@ -0,0 +1,342 @@
+//! OpenTelemetry spans for model calls.
+//!
+//! With the `otel` feature, `generate_text`, `stream_text` and `embed` each record a
+//! client span on the tracer provider installed with
+//! [`opentelemetry::global::set_tracer_provider`], following the semantic conventions
+//! for generative AI. The span is a child of the current OpenTelemetry context, and
+//! spans recorded while the model is called, e.g. by an instrumented HTTP client, are
+//! its children.
+//!
+//! A span is named after its operation and model, e.g. `chat gpt-5`, and has these
+//! attributes when the values are known:
+//!
+//! - `gen_ai.operation.name`: `chat` or `embeddings`
+//! - `gen_ai.provider.name`: the provider, e.g. `openai`
+//! - `gen_ai.request.model`
+//! - `gen_ai.request.temperature`, `gen_ai.request.top_p`, `gen_ai.request.top_k`,
+//!   `gen_ai.request.max_tokens`, `gen_ai.request.seed`,
+//!   `gen_ai.request.frequency_penalty`, `gen_ai.request.presence_penalty` and
+//!   `gen_ai.request.stop_sequences`
+//! - `gen_ai.embeddings.dimension.count`
+//! - `gen_ai.response.finish_reasons`
+//! - `gen_ai.usage.input_tokens` and `gen_ai.usage.output_tokens`, summed over all
+//!   steps
+//! - `aisdk.response.time_to_first_token`: the seconds until the first delta of a
+//!   stream
+//! - `error.type`, with an error status, when the call fails
+
+#[cfg(feature = "embedding-model-request")]
+use crate::core::embedding_model::EmbeddingModelOptions;
+use crate::core::language_model::{
+    FinishReason, LanguageModel, LanguageModelOptions, StopReason, Usage,
+};
+use crate::core::runtime::Instant;
+use crate::core::utils::{error_type, provider_name};
+use crate::error::{Error, Result};
+use opentelemetry::trace::{FutureExt, SpanKind, Status, TraceContextExt, Tracer};
+use opentelemetry::{Array, Context, KeyValue, StringValue, Value, global};
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// The name of the tracer recording the spans.
+pub const TRACER_NAME: &str = "aisdk";
+
+/// The span of a model call.
+pub(crate) struct GenAiSpan {
+    cx: Context,
+    started: Instant,
+    first_token: AtomicBool,
+}
+
+impl GenAiSpan {
+    /// Starts the span of a `chat` call to `model`.
+    pub(crate) fn chat<M: LanguageModel>(model: &M, options: &LanguageModelOptions) -> Self {
+        let model_name = model.name();
+        let mut attributes = vec![
+            KeyValue::new("gen_ai.operation.name", "chat"),
+            KeyValue::new("gen_ai.provider.name", provider_name::<M>()),
+            KeyValue::new("gen_ai.request.model", model_name.clone()),
+        ];
+        // Temperature and top-p are given in percent
+        let sampling = [
+            ("gen_ai.request.temperature", options.temperature),
+            ("gen_ai.request.top_p", options.top_p),
+        ];
+        for (key, value) in sampling {
+            if let Some(value) = value {
+                attributes.push(KeyValue::new(key, f64::from(value) / 100.0));
+            }
+        }
+        let integers = [
+            ("gen_ai.request.top_k", options.top_k),
+            ("gen_ai.request.max_tokens", options.max_output_tokens),
+            ("gen_ai.request.seed", options.seed),
+        ];
+        for (key, value) in integers {
+            if let Some(value) = value {
+                attributes.push(KeyValue::new(key, i64::from(value)));
+            }
+        }
+        let penalties = [
+            (
+                "gen_ai.request.frequency_penalty",
+                options.frequency_penalty,
+            ),
+            ("gen_ai.request.presence_penalty", options.presence_penalty),
+        ];
+        for (key, value) in penalties {
+            if let Some(value) = value {
+                attributes.push(KeyValue::new(key, f64::from(value)));
+            }
+        }
+        if let Some(stop_sequences) = &options.stop_sequences {
+            attributes.push(KeyValue::new(
+                "gen_ai.request.stop_sequences",
+                string_array(stop_sequences.iter().cloned()),
+            ));
+        }
+
+        Self::start(format!("chat {model_name}"), attributes)
+    }
+
+    /// Starts the span of an `embeddings` call to a model of type `M`.
+    #[cfg(feature = "embedding-model-request")]
+    pub(crate) fn embeddings<M>(options: &EmbeddingModelOptions) -> Self {
+        let mut attributes = vec![
+            KeyValue::new("gen_ai.operation.name", "embeddings"),
+            KeyValue::new("gen_ai.provider.name", provider_name::<M>()),
+        ];
+        if let Some(dimensions) = options.dimensions {
+            attributes.push(KeyValue::new(
+                "gen_ai.embeddings.dimension.count",
+                dimensions as i64,
+            ));
+        }
+
+        Self::start("embeddings".to_string(), attributes)
+    }
+
+    fn start(name: String, attributes: Vec<KeyValue>) -> Self {
+        let tracer = global::tracer(TRACER_NAME);
+        let span = tracer
+            .span_builder(name)
+            .with_kind(SpanKind::Client)
+            .with_attributes(attributes)
+            .start(&tracer);
+        Self {
+            cx: Context::current_with_span(span),
+            started: Instant::now(),
+            first_token: AtomicBool::new(false),
+        }
+    }
+
+    /// Returns the context with this span active, to run the call in.
+    pub(crate) fn context(&self) -> Context {
+        self.cx.clone()
+    }
+
+    /// Runs `call` in this span, then ends the span, recording the result with
+    /// `record` or the error.
+    pub(crate) async fn instrument<T>(
+        self,
+        call: impl Future<Output = Result<T>>,
+        record: impl FnOnce(&Self, &T),
+    ) -> Result<T> {
+        let result = call.with_context(self.context()).await;
+        match &result {
+            Ok(output) => record(&self, output),
+            Err(error) => self.fail(error),
+        }
+        self.end();
+        result
+    }
+
+    /// Records the time to the first token, unless it was already recorded.
+    pub(crate) fn first_token(&self) {
+        if !self.first_token.swap(true, Ordering::Relaxed) {
+            self.cx.span().set_attribute(KeyValue::new(
+                "aisdk.response.time_to_first_token",
+                self.started.elapsed().as_secs_f64(),
+            ));
+        }
+    }
+
+    /// Records the outcome of a `chat` call from its final `options` and total `usage`.
+    pub(crate) fn finish(&self, options: &LanguageModelOptions, usage: &Usage) {
+        let span = self.cx.span();
+        if let Some(finish_reason) = &options.finish_reason {
+            span.set_attribute(KeyValue::new(
+                "gen_ai.response.finish_reasons",
+                string_array([finish_reason_name(finish_reason)]),
+            ));
+        }
+        if let Some(input_tokens) = usage.input_tokens {
+            span.set_attribute(KeyValue::new(
+                "gen_ai.usage.input_tokens",
+                input_tokens as i64,
+            ));
+        }
+        if let Some(output_tokens) = usage.output_tokens {
+            span.set_attribute(KeyValue::new(
+                "gen_ai.usage.output_tokens",
+                output_tokens as i64,
+            ));
+        }
+        if let Some(StopReason::Error(error)) = &options.stop_reason {
+            self.fail(error);
+        }
+    }
+
+    /// Records `error` as the reason the call failed.
+    pub(crate) fn fail(&self, error: &Error) {
+        let span = self.cx.span();
+        span.set_attribute(KeyValue::new("error.type", error_type(error)));
+        span.set_status(Status::error(error.to_string()));
+    }
+
+    /// Ends the span.
+    pub(crate) fn end(&self) {
+        self.cx.span().end();
+    }
+}
+
+/// Returns the name of a finish reason, as used by the semantic conventions.
+fn finish_reason_name(finish_reason: &FinishReason) -> String {
+    match finish_reason {
+        FinishReason::Stop => "stop".to_string(),
+        FinishReason::Length => "length".to_string(),
+        FinishReason::ToolCalls => "tool_calls".to_string(),
+        FinishReason::ContentFilter => "content_filter".to_string(),
+        FinishReason::Error => "error".to_string(),
+        FinishReason::Other(reason) => reason.clone(),
+    }
+}
+
+fn string_array(values: impl IntoIterator<Item = String>) -> Value {
+    Value::Array(Array::String(
+        values.into_iter().map(StringValue::from).collect(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::LanguageModelRequest;
+    use crate::core::test_model::ScriptedModel;
+    use futures::StreamExt;
+    use opentelemetry::trace::Status;
+    use opentelemetry_sdk::trace::{InMemorySpanExporter, SdkTracerProvider, SpanData};
+    use std::sync::OnceLock;
+
+    /// Returns the exporter of the global tracer provider, installing it once.
+    fn exporter() -> &'static InMemorySpanExporter {
+        static EXPORTER: OnceLock<InMemorySpanExporter> = OnceLock::new();
+        EXPORTER.get_or_init(|| {
+            let exporter = InMemorySpanExporter::default();
+            let provider = SdkTracerProvider::builder()
+                .with_simple_exporter(exporter.clone())
+                .build();
+            global::set_tracer_provider(provider);
+            exporter
+        })
+    }
+
+    /// Returns the finished span named `name`.
+    fn finished_span(name: &str) -> SpanData {
+        exporter()
+            .get_finished_spans()
+            .unwrap()
+            .into_iter()
+            .find(|span| span.name == name)
+            .unwrap()
+    }
+
+    fn attribute(span: &SpanData, key: &str) -> Option<Value> {
+        span.attributes
+            .iter()
+            .find(|attribute| attribute.key.as_str() == key)
+            .map(|attribute| attribute.value.clone())
+    }
+
+    #[tokio::test]
+    async fn test_generate_text_span() {
+        exporter();
+
+        LanguageModelRequest::builder()
+            .model(ScriptedModel::new("generating"))
+            .prompt("hi")
+            .temperature(70u32)
+            .top_k(40u32)
+            .build()
+            .generate_text()
+            .await
+            .unwrap();
+
+        let span = finished_span("chat generating");
+        assert_eq!(span.span_kind, SpanKind::Client);
+        assert_eq!(
+            attribute(&span, "gen_ai.operation.name"),
+            Some("chat".into())
+        );
+        assert_eq!(
+            attribute(&span, "gen_ai.request.model"),
+            Some("generating".into())
+        );
+        assert_eq!(
+            attribute(&span, "gen_ai.request.temperature"),
+            Some(0.7.into())
+        );
+        assert_eq!(attribute(&span, "gen_ai.request.top_k"), Some(40i64.into()));
+        assert_eq!(
+            attribute(&span, "gen_ai.usage.input_tokens"),
+            Some(5i64.into())
+        );
+        assert_eq!(
+            attribute(&span, "gen_ai.response.finish_reasons"),
+            Some(string_array(["stop".to_string()]))
+        );
+        assert_eq!(span.status, Status::Unset);
+    }
+
+    #[tokio::test]
+    async fn test_failed_call_span() {
+        exporter();
+
+        let result = LanguageModelRequest::builder()
+            .model(ScriptedModel::new("failing").failing(Error::Other("boom".to_string())))
+            .prompt("hi")
+            .build()
+            .generate_text()
+            .await;
+
+        assert!(result.is_err());
+        let span = finished_span("chat failing");
+        assert_eq!(attribute(&span, "error.type"), Some("Other".into()));
+        assert!(matches!(span.status, Status::Error { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_stream_text_span() {
+        exporter();
+
+        let mut response = LanguageModelRequest::builder()
+            .model(ScriptedModel::new("streaming"))
+            .prompt("hi")
+            .build()
+            .stream_text()
+            .await
+            .unwrap();
+        while response.stream.next().await.is_some() {}
+
+        let span = finished_span("chat streaming");
+        assert!(attribute(&span, "aisdk.response.time_to_first_token").is_some());
+        assert_eq!(
+            attribute(&span, "gen_ai.usage.output_tokens"),
+            Some(1i64.into())
+        );
+        assert_eq!(
+            attribute(&span, "gen_ai.response.finish_reasons"),
+            Some(string_array(["stop".to_string()]))
+        );
+    }
+}
@@ -0,0 +1,283 @@
+//! A scripted language model shared by the tests of the SDK.
+
+use crate::core::capabilities::{
+    Capabilities, ModelInfo, StructuredOutputSupport, ToolCallSupport,
+};
+use crate::core::language_model::{
+    FinishReason, LanguageModel, LanguageModelOptions, LanguageModelResponse,
+    LanguageModelResponseContentType, LanguageModelStreamChunk, LanguageModelStreamChunkType,
+    ProviderStream, SamplingOption, Usage,
+};
+use crate::core::messages::AssistantMessage;
+use crate::core::tokens;
+use crate::error::{Error, Result};
+use futures::StreamExt;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// A model answering every call with a scripted response, "Hello" using 5 input and
+/// 1 output tokens unless set otherwise.
+///
+/// Its streams yield the text of the response as one delta, unless their chunks are
+/// scripted. Clones share the count of calls.
+#[derive(Debug, Clone)]
+pub(crate) struct ScriptedModel {
+    name: String,
+    content: LanguageModelResponseContentType,
+    respond: Option<fn(&LanguageModelOptions) -> LanguageModelResponseContentType>,
+    chunks: Option<Vec<Vec<LanguageModelStreamChunk>>>,
+    raw_events: Vec<&'static str>,
+    usage: Option<Usage>,
+    finish_reason: Option<FinishReason>,
+    reports_stop_sequence: bool,
+    error: Option<Error>,
+    error_in_stream: bool,
+    stalls: bool,
+    capabilities: Capabilities,
+    info: ModelInfo,
+    token_factor: usize,
+    unsupported_options: &'static [SamplingOption],
+    calls: Arc<AtomicUsize>,
+}
+
+impl ToolCallSupport for ScriptedModel {}
+
+impl StructuredOutputSupport for ScriptedModel {}
+
+impl ScriptedModel {
+    /// Creates a model answering "Hello", reported under `name`.
+    pub(crate) fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            content: LanguageModelResponseContentType::new("Hello"),
+            respond: None,
+            chunks: None,
+            raw_events: Vec::new(),
+            usage: Some(Usage {
+                input_tokens: Some(5),
+                output_tokens: Some(1),
+                ..Default::default()
+            }),
+            finish_reason: Some(FinishReason::Stop),
+            reports_stop_sequence: false,
+            error: None,
+            error_in_stream: false,
+            stalls: false,
+            capabilities: Capabilities::UNKNOWN,
+            info: ModelInfo::UNKNOWN,
+            token_factor: 1,
+            unsupported_options: &[],
+            calls: Arc::default(),
+        }
+    }
+
+    /// Answers with `text`.
+    pub(crate) fn text(self, text: impl Into<String>) -> Self {
+        self.content(LanguageModelResponseContentType::Text(text.into()))
+    }
+
+    /// Answers with `content`, such as a refusal or a tool call.
+    pub(crate) fn content(mut self, content: LanguageModelResponseContentType) -> Self {
+        self.content = content;
+        self
+    }
+
+    /// Answers with the content `respond` returns for the options of each call.
+    pub(crate) fn respond(
+        mut self,
+        respond: fn(&LanguageModelOptions) -> LanguageModelResponseContentType,
+    ) -> Self {
+        self.respond = Some(respond);
+        self
+    }
+
+    /// Streams `chunks`, one provider event per batch, instead of the response.
+    pub(crate) fn chunks(mut self, chunks: Vec<Vec<LanguageModelStreamChunk>>) -> Self {
+        self.chunks = Some(chunks);
+        self
+    }
+
+    /// Records `payloads` as the provider events of the scripted chunks, one per batch,
+    /// as provider clients do.
+    pub(crate) fn raw_events(mut self, payloads: Vec<&'static str>) -> Self {
+        self.raw_events = payloads;
+        self
+    }
+
+    /// Streams `deltas` as text, one provider event per delta, followed by the message
+    /// they make up.
+    pub(crate) fn text_deltas(self, deltas: &[&str]) -> Self {
+        let mut chunks: Vec<_> = deltas.iter().map(|delta| vec![text_delta(delta)]).collect();
+        chunks.push(vec![LanguageModelStreamChunk::Done(AssistantMessage::new(
+            LanguageModelResponseContentType::Text(deltas.concat()),
+            None,
+        ))]);
+        self.text(deltas.concat()).chunks(chunks)
+    }
+
+    /// Reports `usage` for every call.
+    pub(crate) fn usage(mut self, usage: Option<Usage>) -> Self {
+        self.usage = usage;
+        self
+    }
+
+    /// Reports `finish_reason` for every call.
+    pub(crate) fn finish_reason(mut self, finish_reason: Option<FinishReason>) -> Self {
+        self.finish_reason = finish_reason;
+        self
+    }
+
+    /// Reports the first stop sequence of each call as the one that stopped it.
+    pub(crate) fn reports_stop_sequence(mut self) -> Self {
+        self.reports_stop_sequence = true;
+        self
+    }
+
+    /// Fails every call with `error`.
+    pub(crate) fn failing(mut self, error: Error) -> Self {
+        self.error = Some(error);
+        self
+    }
+
+    /// Fails generations with `error`, and streams with `error` as their first event.
+    pub(crate) fn failing_in_stream(mut self, error: Error) -> Self {
+        self.error_in_stream = true;
+        self.failing(error)
+    }
+
+    /// Never answers generations, and leaves streams open after their chunks.
+    pub(crate) fn stalling(mut self) -> Self {
+        self.stalls = true;
+        self
+    }
+
+    /// Reports `capabilities` as the capabilities of the model.
+    pub(crate) fn capabilities(mut self, capabilities: Capabilities) -> Self {
+        self.capabilities = capabilities;
+        self
+    }
+
+    /// Reports `info` as the limits and pricing of the model.
+    pub(crate) fn info(mut self, info: ModelInfo) -> Self {
+        self.info = info;
+        self
+    }
+
+    /// Counts `factor` times the estimated tokens of each request.
+    pub(crate) fn token_factor(mut self, factor: usize) -> Self {
+        self.token_factor = factor;
+        self
+    }
+
+    /// Reports `options` as the sampling options the model does not support.
+    pub(crate) fn unsupported_options(mut self, options: &'static [SamplingOption]) -> Self {
+        self.unsupported_options = options;
+        self
+    }
+
+    /// Returns the number of calls made to the model and its clones.
+    pub(crate) fn calls(&self) -> usize {
+        self.calls.load(Ordering::SeqCst)
+    }
+
+    fn content_for(&self, options: &LanguageModelOptions) -> LanguageModelResponseContentType {
+        match self.respond {
+            Some(respond) => respond(options),
+            None => self.content.clone(),
+        }
+    }
+}
+
+fn text_delta(text: &str) -> LanguageModelStreamChunk {
+    LanguageModelStreamChunk::Delta(LanguageModelStreamChunkType::Text(text.to_string()))
+}
+
+#[async_trait::async_trait]
+impl LanguageModel for ScriptedModel {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn unsupported_options(&self) -> &'static [SamplingOption] {
+        self.unsupported_options
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        self.capabilities
+    }
+
+    fn info(&self) -> ModelInfo {
+        self.info
+    }
+
+    async fn count_tokens(&self, options: &LanguageModelOptions) -> Result<usize> {
+        Ok(tokens::estimate(options) * self.token_factor)
+    }
+
+    async fn generate_text(
+        &mut self,
+        options: LanguageModelOptions,
+    ) -> Result<LanguageModelResponse> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        if self.stalls {
+            std::future::pending::<()>().await;
+        }
+        if let Some(error) = &self.error {
+            return Err(error.clone());
+        }
+        let stop_sequence = match self.reports_stop_sequence {
+            true => options
+                .stop_sequences
+                .as_ref()
+                .and_then(|s| s.first().cloned()),
+            false => None,
+        };
+        Ok(LanguageModelResponse {
+            contents: vec![self.content_for(&options)],
+            usage: self.usage.clone(),
+            stop_sequence,
+            finish_reason: self.finish_reason.clone(),
+            logprobs: Vec::new(),
+            extensions: Default::default(),
+        })
+    }
+
+    async fn stream_text(&mut self, options: LanguageModelOptions) -> Result<ProviderStream> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        let events = match (&self.error, self.error_in_stream) {
+            (Some(error), false) => return Err(error.clone()),
+            (Some(error), true) => vec![Err(error.clone())],
+            (None, _) => match &self.chunks {
+                Some(chunks) => chunks.iter().cloned().map(Ok).collect(),
+                None => {
+                    let content = self.content_for(&options);
+                    let mut chunks = Vec::new();
+                    if let LanguageModelResponseContentType::Text(text) = &content {
+                        chunks.push(text_delta(text));
+                    }
+                    chunks.extend(self.usage.clone().map(LanguageModelStreamChunk::Usage));
+                    chunks.extend(
+                        self.finish_reason
+                            .clone()
+                            .map(LanguageModelStreamChunk::FinishReason),
+                    );
+                    chunks.push(LanguageModelStreamChunk::Done(AssistantMessage::new(
+                        content, None,
+                    )));
+                    vec![Ok(chunks)]
+                }
+            },
+        };
+        let mut payloads = self.raw_events.clone().into_iter();
+        let stream = futures::stream::iter(events).map(move |event| {
+            if let Some(payload) = payloads.next() {
+                crate::core::client::record_raw_event(payload);
+            }
+            event
+        });
+        if self.stalls {
+            return Ok(Box::pin(stream.chain(futures::stream::pending())));
+        }
+        Ok(Box::pin(stream))
+    }
+}
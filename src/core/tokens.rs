@@ -0,0 +1,417 @@
+//! Token counting for prompts.
+//!
+//! [`count_tokens`] counts the input tokens of a conversation with the tokenizer of the
+//! model it is sent to, through [`LanguageModel::count_tokens`]. Providers count as
+//! accurately as their API allows:
+//!
+//! - OpenAI models are counted locally with the tiktoken byte-pair encoding of the
+//!   model, see [`Encoding`]. The encoding is downloaded once per process, or loaded
+//!   from a file with [`Encoding::load_from`].
+//! - Anthropic models are counted by the `/messages/count_tokens` endpoint.
+//! - Gemini models are counted by the `countTokens` method.
+//!
+//! Other models fall back to [`estimate`], at four characters per token.
+//!
+//! # Example
+//!
+//! ```no_run
+//! # #[cfg(feature = "openai")]
+//! # async fn run() -> aisdk::error::Result<()> {
+//! use aisdk::core::{Message, tokens::count_tokens};
+//! use aisdk::providers::OpenAI;
+//!
+//! let messages = vec![Message::User("How many tokens is this?".into())];
+//! let tokens = count_tokens(&messages, &OpenAI::gpt_4o()).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::core::language_model::{
+    LanguageModel, LanguageModelOptions, LanguageModelResponseContentType,
+};
+use crate::core::messages::{ContentPart, Message, TaggedMessage};
+use crate::core::runtime::assume_send;
+use crate::error::{Error, Result};
+use base64::Engine;
+use parking_lot::Mutex;
+use regex::Regex;
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock};
+
+/// The tokens counted for an image, file, or other media part when the exact count
+/// depends on the provider.
+pub const MEDIA_TOKENS: usize = 1_000;
+
+/// The tokens OpenAI chat models add around every message, and once to prime the reply.
+const MESSAGE_OVERHEAD: usize = 3;
+
+/// Counts the input tokens of `messages` with the tokenizer of `model`.
+///
+/// # Errors
+///
+/// Returns an `Error` if the provider's counting request fails, or the tokenizer of
+/// the model cannot be loaded.
+pub async fn count_tokens<M: LanguageModel>(messages: &[Message], model: &M) -> Result<usize> {
+    let options = LanguageModelOptions {
+        messages: messages
+            .iter()
+            .cloned()
+            .map(TaggedMessage::initial_step_msg)
+            .collect(),
+        ..Default::default()
+    };
+    model.count_tokens(&options).await
+}
+
+/// Returns a rough estimate of the input tokens of a request, at four characters per
+/// token.
+pub fn estimate(options: &LanguageModelOptions) -> usize {
+    let system = options.system.as_ref().map_or(0, |s| s.len().div_ceil(4));
+    system
+        + options
+            .messages
+            .iter()
+            .map(|m| estimate_tokens(&m.message))
+            .sum::<usize>()
+}
+
+/// Returns a rough estimate of the tokens of a message, at four characters per token.
+///
+/// Media parts are counted as [`MEDIA_TOKENS`] each.
+pub fn estimate_tokens(message: &Message) -> usize {
+    let (text, media) = message_text(message);
+    // Every message carries a few tokens of role and formatting overhead
+    text.len().div_ceil(4) + media * MEDIA_TOKENS + 4
+}
+
+/// Returns the text of a message as the model reads it, and its number of media parts.
+fn message_text(message: &Message) -> (String, usize) {
+    match message {
+        Message::System(m) => (m.content.clone(), 0),
+        Message::User(m) => {
+            let media = m
+                .parts
+                .iter()
+                .filter(|part| !matches!(part, ContentPart::Text(_)))
+                .count();
+            (m.content.clone(), media)
+        }
+        Message::Assistant(m) => match &m.content {
+            LanguageModelResponseContentType::Text(text)
+            | LanguageModelResponseContentType::Refusal(text) => (text.clone(), 0),
+            LanguageModelResponseContentType::Reasoning { content, .. } => (content.clone(), 0),
+            LanguageModelResponseContentType::ToolCall(call) => {
+                (format!("{}{}", call.tool.name, call.input), 0)
+            }
+            LanguageModelResponseContentType::Image(_) => (String::new(), 1),
+            _ => (String::new(), 0),
+        },
+        Message::Tool(result) => match &result.output {
+            Ok(output) => (output.to_string(), result.images.len()),
+            Err(e) => (e.to_string(), result.images.len()),
+        },
+        Message::Developer(text) => (text.clone(), 0),
+    }
+}
+
+// ============================================================================
+// Section: byte-pair encoding
+// ============================================================================
+
+/// A tiktoken byte-pair encoding used by OpenAI models.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Encoding {
+    /// The encoding of GPT-4 and GPT-3.5 Turbo.
+    Cl100kBase,
+    /// The encoding of GPT-4o, GPT-4.1, GPT-5, and the o-series models.
+    O200kBase,
+}
+
+static TOKENIZERS: OnceLock<Mutex<HashMap<Encoding, Arc<BpeTokenizer>>>> = OnceLock::new();
+
+impl Encoding {
+    /// Returns the encoding of an OpenAI model, or `None` if the model is unknown.
+    pub fn for_model(model: &str) -> Option<Self> {
+        let model = model.rsplit('/').next().unwrap_or(model);
+        if ["gpt-4o", "gpt-4.1", "gpt-4.5", "gpt-5", "o1", "o3", "o4"]
+            .iter()
+            .any(|prefix| model.starts_with(prefix))
+        {
+            Some(Self::O200kBase)
+        } else if ["gpt-4", "gpt-3.5"]
+            .iter()
+            .any(|prefix| model.starts_with(prefix))
+        {
+            Some(Self::Cl100kBase)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the name of the encoding, e.g. `o200k_base`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Cl100kBase => "cl100k_base",
+            Self::O200kBase => "o200k_base",
+        }
+    }
+
+    /// Returns the URL the encoding's ranks are downloaded from.
+    pub fn url(&self) -> String {
+        format!(
+            "https://openaipublic.blob.core.windows.net/encodings/{}.tiktoken",
+            self.name()
+        )
+    }
+
+    /// Returns the pre-tokenization pattern, without the `\s+(?!\S)` alternative the
+    /// `regex` crate cannot express. [`BpeTokenizer`] applies it by hand.
+    fn pattern(&self) -> &'static str {
+        match self {
+            Self::Cl100kBase => {
+                r"(?i:'s|'t|'re|'ve|'m|'ll|'d)|[^\r\n\p{L}\p{N}]?\p{L}+|\p{N}{1,3}| ?[^\s\p{L}\p{N}]+[\r\n]*|\s*[\r\n]+|\s+"
+            }
+            Self::O200kBase => concat!(
+                r"[^\r\n\p{L}\p{N}]?[\p{Lu}\p{Lt}\p{Lm}\p{Lo}\p{M}]*[\p{Ll}\p{Lm}\p{Lo}\p{M}]+(?i:'s|'t|'re|'ve|'m|'ll|'d)?",
+                r"|[^\r\n\p{L}\p{N}]?[\p{Lu}\p{Lt}\p{Lm}\p{Lo}\p{M}]+[\p{Ll}\p{Lm}\p{Lo}\p{M}]*(?i:'s|'t|'re|'ve|'m|'ll|'d)?",
+                r"|\p{N}{1,3}| ?[^\s\p{L}\p{N}]+[\r\n/]*|\s*[\r\n]+|\s+"
+            ),
+        }
+    }
+
+    /// Returns the tokenizer of the encoding, downloading its ranks on first use.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the download fails or the ranks cannot be parsed.
+    pub async fn tokenizer(self) -> Result<Arc<BpeTokenizer>> {
+        if let Some(tokenizer) = Self::cached(self) {
+            return Ok(tokenizer);
+        }
+
+        let api_error = |e: reqwest::Error| Error::ApiError {
+            status_code: e.status(),
+            details: format!("Failed to download the {} encoding: {e}", self.name()),
+        };
+        // The response is not `Send` on wasm32
+        let data = assume_send(async {
+            crate::core::client::http_client()
+                .get(self.url())
+                .send()
+                .await?
+                .error_for_status()?
+                .text()
+                .await
+        })
+        .await
+        .map_err(api_error)?;
+        self.load_from(&data)
+    }
+
+    /// Parses the encoding's ranks from the contents of a `.tiktoken` file and caches
+    /// the tokenizer, so it is not downloaded.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidInput`] if a line is not a base64 token and its rank.
+    pub fn load_from(self, data: &str) -> Result<Arc<BpeTokenizer>> {
+        let tokenizer = Arc::new(BpeTokenizer::from_tiktoken(self, data)?);
+        TOKENIZERS
+            .get_or_init(Default::default)
+            .lock()
+            .insert(self, tokenizer.clone());
+        Ok(tokenizer)
+    }
+
+    fn cached(self) -> Option<Arc<BpeTokenizer>> {
+        TOKENIZERS.get()?.lock().get(&self).cloned()
+    }
+}
+
+/// A byte-pair tokenizer reading the `.tiktoken` rank files of OpenAI encodings.
+#[derive(Debug, Clone)]
+pub struct BpeTokenizer {
+    ranks: HashMap<Vec<u8>, u32>,
+    pattern: Regex,
+}
+
+impl BpeTokenizer {
+    /// Creates a tokenizer from the contents of a `.tiktoken` file, with one base64
+    /// token and its rank per line.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidInput`] if a line cannot be parsed.
+    pub fn from_tiktoken(encoding: Encoding, data: &str) -> Result<Self> {
+        let ranks = data
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let invalid = || Error::InvalidInput(format!("Invalid tiktoken line: {line}"));
+                let (token, rank) = line.split_once(' ').ok_or_else(invalid)?;
+                let token = base64::engine::general_purpose::STANDARD
+                    .decode(token)
+                    .map_err(|_| invalid())?;
+                let rank = rank.trim().parse().map_err(|_| invalid())?;
+                Ok((token, rank))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            ranks,
+            pattern: Regex::new(encoding.pattern()).expect("valid encoding pattern"),
+        })
+    }
+
+    /// Returns the token ids of `text`.
+    pub fn encode(&self, text: &str) -> Vec<u32> {
+        self.pieces(text)
+            .into_iter()
+            .flat_map(|piece| self.encode_piece(piece.as_bytes()))
+            .collect()
+    }
+
+    /// Returns the number of tokens of `text`.
+    pub fn count(&self, text: &str) -> usize {
+        self.encode(text).len()
+    }
+
+    /// Returns the number of input tokens of a chat request, including the tokens
+    /// OpenAI adds around every message.
+    pub fn count_request(&self, options: &LanguageModelOptions) -> usize {
+        let system = options
+            .system
+            .as_ref()
+            .map_or(0, |system| self.count(system) + MESSAGE_OVERHEAD);
+        let messages: usize = options
+            .messages
+            .iter()
+            .map(|m| {
+                let (text, media) = message_text(&m.message);
+                self.count(&text) + media * MEDIA_TOKENS + MESSAGE_OVERHEAD
+            })
+            .sum();
+        system + messages + MESSAGE_OVERHEAD
+    }
+
+    /// Splits text into the pieces that are encoded separately.
+    ///
+    /// A run of spaces followed by a word leaves its last space to the word, as the
+    /// `\s+(?!\S)` alternative of the original patterns does.
+    fn pieces<'a>(&self, text: &'a str) -> Vec<&'a str> {
+        let mut pieces = Vec::new();
+        let mut start = 0;
+        while let Some(found) = self.pattern.find_at(text, start) {
+            let mut piece = found.as_str();
+            let whitespace = piece.chars().all(char::is_whitespace)
+                && !piece.contains(['\r', '\n'])
+                && found.end() < text.len();
+            if whitespace
+                && let Some((last, _)) = piece.char_indices().last().filter(|(i, _)| *i > 0)
+            {
+                piece = &piece[..last];
+            }
+            start = found.start() + piece.len();
+            pieces.push(piece);
+        }
+        pieces
+    }
+
+    /// Merges the bytes of a piece by rank until no known pair is left.
+    fn encode_piece(&self, piece: &[u8]) -> Vec<u32> {
+        if let Some(rank) = self.ranks.get(piece) {
+            return vec![*rank];
+        }
+
+        // Boundaries of the current parts of the piece
+        let mut parts: Vec<usize> = (0..=piece.len()).collect();
+        loop {
+            let best = (0..parts.len().saturating_sub(2))
+                .filter_map(|i| {
+                    self.ranks
+                        .get(&piece[parts[i]..parts[i + 2]])
+                        .map(|rank| (*rank, i))
+                })
+                .min();
+            match best {
+                Some((_, i)) => {
+                    parts.remove(i + 1);
+                }
+                None => break,
+            }
+        }
+
+        parts
+            .windows(2)
+            .filter_map(|w| self.ranks.get(&piece[w[0]..w[1]]).copied())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base64::engine::general_purpose::STANDARD;
+
+    fn tokenizer() -> BpeTokenizer {
+        let data = ["a", "b", "c", " ", "ab", "abc", " a", " b"]
+            .iter()
+            .enumerate()
+            .map(|(rank, token)| format!("{} {rank}", STANDARD.encode(token)))
+            .collect::<Vec<_>>()
+            .join("\n");
+        BpeTokenizer::from_tiktoken(Encoding::Cl100kBase, &data).unwrap()
+    }
+
+    #[test]
+    fn test_encode_merges_by_rank() {
+        let tokenizer = tokenizer();
+        // " abc" merges "ab" before " a", leaving the space alone
+        assert_eq!(tokenizer.encode("abc abc"), vec![5, 3, 5]);
+        assert_eq!(tokenizer.count("abc abc"), 3);
+    }
+
+    #[test]
+    fn test_last_space_joins_the_next_word() {
+        let tokenizer = tokenizer();
+        assert_eq!(tokenizer.pieces("a  b"), vec!["a", " ", " b"]);
+        assert_eq!(tokenizer.encode("a  b"), vec![0, 3, 7]);
+        assert_eq!(tokenizer.pieces("a  "), vec!["a", "  "]);
+    }
+
+    #[test]
+    fn test_encoding_for_model() {
+        assert_eq!(
+            Encoding::for_model("gpt-4o-mini"),
+            Some(Encoding::O200kBase)
+        );
+        assert_eq!(
+            Encoding::for_model("openai/gpt-5"),
+            Some(Encoding::O200kBase)
+        );
+        assert_eq!(
+            Encoding::for_model("gpt-4-turbo"),
+            Some(Encoding::Cl100kBase)
+        );
+        assert_eq!(Encoding::for_model("claude-sonnet-4-0"), None);
+
+        let tokenizer = BpeTokenizer::from_tiktoken(Encoding::O200kBase, "").unwrap();
+        assert_eq!(
+            tokenizer.pieces("Hello  world's"),
+            vec!["Hello", " ", " world's"]
+        );
+    }
+
+    #[test]
+    fn test_count_request_adds_message_overhead() {
+        let tokenizer = tokenizer();
+        let options = LanguageModelOptions {
+            system: Some("ab".to_string()),
+            messages: vec![TaggedMessage::initial_step_msg(Message::User(
+                "abc abc".into(),
+            ))],
+            ..Default::default()
+        };
+        assert_eq!(tokenizer.count_request(&options), (1 + 3) + (3 + 3) + 3);
+    }
+}
@@ -62,38 +62,79 @@
 //! ```
 //!
 
+use crate::core::runtime::JoinHandle;
 use crate::error::{Error, Result};
 use crate::extensions::Extensions;
+use async_trait::async_trait;
 use derive_builder::Builder;
-use schemars::Schema;
+use futures::future::BoxFuture;
+use schemars::{JsonSchema, Schema, schema_for};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::HashMap;
 use std::fmt::Debug;
 use std::sync::{Arc, Mutex};
-use tokio::task::JoinHandle;
 
 /// A function that will be called when the tool is executed.
 pub type ToolFn = Box<dyn Fn(Value) -> std::result::Result<String, String> + Send + Sync>;
 
+/// An asynchronous function that will be called when the tool is executed.
+pub type AsyncToolFn =
+    Box<dyn Fn(Value) -> BoxFuture<'static, std::result::Result<String, String>> + Send + Sync>;
+
+#[derive(Clone)]
+enum ToolExecuteInner {
+    Sync(Arc<ToolFn>),
+    Async(Arc<AsyncToolFn>),
+}
+
 /// Holds the function that will be called when the tool is executed. the function
 /// should take a single argument of type `Value` and returns a
-/// `Result<String, String>`.
+/// `Result<String, String>`, either directly or as a future.
 #[derive(Clone)]
 pub struct ToolExecute {
-    inner: Arc<ToolFn>,
+    inner: ToolExecuteInner,
 }
 
 impl ToolExecute {
     /// Calls the tool with the given input.
+    ///
+    /// Asynchronous tools cannot be called synchronously and return an error, use
+    /// [`call_async`](Self::call_async) instead.
     pub fn call(&self, map: Value) -> Result<String> {
-        (*self.inner)(map).map_err(Error::ToolCallError)
+        match &self.inner {
+            ToolExecuteInner::Sync(f) => (**f)(map).map_err(Error::ToolCallError),
+            ToolExecuteInner::Async(_) => Err(Error::ToolCallError(
+                "Asynchronous tools must be called with call_async".to_string(),
+            )),
+        }
+    }
+
+    /// Calls the tool with the given input, awaiting it if it is asynchronous.
+    pub async fn call_async(&self, map: Value) -> Result<String> {
+        match &self.inner {
+            ToolExecuteInner::Sync(f) => (**f)(map).map_err(Error::ToolCallError),
+            ToolExecuteInner::Async(f) => (**f)(map).await.map_err(Error::ToolCallError),
+        }
     }
 
     /// Creates a new `ToolExecute` instance with the given function.
     /// The function should take a single argument of type `Value` and return a
     /// `Result<String, String>`.
     pub fn new(f: ToolFn) -> Self {
-        Self { inner: Arc::new(f) }
+        Self {
+            inner: ToolExecuteInner::Sync(Arc::new(f)),
+        }
+    }
+
+    /// Creates a new `ToolExecute` instance with the given asynchronous function.
+    /// The function should take a single argument of type `Value` and return a
+    /// future resolving to a `Result<String, String>`.
+    pub fn new_async(f: AsyncToolFn) -> Self {
+        Self {
+            inner: ToolExecuteInner::Async(Arc::new(f)),
+        }
     }
 }
 
@@ -192,11 +233,109 @@ impl Tool {
     }
 }
 
+/// A tool with a typed input and output.
+///
+/// `TypedTool` is an alternative to building a [`Tool`] by hand or with the `#[tool]`
+/// macro. The input schema is derived from [`Input`](Self::Input), the model's arguments
+/// are deserialized into it before [`execute`](Self::execute) is called, and the output
+/// is serialized back for the model: strings are sent as is, other values as JSON.
+/// Any `TypedTool` converts into a [`Tool`], so it can be registered with
+/// [`with_tool`](crate::core::language_model::request::LanguageModelRequestBuilder::with_tool)
+/// and is sent to every provider in its native tool format.
+///
+/// # Example
+/// ```
+/// use aisdk::core::tools::{Tool, TypedTool};
+/// use async_trait::async_trait;
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize, schemars::JsonSchema)]
+/// struct SumInput {
+///     a: u8,
+///     b: u8,
+/// }
+///
+/// struct Sum;
+///
+/// #[async_trait]
+/// impl TypedTool for Sum {
+///     type Input = SumInput;
+///     type Output = u16;
+///
+///     fn name(&self) -> String {
+///         "sum".to_string()
+///     }
+///
+///     fn description(&self) -> String {
+///         "Adds two numbers together.".to_string()
+///     }
+///
+///     async fn execute(&self, input: SumInput) -> Result<u16, String> {
+///         Ok(input.a as u16 + input.b as u16)
+///     }
+/// }
+///
+/// let tool: Tool = Sum.into();
+/// assert_eq!(tool.name, "sum");
+/// ```
+#[async_trait]
+pub trait TypedTool: Send + Sync + 'static {
+    /// The arguments of the tool, from which the input schema is derived.
+    type Input: JsonSchema + DeserializeOwned + Send;
+    /// The result of the tool.
+    type Output: Serialize;
+
+    /// The name of the tool.
+    fn name(&self) -> String;
+
+    /// AI friendly description of the tool.
+    fn description(&self) -> String;
+
+    /// Executes the tool with the deserialized arguments.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error message that is reported back to the model.
+    async fn execute(&self, input: Self::Input) -> std::result::Result<Self::Output, String>;
+}
+
+impl<T: TypedTool> From<T> for Tool {
+    fn from(tool: T) -> Self {
+        let name = tool.name();
+        let description = tool.description();
+        let tool = Arc::new(tool);
+        let execute = ToolExecute::new_async(Box::new(move |input| {
+            let tool = tool.clone();
+            Box::pin(async move {
+                let input: T::Input =
+                    serde_json::from_value(input).map_err(|e| format!("Invalid arguments: {e}"))?;
+                let output = tool.execute(input).await?;
+                match serde_json::to_value(output) {
+                    Ok(Value::String(text)) => Ok(text),
+                    Ok(value) => Ok(value.to_string()),
+                    Err(e) => Err(format!("Failed to serialize output: {e}")),
+                }
+            })
+        }));
+
+        Tool {
+            name,
+            description,
+            input_schema: schema_for!(T::Input),
+            execute,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 /// A list of tools.
 pub struct ToolList {
     /// The list of tools.
     pub tools: Arc<Mutex<Vec<Tool>>>,
+    /// Result policies keyed by tool name.
+    pub(crate) policies: HashMap<String, ToolResultPolicy>,
+    /// The policy used for tools without a dedicated one.
+    pub(crate) default_policy: ToolResultPolicy,
 }
 
 impl ToolList {
@@ -204,9 +343,25 @@ impl ToolList {
     pub fn new(tools: Vec<Tool>) -> Self {
         Self {
             tools: Arc::new(Mutex::new(tools)),
+            ..Default::default()
         }
     }
 
+    /// Sets the result policy for the tool with the given name.
+    pub fn set_policy(&mut self, tool_name: impl Into<String>, policy: ToolResultPolicy) {
+        self.policies.insert(tool_name.into(), policy);
+    }
+
+    /// Sets the result policy used for tools without a dedicated one.
+    pub fn set_default_policy(&mut self, policy: ToolResultPolicy) {
+        self.default_policy = policy;
+    }
+
+    /// Returns the result policy that applies to the tool with the given name.
+    pub fn policy(&self, tool_name: &str) -> &ToolResultPolicy {
+        self.policies.get(tool_name).unwrap_or(&self.default_policy)
+    }
+
     /// Adds a tool to the list.
     pub fn add_tool(&mut self, tool: Tool) {
         self.tools
@@ -218,14 +373,16 @@ impl ToolList {
     /// Executes a tool.
     pub async fn execute(&self, tool_info: ToolCallInfo) -> JoinHandle<Result<String>> {
         let tools = self.tools.clone();
-        tokio::spawn(async move {
-            let tools = tools
+        crate::core::runtime::spawn(async move {
+            let execute = tools
                 .lock()
-                .unwrap_or_else(|poisoned| poisoned.into_inner());
-            let tool = tools.iter().find(|tool| tool.name == tool_info.tool.name);
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .iter()
+                .find(|tool| tool.name == tool_info.tool.name)
+                .map(|tool| tool.execute.clone());
 
-            match tool {
-                Some(tool) => tool.execute.call(tool_info.input),
+            match execute {
+                Some(execute) => execute.call_async(tool_info.input).await,
                 None => Err(crate::error::Error::ToolCallError(
                     "Tool not found".to_string(),
                 )),
@@ -234,7 +391,25 @@ impl ToolList {
     }
 }
 
-#[derive(Default, Debug, Clone, PartialEq)]
+/// Controls whether, and which, tools the model calls.
+///
+/// The choice applies to every step of the tool execution loop, so forcing a tool
+/// call is usually combined with `max_steps` or `stop_when`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ToolChoice {
+    /// The model decides whether to call tools.
+    #[default]
+    Auto,
+    /// The model must call at least one tool.
+    Required,
+    /// The model must not call tools.
+    None,
+    /// The model must call the tool with the given name.
+    Tool(String),
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 /// Describes a tool
 pub struct ToolDetails {
     /// The name of the tool, usually a function name.
@@ -243,14 +418,24 @@ pub struct ToolDetails {
     pub id: String,
 }
 
+/// A fragment of a tool call's arguments, streamed by a provider.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct ToolCallDelta {
+    /// The details of the tool call the fragment belongs to.
+    pub tool: ToolDetails,
+    /// The partial JSON arguments.
+    pub delta: String,
+}
+
 /// Contains information necessary to call a tool
-#[derive(Default, Debug, Clone)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
 pub struct ToolCallInfo {
     /// The details of the tool to be called.
     pub tool: ToolDetails,
     /// The input parameters for the tool.
     pub input: serde_json::Value,
     /// Provider-specific extensions.
+    #[serde(default, skip_serializing_if = "Extensions::is_empty")]
     pub extensions: Extensions,
 }
 
@@ -290,13 +475,18 @@ impl ToolCallInfo {
 }
 
 /// Contains information from a tool
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ToolResultInfo {
     /// The details of the tool.
     pub tool: ToolDetails,
 
     /// The output of the tool.
+    #[serde(with = "tool_output")]
     pub output: Result<serde_json::Value>,
+
+    /// Images returned by the tool that are forwarded to the model as image parts.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub images: Vec<ToolResultImage>,
 }
 
 impl Default for ToolResultInfo {
@@ -304,6 +494,7 @@ impl Default for ToolResultInfo {
         Self {
             tool: ToolDetails::default(),
             output: Ok(serde_json::Value::Null),
+            images: Vec::new(),
         }
     }
 }
@@ -335,3 +526,519 @@ impl ToolResultInfo {
         self.output = Ok(inp);
     }
 }
+
+/// Serializes a tool output as `{"ok": value}` or `{"error": message}`.
+mod tool_output {
+    use crate::error::{Error, Result};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use serde_json::Value;
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    enum Output {
+        Ok(Value),
+        Error(String),
+    }
+
+    pub(super) fn serialize<S: Serializer>(
+        output: &Result<Value>,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        match output {
+            Ok(value) => Output::Ok(value.clone()),
+            Err(err) => Output::Error(err.to_string()),
+        }
+        .serialize(serializer)
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Result<Value>, D::Error> {
+        Ok(match Output::deserialize(deserializer)? {
+            Output::Ok(value) => Ok(value),
+            Output::Error(message) => Err(Error::ToolCallError(message)),
+        })
+    }
+}
+
+// ============================================================================
+// Section: tool result policies
+// ============================================================================
+
+/// The kind of content a tool returned, detected from its output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToolResultContentType {
+    /// Plain text.
+    Text,
+    /// A JSON object or array.
+    Json,
+    /// An image, either as a `data:image/...` URL or as raw base64.
+    Image,
+    /// Binary data, e.g. a non-image data URL or text with control characters.
+    Binary,
+}
+
+impl ToolResultContentType {
+    /// Detects the content type of a raw tool output.
+    pub fn detect(output: &str) -> Self {
+        let trimmed = output.trim();
+        if let Some(rest) = trimmed.strip_prefix("data:") {
+            return if rest.starts_with("image/") {
+                Self::Image
+            } else {
+                Self::Binary
+            };
+        }
+        if image_media_type_from_base64(trimmed).is_some() {
+            return Self::Image;
+        }
+        if (trimmed.starts_with('{') || trimmed.starts_with('['))
+            && serde_json::from_str::<Value>(trimmed).is_ok()
+        {
+            return Self::Json;
+        }
+        if output
+            .chars()
+            .any(|c| c.is_control() && !matches!(c, '\n' | '\r' | '\t'))
+        {
+            return Self::Binary;
+        }
+        Self::Text
+    }
+}
+
+/// How oversized tool output is shortened before it is sent to the model.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TruncateStrategy {
+    /// Keep the beginning of the output.
+    #[default]
+    Head,
+    /// Keep the beginning and the end of the output, dropping the middle.
+    HeadAndTail,
+    /// Replace the output with an error message.
+    Reject,
+}
+
+/// How images returned by a tool are handled.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ImageResultHandling {
+    /// Send the image to the model as text, exactly as the tool returned it.
+    #[default]
+    Inline,
+    /// Send the image as a provider image part. Providers that cannot attach images
+    /// to tool results receive only the text output.
+    ImagePart,
+    /// Replace the image with a short note.
+    Drop,
+}
+
+/// An image returned by a tool.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ToolResultImage {
+    /// The media type of the image, e.g. `image/png`.
+    pub media_type: String,
+    /// The base64 encoded image data.
+    pub data: String,
+}
+
+impl ToolResultImage {
+    /// Returns the image as a `data:` URL.
+    pub fn data_url(&self) -> String {
+        format!("data:{};base64,{}", self.media_type, self.data)
+    }
+}
+
+/// Rules the agent loop enforces on a tool result before sending it back to the model.
+///
+/// The default policy passes every result through unchanged.
+///
+/// # Example
+///
+/// ```
+/// use aisdk::core::tools::{ToolResultContentType, ToolResultPolicy, TruncateStrategy};
+///
+/// let policy = ToolResultPolicy::default()
+///     .max_bytes(4096)
+///     .truncate(TruncateStrategy::HeadAndTail)
+///     .allow(vec![ToolResultContentType::Text, ToolResultContentType::Json]);
+///
+/// let result = policy.apply("x".repeat(10_000)).unwrap();
+/// assert!(result.text.len() < 4200);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ToolResultPolicy {
+    /// Maximum size of the output in bytes. `None` means unlimited.
+    pub max_bytes: Option<usize>,
+    /// How oversized output is shortened.
+    pub truncate: TruncateStrategy,
+    /// Content types that may be sent to the model. `None` allows every type.
+    pub allowed_content_types: Option<Vec<ToolResultContentType>>,
+    /// How images are handled.
+    pub images: ImageResultHandling,
+}
+
+/// A tool result after its policy has been applied.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PolicyOutput {
+    /// The text sent to the model.
+    pub text: String,
+    /// Images sent to the model as image parts.
+    pub images: Vec<ToolResultImage>,
+}
+
+impl ToolResultPolicy {
+    /// Sets the maximum output size in bytes.
+    pub fn max_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_bytes = Some(max_bytes);
+        self
+    }
+
+    /// Sets the truncate strategy.
+    pub fn truncate(mut self, strategy: TruncateStrategy) -> Self {
+        self.truncate = strategy;
+        self
+    }
+
+    /// Restricts the content types that may be sent to the model.
+    pub fn allow(mut self, content_types: impl Into<Vec<ToolResultContentType>>) -> Self {
+        self.allowed_content_types = Some(content_types.into());
+        self
+    }
+
+    /// Sets how images are handled.
+    pub fn images(mut self, handling: ImageResultHandling) -> Self {
+        self.images = handling;
+        self
+    }
+
+    /// Applies the policy to a raw tool output.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::ToolCallError` if the content type is not allowed or the
+    /// output is too large and the strategy is [`TruncateStrategy::Reject`].
+    pub fn apply(&self, output: String) -> Result<PolicyOutput> {
+        let content_type = ToolResultContentType::detect(&output);
+
+        if let Some(allowed) = &self.allowed_content_types
+            && !allowed.contains(&content_type)
+        {
+            return Err(Error::ToolCallError(format!(
+                "Tool result of type {content_type:?} is not allowed"
+            )));
+        }
+
+        if content_type == ToolResultContentType::Image {
+            let oversized = self.max_bytes.is_some_and(|max| output.len() > max);
+            match self.images {
+                ImageResultHandling::Drop => {
+                    return Ok(PolicyOutput {
+                        text: format!("[image omitted: {} bytes]", output.len()),
+                        images: vec![],
+                    });
+                }
+                ImageResultHandling::ImagePart if !oversized => {
+                    if let Some(image) = parse_image(&output) {
+                        return Ok(PolicyOutput {
+                            text: "[image attached]".to_string(),
+                            images: vec![image],
+                        });
+                    }
+                }
+                // Truncating an encoded image only corrupts it.
+                _ if oversized => {
+                    return Ok(PolicyOutput {
+                        text: format!("[image omitted: {} bytes exceeds limit]", output.len()),
+                        images: vec![],
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        let text = match self.max_bytes {
+            Some(max) if output.len() > max => match self.truncate {
+                TruncateStrategy::Head => {
+                    let head = floor_char_boundary(&output, max);
+                    format!(
+                        "{}\n[truncated {} bytes]",
+                        &output[..head],
+                        output.len() - head
+                    )
+                }
+                TruncateStrategy::HeadAndTail => {
+                    let head = floor_char_boundary(&output, max / 2);
+                    let tail = ceil_char_boundary(&output, output.len() - (max - max / 2));
+                    format!(
+                        "{}\n[truncated {} bytes]\n{}",
+                        &output[..head],
+                        tail - head,
+                        &output[tail..]
+                    )
+                }
+                TruncateStrategy::Reject => {
+                    return Err(Error::ToolCallError(format!(
+                        "Tool result of {} bytes exceeds the limit of {max} bytes",
+                        output.len()
+                    )));
+                }
+            },
+            _ => output,
+        };
+
+        Ok(PolicyOutput {
+            text,
+            images: vec![],
+        })
+    }
+}
+
+/// Detects the image media type of raw base64 data from its magic bytes.
+fn image_media_type_from_base64(data: &str) -> Option<&'static str> {
+    const SIGNATURES: [(&str, &str); 4] = [
+        ("iVBORw0KGgo", "image/png"),
+        ("/9j/", "image/jpeg"),
+        ("R0lGOD", "image/gif"),
+        ("UklGR", "image/webp"),
+    ];
+    if data.len() < 16 || data.contains(char::is_whitespace) {
+        return None;
+    }
+    SIGNATURES
+        .iter()
+        .find(|(prefix, _)| data.starts_with(prefix))
+        .map(|(_, media_type)| *media_type)
+}
+
+/// Parses an image tool output into its media type and base64 data.
+fn parse_image(output: &str) -> Option<ToolResultImage> {
+    let trimmed = output.trim();
+    if let Some(rest) = trimmed.strip_prefix("data:") {
+        let (media_type, data) = rest.split_once(";base64,")?;
+        return Some(ToolResultImage {
+            media_type: media_type.to_string(),
+            data: data.to_string(),
+        });
+    }
+    image_media_type_from_base64(trimmed).map(|media_type| ToolResultImage {
+        media_type: media_type.to_string(),
+        data: trimmed.to_string(),
+    })
+}
+
+fn floor_char_boundary(s: &str, mut index: usize) -> usize {
+    while !s.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+fn ceil_char_boundary(s: &str, mut index: usize) -> usize {
+    while !s.is_char_boundary(index) {
+        index += 1;
+    }
+    index
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PNG: &str = "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mNk";
+
+    #[test]
+    fn test_detect_content_types() {
+        assert_eq!(
+            ToolResultContentType::detect("hello"),
+            ToolResultContentType::Text
+        );
+        assert_eq!(
+            ToolResultContentType::detect(r#"{"a": 1}"#),
+            ToolResultContentType::Json
+        );
+        assert_eq!(
+            ToolResultContentType::detect("data:image/png;base64,AAAA"),
+            ToolResultContentType::Image
+        );
+        assert_eq!(
+            ToolResultContentType::detect(PNG),
+            ToolResultContentType::Image
+        );
+        assert_eq!(
+            ToolResultContentType::detect("data:application/pdf;base64,AAAA"),
+            ToolResultContentType::Binary
+        );
+        assert_eq!(
+            ToolResultContentType::detect("a\u{0}b"),
+            ToolResultContentType::Binary
+        );
+    }
+
+    #[test]
+    fn test_default_policy_passes_through() {
+        let output = ToolResultPolicy::default().apply(PNG.to_string()).unwrap();
+        assert_eq!(output.text, PNG);
+        assert!(output.images.is_empty());
+    }
+
+    #[test]
+    fn test_policy_truncates_head() {
+        let policy = ToolResultPolicy::default().max_bytes(5);
+        let output = policy.apply("hello world".to_string()).unwrap();
+        assert_eq!(output.text, "hello\n[truncated 6 bytes]");
+    }
+
+    #[test]
+    fn test_policy_truncates_head_and_tail() {
+        let policy = ToolResultPolicy::default()
+            .max_bytes(4)
+            .truncate(TruncateStrategy::HeadAndTail);
+        let output = policy.apply("abcdefgh".to_string()).unwrap();
+        assert_eq!(output.text, "ab\n[truncated 4 bytes]\ngh");
+    }
+
+    #[test]
+    fn test_policy_truncates_on_char_boundary() {
+        let policy = ToolResultPolicy::default().max_bytes(3);
+        let output = policy.apply("héllo".to_string()).unwrap();
+        assert!(output.text.starts_with("hé\n"));
+    }
+
+    #[test]
+    fn test_policy_rejects_oversized_output() {
+        let policy = ToolResultPolicy::default()
+            .max_bytes(2)
+            .truncate(TruncateStrategy::Reject);
+        assert!(policy.apply("abc".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_policy_rejects_disallowed_content_type() {
+        let policy = ToolResultPolicy::default().allow(vec![ToolResultContentType::Text]);
+        assert!(policy.apply(r#"{"a": 1}"#.to_string()).is_err());
+        assert!(policy.apply("plain".to_string()).is_ok());
+    }
+
+    #[test]
+    fn test_policy_converts_image_to_part() {
+        let policy = ToolResultPolicy::default().images(ImageResultHandling::ImagePart);
+        let output = policy
+            .apply(format!("data:image/png;base64,{PNG}"))
+            .unwrap();
+        assert_eq!(output.text, "[image attached]");
+        assert_eq!(
+            output.images,
+            vec![ToolResultImage {
+                media_type: "image/png".to_string(),
+                data: PNG.to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_policy_drops_images() {
+        let policy = ToolResultPolicy::default().images(ImageResultHandling::Drop);
+        let output = policy.apply(PNG.to_string()).unwrap();
+        assert!(output.text.starts_with("[image omitted"));
+        assert!(output.images.is_empty());
+    }
+
+    #[test]
+    fn test_policy_never_truncates_images() {
+        let policy = ToolResultPolicy::default().max_bytes(8);
+        let output = policy.apply(PNG.to_string()).unwrap();
+        assert!(output.text.starts_with("[image omitted"));
+    }
+
+    #[test]
+    fn test_tool_list_policy_lookup() {
+        let mut list = ToolList::default();
+        let special = ToolResultPolicy::default().max_bytes(10);
+        list.set_policy("special", special.clone());
+        assert_eq!(list.policy("special"), &special);
+        assert_eq!(list.policy("other"), &ToolResultPolicy::default());
+    }
+
+    #[derive(Deserialize, JsonSchema)]
+    struct SumInput {
+        a: u8,
+        b: u8,
+    }
+
+    #[derive(Serialize)]
+    struct SumOutput {
+        total: u16,
+    }
+
+    struct Sum;
+
+    #[async_trait]
+    impl TypedTool for Sum {
+        type Input = SumInput;
+        type Output = SumOutput;
+
+        fn name(&self) -> String {
+            "sum".to_string()
+        }
+
+        fn description(&self) -> String {
+            "Adds two numbers together.".to_string()
+        }
+
+        async fn execute(&self, input: SumInput) -> std::result::Result<SumOutput, String> {
+            tokio::task::yield_now().await;
+            Ok(SumOutput {
+                total: input.a as u16 + input.b as u16,
+            })
+        }
+    }
+
+    fn sum_call(input: Value) -> ToolCallInfo {
+        let mut info = ToolCallInfo::new("sum");
+        info.input(input);
+        info
+    }
+
+    #[test]
+    fn test_typed_tool_into_tool() {
+        let tool: Tool = Sum.into();
+        assert_eq!(tool.name, "sum");
+        assert_eq!(tool.description, "Adds two numbers together.");
+        assert_eq!(tool.input_schema, schema_for!(SumInput));
+    }
+
+    #[tokio::test]
+    async fn test_typed_tool_executes_with_typed_input() {
+        let list = ToolList::new(vec![Sum.into()]);
+        let output = list
+            .execute(sum_call(serde_json::json!({ "a": 2, "b": 3 })))
+            .await
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(output, r#"{"total":5}"#);
+    }
+
+    #[tokio::test]
+    async fn test_typed_tool_rejects_invalid_input() {
+        let list = ToolList::new(vec![Sum.into()]);
+        let output = list
+            .execute(sum_call(serde_json::json!({ "a": "two" })))
+            .await
+            .await
+            .unwrap();
+        assert!(
+            matches!(output, Err(Error::ToolCallError(msg)) if msg.starts_with("Invalid arguments"))
+        );
+    }
+
+    #[test]
+    fn test_sync_call_rejects_async_tool() {
+        let tool: Tool = Sum.into();
+        assert!(
+            tool.execute
+                .call(serde_json::json!({ "a": 1, "b": 1 }))
+                .is_err()
+        );
+    }
+}
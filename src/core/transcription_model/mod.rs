@@ -0,0 +1,114 @@
+//! Transcription model
+//!
+//! A transcription model converts spoken audio into text, along with timing
+//! information for the words and segments of the transcript.
+
+use crate::error::Result;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use derive_builder::Builder;
+
+/// The audio to transcribe.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TranscriptionAudio {
+    /// Encoded audio, e.g. the contents of an mp3 or wav file.
+    Bytes {
+        /// The encoded audio.
+        data: Vec<u8>,
+        /// The media type of the audio, e.g. `audio/wav`. Providers detect the format
+        /// when unset.
+        media_type: Option<String>,
+    },
+    /// A URL the provider downloads the audio from.
+    Url(String),
+}
+
+impl TranscriptionAudio {
+    /// Creates audio from encoded bytes, leaving the format for the provider to detect.
+    pub fn bytes(data: impl Into<Vec<u8>>) -> Self {
+        Self::Bytes {
+            data: data.into(),
+            media_type: None,
+        }
+    }
+
+    /// Creates audio the provider downloads from `url`.
+    pub fn url(url: impl Into<String>) -> Self {
+        Self::Url(url.into())
+    }
+}
+
+/// The options for transcription requests.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(into))]
+pub struct TranscriptionModelOptions {
+    /// The audio to transcribe.
+    pub audio: TranscriptionAudio,
+    /// The language spoken in the audio, e.g. `en`. Providers that support it detect
+    /// the language when unset.
+    #[builder(default)]
+    pub language: Option<String>,
+}
+
+impl TranscriptionModelOptions {
+    /// Returns the transcription options builder.
+    pub fn builder() -> TranscriptionModelOptionsBuilder {
+        TranscriptionModelOptionsBuilder::default()
+    }
+}
+
+/// The core trait abstracting the capabilities of a transcription model.
+#[async_trait]
+pub trait TranscriptionModel: Clone + Send + Sync + std::fmt::Debug + 'static {
+    /// Transcribes the audio into text.
+    async fn transcribe(
+        &self,
+        input: TranscriptionModelOptions,
+    ) -> Result<TranscriptionModelResponse>;
+}
+
+/// A transcribed word.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TranscriptionWord {
+    /// The word as spoken, including punctuation if the provider adds it.
+    pub word: String,
+    /// The time the word starts at, in seconds from the start of the audio.
+    pub start: f32,
+    /// The time the word ends at, in seconds from the start of the audio.
+    pub end: f32,
+    /// The confidence in the word, from 0 to 1, if the provider reports it.
+    pub confidence: Option<f32>,
+    /// The speaker of the word, if the provider separates speakers.
+    pub speaker: Option<usize>,
+}
+
+/// A transcribed segment, e.g. a sentence or an utterance of one speaker.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TranscriptionSegment {
+    /// The text of the segment.
+    pub text: String,
+    /// The time the segment starts at, in seconds from the start of the audio.
+    pub start: f32,
+    /// The time the segment ends at, in seconds from the start of the audio.
+    pub end: f32,
+    /// The speaker of the segment, if the provider separates speakers.
+    pub speaker: Option<usize>,
+}
+
+/// The response type for transcription requests.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TranscriptionModelResponse {
+    /// The transcript of the audio.
+    pub text: String,
+    /// The language spoken in the audio, if known.
+    pub language: Option<String>,
+    /// The duration of the audio in seconds, if known.
+    pub duration: Option<f32>,
+    /// The confidence in the transcript, from 0 to 1, if the provider reports it.
+    pub confidence: Option<f32>,
+    /// The segments of the transcript, if the provider returns them.
+    pub segments: Vec<TranscriptionSegment>,
+    /// The words of the transcript, if the provider returns them.
+    pub words: Vec<TranscriptionWord>,
+}
@@ -2,10 +2,9 @@
 
 use reqwest::{IntoUrl, Url};
 
-use crate::{
-    Error, Result,
-    core::{Message, language_model::LanguageModelOptions, messages::TaggedMessage},
-};
+#[cfg(feature = "language-model-request")]
+use crate::core::{Message, messages::TaggedMessage};
+use crate::{Error, Result, core::language_model::LanguageModelOptions};
 
 /// Creates a hook that returns `true` if the number of conversation steps exceeds the given count.
 ///
@@ -52,6 +51,7 @@ pub fn step_count_is(step: usize) -> impl Fn(&LanguageModelOptions) -> bool {
 /// This function takes a prompt and a list of messages and returns a vector of
 /// messages that can be used for LanguageModelCallOptions.
 /// if no messages are provided, a default message is created with the prompt and system prompt.
+#[cfg(feature = "language-model-request")]
 pub(crate) fn resolve_message(
     options: &LanguageModelOptions,
     prompt: &Option<String>,
@@ -153,6 +153,103 @@ pub(crate) fn join_url(base_url: impl IntoUrl, path: &str) -> Result<Url> {
         .map_err(|_| Error::InvalidInput("Failed to join base URL and path".into()))
 }
 
+/// A `multipart/form-data` body, encoded up front so it can be resent on retries.
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub(crate) struct MultipartForm {
+    boundary: String,
+    body: Vec<u8>,
+}
+
+#[allow(dead_code)]
+impl MultipartForm {
+    pub(crate) fn new() -> Self {
+        Self {
+            boundary: format!("aisdk-{}", uuid::Uuid::new_v4().simple()),
+            body: Vec::new(),
+        }
+    }
+
+    /// Adds a text field.
+    pub(crate) fn text(mut self, name: &str, value: impl AsRef<str>) -> Self {
+        self.part_header(name, None, None);
+        self.body.extend_from_slice(value.as_ref().as_bytes());
+        self.body.extend_from_slice(b"\r\n");
+        self
+    }
+
+    /// Adds a file field.
+    pub(crate) fn file(
+        mut self,
+        name: &str,
+        filename: &str,
+        media_type: &str,
+        data: &[u8],
+    ) -> Self {
+        self.part_header(name, Some(filename), Some(media_type));
+        self.body.extend_from_slice(data);
+        self.body.extend_from_slice(b"\r\n");
+        self
+    }
+
+    /// Returns the `Content-Type` header value, which carries the boundary.
+    pub(crate) fn content_type(&self) -> String {
+        format!("multipart/form-data; boundary={}", self.boundary)
+    }
+
+    /// Returns the encoded body.
+    pub(crate) fn finish(mut self) -> Vec<u8> {
+        self.body
+            .extend_from_slice(format!("--{}--\r\n", self.boundary).as_bytes());
+        self.body
+    }
+
+    fn part_header(&mut self, name: &str, filename: Option<&str>, media_type: Option<&str>) {
+        let mut header = format!(
+            "--{}\r\nContent-Disposition: form-data; name=\"{}\"",
+            self.boundary,
+            escape_quotes(name)
+        );
+        if let Some(filename) = filename {
+            header.push_str(&format!("; filename=\"{}\"", escape_quotes(filename)));
+        }
+        header.push_str("\r\n");
+        if let Some(media_type) = media_type {
+            header.push_str(&format!("Content-Type: {media_type}\r\n"));
+        }
+        header.push_str("\r\n");
+        self.body.extend_from_slice(header.as_bytes());
+    }
+}
+
+fn escape_quotes(value: &str) -> String {
+    value.replace('"', "%22").replace(['\r', '\n'], " ")
+}
+
+/// Returns the name of the provider of a model of type `M`, i.e. the module of
+/// [`providers`](crate::providers) it is defined in, or its lowercase type name.
+#[cfg(any(feature = "otel", feature = "metrics"))]
+pub(crate) fn provider_name<M>() -> String {
+    let type_name = std::any::type_name::<M>();
+    let path = type_name.split('<').next().unwrap_or(type_name);
+    let mut segments = path.split("::");
+    match segments.by_ref().position(|segment| segment == "providers") {
+        Some(_) => segments.next().unwrap_or(path).to_string(),
+        None => path.rsplit("::").next().unwrap_or(path).to_lowercase(),
+    }
+}
+
+/// Returns the name of the variant of `error`.
+#[cfg(any(feature = "otel", feature = "metrics"))]
+pub(crate) fn error_type(error: &Error) -> String {
+    let debug = format!("{error:?}");
+    debug
+        .split(['(', ' ', '{'])
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -195,4 +292,50 @@ mod tests {
         let url = join_url("https://api.example.com/v1/", "/chat/completions").unwrap();
         assert_eq!(url.as_str(), "https://api.example.com/v1/chat/completions");
     }
+
+    #[test]
+    fn test_multipart_form() {
+        let form = MultipartForm::new().text("prompt", "a cat").file(
+            "image",
+            "cat.png",
+            "image/png",
+            b"PNG",
+        );
+        let boundary = form.boundary.clone();
+        assert_eq!(
+            form.content_type(),
+            format!("multipart/form-data; boundary={boundary}")
+        );
+
+        let body = String::from_utf8(form.finish()).unwrap();
+        assert_eq!(
+            body,
+            format!(
+                "--{boundary}\r\nContent-Disposition: form-data; name=\"prompt\"\r\n\r\na cat\r\n\
+                 --{boundary}\r\nContent-Disposition: form-data; name=\"image\"; filename=\"cat.png\"\r\nContent-Type: image/png\r\n\r\nPNG\r\n\
+                 --{boundary}--\r\n"
+            )
+        );
+    }
+
+    #[cfg(any(feature = "otel", feature = "metrics"))]
+    mod providers {
+        pub mod acme {
+            pub struct Acme<T>(T);
+        }
+    }
+
+    #[cfg(any(feature = "otel", feature = "metrics"))]
+    #[test]
+    fn test_provider_name() {
+        assert_eq!(provider_name::<providers::acme::Acme<String>>(), "acme");
+        assert_eq!(provider_name::<MultipartForm>(), "multipartform");
+    }
+
+    #[cfg(any(feature = "otel", feature = "metrics"))]
+    #[test]
+    fn test_error_type() {
+        assert_eq!(error_type(&Error::Other("boom".to_string())), "Other");
+        assert_eq!(error_type(&Error::Cancelled), "Cancelled");
+    }
 }
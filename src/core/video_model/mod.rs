@@ -0,0 +1,243 @@
+//! Video model
+//!
+//! A video model generates a video from a text prompt. Generation takes minutes, so it
+//! runs as a job: the request is submitted, the job is polled until it settles, and
+//! the finished video is then downloaded through [`VideoModel::download_video`].
+
+use crate::core::image_model::{AspectRatio, ImageSize};
+use crate::error::{Error, Result};
+use async_trait::async_trait;
+use futures::Stream;
+use serde::{Deserialize, Serialize};
+use std::pin::Pin;
+use std::time::Duration;
+
+use derive_builder::Builder;
+
+/// The options for video generation requests.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(into))]
+pub struct VideoModelOptions {
+    /// A description of the video to generate.
+    pub prompt: String,
+    /// A description of what the video should not contain.
+    #[builder(default)]
+    pub negative_prompt: Option<String>,
+    /// The aspect ratio of the video, for providers that take an aspect ratio.
+    #[builder(default)]
+    pub aspect_ratio: Option<AspectRatio>,
+    /// The resolution of the video, for providers that take exact dimensions.
+    #[builder(default)]
+    pub size: Option<ImageSize>,
+    /// The length of the video in seconds. Accepted values are provider specific.
+    #[builder(default)]
+    pub duration: Option<u32>,
+    /// The seed of the generation, to reproduce a video.
+    #[builder(default)]
+    pub seed: Option<u64>,
+}
+
+impl VideoModelOptions {
+    /// Returns the video options builder.
+    pub fn builder() -> VideoModelOptionsBuilder {
+        VideoModelOptionsBuilder::default()
+    }
+}
+
+/// The status of a video generation job.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VideoJobStatus {
+    /// The job waits to be started.
+    Queued,
+    /// The video is being generated.
+    InProgress,
+    /// The video is ready to be downloaded.
+    Completed,
+    /// The job failed, see [`VideoJob::error`].
+    Failed,
+}
+
+/// A generated video, downloaded with [`VideoModel::download_video`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GeneratedVideo {
+    /// The URL the video is downloaded from. It usually requires the provider's
+    /// credentials.
+    pub url: String,
+    /// The media type of the video, e.g. `video/mp4`, if known.
+    pub media_type: Option<String>,
+}
+
+/// The state of a video generation job.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VideoJob {
+    /// The provider's identifier of the job.
+    pub id: String,
+    /// The status of the job.
+    pub status: VideoJobStatus,
+    /// The completed share of the job, from 0 to 100, if the provider reports it.
+    pub progress: Option<f32>,
+    /// The reason the job failed.
+    pub error: Option<String>,
+    /// The generated videos, once the job completed.
+    pub videos: Vec<GeneratedVideo>,
+}
+
+impl VideoJob {
+    /// Returns true once the job completed or failed.
+    pub fn is_settled(&self) -> bool {
+        matches!(
+            self.status,
+            VideoJobStatus::Completed | VideoJobStatus::Failed
+        )
+    }
+}
+
+/// A stream of the states of a job, ending with its settled state.
+pub type VideoJobStream = Pin<Box<dyn Stream<Item = Result<VideoJob>> + Send>>;
+
+/// The core trait abstracting the capabilities of a video generation model.
+#[async_trait]
+pub trait VideoModel: Clone + Send + Sync + std::fmt::Debug + 'static {
+    /// Submits a generation job and returns it in its initial state.
+    async fn submit_video(&self, input: VideoModelOptions) -> Result<VideoJob>;
+
+    /// Returns the current state of a job.
+    async fn poll_video(&self, job: &VideoJob) -> Result<VideoJob>;
+
+    /// Downloads a generated video and returns the encoded video.
+    async fn download_video(&self, video: &GeneratedVideo) -> Result<Vec<u8>>;
+
+    /// The time waited between two polls of a pending job. Defaults to ten seconds.
+    fn poll_interval(&self) -> Duration {
+        Duration::from_secs(10)
+    }
+
+    /// Submits a generation job and streams its state after every poll, until it
+    /// settles.
+    async fn stream_video(&self, input: VideoModelOptions) -> Result<VideoJobStream> {
+        let job = self.submit_video(input).await?;
+        let model = self.clone();
+
+        let stream = futures::stream::unfold(Some(job), move |job| {
+            let model = model.clone();
+            async move {
+                let job = job?;
+                if job.is_settled() {
+                    return None;
+                }
+                crate::core::runtime::sleep(model.poll_interval()).await;
+                match model.poll_video(&job).await {
+                    Ok(next) => Some((Ok(next.clone()), Some(next))),
+                    Err(e) => Some((Err(e), None)),
+                }
+            }
+        });
+
+        Ok(Box::pin(stream))
+    }
+
+    /// Submits a generation job and waits until it completes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if a request fails or the job fails.
+    async fn generate_video(&self, input: VideoModelOptions) -> Result<VideoJob> {
+        let mut job = self.submit_video(input).await?;
+        while !job.is_settled() {
+            crate::core::runtime::sleep(self.poll_interval()).await;
+            job = self.poll_video(&job).await?;
+        }
+
+        match job.status {
+            VideoJobStatus::Failed => Err(Error::Other(format!(
+                "Video job {} failed: {}",
+                job.id,
+                job.error.as_deref().unwrap_or("unknown error")
+            ))),
+            _ => Ok(job),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+    use std::sync::{Arc, Mutex};
+
+    /// Completes a job after two polls.
+    #[derive(Debug, Clone, Default)]
+    struct CountingModel {
+        polls: Arc<Mutex<u32>>,
+    }
+
+    #[async_trait]
+    impl VideoModel for CountingModel {
+        async fn submit_video(&self, _input: VideoModelOptions) -> Result<VideoJob> {
+            Ok(VideoJob {
+                id: "job".to_string(),
+                status: VideoJobStatus::Queued,
+                progress: None,
+                error: None,
+                videos: Vec::new(),
+            })
+        }
+
+        async fn poll_video(&self, job: &VideoJob) -> Result<VideoJob> {
+            let mut polls = self.polls.lock().unwrap();
+            *polls += 1;
+            let mut job = job.clone();
+            if *polls < 2 {
+                job.status = VideoJobStatus::InProgress;
+                job.progress = Some(50.0);
+            } else {
+                job.status = VideoJobStatus::Completed;
+                job.videos.push(GeneratedVideo {
+                    url: "https://example.com/video.mp4".to_string(),
+                    media_type: Some("video/mp4".to_string()),
+                });
+            }
+            Ok(job)
+        }
+
+        async fn download_video(&self, _video: &GeneratedVideo) -> Result<Vec<u8>> {
+            Ok(Vec::new())
+        }
+
+        fn poll_interval(&self) -> Duration {
+            Duration::ZERO
+        }
+    }
+
+    fn options() -> VideoModelOptions {
+        VideoModelOptions::builder()
+            .prompt("A cat surfing")
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_stream_video_ends_when_settled() {
+        let stream = CountingModel::default()
+            .stream_video(options())
+            .await
+            .unwrap();
+        let states: Vec<_> = stream.map(|job| job.unwrap().status).collect().await;
+
+        assert_eq!(
+            states,
+            vec![VideoJobStatus::InProgress, VideoJobStatus::Completed]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_generate_video_waits_for_completion() {
+        let job = CountingModel::default()
+            .generate_video(options())
+            .await
+            .unwrap();
+
+        assert_eq!(job.status, VideoJobStatus::Completed);
+        assert_eq!(job.videos.len(), 1);
+    }
+}
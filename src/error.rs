@@ -1,5 +1,10 @@
 //! Defines the core error and result types for the SDK.
 //!
+//! Failed provider responses are classified from the provider's error body, so callers
+//! can branch on [`Error::RateLimited`], [`Error::AuthenticationFailed`],
+//! [`Error::ModelNotFound`], [`Error::ContextLengthExceeded`], [`Error::ContentFiltered`]
+//! and [`Error::Overloaded`]. Other failures are returned as [`Error::ApiError`].
+//!
 //! # Examples
 //!
 //! ```
@@ -46,6 +51,64 @@ pub enum Error {
         status_code: Option<reqwest::StatusCode>,
     },
 
+    /// The provider rejected the request because a rate limit or quota was reached.
+    #[error("Rate limited (retry after {retry_after:?}): {details}")]
+    RateLimited {
+        /// How long the provider asked to wait before retrying, if it said.
+        retry_after: Option<std::time::Duration>,
+        /// The error message of the provider.
+        details: String,
+        /// The HTTP status code of the response, if the error came from one.
+        status_code: Option<reqwest::StatusCode>,
+        /// The rate limits reported in the headers of the response, if any.
+        rate_limit: Option<Box<crate::core::rate_limit::RateLimitInfo>>,
+    },
+
+    /// The provider rejected the API key or credentials of the request.
+    #[error("Authentication failed: {details}")]
+    AuthenticationFailed {
+        /// The error message of the provider.
+        details: String,
+        /// The HTTP status code of the response, if the error came from one.
+        status_code: Option<reqwest::StatusCode>,
+    },
+
+    /// The provider does not know the requested model, or the key cannot access it.
+    #[error("Model not found: {details}")]
+    ModelNotFound {
+        /// The error message of the provider.
+        details: String,
+        /// The HTTP status code of the response, if the error came from one.
+        status_code: Option<reqwest::StatusCode>,
+    },
+
+    /// The request does not fit in the context window of the model.
+    #[error("Context length exceeded: {details}")]
+    ContextLengthExceeded {
+        /// The error message of the provider.
+        details: String,
+        /// The HTTP status code of the response, if the error came from one.
+        status_code: Option<reqwest::StatusCode>,
+    },
+
+    /// The provider refused the request or its response with a content filter.
+    #[error("Content filtered: {details}")]
+    ContentFiltered {
+        /// The error message of the provider.
+        details: String,
+        /// The HTTP status code of the response, if the error came from one.
+        status_code: Option<reqwest::StatusCode>,
+    },
+
+    /// The provider is temporarily overloaded.
+    #[error("Provider overloaded: {details}")]
+    Overloaded {
+        /// The error message of the provider.
+        details: String,
+        /// The HTTP status code of the response, if the error came from one.
+        status_code: Option<reqwest::StatusCode>,
+    },
+
     /// An error for invalid input.
     #[error("Invalid input: {0}")]
     InvalidInput(String),
@@ -59,6 +122,30 @@ pub enum Error {
     #[error("Prompt error: {0}")]
     PromptError(String),
 
+    /// An error raised by an input or output guard.
+    #[error("Guard error: {0}")]
+    GuardError(String),
+
+    /// An error raised when a budget limit has been reached.
+    #[error("Budget exceeded: {0}")]
+    BudgetExceeded(String),
+
+    /// An error raised when a structured object could not be generated.
+    #[error("Object generation error: {0}")]
+    ObjectGenerationError(String),
+
+    /// An error raised when a request was cancelled through its cancellation token.
+    #[error("Request cancelled")]
+    Cancelled,
+
+    /// An error raised when a request did not complete within its timeout.
+    #[error("Request timed out after {0:?}")]
+    Timeout(std::time::Duration),
+
+    /// An error raised when a provider stream sent no event within its idle timeout.
+    #[error("Stream stalled: no event for {0:?}")]
+    StreamStalled(std::time::Duration),
+
     /// A catch-all for other miscellaneous errors.
     #[error("AI SDK error: {0}")]
     Other(String),
@@ -68,6 +155,22 @@ pub enum Error {
     ProviderError(Arc<dyn ProviderError>),
 }
 
+impl Error {
+    /// Returns the HTTP status code of the response the error came from, if any.
+    pub fn status_code(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Error::ApiError { status_code, .. }
+            | Error::RateLimited { status_code, .. }
+            | Error::AuthenticationFailed { status_code, .. }
+            | Error::ModelNotFound { status_code, .. }
+            | Error::ContextLengthExceeded { status_code, .. }
+            | Error::ContentFiltered { status_code, .. }
+            | Error::Overloaded { status_code, .. } => *status_code,
+            _ => None,
+        }
+    }
+}
+
 /// Implements `From` for `UninitializedFieldError` to convert it to `Error`.
 /// Mainly used for the `derive_builder` crate.
 impl From<UninitializedFieldError> for Error {
@@ -86,11 +189,31 @@ impl From<Error> for String {
             } => {
                 format!("API error: {status_code:?} - {details}")
             }
+            Error::RateLimited {
+                retry_after,
+                details,
+                ..
+            } => format!("Rate limited (retry after {retry_after:?}): {details}"),
+            Error::AuthenticationFailed { details, .. } => {
+                format!("Authentication failed: {details}")
+            }
+            Error::ModelNotFound { details, .. } => format!("Model not found: {details}"),
+            Error::ContextLengthExceeded { details, .. } => {
+                format!("Context length exceeded: {details}")
+            }
+            Error::ContentFiltered { details, .. } => format!("Content filtered: {details}"),
+            Error::Overloaded { details, .. } => format!("Provider overloaded: {details}"),
             Error::InvalidInput(error) => format!("Invalid input: {error}"),
             Error::ToolCallError(error) => format!("Tool error: {error}"),
             Error::Other(error) => format!("Other error: {error}"),
             Error::ProviderError(error) => format!("Provider error: {error}"),
             Error::PromptError(error) => format!("Prompt error: {error}"),
+            Error::GuardError(error) => format!("Guard error: {error}"),
+            Error::BudgetExceeded(error) => format!("Budget exceeded: {error}"),
+            Error::ObjectGenerationError(error) => format!("Object generation error: {error}"),
+            Error::Cancelled => "Request cancelled".to_string(),
+            Error::Timeout(timeout) => format!("Request timed out after {timeout:?}"),
+            Error::StreamStalled(idle) => format!("Stream stalled: no event for {idle:?}"),
         }
     }
 }
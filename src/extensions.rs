@@ -1,50 +1,127 @@
 //! Extensions are a way to extend the capabilities of `aisdk`.
 //! They are used to attach provider-specific information to core SDK structures without polluting the core API.
+//!
+//! Extensions serialize to a JSON object keyed by [`Extension::NAME`], so conversations
+//! carrying provider metadata, such as thinking signatures, survive being persisted and
+//! reloaded.
 
 use parking_lot::{
     MappedRwLockReadGuard, MappedRwLockWriteGuard, RwLock, RwLockReadGuard, RwLockWriteGuard,
 };
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Map, Value};
 use std::any::{Any, TypeId};
 use std::collections::HashMap;
 use std::sync::Arc;
 
+/// A value that can be stored in [`Extensions`].
+///
+/// # Example
+///
+/// ```
+/// use aisdk::extensions::{Extension, Extensions};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Debug, Default, Serialize, Deserialize)]
+/// struct TraceId(String);
+///
+/// impl Extension for TraceId {
+///     const NAME: &'static str = "my_app.trace_id";
+/// }
+///
+/// let extensions = Extensions::default();
+/// extensions.insert(TraceId("abc".to_string()));
+///
+/// let json = serde_json::to_string(&extensions).unwrap();
+/// let restored: Extensions = serde_json::from_str(&json).unwrap();
+/// assert_eq!(restored.get::<TraceId>().0, "abc");
+/// ```
+pub trait Extension: Serialize + DeserializeOwned + Send + Sync + 'static {
+    /// The key of the value in serialized extensions. It must be unique and stable, as
+    /// persisted extensions are matched by it.
+    const NAME: &'static str;
+}
+
+struct Entry {
+    value: Box<dyn Any + Send + Sync>,
+    name: &'static str,
+    to_json: fn(&(dyn Any + Send + Sync)) -> Option<Value>,
+}
+
+fn to_json<T: Extension>(value: &(dyn Any + Send + Sync)) -> Option<Value> {
+    value
+        .downcast_ref::<T>()
+        .and_then(|value| serde_json::to_value(value).ok())
+}
+
+#[derive(Default)]
+struct Inner {
+    entries: HashMap<TypeId, Entry>,
+    /// Deserialized values, converted to their type on first access.
+    pending: Map<String, Value>,
+}
+
 /// Extensions are a type-safe container for storing arbitrary metadata.
 #[derive(Default, Clone)]
 pub struct Extensions {
-    map: Arc<RwLock<HashMap<TypeId, Box<dyn Any + Send + Sync>>>>,
+    map: Arc<RwLock<Inner>>,
 }
 
 impl Extensions {
     /// Inserts a value into the extensions map.
-    pub fn insert<T: Send + Sync + 'static>(&self, value: T) {
-        self.map.write().insert(TypeId::of::<T>(), Box::new(value));
+    pub fn insert<T: Extension>(&self, value: T) {
+        let mut inner = self.map.write();
+        inner.pending.remove(T::NAME);
+        inner.entries.insert(
+            TypeId::of::<T>(),
+            Entry {
+                value: Box::new(value),
+                name: T::NAME,
+                to_json: to_json::<T>,
+            },
+        );
     }
 
     /// Gets a value from the extensions map.
-    pub fn get<T: Send + Sync + Default + 'static>(&self) -> MappedRwLockReadGuard<'_, T> {
+    pub fn get<T: Extension + Default>(&self) -> MappedRwLockReadGuard<'_, T> {
         self.ensure::<T>();
         RwLockReadGuard::map(self.map.read(), |m| {
-            m.get(&TypeId::of::<T>())
-                .and_then(|b| b.downcast_ref())
+            m.entries
+                .get(&TypeId::of::<T>())
+                .and_then(|e| e.value.downcast_ref())
                 .unwrap()
         })
     }
 
     /// Gets a mutable value from the extensions map.
-    pub fn get_mut<T: Send + Sync + Default + 'static>(&self) -> MappedRwLockWriteGuard<'_, T> {
+    pub fn get_mut<T: Extension + Default>(&self) -> MappedRwLockWriteGuard<'_, T> {
         self.ensure::<T>();
         RwLockWriteGuard::map(self.map.write(), |m| {
-            m.get_mut(&TypeId::of::<T>())
-                .and_then(|b| b.downcast_mut())
+            m.entries
+                .get_mut(&TypeId::of::<T>())
+                .and_then(|e| e.value.downcast_mut())
                 .unwrap()
         })
     }
 
-    /// Ensures that a value of the given type is present in the extensions map.
-    fn ensure<T: Default + Send + Sync + 'static>(&self) {
-        if self.map.read().get(&TypeId::of::<T>()).is_none() {
-            self.insert(T::default());
+    /// Returns true if no value is stored.
+    pub fn is_empty(&self) -> bool {
+        let inner = self.map.read();
+        inner.entries.is_empty() && inner.pending.is_empty()
+    }
+
+    /// Ensures that a value of the given type is present in the extensions map, reading
+    /// it from the deserialized values if there is one.
+    fn ensure<T: Extension + Default>(&self) {
+        if self.map.read().entries.contains_key(&TypeId::of::<T>()) {
+            return;
         }
+        let pending = self.map.write().pending.remove(T::NAME);
+        let value = pending
+            .and_then(|value| serde_json::from_value(value).ok())
+            .unwrap_or_default();
+        self.insert::<T>(value);
     }
 }
 
@@ -53,3 +130,76 @@ impl std::fmt::Debug for Extensions {
         f.debug_struct("Extensions").finish()
     }
 }
+
+impl Serialize for Extensions {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let inner = self.map.read();
+        let mut map = inner.pending.clone();
+        for entry in inner.entries.values() {
+            if let Some(value) = (entry.to_json)(entry.value.as_ref()) {
+                map.insert(entry.name.to_string(), value);
+            }
+        }
+        map.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Extensions {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let pending = Map::deserialize(deserializer)?;
+        Ok(Self {
+            map: Arc::new(RwLock::new(Inner {
+                entries: HashMap::new(),
+                pending,
+            })),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+    struct Signature(Option<String>);
+
+    impl Extension for Signature {
+        const NAME: &'static str = "test.signature";
+    }
+
+    #[derive(Debug, Default, Serialize, Deserialize)]
+    struct Other(u32);
+
+    impl Extension for Other {
+        const NAME: &'static str = "test.other";
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let extensions = Extensions::default();
+        extensions.get_mut::<Signature>().0 = Some("sig".to_string());
+
+        let json = serde_json::to_value(&extensions).unwrap();
+        assert_eq!(json, serde_json::json!({"test.signature": "sig"}));
+
+        let restored: Extensions = serde_json::from_value(json).unwrap();
+        assert_eq!(
+            *restored.get::<Signature>(),
+            Signature(Some("sig".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_unread_values_are_kept() {
+        let restored: Extensions =
+            serde_json::from_value(serde_json::json!({"test.other": 7, "unknown": true})).unwrap();
+        restored.get_mut::<Signature>().0 = Some("sig".to_string());
+
+        let json = serde_json::to_value(&restored).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"test.other": 7, "unknown": true, "test.signature": "sig"})
+        );
+        assert_eq!(restored.get::<Other>().0, 7);
+    }
+}
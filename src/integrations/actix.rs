@@ -0,0 +1,300 @@
+//! Integration with Actix Web.
+//!
+//! Serves a [`StreamTextResponse`](crate::core::StreamTextResponse), or any stream of
+//! [`VercelUIStream`] chunks, as a Vercel AI SDK UI message stream in a streaming
+//! [`HttpResponse`] body.
+//!
+//! Errors of the stream are sent as `error` chunks, which `useChat` surfaces, and end
+//! the body cleanly instead of aborting the connection. When the client disconnects,
+//! Actix drops the body; [`VercelUISse::cancel_on_disconnect`] then cancels the
+//! request, so the model stops generating for nobody.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # #[cfg(feature = "openai")]
+//! # {
+//! use aisdk::core::LanguageModelRequest;
+//! use aisdk::core::cancellation::CancellationToken;
+//! use aisdk::providers::OpenAI;
+//! use actix_web::HttpResponse;
+//!
+//! async fn chat() -> HttpResponse {
+//!     let token = CancellationToken::new();
+//!     let response = LanguageModelRequest::builder()
+//!         .model(OpenAI::gpt_5())
+//!         .prompt("Tell me a story")
+//!         .cancellation_token(token.clone())
+//!         .build()
+//!         .stream_text()
+//!         .await;
+//!
+//!     match response {
+//!         Ok(response) => response
+//!             .to_actix_vercel_ui_stream()
+//!             .send_start()
+//!             .send_finish()
+//!             .build()
+//!             .cancel_on_disconnect(token)
+//!             .into_http_response(),
+//!         Err(e) => HttpResponse::BadGateway().body(e.to_string()),
+//!     }
+//! }
+//! # }
+//! ```
+
+use crate::core::cancellation::CancellationToken;
+use crate::integrations::sse::encode_sse_event;
+use crate::integrations::vercel_aisdk_ui::{
+    UI_MESSAGE_STREAM_HEADER, VercelUIStream, VercelUIStreamBuilder,
+};
+use actix_web::body::BoxBody;
+use actix_web::http::header::{CACHE_CONTROL, CONTENT_TYPE};
+use actix_web::{HttpRequest, HttpResponse, Responder};
+use futures::{Stream, StreamExt};
+use std::convert::Infallible;
+use std::pin::Pin;
+
+/// Type alias for the boxed stream of Vercel UI chunks served by [`VercelUISse`].
+pub type VercelUIChunkStream =
+    Pin<Box<dyn Stream<Item = crate::Result<VercelUIStream>> + Send + 'static>>;
+
+/// A responder that streams Vercel UI chunks as server-sent events.
+pub struct VercelUISse<S = VercelUIChunkStream> {
+    stream: S,
+    disconnect_token: Option<CancellationToken>,
+}
+
+impl<S> VercelUISse<S>
+where
+    S: Stream<Item = crate::Result<VercelUIStream>> + 'static,
+{
+    /// Creates a responder that streams the chunks of `stream`.
+    pub fn new(stream: S) -> Self {
+        Self {
+            stream,
+            disconnect_token: None,
+        }
+    }
+
+    /// Cancels `token` if the client disconnects before the stream finishes. Pass the
+    /// token given to the request, so that generation stops with the connection.
+    pub fn cancel_on_disconnect(mut self, token: CancellationToken) -> Self {
+        self.disconnect_token = Some(token);
+        self
+    }
+
+    /// Converts this responder into a streaming `text/event-stream` response, with the
+    /// [`UI_MESSAGE_STREAM_HEADER`] set to `v1`.
+    ///
+    /// # Returns
+    /// The Actix response.
+    pub fn into_http_response(self) -> HttpResponse {
+        let guard = DisconnectGuard {
+            token: self.disconnect_token,
+            finished: false,
+        };
+        let body = futures::stream::unfold(
+            (Box::pin(self.stream), guard, false),
+            |(mut stream, mut guard, ended)| async move {
+                if ended {
+                    guard.disarm();
+                    return None;
+                }
+                match stream.next().await {
+                    Some(Ok(chunk)) => Some((Ok(encode_sse_event(chunk)), (stream, guard, false))),
+                    // Report the error to the client, then end the body
+                    Some(Err(e)) => {
+                        let chunk = VercelUIStream::Error {
+                            error_text: e.to_string(),
+                        };
+                        Some((Ok(encode_sse_event(chunk)), (stream, guard, true)))
+                    }
+                    None => {
+                        guard.disarm();
+                        None
+                    }
+                }
+            },
+        );
+
+        HttpResponse::Ok()
+            .insert_header((CONTENT_TYPE, "text/event-stream"))
+            .insert_header((CACHE_CONTROL, "no-cache"))
+            .insert_header((UI_MESSAGE_STREAM_HEADER, "v1"))
+            .streaming::<_, Infallible>(body)
+    }
+}
+
+impl<S> Responder for VercelUISse<S>
+where
+    S: Stream<Item = crate::Result<VercelUIStream>> + 'static,
+{
+    type Body = BoxBody;
+
+    fn respond_to(self, _req: &HttpRequest) -> HttpResponse<Self::Body> {
+        self.into_http_response()
+    }
+}
+
+/// Cancels a token when a response body is dropped before its stream finished.
+struct DisconnectGuard {
+    token: Option<CancellationToken>,
+    finished: bool,
+}
+
+impl DisconnectGuard {
+    /// Marks the stream as finished, so dropping the guard cancels nothing.
+    fn disarm(&mut self) {
+        self.finished = true;
+    }
+}
+
+impl Drop for DisconnectGuard {
+    fn drop(&mut self) {
+        if self.finished {
+            return;
+        }
+        log::debug!("Client disconnected before the UI message stream finished");
+        if let Some(token) = &self.token {
+            token.cancel();
+        }
+    }
+}
+
+/// Implement From trait for StreamTextResponse to VercelUISse.
+impl From<crate::core::StreamTextResponse> for VercelUISse {
+    fn from(response: crate::core::StreamTextResponse) -> Self {
+        response
+            .to_actix_vercel_ui_stream()
+            .send_reasoning()
+            .send_start()
+            .send_finish()
+            .build()
+    }
+}
+
+impl Responder for crate::core::StreamTextResponse {
+    type Body = BoxBody;
+
+    fn respond_to(self, req: &HttpRequest) -> HttpResponse<Self::Body> {
+        VercelUISse::from(self).respond_to(req)
+    }
+}
+
+impl crate::core::StreamTextResponse {
+    /// Creates a builder for configuring a Vercel AI SDK UI compatible stream response from this `StreamTextResponse`.
+    ///
+    /// Allows configuration of options such as sending reasoning chunks, start signals, finish signals,
+    /// and custom message ID generation. The final build produces a [`VercelUISse`] responder, which
+    /// can also cancel the request when the client disconnects.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let responder = response
+    ///     .to_actix_vercel_ui_stream()
+    ///     .send_reasoning() // Enable reasoning chunks
+    ///     .send_start()    // Enable start signals
+    ///     .send_finish()  // Enable finish signals
+    ///     .build();
+    /// ```
+    ///
+    /// # Returns
+    /// A `VercelUIStreamBuilder` for configuring and building the Actix responder.
+    pub fn to_actix_vercel_ui_stream(self) -> VercelUIStreamBuilder<Self, VercelUISse> {
+        VercelUIStreamBuilder::new(self, |context, options| {
+            VercelUISse::new(Box::pin(context.into_vercel_ui_stream(options)) as VercelUIChunkStream)
+        })
+    }
+
+    /// Converts this response into an Actix response streaming Vercel AI SDK UI chunks,
+    /// including reasoning, start and finish chunks, as expected by `useChat`.
+    ///
+    /// # Returns
+    /// The Actix response.
+    pub fn into_actix_sse_response(self) -> HttpResponse {
+        VercelUISse::from(self).into_http_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+
+    async fn body_of(response: HttpResponse) -> String {
+        let body = actix_web::body::to_bytes(response.into_body())
+            .await
+            .unwrap();
+        String::from_utf8(body.to_vec()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_streams_chunks_as_events() {
+        let chunks = futures::stream::iter([Ok(VercelUIStream::TextDelta {
+            id: "msg_1".to_string(),
+            delta: "Hello".to_string(),
+            provider_metadata: None,
+        })]);
+
+        let response = VercelUISse::new(chunks).into_http_response();
+
+        assert_eq!(
+            response.headers().get(CONTENT_TYPE).unwrap(),
+            "text/event-stream"
+        );
+        assert_eq!(
+            response.headers().get(UI_MESSAGE_STREAM_HEADER).unwrap(),
+            "v1"
+        );
+        assert_eq!(
+            body_of(response).await,
+            "data: {\"type\":\"text-delta\",\"id\":\"msg_1\",\"delta\":\"Hello\"}\n\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_error_ends_stream_with_error_chunk() {
+        let chunks = futures::stream::iter([
+            Err(Error::Other("boom".to_string())),
+            Ok(VercelUIStream::TextDelta {
+                id: "msg_1".to_string(),
+                delta: "unsent".to_string(),
+                provider_metadata: None,
+            }),
+        ]);
+
+        let body = body_of(VercelUISse::new(chunks).into_http_response()).await;
+
+        assert!(body.contains("\"type\":\"error\""));
+        assert!(body.contains("boom"));
+        assert!(!body.contains("unsent"));
+    }
+
+    #[tokio::test]
+    async fn test_disconnect_cancels_token() {
+        let token = CancellationToken::new();
+        let chunks = futures::stream::pending::<crate::Result<VercelUIStream>>();
+
+        let response = VercelUISse::new(chunks)
+            .cancel_on_disconnect(token.clone())
+            .into_http_response();
+        drop(response);
+
+        assert!(token.is_cancelled());
+    }
+
+    #[tokio::test]
+    async fn test_finished_stream_keeps_token() {
+        let token = CancellationToken::new();
+        let chunks = futures::stream::empty::<crate::Result<VercelUIStream>>();
+
+        let response = VercelUISse::new(chunks)
+            .cancel_on_disconnect(token.clone())
+            .into_http_response();
+        body_of(response).await;
+
+        assert!(!token.is_cancelled());
+    }
+}
@@ -0,0 +1,682 @@
+//! Integration with the [AG-UI](https://docs.ag-ui.com) agent protocol.
+//!
+//! [`StreamTextResponse::into_ag_ui_stream`](crate::core::StreamTextResponse::into_ag_ui_stream)
+//! converts a stream into AG-UI events, from `RUN_STARTED` through text message, tool
+//! call and step events to `RUN_FINISHED`, or `RUN_ERROR` when generation fails. The
+//! events implement [`SseData`], so
+//! [`ToSseBytes`](crate::integrations::sse::ToSseBytes) serves them as the
+//! server-sent events an AG-UI `HttpAgent` reads.
+//!
+//! [`RunAgentInput`] is the body the agent posts to start a run; its messages convert to
+//! core messages.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # #[cfg(feature = "openai")]
+//! # {
+//! use aisdk::core::LanguageModelRequest;
+//! use aisdk::integrations::ag_ui::RunAgentInput;
+//! use aisdk::integrations::sse::ToSseBytes;
+//! use aisdk::providers::OpenAI;
+//!
+//! # tokio_test_block_on(async {
+//! # let input: RunAgentInput = serde_json::from_str("{}")?;
+//! let options = input.stream_options();
+//! let body = LanguageModelRequest::builder()
+//!     .model(OpenAI::gpt_5())
+//!     .messages(input.into())
+//!     .build()
+//!     .stream_text()
+//!     .await?
+//!     .into_ag_ui_stream(options)
+//!     .to_sse_bytes();
+//! # Ok::<_, Box<dyn std::error::Error>>(())
+//! # });
+//! # }
+//! # fn tokio_test_block_on<F: std::future::Future>(f: F) -> F::Output {
+//! #     tokio::runtime::Runtime::new().unwrap().block_on(f)
+//! # }
+//! ```
+
+use crate::core::messages::{AssistantMessage, Message, Messages};
+use crate::core::tools::{ToolCallInfo, ToolResultInfo};
+use crate::integrations::sse::SseData;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[cfg(feature = "language-model-request")]
+use crate::core::language_model::StreamEvent;
+#[cfg(feature = "language-model-request")]
+use crate::core::tools::ToolCallDelta;
+#[cfg(feature = "language-model-request")]
+use futures::{Stream, StreamExt, stream};
+#[cfg(feature = "language-model-request")]
+use std::collections::HashSet;
+
+// ============================================================================
+// Section: events
+// ============================================================================
+
+/// An event of the AG-UI protocol, as sent to the frontend.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(
+    tag = "type",
+    rename_all = "SCREAMING_SNAKE_CASE",
+    rename_all_fields = "camelCase"
+)]
+pub enum AgUiEvent {
+    /// A run started.
+    RunStarted {
+        /// The conversation the run belongs to.
+        thread_id: String,
+        /// The run.
+        run_id: String,
+    },
+    /// A run finished successfully.
+    RunFinished {
+        /// The conversation the run belongs to.
+        thread_id: String,
+        /// The run.
+        run_id: String,
+    },
+    /// A run failed. No event follows.
+    RunError {
+        /// The error message.
+        message: String,
+        /// An optional error code.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        code: Option<String>,
+    },
+    /// A step of the run, i.e. a model call, started.
+    StepStarted {
+        /// The name of the step.
+        step_name: String,
+    },
+    /// A step of the run finished.
+    StepFinished {
+        /// The name of the step.
+        step_name: String,
+    },
+    /// An assistant text message started.
+    TextMessageStart {
+        /// The message.
+        message_id: String,
+        /// The role of the author, always `assistant`.
+        role: String,
+    },
+    /// A chunk of an assistant text message.
+    TextMessageContent {
+        /// The message.
+        message_id: String,
+        /// The text, never empty.
+        delta: String,
+    },
+    /// An assistant text message ended.
+    TextMessageEnd {
+        /// The message.
+        message_id: String,
+    },
+    /// A reasoning message started.
+    ThinkingTextMessageStart,
+    /// A chunk of a reasoning message.
+    ThinkingTextMessageContent {
+        /// The reasoning text.
+        delta: String,
+    },
+    /// A reasoning message ended.
+    ThinkingTextMessageEnd,
+    /// A tool call started.
+    ToolCallStart {
+        /// The tool call.
+        tool_call_id: String,
+        /// The name of the tool.
+        tool_call_name: String,
+        /// The assistant message making the call.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        parent_message_id: Option<String>,
+    },
+    /// A fragment of the JSON arguments of a tool call.
+    ToolCallArgs {
+        /// The tool call.
+        tool_call_id: String,
+        /// The fragment.
+        delta: String,
+    },
+    /// The arguments of a tool call are complete.
+    ToolCallEnd {
+        /// The tool call.
+        tool_call_id: String,
+    },
+    /// The result of an executed tool call.
+    ToolCallResult {
+        /// The tool message holding the result.
+        message_id: String,
+        /// The tool call.
+        tool_call_id: String,
+        /// The output, serialized as JSON unless it is a string.
+        content: String,
+        /// The role of the message, always `tool`.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        role: Option<String>,
+    },
+}
+
+impl SseData for AgUiEvent {
+    fn into_sse_data(self) -> String {
+        serde_json::to_string(&self).unwrap_or_else(|e| {
+            AgUiEvent::RunError {
+                message: format!("JSON serialization error: {e}"),
+                code: None,
+            }
+            .into_sse_data()
+        })
+    }
+}
+
+/// Configuration of an AG-UI event stream.
+#[derive(Debug, Clone, Default)]
+pub struct AgUiStreamOptions {
+    /// The conversation the run belongs to.
+    pub thread_id: String,
+    /// The run.
+    pub run_id: String,
+    /// Whether to send reasoning as thinking events.
+    pub send_reasoning: bool,
+}
+
+impl AgUiStreamOptions {
+    /// Creates options for the run `run_id` of the thread `thread_id`.
+    pub fn new(thread_id: impl Into<String>, run_id: impl Into<String>) -> Self {
+        Self {
+            thread_id: thread_id.into(),
+            run_id: run_id.into(),
+            send_reasoning: false,
+        }
+    }
+
+    /// Enables sending reasoning as thinking events.
+    pub fn send_reasoning(mut self) -> Self {
+        self.send_reasoning = true;
+        self
+    }
+}
+
+#[cfg(feature = "language-model-request")]
+impl crate::core::StreamTextResponse {
+    /// Converts this `StreamTextResponse` into a stream of AG-UI events.
+    ///
+    /// Failures are sent as a `RUN_ERROR` event, which ends the stream.
+    ///
+    /// # Parameters
+    /// - `options`: The thread and run ids, and whether to send reasoning.
+    ///
+    /// # Returns
+    /// A stream yielding `AgUiEvent`s.
+    pub fn into_ag_ui_stream(self, options: AgUiStreamOptions) -> impl Stream<Item = AgUiEvent> {
+        let mut encoder = AgUiEncoder::new(options);
+        self.stream
+            .map(move |event| encoder.encode(event))
+            .flat_map(stream::iter)
+    }
+}
+
+/// Converts stream events to AG-UI events, tracking the open messages and tool calls.
+#[cfg(feature = "language-model-request")]
+#[derive(Debug, Default)]
+struct AgUiEncoder {
+    options: AgUiStreamOptions,
+    step: usize,
+    /// The id of the assistant message of the current step, once it started.
+    message_id: Option<String>,
+    thinking: bool,
+    /// The tool calls of the current step whose arguments are streaming.
+    streaming: HashSet<String>,
+    /// Argument fragments the provider did not attribute to a tool call. They are sent
+    /// once the call completes, as its name is unknown until then.
+    unattributed: String,
+    /// Whether a `RUN_ERROR` was sent, after which nothing else is.
+    failed: bool,
+}
+
+#[cfg(feature = "language-model-request")]
+impl AgUiEncoder {
+    fn new(options: AgUiStreamOptions) -> Self {
+        Self {
+            options,
+            ..Default::default()
+        }
+    }
+
+    fn encode(&mut self, event: StreamEvent) -> Vec<AgUiEvent> {
+        if self.failed {
+            return Vec::new();
+        }
+
+        let mut events = Vec::new();
+        match event {
+            StreamEvent::StreamStart => events.push(AgUiEvent::RunStarted {
+                thread_id: self.options.thread_id.clone(),
+                run_id: self.options.run_id.clone(),
+            }),
+            StreamEvent::StepStart => {
+                self.step += 1;
+                self.streaming.clear();
+                self.unattributed.clear();
+                events.push(AgUiEvent::StepStarted {
+                    step_name: self.step_name(),
+                });
+            }
+            StreamEvent::TextDelta(delta) if !delta.is_empty() => {
+                let message_id = self.open_message(&mut events);
+                events.push(AgUiEvent::TextMessageContent { message_id, delta });
+            }
+            StreamEvent::ReasoningDelta(delta) if self.options.send_reasoning => {
+                if !self.thinking {
+                    self.thinking = true;
+                    events.push(AgUiEvent::ThinkingTextMessageStart);
+                }
+                events.push(AgUiEvent::ThinkingTextMessageContent { delta });
+            }
+            StreamEvent::ToolCallDelta(delta) => self.tool_call_delta(delta, &mut events),
+            StreamEvent::ToolCall(info) => self.tool_call(info, &mut events),
+            StreamEvent::ToolResult(info) => events.push(tool_call_result(info)),
+            StreamEvent::StepFinish { .. } => {
+                self.close(&mut events);
+                events.push(AgUiEvent::StepFinished {
+                    step_name: self.step_name(),
+                });
+            }
+            StreamEvent::Error(error) => {
+                self.failed = true;
+                events.push(AgUiEvent::RunError {
+                    message: error.to_string(),
+                    code: None,
+                });
+            }
+            StreamEvent::Finish { .. } => {
+                self.close(&mut events);
+                events.push(AgUiEvent::RunFinished {
+                    thread_id: self.options.thread_id.clone(),
+                    run_id: self.options.run_id.clone(),
+                });
+            }
+            _ => {}
+        }
+        events
+    }
+
+    fn step_name(&self) -> String {
+        format!("step-{}", self.step)
+    }
+
+    /// Returns the id of the assistant message of the step, starting it if needed.
+    fn open_message(&mut self, events: &mut Vec<AgUiEvent>) -> String {
+        if let Some(message_id) = &self.message_id {
+            return message_id.clone();
+        }
+        let message_id = format!("msg_{}", uuid::Uuid::new_v4().simple());
+        events.push(AgUiEvent::TextMessageStart {
+            message_id: message_id.clone(),
+            role: "assistant".to_string(),
+        });
+        self.message_id = Some(message_id.clone());
+        message_id
+    }
+
+    /// Ends the open reasoning and text messages.
+    fn close(&mut self, events: &mut Vec<AgUiEvent>) {
+        if std::mem::take(&mut self.thinking) {
+            events.push(AgUiEvent::ThinkingTextMessageEnd);
+        }
+        if let Some(message_id) = self.message_id.take() {
+            events.push(AgUiEvent::TextMessageEnd { message_id });
+        }
+    }
+
+    fn tool_call_delta(&mut self, delta: ToolCallDelta, events: &mut Vec<AgUiEvent>) {
+        if delta.tool.id.is_empty() {
+            self.unattributed.push_str(&delta.delta);
+            return;
+        }
+        if self.streaming.insert(delta.tool.id.clone()) {
+            events.push(AgUiEvent::ToolCallStart {
+                tool_call_id: delta.tool.id.clone(),
+                tool_call_name: delta.tool.name,
+                parent_message_id: self.message_id.clone(),
+            });
+        }
+        events.push(AgUiEvent::ToolCallArgs {
+            tool_call_id: delta.tool.id,
+            delta: delta.delta,
+        });
+    }
+
+    fn tool_call(&mut self, info: ToolCallInfo, events: &mut Vec<AgUiEvent>) {
+        if !self.streaming.remove(&info.tool.id) {
+            events.push(AgUiEvent::ToolCallStart {
+                tool_call_id: info.tool.id.clone(),
+                tool_call_name: info.tool.name.clone(),
+                parent_message_id: self.message_id.clone(),
+            });
+            let args = match std::mem::take(&mut self.unattributed) {
+                args if args.is_empty() => info.input.to_string(),
+                args => args,
+            };
+            events.push(AgUiEvent::ToolCallArgs {
+                tool_call_id: info.tool.id.clone(),
+                delta: args,
+            });
+        }
+        events.push(AgUiEvent::ToolCallEnd {
+            tool_call_id: info.tool.id,
+        });
+    }
+}
+
+/// Returns the result event of an executed tool call.
+#[cfg(feature = "language-model-request")]
+fn tool_call_result(info: ToolResultInfo) -> AgUiEvent {
+    let content = match info.output {
+        Ok(Value::String(output)) => output,
+        Ok(output) => output.to_string(),
+        Err(e) => format!("Error: {e}"),
+    };
+    AgUiEvent::ToolCallResult {
+        message_id: format!("msg_{}", uuid::Uuid::new_v4().simple()),
+        tool_call_id: info.tool.id,
+        content,
+        role: Some("tool".to_string()),
+    }
+}
+
+// ============================================================================
+// Section: requests
+// ============================================================================
+
+/// The body an AG-UI agent posts to start a run.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunAgentInput {
+    /// The conversation the run belongs to.
+    #[serde(default)]
+    pub thread_id: String,
+    /// The run.
+    #[serde(default)]
+    pub run_id: String,
+    /// The messages of the conversation.
+    #[serde(default)]
+    pub messages: Vec<AgUiMessage>,
+    /// The tools the frontend provides.
+    #[serde(default)]
+    pub tools: Vec<Value>,
+    /// The state of the agent.
+    #[serde(default)]
+    pub state: Value,
+    /// Context entries provided by the frontend.
+    #[serde(default)]
+    pub context: Vec<Value>,
+    /// Properties forwarded by the frontend as-is.
+    #[serde(default)]
+    pub forwarded_props: Value,
+}
+
+impl RunAgentInput {
+    /// Returns stream options for the thread and run of this input.
+    pub fn stream_options(&self) -> AgUiStreamOptions {
+        AgUiStreamOptions::new(&self.thread_id, &self.run_id)
+    }
+}
+
+/// A message of an AG-UI conversation.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgUiMessage {
+    /// The message.
+    #[serde(default)]
+    pub id: String,
+    /// The role of the author: `developer`, `system`, `user`, `assistant` or `tool`.
+    pub role: String,
+    /// The content, either a string or a list of content parts.
+    #[serde(default)]
+    pub content: Value,
+    /// The tool calls made by an assistant message.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tool_calls: Vec<AgUiToolCall>,
+    /// The tool call a tool message answers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tool_call_id: Option<String>,
+}
+
+impl AgUiMessage {
+    /// Returns the text of the content, joining text parts.
+    pub fn text(&self) -> String {
+        match &self.content {
+            Value::String(text) => text.clone(),
+            Value::Array(parts) => parts
+                .iter()
+                .filter_map(|part| part["text"].as_str())
+                .collect::<Vec<_>>()
+                .join("\n"),
+            _ => String::new(),
+        }
+    }
+}
+
+/// A tool call of an AG-UI assistant message.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AgUiToolCall {
+    /// The tool call.
+    pub id: String,
+    /// The function called.
+    pub function: AgUiFunctionCall,
+}
+
+/// The function of an AG-UI tool call.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AgUiFunctionCall {
+    /// The name of the tool.
+    pub name: String,
+    /// The JSON arguments, as a string.
+    pub arguments: String,
+}
+
+/// Converts AG-UI messages to core messages. Tool results take the name of the tool
+/// from the call they answer.
+///
+/// # Parameters
+/// - `messages`: The AG-UI messages to convert.
+///
+/// # Returns
+/// The core messages, in order.
+pub fn convert_to_model_messages(messages: &[AgUiMessage]) -> Messages {
+    use crate::core::language_model::LanguageModelResponseContentType;
+
+    let mut converted = Vec::new();
+    let mut tool_names = std::collections::HashMap::new();
+    for message in messages {
+        match message.role.as_str() {
+            "developer" | "system" => converted.push(Message::System(message.text().into())),
+            "user" => converted.push(Message::User(message.text().into())),
+            "assistant" => {
+                let text = message.text();
+                if !text.is_empty() {
+                    converted.push(Message::Assistant(text.into()));
+                }
+                for call in &message.tool_calls {
+                    tool_names.insert(call.id.clone(), call.function.name.clone());
+                    let mut info = ToolCallInfo::new(&call.function.name);
+                    info.id(&call.id);
+                    info.input(
+                        serde_json::from_str(&call.function.arguments).unwrap_or(Value::Null),
+                    );
+                    converted.push(Message::Assistant(AssistantMessage::new(
+                        LanguageModelResponseContentType::ToolCall(info),
+                        None,
+                    )));
+                }
+            }
+            "tool" => {
+                let id = message.tool_call_id.clone().unwrap_or_default();
+                let name = tool_names.get(&id).cloned().unwrap_or_default();
+                let mut result = ToolResultInfo::new(name);
+                result.id(id);
+                result.output(Value::String(message.text()));
+                converted.push(Message::Tool(result));
+            }
+            _ => {}
+        }
+    }
+    converted
+}
+
+/// Converts a `RunAgentInput` into its messages.
+impl From<RunAgentInput> for Messages {
+    fn from(input: RunAgentInput) -> Self {
+        convert_to_model_messages(&input.messages)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_wire_format() {
+        assert_eq!(
+            serde_json::to_value(AgUiEvent::TextMessageContent {
+                message_id: "msg_1".to_string(),
+                delta: "Hi".to_string(),
+            })
+            .unwrap(),
+            serde_json::json!({"type": "TEXT_MESSAGE_CONTENT", "messageId": "msg_1", "delta": "Hi"})
+        );
+        assert_eq!(
+            serde_json::to_value(AgUiEvent::ThinkingTextMessageStart).unwrap(),
+            serde_json::json!({"type": "THINKING_TEXT_MESSAGE_START"})
+        );
+    }
+
+    #[cfg(feature = "language-model-request")]
+    #[test]
+    fn test_encoder_run() {
+        use crate::core::language_model::{StopReason, Usage};
+        use crate::core::tools::ToolDetails;
+
+        let mut encoder = AgUiEncoder::new(AgUiStreamOptions::new("thread_1", "run_1"));
+        let mut call = ToolCallInfo::new("weather");
+        call.id("call_1");
+        call.input(serde_json::json!({"city": "Paris"}));
+        let mut result = ToolResultInfo::new("weather");
+        result.id("call_1");
+        result.output(serde_json::json!("sunny"));
+        let stream = vec![
+            StreamEvent::StreamStart,
+            StreamEvent::StepStart,
+            StreamEvent::TextDelta("Let me check.".to_string()),
+            StreamEvent::ToolCallDelta(ToolCallDelta {
+                tool: ToolDetails {
+                    name: "weather".to_string(),
+                    id: "call_1".to_string(),
+                },
+                delta: "{\"city\":\"Paris\"}".to_string(),
+            }),
+            StreamEvent::ToolCall(call),
+            StreamEvent::ToolResult(result),
+            StreamEvent::StepFinish {
+                usage: None,
+                finish_reason: None,
+            },
+            StreamEvent::Finish {
+                usage: Usage::default(),
+                stop_reason: StopReason::Finish,
+            },
+        ];
+
+        let events: Vec<AgUiEvent> = stream
+            .into_iter()
+            .flat_map(|event| encoder.encode(event))
+            .collect();
+        let types: Vec<_> = events
+            .iter()
+            .map(|event| serde_json::to_value(event).unwrap()["type"].clone())
+            .collect();
+
+        assert_eq!(
+            types,
+            [
+                "RUN_STARTED",
+                "STEP_STARTED",
+                "TEXT_MESSAGE_START",
+                "TEXT_MESSAGE_CONTENT",
+                "TOOL_CALL_START",
+                "TOOL_CALL_ARGS",
+                "TOOL_CALL_END",
+                "TOOL_CALL_RESULT",
+                "TEXT_MESSAGE_END",
+                "STEP_FINISHED",
+                "RUN_FINISHED",
+            ]
+        );
+        let AgUiEvent::ToolCallResult { content, .. } = &events[7] else {
+            panic!("expected a tool call result");
+        };
+        assert_eq!(content, "sunny");
+    }
+
+    #[cfg(feature = "language-model-request")]
+    #[test]
+    fn test_encoder_stops_after_error() {
+        use crate::core::language_model::{StopReason, Usage};
+
+        let mut encoder = AgUiEncoder::new(AgUiStreamOptions::new("thread_1", "run_1"));
+        let error = crate::Error::Other("boom".to_string());
+
+        assert_eq!(
+            encoder.encode(StreamEvent::Error(error.clone())),
+            vec![AgUiEvent::RunError {
+                message: error.to_string(),
+                code: None,
+            }]
+        );
+        assert!(
+            encoder
+                .encode(StreamEvent::Finish {
+                    usage: Usage::default(),
+                    stop_reason: StopReason::Error(error),
+                })
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_convert_to_model_messages() {
+        let input: RunAgentInput = serde_json::from_value(serde_json::json!({
+            "threadId": "thread_1",
+            "runId": "run_1",
+            "messages": [
+                {"id": "1", "role": "user", "content": "Weather in Paris?"},
+                {
+                    "id": "2",
+                    "role": "assistant",
+                    "toolCalls": [{
+                        "id": "call_1",
+                        "type": "function",
+                        "function": {"name": "weather", "arguments": "{\"city\":\"Paris\"}"}
+                    }]
+                },
+                {"id": "3", "role": "tool", "toolCallId": "call_1", "content": "sunny"}
+            ]
+        }))
+        .unwrap();
+        assert_eq!(input.stream_options().run_id, "run_1");
+
+        let messages: Messages = input.into();
+        assert_eq!(messages.len(), 3);
+        let Message::Tool(result) = &messages[2] else {
+            panic!("expected a tool message");
+        };
+        assert_eq!(result.tool.name, "weather");
+        assert_eq!(result.tool.id, "call_1");
+    }
+}
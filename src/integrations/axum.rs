@@ -1,8 +1,44 @@
 //! Integration with Axum.
+//!
+//! Serves a [`StreamTextResponse`](crate::core::StreamTextResponse) as a Vercel AI SDK UI
+//! message stream, and extracts the [`VercelUIRequest`] bodies sent by `useChat`, so a
+//! chat endpoint takes a few lines.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # #[cfg(feature = "openai")]
+//! # {
+//! use aisdk::core::LanguageModelRequest;
+//! use aisdk::integrations::vercel_aisdk_ui::VercelUIRequest;
+//! use aisdk::providers::OpenAI;
+//! use axum::response::{IntoResponse, Response};
+//!
+//! async fn chat(request: VercelUIRequest) -> Response {
+//!     let response = LanguageModelRequest::builder()
+//!         .model(OpenAI::gpt_5())
+//!         .messages(request.into())
+//!         .build()
+//!         .stream_text()
+//!         .await;
+//!
+//!     match response {
+//!         Ok(response) => response.into_axum_sse_response(),
+//!         Err(e) => (axum::http::StatusCode::BAD_GATEWAY, e.to_string()).into_response(),
+//!     }
+//! }
+//!
+//! let app: axum::Router = axum::Router::new().route("/api/chat", axum::routing::post(chat));
+//! # }
+//! ```
 
-use crate::integrations::vercel_aisdk_ui::VercelUIStreamBuilder;
-use axum::response::Sse;
+use crate::integrations::vercel_aisdk_ui::{
+    UI_MESSAGE_STREAM_HEADER, VercelUIRequest, VercelUIStreamBuilder,
+};
+use axum::Json;
+use axum::extract::{FromRequest, Request, rejection::JsonRejection};
 use axum::response::sse::{Event, KeepAliveStream};
+use axum::response::{IntoResponse, Response, Sse};
 use futures::StreamExt;
 
 /// Type alias for the Axum SSE response with boxed stream for trait implementations.
@@ -25,6 +61,22 @@ impl From<crate::core::StreamTextResponse> for AxumSseResponse {
 }
 
 impl crate::core::StreamTextResponse {
+    /// Converts this response into an Axum response streaming Vercel AI SDK UI chunks,
+    /// as expected by `useChat`.
+    ///
+    /// Streams the chunks built by [`AxumSseResponse::from`], including reasoning, start
+    /// and finish chunks, with the `text/event-stream` content type, the
+    /// [`UI_MESSAGE_STREAM_HEADER`] set to `v1`, and keep-alive comments while the model
+    /// is silent. Use [`to_axum_vercel_ui_stream`](Self::to_axum_vercel_ui_stream) to
+    /// configure the chunks.
+    ///
+    /// # Returns
+    /// The Axum response.
+    pub fn into_axum_sse_response(self) -> Response {
+        let sse = AxumSseResponse::from(self);
+        ([(UI_MESSAGE_STREAM_HEADER, "v1")], sse).into_response()
+    }
+
     /// Creates a builder for configuring a Vercel AI SDK UI compatible stream response from this `StreamTextResponse`.
     ///
     /// Allows configuration of options such as sending reasoning chunks, start signals, finish signals,
@@ -68,3 +120,88 @@ impl crate::core::StreamTextResponse {
         })
     }
 }
+
+/// Extracts the JSON body sent by Vercel's `useChat` hook.
+///
+/// Rejects the request like [`Json`] if it is not a JSON request or the body is not a
+/// valid [`VercelUIRequest`].
+impl<S> FromRequest<S> for VercelUIRequest
+where
+    S: Send + Sync,
+{
+    type Rejection = JsonRejection;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let Json(request) = Json::<VercelUIRequest>::from_request(req, state).await?;
+        Ok(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::LanguageModelRequest;
+    use crate::core::test_model::ScriptedModel;
+    use crate::integrations::vercel_aisdk_ui::UIMessagePart;
+    use axum::body::Body;
+
+    #[tokio::test]
+    async fn test_into_axum_sse_response() {
+        let response = LanguageModelRequest::builder()
+            .model(ScriptedModel::new("greeting"))
+            .prompt("greet me")
+            .build()
+            .stream_text()
+            .await
+            .unwrap()
+            .into_axum_sse_response();
+
+        let headers = response.headers();
+        assert_eq!(
+            headers[axum::http::header::CONTENT_TYPE],
+            "text/event-stream"
+        );
+        assert_eq!(headers[UI_MESSAGE_STREAM_HEADER], "v1");
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+        assert!(body.contains(r#""delta":"Hello""#));
+    }
+
+    #[tokio::test]
+    async fn test_extract_vercel_ui_request() {
+        let body = r#"{
+            "id": "chat-1",
+            "messages": [
+                {"id": "m1", "role": "user", "parts": [{"type": "text", "text": "Hi"}]}
+            ],
+            "trigger": "submit-message"
+        }"#;
+        let request = Request::builder()
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(Body::from(body))
+            .unwrap();
+
+        let request = VercelUIRequest::from_request(request, &()).await.unwrap();
+
+        assert_eq!(request.id, "chat-1");
+        assert_eq!(
+            request.messages[0].parts,
+            vec![UIMessagePart::Text {
+                text: "Hi".to_string()
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_extract_rejects_non_json() {
+        let request = Request::builder().body(Body::from("{}")).unwrap();
+
+        assert!(matches!(
+            VercelUIRequest::from_request(request, &()).await,
+            Err(JsonRejection::MissingJsonContentType(_))
+        ));
+    }
+}
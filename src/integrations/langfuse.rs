@@ -0,0 +1,312 @@
+//! Exporter recording generations in [Langfuse](https://langfuse.com).
+//!
+//! [`Langfuse`] implements [`GenerationExporter`], sending each generation to the
+//! Langfuse ingestion API as a trace holding one generation observation, with the
+//! prompt, completion, tool calls, model, parameters, token usage and timings. Attach
+//! it to the requests to record with
+//! [`TelemetryOptions`](crate::core::observability::TelemetryOptions).
+//!
+//! The trace takes its name from the function id of the options, and its user, session
+//! and tags from the `user_id`, `session_id` and `tags` metadata entries.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # #[cfg(feature = "openai")]
+//! # {
+//! use aisdk::core::LanguageModelRequest;
+//! use aisdk::core::observability::TelemetryOptions;
+//! use aisdk::integrations::langfuse::Langfuse;
+//! use aisdk::providers::OpenAI;
+//!
+//! # tokio_test_block_on(async {
+//! let langfuse = Langfuse::from_env()?;
+//!
+//! let response = LanguageModelRequest::builder()
+//!     .model(OpenAI::gpt_5())
+//!     .prompt("Summarize the ticket")
+//!     .telemetry(
+//!         TelemetryOptions::new(langfuse)
+//!             .function_id("summarize-ticket")
+//!             .metadata("user_id", "user-42"),
+//!     )
+//!     .build()
+//!     .generate_text()
+//!     .await?;
+//! # Ok::<_, aisdk::Error>(())
+//! # });
+//! # }
+//! # fn tokio_test_block_on<F: std::future::Future>(f: F) -> F::Output {
+//! #     tokio::runtime::Runtime::new().unwrap().block_on(f)
+//! # }
+//! ```
+
+use crate::core::observability::{Generation, GenerationExporter};
+use crate::core::runtime::{SystemTime, UNIX_EPOCH, assume_send};
+use crate::error::{Error, Result};
+use async_trait::async_trait;
+use serde_json::{Value, json};
+
+/// The default host, Langfuse Cloud in the EU region.
+pub const DEFAULT_HOST: &str = "https://cloud.langfuse.com";
+
+/// An exporter sending generations to the Langfuse ingestion API.
+#[derive(Clone)]
+pub struct Langfuse {
+    client: reqwest::Client,
+    host: String,
+    public_key: String,
+    secret_key: String,
+}
+
+impl std::fmt::Debug for Langfuse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Langfuse")
+            .field("host", &self.host)
+            .field("public_key", &self.public_key)
+            .field("secret_key", &"<redacted>")
+            .finish()
+    }
+}
+
+impl Langfuse {
+    /// Creates an exporter to Langfuse Cloud authenticated with a project's API keys.
+    pub fn new(public_key: impl Into<String>, secret_key: impl Into<String>) -> Self {
+        Self {
+            client: crate::core::client::http_client(),
+            host: DEFAULT_HOST.to_string(),
+            public_key: public_key.into(),
+            secret_key: secret_key.into(),
+        }
+    }
+
+    /// Creates an exporter from the `LANGFUSE_PUBLIC_KEY`, `LANGFUSE_SECRET_KEY` and,
+    /// optionally, `LANGFUSE_HOST` environment variables.
+    pub fn from_env() -> Result<Self> {
+        let var = |name: &str| {
+            std::env::var(name).map_err(|_| Error::MissingField(format!("{name} is not set")))
+        };
+        let langfuse = Self::new(var("LANGFUSE_PUBLIC_KEY")?, var("LANGFUSE_SECRET_KEY")?);
+        match std::env::var("LANGFUSE_HOST") {
+            Ok(host) => langfuse.host(host),
+            Err(_) => Ok(langfuse),
+        }
+    }
+
+    /// Sets the host of the Langfuse instance, e.g. `https://us.cloud.langfuse.com` or
+    /// a self-hosted one.
+    pub fn host(mut self, host: impl Into<String>) -> Result<Self> {
+        self.host = crate::core::utils::validate_base_url(&host.into())?;
+        Ok(self)
+    }
+
+    /// Sends a batch of ingestion events.
+    async fn ingest(&self, batch: Vec<Value>) -> Result<()> {
+        let url = crate::core::utils::join_url(&self.host, "api/public/ingestion")?;
+        let request = self
+            .client
+            .post(url)
+            .basic_auth(&self.public_key, Some(&self.secret_key))
+            .json(&json!({ "batch": batch }));
+        let response = assume_send(request.send())
+            .await
+            .map_err(|e| Error::ApiError {
+                status_code: e.status(),
+                details: format!("Langfuse request failed: {e}"),
+            })?;
+
+        // Events are accepted one by one, with a 207 listing the ones that failed
+        let status = response.status();
+        let body: Value = assume_send(response.json()).await.unwrap_or_default();
+        let errors = body["errors"].as_array().filter(|e| !e.is_empty());
+        if !status.is_success() || errors.is_some() {
+            return Err(Error::ApiError {
+                status_code: Some(status),
+                details: format!("Langfuse ingestion failed: {body}"),
+            });
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl GenerationExporter for Langfuse {
+    async fn export(&self, generation: Generation) -> Result<()> {
+        self.ingest(ingestion_batch(&generation)).await
+    }
+}
+
+/// Returns the `trace-create` and `generation-create` events recording `generation`.
+fn ingestion_batch(generation: &Generation) -> Vec<Value> {
+    let trace_id = uuid::Uuid::new_v4().to_string();
+    let timestamp = rfc3339(generation.start_time);
+    let input = generation
+        .input
+        .as_ref()
+        .map(|input| serde_json::to_value(input).unwrap_or_default());
+    let output = if generation.tool_calls.is_empty() {
+        generation.output.clone().map(Value::String)
+    } else {
+        Some(json!({
+            "content": generation.output,
+            "tool_calls": generation.tool_calls.iter().map(|call| json!({
+                "id": call.tool.id,
+                "name": call.tool.name,
+                "arguments": call.input,
+            })).collect::<Vec<_>>(),
+        }))
+    };
+    let metadata = &generation.metadata;
+
+    let mut trace = json!({
+        "id": trace_id,
+        "timestamp": timestamp,
+        "name": generation.name,
+        "input": input,
+        "output": output,
+        "metadata": metadata,
+    });
+    let trace_fields = [
+        ("userId", "user_id"),
+        ("sessionId", "session_id"),
+        ("tags", "tags"),
+    ];
+    for (field, key) in trace_fields {
+        if let Some(value) = metadata.get(key) {
+            trace[field] = value.clone();
+        }
+    }
+
+    let mut body = json!({
+        "id": generation.id,
+        "traceId": trace_id,
+        "name": generation.name.clone().unwrap_or_else(|| "generation".to_string()),
+        "startTime": timestamp,
+        "endTime": rfc3339(generation.end_time),
+        "model": generation.model,
+        "modelParameters": generation.parameters,
+        "input": input,
+        "output": output,
+        "usageDetails": {
+            "input": generation.usage.input_tokens.unwrap_or_default(),
+            "output": generation.usage.output_tokens.unwrap_or_default(),
+        },
+        "metadata": metadata,
+    });
+    if let Some(completion_start_time) = generation.completion_start_time {
+        body["completionStartTime"] = rfc3339(completion_start_time).into();
+    }
+    if let Some(error) = &generation.error {
+        body["level"] = "ERROR".into();
+        body["statusMessage"] = error.to_string().into();
+    }
+
+    vec![
+        json!({
+            "id": uuid::Uuid::new_v4().to_string(),
+            "timestamp": timestamp,
+            "type": "trace-create",
+            "body": trace,
+        }),
+        json!({
+            "id": uuid::Uuid::new_v4().to_string(),
+            "timestamp": timestamp,
+            "type": "generation-create",
+            "body": body,
+        }),
+    ]
+}
+
+/// Formats a time as an RFC 3339 timestamp in UTC, with milliseconds.
+fn rfc3339(time: SystemTime) -> String {
+    let since_epoch = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+    let secs = since_epoch.as_secs();
+    let days = (secs / 86_400) as i64;
+    let rem = secs % 86_400;
+
+    // Civil date from days since the epoch, see http://howardhinnant.github.io/date_algorithms.html
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}.{:03}Z",
+        rem / 3600,
+        rem % 3600 / 60,
+        rem % 60,
+        since_epoch.subsec_millis()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::language_model::Usage;
+    use crate::core::tools::{ToolCallInfo, ToolDetails};
+    use std::time::Duration;
+
+    fn generation() -> Generation {
+        let start_time = UNIX_EPOCH + Duration::from_millis(951_782_400_250);
+        Generation {
+            id: "gen-1".to_string(),
+            name: Some("weather".to_string()),
+            model: "gpt-5".to_string(),
+            parameters: serde_json::Map::new(),
+            input: Some(vec![]),
+            output: Some("Sunny".to_string()),
+            tool_calls: vec![ToolCallInfo {
+                tool: ToolDetails {
+                    name: "get_weather".to_string(),
+                    id: "call-1".to_string(),
+                },
+                input: json!({ "city": "Paris" }),
+                ..Default::default()
+            }],
+            usage: Usage {
+                input_tokens: Some(10),
+                output_tokens: Some(3),
+                ..Default::default()
+            },
+            finish_reason: None,
+            error: Some(Error::Other("boom".to_string())),
+            start_time,
+            completion_start_time: None,
+            end_time: start_time + Duration::from_secs(2),
+            metadata: json!({ "user_id": "ada" }).as_object().unwrap().clone(),
+        }
+    }
+
+    #[test]
+    fn test_ingestion_batch() {
+        let batch = ingestion_batch(&generation());
+
+        assert_eq!(batch[0]["type"], "trace-create");
+        assert_eq!(batch[0]["body"]["name"], "weather");
+        assert_eq!(batch[0]["body"]["userId"], "ada");
+
+        let body = &batch[1]["body"];
+        assert_eq!(batch[1]["type"], "generation-create");
+        assert_eq!(body["traceId"], batch[0]["body"]["id"]);
+        assert_eq!(body["id"], "gen-1");
+        assert_eq!(body["startTime"], "2000-02-29T00:00:00.250Z");
+        assert_eq!(body["endTime"], "2000-02-29T00:00:02.250Z");
+        assert_eq!(body["usageDetails"], json!({ "input": 10, "output": 3 }));
+        assert_eq!(body["output"]["content"], "Sunny");
+        assert_eq!(body["output"]["tool_calls"][0]["name"], "get_weather");
+        assert_eq!(body["level"], "ERROR");
+        assert!(body.get("completionStartTime").is_none());
+    }
+
+    #[test]
+    fn test_debug_redacts_secret_key() {
+        let debug = format!("{:?}", Langfuse::new("pk-lf-1", "sk-lf-1"));
+        assert!(debug.contains("pk-lf-1"));
+        assert!(!debug.contains("sk-lf-1"));
+    }
+}
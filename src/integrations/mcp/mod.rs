@@ -0,0 +1,866 @@
+//! Client for the Model Context Protocol (MCP).
+//!
+//! [`McpClient`] connects to an MCP server, either a local process speaking over
+//! stdio or a remote server over streamable HTTP, and exposes the server's tools as
+//! [`Tool`]s. Calling one of these tools proxies the call back to the server, so they
+//! can be passed to a request like any other tool.
+//!
+//! [`McpServer`] does the opposite, publishing the crate's tools to MCP hosts.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # #[cfg(feature = "openai")]
+//! # {
+//! use aisdk::core::LanguageModelRequest;
+//! use aisdk::integrations::mcp::McpClient;
+//! use aisdk::providers::OpenAI;
+//! use tokio::process::Command;
+//!
+//! # tokio_test_block_on(async {
+//! let mut command = Command::new("npx");
+//! command.args(["-y", "@modelcontextprotocol/server-everything"]);
+//! let client = McpClient::stdio(command).await?;
+//!
+//! let mut request = LanguageModelRequest::builder()
+//!     .model(OpenAI::gpt_5())
+//!     .prompt("Add 2 and 3");
+//! for tool in client.tools().await? {
+//!     request = request.with_tool(tool);
+//! }
+//! let response = request.build().generate_text().await?;
+//! # Ok::<_, aisdk::Error>(())
+//! # });
+//! # }
+//! # fn tokio_test_block_on<F: std::future::Future>(f: F) -> F::Output {
+//! #     tokio::runtime::Runtime::new().unwrap().block_on(f)
+//! # }
+//! ```
+
+mod server;
+
+pub use server::McpServer;
+
+use crate::core::tools::{Tool, ToolExecute};
+use crate::error::{Error, Result};
+use async_trait::async_trait;
+use reqwest::header::{ACCEPT, CONTENT_TYPE, HeaderMap, HeaderName, HeaderValue};
+use schemars::{Schema, json_schema};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::process::Stdio;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::process::{Child, ChildStdin, Command};
+use tokio::sync::oneshot;
+use tokio::task::JoinHandle;
+
+/// The version of the protocol requested by the client.
+pub const PROTOCOL_VERSION: &str = "2025-06-18";
+
+/// The header carrying the session of a streamable HTTP connection.
+const SESSION_ID_HEADER: &str = "mcp-session-id";
+
+/// The header carrying the protocol version on streamable HTTP requests.
+const PROTOCOL_VERSION_HEADER: &str = "mcp-protocol-version";
+
+// ============================================================================
+// Section: transports
+// ============================================================================
+
+/// Carries JSON-RPC messages between a client and an MCP server.
+#[async_trait]
+pub trait McpTransport: Send + Sync {
+    /// Sends a JSON-RPC request and returns the response with the same id.
+    async fn request(&self, request: Value) -> Result<Value>;
+
+    /// Sends a JSON-RPC notification, which has no response.
+    async fn notify(&self, notification: Value) -> Result<()>;
+}
+
+/// The requests waiting for a response, by id.
+type PendingRequests = Arc<parking_lot::Mutex<HashMap<String, oneshot::Sender<Value>>>>;
+
+/// A transport to a server running as a child process, exchanging newline-delimited
+/// JSON-RPC messages over its stdin and stdout.
+///
+/// The process is killed when the transport is dropped.
+pub struct StdioTransport {
+    stdin: Arc<tokio::sync::Mutex<ChildStdin>>,
+    pending: PendingRequests,
+    closed: Arc<AtomicBool>,
+    reader: JoinHandle<()>,
+    _child: Child,
+}
+
+impl StdioTransport {
+    /// Spawns `command` as the server. Its stdin and stdout are piped, its stderr is
+    /// left as configured on the command.
+    pub fn spawn(mut command: Command) -> Result<Self> {
+        let mut child = command
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(|e| Error::Other(format!("Failed to spawn MCP server: {e}")))?;
+        let stdin = Arc::new(tokio::sync::Mutex::new(
+            child.stdin.take().expect("stdin is piped"),
+        ));
+        let stdout = child.stdout.take().expect("stdout is piped");
+
+        let pending: PendingRequests = Default::default();
+        let closed = Arc::new(AtomicBool::new(false));
+        let reader = tokio::spawn({
+            let stdin = stdin.clone();
+            let pending = pending.clone();
+            let closed = closed.clone();
+            async move {
+                let mut lines = BufReader::new(stdout).lines();
+                while let Ok(Some(line)) = lines.next_line().await {
+                    let Ok(message) = serde_json::from_str::<Value>(&line) else {
+                        log::debug!("Ignoring non JSON-RPC output of MCP server: {line}");
+                        continue;
+                    };
+                    match (message.get("id"), message.get("method")) {
+                        // A request from the server
+                        (Some(id), Some(method)) => {
+                            let response = server_request_response(id, method);
+                            if let Err(e) = write_message(&stdin, &response).await {
+                                log::debug!("Failed to answer MCP server request: {e}");
+                            }
+                        }
+                        (Some(id), None) => {
+                            if let Some(sender) = pending.lock().remove(&id.to_string()) {
+                                let _ = sender.send(message);
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                // Fail the requests still waiting for a response
+                closed.store(true, Ordering::SeqCst);
+                pending.lock().clear();
+            }
+        });
+
+        Ok(Self {
+            stdin,
+            pending,
+            closed,
+            reader,
+            _child: child,
+        })
+    }
+}
+
+#[async_trait]
+impl McpTransport for StdioTransport {
+    async fn request(&self, request: Value) -> Result<Value> {
+        let id = request["id"].to_string();
+        let (sender, receiver) = oneshot::channel();
+        self.pending.lock().insert(id.clone(), sender);
+        if self.closed.load(Ordering::SeqCst) {
+            self.pending.lock().remove(&id);
+            return Err(Error::Other("MCP server closed the connection".to_string()));
+        }
+        if let Err(e) = write_message(&self.stdin, &request).await {
+            self.pending.lock().remove(&id);
+            return Err(e);
+        }
+        receiver
+            .await
+            .map_err(|_| Error::Other("MCP server closed the connection".to_string()))
+    }
+
+    async fn notify(&self, notification: Value) -> Result<()> {
+        write_message(&self.stdin, &notification).await
+    }
+}
+
+impl Drop for StdioTransport {
+    fn drop(&mut self) {
+        self.reader.abort();
+    }
+}
+
+/// Writes `message` to the server as a single line.
+async fn write_message(stdin: &tokio::sync::Mutex<ChildStdin>, message: &Value) -> Result<()> {
+    let mut line = message.to_string();
+    line.push('\n');
+    let mut stdin = stdin.lock().await;
+    let written = match stdin.write_all(line.as_bytes()).await {
+        Ok(()) => stdin.flush().await,
+        Err(e) => Err(e),
+    };
+    written.map_err(|e| Error::Other(format!("Failed to write to MCP server: {e}")))
+}
+
+/// Answers a request sent by the server. Only `ping` is supported, as the client
+/// declares no capabilities.
+fn server_request_response(id: &Value, method: &Value) -> Value {
+    if method == "ping" {
+        json!({ "jsonrpc": "2.0", "id": id, "result": {} })
+    } else {
+        json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "error": { "code": -32601, "message": format!("Method not found: {method}") },
+        })
+    }
+}
+
+/// A transport to a remote server over streamable HTTP.
+///
+/// Each message is posted to the server's endpoint, which answers with either a JSON
+/// body or a stream of server-sent events ending with the response. The session id
+/// assigned by the server is sent with every subsequent message.
+pub struct HttpTransport {
+    client: reqwest::Client,
+    url: String,
+    headers: HeaderMap,
+    session_id: parking_lot::Mutex<Option<String>>,
+}
+
+impl HttpTransport {
+    /// Creates a transport to the MCP endpoint at `url`.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            client: crate::core::client::http_client(),
+            url: url.into(),
+            headers: HeaderMap::new(),
+            session_id: parking_lot::Mutex::new(None),
+        }
+    }
+
+    /// Adds a header sent with every message, e.g. `Authorization`.
+    ///
+    /// # Parameters
+    /// - `name`: The name of the header.
+    /// - `value`: The value of the header.
+    ///
+    /// # Returns
+    /// The transport, or an error if the header is invalid.
+    pub fn with_header(mut self, name: &str, value: &str) -> Result<Self> {
+        let name = HeaderName::try_from(name)
+            .map_err(|e| Error::InvalidInput(format!("Invalid header name: {e}")))?;
+        let value = HeaderValue::try_from(value)
+            .map_err(|e| Error::InvalidInput(format!("Invalid header value: {e}")))?;
+        self.headers.insert(name, value);
+        Ok(self)
+    }
+
+    /// Posts `message` and returns the response, once it succeeded.
+    async fn post(&self, message: &Value) -> Result<reqwest::Response> {
+        let mut request = self
+            .client
+            .post(&self.url)
+            .headers(self.headers.clone())
+            .header(ACCEPT, "application/json, text/event-stream")
+            .header(PROTOCOL_VERSION_HEADER, PROTOCOL_VERSION)
+            .json(message);
+        if let Some(session_id) = self.session_id.lock().clone() {
+            request = request.header(SESSION_ID_HEADER, session_id);
+        }
+
+        let response = request.send().await.map_err(|e| Error::ApiError {
+            status_code: e.status(),
+            details: format!("MCP request failed: {e}"),
+        })?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let details = response.text().await.unwrap_or_default();
+            return Err(Error::ApiError {
+                status_code: Some(status),
+                details: format!("MCP request failed: {details}"),
+            });
+        }
+        if let Some(session_id) = response
+            .headers()
+            .get(SESSION_ID_HEADER)
+            .and_then(|value| value.to_str().ok())
+        {
+            *self.session_id.lock() = Some(session_id.to_string());
+        }
+        Ok(response)
+    }
+}
+
+#[async_trait]
+impl McpTransport for HttpTransport {
+    async fn request(&self, request: Value) -> Result<Value> {
+        let response = self.post(&request).await?;
+        let status = response.status();
+        let is_event_stream = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|value| value.starts_with("text/event-stream"));
+        let body = response.text().await.map_err(|e| Error::ApiError {
+            status_code: Some(status),
+            details: format!("Failed to read MCP response: {e}"),
+        })?;
+
+        let response = if is_event_stream {
+            response_from_sse(&body, &request["id"])
+        } else {
+            serde_json::from_str(&body).ok()
+        };
+        response.ok_or_else(|| Error::ApiError {
+            status_code: Some(status),
+            details: format!("Invalid MCP response: {body}"),
+        })
+    }
+
+    async fn notify(&self, notification: Value) -> Result<()> {
+        self.post(&notification).await.map(|_| ())
+    }
+}
+
+/// Finds the response with the given id among the events of a server-sent event body.
+fn response_from_sse(body: &str, id: &Value) -> Option<Value> {
+    body.replace("\r\n", "\n")
+        .split("\n\n")
+        .filter_map(|event| {
+            let data = event
+                .lines()
+                .filter_map(|line| line.strip_prefix("data:"))
+                .map(|data| data.strip_prefix(' ').unwrap_or(data))
+                .collect::<Vec<_>>()
+                .join("\n");
+            serde_json::from_str::<Value>(&data).ok()
+        })
+        .find(|message| message.get("id") == Some(id) && message.get("method").is_none())
+}
+
+// ============================================================================
+// Section: protocol types
+// ============================================================================
+
+/// The name and version a server reported when the client connected.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct McpServerInfo {
+    /// The name of the server.
+    pub name: String,
+    /// The version of the server.
+    #[serde(default)]
+    pub version: String,
+}
+
+/// A tool listed by a server.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct McpTool {
+    /// The name of the tool.
+    pub name: String,
+    /// The description of the tool, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The JSON schema of the tool's arguments.
+    #[serde(rename = "inputSchema", default)]
+    pub input_schema: Value,
+}
+
+/// A piece of content returned by a tool call.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum McpContent {
+    /// Text content.
+    Text {
+        /// The text.
+        text: String,
+    },
+    /// A base64 encoded image.
+    Image {
+        /// The base64 encoded data.
+        data: String,
+        /// The media type of the image.
+        #[serde(rename = "mimeType")]
+        mime_type: String,
+    },
+    /// Base64 encoded audio.
+    Audio {
+        /// The base64 encoded data.
+        data: String,
+        /// The media type of the audio.
+        #[serde(rename = "mimeType")]
+        mime_type: String,
+    },
+    /// An embedded resource.
+    Resource {
+        /// The resource, with its `uri` and either `text` or `blob`.
+        resource: Value,
+    },
+    /// A link to a resource.
+    ResourceLink {
+        /// The URI of the resource.
+        uri: String,
+    },
+    /// A content type not known to this client.
+    #[serde(other)]
+    Other,
+}
+
+/// The result of a tool call.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct McpToolResult {
+    /// The content returned by the tool.
+    #[serde(default)]
+    pub content: Vec<McpContent>,
+    /// The structured output of the tool, if it declares an output schema.
+    #[serde(
+        rename = "structuredContent",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub structured_content: Option<Value>,
+    /// Whether the tool failed.
+    #[serde(rename = "isError", default)]
+    pub is_error: bool,
+}
+
+impl McpToolResult {
+    /// Returns the content of the result as a single string, one line per piece of
+    /// content. Images and audio are returned as data URLs, resources by their text
+    /// or URI.
+    pub fn text(&self) -> String {
+        if self.content.is_empty() {
+            return self
+                .structured_content
+                .as_ref()
+                .map(Value::to_string)
+                .unwrap_or_default();
+        }
+        self.content
+            .iter()
+            .filter_map(|content| match content {
+                McpContent::Text { text } => Some(text.clone()),
+                McpContent::Image { data, mime_type } | McpContent::Audio { data, mime_type } => {
+                    Some(format!("data:{mime_type};base64,{data}"))
+                }
+                McpContent::Resource { resource } => resource
+                    .get("text")
+                    .or_else(|| resource.get("uri"))
+                    .and_then(Value::as_str)
+                    .map(str::to_string),
+                McpContent::ResourceLink { uri } => Some(uri.clone()),
+                McpContent::Other => None,
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+// ============================================================================
+// Section: client
+// ============================================================================
+
+/// A client connected to an MCP server.
+///
+/// The client is cheap to clone; clones share the connection, which is closed once
+/// the client and all the tools created from it are dropped.
+#[derive(Clone)]
+pub struct McpClient {
+    transport: Arc<dyn McpTransport>,
+    next_id: Arc<AtomicU64>,
+    server_info: McpServerInfo,
+    instructions: Option<String>,
+}
+
+impl McpClient {
+    /// Connects to a server over `transport`, performing the initialization handshake.
+    ///
+    /// # Parameters
+    /// - `transport`: The transport to the server.
+    ///
+    /// # Returns
+    /// The connected client, or an error if the server rejected the connection.
+    pub async fn connect(transport: impl McpTransport + 'static) -> Result<Self> {
+        let mut client = Self {
+            transport: Arc::new(transport),
+            next_id: Arc::new(AtomicU64::new(1)),
+            server_info: McpServerInfo::default(),
+            instructions: None,
+        };
+
+        let result = client
+            .request(
+                "initialize",
+                json!({
+                    "protocolVersion": PROTOCOL_VERSION,
+                    "capabilities": {},
+                    "clientInfo": { "name": "aisdk", "version": env!("CARGO_PKG_VERSION") },
+                }),
+            )
+            .await?;
+        client.server_info = result
+            .get("serverInfo")
+            .cloned()
+            .and_then(|info| serde_json::from_value(info).ok())
+            .unwrap_or_default();
+        client.instructions = result
+            .get("instructions")
+            .and_then(Value::as_str)
+            .map(str::to_string);
+
+        client
+            .transport
+            .notify(json!({ "jsonrpc": "2.0", "method": "notifications/initialized" }))
+            .await?;
+        Ok(client)
+    }
+
+    /// Spawns `command` and connects to it over stdio.
+    pub async fn stdio(command: Command) -> Result<Self> {
+        Self::connect(StdioTransport::spawn(command)?).await
+    }
+
+    /// Connects to the streamable HTTP endpoint at `url`. Use
+    /// [`connect`](Self::connect) with an [`HttpTransport`] to send extra headers.
+    pub async fn http(url: impl Into<String>) -> Result<Self> {
+        Self::connect(HttpTransport::new(url)).await
+    }
+
+    /// Returns the name and version of the server.
+    pub fn server_info(&self) -> &McpServerInfo {
+        &self.server_info
+    }
+
+    /// Returns the instructions the server gave for using it, if any.
+    pub fn instructions(&self) -> Option<&str> {
+        self.instructions.as_deref()
+    }
+
+    /// Lists all the tools of the server.
+    pub async fn list_tools(&self) -> Result<Vec<McpTool>> {
+        let mut tools = Vec::new();
+        let mut cursor: Option<String> = None;
+        loop {
+            let params = match &cursor {
+                Some(cursor) => json!({ "cursor": cursor }),
+                None => json!({}),
+            };
+            let mut result = self.request("tools/list", params).await?;
+            let page: Vec<McpTool> = serde_json::from_value(result["tools"].take())
+                .map_err(|e| Error::Other(format!("Invalid MCP tool list: {e}")))?;
+            tools.extend(page);
+
+            cursor = result
+                .get("nextCursor")
+                .and_then(Value::as_str)
+                .map(str::to_string);
+            if cursor.is_none() {
+                return Ok(tools);
+            }
+        }
+    }
+
+    /// Calls the tool `name` of the server with `arguments`.
+    ///
+    /// A tool that failed is not an error, but a result with
+    /// [`is_error`](McpToolResult::is_error) set.
+    pub async fn call_tool(&self, name: &str, arguments: Value) -> Result<McpToolResult> {
+        let arguments = if arguments.is_null() {
+            json!({})
+        } else {
+            arguments
+        };
+        let result = self
+            .request(
+                "tools/call",
+                json!({ "name": name, "arguments": arguments }),
+            )
+            .await?;
+        serde_json::from_value(result)
+            .map_err(|e| Error::Other(format!("Invalid MCP tool result: {e}")))
+    }
+
+    /// Lists the tools of the server as [`Tool`]s that call back to the server when
+    /// executed.
+    pub async fn tools(&self) -> Result<Vec<Tool>> {
+        Ok(self
+            .list_tools()
+            .await?
+            .into_iter()
+            .map(|tool| self.to_tool(tool))
+            .collect())
+    }
+
+    /// Converts `tool` into a [`Tool`] that calls back to the server when executed.
+    ///
+    /// A result with [`is_error`](McpToolResult::is_error) set is returned to the
+    /// model as a tool error.
+    pub fn to_tool(&self, tool: McpTool) -> Tool {
+        // Fall back to an object without constraints if the schema is not an object
+        let input_schema = Schema::try_from(tool.input_schema)
+            .unwrap_or_else(|_| json_schema!({ "type": "object" }));
+        let client = self.clone();
+        let name = tool.name.clone();
+        let execute = ToolExecute::new_async(Box::new(move |arguments| {
+            let client = client.clone();
+            let name = name.clone();
+            Box::pin(async move {
+                let result = client
+                    .call_tool(&name, arguments)
+                    .await
+                    .map_err(String::from)?;
+                if result.is_error {
+                    Err(result.text())
+                } else {
+                    Ok(result.text())
+                }
+            })
+        }));
+
+        Tool {
+            name: tool.name,
+            description: tool.description.unwrap_or_default(),
+            input_schema,
+            execute,
+        }
+    }
+
+    /// Sends a request and returns its result.
+    async fn request(&self, method: &str, params: Value) -> Result<Value> {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let mut response = self
+            .transport
+            .request(json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params }))
+            .await?;
+
+        if let Some(error) = response.get("error") {
+            return Err(Error::ApiError {
+                status_code: None,
+                details: format!(
+                    "MCP error {}: {}",
+                    error["code"],
+                    error["message"].as_str().unwrap_or_default()
+                ),
+            });
+        }
+        Ok(response["result"].take())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A server answering from canned results and recording the messages it got.
+    #[derive(Clone, Default)]
+    struct MockTransport {
+        results: Arc<parking_lot::Mutex<HashMap<String, Vec<Value>>>>,
+        messages: Arc<parking_lot::Mutex<Vec<Value>>>,
+    }
+
+    impl MockTransport {
+        fn answer(self, method: &str, result: Value) -> Self {
+            self.results
+                .lock()
+                .entry(method.to_string())
+                .or_default()
+                .push(result);
+            self
+        }
+
+        fn methods(&self) -> Vec<String> {
+            self.messages
+                .lock()
+                .iter()
+                .map(|message| message["method"].as_str().unwrap().to_string())
+                .collect()
+        }
+    }
+
+    #[async_trait]
+    impl McpTransport for MockTransport {
+        async fn request(&self, request: Value) -> Result<Value> {
+            self.messages.lock().push(request.clone());
+            let method = request["method"].as_str().unwrap();
+            let mut results = self.results.lock();
+            let queue = results.get_mut(method).filter(|queue| !queue.is_empty());
+            Ok(match queue {
+                Some(queue) => {
+                    json!({ "jsonrpc": "2.0", "id": request["id"], "result": queue.remove(0) })
+                }
+                None => json!({
+                    "jsonrpc": "2.0",
+                    "id": request["id"],
+                    "error": { "code": -32601, "message": "Method not found" },
+                }),
+            })
+        }
+
+        async fn notify(&self, notification: Value) -> Result<()> {
+            self.messages.lock().push(notification);
+            Ok(())
+        }
+    }
+
+    fn server() -> MockTransport {
+        MockTransport::default().answer(
+            "initialize",
+            json!({
+                "protocolVersion": PROTOCOL_VERSION,
+                "capabilities": { "tools": {} },
+                "serverInfo": { "name": "mock", "version": "1.0.0" },
+                "instructions": "Use the add tool for sums",
+            }),
+        )
+    }
+
+    fn add_tool() -> Value {
+        json!({
+            "name": "add",
+            "description": "Adds two numbers",
+            "inputSchema": {
+                "type": "object",
+                "properties": { "a": { "type": "number" }, "b": { "type": "number" } },
+                "required": ["a", "b"],
+            },
+        })
+    }
+
+    #[tokio::test]
+    async fn test_connect_performs_handshake() {
+        let transport = server();
+        let client = McpClient::connect(transport.clone()).await.unwrap();
+
+        assert_eq!(client.server_info().name, "mock");
+        assert_eq!(client.instructions(), Some("Use the add tool for sums"));
+        assert_eq!(
+            transport.methods(),
+            ["initialize", "notifications/initialized"]
+        );
+        let initialize = transport.messages.lock()[0].clone();
+        assert_eq!(initialize["params"]["protocolVersion"], PROTOCOL_VERSION);
+    }
+
+    #[tokio::test]
+    async fn test_list_tools_follows_cursor() {
+        let transport = server()
+            .answer(
+                "tools/list",
+                json!({ "tools": [add_tool()], "nextCursor": "2" }),
+            )
+            .answer(
+                "tools/list",
+                json!({ "tools": [{ "name": "echo", "inputSchema": {} }] }),
+            );
+        let client = McpClient::connect(transport.clone()).await.unwrap();
+
+        let tools = client.list_tools().await.unwrap();
+
+        let names: Vec<_> = tools.iter().map(|tool| tool.name.as_str()).collect();
+        assert_eq!(names, ["add", "echo"]);
+        assert_eq!(transport.messages.lock()[3]["params"]["cursor"], "2");
+    }
+
+    #[tokio::test]
+    async fn test_tools_proxy_calls_to_server() {
+        let transport = server()
+            .answer("tools/list", json!({ "tools": [add_tool()] }))
+            .answer(
+                "tools/call",
+                json!({ "content": [{ "type": "text", "text": "5" }] }),
+            );
+        let client = McpClient::connect(transport.clone()).await.unwrap();
+
+        let tools = client.tools().await.unwrap();
+        assert_eq!(tools[0].name, "add");
+        assert_eq!(tools[0].description, "Adds two numbers");
+        assert_eq!(
+            tools[0].input_schema.get("required"),
+            Some(&json!(["a", "b"]))
+        );
+
+        let output = tools[0]
+            .execute
+            .call_async(json!({ "a": 2, "b": 3 }))
+            .await
+            .unwrap();
+        assert_eq!(output, "5");
+        let call = transport.messages.lock().last().unwrap().clone();
+        assert_eq!(
+            call["params"],
+            json!({ "name": "add", "arguments": { "a": 2, "b": 3 } })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_tool_error_result_fails_tool() {
+        let transport = server().answer(
+            "tools/call",
+            json!({ "content": [{ "type": "text", "text": "division by zero" }], "isError": true }),
+        );
+        let client = McpClient::connect(transport).await.unwrap();
+        let tool = client.to_tool(serde_json::from_value(add_tool()).unwrap());
+
+        let error = tool.execute.call_async(json!({})).await.unwrap_err();
+
+        assert!(error.to_string().contains("division by zero"));
+    }
+
+    #[tokio::test]
+    async fn test_json_rpc_error() {
+        let client = McpClient::connect(server()).await.unwrap();
+
+        let error = client.call_tool("missing", Value::Null).await.unwrap_err();
+
+        assert!(error.to_string().contains("Method not found"));
+    }
+
+    #[test]
+    fn test_result_text() {
+        let result: McpToolResult = serde_json::from_value(json!({
+            "content": [
+                { "type": "text", "text": "chart:" },
+                { "type": "image", "data": "iVBORw0KGgo=", "mimeType": "image/png" },
+                { "type": "resource", "resource": { "uri": "file:///a.txt", "text": "contents" } },
+                { "type": "unknown" },
+            ],
+        }))
+        .unwrap();
+
+        assert_eq!(
+            result.text(),
+            "chart:\ndata:image/png;base64,iVBORw0KGgo=\ncontents"
+        );
+    }
+
+    #[test]
+    fn test_response_from_sse() {
+        let body = "event: message\r\ndata: {\"jsonrpc\":\"2.0\",\"method\":\"notifications/progress\"}\r\n\r\n\
+                    event: message\r\ndata: {\"jsonrpc\":\"2.0\",\"id\":2,\"result\":{}}\r\n\r\n";
+
+        let response = response_from_sse(body, &json!(2)).unwrap();
+
+        assert_eq!(response, json!({ "jsonrpc": "2.0", "id": 2, "result": {} }));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_stdio_transport() {
+        // Answers initialize, skips the initialized notification, then lists one tool
+        let script = r#"
+            read line
+            echo '{"jsonrpc":"2.0","id":1,"result":{"serverInfo":{"name":"sh","version":"0"}}}'
+            read line
+            read line
+            echo 'not json'
+            echo '{"jsonrpc":"2.0","id":2,"result":{"tools":[{"name":"echo","inputSchema":{"type":"object"}}]}}'
+            read line
+        "#;
+        let mut command = Command::new("sh");
+        command.args(["-c", script]);
+
+        let client = McpClient::stdio(command).await.unwrap();
+        let tools = client.list_tools().await.unwrap();
+
+        assert_eq!(client.server_info().name, "sh");
+        assert_eq!(tools[0].name, "echo");
+        // The script exits without answering
+        assert!(client.list_tools().await.is_err());
+    }
+}
@@ -0,0 +1,446 @@
+//! An MCP server publishing the crate's tools.
+
+use super::{McpContent, McpServerInfo, McpTool, McpToolResult, PROTOCOL_VERSION};
+use crate::core::tools::Tool;
+use crate::error::{Error, Result};
+use serde_json::{Value, json};
+use std::sync::Arc;
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader};
+
+/// The protocol versions the server accepts, from newest to oldest.
+const SUPPORTED_PROTOCOL_VERSIONS: [&str; 3] = [PROTOCOL_VERSION, "2025-03-26", "2024-11-05"];
+
+/// The JSON-RPC error code of a message that is not valid JSON.
+const PARSE_ERROR: i64 = -32700;
+
+/// The JSON-RPC error code of an unknown method.
+const METHOD_NOT_FOUND: i64 = -32601;
+
+/// The JSON-RPC error code of invalid method parameters.
+const INVALID_PARAMS: i64 = -32602;
+
+/// A server publishing [`Tool`]s over the Model Context Protocol, so MCP hosts such
+/// as Claude Desktop can call them.
+///
+/// The server is served over stdio with [`serve_stdio`](Self::serve_stdio), or over
+/// streamable HTTP by passing request bodies to
+/// [`handle_http_body`](Self::handle_http_body), which
+/// [`into_axum_router`](Self::into_axum_router) does for Axum. It only answers with
+/// JSON, never opening an event stream, and keeps no session.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use aisdk::core::tools::Tool;
+/// use aisdk::integrations::mcp::McpServer;
+///
+/// # fn weather_tool() -> Tool { unimplemented!() }
+/// # tokio_test_block_on(async {
+/// McpServer::new("weather", "1.0.0")
+///     .with_tool(weather_tool())
+///     .serve_stdio()
+///     .await?;
+/// # Ok::<_, aisdk::Error>(())
+/// # });
+/// # fn tokio_test_block_on<F: std::future::Future>(f: F) -> F::Output {
+/// #     tokio::runtime::Runtime::new().unwrap().block_on(f)
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct McpServer {
+    info: McpServerInfo,
+    instructions: Option<String>,
+    tools: Vec<Tool>,
+}
+
+impl McpServer {
+    /// Creates a server without tools.
+    ///
+    /// # Parameters
+    /// - `name`: The name reported to clients.
+    /// - `version`: The version reported to clients.
+    pub fn new(name: impl Into<String>, version: impl Into<String>) -> Self {
+        Self {
+            info: McpServerInfo {
+                name: name.into(),
+                version: version.into(),
+            },
+            instructions: None,
+            tools: Vec::new(),
+        }
+    }
+
+    /// Sets the instructions given to clients for using the server.
+    pub fn with_instructions(mut self, instructions: impl Into<String>) -> Self {
+        self.instructions = Some(instructions.into());
+        self
+    }
+
+    /// Publishes a tool.
+    ///
+    /// # Parameters
+    /// - `tool`: The tool, either a [`Tool`] or a
+    ///   [`TypedTool`](crate::core::tools::TypedTool).
+    ///
+    /// # Returns
+    /// The server with the tool added.
+    pub fn with_tool(mut self, tool: impl Into<Tool>) -> Self {
+        self.tools.push(tool.into());
+        self
+    }
+
+    /// Handles a JSON-RPC message and returns the response, or `None` if the message
+    /// is a notification or a response, which have no response.
+    pub async fn handle_message(&self, message: Value) -> Option<Value> {
+        let id = message.get("id")?;
+        let method = message.get("method")?.as_str().unwrap_or_default();
+        let params = message.get("params").cloned().unwrap_or(Value::Null);
+
+        let result = match method {
+            "initialize" => Ok(self.initialize(&params)),
+            "ping" => Ok(json!({})),
+            "tools/list" => Ok(self.list_tools()),
+            "tools/call" => self.call_tool(params).await,
+            _ => Err((METHOD_NOT_FOUND, format!("Method not found: {method}"))),
+        };
+        Some(match result {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            Err((code, message)) => error_response(id, code, message),
+        })
+    }
+
+    /// Handles the body of a streamable HTTP `POST`. Answer with the returned message
+    /// as `application/json`, or with `202 Accepted` if there is none.
+    pub async fn handle_http_body(&self, body: &[u8]) -> Option<Value> {
+        match serde_json::from_slice(body) {
+            Ok(message) => self.handle_message(message).await,
+            Err(e) => Some(error_response(&Value::Null, PARSE_ERROR, e.to_string())),
+        }
+    }
+
+    /// Serves newline-delimited JSON-RPC messages read from `reader`, writing the
+    /// responses to `writer`, until `reader` ends. Tool calls run concurrently.
+    pub async fn serve<R, W>(self, reader: R, mut writer: W) -> Result<()>
+    where
+        R: AsyncRead + Unpin,
+        W: AsyncWrite + Unpin,
+    {
+        let server = Arc::new(self);
+        let (sender, mut responses) = tokio::sync::mpsc::unbounded_channel::<Value>();
+        // Dropped once the reader ends, so the responses end with the last tool call
+        let mut sender = Some(sender);
+        let mut lines = BufReader::new(reader).lines();
+
+        loop {
+            tokio::select! {
+                line = lines.next_line(), if sender.is_some() => {
+                    let line = line.map_err(|e| Error::Other(format!("Failed to read MCP message: {e}")))?;
+                    let (Some(line), Some(sender)) = (line, &sender) else {
+                        sender = None;
+                        continue;
+                    };
+                    if line.trim().is_empty() {
+                        continue;
+                    }
+                    let server = server.clone();
+                    let sender = sender.clone();
+                    tokio::spawn(async move {
+                        if let Some(response) = server.handle_http_body(line.as_bytes()).await {
+                            let _ = sender.send(response);
+                        }
+                    });
+                }
+                response = responses.recv() => {
+                    let Some(response) = response else {
+                        return Ok(());
+                    };
+                    let mut line = response.to_string();
+                    line.push('\n');
+                    writer
+                        .write_all(line.as_bytes())
+                        .await
+                        .map_err(|e| Error::Other(format!("Failed to write MCP message: {e}")))?;
+                    writer
+                        .flush()
+                        .await
+                        .map_err(|e| Error::Other(format!("Failed to write MCP message: {e}")))?;
+                }
+            }
+        }
+    }
+
+    /// Serves over the process's stdin and stdout, as MCP hosts expect from servers
+    /// they launch. Nothing else may be written to stdout; log to stderr instead.
+    pub async fn serve_stdio(self) -> Result<()> {
+        self.serve(tokio::io::stdin(), tokio::io::stdout()).await
+    }
+
+    /// Converts the server into an Axum router answering streamable HTTP `POST`s at its
+    /// root. Nest it at the endpoint of the server, e.g. `/mcp`.
+    #[cfg(feature = "axum")]
+    pub fn into_axum_router(self) -> axum::Router {
+        axum::Router::new()
+            .route("/", axum::routing::post(handle_axum_request))
+            .with_state(Arc::new(self))
+    }
+
+    /// Answers an `initialize` request.
+    fn initialize(&self, params: &Value) -> Value {
+        let requested = params["protocolVersion"].as_str().unwrap_or_default();
+        let version = SUPPORTED_PROTOCOL_VERSIONS
+            .into_iter()
+            .find(|version| *version == requested)
+            .unwrap_or(PROTOCOL_VERSION);
+
+        let mut result = json!({
+            "protocolVersion": version,
+            "capabilities": { "tools": {} },
+            "serverInfo": self.info,
+        });
+        if let Some(instructions) = &self.instructions {
+            result["instructions"] = json!(instructions);
+        }
+        result
+    }
+
+    /// Answers a `tools/list` request with all the tools, on a single page.
+    fn list_tools(&self) -> Value {
+        let tools: Vec<McpTool> = self
+            .tools
+            .iter()
+            .map(|tool| McpTool {
+                name: tool.name.clone(),
+                description: Some(tool.description.clone()).filter(|d| !d.is_empty()),
+                input_schema: tool.input_schema.as_value().clone(),
+            })
+            .collect();
+        json!({ "tools": tools })
+    }
+
+    /// Answers a `tools/call` request. A failed tool is reported in the result, so the
+    /// client's model can see the error.
+    async fn call_tool(&self, params: Value) -> std::result::Result<Value, (i64, String)> {
+        let name = params["name"].as_str().unwrap_or_default();
+        let tool = self
+            .tools
+            .iter()
+            .find(|tool| tool.name == name)
+            .ok_or_else(|| (INVALID_PARAMS, format!("Unknown tool: {name}")))?;
+        let arguments = match params.get("arguments") {
+            Some(Value::Null) | None => json!({}),
+            Some(arguments) => arguments.clone(),
+        };
+
+        let (text, is_error) = match tool.execute.call_async(arguments).await {
+            Ok(output) => (output, false),
+            Err(Error::ToolCallError(error)) => (error, true),
+            Err(error) => (error.to_string(), true),
+        };
+        let result = McpToolResult {
+            content: vec![McpContent::Text { text }],
+            structured_content: None,
+            is_error,
+        };
+        Ok(serde_json::to_value(result).unwrap_or_default())
+    }
+}
+
+/// Builds a JSON-RPC error response.
+fn error_response(id: &Value, code: i64, message: String) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": message } })
+}
+
+#[cfg(feature = "axum")]
+async fn handle_axum_request(
+    axum::extract::State(server): axum::extract::State<Arc<McpServer>>,
+    body: bytes::Bytes,
+) -> axum::response::Response {
+    use axum::response::IntoResponse;
+
+    match server.handle_http_body(&body).await {
+        Some(response) => axum::Json(response).into_response(),
+        None => axum::http::StatusCode::ACCEPTED.into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::tools::ToolExecute;
+    use crate::integrations::mcp::{McpClient, McpTransport};
+    use async_trait::async_trait;
+    use schemars::json_schema;
+
+    /// A transport handing messages straight to a server.
+    struct InProcess(McpServer);
+
+    #[async_trait]
+    impl McpTransport for InProcess {
+        async fn request(&self, request: Value) -> Result<Value> {
+            self.0
+                .handle_message(request)
+                .await
+                .ok_or_else(|| Error::Other("no response".to_string()))
+        }
+
+        async fn notify(&self, notification: Value) -> Result<()> {
+            assert!(self.0.handle_message(notification).await.is_none());
+            Ok(())
+        }
+    }
+
+    fn divide_tool() -> Tool {
+        Tool {
+            name: "divide".to_string(),
+            description: "Divides a by b".to_string(),
+            input_schema: json_schema!({
+                "type": "object",
+                "properties": { "a": { "type": "number" }, "b": { "type": "number" } },
+            }),
+            execute: ToolExecute::new(Box::new(|params| {
+                let a = params["a"].as_f64().unwrap_or_default();
+                let b = params["b"].as_f64().unwrap_or_default();
+                if b == 0.0 {
+                    return Err("division by zero".to_string());
+                }
+                Ok((a / b).to_string())
+            })),
+        }
+    }
+
+    fn server() -> McpServer {
+        McpServer::new("calculator", "1.0.0")
+            .with_instructions("Use divide for quotients")
+            .with_tool(divide_tool())
+    }
+
+    #[tokio::test]
+    async fn test_client_round_trip() {
+        let client = McpClient::connect(InProcess(server())).await.unwrap();
+
+        assert_eq!(client.server_info().name, "calculator");
+        assert_eq!(client.instructions(), Some("Use divide for quotients"));
+
+        let tools = client.tools().await.unwrap();
+        assert_eq!(tools[0].name, "divide");
+        assert_eq!(
+            tools[0].input_schema.get("properties"),
+            divide_tool().input_schema.get("properties")
+        );
+
+        let output = tools[0]
+            .execute
+            .call_async(json!({ "a": 6, "b": 3 }))
+            .await
+            .unwrap();
+        assert_eq!(output, "2");
+    }
+
+    #[tokio::test]
+    async fn test_failed_tool_is_error_result() {
+        let response = server()
+            .handle_message(json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "method": "tools/call",
+                "params": { "name": "divide", "arguments": { "a": 1, "b": 0 } },
+            }))
+            .await
+            .unwrap();
+
+        assert_eq!(response["result"]["isError"], true);
+        assert_eq!(response["result"]["content"][0]["text"], "division by zero");
+    }
+
+    #[tokio::test]
+    async fn test_errors() {
+        let server = server();
+
+        let unknown_tool = server
+            .handle_message(json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "method": "tools/call",
+                "params": { "name": "multiply" },
+            }))
+            .await
+            .unwrap();
+        assert_eq!(unknown_tool["error"]["code"], INVALID_PARAMS);
+
+        let unknown_method = server
+            .handle_message(json!({ "jsonrpc": "2.0", "id": 2, "method": "prompts/list" }))
+            .await
+            .unwrap();
+        assert_eq!(unknown_method["error"]["code"], METHOD_NOT_FOUND);
+
+        let invalid_json = server.handle_http_body(b"{").await.unwrap();
+        assert_eq!(invalid_json["error"]["code"], PARSE_ERROR);
+    }
+
+    #[tokio::test]
+    async fn test_initialize_negotiates_version() {
+        let initialize = |version: &str| {
+            json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "method": "initialize",
+                "params": { "protocolVersion": version },
+            })
+        };
+
+        let older = server()
+            .handle_message(initialize("2025-03-26"))
+            .await
+            .unwrap();
+        let unknown = server()
+            .handle_message(initialize("1999-01-01"))
+            .await
+            .unwrap();
+
+        assert_eq!(older["result"]["protocolVersion"], "2025-03-26");
+        assert_eq!(unknown["result"]["protocolVersion"], PROTOCOL_VERSION);
+    }
+
+    #[tokio::test]
+    async fn test_serve_lines() {
+        let input = concat!(
+            r#"{"jsonrpc":"2.0","method":"notifications/initialized"}"#,
+            "\n",
+            r#"{"jsonrpc":"2.0","id":1,"method":"ping"}"#,
+            "\n",
+        );
+        let mut output = Vec::new();
+
+        server().serve(input.as_bytes(), &mut output).await.unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+        let responses: Vec<Value> = output
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(
+            responses,
+            [json!({ "jsonrpc": "2.0", "id": 1, "result": {} })]
+        );
+    }
+
+    #[cfg(feature = "axum")]
+    #[tokio::test]
+    async fn test_axum_handler() {
+        let server = Arc::new(server());
+
+        let response = handle_axum_request(
+            axum::extract::State(server.clone()),
+            r#"{"jsonrpc":"2.0","id":1,"method":"tools/list"}"#.into(),
+        )
+        .await;
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+
+        let response = handle_axum_request(
+            axum::extract::State(server),
+            r#"{"jsonrpc":"2.0","method":"notifications/initialized"}"#.into(),
+        )
+        .await;
+        assert_eq!(response.status(), axum::http::StatusCode::ACCEPTED);
+    }
+}
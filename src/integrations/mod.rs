@@ -1,5 +1,14 @@
 //! Provides extra integrations for seamless use with common libraries and frameworks.
 
+#[cfg(feature = "actix")]
+pub mod actix;
+pub mod ag_ui;
 #[cfg(feature = "axum")]
 pub mod axum;
+#[cfg(feature = "langfuse")]
+pub mod langfuse;
+#[cfg(feature = "mcp")]
+pub mod mcp;
+pub mod resumable_stream;
+pub mod sse;
 pub mod vercel_aisdk_ui;
@@ -0,0 +1,318 @@
+//! Resumable streams: journaled chunks that a reconnecting client can replay.
+//!
+//! [`ResumableStream::create`] forwards the chunks of a stream, e.g. the serialized
+//! Vercel UI chunks of a response, to the client while journaling each one to a
+//! [`StreamStore`] under a stream id. The journal keeps filling even if the client
+//! disconnects. When the client reconnects, [`ResumableStream::resume`] replays the
+//! chunks it missed and then follows the journal until the stream finishes.
+//!
+//! [`InMemoryStreamStore`] journals to memory, for a single server process. Implement
+//! [`StreamStore`] to share journals between processes, e.g. with Redis lists.
+//!
+//! # Example
+//!
+//! ```
+//! use aisdk::integrations::resumable_stream::{InMemoryStreamStore, ResumableStream};
+//! use futures::StreamExt;
+//!
+//! # tokio_test_block_on(async {
+//! let streams = ResumableStream::new(InMemoryStreamStore::new());
+//! let chunks = futures::stream::iter(["Hello".to_string(), " world".to_string()]);
+//!
+//! let mut live = streams.create("chat-1", chunks);
+//! assert_eq!(live.next().await.unwrap().unwrap(), "Hello");
+//! drop(live); // The client disconnects
+//!
+//! // On reconnect, replay from the first chunk the client did not receive
+//! let resumed = streams.resume("chat-1", 1).await.unwrap().unwrap();
+//! let missed: Vec<String> = resumed.map(|chunk| chunk.unwrap()).collect().await;
+//! assert_eq!(missed, vec![" world"]);
+//! # });
+//! # fn tokio_test_block_on<F: std::future::Future>(f: F) -> F::Output {
+//! #     tokio::runtime::Runtime::new().unwrap().block_on(f)
+//! # }
+//! ```
+
+use crate::error::Result;
+use async_trait::async_trait;
+use futures::{Stream, StreamExt};
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tokio::sync::Notify;
+use tokio::sync::mpsc::{self, UnboundedReceiver};
+
+/// How long [`StreamStore::wait`] sleeps by default before the journal is read again.
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+// ============================================================================
+// Section: traits
+// ============================================================================
+
+/// Persists the journals of resumable streams, keyed by stream id.
+#[async_trait]
+pub trait StreamStore: Send + Sync {
+    /// Appends `chunk` to the journal of stream `id`, creating the journal if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the chunk cannot be persisted.
+    async fn append(&self, id: &str, chunk: String) -> Result<()>;
+
+    /// Marks the journal of stream `id` as finished. No chunks are appended after it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the journal cannot be updated.
+    async fn finish(&self, id: &str) -> Result<()>;
+
+    /// Returns the chunks of stream `id` from index `from` on, or `None` if no stream
+    /// with this id has been journaled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the journal cannot be read.
+    async fn read(&self, id: &str, from: usize) -> Result<Option<JournalEntries>>;
+
+    /// Waits until the journal of stream `id` may hold more than `len` chunks, or may
+    /// have finished. Returning early is allowed; the journal is read again.
+    ///
+    /// Polls every [`DEFAULT_POLL_INTERVAL`] unless the store can be notified of
+    /// appends, e.g. with Redis pub/sub.
+    async fn wait(&self, _id: &str, _len: usize) {
+        crate::core::runtime::sleep(DEFAULT_POLL_INTERVAL).await;
+    }
+}
+
+/// Chunks read from the journal of a stream.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct JournalEntries {
+    /// The chunks, starting at the requested index.
+    pub chunks: Vec<String>,
+    /// Whether the stream has finished, so no more chunks follow.
+    pub finished: bool,
+}
+
+// ============================================================================
+// Section: resumable stream
+// ============================================================================
+
+/// Creates streams whose chunks are journaled to a [`StreamStore`], and resumes them.
+#[derive(Clone)]
+pub struct ResumableStream {
+    store: Arc<dyn StreamStore>,
+}
+
+impl ResumableStream {
+    /// Creates resumable streams journaled to `store`.
+    pub fn new(store: impl StreamStore + 'static) -> Self {
+        Self {
+            store: Arc::new(store),
+        }
+    }
+
+    /// Journals the chunks of `stream` under `id` and returns a stream that yields them.
+    ///
+    /// The chunks are consumed and journaled in a background task, so the journal
+    /// completes even if the returned stream is dropped. A chunk that cannot be
+    /// journaled is yielded as an error, and journaling stops.
+    pub fn create<S>(&self, id: impl Into<String>, stream: S) -> ChunkStream
+    where
+        S: Stream<Item = String> + Send + 'static,
+    {
+        let id = id.into();
+        let store = self.store.clone();
+        let (tx, receiver) = mpsc::unbounded_channel();
+
+        crate::core::runtime::spawn(async move {
+            let mut stream = Box::pin(stream);
+            while let Some(chunk) = stream.next().await {
+                if let Err(e) = store.append(&id, chunk.clone()).await {
+                    let _ = tx.send(Err(e));
+                    return;
+                }
+                let _ = tx.send(Ok(chunk));
+            }
+            if let Err(e) = store.finish(&id).await {
+                let _ = tx.send(Err(e));
+            }
+        });
+
+        ChunkStream { receiver }
+    }
+
+    /// Resumes stream `id`, yielding its chunks from index `from` on and then the
+    /// chunks appended after them, until the stream finishes.
+    ///
+    /// `from` is the number of chunks the client already received, e.g. one more than
+    /// the last SSE event id it saw.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the journal cannot be read. Returns `None` if no stream
+    /// with this id has been journaled.
+    pub async fn resume(&self, id: &str, from: usize) -> Result<Option<ChunkStream>> {
+        let Some(mut entries) = self.store.read(id, from).await? else {
+            return Ok(None);
+        };
+
+        let id = id.to_string();
+        let store = self.store.clone();
+        let (tx, receiver) = mpsc::unbounded_channel();
+
+        crate::core::runtime::spawn(async move {
+            let mut len = from;
+            loop {
+                len += entries.chunks.len();
+                for chunk in entries.chunks {
+                    if tx.send(Ok(chunk)).is_err() {
+                        return;
+                    }
+                }
+                if entries.finished {
+                    return;
+                }
+
+                store.wait(&id, len).await;
+                entries = match store.read(&id, len).await {
+                    Ok(entries) => entries.unwrap_or_default(),
+                    Err(e) => {
+                        let _ = tx.send(Err(e));
+                        return;
+                    }
+                };
+            }
+        });
+
+        Ok(Some(ChunkStream { receiver }))
+    }
+}
+
+/// A stream of the chunks of a [`ResumableStream`].
+pub struct ChunkStream {
+    receiver: UnboundedReceiver<Result<String>>,
+}
+
+impl Stream for ChunkStream {
+    type Item = Result<String>;
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_recv(cx)
+    }
+}
+
+// ============================================================================
+// Section: in-memory store
+// ============================================================================
+
+/// A [`StreamStore`] that keeps journals in memory, for a single server process.
+///
+/// Clones share the same journals. Journals are kept until [`remove`](Self::remove)d.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryStreamStore {
+    journals: Arc<Mutex<HashMap<String, JournalEntries>>>,
+    appended: Arc<Notify>,
+}
+
+impl InMemoryStreamStore {
+    /// Creates an empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Removes the journal of stream `id`.
+    pub fn remove(&self, id: &str) {
+        self.journals.lock().unwrap().remove(id);
+    }
+}
+
+#[async_trait]
+impl StreamStore for InMemoryStreamStore {
+    async fn append(&self, id: &str, chunk: String) -> Result<()> {
+        self.journals
+            .lock()
+            .unwrap()
+            .entry(id.to_string())
+            .or_default()
+            .chunks
+            .push(chunk);
+        self.appended.notify_waiters();
+        Ok(())
+    }
+
+    async fn finish(&self, id: &str) -> Result<()> {
+        self.journals
+            .lock()
+            .unwrap()
+            .entry(id.to_string())
+            .or_default()
+            .finished = true;
+        self.appended.notify_waiters();
+        Ok(())
+    }
+
+    async fn read(&self, id: &str, from: usize) -> Result<Option<JournalEntries>> {
+        Ok(self
+            .journals
+            .lock()
+            .unwrap()
+            .get(id)
+            .map(|journal| JournalEntries {
+                chunks: journal.chunks.iter().skip(from).cloned().collect(),
+                finished: journal.finished,
+            }))
+    }
+
+    async fn wait(&self, id: &str, len: usize) {
+        let appended = self.appended.notified();
+        tokio::pin!(appended);
+        // Register before checking, so an append in between is not missed
+        appended.as_mut().enable();
+
+        let grown = self
+            .journals
+            .lock()
+            .unwrap()
+            .get(id)
+            .is_none_or(|journal| journal.finished || journal.chunks.len() > len);
+        if !grown {
+            appended.await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_resume_follows_live_stream() {
+        let streams = ResumableStream::new(InMemoryStreamStore::new());
+        let (tx, rx) = mpsc::unbounded_channel();
+        let chunks = futures::stream::unfold(rx, |mut rx| async move {
+            rx.recv().await.map(|chunk| (chunk, rx))
+        });
+
+        let mut live = streams.create("chat", chunks);
+        tx.send("a".to_string()).unwrap();
+        tx.send("b".to_string()).unwrap();
+        assert_eq!(live.next().await.unwrap().unwrap(), "a");
+        assert_eq!(live.next().await.unwrap().unwrap(), "b");
+        drop(live);
+
+        let resumed = streams.resume("chat", 1).await.unwrap().unwrap();
+        tx.send("c".to_string()).unwrap();
+        drop(tx);
+
+        let chunks: Vec<String> = resumed.map(|chunk| chunk.unwrap()).collect().await;
+        assert_eq!(chunks, vec!["b", "c"]);
+    }
+
+    #[tokio::test]
+    async fn test_resume_unknown_stream() {
+        let streams = ResumableStream::new(InMemoryStreamStore::new());
+
+        assert!(streams.resume("missing", 0).await.unwrap().is_none());
+    }
+}
@@ -0,0 +1,223 @@
+//! Framework-agnostic encoding of the crate's streams as server-sent events.
+//!
+//! [`ToSseBytes::to_sse_bytes`] turns a stream of Vercel UI chunks, or of already
+//! serialized chunks such as those of a
+//! [`ResumableStream`](crate::integrations::resumable_stream::ResumableStream), into a
+//! stream of [`Bytes`] framed as `data: ...\n\n` events. Any server that accepts a byte
+//! stream as a response body, e.g. warp, poem or hyper, can serve it without a
+//! dedicated integration. [`SseBytes::heartbeat`] adds comment lines while the stream
+//! is idle, so proxies keep the connection open.
+//!
+//! # Example
+//!
+//! ```
+//! use aisdk::integrations::sse::ToSseBytes;
+//! use aisdk::integrations::vercel_aisdk_ui::VercelUIStream;
+//! use futures::StreamExt;
+//! use std::time::Duration;
+//!
+//! # tokio_test_block_on(async {
+//! let chunks = futures::stream::iter([VercelUIStream::TextDelta {
+//!     id: "msg_1".to_string(),
+//!     delta: "Hello".to_string(),
+//!     provider_metadata: None,
+//! }]);
+//!
+//! let mut body = chunks.to_sse_bytes().heartbeat(Duration::from_secs(15));
+//! assert_eq!(
+//!     body.next().await.unwrap(),
+//!     "data: {\"type\":\"text-delta\",\"id\":\"msg_1\",\"delta\":\"Hello\"}\n\n"
+//! );
+//! # });
+//! # fn tokio_test_block_on<F: std::future::Future>(f: F) -> F::Output {
+//! #     tokio::runtime::Runtime::new().unwrap().block_on(f)
+//! # }
+//! ```
+
+use crate::integrations::vercel_aisdk_ui::VercelUIStream;
+use bytes::Bytes;
+use futures::{Stream, StreamExt};
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+/// The comment sent as a heartbeat while the stream is idle.
+const HEARTBEAT: &[u8] = b": keep-alive\n\n";
+
+/// An item that can be sent as the data of a server-sent event.
+pub trait SseData {
+    /// Returns the data of the event.
+    fn into_sse_data(self) -> String;
+}
+
+impl SseData for VercelUIStream {
+    fn into_sse_data(self) -> String {
+        serde_json::to_string(&self).unwrap_or_else(|e| {
+            VercelUIStream::Error {
+                error_text: format!("JSON serialization error: {e}"),
+            }
+            .into_sse_data()
+        })
+    }
+}
+
+/// Already serialized data, e.g. the journaled chunks of a resumable stream.
+impl SseData for String {
+    fn into_sse_data(self) -> String {
+        self
+    }
+}
+
+/// Errors are sent as Vercel UI `error` chunks.
+impl<T: SseData> SseData for crate::Result<T> {
+    fn into_sse_data(self) -> String {
+        match self {
+            Ok(data) => data.into_sse_data(),
+            Err(e) => VercelUIStream::Error {
+                error_text: e.to_string(),
+            }
+            .into_sse_data(),
+        }
+    }
+}
+
+/// Frames `data` as a server-sent event, prefixing each of its lines with `data: `.
+pub fn encode_sse_event(data: impl SseData) -> Bytes {
+    let data = data.into_sse_data();
+    let mut event = String::with_capacity(data.len() + 8);
+    for line in data.split('\n') {
+        event.push_str("data: ");
+        event.push_str(line.strip_suffix('\r').unwrap_or(line));
+        event.push('\n');
+    }
+    event.push('\n');
+    Bytes::from(event)
+}
+
+/// Converts a stream into a stream of server-sent events.
+pub trait ToSseBytes: Stream + Sized {
+    /// Returns a stream of the items of this stream, each framed as a server-sent event.
+    fn to_sse_bytes(self) -> SseBytes
+    where
+        Self: Send + 'static,
+        Self::Item: SseData,
+    {
+        SseBytes {
+            events: Box::pin(self.map(encode_sse_event)),
+            heartbeat: None,
+        }
+    }
+}
+
+impl<S: Stream> ToSseBytes for S {}
+
+/// A stream of server-sent events, ready to be sent as a response body.
+pub struct SseBytes {
+    events: Pin<Box<dyn Stream<Item = Bytes> + Send>>,
+    heartbeat: Option<Heartbeat>,
+}
+
+/// The interval of a heartbeat and the timer until the next one.
+struct Heartbeat {
+    interval: Duration,
+    timer: Pin<Box<dyn Future<Output = ()> + Send>>,
+}
+
+impl Heartbeat {
+    fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            timer: Box::pin(crate::core::runtime::sleep(interval)),
+        }
+    }
+
+    fn reset(&mut self) {
+        *self = Self::new(self.interval);
+    }
+}
+
+impl SseBytes {
+    /// Sends a comment line whenever no event was sent for `interval`.
+    pub fn heartbeat(mut self, interval: Duration) -> Self {
+        self.heartbeat = Some(Heartbeat::new(interval));
+        self
+    }
+}
+
+impl Stream for SseBytes {
+    type Item = Bytes;
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if let Poll::Ready(event) = self.events.as_mut().poll_next(cx) {
+            if let Some(heartbeat) = &mut self.heartbeat {
+                heartbeat.reset();
+            }
+            return Poll::Ready(event);
+        }
+        match &mut self.heartbeat {
+            Some(heartbeat) => heartbeat.timer.as_mut().poll(cx).map(|_| {
+                heartbeat.reset();
+                Some(Bytes::from_static(HEARTBEAT))
+            }),
+            None => Poll::Pending,
+        }
+    }
+}
+
+#[cfg(feature = "language-model-request")]
+impl crate::core::StreamTextResponse {
+    /// Converts this response into a stream of server-sent events of Vercel AI SDK UI
+    /// chunks, including reasoning, start and finish chunks, for servers without a
+    /// dedicated integration.
+    ///
+    /// # Returns
+    /// The stream of events, to which a heartbeat can be added.
+    pub fn to_sse_bytes(self) -> SseBytes {
+        let options = crate::integrations::vercel_aisdk_ui::VercelUIStreamOptions {
+            send_reasoning: true,
+            send_start: true,
+            send_finish: true,
+            ..Default::default()
+        };
+        self.into_vercel_ui_stream(options).to_sse_bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+
+    #[test]
+    fn test_encode_multiline_data() {
+        assert_eq!(
+            encode_sse_event("first\r\nsecond".to_string()),
+            "data: first\ndata: second\n\n"
+        );
+    }
+
+    #[test]
+    fn test_encode_error() {
+        let event = encode_sse_event(Err::<String, _>(Error::Other("boom".to_string())));
+
+        assert_eq!(
+            event,
+            "data: {\"type\":\"error\",\"errorText\":\"AI SDK error: boom\"}\n\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_heartbeat_while_idle() {
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel::<String>();
+        let chunks = futures::stream::unfold(rx, |mut rx| async move {
+            rx.recv().await.map(|chunk| (chunk, rx))
+        });
+        let mut body = chunks.to_sse_bytes().heartbeat(Duration::from_millis(10));
+
+        assert_eq!(body.next().await.unwrap(), HEARTBEAT);
+        tx.send("done".to_string()).unwrap();
+        drop(tx);
+        assert_eq!(body.next().await.unwrap(), "data: done\n\n");
+        assert!(body.next().await.is_none());
+    }
+}
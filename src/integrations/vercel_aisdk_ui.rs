@@ -12,7 +12,18 @@ use serde_json::Value;
 use uuid;
 
 #[cfg(feature = "language-model-request")]
-use crate::core::LanguageModelStreamChunkType;
+use crate::core::image_model::ImageData;
+#[cfg(feature = "language-model-request")]
+use crate::core::language_model::{StopReason, StreamEvent};
+#[cfg(feature = "language-model-request")]
+use crate::core::tools::{ToolCallDelta, ToolCallInfo, ToolResultInfo};
+#[cfg(feature = "language-model-request")]
+use base64::{Engine, engine::general_purpose::STANDARD};
+#[cfg(feature = "language-model-request")]
+use std::collections::HashMap;
+
+/// The header that tells the Vercel AI SDK UI the response is a UI message stream.
+pub const UI_MESSAGE_STREAM_HEADER: &str = "x-vercel-ai-ui-message-stream";
 
 /// Vercel's ai-sdk UI message chunk types.
 /// These represent the JSON chunks sent over SSE to the frontend.
@@ -115,10 +126,78 @@ pub enum VercelUIStream {
         #[serde(rename = "providerMetadata", skip_serializing_if = "Option::is_none")]
         provider_metadata: Option<Value>,
     },
+    /// Output of an executed tool call (ai-sdk v6: tool-output-available)
+    #[serde(rename = "tool-output-available")]
+    ToolOutputAvailable {
+        /// Tool call ID
+        #[serde(rename = "toolCallId")]
+        tool_call_id: String,
+        /// Tool output
+        output: Value,
+    },
+    /// Error of an executed tool call (ai-sdk v6: tool-output-error)
+    #[serde(rename = "tool-output-error")]
+    ToolOutputError {
+        /// Tool call ID
+        #[serde(rename = "toolCallId")]
+        tool_call_id: String,
+        /// Error text
+        #[serde(rename = "errorText")]
+        error_text: String,
+    },
+    /// A URL the response is grounded in
+    #[serde(rename = "source-url")]
+    SourceUrl {
+        /// Source ID
+        #[serde(rename = "sourceId")]
+        source_id: String,
+        /// Source URL
+        url: String,
+        /// Optional title
+        #[serde(skip_serializing_if = "Option::is_none")]
+        title: Option<String>,
+        /// Optional provider metadata
+        #[serde(rename = "providerMetadata", skip_serializing_if = "Option::is_none")]
+        provider_metadata: Option<Value>,
+    },
+    /// A document the response is grounded in
+    #[serde(rename = "source-document")]
+    SourceDocument {
+        /// Source ID
+        #[serde(rename = "sourceId")]
+        source_id: String,
+        /// Media type of the document, e.g. `application/pdf`
+        #[serde(rename = "mediaType")]
+        media_type: String,
+        /// Document title
+        title: String,
+        /// Optional file name
+        #[serde(skip_serializing_if = "Option::is_none")]
+        filename: Option<String>,
+        /// Optional provider metadata
+        #[serde(rename = "providerMetadata", skip_serializing_if = "Option::is_none")]
+        provider_metadata: Option<Value>,
+    },
+    /// A file generated by the model
+    #[serde(rename = "file")]
+    File {
+        /// URL of the file, or a data URL holding its content
+        url: String,
+        /// Media type of the file
+        #[serde(rename = "mediaType")]
+        media_type: String,
+    },
+    /// Start of a model call
+    #[serde(rename = "start-step")]
+    StartStep,
+    /// End of a model call
+    #[serde(rename = "finish-step")]
+    FinishStep,
     /// Error chunk
     #[serde(rename = "error")]
     Error {
         /// Error text
+        #[serde(rename = "errorText")]
         error_text: String,
     },
     /// Not supported chunk by aisdk.rs
@@ -127,8 +206,77 @@ pub enum VercelUIStream {
         /// Error text
         error_text: String,
     },
-    // TODO: init - Add additional vercel UI chunks for data parts, sources, etc.
-    // as needed for full compatibility
+    /// Custom data chunk, sent as `data-{name}`. Create it with [`VercelUIStream::data`].
+    #[serde(untagged)]
+    Data(VercelUIDataPart),
+}
+
+impl VercelUIStream {
+    /// Creates a custom data chunk of type `data-{name}`, which `useChat` adds to the
+    /// message as a data part.
+    pub fn data(name: impl Into<String>, data: Value) -> Self {
+        Self::Data(VercelUIDataPart {
+            name: name.into(),
+            id: None,
+            data,
+            transient: false,
+        })
+    }
+}
+
+/// A custom data chunk of the UI message stream, of type `data-{name}`.
+///
+/// Chunks with the same name and `id` replace each other on the client, e.g. to update
+/// a progress indicator. Transient chunks are not added to the message history.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VercelUIDataPart {
+    /// Name of the data part, without the `data-` prefix
+    pub name: String,
+    /// Optional ID
+    pub id: Option<String>,
+    /// Data of the part
+    pub data: Value,
+    /// Whether the part is only sent to the client, not kept in the message
+    pub transient: bool,
+}
+
+/// The wire format of [`VercelUIDataPart`].
+#[derive(Serialize, Deserialize)]
+struct DataPartChunk {
+    #[serde(rename = "type")]
+    chunk_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    data: Value,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    transient: bool,
+}
+
+impl Serialize for VercelUIDataPart {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        DataPartChunk {
+            chunk_type: format!("data-{}", self.name),
+            id: self.id.clone(),
+            data: self.data.clone(),
+            transient: self.transient,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for VercelUIDataPart {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let chunk = DataPartChunk::deserialize(deserializer)?;
+        let name = chunk.chunk_type.strip_prefix("data-").ok_or_else(|| {
+            serde::de::Error::custom(format!("unknown chunk type `{}`", chunk.chunk_type))
+        })?;
+        Ok(Self {
+            name: name.to_string(),
+            id: chunk.id,
+            data: chunk.data,
+            transient: chunk.transient,
+        })
+    }
 }
 
 #[derive(Default)]
@@ -240,23 +388,27 @@ impl crate::core::StreamTextResponse {
             .map(|f| f())
             .unwrap_or_else(|| format!("msg_{}", uuid::Uuid::new_v4().simple()));
         let mut reasoning_open = false;
-        let mut tool_call_id: Option<String> = None;
+        let mut tool_calls = ToolCallTracker::default();
 
         self.stream
             .map(move |chunk| {
                 let mut chunks = Vec::new();
 
+                if let StreamEvent::StepStart = chunk {
+                    tool_calls.start_step();
+                }
+
                 match chunk {
-                    LanguageModelStreamChunkType::Start if options.send_start => {
+                    StreamEvent::StepStart if options.send_start => {
                         reasoning_open = false;
-                        tool_call_id = None;
+                        chunks.push(VercelUIStream::StartStep);
                         chunks.push(VercelUIStream::TextStart {
                             id: message_id.clone(),
                             provider_metadata: None,
                         });
                     }
 
-                    LanguageModelStreamChunkType::Text(delta) => {
+                    StreamEvent::TextDelta(delta) => {
                         chunks.push(VercelUIStream::TextDelta {
                             id: message_id.clone(),
                             delta,
@@ -264,7 +416,7 @@ impl crate::core::StreamTextResponse {
                         });
                     }
 
-                    LanguageModelStreamChunkType::Reasoning(delta) if options.send_reasoning => {
+                    StreamEvent::ReasoningDelta(delta) if options.send_reasoning => {
                         if !reasoning_open {
                             reasoning_open = true;
                             chunks.push(VercelUIStream::ReasoningStart {
@@ -279,29 +431,36 @@ impl crate::core::StreamTextResponse {
                         });
                     }
 
-                    LanguageModelStreamChunkType::ToolCall(delta) => {
-                        let first = tool_call_id.is_none();
-                        let current_id = tool_call_id
-                            .get_or_insert_with(|| {
-                                format!("tool_call_{}", uuid::Uuid::new_v4().simple())
-                            })
-                            .clone();
-
-                        if first {
-                            chunks.push(VercelUIStream::ToolCallStart {
-                                tool_call_id: current_id.clone(),
-                                tool_name: "tool".to_string(),
-                                provider_metadata: None,
-                            });
-                        }
+                    StreamEvent::ToolCallDelta(delta) => chunks.extend(tool_calls.delta(delta)),
 
-                        chunks.push(VercelUIStream::ToolCallDelta {
-                            tool_call_id: current_id,
-                            delta,
+                    StreamEvent::ToolCall(info) => chunks.extend(tool_calls.call(info)),
+
+                    StreamEvent::ToolResult(info) => chunks.push(tool_calls.result(info)),
+
+                    StreamEvent::Source(source) => {
+                        chunks.push(VercelUIStream::SourceUrl {
+                            source_id: format!("source_{}", uuid::Uuid::new_v4().simple()),
+                            url: source.url,
+                            title: source.title,
+                            provider_metadata: None,
                         });
                     }
 
-                    LanguageModelStreamChunkType::End(_) if options.send_finish => {
+                    StreamEvent::File(file) => {
+                        let media_type = file
+                            .media_type
+                            .unwrap_or_else(|| "application/octet-stream".to_string());
+                        let url = match file.data {
+                            ImageData::Url(url) => url,
+                            ImageData::Base64(data) => format!("data:{media_type};base64,{data}"),
+                            ImageData::Bytes(bytes) => {
+                                format!("data:{media_type};base64,{}", STANDARD.encode(bytes))
+                            }
+                        };
+                        chunks.push(VercelUIStream::File { url, media_type });
+                    }
+
+                    StreamEvent::StepFinish { .. } if options.send_finish => {
                         if reasoning_open && options.send_reasoning {
                             reasoning_open = false;
                             chunks.push(VercelUIStream::ReasoningEnd {
@@ -309,19 +468,36 @@ impl crate::core::StreamTextResponse {
                                 provider_metadata: None,
                             });
                         }
-                        tool_call_id = None;
                         chunks.push(VercelUIStream::TextEnd {
                             id: message_id.clone(),
                             provider_metadata: None,
                         });
+                        chunks.push(VercelUIStream::FinishStep);
                     }
 
-                    LanguageModelStreamChunkType::Failed(error)
-                    | LanguageModelStreamChunkType::Incomplete(error) => {
-                        chunks.push(VercelUIStream::Error { error_text: error });
+                    StreamEvent::Error(error) => {
+                        chunks.push(VercelUIStream::Error {
+                            error_text: error.to_string(),
+                        });
                     }
 
-                    LanguageModelStreamChunkType::NotSupported(_) => {}
+                    StreamEvent::Finish {
+                        stop_reason: StopReason::Hook,
+                        ..
+                    } => {
+                        chunks.push(VercelUIStream::Error {
+                            error_text: "Stopped by hook".to_string(),
+                        });
+                    }
+
+                    StreamEvent::Finish {
+                        stop_reason: StopReason::MaxSteps,
+                        ..
+                    } => {
+                        chunks.push(VercelUIStream::Error {
+                            error_text: "Maximum steps reached".to_string(),
+                        });
+                    }
 
                     _ => {}
                 }
@@ -335,6 +511,107 @@ impl crate::core::StreamTextResponse {
     }
 }
 
+/// Tracks the tool calls of a stream, to attribute the UI tool chunks to them.
+#[cfg(feature = "language-model-request")]
+#[derive(Debug, Default)]
+struct ToolCallTracker {
+    /// The chunk IDs of tool calls whose input is streaming, by provider tool call ID.
+    streaming: HashMap<String, String>,
+    /// The chunk IDs of tool calls awaiting their output, by provider tool call ID.
+    awaiting_output: HashMap<String, String>,
+    /// Input fragments the provider did not attribute to a tool call. They are sent
+    /// once the call completes, as its tool name is unknown until then.
+    unattributed: String,
+}
+
+#[cfg(feature = "language-model-request")]
+impl ToolCallTracker {
+    /// Forgets the input of the previous step's tool calls.
+    fn start_step(&mut self) {
+        self.streaming.clear();
+        self.unattributed.clear();
+    }
+
+    /// Returns the chunks of an input fragment, starting its tool call on the first one.
+    fn delta(&mut self, delta: ToolCallDelta) -> Vec<VercelUIStream> {
+        if delta.tool.id.is_empty() {
+            self.unattributed.push_str(&delta.delta);
+            return Vec::new();
+        }
+
+        let mut chunks = Vec::new();
+        if !self.streaming.contains_key(&delta.tool.id) {
+            self.streaming
+                .insert(delta.tool.id.clone(), delta.tool.id.clone());
+            chunks.push(VercelUIStream::ToolCallStart {
+                tool_call_id: delta.tool.id.clone(),
+                tool_name: delta.tool.name,
+                provider_metadata: None,
+            });
+        }
+        chunks.push(VercelUIStream::ToolCallDelta {
+            tool_call_id: delta.tool.id,
+            delta: delta.delta,
+        });
+        chunks
+    }
+
+    /// Returns the chunks of a complete tool call, starting it if no input was streamed.
+    fn call(&mut self, info: ToolCallInfo) -> Vec<VercelUIStream> {
+        let mut chunks = Vec::new();
+        let tool_call_id = match self.streaming.remove(&info.tool.id) {
+            Some(id) => id,
+            None => {
+                let id = if info.tool.id.is_empty() {
+                    format!("tool_call_{}", uuid::Uuid::new_v4().simple())
+                } else {
+                    info.tool.id.clone()
+                };
+                chunks.push(VercelUIStream::ToolCallStart {
+                    tool_call_id: id.clone(),
+                    tool_name: info.tool.name.clone(),
+                    provider_metadata: None,
+                });
+                if !self.unattributed.is_empty() {
+                    chunks.push(VercelUIStream::ToolCallDelta {
+                        tool_call_id: id.clone(),
+                        delta: std::mem::take(&mut self.unattributed),
+                    });
+                }
+                id
+            }
+        };
+
+        self.awaiting_output
+            .insert(info.tool.id.clone(), tool_call_id.clone());
+        chunks.push(VercelUIStream::ToolCallEnd {
+            tool_call_id,
+            tool_name: info.tool.name,
+            input: info.input,
+            provider_metadata: None,
+        });
+        chunks
+    }
+
+    /// Returns the output chunk of an executed tool call.
+    fn result(&mut self, info: ToolResultInfo) -> VercelUIStream {
+        let tool_call_id = self
+            .awaiting_output
+            .remove(&info.tool.id)
+            .unwrap_or(info.tool.id);
+        match info.output {
+            Ok(output) => VercelUIStream::ToolOutputAvailable {
+                tool_call_id,
+                output,
+            },
+            Err(e) => VercelUIStream::ToolOutputError {
+                tool_call_id,
+                error_text: e.to_string(),
+            },
+        }
+    }
+}
+
 /// Represents a part of a UI message from Vercel's useChat hook.
 #[derive(Deserialize, Debug)]
 pub struct VercelUIMessagePart {
@@ -362,11 +639,338 @@ pub struct VercelUIRequest {
     /// Unique identifier for the chat session.
     pub id: String,
     /// Array of UI messages from the frontend.
-    pub messages: Vec<VercelUIMessage>,
+    pub messages: Vec<UIMessage>,
     /// Trigger indicating the action (e.g., "submit-message").
     pub trigger: String,
 }
 
+/// The role of a [`UIMessage`].
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum UIMessageRole {
+    /// System instructions
+    System,
+    /// Input of the user
+    User,
+    /// Response of the model
+    Assistant,
+}
+
+/// A message in the format `useChat` sends and persists (Vercel AI SDK v5 `UIMessage`).
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct UIMessage {
+    /// Unique identifier for the message.
+    pub id: String,
+    /// Role of the message sender.
+    pub role: UIMessageRole,
+    /// Optional metadata set by the application.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Value>,
+    /// The parts of the message, in order.
+    pub parts: Vec<UIMessagePart>,
+}
+
+/// A part of a [`UIMessage`].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type")]
+pub enum UIMessagePart {
+    /// Text
+    #[serde(rename = "text")]
+    Text {
+        /// The text
+        text: String,
+    },
+    /// Reasoning of the model
+    #[serde(rename = "reasoning")]
+    Reasoning {
+        /// The reasoning text
+        text: String,
+    },
+    /// A file, e.g. an image attached by the user
+    #[serde(rename = "file")]
+    File {
+        /// Media type of the file
+        #[serde(rename = "mediaType")]
+        media_type: String,
+        /// Optional file name
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        filename: Option<String>,
+        /// URL of the file, or a data URL holding its content
+        url: String,
+    },
+    /// A URL the response is grounded in
+    #[serde(rename = "source-url")]
+    SourceUrl {
+        /// Source ID
+        #[serde(rename = "sourceId")]
+        source_id: String,
+        /// Source URL
+        url: String,
+        /// Optional title
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        title: Option<String>,
+    },
+    /// A document the response is grounded in
+    #[serde(rename = "source-document")]
+    SourceDocument {
+        /// Source ID
+        #[serde(rename = "sourceId")]
+        source_id: String,
+        /// Media type of the document
+        #[serde(rename = "mediaType")]
+        media_type: String,
+        /// Document title
+        title: String,
+        /// Optional file name
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        filename: Option<String>,
+    },
+    /// Start of a model call
+    #[serde(rename = "step-start")]
+    StepStart,
+    /// A tool call and its result, of type `tool-{name}` or `dynamic-tool`
+    #[serde(untagged)]
+    Tool(UIToolPart),
+    /// Custom data, of type `data-{name}`
+    #[serde(untagged)]
+    Data(VercelUIDataPart),
+    /// A part of a type this crate does not know, kept as is
+    #[serde(untagged)]
+    Other(Value),
+}
+
+/// A tool call part of a [`UIMessage`], with its result once available.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UIToolPart {
+    /// Name of the tool
+    pub tool_name: String,
+    /// Tool call ID
+    pub tool_call_id: String,
+    /// Whether the part is a `dynamic-tool` part, for tools not known in advance
+    pub dynamic: bool,
+    /// State of the call: `input-streaming`, `input-available`, `output-available` or
+    /// `output-error`
+    pub state: String,
+    /// Input of the call
+    pub input: Option<Value>,
+    /// Output of the call, once `output-available`
+    pub output: Option<Value>,
+    /// Error of the call, once `output-error`
+    pub error_text: Option<String>,
+}
+
+/// The wire format of [`UIToolPart`].
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ToolPartChunk {
+    #[serde(rename = "type")]
+    part_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    tool_name: Option<String>,
+    tool_call_id: String,
+    state: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    output: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    error_text: Option<String>,
+}
+
+impl Serialize for UIToolPart {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let (part_type, tool_name) = if self.dynamic {
+            ("dynamic-tool".to_string(), Some(self.tool_name.clone()))
+        } else {
+            (format!("tool-{}", self.tool_name), None)
+        };
+        ToolPartChunk {
+            part_type,
+            tool_name,
+            tool_call_id: self.tool_call_id.clone(),
+            state: self.state.clone(),
+            input: self.input.clone(),
+            output: self.output.clone(),
+            error_text: self.error_text.clone(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for UIToolPart {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let part = ToolPartChunk::deserialize(deserializer)?;
+        let (tool_name, dynamic) = match (part.part_type.strip_prefix("tool-"), part.tool_name) {
+            (Some(name), _) => (name.to_string(), false),
+            (None, Some(name)) if part.part_type == "dynamic-tool" => (name, true),
+            _ => {
+                return Err(serde::de::Error::custom(format!(
+                    "unknown part type `{}`",
+                    part.part_type
+                )));
+            }
+        };
+        Ok(Self {
+            tool_name,
+            tool_call_id: part.tool_call_id,
+            dynamic,
+            state: part.state,
+            input: part.input,
+            output: part.output,
+            error_text: part.error_text,
+        })
+    }
+}
+
+/// Converts UI messages, e.g. those of a `useChat` request body, to core messages.
+///
+/// - System messages become [`Message::System`](crate::core::messages::Message::System)
+///   with the text of their text parts.
+/// - User messages become multi-part user messages when they hold files. Image files
+///   become image parts, other files file parts. Data URLs are decoded to base64 data.
+/// - Assistant messages become one core message per text, reasoning and tool part.
+///   A tool part with an input becomes a tool call, followed by a tool result once its
+///   output or error is available.
+/// - Sources, step starts, data parts and unknown parts are skipped.
+///
+/// # Parameters
+/// - `messages`: The UI messages to convert.
+///
+/// # Returns
+/// The core messages, in order.
+pub fn convert_to_model_messages(messages: &[UIMessage]) -> crate::core::messages::Messages {
+    use crate::core::language_model::LanguageModelResponseContentType;
+    use crate::core::messages::{AssistantMessage, ContentPart, Message, UserMessage};
+
+    let mut converted = Vec::new();
+    for message in messages {
+        match message.role {
+            UIMessageRole::System => {
+                converted.push(Message::System(text_of_parts(&message.parts).into()));
+            }
+            UIMessageRole::User => {
+                let parts: Vec<ContentPart> = message
+                    .parts
+                    .iter()
+                    .filter_map(|part| match part {
+                        UIMessagePart::Text { text } => Some(ContentPart::text(text)),
+                        UIMessagePart::File {
+                            media_type,
+                            filename,
+                            url,
+                        } => Some(file_content_part(media_type, filename.clone(), url)),
+                        _ => None,
+                    })
+                    .collect();
+                let message = if parts
+                    .iter()
+                    .all(|part| matches!(part, ContentPart::Text(_)))
+                {
+                    UserMessage::new(text_of_parts(&message.parts))
+                } else {
+                    UserMessage::with_parts(parts)
+                };
+                converted.push(Message::User(message));
+            }
+            UIMessageRole::Assistant => {
+                for part in &message.parts {
+                    match part {
+                        UIMessagePart::Text { text } => {
+                            converted.push(Message::Assistant(text.clone().into()));
+                        }
+                        UIMessagePart::Reasoning { text } => {
+                            converted.push(Message::Assistant(AssistantMessage::new(
+                                LanguageModelResponseContentType::Reasoning {
+                                    content: text.clone(),
+                                    extensions: Default::default(),
+                                },
+                                None,
+                            )));
+                        }
+                        UIMessagePart::Tool(tool) => converted.extend(tool_messages(tool)),
+                        _ => {}
+                    }
+                }
+            }
+        }
+    }
+    converted
+}
+
+/// Joins the text parts of a UI message.
+fn text_of_parts(parts: &[UIMessagePart]) -> String {
+    parts
+        .iter()
+        .filter_map(|part| match part {
+            UIMessagePart::Text { text } => Some(text.as_str()),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Converts a file of a UI message to a content part, decoding data URLs.
+fn file_content_part(
+    media_type: &str,
+    filename: Option<String>,
+    url: &str,
+) -> crate::core::messages::ContentPart {
+    use crate::core::messages::{FilePart, ImagePart, MediaSource};
+
+    let source = url
+        .strip_prefix("data:")
+        .and_then(|data_url| data_url.split_once(";base64,"))
+        .map_or_else(
+            || MediaSource::Url(url.to_string()),
+            |(_, data)| MediaSource::Base64(data.to_string()),
+        );
+    if media_type.starts_with("image/") {
+        ImagePart {
+            source,
+            media_type: Some(media_type.to_string()),
+        }
+        .into()
+    } else {
+        FilePart {
+            source,
+            media_type: media_type.to_string(),
+            filename,
+        }
+        .into()
+    }
+}
+
+/// Converts a tool part to a tool call message and, once available, its result.
+fn tool_messages(tool: &UIToolPart) -> Vec<crate::core::messages::Message> {
+    use crate::core::language_model::LanguageModelResponseContentType;
+    use crate::core::messages::{AssistantMessage, Message};
+    use crate::core::tools::{ToolCallInfo, ToolResultInfo};
+
+    let Some(input) = &tool.input else {
+        return Vec::new();
+    };
+    let mut call = ToolCallInfo::new(&tool.tool_name);
+    call.id(&tool.tool_call_id);
+    call.input(input.clone());
+    let mut messages = vec![Message::Assistant(AssistantMessage::new(
+        LanguageModelResponseContentType::ToolCall(call),
+        None,
+    ))];
+
+    let output = match (&tool.output, &tool.error_text) {
+        (Some(output), _) if tool.state == "output-available" => output.clone(),
+        (_, Some(error)) if tool.state == "output-error" => {
+            Value::String(format!("Error: {error}"))
+        }
+        _ => return messages,
+    };
+    let mut result = ToolResultInfo::new(&tool.tool_name);
+    result.id(&tool.tool_call_id);
+    result.output(output);
+    messages.push(Message::Tool(result));
+    messages
+}
+
 impl crate::core::Message {
     /// Converts a slice of Vercel UI messages to the `aisdk::core::Message` format.
     ///
@@ -412,6 +1016,256 @@ impl crate::core::Message {
 /// Converts a VercelUIRequest into native aisdk::core::messages::Message
 impl From<VercelUIRequest> for Vec<crate::core::messages::Message> {
     fn from(request: VercelUIRequest) -> Self {
-        crate::core::messages::Message::from_vercel_ui_message(&request.messages)
+        convert_to_model_messages(&request.messages)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_data_chunk_round_trip() {
+        let chunk = VercelUIStream::data("weather", serde_json::json!({"city": "Paris"}));
+
+        let json = serde_json::to_value(&chunk).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"type": "data-weather", "data": {"city": "Paris"}})
+        );
+
+        let VercelUIStream::Data(part) = serde_json::from_value(json).unwrap() else {
+            panic!("expected a data chunk");
+        };
+        assert_eq!(part.name, "weather");
+    }
+
+    #[test]
+    fn test_step_and_error_chunks() {
+        assert_eq!(
+            serde_json::to_value(VercelUIStream::StartStep).unwrap(),
+            serde_json::json!({"type": "start-step"})
+        );
+        assert_eq!(
+            serde_json::to_value(VercelUIStream::Error {
+                error_text: "boom".to_string()
+            })
+            .unwrap(),
+            serde_json::json!({"type": "error", "errorText": "boom"})
+        );
+    }
+
+    #[test]
+    fn test_convert_to_model_messages() {
+        use crate::core::language_model::LanguageModelResponseContentType;
+        use crate::core::messages::{ContentPart, MediaSource, Message};
+
+        let messages: Vec<UIMessage> = serde_json::from_value(serde_json::json!([
+            {
+                "id": "1",
+                "role": "user",
+                "parts": [
+                    {"type": "text", "text": "What is this?"},
+                    {"type": "file", "mediaType": "image/png", "url": "data:image/png;base64,AAAA"}
+                ]
+            },
+            {
+                "id": "2",
+                "role": "assistant",
+                "parts": [
+                    {"type": "step-start"},
+                    {
+                        "type": "tool-describe",
+                        "toolCallId": "call_1",
+                        "state": "output-available",
+                        "input": {"detail": "high"},
+                        "output": "A cat"
+                    },
+                    {"type": "data-progress", "data": {"done": true}},
+                    {"type": "text", "text": "It is a cat."}
+                ]
+            }
+        ]))
+        .unwrap();
+
+        let converted = convert_to_model_messages(&messages);
+
+        assert_eq!(converted.len(), 4);
+        let Message::User(user) = &converted[0] else {
+            panic!("expected a user message");
+        };
+        assert_eq!(user.content, "What is this?");
+        assert!(matches!(
+            &user.parts[1],
+            ContentPart::Image(image) if image.source == MediaSource::Base64("AAAA".to_string())
+        ));
+        assert!(matches!(
+            &converted[1],
+            Message::Assistant(message) if matches!(
+                &message.content,
+                LanguageModelResponseContentType::ToolCall(call)
+                    if call.tool.name == "describe" && call.tool.id == "call_1"
+            )
+        ));
+        assert!(matches!(
+            &converted[2],
+            Message::Tool(result) if result.output == Ok(serde_json::json!("A cat"))
+        ));
+        assert!(matches!(&converted[3], Message::Assistant(_)));
+    }
+
+    #[test]
+    fn test_tool_part_round_trip() {
+        let json = serde_json::json!({
+            "type": "dynamic-tool",
+            "toolName": "search",
+            "toolCallId": "call_1",
+            "state": "output-error",
+            "input": {},
+            "errorText": "offline"
+        });
+
+        let part: UIMessagePart = serde_json::from_value(json.clone()).unwrap();
+
+        assert!(matches!(&part, UIMessagePart::Tool(tool) if tool.dynamic));
+        assert_eq!(serde_json::to_value(&part).unwrap(), json);
+    }
+
+    #[cfg(feature = "language-model-request")]
+    fn chunk_types(chunks: &[VercelUIStream]) -> Vec<String> {
+        chunks
+            .iter()
+            .map(|chunk| {
+                let chunk = serde_json::to_value(chunk).unwrap();
+                format!("{} {}", chunk["type"], chunk["toolCallId"])
+            })
+            .collect()
+    }
+
+    #[cfg(feature = "language-model-request")]
+    #[test]
+    fn test_tool_call_tracker_parallel_calls() {
+        use crate::core::tools::ToolDetails;
+
+        let delta = |id: &str, name: &str, fragment: &str| ToolCallDelta {
+            tool: ToolDetails {
+                id: id.to_string(),
+                name: name.to_string(),
+            },
+            delta: fragment.to_string(),
+        };
+        let call = |id: &str, name: &str| {
+            let mut info = ToolCallInfo::new(name);
+            info.id(id);
+            info.input(serde_json::json!({}));
+            info
+        };
+        let mut tracker = ToolCallTracker::default();
+
+        let mut chunks = tracker.delta(delta("a", "weather", "{"));
+        chunks.extend(tracker.delta(delta("b", "time", "{")));
+        chunks.extend(tracker.delta(delta("a", "weather", "}")));
+        chunks.extend(tracker.call(call("a", "weather")));
+        chunks.extend(tracker.call(call("b", "time")));
+        let mut output = ToolResultInfo::new("time");
+        output.id("b");
+        output.output(serde_json::json!("noon"));
+        chunks.push(tracker.result(output));
+
+        assert_eq!(
+            chunk_types(&chunks),
+            vec![
+                r#""tool-input-start" "a""#,
+                r#""tool-input-delta" "a""#,
+                r#""tool-input-start" "b""#,
+                r#""tool-input-delta" "b""#,
+                r#""tool-input-delta" "a""#,
+                r#""tool-input-available" "a""#,
+                r#""tool-input-available" "b""#,
+                r#""tool-output-available" "b""#,
+            ]
+        );
+        let VercelUIStream::ToolCallStart { tool_name, .. } = &chunks[2] else {
+            panic!("expected a tool input start");
+        };
+        assert_eq!(tool_name, "time");
+    }
+
+    #[cfg(feature = "language-model-request")]
+    #[test]
+    fn test_tool_call_tracker_unattributed_fragments() {
+        let mut tracker = ToolCallTracker::default();
+
+        assert!(tracker.delta(ToolCallDelta::default()).is_empty());
+        let fragment = ToolCallDelta {
+            delta: r#"{"city":"Paris"}"#.to_string(),
+            ..Default::default()
+        };
+        assert!(tracker.delta(fragment).is_empty());
+        let mut info = ToolCallInfo::new("weather");
+        info.id("call_1");
+        let chunks = tracker.call(info);
+
+        assert_eq!(
+            chunk_types(&chunks),
+            vec![
+                r#""tool-input-start" "call_1""#,
+                r#""tool-input-delta" "call_1""#,
+                r#""tool-input-available" "call_1""#,
+            ]
+        );
+    }
+
+    #[cfg(feature = "language-model-request")]
+    #[tokio::test]
+    async fn test_sources_and_steps_are_streamed() {
+        use crate::core::language_model::{
+            LanguageModelResponseContentType, LanguageModelStreamChunk,
+            LanguageModelStreamChunkType, Source,
+        };
+        use crate::core::test_model::ScriptedModel;
+        use crate::core::{AssistantMessage, LanguageModelRequest};
+
+        // A model that answers with a source and a text
+        let model = ScriptedModel::new("source").chunks(vec![vec![
+            LanguageModelStreamChunk::Done(AssistantMessage::new(
+                LanguageModelResponseContentType::Source(Source::new("https://example.com")),
+                None,
+            )),
+            LanguageModelStreamChunk::Delta(LanguageModelStreamChunkType::Text("Hi".to_string())),
+            LanguageModelStreamChunk::Done(AssistantMessage::new(
+                LanguageModelResponseContentType::Text("Hi".to_string()),
+                None,
+            )),
+        ]]);
+
+        let options = VercelUIStreamOptions {
+            send_start: true,
+            send_finish: true,
+            ..Default::default()
+        };
+        let chunks: Vec<String> = LanguageModelRequest::builder()
+            .model(model)
+            .prompt("cite")
+            .build()
+            .stream_text()
+            .await
+            .unwrap()
+            .into_vercel_ui_stream(options)
+            .map(|chunk| serde_json::to_value(chunk.unwrap()).unwrap()["type"].to_string())
+            .collect()
+            .await;
+
+        assert_eq!(
+            chunks,
+            vec![
+                "\"start-step\"",
+                "\"text-start\"",
+                "\"source-url\"",
+                "\"text-delta\"",
+                "\"text-end\"",
+                "\"finish-step\"",
+            ]
+        );
     }
 }
@@ -3,6 +3,9 @@
 //! `aisdk` is An open-source Rust library for building AI-powered applications, inspired by the Vercel AI SDK.
 //! It provides a type-safe interface for interacting with Large Language Models (LLMs).
 
+#[cfg(all(target_arch = "wasm32", not(feature = "wasm")))]
+compile_error!("building for wasm32 requires the `wasm` feature");
+
 pub mod core;
 pub mod error;
 pub mod extensions;
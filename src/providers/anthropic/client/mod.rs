@@ -10,7 +10,7 @@ use reqwest_eventsource::Event;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    core::client::LanguageModelClient,
+    core::client::{LanguageModelClient, RetryPolicy, sse_error},
     providers::anthropic::{ANTHROPIC_API_VERSION, Anthropic},
 };
 
@@ -42,10 +42,17 @@ pub(crate) struct AnthropicOptions {
     pub tools: Option<Vec<AnthropicTool>>,
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_choice: Option<AnthropicToolChoice>,
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub top_k: Option<u32>,
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub top_p: Option<f32>,
+    /// Provider-specific fields merged into the request body.
+    #[builder(default)]
+    #[serde(flatten, skip_serializing_if = "serde_json::Map::is_empty")]
+    pub provider_options: serde_json::Map<String, serde_json::Value>,
 }
 
 impl AnthropicOptions {
@@ -75,6 +82,12 @@ impl<M: ModelName> LanguageModelClient for Anthropic<M> {
         default_headers.insert(CONTENT_TYPE, "application/json".parse().unwrap());
         default_headers.insert("x-api-key", self.settings.api_key.parse().unwrap());
         default_headers.insert("anthropic-version", ANTHROPIC_API_VERSION.parse().unwrap());
+        if !self.settings.betas.is_empty() {
+            default_headers.insert(
+                "anthropic-beta",
+                self.settings.betas.join(",").parse().unwrap(),
+            );
+        }
 
         default_headers
     }
@@ -83,6 +96,14 @@ impl<M: ModelName> LanguageModelClient for Anthropic<M> {
         Vec::new()
     }
 
+    fn retry_policy(&self) -> RetryPolicy {
+        self.settings.retry_policy.clone()
+    }
+
+    fn transport(&self) -> crate::core::client::TransportSettings {
+        self.settings.transport.clone()
+    }
+
     fn body(&self) -> reqwest::Body {
         let body = serde_json::to_string(&self.options).unwrap();
         reqwest::Body::from(body)
@@ -109,17 +130,7 @@ impl<M: ModelName> LanguageModelClient for Anthropic<M> {
                         .unwrap_or(AnthropicStreamEvent::NotSupported(msg.data)))
                 }
             },
-            Err(e) => {
-                // Extract status code if it's an InvalidStatusCode error
-                let status_code = match &e {
-                    reqwest_eventsource::Error::InvalidStatusCode(status, _) => Some(*status),
-                    _ => None,
-                };
-                Err(Error::ApiError {
-                    status_code,
-                    details: format!("SSE error: {e}"),
-                })
-            }
+            Err(e) => Err(sse_error(e)),
         }
     }
 
@@ -127,3 +138,284 @@ impl<M: ModelName> LanguageModelClient for Anthropic<M> {
         matches!(event, AnthropicStreamEvent::MessageStop)
     }
 }
+
+impl<M: ModelName> crate::core::client::ModelListClient for Anthropic<M> {
+    type Response = AnthropicModelList;
+
+    fn path(&self) -> String {
+        "/models".to_string()
+    }
+
+    fn query_params(&self) -> Vec<(&str, &str)> {
+        vec![("limit", "1000")]
+    }
+
+    fn headers(&self) -> reqwest::header::HeaderMap {
+        LanguageModelClient::headers(self)
+    }
+
+    fn retry_policy(&self) -> RetryPolicy {
+        self.settings.retry_policy.clone()
+    }
+
+    fn transport(&self) -> crate::core::client::TransportSettings {
+        self.settings.transport.clone()
+    }
+
+    fn next_page(response: &Self::Response) -> Option<(&'static str, String)> {
+        match &response.last_id {
+            Some(last_id) if response.has_more => Some(("after_id", last_id.clone())),
+            _ => None,
+        }
+    }
+
+    fn descriptors(response: Self::Response) -> Vec<crate::core::provider::ModelDescriptor> {
+        response
+            .data
+            .into_iter()
+            .map(|model| crate::core::provider::ModelDescriptor {
+                id: model.id,
+                display_name: model.display_name,
+                owned_by: Some("anthropic".to_string()),
+                ..Default::default()
+            })
+            .collect()
+    }
+}
+
+impl<M: ModelName> crate::core::client::TokenCountClient for Anthropic<M> {
+    type Response = AnthropicCountTokensResponse;
+
+    fn path(&self) -> String {
+        format!("{}/count_tokens", LanguageModelClient::path(self))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn headers(&self) -> reqwest::header::HeaderMap {
+        LanguageModelClient::headers(self)
+    }
+
+    fn query_params(&self) -> Vec<(&str, &str)> {
+        Vec::new()
+    }
+
+    fn retry_policy(&self) -> RetryPolicy {
+        self.settings.retry_policy.clone()
+    }
+
+    fn transport(&self) -> crate::core::client::TransportSettings {
+        self.settings.transport.clone()
+    }
+
+    fn body(&self) -> reqwest::Body {
+        let request = AnthropicCountTokensRequest {
+            model: self.options.model.clone(),
+            messages: self.options.messages.clone(),
+            system: self.options.system.clone(),
+            thinking: self.options.thinking.clone(),
+            tools: self.options.tools.clone(),
+            tool_choice: self.options.tool_choice.clone(),
+        };
+        let body = serde_json::to_string(&request).unwrap();
+        reqwest::Body::from(body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::DynamicModel;
+    use crate::providers::anthropic::ANTHROPIC_TOKEN_EFFICIENT_TOOLS_BETA;
+
+    fn provider() -> crate::providers::anthropic::AnthropicBuilder<DynamicModel> {
+        Anthropic::<DynamicModel>::builder()
+            .model_name("claude-sonnet-4-0")
+            .api_key("test")
+    }
+
+    #[test]
+    fn no_beta_header_by_default() {
+        let anthropic = provider().build().unwrap();
+        assert!(anthropic.headers().get("anthropic-beta").is_none());
+    }
+
+    #[test]
+    fn token_efficient_tools_merges_with_other_betas() {
+        let anthropic = provider()
+            .beta("prompt-caching-2024-07-31")
+            .token_efficient_tools(true)
+            .token_efficient_tools(true)
+            .build()
+            .unwrap();
+        assert_eq!(
+            anthropic.headers().get("anthropic-beta").unwrap(),
+            &format!("prompt-caching-2024-07-31,{ANTHROPIC_TOKEN_EFFICIENT_TOOLS_BETA}")
+        );
+
+        let anthropic = provider()
+            .token_efficient_tools(true)
+            .token_efficient_tools(false)
+            .build()
+            .unwrap();
+        assert!(anthropic.headers().get("anthropic-beta").is_none());
+    }
+
+    #[test]
+    fn count_tokens_request() {
+        use crate::core::client::TokenCountClient;
+
+        let mut anthropic = provider().build().unwrap();
+        anthropic.options.system = Some("Be brief".to_string());
+        anthropic.options.stream = Some(true);
+
+        let body: serde_json::Value =
+            serde_json::from_slice(TokenCountClient::body(&anthropic).as_bytes().unwrap()).unwrap();
+        assert_eq!(TokenCountClient::path(&anthropic), "/messages/count_tokens");
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "model": "claude-sonnet-4-0",
+                "messages": [],
+                "system": "Be brief"
+            })
+        );
+    }
+
+    /// Answers the models endpoint with two pages.
+    struct ModelPages;
+
+    #[async_trait::async_trait]
+    impl crate::core::client::Middleware for ModelPages {
+        async fn respond(
+            &self,
+            request: &reqwest::Request,
+        ) -> Option<crate::core::client::HttpResponse> {
+            let query = request.url().query().unwrap_or_default();
+            let body = if query.contains("after_id=claude-b") {
+                r#"{"data":[{"id":"claude-c"}],"has_more":false,"last_id":"claude-c"}"#
+            } else {
+                r#"{"data":[{"id":"claude-a"},{"id":"claude-b"}],"has_more":true,"last_id":"claude-b"}"#
+            };
+            Some(crate::core::client::HttpResponse::new(
+                reqwest::StatusCode::OK,
+                body,
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn list_models_follows_pages() {
+        let anthropic = provider().middleware(ModelPages).build().unwrap();
+
+        let models = anthropic.list_models().await.unwrap();
+
+        let ids: Vec<_> = models.iter().map(|model| model.id.as_str()).collect();
+        assert_eq!(ids, ["claude-a", "claude-b", "claude-c"]);
+    }
+
+    /// Answers messages with the given status and body, and rate limit headers.
+    struct RateLimited(reqwest::StatusCode, &'static str);
+
+    #[async_trait::async_trait]
+    impl crate::core::client::Middleware for RateLimited {
+        async fn respond(
+            &self,
+            _request: &reqwest::Request,
+        ) -> Option<crate::core::client::HttpResponse> {
+            let mut response = crate::core::client::HttpResponse::new(self.0, self.1);
+            for (name, value) in [
+                ("anthropic-ratelimit-requests-limit", "50"),
+                ("anthropic-ratelimit-requests-remaining", "0"),
+                ("retry-after", "0"),
+            ] {
+                response.headers.insert(name, value.parse().unwrap());
+            }
+            Some(response)
+        }
+    }
+
+    #[tokio::test]
+    async fn rate_limits_are_attached_to_responses_and_errors() {
+        use crate::core::language_model::{LanguageModel, LanguageModelOptions};
+        use crate::core::rate_limit::RateLimitInfo;
+
+        let mut anthropic = provider()
+            .middleware(RateLimited(
+                reqwest::StatusCode::OK,
+                r#"{"id":"msg","type":"message","role":"assistant","model":"claude-sonnet-4-0","content":[{"type":"text","text":"Hi"}],"stop_reason":"end_turn","stop_sequence":null,"usage":{"input_tokens":1,"output_tokens":1,"cache_creation_input_tokens":0,"cache_read_input_tokens":0,"cache_creation":{"ephemeral_1h_input_tokens":0,"ephemeral_5m_input_tokens":0},"service_tier":"standard"}}"#,
+            ))
+            .build()
+            .unwrap();
+        let response = anthropic
+            .generate_text(LanguageModelOptions::default())
+            .await
+            .unwrap();
+        let info = response.extensions.get::<RateLimitInfo>().clone();
+        assert_eq!(info.requests_limit, Some(50));
+        assert_eq!(info.requests_remaining, Some(0));
+
+        let mut anthropic = provider()
+            .middleware(RateLimited(
+                reqwest::StatusCode::TOO_MANY_REQUESTS,
+                r#"{"type":"error","error":{"type":"rate_limit_error","message":"Slow down"}}"#,
+            ))
+            .build()
+            .unwrap();
+        let error = anthropic
+            .generate_text(LanguageModelOptions::default())
+            .await
+            .unwrap_err();
+        let Error::RateLimited {
+            rate_limit: Some(info),
+            ..
+        } = error
+        else {
+            panic!("unexpected error: {error:?}");
+        };
+        assert_eq!(info.requests_remaining, Some(0));
+    }
+    #[tokio::test]
+    async fn rate_limits_are_attached_to_streams() {
+        use crate::core::LanguageModelRequest;
+        use crate::core::rate_limit::RateLimitInfo;
+        use futures::StreamExt;
+
+        let events = concat!(
+            "event: message_start\n",
+            r#"data: {"type":"message_start","message":{"id":"msg","type":"message","role":"assistant","model":"claude-sonnet-4-0","content":[],"stop_reason":null,"stop_sequence":null,"usage":{"input_tokens":1,"output_tokens":0}}}"#,
+            "\n\n",
+            r#"data: {"type":"content_block_start","index":0,"content_block":{"type":"text","text":""}}"#,
+            "\n\n",
+            r#"data: {"type":"content_block_delta","index":0,"delta":{"type":"text_delta","text":"Hi"}}"#,
+            "\n\n",
+            r#"data: {"type":"content_block_stop","index":0}"#,
+            "\n\n",
+            r#"data: {"type":"message_delta","delta":{"stop_reason":"end_turn","stop_sequence":null},"usage":{"output_tokens":1}}"#,
+            "\n\n",
+            r#"data: {"type":"message_stop"}"#,
+            "\n\n",
+        );
+        let anthropic = provider()
+            .middleware(RateLimited(reqwest::StatusCode::OK, events))
+            .build()
+            .unwrap();
+
+        let mut response = LanguageModelRequest::builder()
+            .model(anthropic)
+            .prompt("Hi")
+            .build()
+            .stream_text()
+            .await
+            .unwrap();
+        let text: Vec<String> = response.text_stream().collect().await;
+        assert_eq!(text.concat(), "Hi");
+
+        let extensions = response.response_extensions().await;
+        let info = extensions.get::<RateLimitInfo>();
+        assert_eq!(info.requests_limit, Some(50));
+        assert_eq!(info.requests_remaining, Some(0));
+    }
+}
@@ -38,6 +38,8 @@ pub(crate) enum AnthropicStopReason {
     PauseTurn,
     #[serde(rename = "refusal")]
     Refusal,
+    #[serde(untagged)]
+    Other(String),
 }
 
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
@@ -47,13 +49,33 @@ pub(crate) struct AnthropicMessageResponse {
     pub model: String,
     #[serde(default = "assistant_as_str")]
     role: String, // always "assistant"
-    pub stop_reason: Option<String>,
-    pub stop_sequences: Option<Vec<String>>,
+    pub stop_reason: Option<AnthropicStopReason>,
+    pub stop_sequence: Option<String>,
     #[serde(rename = "type", default = "message_as_str")]
     type_: String,
     pub usage: AnthropicUsage,
 }
 
+/// The body of a `/messages/count_tokens` request.
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct AnthropicCountTokensRequest {
+    pub model: String,
+    pub messages: Vec<AnthropicMessageParam>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub system: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thinking: Option<AnthropicThinking>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tools: Option<Vec<AnthropicTool>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_choice: Option<AnthropicToolChoice>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub(crate) struct AnthropicCountTokensResponse {
+    pub input_tokens: usize,
+}
+
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub(crate) struct AnthropicUsage {
     pub cache_creation: AnthropicCacheCreation,
@@ -174,13 +196,104 @@ pub enum AnthropicUserMessageContentBlock {
         /// The text content
         text: String,
     },
+    #[serde(rename = "image")]
+    /// Image content
+    Image {
+        /// The image source
+        source: AnthropicImageSource,
+    },
+    #[serde(rename = "document")]
+    /// Document content
+    Document {
+        /// The document source
+        source: AnthropicDocumentSource,
+        /// The title of the document
+        #[serde(skip_serializing_if = "Option::is_none")]
+        title: Option<String>,
+    },
     #[serde(rename = "tool_result")]
     /// Tool result content
     ToolResult {
         /// The ID of the tool used
         tool_use_id: String,
         /// The content of the tool result
-        content: String,
+        content: AnthropicToolResultContent,
+    },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+/// See more [here](https://platform.claude.com/docs/en/api/messages#tool_result_block_param)
+pub enum AnthropicToolResultContent {
+    /// Regular text content
+    Text(String),
+    /// List of text and image blocks
+    Blocks(Vec<AnthropicToolResultContentBlock>),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+/// A content block inside a tool result
+pub enum AnthropicToolResultContentBlock {
+    #[serde(rename = "text")]
+    /// Regular text content
+    Text {
+        /// The text content
+        text: String,
+    },
+    #[serde(rename = "image")]
+    /// Image content
+    Image {
+        /// The image source
+        source: AnthropicImageSource,
+    },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+/// See more [here](https://platform.claude.com/docs/en/api/messages#image_block_param)
+pub enum AnthropicImageSource {
+    #[serde(rename = "base64")]
+    /// Base64 encoded image data
+    Base64 {
+        /// The media type of the image, e.g. `image/png`
+        media_type: String,
+        /// The base64 encoded data
+        data: String,
+    },
+    #[serde(rename = "url")]
+    /// An image downloaded from a URL
+    Url {
+        /// The URL of the image
+        url: String,
+    },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+/// See more [here](https://platform.claude.com/docs/en/api/messages#document_block_param)
+pub enum AnthropicDocumentSource {
+    #[serde(rename = "base64")]
+    /// Base64 encoded PDF data
+    Base64 {
+        /// The media type of the document, `application/pdf`
+        media_type: String,
+        /// The base64 encoded data
+        data: String,
+    },
+    #[serde(rename = "text")]
+    /// Plain text
+    Text {
+        /// The media type of the document, `text/plain`
+        media_type: String,
+        /// The text of the document
+        data: String,
+    },
+    #[serde(rename = "url")]
+    /// A PDF downloaded from a URL
+    Url {
+        /// The URL of the document
+        url: String,
     },
 }
 
@@ -206,6 +319,45 @@ pub(crate) struct AnthropicTool {
     pub input_schema: serde_json::Value,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub(crate) enum AnthropicToolChoice {
+    Auto {
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        disable_parallel_tool_use: bool,
+    },
+    Any {
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        disable_parallel_tool_use: bool,
+    },
+    Tool {
+        name: String,
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        disable_parallel_tool_use: bool,
+    },
+    None,
+}
+
+impl AnthropicToolChoice {
+    /// Returns the choice with parallel tool use disabled, so the model calls at most
+    /// one tool per response.
+    pub(crate) fn without_parallel_tool_use(self) -> Self {
+        match self {
+            Self::Auto { .. } => Self::Auto {
+                disable_parallel_tool_use: true,
+            },
+            Self::Any { .. } => Self::Any {
+                disable_parallel_tool_use: true,
+            },
+            Self::Tool { name, .. } => Self::Tool {
+                name,
+                disable_parallel_tool_use: true,
+            },
+            Self::None => Self::None,
+        }
+    }
+}
+
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 #[serde(tag = "type")]
 pub(crate) enum AnthropicThinking {
@@ -323,3 +475,21 @@ fn assistant_as_str() -> String {
 fn message_as_str() -> String {
     "message".to_string()
 }
+
+/// A page of the models endpoint.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub(crate) struct AnthropicModelList {
+    pub data: Vec<AnthropicModel>,
+    #[serde(default)]
+    pub has_more: bool,
+    #[serde(default)]
+    pub last_id: Option<String>,
+}
+
+/// A model of the models endpoint.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub(crate) struct AnthropicModel {
+    pub id: String,
+    #[serde(default)]
+    pub display_name: Option<String>,
+}
@@ -1,12 +1,36 @@
 use crate::core::Message;
 use crate::core::language_model::{
-    LanguageModelOptions, LanguageModelResponseContentType, ReasoningEffort, Usage,
+    FinishReason, LanguageModelOptions, LanguageModelResponseContentType, ReasoningEffort, Usage,
 };
+use crate::core::messages::{ContentPart, MediaSource};
+use crate::core::tools::ToolChoice;
 use crate::providers::anthropic::client::{
-    AnthropicAssistantMessageParamContent, AnthropicMessageDeltaUsage, AnthropicMessageParam,
-    AnthropicOptions, AnthropicThinking, AnthropicTool, AnthropicUsage,
+    AnthropicAssistantMessageParamContent, AnthropicDocumentSource, AnthropicImageSource,
+    AnthropicMessageDeltaUsage, AnthropicMessageParam, AnthropicOptions, AnthropicStopReason,
+    AnthropicThinking, AnthropicTool, AnthropicToolChoice, AnthropicToolResultContent,
+    AnthropicToolResultContentBlock, AnthropicUsage, AnthropicUserMessageContent,
+    AnthropicUserMessageContentBlock,
 };
 use crate::providers::anthropic::extensions;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+
+/// Name of the tool Anthropic is asked to call when structured output is requested.
+pub(crate) const STRUCTURED_OUTPUT_TOOL: &str = "json_response";
+
+/// Turns a call to the structured output tool back into a JSON text response.
+pub(crate) fn structured_output_content(
+    content: LanguageModelResponseContentType,
+) -> LanguageModelResponseContentType {
+    match content {
+        LanguageModelResponseContentType::ToolCall(ref call)
+            if call.tool.name == STRUCTURED_OUTPUT_TOOL =>
+        {
+            LanguageModelResponseContentType::Text(call.input.to_string())
+        }
+        other => other,
+    }
+}
 
 impl From<LanguageModelOptions> for AnthropicOptions {
     fn from(options: LanguageModelOptions) -> Self {
@@ -34,12 +58,16 @@ impl From<LanguageModelOptions> for AnthropicOptions {
                         request.system(Some(s.content));
                     }
                 }
+                Message::User(u) if u.parts.is_empty() => {
+                    messages.push(AnthropicMessageParam::User {
+                        content: AnthropicUserMessageContent::Text(u.content),
+                    });
+                }
                 Message::User(u) => {
                     messages.push(AnthropicMessageParam::User {
-                        content:
-                            crate::providers::anthropic::client::AnthropicUserMessageContent::Text(
-                                u.content,
-                            ),
+                        content: AnthropicUserMessageContent::Blocks(
+                            u.parts.into_iter().filter_map(user_block).collect(),
+                        ),
                     });
                 }
                 Message::Assistant(a) => match a.content {
@@ -75,14 +103,32 @@ impl From<LanguageModelOptions> for AnthropicOptions {
                             }],
                         });
                     }
-                    LanguageModelResponseContentType::NotSupported(_) => {}
+                    LanguageModelResponseContentType::Source(_)
+                    | LanguageModelResponseContentType::Image(_)
+                    | LanguageModelResponseContentType::Refusal(_)
+                    | LanguageModelResponseContentType::NotSupported(_) => {}
                 },
                 Message::Tool(tool) => {
+                    let text = tool.output.unwrap_or_default().to_string();
+                    let content = if tool.images.is_empty() {
+                        AnthropicToolResultContent::Text(text)
+                    } else {
+                        let mut blocks = vec![AnthropicToolResultContentBlock::Text { text }];
+                        blocks.extend(tool.images.into_iter().map(|image| {
+                            AnthropicToolResultContentBlock::Image {
+                                source: AnthropicImageSource::Base64 {
+                                    media_type: image.media_type,
+                                    data: image.data,
+                                },
+                            }
+                        }));
+                        AnthropicToolResultContent::Blocks(blocks)
+                    };
                     messages.push(AnthropicMessageParam::User {
                         content: crate::providers::anthropic::client::AnthropicUserMessageContent::Blocks(vec![
                             crate::providers::anthropic::client::AnthropicUserMessageContentBlock::ToolResult {
                                 tool_use_id: tool.tool.id,
-                                content: tool.output.unwrap_or_default().to_string(),
+                                content,
                             },
                         ]),
                     });
@@ -101,8 +147,9 @@ impl From<LanguageModelOptions> for AnthropicOptions {
         request.messages(messages);
 
         // convert tools to anthropic tools
-        if let Some(tools) = options.tools {
-            request.tools(Some(
+        let mut tools: Vec<AnthropicTool> = options
+            .tools
+            .map(|tools| {
                 tools
                     .tools
                     .lock()
@@ -120,12 +167,21 @@ impl From<LanguageModelOptions> for AnthropicOptions {
                             input_schema: tool_schema,
                         }
                     })
-                    .collect(),
-            ));
-        }
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let mut tool_choice = options
+            .tool_choice
+            .filter(|_| !tools.is_empty())
+            .map(AnthropicToolChoice::from);
 
         // convert reasoning to antropic thinking
-        request.thinking(options.reasoning_effort.map(|effort| match effort {
+        let thinking = options.reasoning_effort.map(|effort| match effort {
+            // Minimal is the smallest budget anthropic accepts
+            ReasoningEffort::Minimal => AnthropicThinking::Enable {
+                budget_tokens: 1024,
+            },
             // Low is 25% of the max_tokens
             ReasoningEffort::Low => AnthropicThinking::Enable {
                 budget_tokens: (max_tokens / 4) as usize,
@@ -138,12 +194,147 @@ impl From<LanguageModelOptions> for AnthropicOptions {
             ReasoningEffort::High => AnthropicThinking::Enable {
                 budget_tokens: (max_tokens - (max_tokens / 4)) as usize,
             },
-        }));
+        });
+
+        // anthropic has no json schema response format, so structured output is
+        // requested by forcing a call to a tool whose input is the schema.
+        // Forcing a tool is not allowed while thinking, so the model is left to
+        // pick it in that case.
+        if let Some(schema) = options.schema {
+            let mut input_schema = schema.to_value();
+            if let Some(schema) = input_schema.as_object_mut() {
+                schema.remove("$schema");
+            };
+            tools.push(AnthropicTool {
+                name: STRUCTURED_OUTPUT_TOOL.to_string(),
+                description: "Respond with a JSON object that matches the input schema."
+                    .to_string(),
+                input_schema,
+            });
+            tool_choice = Some(match thinking {
+                Some(_) => AnthropicToolChoice::Auto {
+                    disable_parallel_tool_use: false,
+                },
+                None => AnthropicToolChoice::Tool {
+                    name: STRUCTURED_OUTPUT_TOOL.to_string(),
+                    disable_parallel_tool_use: false,
+                },
+            });
+        }
+
+        // Parallel tool use is disabled through the tool choice
+        if options.parallel_tool_calls == Some(false) && !tools.is_empty() {
+            tool_choice = Some(
+                tool_choice
+                    .unwrap_or(AnthropicToolChoice::Auto {
+                        disable_parallel_tool_use: false,
+                    })
+                    .without_parallel_tool_use(),
+            );
+        }
+        request.tool_choice(tool_choice);
+
+        request.tools((!tools.is_empty()).then_some(tools));
+        request.thinking(thinking);
+        request.stop_sequences(options.stop_sequences);
+        request.temperature(options.temperature.map(|t| t as f32 / 100.0));
+        request.top_p(options.top_p.map(|t| t as f32 / 100.0));
+        request.top_k(options.top_k);
+        request.provider_options(options.provider_options);
 
         request.build().expect("Failed to build AntropicRequest")
     }
 }
 
+/// Converts a part of a multi-part user message into a content block. Audio, and files
+/// other than PDFs and text documents, are not supported and are left out.
+fn user_block(part: ContentPart) -> Option<AnthropicUserMessageContentBlock> {
+    match part {
+        ContentPart::Text(text) => Some(AnthropicUserMessageContentBlock::Text { text }),
+        ContentPart::Image(image) => {
+            let source = match image.source {
+                MediaSource::Url(url) => AnthropicImageSource::Url { url },
+                MediaSource::Base64(data) => AnthropicImageSource::Base64 {
+                    media_type: image.media_type.unwrap_or_else(|| "image/jpeg".to_string()),
+                    data,
+                },
+            };
+            Some(AnthropicUserMessageContentBlock::Image { source })
+        }
+        ContentPart::File(file)
+            if file.media_type == "application/pdf" || file.media_type.starts_with("text/") =>
+        {
+            Some(document_block(file.source, file.media_type, file.filename))
+        }
+        ContentPart::File(_) | ContentPart::Audio(_) => None,
+        ContentPart::Document(document) => Some(document_block(
+            document.source,
+            document.media_type,
+            document.title,
+        )),
+    }
+}
+
+fn document_block(
+    source: MediaSource,
+    media_type: String,
+    title: Option<String>,
+) -> AnthropicUserMessageContentBlock {
+    let source = match source {
+        MediaSource::Url(url) => AnthropicDocumentSource::Url { url },
+        // Anthropic takes text documents as plain text, not base64
+        MediaSource::Base64(data) if media_type.starts_with("text/") => {
+            match STANDARD.decode(&data).map(String::from_utf8) {
+                Ok(Ok(text)) => AnthropicDocumentSource::Text {
+                    media_type: "text/plain".to_string(),
+                    data: text,
+                },
+                _ => AnthropicDocumentSource::Base64 { media_type, data },
+            }
+        }
+        MediaSource::Base64(data) => AnthropicDocumentSource::Base64 { media_type, data },
+    };
+    AnthropicUserMessageContentBlock::Document { source, title }
+}
+
+impl From<ToolChoice> for AnthropicToolChoice {
+    fn from(choice: ToolChoice) -> Self {
+        match choice {
+            ToolChoice::Auto => Self::Auto {
+                disable_parallel_tool_use: false,
+            },
+            ToolChoice::Required => Self::Any {
+                disable_parallel_tool_use: false,
+            },
+            ToolChoice::None => Self::None,
+            ToolChoice::Tool(name) => Self::Tool {
+                name,
+                disable_parallel_tool_use: false,
+            },
+        }
+    }
+}
+
+impl From<AnthropicStopReason> for FinishReason {
+    fn from(reason: AnthropicStopReason) -> Self {
+        match reason {
+            AnthropicStopReason::EndTurn | AnthropicStopReason::StopSequence => Self::Stop,
+            AnthropicStopReason::MaxTokens => Self::Length,
+            AnthropicStopReason::ToolUse => Self::ToolCalls,
+            AnthropicStopReason::Refusal => Self::ContentFilter,
+            AnthropicStopReason::PauseTurn => Self::Other("pause_turn".to_string()),
+            AnthropicStopReason::Other(reason) => Self::Other(reason),
+        }
+    }
+}
+
+/// Returns the refusal content for a response Anthropic stopped because Claude
+/// declined to answer.
+pub(crate) fn refusal(reason: &AnthropicStopReason) -> Option<LanguageModelResponseContentType> {
+    matches!(reason, AnthropicStopReason::Refusal)
+        .then(|| LanguageModelResponseContentType::Refusal("refusal".to_string()))
+}
+
 impl From<AnthropicUsage> for Usage {
     fn from(usage: AnthropicUsage) -> Self {
         Self {
@@ -158,13 +349,223 @@ impl From<AnthropicUsage> for Usage {
 impl From<AnthropicMessageDeltaUsage> for Usage {
     fn from(usage: AnthropicMessageDeltaUsage) -> Self {
         Self {
-            input_tokens: Some(usage.input_tokens.unwrap_or(0)),
+            // Fields absent from a delta were reported by `message_start` and are kept
+            input_tokens: usage.input_tokens,
             output_tokens: Some(usage.output_tokens),
-            cached_tokens: Some(
-                usage.cache_creation_input_tokens.unwrap_or(0)
-                    + usage.cache_read_input_tokens.unwrap_or(0),
+            cached_tokens: crate::core::utils::sum_options(
+                usage.cache_creation_input_tokens,
+                usage.cache_read_input_tokens,
             ),
             reasoning_tokens: None,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ToolCallInfo;
+    use schemars::{JsonSchema, schema_for};
+
+    #[derive(JsonSchema)]
+    #[allow(dead_code)]
+    struct City {
+        name: String,
+    }
+
+    #[test]
+    fn schema_forces_structured_output_tool() {
+        let options = LanguageModelOptions {
+            schema: Some(schema_for!(City)),
+            ..Default::default()
+        };
+        let request: AnthropicOptions = options.into();
+
+        let tools = request.tools.unwrap();
+        assert_eq!(tools.len(), 1);
+        assert_eq!(tools[0].name, STRUCTURED_OUTPUT_TOOL);
+        assert!(tools[0].input_schema.get("$schema").is_none());
+        assert!(matches!(
+            request.tool_choice,
+            Some(AnthropicToolChoice::Tool { ref name, .. }) if name == STRUCTURED_OUTPUT_TOOL
+        ));
+    }
+
+    #[test]
+    fn tool_choice_is_sent_with_tools() {
+        let options = LanguageModelOptions {
+            tools: Some(crate::core::tools::ToolList::new(vec![
+                crate::core::tools::Tool {
+                    name: "weather".to_string(),
+                    ..Default::default()
+                },
+            ])),
+            tool_choice: Some(ToolChoice::Tool("weather".to_string())),
+            ..Default::default()
+        };
+        let request: AnthropicOptions = options.into();
+        assert!(matches!(
+            request.tool_choice,
+            Some(AnthropicToolChoice::Tool { ref name, .. }) if name == "weather"
+        ));
+
+        let options = LanguageModelOptions {
+            tool_choice: Some(ToolChoice::Required),
+            ..Default::default()
+        };
+        let request: AnthropicOptions = options.into();
+        assert!(request.tool_choice.is_none());
+    }
+
+    #[test]
+    fn provider_options_are_merged_into_the_body() {
+        let options = LanguageModelOptions {
+            provider_options: serde_json::json!({"service_tier": "standard_only"})
+                .as_object()
+                .cloned()
+                .unwrap(),
+            ..Default::default()
+        };
+        let request: AnthropicOptions = options.into();
+
+        let body = serde_json::to_value(&request).unwrap();
+        assert_eq!(body["service_tier"], "standard_only");
+        assert!(body.get("provider_options").is_none());
+    }
+
+    #[test]
+    fn parallel_tool_use_can_be_disabled() {
+        let options = LanguageModelOptions {
+            tools: Some(crate::core::tools::ToolList::new(vec![
+                crate::core::tools::Tool {
+                    name: "weather".to_string(),
+                    ..Default::default()
+                },
+            ])),
+            parallel_tool_calls: Some(false),
+            ..Default::default()
+        };
+        let request: AnthropicOptions = options.into();
+
+        let body = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            body["tool_choice"],
+            serde_json::json!({"type": "auto", "disable_parallel_tool_use": true})
+        );
+    }
+
+    #[test]
+    fn stop_sequences_are_sent() {
+        let options = LanguageModelOptions {
+            stop_sequences: Some(vec!["END".to_string()]),
+            ..Default::default()
+        };
+        let request: AnthropicOptions = options.into();
+
+        let body = serde_json::to_value(&request).unwrap();
+        assert_eq!(body["stop_sequences"], serde_json::json!(["END"]));
+    }
+
+    #[test]
+    fn sampling_options_are_sent() {
+        let options = LanguageModelOptions {
+            temperature: Some(50),
+            top_p: Some(90),
+            top_k: Some(40),
+            ..Default::default()
+        };
+        let request: AnthropicOptions = options.into();
+
+        assert_eq!(request.temperature, Some(0.5));
+        assert_eq!(request.top_p, Some(0.9));
+        assert_eq!(request.top_k, Some(40));
+    }
+
+    #[test]
+    fn structured_output_tool_call_becomes_text() {
+        let mut call = ToolCallInfo::new(STRUCTURED_OUTPUT_TOOL);
+        call.input(serde_json::json!({ "name": "Paris" }));
+        let content = structured_output_content(LanguageModelResponseContentType::ToolCall(call));
+        assert!(matches!(
+            content,
+            LanguageModelResponseContentType::Text(ref text) if text == r#"{"name":"Paris"}"#
+        ));
+    }
+
+    #[test]
+    fn multi_part_user_message_becomes_blocks() {
+        use crate::core::messages::{ImagePart, UserMessage};
+
+        let options = LanguageModelOptions {
+            messages: vec![
+                Message::User(UserMessage::with_parts([
+                    ContentPart::text("What is this?"),
+                    ImagePart::url("https://example.com/cat.png").into(),
+                ]))
+                .into(),
+            ],
+            ..Default::default()
+        };
+        let request: AnthropicOptions = options.into();
+
+        let value = serde_json::to_value(&request.messages[0]).unwrap();
+        assert_eq!(
+            value["content"],
+            serde_json::json!([
+                {"type": "text", "text": "What is this?"},
+                {"type": "image", "source": {"type": "url", "url": "https://example.com/cat.png"}}
+            ])
+        );
+    }
+
+    #[test]
+    fn document_parts_become_document_blocks() {
+        use crate::core::messages::{DocumentPart, UserMessage};
+
+        let options = LanguageModelOptions {
+            messages: vec![
+                Message::User(UserMessage::with_parts([
+                    DocumentPart::pdf(&b"%PDF"[..])
+                        .unwrap()
+                        .title("Report")
+                        .into(),
+                    DocumentPart::bytes(&b"Meeting notes"[..], "text/markdown")
+                        .unwrap()
+                        .into(),
+                ]))
+                .into(),
+            ],
+            ..Default::default()
+        };
+        let request: AnthropicOptions = options.into();
+
+        let value = serde_json::to_value(&request.messages[0]).unwrap();
+        assert_eq!(
+            value["content"],
+            serde_json::json!([
+                {
+                    "type": "document",
+                    "source": {"type": "base64", "media_type": "application/pdf", "data": "JVBERg=="},
+                    "title": "Report"
+                },
+                {
+                    "type": "document",
+                    "source": {"type": "text", "media_type": "text/plain", "data": "Meeting notes"}
+                }
+            ])
+        );
+    }
+
+    #[test]
+    fn stop_reason_maps_to_finish_reason() {
+        let tool_use: AnthropicStopReason = serde_json::from_str(r#""tool_use""#).unwrap();
+        assert_eq!(FinishReason::from(tool_use), FinishReason::ToolCalls);
+
+        let unknown: AnthropicStopReason =
+            serde_json::from_str(r#""model_context_window_exceeded""#).unwrap();
+        assert_eq!(
+            FinishReason::from(unknown),
+            FinishReason::Other("model_context_window_exceeded".to_string())
+        );
+    }
+}
@@ -1,7 +1,10 @@
 //! Anthropic-specific metadata for extensions.
 
+use crate::extensions::Extension;
+use serde::{Deserialize, Serialize};
+
 /// Metadata specific to Anthropic provider thinking functionality.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub(crate) struct AnthropicThinkingMetadata {
     /// The signature returned by Anthropic models for thinking blocks.
     ///
@@ -9,3 +12,7 @@ pub(crate) struct AnthropicThinkingMetadata {
     /// for extended thinking to work correctly.
     pub signature: Option<String>,
 }
+
+impl Extension for AnthropicThinkingMetadata {
+    const NAME: &'static str = "anthropic.thinking_metadata";
+}
@@ -4,16 +4,19 @@ use crate::core::capabilities::ModelName;
 use crate::core::client::LanguageModelClient;
 use crate::core::language_model::{
     LanguageModelOptions, LanguageModelResponse, LanguageModelResponseContentType,
-    LanguageModelStreamChunk, ProviderStream,
+    LanguageModelStreamChunk, ProviderStream, SamplingOption, Usage, stream_with_rate_limit,
+    usage_accumulator::UsageAccumulator,
 };
 use crate::core::messages::AssistantMessage;
-use crate::core::tools::ToolDetails;
+use crate::core::tools::{ToolCallDelta, ToolDetails};
 use crate::core::{LanguageModelStreamChunkType, ToolCallInfo};
 use crate::extensions::Extensions;
 use crate::providers::anthropic::Anthropic;
 use crate::providers::anthropic::client::{
-    AnthropicContentBlock, AnthropicDelta, AnthropicMessageDeltaUsage, AnthropicOptions,
-    AnthropicStreamEvent,
+    AnthropicContentBlock, AnthropicDelta, AnthropicOptions, AnthropicStreamEvent,
+};
+use crate::providers::anthropic::conversions::{
+    STRUCTURED_OUTPUT_TOOL, refusal, structured_output_content,
 };
 use crate::providers::anthropic::extensions;
 use crate::{core::language_model::LanguageModel, error::Result};
@@ -28,6 +31,34 @@ impl<M: ModelName> LanguageModel for Anthropic<M> {
         self.options.model.clone()
     }
 
+    fn capabilities(&self) -> crate::core::capabilities::Capabilities {
+        M::CAPABILITIES
+    }
+
+    fn info(&self) -> crate::core::capabilities::ModelInfo {
+        Self::model_info(&self.name())
+    }
+
+    fn unsupported_options(&self) -> &'static [SamplingOption] {
+        &[
+            SamplingOption::Seed,
+            SamplingOption::PresencePenalty,
+            SamplingOption::FrequencyPenalty,
+        ]
+    }
+
+    /// Counts tokens with the `/messages/count_tokens` endpoint.
+    async fn count_tokens(&self, options: &LanguageModelOptions) -> Result<usize> {
+        use crate::core::client::TokenCountClient;
+
+        let mut model = self.clone();
+        model.options = options.clone().into();
+        model.options.model = self.options.model.clone();
+
+        let response = TokenCountClient::send(&model, model.settings.base_url.clone()).await?;
+        Ok(response.input_tokens)
+    }
+
     /// Generates text using the Anthropic provider.
     async fn generate_text(
         &mut self,
@@ -37,7 +68,9 @@ impl<M: ModelName> LanguageModel for Anthropic<M> {
         options.model = self.options.model.clone();
         self.options = options;
 
-        let response = self.send(self.settings.base_url.clone()).await?;
+        let (response, rate_limit) = self
+            .send_with_rate_limit(self.settings.base_url.clone())
+            .await?;
 
         let mut collected: Vec<LanguageModelResponseContentType> = Vec::new();
 
@@ -77,11 +110,20 @@ impl<M: ModelName> LanguageModel for Anthropic<M> {
                 }
             }
         }
+        collected.extend(response.stop_reason.as_ref().and_then(refusal));
 
-        Ok(LanguageModelResponse {
-            contents: collected,
+        let response = LanguageModelResponse {
+            contents: collected
+                .into_iter()
+                .map(structured_output_content)
+                .collect(),
             usage: Some(response.usage.into()),
-        })
+            stop_sequence: response.stop_sequence,
+            finish_reason: response.stop_reason.map(Into::into),
+            logprobs: Vec::new(),
+            extensions: Default::default(),
+        };
+        Ok(response.with_rate_limit(rate_limit))
     }
 
     /// Streams text using the Anthropic provider.
@@ -91,33 +133,14 @@ impl<M: ModelName> LanguageModel for Anthropic<M> {
         options.model = self.options.model.clone();
         self.options = options;
 
-        // Retry logic for rate limiting
-        let max_retries = 5;
-        let mut retry_count = 0;
-        let mut wait_time = std::time::Duration::from_secs(1);
-
-        let response = loop {
-            match self.send_and_stream(self.settings.base_url.clone()).await {
-                Ok(stream) => break stream,
-                Err(crate::error::Error::ApiError {
-                    status_code: Some(status),
-                    ..
-                }) if status == reqwest::StatusCode::TOO_MANY_REQUESTS
-                    && retry_count < max_retries =>
-                {
-                    retry_count += 1;
-                    tokio::time::sleep(wait_time).await;
-                    wait_time *= 2; // Exponential backoff
-                    continue;
-                }
-                Err(e) => return Err(e),
-            }
-        };
+        let (response, rate_limit) = self
+            .send_and_stream_with_rate_limit(self.settings.base_url.clone())
+            .await?;
 
         #[derive(Default)]
         struct StreamState {
             content_blocks: HashMap<usize, AccumulatedBlock>,
-            usage: Option<AnthropicMessageDeltaUsage>,
+            usage: UsageAccumulator,
         }
 
         #[derive(Debug)]
@@ -146,10 +169,13 @@ impl<M: ModelName> LanguageModel for Anthropic<M> {
                 futures::future::ready({
                     match evt_res {
                     Ok(event) => match event {
-                        AnthropicStreamEvent::MessageStart { .. } => {
-                            Some(Ok(vec![LanguageModelStreamChunk::Delta(
-                                LanguageModelStreamChunkType::Start,
-                            )]))
+                        AnthropicStreamEvent::MessageStart { message } => {
+                            let usage: Usage = message.usage.into();
+                            state.usage.observe(&usage);
+                            Some(Ok(vec![
+                                LanguageModelStreamChunk::Delta(LanguageModelStreamChunkType::Start),
+                                LanguageModelStreamChunk::Usage(usage),
+                            ]))
                         }
                         AnthropicStreamEvent::ContentBlockStart {
                             index,
@@ -219,14 +245,27 @@ impl<M: ModelName> LanguageModel for Anthropic<M> {
                                     }
                                     (
                                         AccumulatedBlock::ToolUse {
-                                            accumulated_json, ..
+                                            id,
+                                            name,
+                                            accumulated_json,
                                         },
                                         AnthropicDelta::ToolUseDelta { partial_json },
                                     ) => {
                                         accumulated_json.push_str(&partial_json);
-                                        Some(Ok(vec![LanguageModelStreamChunk::Delta(
-                                            LanguageModelStreamChunkType::ToolCall(partial_json),
-                                        )]))
+                                        let chunk = if name == STRUCTURED_OUTPUT_TOOL {
+                                            LanguageModelStreamChunk::Delta(
+                                                LanguageModelStreamChunkType::Text(partial_json),
+                                            )
+                                        } else {
+                                            LanguageModelStreamChunk::ToolCallDelta(ToolCallDelta {
+                                                tool: ToolDetails {
+                                                    name: name.clone(),
+                                                    id: id.clone(),
+                                                },
+                                                delta: partial_json,
+                                            })
+                                        };
+                                        Some(Ok(vec![chunk]))
                                     }
                                     _ => Some(Ok(unsupported("ContentBlockDelta"))),
                                 }
@@ -237,9 +276,23 @@ impl<M: ModelName> LanguageModel for Anthropic<M> {
                         AnthropicStreamEvent::ContentBlockStop { .. } => {
                             Some(Ok(unsupported("ContentBlockStop")))
                         }
-                        AnthropicStreamEvent::MessageDelta { usage, .. } => {
-                            state.usage = Some(usage);
-                            Some(Ok(unsupported("MessageDelta")))
+                        AnthropicStreamEvent::MessageDelta { delta, usage } => {
+                            let usage: Usage = usage.into();
+                            state.usage.observe(&usage);
+                            let mut chunks = vec![LanguageModelStreamChunk::Usage(usage)];
+                            if let Some(sequence) = delta.stop_sequence {
+                                chunks.push(LanguageModelStreamChunk::StopSequence(sequence));
+                            }
+                            if let Some(reason) = delta.stop_reason {
+                                chunks.push(LanguageModelStreamChunk::FinishReason(reason.clone().into()));
+                                if let Some(content) = refusal(&reason) {
+                                    chunks.push(LanguageModelStreamChunk::Done(AssistantMessage {
+                                        content,
+                                        usage: None,
+                                    }));
+                                }
+                            }
+                            Some(Ok(chunks))
                         }
                         AnthropicStreamEvent::MessageStop => {
                             let mut collected = vec![];
@@ -302,10 +355,11 @@ impl<M: ModelName> LanguageModel for Anthropic<M> {
                             }
                             Some(Ok(collected
                                 .into_iter()
+                                .map(structured_output_content)
                                 .map(|ref c| {
                                     LanguageModelStreamChunk::Done(AssistantMessage {
                                         content: c.clone(),
-                                        usage: state.usage.clone().map(|usage| usage.into()),
+                                        usage: Some(state.usage.total()),
                                     })
                                 })
                                 .collect()))
@@ -328,6 +382,6 @@ impl<M: ModelName> LanguageModel for Anthropic<M> {
             },
         );
 
-        Ok(Box::pin(stream))
+        Ok(stream_with_rate_limit(rate_limit, Box::pin(stream)))
     }
 }
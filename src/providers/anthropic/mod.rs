@@ -12,6 +12,8 @@ pub mod settings;
 
 use crate::core::DynamicModel;
 use crate::core::capabilities::ModelName;
+use crate::core::client::RetryPolicy;
+use crate::core::provider::ModelDescriptor;
 use crate::core::utils::validate_base_url;
 use crate::error::Error;
 use crate::providers::anthropic::client::AnthropicOptions;
@@ -21,6 +23,9 @@ use serde::Serialize;
 /// The API version used for Anthropic requests.
 pub const ANTHROPIC_API_VERSION: &str = "2023-06-01";
 
+/// The beta flag enabling token-efficient tool use.
+pub const ANTHROPIC_TOKEN_EFFICIENT_TOOLS_BETA: &str = "token-efficient-tools-2025-02-19";
+
 /// The Anthropic provider.
 #[derive(Debug, Serialize, Clone)]
 pub struct Anthropic<M: ModelName> {
@@ -35,6 +40,15 @@ impl<M: ModelName> Anthropic<M> {
     pub fn builder() -> AnthropicBuilder<M> {
         AnthropicBuilder::default()
     }
+
+    /// Lists the models available to the API key, e.g. to populate a model picker.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the API cannot be reached or returns an invalid response.
+    pub async fn list_models(&self) -> crate::error::Result<Vec<ModelDescriptor>> {
+        crate::core::client::ModelListClient::list(self, self.settings.base_url.clone()).await
+    }
 }
 
 impl Anthropic<DynamicModel> {
@@ -162,6 +176,22 @@ impl<M: ModelName> AnthropicBuilder<M> {
         self
     }
 
+    /// Sets the retry policy applied to requests made by this provider.
+    ///
+    /// # Parameters
+    ///
+    /// * `retry_policy` - The [`RetryPolicy`] to use for retryable failures.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the retry policy set.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.settings.retry_policy = retry_policy;
+        self
+    }
+
+    crate::core::client::transport_builder_methods!();
+
     /// Sets the name of the provider. Defaults to "anthropic".
     ///
     /// # Parameters
@@ -182,6 +212,44 @@ impl<M: ModelName> AnthropicBuilder<M> {
         self
     }
 
+    /// Adds a beta feature flag to send in the `anthropic-beta` header.
+    ///
+    /// # Parameters
+    ///
+    /// * `beta` - The beta flag, e.g. "token-efficient-tools-2025-02-19".
+    ///
+    /// # Returns
+    ///
+    /// The builder with the beta flag added.
+    pub fn beta(mut self, beta: impl Into<String>) -> Self {
+        let beta = beta.into();
+        if !self.settings.betas.contains(&beta) {
+            self.settings.betas.push(beta);
+        }
+        self
+    }
+
+    /// Enables or disables Anthropic's token-efficient tool use beta, which
+    /// reduces output tokens spent on tool calls.
+    ///
+    /// # Parameters
+    ///
+    /// * `enabled` - Whether to send the token-efficient tools beta flag.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the beta flag toggled.
+    pub fn token_efficient_tools(mut self, enabled: bool) -> Self {
+        if enabled {
+            self.beta(ANTHROPIC_TOKEN_EFFICIENT_TOOLS_BETA)
+        } else {
+            self.settings
+                .betas
+                .retain(|b| b != ANTHROPIC_TOKEN_EFFICIENT_TOOLS_BETA);
+            self
+        }
+    }
+
     /// Builds the Anthropic provider.
     ///
     /// Validates the configuration and creates the provider instance.
@@ -1,10 +1,11 @@
 //! Defines the settings for the Anthropic provider.
 
+use crate::core::client::{RetryPolicy, TransportSettings};
 use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 
 /// Settings for the Anthropic provider.
-#[derive(Debug, Clone, Serialize, Deserialize, Builder)]
+#[derive(Clone, Serialize, Deserialize, Builder)]
 #[builder(setter(into), default)]
 pub struct AnthropicProviderSettings {
     /// The name of the provider.
@@ -19,6 +20,30 @@ pub struct AnthropicProviderSettings {
     /// Custom API path override. When set, this path is used instead of the
     /// default "/messages".
     pub path: Option<String>,
+
+    /// Beta feature flags sent in the `anthropic-beta` header.
+    pub betas: Vec<String>,
+
+    /// The retry policy applied to requests.
+    pub retry_policy: RetryPolicy,
+
+    /// The HTTP client, middleware and rate limiter requests go through.
+    #[serde(skip)]
+    pub transport: TransportSettings,
+}
+
+impl std::fmt::Debug for AnthropicProviderSettings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AnthropicProviderSettings")
+            .field("provider_name", &self.provider_name)
+            .field("base_url", &self.base_url)
+            .field("api_key", &"<redacted>")
+            .field("path", &self.path)
+            .field("betas", &self.betas)
+            .field("retry_policy", &self.retry_policy)
+            .field("transport", &self.transport)
+            .finish()
+    }
 }
 
 impl Default for AnthropicProviderSettings {
@@ -29,6 +54,9 @@ impl Default for AnthropicProviderSettings {
             base_url: "https://api.anthropic.com/v1/".to_string(),
             api_key: std::env::var("ANTHROPIC_API_KEY").unwrap_or_default(),
             path: None,
+            betas: Vec::new(),
+            retry_policy: RetryPolicy::default(),
+            transport: TransportSettings::default(),
         }
     }
 }
@@ -0,0 +1,142 @@
+//! Capabilities for Bedrock models.
+//!
+//! This module defines model types and their capabilities for models available through
+//! the Bedrock Converse API. Any other model ID or inference profile can be used
+//! through `Bedrock::model_name`. Users can implement additional traits on custom
+//! models.
+
+use crate::core::capabilities::*;
+use crate::model_capabilities;
+use crate::providers::bedrock::Bedrock;
+
+model_capabilities! {
+    provider: Bedrock,
+    models: {
+        AmazonNova2LiteV10 {
+            model_name: "amazon.nova-2-lite-v1:0",
+            constructor_name: amazon_nova_2_lite_v1_0,
+            display_name: "Nova 2 Lite",
+            capabilities: [ImageInputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport, VideoInputSupport]
+        },
+        AmazonNovaLiteV10 {
+            model_name: "amazon.nova-lite-v1:0",
+            constructor_name: amazon_nova_lite_v1_0,
+            display_name: "Nova Lite",
+            capabilities: [ImageInputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport, VideoInputSupport]
+        },
+        AmazonNovaMicroV10 {
+            model_name: "amazon.nova-micro-v1:0",
+            constructor_name: amazon_nova_micro_v1_0,
+            display_name: "Nova Micro",
+            capabilities: [TextInputSupport, TextOutputSupport, ToolCallSupport]
+        },
+        AmazonNovaPremierV10 {
+            model_name: "amazon.nova-premier-v1:0",
+            constructor_name: amazon_nova_premier_v1_0,
+            display_name: "Nova Premier",
+            capabilities: [ImageInputSupport, ReasoningSupport, TextInputSupport, TextOutputSupport, ToolCallSupport, VideoInputSupport]
+        },
+        AmazonNovaProV10 {
+            model_name: "amazon.nova-pro-v1:0",
+            constructor_name: amazon_nova_pro_v1_0,
+            display_name: "Nova Pro",
+            capabilities: [ImageInputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport, VideoInputSupport]
+        },
+        AnthropicClaude35Haiku20241022V10 {
+            model_name: "anthropic.claude-3-5-haiku-20241022-v1:0",
+            constructor_name: anthropic_claude_3_5_haiku_20241022_v1_0,
+            display_name: "Claude Haiku 3.5",
+            capabilities: [ImageInputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+        },
+        AnthropicClaude35Sonnet20240620V10 {
+            model_name: "anthropic.claude-3-5-sonnet-20240620-v1:0",
+            constructor_name: anthropic_claude_3_5_sonnet_20240620_v1_0,
+            display_name: "Claude Sonnet 3.5",
+            capabilities: [ImageInputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+        },
+        AnthropicClaude35Sonnet20241022V20 {
+            model_name: "anthropic.claude-3-5-sonnet-20241022-v2:0",
+            constructor_name: anthropic_claude_3_5_sonnet_20241022_v2_0,
+            display_name: "Claude Sonnet 3.5 v2",
+            capabilities: [ImageInputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+        },
+        AnthropicClaude37Sonnet20250219V10 {
+            model_name: "anthropic.claude-3-7-sonnet-20250219-v1:0",
+            constructor_name: anthropic_claude_3_7_sonnet_20250219_v1_0,
+            display_name: "Claude Sonnet 3.7",
+            capabilities: [ImageInputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+        },
+        AnthropicClaudeHaiku4520251001V10 {
+            model_name: "anthropic.claude-haiku-4-5-20251001-v1:0",
+            constructor_name: anthropic_claude_haiku_4_5_20251001_v1_0,
+            display_name: "Claude Haiku 4.5",
+            capabilities: [ImageInputSupport, ReasoningSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+        },
+        AnthropicClaudeOpus4120250805V10 {
+            model_name: "anthropic.claude-opus-4-1-20250805-v1:0",
+            constructor_name: anthropic_claude_opus_4_1_20250805_v1_0,
+            display_name: "Claude Opus 4.1",
+            capabilities: [ImageInputSupport, ReasoningSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+        },
+        AnthropicClaudeOpus420250514V10 {
+            model_name: "anthropic.claude-opus-4-20250514-v1:0",
+            constructor_name: anthropic_claude_opus_4_20250514_v1_0,
+            display_name: "Claude Opus 4",
+            capabilities: [ImageInputSupport, ReasoningSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+        },
+        AnthropicClaudeOpus4520251101V10 {
+            model_name: "anthropic.claude-opus-4-5-20251101-v1:0",
+            constructor_name: anthropic_claude_opus_4_5_20251101_v1_0,
+            display_name: "Claude Opus 4.5",
+            capabilities: [ImageInputSupport, ReasoningSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+        },
+        AnthropicClaudeSonnet420250514V10 {
+            model_name: "anthropic.claude-sonnet-4-20250514-v1:0",
+            constructor_name: anthropic_claude_sonnet_4_20250514_v1_0,
+            display_name: "Claude Sonnet 4",
+            capabilities: [ImageInputSupport, ReasoningSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+        },
+        AnthropicClaudeSonnet4520250929V10 {
+            model_name: "anthropic.claude-sonnet-4-5-20250929-v1:0",
+            constructor_name: anthropic_claude_sonnet_4_5_20250929_v1_0,
+            display_name: "Claude Sonnet 4.5",
+            capabilities: [ImageInputSupport, ReasoningSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+        },
+        DeepseekR1V10 {
+            model_name: "deepseek.r1-v1:0",
+            constructor_name: deepseek_r1_v1_0,
+            display_name: "DeepSeek-R1",
+            capabilities: [ReasoningSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+        },
+        DeepseekV3V10 {
+            model_name: "deepseek.v3-v1:0",
+            constructor_name: deepseek_v3_v1_0,
+            display_name: "DeepSeek-V3.1",
+            capabilities: [ReasoningSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+        },
+        MetaLlama3370bInstructV10 {
+            model_name: "meta.llama3-3-70b-instruct-v1:0",
+            constructor_name: meta_llama3_3_70b_instruct_v1_0,
+            display_name: "Llama 3.3 70B Instruct",
+            capabilities: [TextInputSupport, TextOutputSupport, ToolCallSupport]
+        },
+        MetaLlama4Maverick17bInstructV10 {
+            model_name: "meta.llama4-maverick-17b-instruct-v1:0",
+            constructor_name: meta_llama4_maverick_17b_instruct_v1_0,
+            display_name: "Llama 4 Maverick 17B Instruct",
+            capabilities: [ImageInputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+        },
+        MetaLlama4Scout17bInstructV10 {
+            model_name: "meta.llama4-scout-17b-instruct-v1:0",
+            constructor_name: meta_llama4_scout_17b_instruct_v1_0,
+            display_name: "Llama 4 Scout 17B Instruct",
+            capabilities: [ImageInputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+        },
+        MistralMistralLarge2402V10 {
+            model_name: "mistral.mistral-large-2402-v1:0",
+            constructor_name: mistral_mistral_large_2402_v1_0,
+            display_name: "Mistral Large (24.02)",
+            capabilities: [TextInputSupport, TextOutputSupport, ToolCallSupport]
+        }
+    }
+}
@@ -0,0 +1,272 @@
+//! Decoder for the `application/vnd.amazon.eventstream` framing used by
+//! `ConverseStream`.
+//!
+//! Each message is a binary frame: a 12 byte prelude (total length, headers length,
+//! prelude CRC32), the headers, the payload, and a CRC32 of the whole message. All
+//! integers are big-endian.
+
+use crate::error::{Error, Result};
+use crate::providers::bedrock::client::types::StreamEvent;
+use std::collections::HashMap;
+
+const PRELUDE_LEN: usize = 12;
+const CRC_LEN: usize = 4;
+
+/// A decoded event stream message.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Frame {
+    /// The string headers of the message. Headers of other types are skipped.
+    pub(crate) headers: HashMap<String, String>,
+    pub(crate) payload: Vec<u8>,
+}
+
+impl Frame {
+    /// Converts the frame to a stream event, or an error for exception messages.
+    pub(crate) fn into_event(self) -> Result<StreamEvent> {
+        let header = |name: &str| self.headers.get(name).map(String::as_str);
+
+        match header(":message-type") {
+            Some("event") => {}
+            Some("exception") => {
+                let message = serde_json::from_slice::<serde_json::Value>(&self.payload)
+                    .ok()
+                    .and_then(|v| v.get("message").and_then(|m| m.as_str()).map(String::from))
+                    .unwrap_or_else(|| String::from_utf8_lossy(&self.payload).into_owned());
+                return Err(Error::ApiError {
+                    status_code: None,
+                    details: format!(
+                        "{}: {message}",
+                        header(":exception-type").unwrap_or("exception")
+                    ),
+                });
+            }
+            _ => {
+                return Err(Error::ApiError {
+                    status_code: None,
+                    details: format!(
+                        "{}: {}",
+                        header(":error-code").unwrap_or("error"),
+                        header(":error-message").unwrap_or_default()
+                    ),
+                });
+            }
+        }
+
+        StreamEvent::parse(header(":event-type").unwrap_or_default(), &self.payload)
+    }
+}
+
+/// Removes every complete frame from `buffer` and converts it to a stream event. A
+/// trailing partial frame is kept for the next chunk.
+pub(crate) fn drain_frames(buffer: &mut Vec<u8>) -> Vec<Result<StreamEvent>> {
+    let mut events = Vec::new();
+    loop {
+        match decode_frame(buffer) {
+            Ok(Some((frame, len))) => {
+                buffer.drain(..len);
+                events.push(frame.into_event());
+            }
+            Ok(None) => break,
+            Err(e) => {
+                // The stream cannot be resynchronized after a corrupt frame
+                buffer.clear();
+                events.push(Err(e));
+                break;
+            }
+        }
+    }
+    events
+}
+
+/// Decodes the frame at the start of `bytes`, returning it with its length, or `None`
+/// if the frame is incomplete.
+pub(crate) fn decode_frame(bytes: &[u8]) -> Result<Option<(Frame, usize)>> {
+    if bytes.len() < PRELUDE_LEN {
+        return Ok(None);
+    }
+    let total_len = read_u32(&bytes[0..4]) as usize;
+    let headers_len = read_u32(&bytes[4..8]) as usize;
+    if read_u32(&bytes[8..12]) != crc32(&bytes[..8]) {
+        return Err(invalid("prelude checksum mismatch"));
+    }
+    if total_len < PRELUDE_LEN + headers_len + CRC_LEN {
+        return Err(invalid("invalid message length"));
+    }
+    if bytes.len() < total_len {
+        return Ok(None);
+    }
+
+    let message = &bytes[..total_len];
+    let (body, checksum) = message.split_at(total_len - CRC_LEN);
+    if read_u32(checksum) != crc32(body) {
+        return Err(invalid("message checksum mismatch"));
+    }
+
+    let headers = decode_headers(&body[PRELUDE_LEN..PRELUDE_LEN + headers_len])?;
+    let payload = body[PRELUDE_LEN + headers_len..].to_vec();
+    Ok(Some((Frame { headers, payload }, total_len)))
+}
+
+fn decode_headers(mut bytes: &[u8]) -> Result<HashMap<String, String>> {
+    let mut headers = HashMap::new();
+    while !bytes.is_empty() {
+        let name_len = usize::from(bytes[0]);
+        let name = take(&mut bytes, 1 + name_len)?[1..].to_vec();
+        let value_type = take(&mut bytes, 1)?[0];
+
+        let value = match value_type {
+            // bool true, bool false
+            0 | 1 => None,
+            2 => take(&mut bytes, 1).map(|_| None)?,
+            3 => take(&mut bytes, 2).map(|_| None)?,
+            4 => take(&mut bytes, 4).map(|_| None)?,
+            // long, timestamp
+            5 | 8 => take(&mut bytes, 8).map(|_| None)?,
+            // byte array, string
+            6 | 7 => {
+                let len = take(&mut bytes, 2)?;
+                let len = usize::from(u16::from_be_bytes([len[0], len[1]]));
+                let value = take(&mut bytes, len)?;
+                (value_type == 7).then(|| String::from_utf8_lossy(value).into_owned())
+            }
+            // uuid
+            9 => take(&mut bytes, 16).map(|_| None)?,
+            other => return Err(invalid(&format!("unknown header type {other}"))),
+        };
+
+        if let Some(value) = value {
+            headers.insert(String::from_utf8_lossy(&name).into_owned(), value);
+        }
+    }
+    Ok(headers)
+}
+
+/// Splits `len` bytes off the front of `bytes`.
+fn take<'a>(bytes: &mut &'a [u8], len: usize) -> Result<&'a [u8]> {
+    if bytes.len() < len {
+        return Err(invalid("truncated headers"));
+    }
+    let (head, tail) = bytes.split_at(len);
+    *bytes = tail;
+    Ok(head)
+}
+
+fn read_u32(bytes: &[u8]) -> u32 {
+    u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+}
+
+fn invalid(reason: &str) -> Error {
+    Error::ApiError {
+        status_code: None,
+        details: format!("Invalid event stream frame: {reason}"),
+    }
+}
+
+/// CRC-32 (IEEE 802.3).
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFFu32;
+    for byte in bytes {
+        crc ^= u32::from(*byte);
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Encodes a frame with string headers.
+    fn encode(headers: &[(&str, &str)], payload: &[u8]) -> Vec<u8> {
+        let mut header_bytes = Vec::new();
+        for (name, value) in headers {
+            header_bytes.push(name.len() as u8);
+            header_bytes.extend_from_slice(name.as_bytes());
+            header_bytes.push(7);
+            header_bytes.extend_from_slice(&(value.len() as u16).to_be_bytes());
+            header_bytes.extend_from_slice(value.as_bytes());
+        }
+        let total_len = PRELUDE_LEN + header_bytes.len() + payload.len() + CRC_LEN;
+
+        let mut frame = Vec::new();
+        frame.extend_from_slice(&(total_len as u32).to_be_bytes());
+        frame.extend_from_slice(&(header_bytes.len() as u32).to_be_bytes());
+        frame.extend_from_slice(&crc32(&frame).to_be_bytes());
+        frame.extend_from_slice(&header_bytes);
+        frame.extend_from_slice(payload);
+        frame.extend_from_slice(&crc32(&frame).to_be_bytes());
+        frame
+    }
+
+    fn event(event_type: &str, payload: &str) -> Vec<u8> {
+        encode(
+            &[
+                (":event-type", event_type),
+                (":content-type", "application/json"),
+                (":message-type", "event"),
+            ],
+            payload.as_bytes(),
+        )
+    }
+
+    #[test]
+    fn test_crc32() {
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    }
+
+    #[test]
+    fn test_drain_frames_keeps_partial_frame() {
+        let first = event(
+            "contentBlockDelta",
+            r#"{"contentBlockIndex":0,"delta":{"text":"Hel"}}"#,
+        );
+        let second = event(
+            "contentBlockDelta",
+            r#"{"contentBlockIndex":0,"delta":{"text":"lo"}}"#,
+        );
+
+        let mut buffer = first.clone();
+        buffer.extend_from_slice(&second[..10]);
+        let events = drain_frames(&mut buffer);
+        assert_eq!(events.len(), 1);
+        assert!(matches!(
+            &events[0],
+            Ok(StreamEvent::ContentBlockDelta { delta, .. }) if delta.text.as_deref() == Some("Hel")
+        ));
+
+        buffer.extend_from_slice(&second[10..]);
+        let events = drain_frames(&mut buffer);
+        assert_eq!(events.len(), 1);
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn test_exception_frame_is_an_error() {
+        let mut buffer = encode(
+            &[
+                (":exception-type", "throttlingException"),
+                (":message-type", "exception"),
+            ],
+            br#"{"message":"Too many requests"}"#,
+        );
+        let events = drain_frames(&mut buffer);
+        assert!(matches!(
+            &events[0],
+            Err(Error::ApiError { details, .. }) if details == "throttlingException: Too many requests"
+        ));
+    }
+
+    #[test]
+    fn test_corrupt_frame_is_an_error() {
+        let mut buffer = event("messageStart", r#"{"role":"assistant"}"#);
+        let last = buffer.len() - 1;
+        buffer[last] ^= 0xFF;
+        let events = drain_frames(&mut buffer);
+        assert_eq!(events.len(), 1);
+        assert!(events[0].is_err());
+        assert!(buffer.is_empty());
+    }
+}
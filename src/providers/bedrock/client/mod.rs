@@ -0,0 +1,256 @@
+//! Client implementation for the Bedrock provider.
+//!
+//! Requests are signed with AWS Signature Version 4 rather than a bearer key, and
+//! `ConverseStream` responses use the binary `application/vnd.amazon.eventstream`
+//! framing rather than server-sent events, so streaming goes through
+//! [`Bedrock::send_and_stream_events`] instead of the SSE-based `send_and_stream`.
+use crate::core::client::{LanguageModelClient, RetryPolicy, Transport, response_error};
+use crate::core::runtime::{SystemTime, assume_send};
+use crate::core::utils::join_url;
+use crate::error::{Error, Result};
+use crate::providers::bedrock::{Bedrock, ModelName};
+use derive_builder::Builder;
+use futures::{Stream, StreamExt};
+use reqwest::header::{ACCEPT, CONTENT_TYPE};
+use reqwest_eventsource::Event;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::pin::Pin;
+
+pub(crate) mod event_stream;
+pub(crate) mod sigv4;
+pub(crate) mod types;
+
+/// The service name requests to the Bedrock runtime are signed for.
+const SIGNING_SERVICE: &str = "bedrock";
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Builder)]
+#[builder(setter(into), build_fn(error = "Error"))]
+pub(crate) struct BedrockOptions {
+    pub(crate) model: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub(crate) request: Option<types::ConverseRequest>,
+    /// Whether the request goes to `ConverseStream` rather than `Converse`.
+    #[builder(default)]
+    pub(crate) stream: bool,
+}
+
+impl BedrockOptions {
+    pub(crate) fn builder() -> BedrockOptionsBuilder {
+        BedrockOptionsBuilder::default()
+    }
+}
+
+/// A stream of decoded `ConverseStream` events.
+pub(crate) type StreamEventStream = Pin<Box<dyn Stream<Item = Result<types::StreamEvent>> + Send>>;
+
+impl<M: ModelName> Bedrock<M> {
+    fn request_body(&self) -> Vec<u8> {
+        self.lm_options
+            .request
+            .as_ref()
+            .and_then(|request| serde_json::to_vec(request).ok())
+            .unwrap_or_else(|| b"{}".to_vec())
+    }
+
+    /// Sends the `ConverseStream` request and decodes the event stream frames as they
+    /// arrive.
+    pub(crate) async fn send_and_stream_events(&self) -> Result<StreamEventStream> {
+        let url = join_url(self.settings.endpoint(), &LanguageModelClient::path(self))?;
+        let transport = Transport::new(LanguageModelClient::transport(self));
+        let request = transport
+            .client
+            .request(LanguageModelClient::method(self), url)
+            .headers(LanguageModelClient::headers(self))
+            .body(self.request_body());
+        // A rejected response is held while its body is read, and is not `Send` on wasm32
+        let response = assume_send(async {
+            let response = transport
+                .send(request)
+                .await?
+                .map_err(|e| Error::ApiError {
+                    status_code: e.status(),
+                    details: e.to_string(),
+                })?;
+            if !response.status().is_success() {
+                return Err(response_error(response).await);
+            }
+            Ok(response)
+        })
+        .await?;
+
+        let state = (
+            assume_send(response.bytes_stream()),
+            Vec::new(),
+            VecDeque::new(),
+            false,
+        );
+        let stream = futures::stream::unfold(
+            state,
+            |(mut bytes, mut buffer, mut pending, mut finished)| async move {
+                loop {
+                    if let Some(item) = pending.pop_front() {
+                        let item: Result<types::StreamEvent> = item;
+                        // Stop after the first error
+                        if item.is_err() {
+                            pending.clear();
+                            finished = true;
+                        }
+                        return Some((item, (bytes, buffer, pending, finished)));
+                    }
+                    if finished {
+                        return None;
+                    }
+                    match bytes.next().await {
+                        Some(Ok(chunk)) => {
+                            buffer.extend_from_slice(&chunk);
+                            pending.extend(event_stream::drain_frames(&mut buffer));
+                        }
+                        Some(Err(e)) => {
+                            finished = true;
+                            pending.push_back(Err(Error::ApiError {
+                                status_code: e.status(),
+                                details: format!("Event stream error: {e}"),
+                            }));
+                        }
+                        None => {
+                            finished = true;
+                            if !buffer.is_empty() {
+                                pending.push_back(Err(Error::ApiError {
+                                    status_code: None,
+                                    details: "Event stream ended with a partial frame".to_string(),
+                                }));
+                            }
+                        }
+                    }
+                }
+            },
+        );
+
+        Ok(Box::pin(stream))
+    }
+}
+
+impl<M: ModelName> LanguageModelClient for Bedrock<M> {
+    type Response = types::ConverseResponse;
+    type StreamEvent = types::StreamEvent;
+
+    fn path(&self) -> String {
+        let operation = if self.lm_options.stream {
+            "converse-stream"
+        } else {
+            "converse"
+        };
+        // Model IDs and inference profile ARNs contain `:` and `/`
+        format!(
+            "model/{}/{operation}",
+            sigv4::uri_encode(&self.lm_options.model)
+        )
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    /// Returns the headers signed for the current request body.
+    fn headers(&self) -> reqwest::header::HeaderMap {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(CONTENT_TYPE, "application/json".parse().unwrap());
+        if !self.lm_options.stream {
+            headers.insert(ACCEPT, "application/json".parse().unwrap());
+        }
+
+        let Ok(url) = join_url(self.settings.endpoint(), &LanguageModelClient::path(self)) else {
+            return headers;
+        };
+        let payload = self.request_body();
+        sigv4::sign(
+            &sigv4::SigningRequest {
+                method: "POST",
+                url: &url,
+                payload: &payload,
+                region: &self.settings.region,
+                service: SIGNING_SERVICE,
+                time: SystemTime::now(),
+            },
+            &self.settings.credentials,
+            &mut headers,
+        );
+        headers
+    }
+
+    fn query_params(&self) -> Vec<(&str, &str)> {
+        Vec::new()
+    }
+
+    fn retry_policy(&self) -> RetryPolicy {
+        self.settings.retry_policy.clone()
+    }
+
+    fn transport(&self) -> crate::core::client::TransportSettings {
+        self.settings.transport.clone()
+    }
+
+    fn body(&self) -> reqwest::Body {
+        reqwest::Body::from(self.request_body())
+    }
+
+    /// Bedrock does not stream over SSE, see [`Bedrock::send_and_stream_events`].
+    fn parse_stream_sse(
+        event: std::result::Result<Event, reqwest_eventsource::Error>,
+    ) -> Result<Self::StreamEvent> {
+        match event {
+            Ok(_) => Ok(types::StreamEvent::NotSupported),
+            Err(e) => Err(Error::ApiError {
+                status_code: None,
+                details: e.to_string(),
+            }),
+        }
+    }
+
+    fn end_stream(event: &Self::StreamEvent) -> bool {
+        matches!(event, types::StreamEvent::Metadata { .. })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::DynamicModel;
+    use crate::providers::bedrock::credentials::BedrockCredentials;
+    use reqwest::header::AUTHORIZATION;
+
+    #[test]
+    fn test_path_encodes_model_id() {
+        let mut bedrock =
+            Bedrock::<DynamicModel>::model_name("anthropic.claude-3-haiku-20240307-v1:0");
+        assert_eq!(
+            LanguageModelClient::path(&bedrock),
+            "model/anthropic.claude-3-haiku-20240307-v1%3A0/converse"
+        );
+
+        bedrock.lm_options.stream = true;
+        assert_eq!(
+            LanguageModelClient::path(&bedrock),
+            "model/anthropic.claude-3-haiku-20240307-v1%3A0/converse-stream"
+        );
+    }
+
+    #[test]
+    fn test_headers_are_signed() {
+        let bedrock = Bedrock::<DynamicModel>::builder()
+            .model_name("amazon.nova-micro-v1:0")
+            .region("eu-west-1")
+            .credentials(BedrockCredentials::new("AKID", "secret"))
+            .build()
+            .unwrap();
+        let headers = LanguageModelClient::headers(&bedrock);
+
+        assert_eq!(headers["host"], "bedrock-runtime.eu-west-1.amazonaws.com");
+        let authorization = headers[AUTHORIZATION].to_str().unwrap();
+        assert!(authorization.starts_with("AWS4-HMAC-SHA256 Credential=AKID/"));
+        assert!(authorization.contains("/eu-west-1/bedrock/aws4_request"));
+        assert!(authorization.contains("SignedHeaders=accept;content-type;host;x-amz-date,"));
+    }
+}
@@ -0,0 +1,289 @@
+//! AWS Signature Version 4 request signing.
+//!
+//! See <https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_sigv.html>.
+
+use crate::core::runtime::{SystemTime, UNIX_EPOCH};
+use crate::providers::bedrock::credentials::BedrockCredentials;
+use reqwest::header::{AUTHORIZATION, HOST, HeaderMap, HeaderValue};
+use sha2::{Digest, Sha256};
+
+const ALGORITHM: &str = "AWS4-HMAC-SHA256";
+
+/// The request to sign.
+pub(crate) struct SigningRequest<'a> {
+    pub(crate) method: &'a str,
+    pub(crate) url: &'a reqwest::Url,
+    pub(crate) payload: &'a [u8],
+    pub(crate) region: &'a str,
+    pub(crate) service: &'a str,
+    pub(crate) time: SystemTime,
+}
+
+/// Signs a request, adding the `host`, `x-amz-date`, `x-amz-security-token` and
+/// `authorization` headers. Every header already in `headers` is signed.
+pub(crate) fn sign(
+    request: &SigningRequest<'_>,
+    credentials: &BedrockCredentials,
+    headers: &mut HeaderMap,
+) {
+    let (date, date_time) = format_time(request.time);
+
+    let host = match request.url.port() {
+        Some(port) => format!("{}:{port}", request.url.host_str().unwrap_or_default()),
+        None => request.url.host_str().unwrap_or_default().to_string(),
+    };
+    if let Ok(value) = HeaderValue::from_str(&host) {
+        headers.insert(HOST, value);
+    }
+    if let Ok(value) = HeaderValue::from_str(&date_time) {
+        headers.insert("x-amz-date", value);
+    }
+    if let Some(token) = &credentials.session_token
+        && let Ok(value) = HeaderValue::from_str(token)
+    {
+        headers.insert("x-amz-security-token", value);
+    }
+
+    // Header names are lowercase in a `HeaderMap`
+    let mut signed: Vec<(&str, String)> = headers
+        .iter()
+        .map(|(name, value)| {
+            let value = String::from_utf8_lossy(value.as_bytes());
+            (
+                name.as_str(),
+                value.split_whitespace().collect::<Vec<_>>().join(" "),
+            )
+        })
+        .collect();
+    signed.sort();
+    let signed_headers = signed
+        .iter()
+        .map(|(name, _)| *name)
+        .collect::<Vec<_>>()
+        .join(";");
+    let canonical_headers: String = signed
+        .iter()
+        .map(|(name, value)| format!("{name}:{value}\n"))
+        .collect();
+
+    let canonical_request = format!(
+        "{}\n{}\n{}\n{}\n{}\n{}",
+        request.method,
+        canonical_uri(request.url),
+        canonical_query(request.url),
+        canonical_headers,
+        signed_headers,
+        hex(&Sha256::digest(request.payload)),
+    );
+
+    let scope = format!("{date}/{}/{}/aws4_request", request.region, request.service);
+    let string_to_sign = format!(
+        "{ALGORITHM}\n{date_time}\n{scope}\n{}",
+        hex(&Sha256::digest(canonical_request.as_bytes()))
+    );
+
+    let key = [
+        date.as_str(),
+        request.region,
+        request.service,
+        "aws4_request",
+    ]
+    .iter()
+    .fold(
+        format!("AWS4{}", credentials.secret_access_key).into_bytes(),
+        |key, part| hmac_sha256(&key, part.as_bytes()).to_vec(),
+    );
+    let signature = hex(&hmac_sha256(&key, string_to_sign.as_bytes()));
+
+    let authorization = format!(
+        "{ALGORITHM} Credential={}/{scope}, SignedHeaders={signed_headers}, Signature={signature}",
+        credentials.access_key_id
+    );
+    if let Ok(value) = HeaderValue::from_str(&authorization) {
+        headers.insert(AUTHORIZATION, value);
+    }
+}
+
+/// Percent-encodes every byte except the unreserved characters.
+pub(crate) fn uri_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{b:02X}"),
+        })
+        .collect()
+}
+
+/// Returns the canonical URI. Services other than S3 encode each path segment twice,
+/// so the already encoded segments of the URL are encoded once more.
+fn canonical_uri(url: &reqwest::Url) -> String {
+    let path = url.path();
+    if path.is_empty() {
+        return "/".to_string();
+    }
+    path.split('/')
+        .map(uri_encode)
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn canonical_query(url: &reqwest::Url) -> String {
+    let mut pairs: Vec<(String, String)> = url
+        .query_pairs()
+        .map(|(k, v)| (uri_encode(&k), uri_encode(&v)))
+        .collect();
+    pairs.sort();
+    pairs
+        .iter()
+        .map(|(k, v)| format!("{k}={v}"))
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// Formats a time as the `YYYYMMDD` date and `YYYYMMDDTHHMMSSZ` timestamp in UTC.
+fn format_time(time: SystemTime) -> (String, String) {
+    let secs = time
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let days = (secs / 86_400) as i64;
+    let rem = secs % 86_400;
+
+    // Civil date from days since the epoch, see http://howardhinnant.github.io/date_algorithms.html
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    let date = format!("{year:04}{month:02}{day:02}");
+    let date_time = format!(
+        "{date}T{:02}{:02}{:02}Z",
+        rem / 3600,
+        rem % 3600 / 60,
+        rem % 60
+    );
+    (date, date_time)
+}
+
+/// HMAC-SHA256, see RFC 2104.
+fn hmac_sha256(key: &[u8], message: &[u8]) -> [u8; 32] {
+    const BLOCK_SIZE: usize = 64;
+
+    let mut block = [0u8; BLOCK_SIZE];
+    if key.len() > BLOCK_SIZE {
+        block[..32].copy_from_slice(&Sha256::digest(key));
+    } else {
+        block[..key.len()].copy_from_slice(key);
+    }
+
+    let mut inner = Sha256::new();
+    inner.update(block.map(|b| b ^ 0x36));
+    inner.update(message);
+
+    let mut outer = Sha256::new();
+    outer.update(block.map(|b| b ^ 0x5c));
+    outer.update(inner.finalize());
+    outer.finalize().into()
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_hmac_sha256() {
+        // RFC 4231, test case 2
+        assert_eq!(
+            hex(&hmac_sha256(b"Jefe", b"what do ya want for nothing?")),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+
+    #[test]
+    fn test_format_time() {
+        let time = UNIX_EPOCH + Duration::from_secs(1_440_938_160);
+        assert_eq!(
+            format_time(time),
+            ("20150830".to_string(), "20150830T123600Z".to_string())
+        );
+
+        let leap_day = UNIX_EPOCH + Duration::from_secs(951_782_400);
+        assert_eq!(format_time(leap_day).0, "20000229");
+    }
+
+    #[test]
+    fn test_sign_get_vanilla() {
+        // The `get-vanilla` case of the AWS Signature Version 4 test suite
+        let url = reqwest::Url::parse("https://example.amazonaws.com/").unwrap();
+        let request = SigningRequest {
+            method: "GET",
+            url: &url,
+            payload: b"",
+            region: "us-east-1",
+            service: "service",
+            time: UNIX_EPOCH + Duration::from_secs(1_440_938_160),
+        };
+        let credentials =
+            BedrockCredentials::new("AKIDEXAMPLE", "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY");
+        let mut headers = HeaderMap::new();
+
+        sign(&request, &credentials, &mut headers);
+
+        assert_eq!(
+            headers[AUTHORIZATION],
+            "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/service/aws4_request, \
+             SignedHeaders=host;x-amz-date, \
+             Signature=5fa00fa31553b73ebf1942676e86291e8372ff2a2260956d9b8aae1d763fbf31"
+        );
+    }
+
+    #[test]
+    fn test_canonical_uri_double_encodes() {
+        let url = reqwest::Url::parse(
+            "https://bedrock-runtime.us-east-1.amazonaws.com/model/anthropic.claude-v2%3A1/converse",
+        )
+        .unwrap();
+        assert_eq!(
+            canonical_uri(&url),
+            "/model/anthropic.claude-v2%253A1/converse"
+        );
+    }
+
+    #[test]
+    fn test_session_token_is_signed() {
+        let url = reqwest::Url::parse("https://example.amazonaws.com/").unwrap();
+        let request = SigningRequest {
+            method: "POST",
+            url: &url,
+            payload: b"{}",
+            region: "us-east-1",
+            service: "bedrock",
+            time: SystemTime::now(),
+        };
+        let credentials = BedrockCredentials::new("AKID", "secret").session_token("token");
+        let mut headers = HeaderMap::new();
+
+        sign(&request, &credentials, &mut headers);
+
+        assert_eq!(headers["x-amz-security-token"], "token");
+        assert!(
+            headers[AUTHORIZATION]
+                .to_str()
+                .unwrap()
+                .contains("SignedHeaders=host;x-amz-date;x-amz-security-token,")
+        );
+    }
+}
@@ -0,0 +1,310 @@
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+// ============================================================================
+// Section: converse
+// ============================================================================
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ConverseRequest {
+    pub(crate) messages: Vec<Message>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) system: Vec<SystemContentBlock>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) inference_config: Option<InferenceConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) tool_config: Option<ToolConfig>,
+    #[serde(default, skip_serializing_if = "Map::is_empty")]
+    pub(crate) additional_model_request_fields: Map<String, Value>,
+    /// Provider-specific fields merged into the request body.
+    #[serde(flatten, skip_serializing_if = "Map::is_empty")]
+    pub(crate) provider_options: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum Role {
+    #[default]
+    User,
+    Assistant,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct Message {
+    pub(crate) role: Role,
+    #[serde(default)]
+    pub(crate) content: Vec<ContentBlock>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct SystemContentBlock {
+    pub(crate) text: String,
+}
+
+/// A content block of a message. Bedrock encodes the block type as the name of its
+/// single field, e.g. `{"text": "..."}` or `{"toolUse": {...}}`. Block types the
+/// provider does not handle deserialize to a block with every field unset.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ContentBlock {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) text: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) tool_use: Option<ToolUseBlock>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) tool_result: Option<ToolResultBlock>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) reasoning_content: Option<ReasoningContentBlock>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ToolUseBlock {
+    pub(crate) tool_use_id: String,
+    pub(crate) name: String,
+    #[serde(default)]
+    pub(crate) input: Value,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ToolResultBlock {
+    pub(crate) tool_use_id: String,
+    pub(crate) content: Vec<ToolResultContent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) status: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum ToolResultContent {
+    Json(Value),
+    Text(String),
+    Image(ImageBlock),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct ImageBlock {
+    /// The image format, e.g. `png` or `jpeg`.
+    pub(crate) format: String,
+    pub(crate) source: ImageSource,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct ImageSource {
+    /// The base64 encoded image data.
+    pub(crate) bytes: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ReasoningContentBlock {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) reasoning_text: Option<ReasoningText>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) redacted_content: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct ReasoningText {
+    pub(crate) text: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) signature: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct InferenceConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) max_tokens: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) top_p: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) stop_sequences: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ToolConfig {
+    pub(crate) tools: Vec<Tool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) tool_choice: Option<ToolChoice>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Tool {
+    pub(crate) tool_spec: ToolSpec,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ToolSpec {
+    pub(crate) name: String,
+    pub(crate) description: String,
+    pub(crate) input_schema: ToolInputSchema,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct ToolInputSchema {
+    pub(crate) json: Value,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum ToolChoice {
+    Auto {},
+    Any {},
+    Tool { name: String },
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ConverseResponse {
+    #[serde(default)]
+    pub(crate) output: ConverseOutput,
+    #[serde(default)]
+    pub(crate) stop_reason: Option<String>,
+    #[serde(default)]
+    pub(crate) usage: Option<TokenUsage>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct ConverseOutput {
+    #[serde(default)]
+    pub(crate) message: Option<Message>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct TokenUsage {
+    #[serde(default)]
+    pub(crate) input_tokens: usize,
+    #[serde(default)]
+    pub(crate) output_tokens: usize,
+    #[serde(default)]
+    pub(crate) cache_read_input_tokens: Option<usize>,
+    #[serde(default)]
+    pub(crate) cache_write_input_tokens: Option<usize>,
+}
+
+// ============================================================================
+// Section: streaming
+// ============================================================================
+
+/// An event of a `ConverseStream` response. The event type is carried by the
+/// `:event-type` header of the event stream frame, see [`StreamEvent::parse`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum StreamEvent {
+    #[serde(rename_all = "camelCase")]
+    MessageStart {
+        #[serde(default)]
+        role: Role,
+    },
+    #[serde(rename_all = "camelCase")]
+    ContentBlockStart {
+        #[serde(default)]
+        content_block_index: usize,
+        #[serde(default)]
+        start: ContentBlockStart,
+    },
+    #[serde(rename_all = "camelCase")]
+    ContentBlockDelta {
+        #[serde(default)]
+        content_block_index: usize,
+        #[serde(default)]
+        delta: ContentBlockDelta,
+    },
+    #[serde(rename_all = "camelCase")]
+    ContentBlockStop {
+        #[serde(default)]
+        content_block_index: usize,
+    },
+    #[serde(rename_all = "camelCase")]
+    MessageStop {
+        #[serde(default)]
+        stop_reason: Option<String>,
+    },
+    Metadata {
+        #[serde(default)]
+        usage: Option<TokenUsage>,
+    },
+    NotSupported,
+}
+
+impl StreamEvent {
+    /// Parses the JSON payload of an event of the given type.
+    pub(crate) fn parse(event_type: &str, payload: &[u8]) -> Result<Self> {
+        if !matches!(
+            event_type,
+            "messageStart"
+                | "contentBlockStart"
+                | "contentBlockDelta"
+                | "contentBlockStop"
+                | "messageStop"
+                | "metadata"
+        ) {
+            return Ok(Self::NotSupported);
+        }
+
+        let payload: Value = serde_json::from_slice(payload).map_err(|e| Error::ApiError {
+            status_code: None,
+            details: format!("Invalid JSON in event stream payload: {e}"),
+        })?;
+        let mut tagged = Map::new();
+        tagged.insert(event_type.to_string(), payload);
+
+        serde_json::from_value(Value::Object(tagged)).map_err(|e| Error::ApiError {
+            status_code: None,
+            details: format!("Invalid {event_type} event: {e}"),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ContentBlockStart {
+    #[serde(default)]
+    pub(crate) tool_use: Option<ToolUseStart>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ToolUseStart {
+    pub(crate) tool_use_id: String,
+    pub(crate) name: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ContentBlockDelta {
+    #[serde(default)]
+    pub(crate) text: Option<String>,
+    #[serde(default)]
+    pub(crate) tool_use: Option<ToolUseDelta>,
+    #[serde(default)]
+    pub(crate) reasoning_content: Option<ReasoningContentDelta>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct ToolUseDelta {
+    /// A fragment of the JSON encoded tool input.
+    #[serde(default)]
+    pub(crate) input: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ReasoningContentDelta {
+    #[serde(default)]
+    pub(crate) text: Option<String>,
+    #[serde(default)]
+    pub(crate) signature: Option<String>,
+    #[serde(default)]
+    pub(crate) redacted_content: Option<String>,
+}
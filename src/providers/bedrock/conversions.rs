@@ -0,0 +1,462 @@
+//! Conversions between types used by the Bedrock provider and the types used by the core library.
+use crate::core::language_model::{
+    FinishReason, LanguageModelOptions, LanguageModelResponseContentType, Usage,
+};
+use crate::core::messages::{Message, TaggedMessage};
+use crate::core::tools::{Tool, ToolCallInfo, ToolDetails, ToolResultInfo};
+use crate::extensions::Extensions;
+use crate::providers::bedrock::client::types::{
+    self, ContentBlock, ConverseRequest, ImageBlock, ImageSource, InferenceConfig,
+    ReasoningContentBlock, ReasoningText, Role, SystemContentBlock, ToolChoice, ToolConfig,
+    ToolInputSchema, ToolResultBlock, ToolResultContent, ToolSpec, ToolUseBlock,
+};
+use crate::providers::bedrock::extensions::BedrockReasoningMetadata;
+use serde_json::Value;
+
+/// Name of the tool the model is asked to call when structured output is requested.
+pub(crate) const STRUCTURED_OUTPUT_TOOL: &str = "json_response";
+
+/// Turns a call to the structured output tool back into a JSON text response.
+pub(crate) fn structured_output_content(
+    content: LanguageModelResponseContentType,
+) -> LanguageModelResponseContentType {
+    match content {
+        LanguageModelResponseContentType::ToolCall(ref call)
+            if call.tool.name == STRUCTURED_OUTPUT_TOOL =>
+        {
+            LanguageModelResponseContentType::Text(call.input.to_string())
+        }
+        other => other,
+    }
+}
+
+impl From<Tool> for types::Tool {
+    fn from(value: Tool) -> Self {
+        let mut json = value.input_schema.to_value();
+        if let Some(obj) = json.as_object_mut() {
+            obj.remove("$schema");
+        }
+
+        Self {
+            tool_spec: ToolSpec {
+                name: value.name,
+                description: value.description,
+                input_schema: ToolInputSchema { json },
+            },
+        }
+    }
+}
+
+impl From<LanguageModelOptions> for ConverseRequest {
+    fn from(options: LanguageModelOptions) -> Self {
+        let mut system = Vec::new();
+        if let Some(text) = options.system.filter(|s| !s.is_empty()) {
+            system.push(SystemContentBlock { text });
+        }
+
+        let mut messages = Vec::new();
+        for tagged in options.messages {
+            push_message(&mut messages, &mut system, tagged);
+        }
+
+        let mut tools: Vec<types::Tool> = options
+            .tools
+            .map(|t| {
+                let tools_list = t.tools.lock().unwrap_or_else(|p| p.into_inner());
+                tools_list.iter().map(|tool| tool.clone().into()).collect()
+            })
+            .unwrap_or_default();
+
+        // Converse has no json schema response format, so structured output is
+        // requested by forcing a call to a tool whose input is the schema.
+        let mut tool_choice = None;
+        if let Some(schema) = options.schema {
+            let mut json = schema.to_value();
+            if let Some(obj) = json.as_object_mut() {
+                obj.remove("$schema");
+            }
+            tools.push(types::Tool {
+                tool_spec: ToolSpec {
+                    name: STRUCTURED_OUTPUT_TOOL.to_string(),
+                    description: "Respond with a JSON object that matches the input schema."
+                        .to_string(),
+                    input_schema: ToolInputSchema { json },
+                },
+            });
+            tool_choice = Some(ToolChoice::Tool {
+                name: STRUCTURED_OUTPUT_TOOL.to_string(),
+            });
+        }
+
+        let inference_config = InferenceConfig {
+            max_tokens: options.max_output_tokens,
+            temperature: options.temperature.map(|t| t as f32 / 100.0),
+            top_p: options.top_p.map(|t| t as f32 / 100.0),
+            stop_sequences: options.stop_sequences,
+        };
+        let has_inference_config = inference_config.max_tokens.is_some()
+            || inference_config.temperature.is_some()
+            || inference_config.top_p.is_some()
+            || inference_config.stop_sequences.is_some();
+
+        Self {
+            messages,
+            system,
+            inference_config: has_inference_config.then_some(inference_config),
+            tool_config: (!tools.is_empty()).then_some(ToolConfig { tools, tool_choice }),
+            additional_model_request_fields: Default::default(),
+            provider_options: options.provider_options,
+        }
+    }
+}
+
+/// Appends a conversation message. Converse expects user and assistant turns to
+/// alternate, so consecutive messages of the same role, such as several tool results,
+/// are merged. System messages are moved to the system prompt.
+fn push_message(
+    messages: &mut Vec<types::Message>,
+    system: &mut Vec<SystemContentBlock>,
+    tagged: TaggedMessage,
+) {
+    let (role, block) = match tagged.message {
+        Message::System(s) => {
+            system.push(SystemContentBlock { text: s.content });
+            return;
+        }
+        Message::Developer(d) => {
+            system.push(SystemContentBlock { text: d });
+            return;
+        }
+        Message::User(u) => (
+            Role::User,
+            ContentBlock {
+                text: Some(u.content),
+                ..Default::default()
+            },
+        ),
+        Message::Assistant(a) => {
+            let block = match a.content {
+                LanguageModelResponseContentType::Text(text) if text.is_empty() => return,
+                LanguageModelResponseContentType::Text(text) => ContentBlock {
+                    text: Some(text),
+                    ..Default::default()
+                },
+                LanguageModelResponseContentType::Reasoning {
+                    content,
+                    extensions,
+                } => {
+                    let metadata = extensions.get::<BedrockReasoningMetadata>().clone();
+                    ContentBlock {
+                        reasoning_content: Some(match metadata.redacted_content {
+                            Some(redacted) => ReasoningContentBlock {
+                                redacted_content: Some(redacted),
+                                ..Default::default()
+                            },
+                            None => ReasoningContentBlock {
+                                reasoning_text: Some(ReasoningText {
+                                    text: content,
+                                    signature: metadata.signature,
+                                }),
+                                ..Default::default()
+                            },
+                        }),
+                        ..Default::default()
+                    }
+                }
+                LanguageModelResponseContentType::ToolCall(tool_info) => ContentBlock {
+                    tool_use: Some(ToolUseBlock {
+                        tool_use_id: tool_info.tool.id,
+                        name: tool_info.tool.name,
+                        input: tool_info.input,
+                    }),
+                    ..Default::default()
+                },
+                LanguageModelResponseContentType::Source(_)
+                | LanguageModelResponseContentType::Image(_)
+                | LanguageModelResponseContentType::Refusal(_)
+                | LanguageModelResponseContentType::NotSupported(_) => return,
+            };
+            (Role::Assistant, block)
+        }
+        Message::Tool(tool_result) => (
+            Role::User,
+            ContentBlock {
+                tool_result: Some(tool_result.into()),
+                ..Default::default()
+            },
+        ),
+    };
+
+    match messages.last_mut() {
+        Some(last) if last.role == role => last.content.push(block),
+        _ => messages.push(types::Message {
+            role,
+            content: vec![block],
+        }),
+    }
+}
+
+impl From<ToolResultInfo> for ToolResultBlock {
+    fn from(tool_result: ToolResultInfo) -> Self {
+        let (mut content, status) = match tool_result.output {
+            // Bedrock only accepts JSON objects as `json` content
+            Ok(value @ Value::Object(_)) => (vec![ToolResultContent::Json(value)], None),
+            Ok(Value::String(text)) => (vec![ToolResultContent::Text(text)], None),
+            Ok(value) => (vec![ToolResultContent::Text(value.to_string())], None),
+            Err(e) => (
+                vec![ToolResultContent::Text(e.to_string())],
+                Some("error".to_string()),
+            ),
+        };
+        content.extend(tool_result.images.into_iter().map(|image| {
+            ToolResultContent::Image(ImageBlock {
+                format: image
+                    .media_type
+                    .strip_prefix("image/")
+                    .unwrap_or(&image.media_type)
+                    .to_string(),
+                source: ImageSource { bytes: image.data },
+            })
+        }));
+
+        Self {
+            tool_use_id: tool_result.tool.id,
+            content,
+            status,
+        }
+    }
+}
+
+/// Maps a Converse `stopReason`.
+pub(crate) fn finish_reason(reason: &str) -> FinishReason {
+    match reason {
+        "end_turn" | "stop_sequence" => FinishReason::Stop,
+        "max_tokens" => FinishReason::Length,
+        "tool_use" => FinishReason::ToolCalls,
+        "guardrail_intervened" | "content_filtered" => FinishReason::ContentFilter,
+        other => FinishReason::Other(other.to_string()),
+    }
+}
+
+/// Returns the refusal content for a response a guardrail or content filter blocked,
+/// with the stop reason as its message.
+pub(crate) fn refusal(reason: &str) -> Option<LanguageModelResponseContentType> {
+    matches!(reason, "guardrail_intervened" | "content_filtered")
+        .then(|| LanguageModelResponseContentType::Refusal(reason.to_string()))
+}
+
+impl From<types::TokenUsage> for Usage {
+    fn from(usage: types::TokenUsage) -> Self {
+        Self {
+            input_tokens: Some(usage.input_tokens),
+            output_tokens: Some(usage.output_tokens),
+            reasoning_tokens: None,
+            cached_tokens: crate::core::utils::sum_options(
+                usage.cache_read_input_tokens,
+                usage.cache_write_input_tokens,
+            ),
+        }
+    }
+}
+
+/// Converts reasoning text to reasoning content carrying its signature.
+pub(crate) fn reasoning(
+    text: String,
+    signature: Option<String>,
+    redacted_content: Option<String>,
+) -> LanguageModelResponseContentType {
+    let extensions = Extensions::default();
+    extensions.insert(BedrockReasoningMetadata {
+        signature,
+        redacted_content,
+    });
+
+    LanguageModelResponseContentType::Reasoning {
+        content: text,
+        extensions,
+    }
+}
+
+/// Converts a tool use block to a tool call.
+pub(crate) fn tool_call(tool_use: ToolUseBlock) -> LanguageModelResponseContentType {
+    LanguageModelResponseContentType::ToolCall(ToolCallInfo {
+        input: tool_use.input,
+        tool: ToolDetails {
+            id: tool_use.tool_use_id,
+            name: tool_use.name,
+        },
+        extensions: Extensions::default(),
+    })
+}
+
+/// Converts the content blocks of a response message to response contents.
+pub(crate) fn message_contents(message: types::Message) -> Vec<LanguageModelResponseContentType> {
+    message
+        .content
+        .into_iter()
+        .filter_map(|block| {
+            if let Some(text) = block.text {
+                Some(LanguageModelResponseContentType::Text(text))
+            } else if let Some(tool_use) = block.tool_use {
+                Some(tool_call(tool_use))
+            } else {
+                let reasoning_content = block.reasoning_content?;
+                match reasoning_content.reasoning_text {
+                    Some(text) => Some(reasoning(text.text, text.signature, None)),
+                    None => Some(reasoning(
+                        String::new(),
+                        None,
+                        reasoning_content.redacted_content,
+                    )),
+                }
+            }
+        })
+        .map(structured_output_content)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::messages::AssistantMessage;
+    use schemars::{JsonSchema, schema_for};
+    use serde_json::json;
+
+    #[derive(JsonSchema)]
+    #[allow(dead_code)]
+    struct City {
+        name: String,
+    }
+
+    #[test]
+    fn test_request_maps_options() {
+        let options = LanguageModelOptions {
+            system: Some("Be brief".to_string()),
+            temperature: Some(50),
+            max_output_tokens: Some(512),
+            messages: vec![
+                TaggedMessage::new(0, Message::Developer("Answer in French".to_string())),
+                TaggedMessage::new(0, Message::User("hi".into())),
+            ],
+            ..Default::default()
+        };
+        let request: ConverseRequest = options.into();
+        let value = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(
+            value,
+            json!({
+                "messages": [{ "role": "user", "content": [{ "text": "hi" }] }],
+                "system": [{ "text": "Be brief" }, { "text": "Answer in French" }],
+                "inferenceConfig": { "maxTokens": 512, "temperature": 0.5 }
+            })
+        );
+    }
+
+    #[test]
+    fn test_tool_turns_are_merged() {
+        let mut first = ToolCallInfo::new("search");
+        first.id("call_1");
+        first.input(json!({ "q": "rust" }));
+        let mut second = ToolCallInfo::new("search");
+        second.id("call_2");
+        second.input(json!({ "q": "go" }));
+        let mut first_result = ToolResultInfo::new("search");
+        first_result.id("call_1");
+        first_result.output(json!({ "hits": 3 }));
+        let mut second_result = ToolResultInfo::new("search");
+        second_result.id("call_2");
+        second_result.output(json!(0));
+
+        let assistant = |content| {
+            TaggedMessage::new(1, Message::Assistant(AssistantMessage::new(content, None)))
+        };
+        let options = LanguageModelOptions {
+            messages: vec![
+                TaggedMessage::new(0, Message::User("compare".into())),
+                assistant(reasoning(
+                    "Search both".to_string(),
+                    Some("sig".to_string()),
+                    None,
+                )),
+                assistant(LanguageModelResponseContentType::ToolCall(first)),
+                assistant(LanguageModelResponseContentType::ToolCall(second)),
+                TaggedMessage::new(1, Message::Tool(first_result)),
+                TaggedMessage::new(1, Message::Tool(second_result)),
+            ],
+            ..Default::default()
+        };
+        let request: ConverseRequest = options.into();
+
+        assert_eq!(request.messages.len(), 3);
+        let assistant = &request.messages[1];
+        assert_eq!(assistant.content.len(), 3);
+        assert_eq!(
+            assistant.content[0]
+                .reasoning_content
+                .as_ref()
+                .and_then(|r| r.reasoning_text.as_ref())
+                .and_then(|t| t.signature.as_deref()),
+            Some("sig")
+        );
+
+        let results = serde_json::to_value(&request.messages[2]).unwrap();
+        assert_eq!(
+            results["content"],
+            json!([
+                { "toolResult": { "toolUseId": "call_1", "content": [{ "json": { "hits": 3 } }] } },
+                { "toolResult": { "toolUseId": "call_2", "content": [{ "text": "0" }] } }
+            ])
+        );
+    }
+
+    #[test]
+    fn test_schema_forces_structured_output_tool() {
+        let options = LanguageModelOptions {
+            schema: Some(schema_for!(City)),
+            ..Default::default()
+        };
+        let request: ConverseRequest = options.into();
+
+        let tool_config = request.tool_config.unwrap();
+        assert_eq!(tool_config.tools[0].tool_spec.name, STRUCTURED_OUTPUT_TOOL);
+        assert_eq!(
+            tool_config.tool_choice,
+            Some(ToolChoice::Tool {
+                name: STRUCTURED_OUTPUT_TOOL.to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_message_contents() {
+        let message: types::Message = serde_json::from_value(json!({
+            "role": "assistant",
+            "content": [
+                { "reasoningContent": { "reasoningText": { "text": "Think", "signature": "sig" } } },
+                { "text": "Let me look" },
+                { "toolUse": { "toolUseId": "tooluse_1", "name": "search", "input": { "q": "rust" } } },
+                { "image": { "format": "png", "source": { "bytes": "" } } }
+            ]
+        }))
+        .unwrap();
+        let contents = message_contents(message);
+
+        assert_eq!(contents.len(), 3);
+        let LanguageModelResponseContentType::Reasoning { extensions, .. } = &contents[0] else {
+            panic!("expected reasoning");
+        };
+        assert_eq!(
+            extensions
+                .get::<BedrockReasoningMetadata>()
+                .signature
+                .as_deref(),
+            Some("sig")
+        );
+        let LanguageModelResponseContentType::ToolCall(info) = &contents[2] else {
+            panic!("expected a tool call");
+        };
+        assert_eq!(info.tool.id, "tooluse_1");
+        assert_eq!(info.input, json!({ "q": "rust" }));
+    }
+}
@@ -0,0 +1,202 @@
+//! AWS credentials and region resolution for the Bedrock provider.
+//!
+//! Credentials are read from the standard `AWS_ACCESS_KEY_ID`, `AWS_SECRET_ACCESS_KEY`
+//! and `AWS_SESSION_TOKEN` environment variables, falling back to a named profile in
+//! the shared credentials file (`~/.aws/credentials`, or `AWS_SHARED_CREDENTIALS_FILE`).
+
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// The region used when none is configured.
+pub(crate) const DEFAULT_REGION: &str = "us-east-1";
+
+/// AWS credentials used to sign Bedrock requests.
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BedrockCredentials {
+    /// The access key ID.
+    pub access_key_id: String,
+    /// The secret access key.
+    pub secret_access_key: String,
+    /// The session token of temporary credentials, e.g. from an assumed role.
+    pub session_token: Option<String>,
+}
+
+impl std::fmt::Debug for BedrockCredentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BedrockCredentials")
+            .field("access_key_id", &self.access_key_id)
+            .field("secret_access_key", &"<redacted>")
+            .field(
+                "session_token",
+                &self.session_token.as_ref().map(|_| "<redacted>"),
+            )
+            .finish()
+    }
+}
+
+impl BedrockCredentials {
+    /// Creates long-term credentials from an access key pair.
+    pub fn new(access_key_id: impl Into<String>, secret_access_key: impl Into<String>) -> Self {
+        Self {
+            access_key_id: access_key_id.into(),
+            secret_access_key: secret_access_key.into(),
+            session_token: None,
+        }
+    }
+
+    /// Sets the session token of temporary credentials.
+    pub fn session_token(mut self, session_token: impl Into<String>) -> Self {
+        self.session_token = Some(session_token.into());
+        self
+    }
+
+    /// Reads credentials from the `AWS_ACCESS_KEY_ID`, `AWS_SECRET_ACCESS_KEY`, and
+    /// `AWS_SESSION_TOKEN` environment variables.
+    pub fn from_env() -> Option<Self> {
+        let access_key_id = std::env::var("AWS_ACCESS_KEY_ID").ok()?;
+        let secret_access_key = std::env::var("AWS_SECRET_ACCESS_KEY").ok()?;
+        Some(Self {
+            access_key_id,
+            secret_access_key,
+            session_token: std::env::var("AWS_SESSION_TOKEN").ok(),
+        })
+    }
+
+    /// Reads the credentials of a profile from the shared credentials file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::InvalidInput` if the file cannot be read or the profile has
+    /// no access key pair.
+    pub fn from_profile(profile: &str) -> Result<Self> {
+        let path = shared_file("AWS_SHARED_CREDENTIALS_FILE", "credentials")
+            .ok_or_else(|| Error::InvalidInput("Cannot locate the home directory".into()))?;
+        let contents = std::fs::read_to_string(&path)
+            .map_err(|e| Error::InvalidInput(format!("Failed to read {}: {e}", path.display())))?;
+
+        parse_profile(&contents, profile)
+            .and_then(|values| {
+                Some(Self {
+                    access_key_id: values.get("aws_access_key_id")?.clone(),
+                    secret_access_key: values.get("aws_secret_access_key")?.clone(),
+                    session_token: values.get("aws_session_token").cloned(),
+                })
+            })
+            .ok_or_else(|| {
+                Error::InvalidInput(format!(
+                    "Profile '{profile}' has no credentials in {}",
+                    path.display()
+                ))
+            })
+    }
+
+    /// Resolves credentials from the environment, then from the `AWS_PROFILE` profile
+    /// (or `default`).
+    pub(crate) fn resolve() -> Option<Self> {
+        Self::from_env().or_else(|| Self::from_profile(&default_profile()).ok())
+    }
+}
+
+/// Resolves the region from `AWS_REGION`, `AWS_DEFAULT_REGION`, or the region of the
+/// `AWS_PROFILE` profile (or `default`) in the shared config file.
+pub(crate) fn resolve_region() -> String {
+    std::env::var("AWS_REGION")
+        .or_else(|_| std::env::var("AWS_DEFAULT_REGION"))
+        .ok()
+        .or_else(|| profile_region(&default_profile()))
+        .unwrap_or_else(|| DEFAULT_REGION.to_string())
+}
+
+/// Reads the region of a profile from the shared config file.
+pub(crate) fn profile_region(profile: &str) -> Option<String> {
+    let path = shared_file("AWS_CONFIG_FILE", "config")?;
+    let contents = std::fs::read_to_string(path).ok()?;
+    // Named profiles are prefixed with "profile" in the config file
+    let section = match profile {
+        "default" => "default".to_string(),
+        name => format!("profile {name}"),
+    };
+    parse_profile(&contents, &section)?.remove("region")
+}
+
+fn default_profile() -> String {
+    std::env::var("AWS_PROFILE").unwrap_or_else(|_| "default".to_string())
+}
+
+/// Returns the path of a shared AWS file, overridden by the `env` variable.
+fn shared_file(env: &str, name: &str) -> Option<PathBuf> {
+    if let Ok(path) = std::env::var(env) {
+        return Some(PathBuf::from(path));
+    }
+    let home = std::env::var_os("HOME").or_else(|| std::env::var_os("USERPROFILE"))?;
+    Some(PathBuf::from(home).join(".aws").join(name))
+}
+
+/// Returns the key-value pairs of a section of an INI formatted AWS file.
+fn parse_profile(contents: &str, section: &str) -> Option<HashMap<String, String>> {
+    let mut values = None;
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+        if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            if values.is_some() {
+                break;
+            }
+            if name.trim() == section {
+                values = Some(HashMap::new());
+            }
+            continue;
+        }
+        if let Some(values) = values.as_mut()
+            && let Some((key, value)) = line.split_once('=')
+        {
+            values.insert(key.trim().to_string(), value.trim().to_string());
+        }
+    }
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_debug_redacts_secrets() {
+        let credentials = BedrockCredentials::new("AKID", "secret").session_token("token");
+
+        let formatted = format!("{credentials:?}");
+
+        assert!(formatted.contains("AKID"));
+        assert!(!formatted.contains("secret\""));
+        assert!(!formatted.contains("\"token\""));
+    }
+
+    #[test]
+    fn test_parse_profile() {
+        let contents = "\
+[default]
+aws_access_key_id = AKIDDEFAULT
+aws_secret_access_key = secret
+
+# a comment
+[profile work]
+aws_access_key_id=AKIDWORK
+aws_secret_access_key=work-secret
+aws_session_token = token
+region = eu-west-1
+";
+        let default = parse_profile(contents, "default").unwrap();
+        assert_eq!(default["aws_access_key_id"], "AKIDDEFAULT");
+        assert!(!default.contains_key("region"));
+
+        let work = parse_profile(contents, "profile work").unwrap();
+        assert_eq!(work["aws_session_token"], "token");
+        assert_eq!(work["region"], "eu-west-1");
+
+        assert!(parse_profile(contents, "missing").is_none());
+    }
+}
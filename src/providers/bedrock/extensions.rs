@@ -0,0 +1,20 @@
+//! Bedrock-specific metadata for extensions.
+
+use crate::extensions::Extension;
+use serde::{Deserialize, Serialize};
+
+/// Metadata of reasoning content returned by Bedrock models.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct BedrockReasoningMetadata {
+    /// The signature of the reasoning text, returned by Anthropic models.
+    ///
+    /// This must be sent back with the reasoning in subsequent turns for extended
+    /// thinking to work with tool use.
+    pub signature: Option<String>,
+    /// Reasoning the model provider encrypted for safety reasons, sent back as is.
+    pub redacted_content: Option<String>,
+}
+
+impl Extension for BedrockReasoningMetadata {
+    const NAME: &'static str = "bedrock.reasoning_metadata";
+}
@@ -0,0 +1,281 @@
+//! Language model implementation for the Bedrock provider.
+use crate::core::capabilities::ModelName;
+use crate::core::client::LanguageModelClient;
+use crate::core::language_model::{
+    LanguageModel, LanguageModelOptions, LanguageModelResponse, LanguageModelResponseContentType,
+    LanguageModelStreamChunk, LanguageModelStreamChunkType, ProviderStream, ReasoningEffort,
+    SamplingOption,
+};
+use crate::core::messages::AssistantMessage;
+use crate::core::tools::{ToolCallDelta, ToolDetails};
+use crate::error::Result;
+use crate::providers::bedrock::Bedrock;
+use crate::providers::bedrock::client::types::{self, StreamEvent, ToolUseBlock};
+use crate::providers::bedrock::conversions::{
+    self, STRUCTURED_OUTPUT_TOOL, structured_output_content,
+};
+use async_trait::async_trait;
+use futures::StreamExt;
+use serde_json::json;
+use std::collections::BTreeMap;
+
+impl<M: ModelName> Bedrock<M> {
+    /// Builds the Converse request for `options`, applying the provider settings.
+    fn converse_request(&self, options: LanguageModelOptions) -> types::ConverseRequest {
+        let reasoning_effort = options.reasoning_effort;
+        let mut request: types::ConverseRequest = options.into();
+
+        // Extended thinking is a model specific field, only known for Anthropic models
+        if let Some(effort) = reasoning_effort
+            && self.lm_options.model.contains("anthropic.")
+        {
+            let config = request
+                .inference_config
+                .get_or_insert_with(Default::default);
+            let max_tokens = *config.max_tokens.get_or_insert(10_000);
+            let budget_tokens = match effort {
+                // The smallest budget Anthropic models accept
+                ReasoningEffort::Minimal => 1024,
+                ReasoningEffort::Low => max_tokens / 4,
+                ReasoningEffort::Medium => max_tokens / 2,
+                ReasoningEffort::High => max_tokens - max_tokens / 4,
+            };
+            request.additional_model_request_fields.insert(
+                "thinking".to_string(),
+                json!({ "type": "enabled", "budget_tokens": budget_tokens }),
+            );
+        }
+
+        for (key, value) in &self.settings.additional_model_request_fields {
+            request
+                .additional_model_request_fields
+                .entry(key.clone())
+                .or_insert_with(|| value.clone());
+        }
+        request
+    }
+}
+
+/// A content block being streamed.
+#[derive(Default)]
+struct StreamBlock {
+    text: String,
+    reasoning: String,
+    signature: Option<String>,
+    redacted_content: Option<String>,
+    tool_use: Option<ToolUseBlock>,
+    tool_input: String,
+}
+
+impl StreamBlock {
+    fn into_content(self) -> Option<LanguageModelResponseContentType> {
+        if let Some(mut tool_use) = self.tool_use {
+            let input = if self.tool_input.trim().is_empty() {
+                "{}"
+            } else {
+                self.tool_input.as_str()
+            };
+            return Some(match serde_json::from_str(input) {
+                Ok(input) => {
+                    tool_use.input = input;
+                    conversions::tool_call(tool_use)
+                }
+                Err(_) => LanguageModelResponseContentType::NotSupported(format!(
+                    "Invalid tool json: {}",
+                    self.tool_input
+                )),
+            });
+        }
+        if !self.reasoning.is_empty() || self.redacted_content.is_some() {
+            return Some(conversions::reasoning(
+                self.reasoning,
+                self.signature,
+                self.redacted_content,
+            ));
+        }
+        (!self.text.is_empty()).then_some(LanguageModelResponseContentType::Text(self.text))
+    }
+}
+
+#[async_trait]
+impl<M: ModelName> LanguageModel for Bedrock<M> {
+    fn name(&self) -> String {
+        self.lm_options.model.clone()
+    }
+
+    fn capabilities(&self) -> crate::core::capabilities::Capabilities {
+        M::CAPABILITIES
+    }
+
+    fn info(&self) -> crate::core::capabilities::ModelInfo {
+        Self::model_info(&self.name())
+    }
+
+    fn unsupported_options(&self) -> &'static [SamplingOption] {
+        &[
+            SamplingOption::Seed,
+            SamplingOption::TopK,
+            SamplingOption::PresencePenalty,
+            SamplingOption::FrequencyPenalty,
+        ]
+    }
+
+    async fn generate_text(
+        &mut self,
+        options: LanguageModelOptions,
+    ) -> Result<LanguageModelResponse> {
+        self.lm_options.request = Some(self.converse_request(options));
+        self.lm_options.stream = false;
+
+        let response: types::ConverseResponse = self.send(self.settings.endpoint()).await?;
+
+        let mut contents = response
+            .output
+            .message
+            .map(conversions::message_contents)
+            .unwrap_or_default();
+        contents.extend(
+            response
+                .stop_reason
+                .as_deref()
+                .and_then(conversions::refusal),
+        );
+
+        Ok(LanguageModelResponse {
+            contents,
+            usage: response.usage.map(Into::into),
+            stop_sequence: None,
+            finish_reason: response
+                .stop_reason
+                .as_deref()
+                .map(conversions::finish_reason),
+            logprobs: Vec::new(),
+            extensions: Default::default(),
+        })
+    }
+
+    async fn stream_text(&mut self, options: LanguageModelOptions) -> Result<ProviderStream> {
+        self.lm_options.request = Some(self.converse_request(options));
+        self.lm_options.stream = true;
+
+        let bedrock_stream = self.send_and_stream_events().await?;
+
+        let stream =
+            bedrock_stream.scan(BTreeMap::<usize, StreamBlock>::new(), |blocks, evt_res| {
+                futures::future::ready(match evt_res {
+                    Ok(event) => {
+                        let mut chunks = Vec::new();
+                        match event {
+                            StreamEvent::MessageStart { .. } => {
+                                chunks.push(LanguageModelStreamChunk::Delta(
+                                    LanguageModelStreamChunkType::Start,
+                                ));
+                            }
+                            StreamEvent::ContentBlockStart {
+                                content_block_index,
+                                start,
+                            } => {
+                                if let Some(tool_use) = start.tool_use {
+                                    blocks.entry(content_block_index).or_default().tool_use =
+                                        Some(ToolUseBlock {
+                                            tool_use_id: tool_use.tool_use_id,
+                                            name: tool_use.name,
+                                            input: serde_json::Value::Null,
+                                        });
+                                }
+                            }
+                            StreamEvent::ContentBlockDelta {
+                                content_block_index,
+                                delta,
+                            } => {
+                                let block = blocks.entry(content_block_index).or_default();
+                                if let Some(text) = delta.text.filter(|t| !t.is_empty()) {
+                                    block.text.push_str(&text);
+                                    chunks.push(LanguageModelStreamChunk::Delta(
+                                        LanguageModelStreamChunkType::Text(text),
+                                    ));
+                                }
+                                if let Some(tool_use) = delta.tool_use
+                                    && let Some(details) = &block.tool_use
+                                {
+                                    block.tool_input.push_str(&tool_use.input);
+                                    chunks.push(if details.name == STRUCTURED_OUTPUT_TOOL {
+                                        LanguageModelStreamChunk::Delta(
+                                            LanguageModelStreamChunkType::Text(tool_use.input),
+                                        )
+                                    } else {
+                                        LanguageModelStreamChunk::ToolCallDelta(ToolCallDelta {
+                                            tool: ToolDetails {
+                                                id: details.tool_use_id.clone(),
+                                                name: details.name.clone(),
+                                            },
+                                            delta: tool_use.input,
+                                        })
+                                    });
+                                }
+                                if let Some(reasoning) = delta.reasoning_content {
+                                    if let Some(text) = reasoning.text.filter(|t| !t.is_empty()) {
+                                        block.reasoning.push_str(&text);
+                                        chunks.push(LanguageModelStreamChunk::Delta(
+                                            LanguageModelStreamChunkType::Reasoning(text),
+                                        ));
+                                    }
+                                    if reasoning.signature.is_some() {
+                                        block.signature = reasoning.signature;
+                                    }
+                                    if reasoning.redacted_content.is_some() {
+                                        block.redacted_content = reasoning.redacted_content;
+                                    }
+                                }
+                            }
+                            StreamEvent::Metadata { usage } => {
+                                // Metadata is the last event, sent after `messageStop`
+                                let usage: Option<crate::core::language_model::Usage> =
+                                    usage.map(Into::into);
+                                if let Some(usage) = &usage {
+                                    chunks.push(LanguageModelStreamChunk::Usage(usage.clone()));
+                                }
+
+                                let mut contents: Vec<_> = std::mem::take(blocks)
+                                    .into_values()
+                                    .filter_map(StreamBlock::into_content)
+                                    .map(structured_output_content)
+                                    .collect();
+                                if contents.is_empty() {
+                                    contents.push(LanguageModelResponseContentType::Text(
+                                        String::new(),
+                                    ));
+                                }
+                                chunks.extend(contents.into_iter().map(|content| {
+                                    LanguageModelStreamChunk::Done(AssistantMessage {
+                                        content,
+                                        usage: usage.clone(),
+                                    })
+                                }));
+                            }
+                            StreamEvent::MessageStop { stop_reason } => {
+                                if let Some(reason) = stop_reason {
+                                    chunks.push(LanguageModelStreamChunk::FinishReason(
+                                        conversions::finish_reason(&reason),
+                                    ));
+                                    if let Some(content) = conversions::refusal(&reason) {
+                                        chunks.push(LanguageModelStreamChunk::Done(
+                                            AssistantMessage {
+                                                content,
+                                                usage: None,
+                                            },
+                                        ));
+                                    }
+                                }
+                            }
+                            StreamEvent::ContentBlockStop { .. } | StreamEvent::NotSupported => {}
+                        }
+                        Some(Ok(chunks))
+                    }
+                    Err(e) => Some(Err(e)),
+                })
+            });
+
+        Ok(Box::pin(stream))
+    }
+}
@@ -0,0 +1,294 @@
+//! This module provides the Bedrock provider, which implements the `LanguageModel` and
+//! `Provider` traits for models hosted on Amazon Bedrock.
+//!
+//! Unlike [`AmazonBedrock`](crate::providers::AmazonBedrock), which goes through
+//! Bedrock's OpenAI-compatible endpoint with a bearer key, this provider signs requests
+//! with AWS Signature Version 4 and speaks the native `Converse` and `ConverseStream`
+//! APIs, so it works with IAM credentials and every model that supports Converse.
+
+pub mod capabilities;
+pub mod client;
+pub mod conversions;
+pub mod credentials;
+pub(crate) mod extensions;
+pub mod language_model;
+pub mod settings;
+
+use crate::core::DynamicModel;
+use crate::core::capabilities::ModelName;
+use crate::core::client::RetryPolicy;
+use crate::core::utils::validate_base_url;
+use crate::error::{Error, Result};
+use crate::providers::bedrock::client::BedrockOptions;
+use crate::providers::bedrock::settings::BedrockProviderSettings;
+use serde::Serialize;
+use serde_json::Value;
+
+pub use credentials::BedrockCredentials;
+
+/// The Bedrock provider.
+#[derive(Debug, Serialize, Clone)]
+pub struct Bedrock<M: ModelName> {
+    /// Configuration settings for the Bedrock provider.
+    pub settings: BedrockProviderSettings,
+    pub(crate) lm_options: BedrockOptions,
+    _phantom: std::marker::PhantomData<M>,
+}
+
+impl<M: ModelName> Bedrock<M> {
+    /// Bedrock provider setting builder.
+    pub fn builder() -> BedrockBuilder<M> {
+        BedrockBuilder::default()
+    }
+
+    fn with_model(settings: BedrockProviderSettings, model_name: String) -> Self {
+        let options = BedrockOptions::builder().model(model_name).build().unwrap();
+
+        Self {
+            settings,
+            lm_options: options,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl Bedrock<DynamicModel> {
+    /// Creates a Bedrock provider with a dynamic model name using default settings.
+    ///
+    /// This allows you to use any model ID or inference profile, specified as a string
+    /// rather than with methods like `Bedrock::amazon_nova_pro_v1_0()`.
+    ///
+    /// **WARNING**: when using `DynamicModel`, model capabilities are not validated.
+    /// This means there is no compile-time guarantee that the model supports requested features.
+    ///
+    /// For custom configuration (region, credentials, etc.), use the builder pattern:
+    /// `Bedrock::<DynamicModel>::builder().model_name(...).region(...).build()`
+    ///
+    /// # Parameters
+    ///
+    /// * `model_name` - The Bedrock model ID or inference profile (e.g.,
+    ///   "amazon.nova-pro-v1:0", "us.anthropic.claude-3-7-sonnet-20250219-v1:0")
+    ///
+    /// # Returns
+    ///
+    /// A configured `Bedrock<DynamicModel>` provider instance with default settings.
+    pub fn model_name(name: impl Into<String>) -> Self {
+        Self::with_model(BedrockProviderSettings::default(), name.into())
+    }
+}
+
+impl<M: ModelName> Default for Bedrock<M> {
+    /// Creates a new Bedrock provider with default settings.
+    fn default() -> Self {
+        Self::with_model(
+            BedrockProviderSettings::default(),
+            M::MODEL_NAME.to_string(),
+        )
+    }
+}
+
+/// Bedrock Provider Builder
+pub struct BedrockBuilder<M: ModelName> {
+    settings: BedrockProviderSettings,
+    model_name: String,
+    profile: Option<String>,
+    region: Option<String>,
+    _phantom: std::marker::PhantomData<M>,
+}
+
+impl<M: ModelName> Default for BedrockBuilder<M> {
+    /// Creates a new Bedrock provider builder with default settings.
+    fn default() -> Self {
+        Self {
+            settings: BedrockProviderSettings::default(),
+            model_name: M::MODEL_NAME.to_string(),
+            profile: None,
+            region: None,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl BedrockBuilder<DynamicModel> {
+    /// Sets the model name from a string. e.g., "amazon.nova-pro-v1:0"
+    ///
+    /// **WARNING**: when using `DynamicModel`, model capabilities are not validated.
+    /// This means there is no compile-time guarantee that the model supports requested features.
+    ///
+    /// # Parameters
+    ///
+    /// * `model_name` - The Bedrock model ID or inference profile.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the model name set.
+    pub fn model_name(mut self, model_name: impl Into<String>) -> Self {
+        self.model_name = model_name.into();
+        self
+    }
+}
+
+impl<M: ModelName> BedrockBuilder<M> {
+    /// Sets the AWS region. Defaults to `AWS_REGION`, `AWS_DEFAULT_REGION`, the region
+    /// of the profile, or `us-east-1`.
+    pub fn region(mut self, region: impl Into<String>) -> Self {
+        self.region = Some(region.into());
+        self
+    }
+
+    /// Overrides the Bedrock runtime endpoint derived from the region.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.settings.base_url = Some(base_url.into());
+        self
+    }
+
+    /// Sets the credentials used to sign requests. Defaults to the `AWS_ACCESS_KEY_ID`,
+    /// `AWS_SECRET_ACCESS_KEY`, and `AWS_SESSION_TOKEN` environment variables, or the
+    /// `AWS_PROFILE` profile.
+    pub fn credentials(mut self, credentials: BedrockCredentials) -> Self {
+        self.settings.credentials = credentials;
+        self
+    }
+
+    /// Reads the credentials, and the region unless one is set, from a profile in the
+    /// shared AWS configuration files when the provider is built.
+    pub fn profile(mut self, profile: impl Into<String>) -> Self {
+        self.profile = Some(profile.into());
+        self
+    }
+
+    /// Sets the retry policy applied to requests made by this provider.
+    ///
+    /// # Parameters
+    ///
+    /// * `retry_policy` - The [`RetryPolicy`] to use for retryable failures.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the retry policy set.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.settings.retry_policy = retry_policy;
+        self
+    }
+
+    crate::core::client::transport_builder_methods!();
+
+    /// Sets the name of the provider. Defaults to "bedrock".
+    pub fn provider_name(mut self, provider_name: impl Into<String>) -> Self {
+        self.settings.provider_name = provider_name.into();
+        self
+    }
+
+    /// Sets a model specific field sent as `additionalModelRequestFields`, e.g.
+    /// `top_k` for Anthropic models.
+    pub fn additional_model_request_field(
+        mut self,
+        key: impl Into<String>,
+        value: impl Into<Value>,
+    ) -> Self {
+        self.settings
+            .additional_model_request_fields
+            .insert(key.into(), value.into());
+        self
+    }
+
+    /// Builds the Bedrock provider.
+    pub fn build(mut self) -> Result<Bedrock<M>> {
+        if let Some(profile) = &self.profile {
+            self.settings.credentials = BedrockCredentials::from_profile(profile)?;
+            if let Some(region) = credentials::profile_region(profile) {
+                self.settings.region = region;
+            }
+        }
+        if let Some(region) = self.region {
+            self.settings.region = region;
+        }
+
+        // validate base url
+        let base_url = self
+            .settings
+            .base_url
+            .as_deref()
+            .map(validate_base_url)
+            .transpose()?;
+
+        if self.settings.region.is_empty() {
+            return Err(Error::MissingField("region".to_string()));
+        }
+
+        // check credentials exist
+        if self.settings.credentials.access_key_id.is_empty()
+            || self.settings.credentials.secret_access_key.is_empty()
+        {
+            return Err(Error::MissingField("credentials".to_string()));
+        }
+
+        if self.model_name.is_empty() {
+            return Err(Error::MissingField("model_name".to_string()));
+        }
+
+        Ok(Bedrock::with_model(
+            BedrockProviderSettings {
+                base_url,
+                ..self.settings
+            },
+            self.model_name,
+        ))
+    }
+}
+
+// Re-exports for convenience
+pub use capabilities::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_sets_region_and_credentials() {
+        let bedrock = Bedrock::<DynamicModel>::builder()
+            .model_name("amazon.nova-pro-v1:0")
+            .region("eu-central-1")
+            .credentials(BedrockCredentials::new("AKID", "secret").session_token("token"))
+            .additional_model_request_field("top_k", 40)
+            .build()
+            .unwrap();
+
+        assert_eq!(bedrock.lm_options.model, "amazon.nova-pro-v1:0");
+        assert_eq!(
+            bedrock.settings.endpoint(),
+            "https://bedrock-runtime.eu-central-1.amazonaws.com/"
+        );
+        assert_eq!(
+            bedrock.settings.credentials.session_token.as_deref(),
+            Some("token")
+        );
+        assert_eq!(
+            bedrock.settings.additional_model_request_fields["top_k"],
+            40
+        );
+    }
+
+    #[test]
+    fn test_builder_requires_credentials() {
+        let result = Bedrock::<DynamicModel>::builder()
+            .model_name("amazon.nova-pro-v1:0")
+            .credentials(BedrockCredentials::default())
+            .build();
+        assert!(matches!(result, Err(Error::MissingField(field)) if field == "credentials"));
+    }
+
+    #[test]
+    fn test_base_url_overrides_endpoint() {
+        let bedrock = Bedrock::<DynamicModel>::builder()
+            .model_name("amazon.nova-pro-v1:0")
+            .credentials(BedrockCredentials::new("AKID", "secret"))
+            .base_url("https://vpce-123.bedrock-runtime.us-east-1.vpce.amazonaws.com")
+            .build()
+            .unwrap();
+        assert_eq!(
+            bedrock.settings.endpoint(),
+            "https://vpce-123.bedrock-runtime.us-east-1.vpce.amazonaws.com/"
+        );
+    }
+}
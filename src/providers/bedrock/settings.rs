@@ -0,0 +1,65 @@
+//! Defines the settings for the Bedrock provider.
+
+use crate::core::client::{RetryPolicy, TransportSettings};
+use crate::providers::bedrock::credentials::{self, BedrockCredentials};
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// Settings for the Bedrock provider.
+#[derive(Debug, Clone, Serialize, Deserialize, Builder)]
+#[builder(setter(into), default)]
+pub struct BedrockProviderSettings {
+    /// The name of the provider.
+    pub provider_name: String,
+
+    /// The AWS region, e.g. `us-east-1`.
+    pub region: String,
+
+    /// Overrides the Bedrock runtime endpoint derived from the region, e.g. for a VPC
+    /// endpoint.
+    pub base_url: Option<String>,
+
+    /// The credentials used to sign requests.
+    pub credentials: BedrockCredentials,
+
+    /// Model specific fields sent as `additionalModelRequestFields` with every request,
+    /// such as `top_k` for Anthropic models.
+    pub additional_model_request_fields: Map<String, Value>,
+
+    /// The retry policy applied to requests.
+    pub retry_policy: RetryPolicy,
+
+    /// The HTTP client, middleware and rate limiter requests go through.
+    #[serde(skip)]
+    pub transport: TransportSettings,
+}
+
+impl Default for BedrockProviderSettings {
+    /// Returns the default settings for the Bedrock provider.
+    fn default() -> Self {
+        Self {
+            provider_name: "bedrock".to_string(),
+            region: credentials::resolve_region(),
+            base_url: None,
+            credentials: BedrockCredentials::resolve().unwrap_or_default(),
+            additional_model_request_fields: Map::new(),
+            retry_policy: RetryPolicy::default(),
+            transport: TransportSettings::default(),
+        }
+    }
+}
+
+impl BedrockProviderSettings {
+    /// Creates a new builder for `BedrockProviderSettings`.
+    pub fn builder() -> BedrockProviderSettingsBuilder {
+        BedrockProviderSettingsBuilder::default()
+    }
+
+    /// Returns the Bedrock runtime endpoint requests are sent to.
+    pub fn endpoint(&self) -> String {
+        self.base_url
+            .clone()
+            .unwrap_or_else(|| format!("https://bedrock-runtime.{}.amazonaws.com/", self.region))
+    }
+}
@@ -0,0 +1,233 @@
+//! The token by token generation loop of the candle provider.
+
+use candle_core::{DType, Device, Tensor};
+use candle_transformers::generation::{LogitsProcessor, Sampling};
+use std::sync::Mutex;
+use tokenizers::Tokenizer;
+
+use crate::core::language_model::{FinishReason, LanguageModelOptions, Usage};
+use crate::error::{Error, Result};
+use crate::providers::candle::settings::CandleSettings;
+use crate::providers::candle::weights::{Weights, candle_error};
+
+/// A loaded model, shared by the clones of a provider. Generations on the same model
+/// run one at a time.
+pub(crate) struct Engine {
+    pub(crate) weights: Mutex<Box<dyn Weights>>,
+    pub(crate) tokenizer: Tokenizer,
+    pub(crate) device: Device,
+    pub(crate) eos_token_ids: Vec<u32>,
+    pub(crate) context_window: Option<usize>,
+}
+
+impl std::fmt::Debug for Engine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Engine")
+            .field("device", &self.device)
+            .field("eos_token_ids", &self.eos_token_ids)
+            .field("context_window", &self.context_window)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The sampling parameters of a generation.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Params {
+    pub(crate) prompt: String,
+    pub(crate) add_special_tokens: bool,
+    pub(crate) sampling: Sampling,
+    pub(crate) seed: u64,
+    pub(crate) max_tokens: usize,
+    pub(crate) stop_sequences: Vec<String>,
+}
+
+impl Params {
+    /// Returns the parameters of a request. Sampling is greedy unless the request sets
+    /// a temperature.
+    pub(crate) fn new(options: &LanguageModelOptions, settings: &CandleSettings) -> Self {
+        let temperature = options
+            .temperature
+            .map(|t| t as f64 / 100.0)
+            .filter(|t| *t > 0.0);
+        let top_p = options.top_p.map(|p| p as f64 / 100.0);
+        let top_k = options.top_k.map(|k| k as usize);
+        let sampling = match (temperature, top_k, top_p) {
+            (None, _, _) => Sampling::ArgMax,
+            (Some(temperature), None, None) => Sampling::All { temperature },
+            (Some(temperature), Some(k), None) => Sampling::TopK { k, temperature },
+            (Some(temperature), None, Some(p)) => Sampling::TopP { p, temperature },
+            (Some(temperature), Some(k), Some(p)) => Sampling::TopKThenTopP { k, p, temperature },
+        };
+
+        Self {
+            prompt: settings
+                .chat_template
+                .render(options.system.as_deref(), &options.messages()),
+            add_special_tokens: settings.chat_template.adds_special_tokens(),
+            sampling,
+            seed: options.seed.map_or(settings.seed, u64::from),
+            max_tokens: options
+                .max_output_tokens
+                .map_or(settings.max_tokens, |max| max as usize),
+            stop_sequences: options.stop_sequences.clone().unwrap_or_default(),
+        }
+    }
+}
+
+/// The result of a generation.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Generation {
+    pub(crate) text: String,
+    pub(crate) usage: Usage,
+    pub(crate) finish_reason: FinishReason,
+    pub(crate) stop_sequence: Option<String>,
+}
+
+impl Engine {
+    /// Returns the number of tokens `text` is encoded into.
+    pub(crate) fn count_tokens(&self, text: &str, add_special_tokens: bool) -> Result<usize> {
+        Ok(self.encode(text, add_special_tokens)?.len())
+    }
+
+    fn encode(&self, text: &str, add_special_tokens: bool) -> Result<Vec<u32>> {
+        self.tokenizer
+            .encode(text, add_special_tokens)
+            .map(|encoding| encoding.get_ids().to_vec())
+            .map_err(|e| Error::InvalidInput(format!("Failed to tokenize the prompt: {e}")))
+    }
+
+    /// Generates a reply to the prompt of `params`, calling `on_text` with each piece
+    /// of text as it is generated. Generation stops early when `on_text` returns
+    /// false, e.g. because the stream was dropped.
+    ///
+    /// Text that may be the start of a stop sequence is held back until it is known
+    /// not to be one, so stop sequences are never passed to `on_text`.
+    pub(crate) fn generate(
+        &self,
+        params: &Params,
+        mut on_text: impl FnMut(&str) -> bool,
+    ) -> Result<Generation> {
+        let prompt = self.encode(&params.prompt, params.add_special_tokens)?;
+        if prompt.is_empty() {
+            return Err(Error::InvalidInput("The prompt is empty".to_string()));
+        }
+        let mut max_tokens = params.max_tokens;
+        if let Some(window) = self.context_window {
+            if prompt.len() >= window {
+                return Err(Error::ContextLengthExceeded {
+                    details: format!(
+                        "The prompt has {} tokens, the context window {window}",
+                        prompt.len()
+                    ),
+                    status_code: None,
+                });
+            }
+            max_tokens = max_tokens.min(window - prompt.len());
+        }
+
+        let mut weights = self.weights.lock().unwrap_or_else(|e| e.into_inner());
+        let mut sampler = LogitsProcessor::from_sampling(params.seed, params.sampling.clone());
+        let mut generated: Vec<u32> = Vec::new();
+        let mut text = String::new();
+        let mut sent = 0;
+        let mut input = prompt.clone();
+        let mut position = 0;
+        let mut finish_reason = FinishReason::Length;
+        let mut stop_sequence = None;
+
+        while generated.len() < max_tokens {
+            let tensor = Tensor::new(input.as_slice(), &self.device)
+                .and_then(|t| t.unsqueeze(0))
+                .map_err(candle_error)?;
+            let logits = weights
+                .forward(&tensor, position)
+                .and_then(|l| l.squeeze(0))
+                .and_then(|l| l.to_dtype(DType::F32))
+                .map_err(candle_error)?;
+            position += input.len();
+            let token = sampler.sample(&logits).map_err(candle_error)?;
+            if self.eos_token_ids.contains(&token) {
+                finish_reason = FinishReason::Stop;
+                break;
+            }
+            generated.push(token);
+            input = vec![token];
+
+            text = self
+                .tokenizer
+                .decode(&generated, true)
+                .map_err(|e| Error::Other(format!("Failed to decode the output: {e}")))?;
+            // Wait for the rest of a character split across tokens
+            if text.ends_with('\u{fffd}') {
+                continue;
+            }
+            if let Some((index, stop)) = find_stop_sequence(&text, &params.stop_sequences) {
+                text.truncate(index);
+                stop_sequence = Some(stop);
+                finish_reason = FinishReason::Stop;
+                break;
+            }
+            let ready = text.len() - held_back(&text, &params.stop_sequences);
+            if ready > sent && text.is_char_boundary(sent) {
+                if !on_text(&text[sent..ready]) {
+                    finish_reason = FinishReason::Stop;
+                    break;
+                }
+                sent = ready;
+            }
+        }
+        if text.len() > sent && text.is_char_boundary(sent) {
+            on_text(&text[sent..]);
+        }
+
+        Ok(Generation {
+            text,
+            usage: Usage {
+                input_tokens: Some(prompt.len()),
+                output_tokens: Some(generated.len()),
+                ..Default::default()
+            },
+            finish_reason,
+            stop_sequence,
+        })
+    }
+}
+
+/// Returns where the first stop sequence in `text` starts, and the stop sequence.
+fn find_stop_sequence(text: &str, stop_sequences: &[String]) -> Option<(usize, String)> {
+    stop_sequences
+        .iter()
+        .filter(|stop| !stop.is_empty())
+        .filter_map(|stop| text.find(stop.as_str()).map(|index| (index, stop.clone())))
+        .min_by_key(|(index, _)| *index)
+}
+
+/// Returns the length of the longest end of `text` that starts a stop sequence.
+fn held_back(text: &str, stop_sequences: &[String]) -> usize {
+    stop_sequences
+        .iter()
+        .flat_map(|stop| {
+            (1..stop.len())
+                .filter(|&len| stop.is_char_boundary(len) && text.ends_with(&stop[..len]))
+                .max()
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stop_sequences_are_held_back() {
+        let stops = vec!["\nUser:".to_string()];
+
+        assert_eq!(held_back("Hello\nUs", &stops), 3);
+        assert_eq!(held_back("Hello", &stops), 0);
+        assert_eq!(
+            find_stop_sequence("Hi\nUser: more", &stops),
+            Some((2, "\nUser:".to_string()))
+        );
+    }
+}
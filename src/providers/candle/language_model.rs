@@ -0,0 +1,92 @@
+//! Language model implementation for the candle provider.
+use crate::core::capabilities::{Capabilities, Capability, ModelInfo};
+use crate::core::language_model::{
+    LanguageModel, LanguageModelOptions, LanguageModelResponse, LanguageModelResponseContentType,
+    LanguageModelStreamChunk, LanguageModelStreamChunkType, ProviderStream,
+};
+use crate::core::messages::AssistantMessage;
+use crate::error::{Error, Result};
+use crate::providers::candle::Candle;
+use crate::providers::candle::generation::{Generation, Params};
+use async_trait::async_trait;
+
+/// Returns the final chunks of a streamed generation.
+fn finish_chunks(generation: Generation) -> Vec<LanguageModelStreamChunk> {
+    let mut chunks = Vec::new();
+    if let Some(stop_sequence) = generation.stop_sequence {
+        chunks.push(LanguageModelStreamChunk::StopSequence(stop_sequence));
+    }
+    chunks.push(LanguageModelStreamChunk::FinishReason(
+        generation.finish_reason,
+    ));
+    chunks.push(LanguageModelStreamChunk::Done(AssistantMessage {
+        content: LanguageModelResponseContentType::Text(generation.text),
+        usage: Some(generation.usage),
+    }));
+    chunks
+}
+
+#[async_trait]
+impl LanguageModel for Candle {
+    fn name(&self) -> String {
+        self.settings.model_name.clone()
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities::new(&[Capability::TextInput, Capability::TextOutput])
+    }
+
+    fn info(&self) -> ModelInfo {
+        ModelInfo {
+            context_window: self.engine.context_window,
+            ..ModelInfo::UNKNOWN
+        }
+    }
+
+    async fn count_tokens(&self, options: &LanguageModelOptions) -> Result<usize> {
+        let params = Params::new(options, &self.settings);
+        self.engine
+            .count_tokens(&params.prompt, params.add_special_tokens)
+    }
+
+    async fn generate_text(
+        &mut self,
+        options: LanguageModelOptions,
+    ) -> Result<LanguageModelResponse> {
+        let params = Params::new(&options, &self.settings);
+        let engine = self.engine.clone();
+
+        let generation = tokio::task::spawn_blocking(move || engine.generate(&params, |_| true))
+            .await
+            .map_err(|e| Error::Other(format!("Generation task failed: {e}")))??;
+
+        Ok(LanguageModelResponse {
+            contents: vec![LanguageModelResponseContentType::Text(generation.text)],
+            usage: Some(generation.usage),
+            stop_sequence: generation.stop_sequence,
+            finish_reason: Some(generation.finish_reason),
+            logprobs: Vec::new(),
+            extensions: Default::default(),
+        })
+    }
+
+    async fn stream_text(&mut self, options: LanguageModelOptions) -> Result<ProviderStream> {
+        let params = Params::new(&options, &self.settings);
+        let engine = self.engine.clone();
+        let (tx, rx) = futures::channel::mpsc::unbounded();
+
+        // Tokens are generated on a blocking thread, which stops once the stream is
+        // dropped
+        tokio::task::spawn_blocking(move || {
+            let result = engine.generate(&params, |text| {
+                tx.unbounded_send(Ok(vec![LanguageModelStreamChunk::Delta(
+                    LanguageModelStreamChunkType::Text(text.to_string()),
+                )]))
+                .is_ok()
+            });
+            let _ = tx.unbounded_send(result.map(finish_chunks));
+        });
+
+        Ok(Box::pin(rx))
+    }
+}
@@ -0,0 +1,417 @@
+//! This module provides the candle provider, which implements the `LanguageModel`
+//! trait for models run in process with [candle](https://github.com/huggingface/candle).
+//!
+//! The model is loaded from a quantized GGUF file or a safetensors Llama checkpoint,
+//! with the `tokenizer.json` of the model, and runs fully offline, without a server.
+//! Text is generated token by token, on a blocking thread, and streamed as it is
+//! decoded. The clones of a provider share the loaded model, and their generations
+//! run one at a time.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use aisdk::core::LanguageModelRequest;
+//! use aisdk::providers::candle::{Candle, ChatTemplate};
+//!
+//! # async fn run() -> aisdk::Result<()> {
+//! let model = Candle::builder()
+//!     .gguf("qwen2.5-0.5b-instruct-q4_k_m.gguf")
+//!     .tokenizer("tokenizer.json")
+//!     .chat_template(ChatTemplate::ChatMl)
+//!     .build()?;
+//!
+//! let response = LanguageModelRequest::builder()
+//!     .model(model)
+//!     .prompt("Why is the sky blue?")
+//!     .build()
+//!     .generate_text()
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+pub(crate) mod generation;
+pub mod language_model;
+pub mod settings;
+pub mod weights;
+
+use crate::core::capabilities::{TextInputSupport, TextOutputSupport};
+use crate::core::language_model::boxed::BoxedLanguageModel;
+use crate::error::{Error, Result};
+use crate::providers::candle::generation::Engine;
+use crate::providers::candle::weights::Loaded;
+use candle_core::Device;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use tokenizers::Tokenizer;
+
+pub use settings::{CandleSettings, ChatTemplate};
+pub use weights::WeightsSource;
+
+/// The candle provider, running a local model in process.
+#[derive(Debug, Clone)]
+pub struct Candle {
+    /// Configuration settings for the candle provider.
+    pub settings: CandleSettings,
+    engine: Arc<Engine>,
+}
+
+impl Candle {
+    /// Candle provider setting builder.
+    pub fn builder() -> CandleBuilder {
+        CandleBuilder::default()
+    }
+
+    /// Creates a provider for loaded weights.
+    pub(crate) fn with_weights(
+        settings: CandleSettings,
+        loaded: Loaded,
+        tokenizer: Tokenizer,
+        device: Device,
+    ) -> Self {
+        let mut eos_token_ids = loaded.eos_token_ids;
+        let eos_tokens = settings
+            .chat_template
+            .end_tokens()
+            .iter()
+            .copied()
+            .chain(settings.eos_tokens.iter().map(String::as_str));
+        for token in eos_tokens {
+            if let Some(id) = tokenizer.token_to_id(token)
+                && !eos_token_ids.contains(&id)
+            {
+                eos_token_ids.push(id);
+            }
+        }
+
+        Self {
+            settings,
+            engine: Arc::new(Engine {
+                weights: Mutex::new(loaded.weights),
+                tokenizer,
+                device,
+                eos_token_ids,
+                context_window: loaded.context_window,
+            }),
+        }
+    }
+}
+
+impl TextInputSupport for Candle {}
+impl TextOutputSupport for Candle {}
+
+impl From<Candle> for BoxedLanguageModel {
+    /// Boxes a [`Candle`] language model, to be held with models of other providers.
+    fn from(model: Candle) -> Self {
+        Box::new(model)
+    }
+}
+
+/// Candle Provider Builder
+#[derive(Debug, Default)]
+pub struct CandleBuilder {
+    settings: CandleSettings,
+    weights: Option<WeightsSource>,
+    tokenizer: Option<PathBuf>,
+    device: Option<Device>,
+}
+
+impl CandleBuilder {
+    /// Loads the model from a quantized GGUF file of the Llama architecture, such as
+    /// Llama, Mistral or Qwen models converted to GGUF.
+    pub fn gguf(mut self, path: impl Into<PathBuf>) -> Self {
+        self.weights = Some(WeightsSource::Gguf(path.into()));
+        self
+    }
+
+    /// Loads the model from the safetensors files of a Llama checkpoint and its
+    /// `config.json`.
+    pub fn safetensors(
+        mut self,
+        files: impl IntoIterator<Item = impl Into<PathBuf>>,
+        config: impl Into<PathBuf>,
+    ) -> Self {
+        self.weights = Some(WeightsSource::Safetensors {
+            files: files.into_iter().map(Into::into).collect(),
+            config: config.into(),
+        });
+        self
+    }
+
+    /// Sets the `tokenizer.json` of the model.
+    pub fn tokenizer(mut self, path: impl Into<PathBuf>) -> Self {
+        self.tokenizer = Some(path.into());
+        self
+    }
+
+    /// Sets the device the model runs on. Defaults to the CPU.
+    pub fn device(mut self, device: Device) -> Self {
+        self.device = Some(device);
+        self
+    }
+
+    /// Sets the chat template conversations are rendered with. Defaults to
+    /// [`ChatTemplate::ChatMl`].
+    pub fn chat_template(mut self, chat_template: ChatTemplate) -> Self {
+        self.settings.chat_template = chat_template;
+        self
+    }
+
+    /// Sets the name the model is reported with. Defaults to "candle".
+    pub fn model_name(mut self, model_name: impl Into<String>) -> Self {
+        self.settings.model_name = model_name.into();
+        self
+    }
+
+    /// Sets the maximum number of tokens generated when the request does not set
+    /// `max_output_tokens`. Defaults to 512.
+    pub fn max_tokens(mut self, max_tokens: usize) -> Self {
+        self.settings.max_tokens = max_tokens;
+        self
+    }
+
+    /// Sets the seed of the sampler when the request does not set one. Defaults to 0.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.settings.seed = seed;
+        self
+    }
+
+    /// Adds a token that ends generation, e.g. a model specific end of turn token.
+    pub fn eos_token(mut self, token: impl Into<String>) -> Self {
+        self.settings.eos_tokens.push(token.into());
+        self
+    }
+
+    /// Sets the name of the provider. Defaults to "candle".
+    pub fn provider_name(mut self, provider_name: impl Into<String>) -> Self {
+        self.settings.provider_name = provider_name.into();
+        self
+    }
+
+    /// Loads the model and builds the candle provider.
+    ///
+    /// Loading reads the whole model and blocks until it is done.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the weights or tokenizer are not set, or cannot be loaded.
+    pub fn build(self) -> Result<Candle> {
+        let weights = self
+            .weights
+            .ok_or_else(|| Error::MissingField("weights".to_string()))?;
+        let tokenizer_path = self
+            .tokenizer
+            .ok_or_else(|| Error::MissingField("tokenizer".to_string()))?;
+        let tokenizer = Tokenizer::from_file(&tokenizer_path).map_err(|e| {
+            Error::InvalidInput(format!("Failed to read {}: {e}", tokenizer_path.display()))
+        })?;
+        let device = self.device.unwrap_or(Device::Cpu);
+        let loaded = weights.load(&device)?;
+
+        Ok(Candle::with_weights(
+            self.settings,
+            loaded,
+            tokenizer,
+            device,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::language_model::{
+        FinishReason, LanguageModel, LanguageModelOptions, LanguageModelResponseContentType,
+        LanguageModelStreamChunk, LanguageModelStreamChunkType,
+    };
+    use crate::core::messages::TaggedMessage;
+    use crate::core::{Message, UserMessage};
+    use crate::providers::candle::weights::Weights;
+    use candle_core::Tensor;
+    use futures::StreamExt;
+    use std::collections::HashMap;
+    use tokenizers::models::wordlevel::WordLevel;
+    use tokenizers::pre_tokenizers::whitespace::Whitespace;
+    use tokenizers::{AddedToken, Tokenizer};
+
+    const VOCAB: [&str; 6] = ["<unk>", "hello", "there", "world", "stop", "<|im_end|>"];
+
+    /// Weights predicting the tokens of `reply`, in order, whatever the input.
+    struct ScriptedWeights {
+        reply: Vec<u32>,
+        next: usize,
+    }
+
+    impl Weights for ScriptedWeights {
+        fn forward(&mut self, _input: &Tensor, position: usize) -> candle_core::Result<Tensor> {
+            if position == 0 {
+                self.next = 0;
+            }
+            let token = self.reply[self.next.min(self.reply.len() - 1)];
+            self.next += 1;
+            let mut logits = vec![0f32; VOCAB.len()];
+            logits[token as usize] = 10.0;
+            Tensor::new(logits.as_slice(), &Device::Cpu)?.unsqueeze(0)
+        }
+    }
+
+    fn tokenizer() -> Tokenizer {
+        let vocab: HashMap<String, u32> = VOCAB
+            .iter()
+            .enumerate()
+            .map(|(id, token)| (token.to_string(), id as u32))
+            .collect();
+        let model = WordLevel::builder()
+            .vocab(vocab.into_iter().collect())
+            .unk_token("<unk>".to_string())
+            .build()
+            .unwrap();
+        let mut tokenizer = Tokenizer::new(model);
+        tokenizer.with_pre_tokenizer(Some(Whitespace {}));
+        tokenizer.add_special_tokens(&[AddedToken::from("<|im_end|>", true)]);
+        tokenizer
+    }
+
+    fn candle(reply: &[&str], context_window: Option<usize>) -> Candle {
+        let tokenizer = tokenizer();
+        let reply = reply
+            .iter()
+            .map(|token| tokenizer.token_to_id(token).unwrap())
+            .collect();
+        let loaded = Loaded {
+            weights: Box::new(ScriptedWeights { reply, next: 0 }),
+            context_window,
+            eos_token_ids: Vec::new(),
+        };
+        let settings = CandleSettings {
+            chat_template: ChatTemplate::Plain,
+            eos_tokens: vec!["<|im_end|>".to_string()],
+            ..Default::default()
+        };
+        Candle::with_weights(settings, loaded, tokenizer, Device::Cpu)
+    }
+
+    fn options(prompt: &str) -> LanguageModelOptions {
+        LanguageModelOptions {
+            messages: vec![TaggedMessage::new(
+                0,
+                Message::User(UserMessage::new(prompt)),
+            )],
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_generate_text_stops_at_end_of_turn() {
+        let mut model = candle(&["hello", "world", "<|im_end|>", "hello"], None);
+
+        let response = model.generate_text(options("hello there")).await.unwrap();
+
+        assert!(matches!(
+            &response.contents[..],
+            [LanguageModelResponseContentType::Text(text)] if text == "hello world"
+        ));
+        assert_eq!(response.finish_reason, Some(FinishReason::Stop));
+        let usage = response.usage.unwrap();
+        assert_eq!(usage.input_tokens, Some(2));
+        assert_eq!(usage.output_tokens, Some(2));
+        assert_eq!(
+            model.count_tokens(&options("hello there")).await.unwrap(),
+            2
+        );
+    }
+
+    #[tokio::test]
+    async fn test_generate_text_honours_limits_and_stop_sequences() {
+        let mut model = candle(&["hello"], None);
+        let response = model
+            .generate_text(LanguageModelOptions {
+                max_output_tokens: Some(3),
+                ..options("hello")
+            })
+            .await
+            .unwrap();
+        assert_eq!(response.finish_reason, Some(FinishReason::Length));
+        assert_eq!(response.usage.unwrap().output_tokens, Some(3));
+
+        let mut model = candle(&["hello", "there", "stop", "world"], None);
+        let response = model
+            .generate_text(LanguageModelOptions {
+                stop_sequences: Some(vec![" stop".to_string()]),
+                ..options("hello")
+            })
+            .await
+            .unwrap();
+        assert!(matches!(
+            &response.contents[..],
+            [LanguageModelResponseContentType::Text(text)] if text == "hello there"
+        ));
+        assert_eq!(response.stop_sequence, Some(" stop".to_string()));
+
+        let mut model = candle(&["hello"], Some(2));
+        let result = model.generate_text(options("hello there")).await;
+        assert!(matches!(result, Err(Error::ContextLengthExceeded { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_stream_text_streams_token_by_token() {
+        let mut model = candle(&["hello", "there", "world", "<|im_end|>"], None);
+
+        let chunks: Vec<LanguageModelStreamChunk> = model
+            .stream_text(options("hello"))
+            .await
+            .unwrap()
+            .map(|chunks| chunks.unwrap())
+            .concat()
+            .await;
+
+        let deltas: Vec<String> = chunks
+            .iter()
+            .filter_map(|chunk| match chunk {
+                LanguageModelStreamChunk::Delta(LanguageModelStreamChunkType::Text(text)) => {
+                    Some(text.clone())
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(deltas, ["hello", " there", " world"]);
+        assert!(matches!(
+            chunks.last(),
+            Some(LanguageModelStreamChunk::Done(message))
+                if matches!(&message.content, LanguageModelResponseContentType::Text(text) if text == "hello there world")
+        ));
+    }
+
+    #[test]
+    fn test_chat_templates() {
+        let messages = vec![
+            Message::User(UserMessage::new("Hi")),
+            Message::Assistant("Hello".to_string().into()),
+            Message::User(UserMessage::new("Bye")),
+        ];
+
+        assert_eq!(
+            ChatTemplate::ChatMl.render(Some("Be brief"), &messages),
+            "<|im_start|>system\nBe brief<|im_end|>\n<|im_start|>user\nHi<|im_end|>\n\
+             <|im_start|>assistant\nHello<|im_end|>\n<|im_start|>user\nBye<|im_end|>\n\
+             <|im_start|>assistant\n"
+        );
+        assert_eq!(
+            ChatTemplate::Mistral.render(Some("Be brief"), &messages),
+            "<s>[INST] Be brief\n\nHi [/INST]Hello</s><s>[INST] Bye [/INST]"
+        );
+        assert_eq!(
+            ChatTemplate::Plain.render(None, &messages),
+            "Hi\n\nHello\n\nBye"
+        );
+    }
+
+    #[test]
+    fn test_builder_requires_weights_and_tokenizer() {
+        let result = Candle::builder().tokenizer("tokenizer.json").build();
+        assert!(matches!(result, Err(Error::MissingField(field)) if field == "weights"));
+
+        let result = Candle::builder().gguf("model.gguf").build();
+        assert!(matches!(result, Err(Error::MissingField(field)) if field == "tokenizer"));
+    }
+}
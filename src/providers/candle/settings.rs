@@ -0,0 +1,142 @@
+//! Defines the settings for the candle provider.
+
+use crate::core::Message;
+use crate::core::language_model::LanguageModelResponseContentType;
+use serde::{Deserialize, Serialize};
+
+/// The chat template a conversation is rendered into the model's prompt with.
+///
+/// Chat models are trained on conversations in a specific format. Use the template of
+/// the model's family, or [`Plain`](Self::Plain) for base models.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChatTemplate {
+    /// The ChatML format of Qwen, SmolLM, Phi and others, ending turns with
+    /// `<|im_end|>`.
+    #[default]
+    ChatMl,
+    /// The format of Llama 3 and later, ending turns with `<|eot_id|>`.
+    Llama3,
+    /// The `[INST]` format of Mistral and Llama 2.
+    Mistral,
+    /// The messages' text separated by blank lines, for base models.
+    Plain,
+}
+
+impl ChatTemplate {
+    /// Returns the tokens ending a turn in this template.
+    pub(crate) fn end_tokens(&self) -> &'static [&'static str] {
+        match self {
+            Self::ChatMl => &["<|im_end|>", "<|endoftext|>"],
+            Self::Llama3 => &["<|eot_id|>", "<|end_of_text|>"],
+            Self::Mistral => &["</s>"],
+            Self::Plain => &["</s>", "<|endoftext|>", "<|end_of_text|>"],
+        }
+    }
+
+    /// Returns whether the tokenizer adds its special tokens to the rendered prompt.
+    /// Templates other than [`Plain`](Self::Plain) write them out.
+    pub(crate) fn adds_special_tokens(&self) -> bool {
+        matches!(self, Self::Plain)
+    }
+
+    /// Renders the conversation into a prompt ending where the assistant's reply
+    /// starts. Reasoning, tool calls and other non-text content are left out.
+    pub(crate) fn render(&self, system: Option<&str>, messages: &[Message]) -> String {
+        let mut turns: Vec<(&str, &str)> = Vec::new();
+        if let Some(system) = system {
+            turns.push(("system", system));
+        }
+        for message in messages {
+            match message {
+                Message::System(system) => turns.push(("system", &system.content)),
+                Message::Developer(content) => turns.push(("system", content)),
+                Message::User(user) => turns.push(("user", &user.content)),
+                Message::Assistant(assistant) => {
+                    if let LanguageModelResponseContentType::Text(text) = &assistant.content {
+                        turns.push(("assistant", text));
+                    }
+                }
+                Message::Tool(_) => {}
+            }
+        }
+
+        let mut prompt = String::new();
+        match self {
+            Self::ChatMl => {
+                for (role, content) in turns {
+                    prompt.push_str(&format!("<|im_start|>{role}\n{content}<|im_end|>\n"));
+                }
+                prompt.push_str("<|im_start|>assistant\n");
+            }
+            Self::Llama3 => {
+                prompt.push_str("<|begin_of_text|>");
+                for (role, content) in turns {
+                    prompt.push_str(&format!(
+                        "<|start_header_id|>{role}<|end_header_id|>\n\n{content}<|eot_id|>"
+                    ));
+                }
+                prompt.push_str("<|start_header_id|>assistant<|end_header_id|>\n\n");
+            }
+            Self::Mistral => {
+                // Mistral has no system role, so system prompts lead the next user turn
+                let mut pending_system = String::new();
+                for (role, content) in turns {
+                    match role {
+                        "system" => {
+                            pending_system.push_str(content);
+                            pending_system.push_str("\n\n");
+                        }
+                        "user" => {
+                            let system = std::mem::take(&mut pending_system);
+                            prompt.push_str(&format!("<s>[INST] {system}{content} [/INST]"));
+                        }
+                        _ => prompt.push_str(&format!("{content}</s>")),
+                    }
+                }
+            }
+            Self::Plain => {
+                let contents: Vec<&str> = turns.into_iter().map(|(_, content)| content).collect();
+                prompt = contents.join("\n\n");
+            }
+        }
+        prompt
+    }
+}
+
+/// Settings for the candle provider.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CandleSettings {
+    /// The name of the provider.
+    pub provider_name: String,
+
+    /// The name the model is reported with.
+    pub model_name: String,
+
+    /// The chat template conversations are rendered with.
+    pub chat_template: ChatTemplate,
+
+    /// The maximum number of tokens generated when the request does not set
+    /// `max_output_tokens`.
+    pub max_tokens: usize,
+
+    /// The seed of the sampler when the request does not set one.
+    pub seed: u64,
+
+    /// Extra tokens that end generation, on top of the end of sequence tokens of the
+    /// model and the chat template.
+    pub eos_tokens: Vec<String>,
+}
+
+impl Default for CandleSettings {
+    /// Returns the default settings for the candle provider.
+    fn default() -> Self {
+        Self {
+            provider_name: "candle".to_string(),
+            model_name: "candle".to_string(),
+            chat_template: ChatTemplate::default(),
+            max_tokens: 512,
+            seed: 0,
+            eos_tokens: Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,144 @@
+//! Model weights loaded with candle.
+//!
+//! Quantized GGUF files are loaded with candle's quantized Llama implementation, which
+//! also runs Mistral, Qwen and other models of the Llama architecture converted to
+//! GGUF. Safetensors checkpoints are loaded with its Llama implementation and the
+//! `config.json` of the checkpoint.
+
+use candle_core::quantized::gguf_file;
+use candle_core::{DType, Device, Tensor};
+use candle_transformers::models::{llama, quantized_llama};
+use std::path::{Path, PathBuf};
+
+use crate::error::{Error, Result};
+
+/// The weights of a model, predicting the next token of a sequence.
+pub(crate) trait Weights: Send {
+    /// Returns the logits of the token following `input`, a `(1, len)` tensor of token
+    /// ids starting at `position` of the sequence. A position of `0` starts a new
+    /// sequence, discarding the cached keys and values of the previous one.
+    fn forward(&mut self, input: &Tensor, position: usize) -> candle_core::Result<Tensor>;
+}
+
+/// Where the weights of a model are loaded from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WeightsSource {
+    /// A quantized GGUF file.
+    Gguf(PathBuf),
+    /// Safetensors files of a Llama checkpoint and its `config.json`.
+    Safetensors {
+        /// The safetensors files, e.g. the shards of the checkpoint.
+        files: Vec<PathBuf>,
+        /// The `config.json` of the checkpoint.
+        config: PathBuf,
+    },
+}
+
+/// Loaded weights and what is known about the model from them.
+pub(crate) struct Loaded {
+    pub(crate) weights: Box<dyn Weights>,
+    pub(crate) context_window: Option<usize>,
+    pub(crate) eos_token_ids: Vec<u32>,
+}
+
+impl WeightsSource {
+    /// Loads the weights on `device`.
+    pub(crate) fn load(&self, device: &Device) -> Result<Loaded> {
+        match self {
+            Self::Gguf(path) => load_gguf(path, device),
+            Self::Safetensors { files, config } => load_safetensors(files, config, device),
+        }
+    }
+}
+
+/// Converts a candle error into an SDK error.
+pub(crate) fn candle_error(e: candle_core::Error) -> Error {
+    Error::Other(format!("candle: {e}"))
+}
+
+fn read_error(path: &Path, e: impl std::fmt::Display) -> Error {
+    Error::InvalidInput(format!("Failed to read {}: {e}", path.display()))
+}
+
+fn load_gguf(path: &Path, device: &Device) -> Result<Loaded> {
+    let mut file = std::fs::File::open(path).map_err(|e| read_error(path, e))?;
+    let content = gguf_file::Content::read(&mut file).map_err(|e| read_error(path, e))?;
+    let context_window = content
+        .metadata
+        .get("llama.context_length")
+        .and_then(|value| value.to_u32().ok())
+        .map(|length| length as usize);
+    let eos_token_ids = content
+        .metadata
+        .get("tokenizer.ggml.eos_token_id")
+        .and_then(|value| value.to_u32().ok())
+        .into_iter()
+        .collect();
+    let weights = quantized_llama::ModelWeights::from_gguf(content, &mut file, device)
+        .map_err(candle_error)?;
+
+    Ok(Loaded {
+        weights: Box::new(weights),
+        context_window,
+        eos_token_ids,
+    })
+}
+
+impl Weights for quantized_llama::ModelWeights {
+    fn forward(&mut self, input: &Tensor, position: usize) -> candle_core::Result<Tensor> {
+        quantized_llama::ModelWeights::forward(self, input, position)
+    }
+}
+
+/// A safetensors Llama model with the key-value cache of the sequence being generated.
+struct SafetensorsLlama {
+    model: llama::Llama,
+    config: llama::Config,
+    cache: llama::Cache,
+    dtype: DType,
+    device: Device,
+}
+
+fn load_safetensors(files: &[PathBuf], config: &Path, device: &Device) -> Result<Loaded> {
+    let json = std::fs::read_to_string(config).map_err(|e| read_error(config, e))?;
+    let llama_config: llama::LlamaConfig =
+        serde_json::from_str(&json).map_err(|e| read_error(config, e))?;
+    let config = llama_config.into_config(false);
+    let dtype = if device.is_cpu() {
+        DType::F32
+    } else {
+        DType::BF16
+    };
+
+    // SAFETY: the files are memory mapped, and must not be modified while loaded
+    let vb = unsafe { candle_nn::VarBuilder::from_mmaped_safetensors(files, dtype, device) }
+        .map_err(candle_error)?;
+    let model = llama::Llama::load(vb, &config).map_err(candle_error)?;
+    let cache = llama::Cache::new(true, dtype, &config, device).map_err(candle_error)?;
+    let eos_token_ids = match &config.eos_token_id {
+        Some(llama::LlamaEosToks::Single(id)) => vec![*id],
+        Some(llama::LlamaEosToks::Multiple(ids)) => ids.clone(),
+        None => Vec::new(),
+    };
+
+    Ok(Loaded {
+        context_window: Some(config.max_position_embeddings),
+        eos_token_ids,
+        weights: Box::new(SafetensorsLlama {
+            model,
+            config,
+            cache,
+            dtype,
+            device: device.clone(),
+        }),
+    })
+}
+
+impl Weights for SafetensorsLlama {
+    fn forward(&mut self, input: &Tensor, position: usize) -> candle_core::Result<Tensor> {
+        if position == 0 {
+            self.cache = llama::Cache::new(true, self.dtype, &self.config, &self.device)?;
+        }
+        self.model.forward(input, position, &mut self.cache)
+    }
+}
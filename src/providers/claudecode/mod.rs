@@ -6,11 +6,15 @@
 
 use crate::core::DynamicModel;
 use crate::core::capabilities::ModelName;
-use crate::core::client::LanguageModelClient;
+use crate::core::client::{LanguageModelClient, RetryPolicy, TransportSettings};
 use crate::core::utils::validate_base_url;
 use crate::error::Error;
+use crate::providers::anthropic::conversions::{
+    STRUCTURED_OUTPUT_TOOL, refusal, structured_output_content,
+};
 use crate::providers::anthropic::{
-    ANTHROPIC_API_VERSION, client::AnthropicOptions, settings::AnthropicProviderSettings,
+    ANTHROPIC_API_VERSION, ANTHROPIC_TOKEN_EFFICIENT_TOOLS_BETA, client::AnthropicOptions,
+    settings::AnthropicProviderSettings,
 };
 use reqwest::header::CONTENT_TYPE;
 use serde::Serialize;
@@ -98,6 +102,13 @@ where
 {
 }
 
+impl<M: ModelName> From<ClaudeCode<M>> for crate::core::language_model::boxed::BoxedLanguageModel {
+    /// Boxes a [`ClaudeCode`] language model, to be held with models of other providers.
+    fn from(model: ClaudeCode<M>) -> Self {
+        Box::new(model)
+    }
+}
+
 /// The ClaudeCode provider.
 ///
 /// Behaves identically to `Anthropic` but authenticates using OAuth 2.0:
@@ -142,7 +153,11 @@ impl<M: ModelName> LanguageModelClient for ClaudeCode<M> {
             format!("Bearer {}", self.settings.api_key).parse().unwrap(),
         );
         headers.insert("anthropic-version", ANTHROPIC_API_VERSION.parse().unwrap());
-        headers.insert("anthropic-beta", "oauth-2025-04-20".parse().unwrap());
+        let betas = std::iter::once("oauth-2025-04-20")
+            .chain(self.settings.betas.iter().map(String::as_str))
+            .collect::<Vec<_>>()
+            .join(",");
+        headers.insert("anthropic-beta", betas.parse().unwrap());
         headers
     }
 
@@ -150,6 +165,14 @@ impl<M: ModelName> LanguageModelClient for ClaudeCode<M> {
         Vec::new()
     }
 
+    fn retry_policy(&self) -> RetryPolicy {
+        self.settings.retry_policy.clone()
+    }
+
+    fn transport(&self) -> crate::core::client::TransportSettings {
+        self.settings.transport.clone()
+    }
+
     fn body(&self) -> reqwest::Body {
         let body = serde_json::to_string(&self.options).unwrap();
         reqwest::Body::from(body)
@@ -172,7 +195,7 @@ impl<M: ModelName> LanguageModelClient for ClaudeCode<M> {
 // ---------------------------------------------------------------------------
 
 use crate::core::language_model::{
-    LanguageModel, LanguageModelOptions, LanguageModelResponse, ProviderStream,
+    LanguageModel, LanguageModelOptions, LanguageModelResponse, ProviderStream, SamplingOption,
 };
 use crate::error::Result;
 use async_trait::async_trait;
@@ -183,6 +206,22 @@ impl<M: ModelName> LanguageModel for ClaudeCode<M> {
         self.options.model.clone()
     }
 
+    fn capabilities(&self) -> crate::core::capabilities::Capabilities {
+        M::CAPABILITIES
+    }
+
+    fn info(&self) -> crate::core::capabilities::ModelInfo {
+        M::INFO
+    }
+
+    fn unsupported_options(&self) -> &'static [SamplingOption] {
+        &[
+            SamplingOption::Seed,
+            SamplingOption::PresencePenalty,
+            SamplingOption::FrequencyPenalty,
+        ]
+    }
+
     async fn generate_text(
         &mut self,
         options: LanguageModelOptions,
@@ -190,7 +229,10 @@ impl<M: ModelName> LanguageModel for ClaudeCode<M> {
         let mut opts: AnthropicOptions = options.into();
         opts.model = self.options.model.clone();
         self.options = opts;
-        self.send(self.settings.base_url.clone()).await.map(|resp| {
+        let (resp, rate_limit) = self
+            .send_with_rate_limit(self.settings.base_url.clone())
+            .await?;
+        let response = {
             // Reuse Anthropic's response-to-LanguageModelResponse mapping by
             // converting through the same fields.
             use crate::core::ToolCallInfo;
@@ -236,11 +278,20 @@ impl<M: ModelName> LanguageModel for ClaudeCode<M> {
                     }
                 }
             }
+            collected.extend(resp.stop_reason.as_ref().and_then(refusal));
             LanguageModelResponse {
-                contents: collected,
+                contents: collected
+                    .into_iter()
+                    .map(structured_output_content)
+                    .collect(),
                 usage: Some(resp.usage.into()),
+                stop_sequence: None,
+                finish_reason: resp.stop_reason.map(Into::into),
+                logprobs: Vec::new(),
+                extensions: Default::default(),
             }
-        })
+        };
+        Ok(response.with_rate_limit(rate_limit))
     }
 
     async fn stream_text(&mut self, options: LanguageModelOptions) -> Result<ProviderStream> {
@@ -249,27 +300,9 @@ impl<M: ModelName> LanguageModel for ClaudeCode<M> {
         opts.model = self.options.model.clone();
         self.options = opts;
 
-        let max_retries = 5;
-        let mut retry_count = 0;
-        let mut wait_time = std::time::Duration::from_secs(1);
-
-        let response = loop {
-            match self.send_and_stream(self.settings.base_url.clone()).await {
-                Ok(stream) => break stream,
-                Err(crate::error::Error::ApiError {
-                    status_code: Some(status),
-                    ..
-                }) if status == reqwest::StatusCode::TOO_MANY_REQUESTS
-                    && retry_count < max_retries =>
-                {
-                    retry_count += 1;
-                    tokio::time::sleep(wait_time).await;
-                    wait_time *= 2;
-                    continue;
-                }
-                Err(e) => return Err(e),
-            }
-        };
+        let (response, rate_limit) = self
+            .send_and_stream_with_rate_limit(self.settings.base_url.clone())
+            .await?;
 
         // Delegate stream parsing to the Anthropic language model by temporarily
         // constructing an Anthropic instance with the same settings and streaming
@@ -277,13 +310,13 @@ impl<M: ModelName> LanguageModel for ClaudeCode<M> {
         use crate::core::ToolCallInfo;
         use crate::core::language_model::{
             LanguageModelResponseContentType, LanguageModelStreamChunk,
-            LanguageModelStreamChunkType,
+            LanguageModelStreamChunkType, Usage, usage_accumulator::UsageAccumulator,
         };
         use crate::core::messages::AssistantMessage;
-        use crate::core::tools::ToolDetails;
+        use crate::core::tools::{ToolCallDelta, ToolDetails};
         use crate::extensions::Extensions;
         use crate::providers::anthropic::client::{
-            AnthropicContentBlock, AnthropicDelta, AnthropicMessageDeltaUsage, AnthropicStreamEvent,
+            AnthropicContentBlock, AnthropicDelta, AnthropicStreamEvent,
         };
         use crate::providers::anthropic::extensions;
         use futures::StreamExt;
@@ -292,7 +325,7 @@ impl<M: ModelName> LanguageModel for ClaudeCode<M> {
         #[derive(Default)]
         struct StreamState {
             content_blocks: HashMap<usize, AccumulatedBlock>,
-            usage: Option<AnthropicMessageDeltaUsage>,
+            usage: UsageAccumulator,
         }
 
         #[derive(Debug)]
@@ -320,9 +353,14 @@ impl<M: ModelName> LanguageModel for ClaudeCode<M> {
                 };
                 futures::future::ready(match evt_res {
                     Ok(event) => match event {
-                        AnthropicStreamEvent::MessageStart { .. } => Some(Ok(vec![
-                            LanguageModelStreamChunk::Delta(LanguageModelStreamChunkType::Start),
-                        ])),
+                        AnthropicStreamEvent::MessageStart { message } => {
+                            let usage: Usage = message.usage.into();
+                            state.usage.observe(&usage);
+                            Some(Ok(vec![
+                                LanguageModelStreamChunk::Delta(LanguageModelStreamChunkType::Start),
+                                LanguageModelStreamChunk::Usage(usage),
+                            ]))
+                        }
                         AnthropicStreamEvent::ContentBlockStart { index, content_block } => {
                             match content_block {
                                 AnthropicContentBlock::Text { .. } => {
@@ -365,9 +403,17 @@ impl<M: ModelName> LanguageModel for ClaudeCode<M> {
                                         *signature = Some(ds);
                                         Some(Ok(unsupported("SignatureDelta")))
                                     }
-                                    (AccumulatedBlock::ToolUse { accumulated_json, .. }, AnthropicDelta::ToolUseDelta { partial_json }) => {
+                                    (AccumulatedBlock::ToolUse { id, name, accumulated_json }, AnthropicDelta::ToolUseDelta { partial_json }) => {
                                         accumulated_json.push_str(&partial_json);
-                                        Some(Ok(vec![LanguageModelStreamChunk::Delta(LanguageModelStreamChunkType::ToolCall(partial_json))]))
+                                        let chunk = if name == STRUCTURED_OUTPUT_TOOL {
+                                            LanguageModelStreamChunk::Delta(LanguageModelStreamChunkType::Text(partial_json))
+                                        } else {
+                                            LanguageModelStreamChunk::ToolCallDelta(ToolCallDelta {
+                                                tool: ToolDetails { name: name.clone(), id: id.clone() },
+                                                delta: partial_json,
+                                            })
+                                        };
+                                        Some(Ok(vec![chunk]))
                                     }
                                     _ => Some(Ok(unsupported("ContentBlockDelta"))),
                                 }
@@ -376,9 +422,20 @@ impl<M: ModelName> LanguageModel for ClaudeCode<M> {
                             }
                         }
                         AnthropicStreamEvent::ContentBlockStop { .. } => Some(Ok(unsupported("ContentBlockStop"))),
-                        AnthropicStreamEvent::MessageDelta { usage, .. } => {
-                            state.usage = Some(usage);
-                            Some(Ok(unsupported("MessageDelta")))
+                        AnthropicStreamEvent::MessageDelta { delta, usage } => {
+                            let usage: Usage = usage.into();
+                            state.usage.observe(&usage);
+                            let mut chunks = vec![LanguageModelStreamChunk::Usage(usage)];
+                            if let Some(reason) = delta.stop_reason {
+                                chunks.push(LanguageModelStreamChunk::FinishReason(reason.clone().into()));
+                                if let Some(content) = refusal(&reason) {
+                                    chunks.push(LanguageModelStreamChunk::Done(AssistantMessage {
+                                        content,
+                                        usage: None,
+                                    }));
+                                }
+                            }
+                            Some(Ok(chunks))
                         }
                         AnthropicStreamEvent::MessageStop => {
                             let mut collected = vec![];
@@ -419,10 +476,10 @@ impl<M: ModelName> LanguageModel for ClaudeCode<M> {
                                     }
                                 }
                             }
-                            Some(Ok(collected.into_iter().map(|ref c| {
+                            Some(Ok(collected.into_iter().map(structured_output_content).map(|ref c| {
                                 LanguageModelStreamChunk::Done(AssistantMessage {
                                     content: c.clone(),
-                                    usage: state.usage.clone().map(|u| u.into()),
+                                    usage: Some(state.usage.total()),
                                 })
                             }).collect()))
                         }
@@ -438,7 +495,10 @@ impl<M: ModelName> LanguageModel for ClaudeCode<M> {
             },
         );
 
-        Ok(Box::pin(stream))
+        Ok(crate::core::language_model::stream_with_rate_limit(
+            rate_limit,
+            Box::pin(stream),
+        ))
     }
 }
 
@@ -494,6 +554,9 @@ fn default_settings() -> AnthropicProviderSettings {
             .or_else(|_| std::env::var("ANTHROPIC_API_KEY"))
             .unwrap_or_default(),
         path: None,
+        betas: Vec::new(),
+        retry_policy: RetryPolicy::default(),
+        transport: TransportSettings::default(),
     }
 }
 
@@ -544,6 +607,22 @@ impl<M: ModelName> ClaudeCodeBuilder<M> {
         self
     }
 
+    /// Sets the retry policy applied to requests made by this provider.
+    ///
+    /// # Parameters
+    ///
+    /// * `retry_policy` - The [`RetryPolicy`] to use for retryable failures.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the retry policy set.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.settings.retry_policy = retry_policy;
+        self
+    }
+
+    crate::core::client::transport_builder_methods!();
+
     /// Sets the name of the provider. Defaults to `"claudecode"`.
     pub fn provider_name(mut self, provider_name: impl Into<String>) -> Self {
         self.settings.provider_name = provider_name.into();
@@ -556,6 +635,20 @@ impl<M: ModelName> ClaudeCodeBuilder<M> {
         self
     }
 
+    /// Enables or disables the token-efficient tool use beta. The flag is sent
+    /// alongside the OAuth beta in the `anthropic-beta` header.
+    pub fn token_efficient_tools(mut self, enabled: bool) -> Self {
+        self.settings
+            .betas
+            .retain(|b| b != ANTHROPIC_TOKEN_EFFICIENT_TOOLS_BETA);
+        if enabled {
+            self.settings
+                .betas
+                .push(ANTHROPIC_TOKEN_EFFICIENT_TOOLS_BETA.to_string());
+        }
+        self
+    }
+
     /// Builds the ClaudeCode provider.
     pub fn build(self) -> Result<ClaudeCode<M>> {
         let base_url = validate_base_url(&self.settings.base_url)?;
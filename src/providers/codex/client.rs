@@ -2,7 +2,11 @@
 
 pub(crate) use crate::providers::openai::client::types::*;
 
-use crate::core::client::LanguageModelClient;
+use crate::core::client::{
+    LanguageModelClient, RetryPolicy, Transport, record_raw_event, response_error, sse_error,
+};
+use crate::core::rate_limit::RateLimitInfo;
+use crate::core::runtime::assume_send;
 use crate::core::utils::join_url;
 use crate::error::Error;
 use crate::providers::codex::Codex;
@@ -47,6 +51,14 @@ impl<M: ModelName> LanguageModelClient for Codex<M> {
         Vec::new()
     }
 
+    fn retry_policy(&self) -> RetryPolicy {
+        self.settings.retry_policy.clone()
+    }
+
+    fn transport(&self) -> crate::core::client::TransportSettings {
+        self.settings.transport.clone()
+    }
+
     fn body(&self) -> reqwest::Body {
         let mut body = serde_json::to_value(&self.lm_options).unwrap_or_else(|_| json!({}));
 
@@ -82,16 +94,7 @@ impl<M: ModelName> LanguageModelClient for Codex<M> {
                         .unwrap_or(OpenAiStreamEvent::NotSupported(msg.data)))
                 }
             },
-            Err(e) => {
-                let status_code = match &e {
-                    reqwest_eventsource::Error::InvalidStatusCode(status, _) => Some(*status),
-                    _ => None,
-                };
-                Err(Error::ApiError {
-                    status_code,
-                    details: e.to_string(),
-                })
-            }
+            Err(e) => Err(sse_error(e)),
         }
     }
 
@@ -101,17 +104,18 @@ impl<M: ModelName> LanguageModelClient for Codex<M> {
             || matches!(event, OpenAiStreamEvent::ResponseError { .. })
     }
 
-    async fn send_and_stream(
+    async fn send_and_stream_with_rate_limit(
         &self,
         base_url: impl IntoUrl,
-    ) -> crate::error::Result<
+    ) -> crate::error::Result<(
         Pin<Box<dyn Stream<Item = crate::error::Result<Self::StreamEvent>> + Send>>,
-    >
+        Option<RateLimitInfo>,
+    )>
     where
         Self::StreamEvent: Send + 'static,
         Self: Sync,
     {
-        let client = reqwest::Client::new();
+        let transport = Transport::new(self.transport());
         let url = join_url(base_url, &self.path())?;
         let method = self.method();
         let headers = self.headers();
@@ -119,37 +123,44 @@ impl<M: ModelName> LanguageModelClient for Codex<M> {
         let body = self.body();
         let body_bytes = body.as_bytes().map_or_else(Vec::new, |b| b.to_vec());
 
-        let response = client
+        let request = transport
+            .client
             .request(method.clone(), url.clone())
             .headers(headers.clone())
             .query(&query_params)
-            .body(reqwest::Body::from(body_bytes.clone()))
-            .send()
-            .await
-            .map_err(|e| Error::ApiError {
-                status_code: e.status(),
-                details: format!("SSE stream request failed: {e}"),
-            })?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let text = response
-                .text()
-                .await
-                .unwrap_or_else(|err| format!("<failed to read body: {err}>"));
-            return Err(Error::ApiError {
-                status_code: Some(status),
-                details: text,
-            });
-        }
+            .body(reqwest::Body::from(body_bytes.clone()));
+        // A rejected response is held while its body is read, and is not `Send` on wasm32
+        let response = assume_send(async {
+            let response = transport
+                .send(request)
+                .await?
+                .map_err(|e| Error::ApiError {
+                    status_code: e.status(),
+                    details: format!("SSE stream request failed: {e}"),
+                })?;
+            if !response.status().is_success() {
+                return Err(response_error(response).await);
+            }
+            Ok(response)
+        })
+        .await?;
+        let rate_limit = RateLimitInfo::from_headers(response.headers());
 
-        let (tx, rx) = mpsc::unbounded_channel::<crate::error::Result<OpenAiStreamEvent>>();
-        let mut bytes = response.bytes_stream();
+        // Events are sent with their payloads, recorded where the stream is polled
+        let (tx, rx) =
+            mpsc::unbounded_channel::<(crate::error::Result<OpenAiStreamEvent>, Option<String>)>();
+        let mut bytes = assume_send(response.bytes_stream());
 
-        tokio::spawn(async move {
+        crate::core::runtime::spawn(async move {
             let mut buffer = String::new();
             loop {
-                match bytes.next().await {
+                // Stop reading once the stream is dropped, e.g. because the call was
+                // interrupted, which closes the connection
+                let next = tokio::select! {
+                    next = bytes.next() => next,
+                    _ = tx.closed() => return,
+                };
+                match next {
                     Some(Ok(chunk)) => {
                         let s = String::from_utf8_lossy(&chunk);
                         buffer.push_str(&s);
@@ -170,7 +181,12 @@ impl<M: ModelName> LanguageModelClient for Codex<M> {
                                 continue;
                             }
 
-                            let data = data_lines.join("\n");
+                            let mut data = data_lines.join("\n");
+                            if let Err(e) = transport.stream_event(&mut data) {
+                                let _ = tx.send((Err(e), None));
+                                return;
+                            }
+                            let payload = data.clone();
 
                             let event = if data.trim() == "[DONE]" || data.trim().is_empty() {
                                 OpenAiStreamEvent::NotSupported("[END]".to_string())
@@ -179,7 +195,7 @@ impl<M: ModelName> LanguageModelClient for Codex<M> {
                                     .unwrap_or(OpenAiStreamEvent::NotSupported(data))
                             };
 
-                            if tx.send(Ok(event.clone())).is_err() {
+                            if tx.send((Ok(event.clone()), Some(payload))).is_err() {
                                 return;
                             }
                             if matches!(
@@ -192,22 +208,30 @@ impl<M: ModelName> LanguageModelClient for Codex<M> {
                         }
                     }
                     Some(Err(e)) => {
-                        let _ = tx.send(Err(Error::ApiError {
-                            status_code: None,
-                            details: format!("SSE body stream error: {e}"),
-                        }));
+                        let _ = tx.send((
+                            Err(Error::ApiError {
+                                status_code: None,
+                                details: format!("SSE body stream error: {e}"),
+                            }),
+                            None,
+                        ));
                         return;
                     }
                     None => {
                         if !buffer.trim().is_empty() {
-                            let trailing = buffer.trim().to_string();
+                            let mut trailing = buffer.trim().to_string();
+                            if let Err(e) = transport.stream_event(&mut trailing) {
+                                let _ = tx.send((Err(e), None));
+                                return;
+                            }
+                            let payload = trailing.clone();
                             let event = if trailing == "[DONE]" {
                                 OpenAiStreamEvent::NotSupported("[END]".to_string())
                             } else {
                                 serde_json::from_str::<OpenAiStreamEvent>(&trailing)
                                     .unwrap_or(OpenAiStreamEvent::NotSupported(trailing))
                             };
-                            let _ = tx.send(Ok(event));
+                            let _ = tx.send((Ok(event), Some(payload)));
                         }
                         return;
                     }
@@ -216,9 +240,14 @@ impl<M: ModelName> LanguageModelClient for Codex<M> {
         });
 
         let event_stream = stream::unfold(rx, |mut rx| async {
-            rx.recv().await.map(|item| (item, rx))
+            rx.recv().await.map(|(item, payload)| {
+                if let Some(payload) = payload {
+                    record_raw_event(&payload);
+                }
+                (item, rx)
+            })
         });
 
-        Ok(Box::pin(event_stream))
+        Ok((Box::pin(event_stream), rate_limit))
     }
 }
@@ -4,18 +4,24 @@ use crate::core::capabilities::ModelName;
 use crate::core::client::LanguageModelClient;
 use crate::core::language_model::{
     LanguageModelOptions, LanguageModelResponse, LanguageModelResponseContentType,
-    LanguageModelStreamChunk, LanguageModelStreamChunkType, ProviderStream, Usage,
+    LanguageModelStreamChunk, LanguageModelStreamChunkType, ProviderStream, SamplingOption, Usage,
+    stream_with_rate_limit,
 };
 use crate::core::messages::AssistantMessage;
 use crate::providers::codex::{Codex, client};
 use crate::providers::openai::client::OpenAILanguageModelOptions;
 use crate::providers::openai::client::types;
+use crate::providers::openai::conversions;
 use crate::{
-    core::{language_model::LanguageModel, tools::ToolCallInfo},
+    core::{
+        language_model::LanguageModel,
+        tools::{ToolCallDelta, ToolCallInfo, ToolDetails},
+    },
     error::{Error, Result},
 };
 use async_trait::async_trait;
 use futures::StreamExt;
+use std::collections::HashMap;
 
 #[async_trait]
 impl<M: ModelName> LanguageModel for Codex<M> {
@@ -24,6 +30,24 @@ impl<M: ModelName> LanguageModel for Codex<M> {
         self.lm_options.model.clone()
     }
 
+    fn capabilities(&self) -> crate::core::capabilities::Capabilities {
+        M::CAPABILITIES
+    }
+
+    fn info(&self) -> crate::core::capabilities::ModelInfo {
+        Self::model_info(&self.name())
+    }
+
+    fn unsupported_options(&self) -> &'static [SamplingOption] {
+        &[
+            SamplingOption::Seed,
+            SamplingOption::TopK,
+            SamplingOption::StopSequences,
+            SamplingOption::PresencePenalty,
+            SamplingOption::FrequencyPenalty,
+        ]
+    }
+
     /// Generates text using the Codex provider.
     async fn generate_text(
         &mut self,
@@ -40,120 +64,134 @@ impl<M: ModelName> LanguageModel for Codex<M> {
 
         options.model = self.lm_options.model.to_string();
         options.stream = Some(true);
+        options.set_reasoning_summary(self.settings.reasoning_summary.map(Into::into));
 
         self.lm_options = options;
 
-        let max_retries = 5;
-        let mut retry_count = 0;
-        let mut wait_time = std::time::Duration::from_secs(1);
+        let (codex_stream, rate_limit) = self
+            .send_and_stream_with_rate_limit(&self.settings.base_url)
+            .await?;
 
-        let codex_stream = loop {
-            match self.send_and_stream(&self.settings.base_url).await {
-                Ok(stream) => break stream,
-                Err(crate::error::Error::ApiError {
-                    status_code: Some(status),
+        // The tool calls announced so far, by output item id
+        let stream = codex_stream.scan(HashMap::new(), |calls, evt_res| {
+            futures::future::ready(Some(match evt_res {
+                Ok(client::OpenAiStreamEvent::ResponseOutputItemAdded {
+                    item:
+                        types::MessageItem::FunctionCall {
+                            call_id,
+                            name,
+                            id: Some(item_id),
+                            ..
+                        },
                     ..
-                }) if status == reqwest::StatusCode::TOO_MANY_REQUESTS
-                    && retry_count < max_retries =>
-                {
-                    retry_count += 1;
-                    tokio::time::sleep(wait_time).await;
-                    wait_time *= 2;
-                    continue;
+                }) => {
+                    calls.insert(item_id, ToolDetails { name, id: call_id });
+                    Ok(vec![])
                 }
-                Err(e) => return Err(e),
-            }
-        };
-
-        let stream = codex_stream.map(|evt_res| match evt_res {
-            Ok(client::OpenAiStreamEvent::ResponseOutputTextDelta { delta, .. }) => {
-                Ok(vec![LanguageModelStreamChunk::Delta(
-                    LanguageModelStreamChunkType::Text(delta),
-                )])
-            }
-            Ok(client::OpenAiStreamEvent::ResponseReasoningSummaryTextDelta { delta, .. }) => {
-                Ok(vec![LanguageModelStreamChunk::Delta(
+                Ok(client::OpenAiStreamEvent::ResponseOutputTextDelta { delta, .. }) => {
+                    Ok(vec![LanguageModelStreamChunk::Delta(
+                        LanguageModelStreamChunkType::Text(delta),
+                    )])
+                }
+                Ok(client::OpenAiStreamEvent::ResponseReasoningSummaryTextDelta {
+                    delta, ..
+                }) => Ok(vec![LanguageModelStreamChunk::Delta(
                     LanguageModelStreamChunkType::Reasoning(delta),
-                )])
-            }
-            Ok(client::OpenAiStreamEvent::ResponseCompleted { response, .. }) => {
-                let mut result: Vec<LanguageModelStreamChunk> = Vec::new();
-
-                let usage: Usage = response.usage.unwrap_or_default().into();
-                let output = response.output.unwrap_or_default();
-
-                for msg in output {
-                    match &msg {
-                        types::MessageItem::OutputMessage { content, .. } => {
-                            if let Some(types::OutputContent::OutputText { text, .. }) =
-                                content.first()
-                            {
-                                result.push(LanguageModelStreamChunk::Done(AssistantMessage {
-                                    content: LanguageModelResponseContentType::new(text.clone()),
-                                    usage: Some(usage.clone()),
-                                }));
+                )]),
+                Ok(client::OpenAiStreamEvent::ResponseCompleted { response, .. }) => {
+                    let mut result: Vec<LanguageModelStreamChunk> =
+                        vec![LanguageModelStreamChunk::FinishReason(
+                            conversions::finish_reason(&response),
+                        )];
+
+                    let usage: Usage = response.usage.unwrap_or_default().into();
+                    let output = response.output.unwrap_or_default();
+
+                    for msg in output {
+                        match &msg {
+                            types::MessageItem::OutputMessage { content, .. } => {
+                                if let Some(content) = content.first() {
+                                    result.push(LanguageModelStreamChunk::Done(AssistantMessage {
+                                        content: content.clone().into(),
+                                        usage: Some(usage.clone()),
+                                    }));
+                                }
                             }
-                        }
-                        types::MessageItem::Reasoning { summary, .. } => {
-                            if let Some(types::ReasoningSummary { text, .. }) = summary.first() {
+                            types::MessageItem::Reasoning { summary, .. } => {
+                                if let Some(types::ReasoningSummary { text, .. }) = summary.first()
+                                {
+                                    result.push(LanguageModelStreamChunk::Done(AssistantMessage {
+                                        content: LanguageModelResponseContentType::Reasoning {
+                                            content: text.to_owned(),
+                                            extensions: crate::extensions::Extensions::default(),
+                                        },
+                                        usage: Some(usage.clone()),
+                                    }));
+                                }
+                            }
+                            types::MessageItem::FunctionCall {
+                                call_id,
+                                name,
+                                arguments,
+                                ..
+                            } => {
+                                let mut tool_info = ToolCallInfo::new(name.clone());
+                                tool_info.id(call_id.clone());
+                                tool_info
+                                    .input(serde_json::from_str(arguments).unwrap_or_default());
+
                                 result.push(LanguageModelStreamChunk::Done(AssistantMessage {
-                                    content: LanguageModelResponseContentType::Reasoning {
-                                        content: text.to_owned(),
-                                        extensions: crate::extensions::Extensions::default(),
-                                    },
+                                    content: LanguageModelResponseContentType::ToolCall(tool_info),
                                     usage: Some(usage.clone()),
                                 }));
                             }
+                            _ => {}
                         }
-                        types::MessageItem::FunctionCall {
-                            call_id,
-                            name,
-                            arguments,
-                            ..
-                        } => {
-                            let mut tool_info = ToolCallInfo::new(name.clone());
-                            tool_info.id(call_id.clone());
-                            tool_info.input(serde_json::from_str(arguments).unwrap_or_default());
-
-                            result.push(LanguageModelStreamChunk::Done(AssistantMessage {
-                                content: LanguageModelResponseContentType::ToolCall(tool_info),
-                                usage: Some(usage.clone()),
-                            }));
-                        }
-                        _ => {}
                     }
-                }
 
-                Ok(result)
-            }
-            Ok(client::OpenAiStreamEvent::ResponseIncomplete { response, .. }) => {
-                Ok(vec![LanguageModelStreamChunk::Delta(
-                    LanguageModelStreamChunkType::Incomplete(
+                    Ok(result)
+                }
+                Ok(client::OpenAiStreamEvent::ResponseIncomplete { response, .. }) => Ok(vec![
+                    LanguageModelStreamChunk::FinishReason(conversions::finish_reason(&response)),
+                    LanguageModelStreamChunk::Delta(LanguageModelStreamChunkType::Incomplete(
                         response
                             .incomplete_details
                             .map(|d| d.reason)
                             .unwrap_or("Unknown".to_string()),
-                    ),
-                )])
-            }
-            Ok(client::OpenAiStreamEvent::ResponseFunctionCallArgumentsDelta { delta, .. }) => {
-                Ok(vec![LanguageModelStreamChunk::Delta(
-                    LanguageModelStreamChunkType::ToolCall(delta),
-                )])
-            }
-            Ok(client::OpenAiStreamEvent::ResponseFunctionCallArgumentsDone { .. }) => Ok(vec![]),
-            Ok(client::OpenAiStreamEvent::ResponseError { code, message, .. }) => {
-                let reason = format!("{}: {}", code.unwrap_or("unknown".to_string()), message);
-                Ok(vec![LanguageModelStreamChunk::Delta(
-                    LanguageModelStreamChunkType::Failed(reason),
-                )])
-            }
-            Ok(evt) => Ok(vec![LanguageModelStreamChunk::Delta(
-                LanguageModelStreamChunkType::NotSupported(format!("{evt:?}")),
-            )]),
-            Err(e) => Err(e),
+                    )),
+                ]),
+                // Arguments of calls that were not announced are reported with the
+                // completed response
+                Ok(client::OpenAiStreamEvent::ResponseFunctionCallArgumentsDelta {
+                    item_id,
+                    delta,
+                    ..
+                }) => Ok(calls
+                    .get(&item_id)
+                    .map(|tool| {
+                        LanguageModelStreamChunk::ToolCallDelta(ToolCallDelta {
+                            tool: tool.clone(),
+                            delta,
+                        })
+                    })
+                    .into_iter()
+                    .collect()),
+                Ok(client::OpenAiStreamEvent::ResponseFunctionCallArgumentsDone { .. }) => {
+                    Ok(vec![])
+                }
+                Ok(client::OpenAiStreamEvent::ResponseError { code, message, .. }) => {
+                    let reason = format!("{}: {}", code.unwrap_or("unknown".to_string()), message);
+                    Ok(vec![LanguageModelStreamChunk::Delta(
+                        LanguageModelStreamChunkType::Failed(reason),
+                    )])
+                }
+                Ok(evt) => Ok(vec![LanguageModelStreamChunk::Delta(
+                    LanguageModelStreamChunkType::NotSupported(format!("{evt:?}")),
+                )]),
+                Err(e) => Err(e),
+            }))
         });
 
-        Ok(Box::pin(stream))
+        Ok(stream_with_rate_limit(rate_limit, Box::pin(stream)))
     }
 }
@@ -7,9 +7,11 @@ pub mod settings;
 
 use crate::core::DynamicModel;
 use crate::core::capabilities::ModelName;
+use crate::core::client::RetryPolicy;
 use crate::core::utils::validate_base_url;
 use crate::error::Error;
 use crate::providers::codex::settings::CodexProviderSettings;
+use crate::providers::openai::OpenAIReasoningSummary;
 use crate::providers::openai::client::OpenAILanguageModelOptions;
 
 /// The Codex provider.
@@ -114,12 +116,42 @@ impl<M: ModelName> CodexBuilder<M> {
         self
     }
 
+    /// Sets the retry policy applied to requests made by this provider.
+    ///
+    /// # Parameters
+    ///
+    /// * `retry_policy` - The [`RetryPolicy`] to use for retryable failures.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the retry policy set.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.settings.retry_policy = retry_policy;
+        self
+    }
+
+    crate::core::client::transport_builder_methods!();
+
     /// Sets the name of the provider. Defaults to "codex".
     pub fn provider_name(mut self, provider_name: impl Into<String>) -> Self {
         self.settings.provider_name = provider_name.into();
         self
     }
 
+    /// Sets how detailed the reasoning summary is.
+    ///
+    /// # Parameters
+    ///
+    /// * `summary` - The [`OpenAIReasoningSummary`] to request.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the reasoning summary set.
+    pub fn reasoning_summary(mut self, summary: OpenAIReasoningSummary) -> Self {
+        self.settings.reasoning_summary = Some(summary);
+        self
+    }
+
     /// Sets a custom API path, overriding the default ("/responses").
     pub fn path(mut self, path: impl Into<String>) -> Self {
         self.settings.path = Some(path.into());
@@ -1,8 +1,10 @@
 //! Defines the settings for the Codex provider.
 
+use crate::core::client::{RetryPolicy, TransportSettings};
+use crate::providers::openai::OpenAIReasoningSummary;
 use derive_builder::Builder;
 
-#[derive(Debug, Clone, Builder)]
+#[derive(Clone, Builder)]
 #[builder(setter(into), default)]
 /// Settings for the Codex provider.
 pub struct CodexProviderSettings {
@@ -21,6 +23,31 @@ pub struct CodexProviderSettings {
 
     /// Instructions field injected into each request body.
     pub instructions: String,
+
+    /// The retry policy applied to requests.
+    pub retry_policy: RetryPolicy,
+
+    /// The HTTP client, middleware and rate limiter requests go through.
+    pub transport: TransportSettings,
+
+    /// How detailed the reasoning summary is. Only sent when a reasoning effort is
+    /// set, defaults to [`OpenAIReasoningSummary::Auto`].
+    pub reasoning_summary: Option<OpenAIReasoningSummary>,
+}
+
+impl std::fmt::Debug for CodexProviderSettings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CodexProviderSettings")
+            .field("provider_name", &self.provider_name)
+            .field("base_url", &self.base_url)
+            .field("api_key", &"<redacted>")
+            .field("path", &self.path)
+            .field("instructions", &self.instructions)
+            .field("retry_policy", &self.retry_policy)
+            .field("transport", &self.transport)
+            .field("reasoning_summary", &self.reasoning_summary)
+            .finish()
+    }
 }
 
 impl Default for CodexProviderSettings {
@@ -35,6 +62,9 @@ impl Default for CodexProviderSettings {
                 .unwrap_or_default(),
             path: Some("/responses".to_string()),
             instructions: "".to_string(),
+            retry_policy: RetryPolicy::default(),
+            transport: TransportSettings::default(),
+            reasoning_summary: None,
         }
     }
 }
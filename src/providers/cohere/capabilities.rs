@@ -0,0 +1,63 @@
+//! Capabilities for Cohere models.
+//!
+//! This module defines model types and their capabilities for Cohere's Command chat
+//! models and Rerank models. Any other model can be used through
+//! `Cohere::model_name`. Users can implement additional traits on custom models.
+
+use crate::core::capabilities::*;
+use crate::model_capabilities;
+use crate::providers::cohere::Cohere;
+
+model_capabilities! {
+    provider: Cohere,
+    models: {
+        CommandA032025 {
+            model_name: "command-a-03-2025",
+            constructor_name: command_a_03_2025,
+            display_name: "Command A",
+            capabilities: [StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+        },
+        CommandAReasoning082025 {
+            model_name: "command-a-reasoning-08-2025",
+            constructor_name: command_a_reasoning_08_2025,
+            display_name: "Command A Reasoning",
+            capabilities: [ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+        },
+        CommandR082024 {
+            model_name: "command-r-08-2024",
+            constructor_name: command_r_08_2024,
+            display_name: "Command R",
+            capabilities: [StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+        },
+        CommandRPlus082024 {
+            model_name: "command-r-plus-08-2024",
+            constructor_name: command_r_plus_08_2024,
+            display_name: "Command R+",
+            capabilities: [StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+        },
+        CommandR7b122024 {
+            model_name: "command-r7b-12-2024",
+            constructor_name: command_r7b_12_2024,
+            display_name: "Command R7B",
+            capabilities: [StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+        },
+        RerankV35 {
+            model_name: "rerank-v3.5",
+            constructor_name: rerank_v3_5,
+            display_name: "Rerank 3.5",
+            capabilities: [TextInputSupport]
+        },
+        RerankEnglishV30 {
+            model_name: "rerank-english-v3.0",
+            constructor_name: rerank_english_v3_0,
+            display_name: "Rerank English 3.0",
+            capabilities: [TextInputSupport]
+        },
+        RerankMultilingualV30 {
+            model_name: "rerank-multilingual-v3.0",
+            constructor_name: rerank_multilingual_v3_0,
+            display_name: "Rerank Multilingual 3.0",
+            capabilities: [TextInputSupport]
+        }
+    }
+}
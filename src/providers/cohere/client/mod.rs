@@ -0,0 +1,136 @@
+//! Client implementation for the Cohere provider.
+use crate::core::client::{LanguageModelClient, RerankClient, RetryPolicy, sse_error};
+use crate::error::{Error, Result};
+use crate::providers::cohere::{Cohere, ModelName};
+use derive_builder::Builder;
+use reqwest::header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE};
+use reqwest_eventsource::Event;
+use serde::{Deserialize, Serialize};
+
+pub(crate) mod types;
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Builder)]
+#[builder(setter(into), build_fn(error = "Error"))]
+pub(crate) struct CohereOptions {
+    pub(crate) model: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub(crate) request: Option<types::ChatRequest>,
+}
+
+impl CohereOptions {
+    pub(crate) fn builder() -> CohereOptionsBuilder {
+        CohereOptionsBuilder::default()
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub(crate) struct CohereRerankOptions {
+    pub(crate) model: String,
+    pub(crate) request: types::RerankRequest,
+}
+
+impl<M: ModelName> Cohere<M> {
+    fn request_headers(&self) -> reqwest::header::HeaderMap {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(CONTENT_TYPE, "application/json".parse().unwrap());
+        headers.insert(ACCEPT, "application/json".parse().unwrap());
+        headers.insert(
+            AUTHORIZATION,
+            format!("Bearer {}", self.settings.api_key).parse().unwrap(),
+        );
+        headers
+    }
+}
+
+impl<M: ModelName> LanguageModelClient for Cohere<M> {
+    type Response = types::ChatResponse;
+    type StreamEvent = types::StreamEvent;
+
+    fn path(&self) -> String {
+        "chat".to_string()
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn headers(&self) -> reqwest::header::HeaderMap {
+        self.request_headers()
+    }
+
+    fn query_params(&self) -> Vec<(&str, &str)> {
+        Vec::new()
+    }
+
+    fn retry_policy(&self) -> RetryPolicy {
+        self.settings.retry_policy.clone()
+    }
+
+    fn transport(&self) -> crate::core::client::TransportSettings {
+        self.settings.transport.clone()
+    }
+
+    fn body(&self) -> reqwest::Body {
+        if let Some(request) = &self.lm_options.request {
+            let body = serde_json::to_string(request).unwrap();
+            return reqwest::Body::from(body);
+        };
+        reqwest::Body::from("{}")
+    }
+
+    fn parse_stream_sse(
+        event: std::result::Result<Event, reqwest_eventsource::Error>,
+    ) -> Result<Self::StreamEvent> {
+        match event {
+            Ok(Event::Open) => Ok(types::StreamEvent::NotSupported),
+            Ok(Event::Message(msg)) => {
+                if msg.data.trim() == "[DONE]" || msg.data.is_empty() {
+                    return Ok(types::StreamEvent::NotSupported);
+                }
+                serde_json::from_str(&msg.data).map_err(|e| Error::ApiError {
+                    status_code: None,
+                    details: format!("Invalid JSON in SSE data: {e}"),
+                })
+            }
+            Err(e) => Err(sse_error(e)),
+        }
+    }
+
+    fn end_stream(event: &Self::StreamEvent) -> bool {
+        matches!(event, types::StreamEvent::MessageEnd { .. })
+    }
+}
+
+impl<M: ModelName> RerankClient for Cohere<M> {
+    type Response = types::RerankResponse;
+
+    fn path(&self) -> String {
+        "rerank".to_string()
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn headers(&self) -> reqwest::header::HeaderMap {
+        self.request_headers()
+    }
+
+    fn query_params(&self) -> Vec<(&str, &str)> {
+        Vec::new()
+    }
+
+    fn retry_policy(&self) -> RetryPolicy {
+        self.settings.retry_policy.clone()
+    }
+
+    fn transport(&self) -> crate::core::client::TransportSettings {
+        self.settings.transport.clone()
+    }
+
+    fn body(&self) -> reqwest::Body {
+        let body = serde_json::to_string(&self.rerank_options.request).unwrap();
+        reqwest::Body::from(body)
+    }
+}
@@ -0,0 +1,325 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::providers::cohere::settings::CohereDocument;
+
+// ============================================================================
+// Section: chat
+// ============================================================================
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct ChatRequest {
+    pub(crate) model: String,
+    pub(crate) messages: Vec<ChatMessage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) tools: Option<Vec<Tool>>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(crate) documents: Vec<CohereDocument>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) response_format: Option<ResponseFormat>,
+    pub(crate) stream: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) max_tokens: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) p: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) k: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) seed: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) stop_sequences: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) frequency_penalty: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) presence_penalty: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) thinking: Option<Thinking>,
+    /// Provider-specific fields merged into the request body.
+    #[serde(flatten, skip_serializing_if = "Map::is_empty")]
+    pub(crate) provider_options: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum Role {
+    System,
+    #[default]
+    User,
+    Assistant,
+    Tool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct ChatMessage {
+    pub(crate) role: Role,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) content: Vec<Content>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) tool_plan: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) tool_calls: Vec<ToolCall>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) tool_call_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) citations: Vec<CohereCitation>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub(crate) enum Content {
+    Text {
+        text: String,
+    },
+    Thinking {
+        thinking: String,
+    },
+    Document {
+        document: CohereDocument,
+    },
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct ToolCall {
+    #[serde(default)]
+    pub(crate) id: String,
+    #[serde(rename = "type", default = "function_type")]
+    pub(crate) type_: String,
+    #[serde(default)]
+    pub(crate) function: FunctionCall,
+}
+
+fn function_type() -> String {
+    "function".to_string()
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct FunctionCall {
+    #[serde(default)]
+    pub(crate) name: String,
+    /// The arguments as a JSON encoded string.
+    #[serde(default)]
+    pub(crate) arguments: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct Tool {
+    #[serde(rename = "type")]
+    pub(crate) type_: String,
+    pub(crate) function: FunctionDefinition,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct FunctionDefinition {
+    pub(crate) name: String,
+    pub(crate) description: String,
+    pub(crate) parameters: Value,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct ResponseFormat {
+    #[serde(rename = "type")]
+    pub(crate) type_: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) json_schema: Option<Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct Thinking {
+    #[serde(rename = "type")]
+    pub(crate) type_: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) token_budget: Option<u32>,
+}
+
+/// A span of a Cohere response grounded in one or more sources.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CohereCitation {
+    /// The offset of the first cited character in the response text.
+    #[serde(default)]
+    pub start: usize,
+    /// The offset after the last cited character in the response text.
+    #[serde(default)]
+    pub end: usize,
+    /// The cited text.
+    #[serde(default)]
+    pub text: String,
+    /// The sources supporting the cited text.
+    #[serde(default)]
+    pub sources: Vec<CohereCitationSource>,
+}
+
+/// A source supporting a [`CohereCitation`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum CohereCitationSource {
+    /// A document sent with the request.
+    Document {
+        /// The document identifier.
+        #[serde(default)]
+        id: Option<String>,
+        /// The document fields.
+        #[serde(default)]
+        document: Map<String, Value>,
+    },
+    /// The output of a tool call.
+    Tool {
+        /// The tool call identifier.
+        #[serde(default)]
+        id: Option<String>,
+        /// The tool output.
+        #[serde(default)]
+        tool_output: Map<String, Value>,
+    },
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct ChatResponse {
+    #[serde(default)]
+    pub(crate) id: String,
+    #[serde(default)]
+    pub(crate) finish_reason: Option<String>,
+    pub(crate) message: ChatMessage,
+    #[serde(default)]
+    pub(crate) usage: Option<ChatUsage>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct ChatUsage {
+    #[serde(default)]
+    pub(crate) billed_units: Option<TokenCounts>,
+    #[serde(default)]
+    pub(crate) tokens: Option<TokenCounts>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct TokenCounts {
+    #[serde(default)]
+    pub(crate) input_tokens: Option<f64>,
+    #[serde(default)]
+    pub(crate) output_tokens: Option<f64>,
+}
+
+// ============================================================================
+// Section: streaming
+// ============================================================================
+
+/// A server-sent event of a streamed `/chat` response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "kebab-case")]
+pub(crate) enum StreamEvent {
+    MessageStart {
+        #[serde(default)]
+        id: Option<String>,
+    },
+    ContentStart {
+        #[serde(default)]
+        index: usize,
+        delta: StreamDelta,
+    },
+    ContentDelta {
+        #[serde(default)]
+        index: usize,
+        delta: StreamDelta,
+    },
+    ContentEnd {
+        #[serde(default)]
+        index: usize,
+    },
+    ToolPlanDelta {
+        delta: StreamDelta,
+    },
+    ToolCallStart {
+        #[serde(default)]
+        index: usize,
+        delta: StreamDelta,
+    },
+    ToolCallDelta {
+        #[serde(default)]
+        index: usize,
+        delta: StreamDelta,
+    },
+    ToolCallEnd {
+        #[serde(default)]
+        index: usize,
+    },
+    CitationStart {
+        #[serde(default)]
+        index: usize,
+        delta: StreamDelta,
+    },
+    CitationEnd {
+        #[serde(default)]
+        index: usize,
+    },
+    MessageEnd {
+        delta: MessageEndDelta,
+    },
+    #[serde(other)]
+    NotSupported,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct StreamDelta {
+    #[serde(default)]
+    pub(crate) message: StreamDeltaMessage,
+}
+
+/// The partial message carried by a stream event. Unlike complete messages, its
+/// `content`, `tool_calls`, and `citations` hold a single element.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct StreamDeltaMessage {
+    #[serde(default)]
+    pub(crate) content: Option<StreamContent>,
+    #[serde(default)]
+    pub(crate) tool_plan: Option<String>,
+    #[serde(default)]
+    pub(crate) tool_calls: Option<ToolCall>,
+    #[serde(default)]
+    pub(crate) citations: Option<CohereCitation>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct StreamContent {
+    #[serde(default)]
+    pub(crate) text: Option<String>,
+    #[serde(default)]
+    pub(crate) thinking: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct MessageEndDelta {
+    #[serde(default)]
+    pub(crate) finish_reason: Option<String>,
+    #[serde(default)]
+    pub(crate) usage: Option<ChatUsage>,
+    #[serde(default)]
+    pub(crate) error: Option<String>,
+}
+
+// ============================================================================
+// Section: rerank
+// ============================================================================
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct RerankRequest {
+    pub(crate) model: String,
+    pub(crate) query: String,
+    pub(crate) documents: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) top_n: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct RerankResponse {
+    pub(crate) results: Vec<RerankResult>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct RerankResult {
+    pub(crate) index: usize,
+    pub(crate) relevance_score: f32,
+}
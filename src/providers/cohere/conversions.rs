@@ -0,0 +1,388 @@
+//! Conversions between types used by the Cohere provider and the types used by the core library.
+use crate::core::language_model::{
+    FinishReason, LanguageModelOptions, LanguageModelResponseContentType, Usage,
+};
+use crate::core::messages::{Message, TaggedMessage};
+use crate::core::rerank_model::RerankModelOptions;
+use crate::core::tools::{Tool, ToolCallInfo, ToolDetails};
+use crate::extensions::Extensions;
+use crate::providers::cohere::client::types::{
+    self, ChatMessage, ChatRequest, ChatUsage, Content, FunctionDefinition, RerankRequest,
+    ResponseFormat, Role, Thinking,
+};
+use crate::providers::cohere::extensions::CohereToolPlanMetadata;
+use serde_json::Value;
+
+impl From<Tool> for types::Tool {
+    fn from(value: Tool) -> Self {
+        let mut parameters = value.input_schema.to_value();
+        if let Some(obj) = parameters.as_object_mut() {
+            obj.remove("$schema");
+        }
+
+        Self {
+            type_: "function".to_string(),
+            function: FunctionDefinition {
+                name: value.name,
+                description: value.description,
+                parameters,
+            },
+        }
+    }
+}
+
+impl From<LanguageModelOptions> for ChatRequest {
+    fn from(options: LanguageModelOptions) -> Self {
+        let mut messages: Vec<ChatMessage> = Vec::new();
+        if let Some(system) = options.system {
+            messages.push(ChatMessage {
+                role: Role::System,
+                content: vec![Content::Text { text: system }],
+                ..Default::default()
+            });
+        }
+        for tagged in options.messages {
+            push_message(&mut messages, tagged);
+        }
+
+        let tools = options.tools.map(|t| {
+            let tools_list = t.tools.lock().unwrap_or_else(|p| p.into_inner());
+            tools_list.iter().map(|tool| tool.clone().into()).collect()
+        });
+
+        let response_format = options.schema.map(|s| {
+            let mut schema = serde_json::to_value(s).unwrap_or_default();
+            if let Some(obj) = schema.as_object_mut() {
+                obj.remove("$schema");
+            }
+            ResponseFormat {
+                type_: "json_object".to_string(),
+                json_schema: Some(schema),
+            }
+        });
+
+        Self {
+            model: String::new(), // will be set in language_model.rs
+            messages,
+            tools,
+            documents: Vec::new(),
+            response_format,
+            stream: false,
+            max_tokens: options.max_output_tokens,
+            temperature: options.temperature.map(|t| t as f32 / 100.0),
+            p: options.top_p.map(|t| t as f32 / 100.0),
+            k: options.top_k,
+            seed: options.seed,
+            stop_sequences: options.stop_sequences,
+            frequency_penalty: options.frequency_penalty,
+            presence_penalty: options.presence_penalty,
+            thinking: options.reasoning_effort.map(|_| Thinking {
+                type_: "enabled".to_string(),
+                token_budget: None,
+            }),
+            provider_options: options.provider_options,
+        }
+    }
+}
+
+/// Appends a conversation message. Cohere expects the text, reasoning, and tool calls
+/// of one assistant turn in a single message, so consecutive assistant messages are
+/// merged.
+fn push_message(messages: &mut Vec<ChatMessage>, tagged: TaggedMessage) {
+    let message = match tagged.message {
+        Message::System(s) => ChatMessage {
+            role: Role::System,
+            content: vec![Content::Text { text: s.content }],
+            ..Default::default()
+        },
+        Message::Developer(d) => ChatMessage {
+            role: Role::System,
+            content: vec![Content::Text { text: d }],
+            ..Default::default()
+        },
+        Message::User(u) => ChatMessage {
+            role: Role::User,
+            content: vec![Content::Text { text: u.content }],
+            ..Default::default()
+        },
+        Message::Assistant(a) => {
+            let mut message = ChatMessage {
+                role: Role::Assistant,
+                ..Default::default()
+            };
+            match a.content {
+                LanguageModelResponseContentType::Text(text) => {
+                    message.content.push(Content::Text { text })
+                }
+                LanguageModelResponseContentType::Reasoning {
+                    content,
+                    extensions,
+                } => {
+                    if extensions.get::<CohereToolPlanMetadata>().is_tool_plan {
+                        message.tool_plan = Some(content);
+                    } else {
+                        message
+                            .content
+                            .push(Content::Thinking { thinking: content });
+                    }
+                }
+                LanguageModelResponseContentType::ToolCall(tool_info) => {
+                    message.tool_calls.push(types::ToolCall {
+                        id: tool_info.tool.id,
+                        type_: "function".to_string(),
+                        function: types::FunctionCall {
+                            name: tool_info.tool.name,
+                            arguments: tool_info.input.to_string(),
+                        },
+                    })
+                }
+                LanguageModelResponseContentType::Source(_)
+                | LanguageModelResponseContentType::Image(_)
+                | LanguageModelResponseContentType::Refusal(_)
+                | LanguageModelResponseContentType::NotSupported(_) => return,
+            }
+
+            if let Some(last) = messages.last_mut()
+                && last.role == Role::Assistant
+            {
+                last.content.extend(message.content);
+                last.tool_calls.extend(message.tool_calls);
+                last.tool_plan = last.tool_plan.take().or(message.tool_plan);
+                return;
+            }
+            message
+        }
+        Message::Tool(tool_result) => ChatMessage {
+            role: Role::Tool,
+            content: vec![Content::Text {
+                text: match tool_result.output {
+                    Ok(Value::String(text)) => text,
+                    Ok(value) => value.to_string(),
+                    Err(e) => e.to_string(),
+                },
+            }],
+            tool_call_id: Some(tool_result.tool.id),
+            ..Default::default()
+        },
+    };
+    messages.push(message);
+}
+
+/// Maps a Cohere `finish_reason`.
+pub(crate) fn finish_reason(reason: &str) -> FinishReason {
+    match reason {
+        "COMPLETE" | "STOP_SEQUENCE" => FinishReason::Stop,
+        "MAX_TOKENS" => FinishReason::Length,
+        "TOOL_CALL" => FinishReason::ToolCalls,
+        "ERROR" => FinishReason::Error,
+        other => FinishReason::Other(other.to_string()),
+    }
+}
+
+/// Extracts the token usage of a response, preferring the tokens processed by the
+/// model over the billed units.
+pub(crate) fn usage(usage: &ChatUsage) -> Option<Usage> {
+    let tokens = usage.tokens.as_ref().or(usage.billed_units.as_ref())?;
+    Some(Usage {
+        input_tokens: tokens.input_tokens.map(|t| t as usize),
+        output_tokens: tokens.output_tokens.map(|t| t as usize),
+        reasoning_tokens: None,
+        cached_tokens: None,
+    })
+}
+
+/// Converts a Cohere tool call, whose arguments are JSON encoded.
+pub(crate) fn tool_call(call: &types::ToolCall) -> LanguageModelResponseContentType {
+    let arguments = if call.function.arguments.trim().is_empty() {
+        "{}"
+    } else {
+        call.function.arguments.as_str()
+    };
+    match serde_json::from_str(arguments) {
+        Ok(input) => LanguageModelResponseContentType::ToolCall(ToolCallInfo {
+            input,
+            tool: ToolDetails {
+                id: call.id.clone(),
+                name: call.function.name.clone(),
+            },
+            extensions: Extensions::default(),
+        }),
+        Err(_) => LanguageModelResponseContentType::NotSupported(format!(
+            "Invalid tool json: {}",
+            call.function.arguments
+        )),
+    }
+}
+
+/// Converts a tool plan to reasoning content that is sent back as a tool plan.
+pub(crate) fn tool_plan(plan: String) -> LanguageModelResponseContentType {
+    let extensions = Extensions::default();
+    extensions.get_mut::<CohereToolPlanMetadata>().is_tool_plan = true;
+    LanguageModelResponseContentType::Reasoning {
+        content: plan,
+        extensions,
+    }
+}
+
+/// Converts a chat message to response contents, in the order reasoning, tool plan,
+/// text, tool calls.
+pub(crate) fn message_contents(message: &ChatMessage) -> Vec<LanguageModelResponseContentType> {
+    let mut contents = Vec::new();
+    let mut text = String::new();
+    for content in &message.content {
+        match content {
+            Content::Thinking { thinking } if !thinking.is_empty() => {
+                contents.push(LanguageModelResponseContentType::Reasoning {
+                    content: thinking.clone(),
+                    extensions: Extensions::default(),
+                })
+            }
+            Content::Text { text: part } => text.push_str(part),
+            _ => {}
+        }
+    }
+    if let Some(plan) = message.tool_plan.as_ref().filter(|p| !p.is_empty()) {
+        contents.push(tool_plan(plan.clone()));
+    }
+    if !text.is_empty() {
+        contents.push(LanguageModelResponseContentType::Text(text));
+    }
+    contents.extend(message.tool_calls.iter().map(tool_call));
+    contents
+}
+
+impl From<RerankModelOptions> for RerankRequest {
+    fn from(value: RerankModelOptions) -> Self {
+        RerankRequest {
+            model: String::new(), // will be set in rerank_model.rs
+            query: value.query,
+            documents: value.documents,
+            top_n: value.top_n,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::messages::AssistantMessage;
+    use crate::core::tools::ToolResultInfo;
+    use serde_json::json;
+
+    #[test]
+    fn test_request_maps_sampling_options() {
+        let options = LanguageModelOptions {
+            system: Some("Be brief".to_string()),
+            temperature: Some(30),
+            top_k: Some(40),
+            max_output_tokens: Some(256),
+            messages: vec![TaggedMessage::new(0, Message::User("hi".into()))],
+            ..Default::default()
+        };
+        let request: ChatRequest = options.into();
+
+        assert_eq!(request.messages.len(), 2);
+        assert_eq!(request.messages[0].role, Role::System);
+        assert_eq!(request.max_tokens, Some(256));
+        assert_eq!(request.k, Some(40));
+        assert!((request.temperature.unwrap() - 0.3).abs() < 1e-6);
+        assert!(request.p.is_none());
+    }
+
+    #[test]
+    fn test_assistant_turn_is_merged() {
+        let mut call = ToolCallInfo::new("search");
+        call.id("call_1");
+        call.input(json!({ "q": "rust" }));
+        let mut result = ToolResultInfo::new("search");
+        result.id("call_1");
+        result.output(json!({ "hits": 3 }));
+
+        let options = LanguageModelOptions {
+            messages: vec![
+                TaggedMessage::new(0, Message::User("find rust".into())),
+                TaggedMessage::new(
+                    1,
+                    Message::Assistant(AssistantMessage::new(
+                        tool_plan("Search first".to_string()),
+                        None,
+                    )),
+                ),
+                TaggedMessage::new(
+                    1,
+                    Message::Assistant(AssistantMessage::new(
+                        LanguageModelResponseContentType::Text("Searching".to_string()),
+                        None,
+                    )),
+                ),
+                TaggedMessage::new(
+                    1,
+                    Message::Assistant(AssistantMessage::new(
+                        LanguageModelResponseContentType::ToolCall(call),
+                        None,
+                    )),
+                ),
+                TaggedMessage::new(1, Message::Tool(result)),
+            ],
+            ..Default::default()
+        };
+        let request: ChatRequest = options.into();
+
+        assert_eq!(request.messages.len(), 3);
+        let assistant = &request.messages[1];
+        assert_eq!(
+            assistant.content,
+            vec![Content::Text {
+                text: "Searching".to_string()
+            }]
+        );
+        assert_eq!(assistant.tool_plan.as_deref(), Some("Search first"));
+        assert_eq!(assistant.tool_calls[0].id, "call_1");
+        assert_eq!(
+            assistant.tool_calls[0].function.arguments,
+            r#"{"q":"rust"}"#
+        );
+        let tool = &request.messages[2];
+        assert_eq!(tool.role, Role::Tool);
+        assert_eq!(tool.tool_call_id.as_deref(), Some("call_1"));
+    }
+
+    #[test]
+    fn test_message_contents() {
+        let message: ChatMessage = serde_json::from_value(json!({
+            "role": "assistant",
+            "tool_plan": "I will search",
+            "tool_calls": [{
+                "id": "search_1",
+                "type": "function",
+                "function": { "name": "search", "arguments": "{\"q\":\"rust\"}" }
+            }]
+        }))
+        .unwrap();
+        let contents = message_contents(&message);
+
+        assert_eq!(contents.len(), 2);
+        let LanguageModelResponseContentType::Reasoning { extensions, .. } = &contents[0] else {
+            panic!("expected the tool plan");
+        };
+        assert!(extensions.get::<CohereToolPlanMetadata>().is_tool_plan);
+        let LanguageModelResponseContentType::ToolCall(info) = &contents[1] else {
+            panic!("expected a tool call");
+        };
+        assert_eq!(info.tool.id, "search_1");
+        assert_eq!(info.input, json!({ "q": "rust" }));
+    }
+
+    #[test]
+    fn test_usage_prefers_tokens() {
+        let usage_value: ChatUsage = serde_json::from_value(json!({
+            "billed_units": { "input_tokens": 5, "output_tokens": 2 },
+            "tokens": { "input_tokens": 70, "output_tokens": 2 }
+        }))
+        .unwrap();
+
+        let usage = usage(&usage_value).unwrap();
+        assert_eq!(usage.input_tokens, Some(70));
+        assert_eq!(usage.output_tokens, Some(2));
+    }
+}
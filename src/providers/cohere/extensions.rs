@@ -0,0 +1,16 @@
+//! Cohere-specific metadata for extensions.
+
+use crate::extensions::Extension;
+use serde::{Deserialize, Serialize};
+
+/// Metadata marking reasoning content that Cohere returned as a tool plan.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct CohereToolPlanMetadata {
+    /// Whether the reasoning is the plan the model wrote before calling tools. It is
+    /// sent back as `tool_plan` rather than as thinking content.
+    pub is_tool_plan: bool,
+}
+
+impl Extension for CohereToolPlanMetadata {
+    const NAME: &'static str = "cohere.tool_plan_metadata";
+}
@@ -0,0 +1,212 @@
+//! Language model implementation for the Cohere provider.
+use crate::core::capabilities::ModelName;
+use crate::core::client::LanguageModelClient;
+use crate::core::language_model::{
+    LanguageModel, LanguageModelOptions, LanguageModelResponse, LanguageModelResponseContentType,
+    LanguageModelStreamChunk, LanguageModelStreamChunkType, ProviderStream, stream_with_rate_limit,
+};
+use crate::core::messages::AssistantMessage;
+use crate::core::tools::{ToolCallDelta, ToolDetails};
+use crate::error::Result;
+use crate::extensions::Extensions;
+use crate::providers::cohere::client::types::{self, CohereCitation, StreamEvent};
+use crate::providers::cohere::{Cohere, conversions};
+use async_trait::async_trait;
+use futures::StreamExt;
+use std::collections::BTreeMap;
+
+impl<M: ModelName> Cohere<M> {
+    /// Builds the chat request for `options`, applying the provider settings.
+    fn chat_request(&self, options: LanguageModelOptions, stream: bool) -> types::ChatRequest {
+        let mut request: types::ChatRequest = options.into();
+        request.model = self.lm_options.model.clone();
+        request.stream = stream;
+        request.documents = self.settings.documents.clone();
+        request
+    }
+}
+
+#[async_trait]
+impl<M: ModelName> LanguageModel for Cohere<M> {
+    fn name(&self) -> String {
+        self.lm_options.model.clone()
+    }
+
+    fn capabilities(&self) -> crate::core::capabilities::Capabilities {
+        M::CAPABILITIES
+    }
+
+    fn info(&self) -> crate::core::capabilities::ModelInfo {
+        Self::model_info(&self.name())
+    }
+
+    async fn generate_text(
+        &mut self,
+        options: LanguageModelOptions,
+    ) -> Result<LanguageModelResponse> {
+        self.lm_options.request = Some(self.chat_request(options, false));
+
+        let (response, rate_limit): (types::ChatResponse, _) =
+            self.send_with_rate_limit(&self.settings.base_url).await?;
+
+        self.set_citations(response.message.citations.clone());
+
+        let response = LanguageModelResponse {
+            contents: conversions::message_contents(&response.message),
+            usage: response.usage.as_ref().and_then(conversions::usage),
+            stop_sequence: None,
+            finish_reason: response
+                .finish_reason
+                .as_deref()
+                .map(conversions::finish_reason),
+            logprobs: Vec::new(),
+            extensions: Default::default(),
+        };
+        Ok(response.with_rate_limit(rate_limit))
+    }
+
+    async fn stream_text(&mut self, options: LanguageModelOptions) -> Result<ProviderStream> {
+        self.lm_options.request = Some(self.chat_request(options, true));
+
+        let (cohere_stream, rate_limit) = self
+            .send_and_stream_with_rate_limit(&self.settings.base_url)
+            .await?;
+        let citations = self.citations.clone();
+
+        #[derive(Default)]
+        struct StreamState {
+            text: String,
+            thinking: String,
+            tool_plan: String,
+            tool_calls: BTreeMap<usize, types::ToolCall>,
+            citations: Vec<CohereCitation>,
+        }
+
+        let stream = cohere_stream.scan(StreamState::default(), move |state, evt_res| {
+            futures::future::ready(match evt_res {
+                Ok(event) => {
+                    let mut chunks = Vec::new();
+                    match event {
+                        StreamEvent::MessageStart { .. } => {
+                            chunks.push(LanguageModelStreamChunk::Delta(
+                                LanguageModelStreamChunkType::Start,
+                            ));
+                        }
+                        StreamEvent::ContentStart { delta, .. }
+                        | StreamEvent::ContentDelta { delta, .. } => {
+                            if let Some(content) = delta.message.content {
+                                if let Some(text) = content.text.filter(|t| !t.is_empty()) {
+                                    state.text.push_str(&text);
+                                    chunks.push(LanguageModelStreamChunk::Delta(
+                                        LanguageModelStreamChunkType::Text(text),
+                                    ));
+                                }
+                                if let Some(thinking) = content.thinking.filter(|t| !t.is_empty()) {
+                                    state.thinking.push_str(&thinking);
+                                    chunks.push(LanguageModelStreamChunk::Delta(
+                                        LanguageModelStreamChunkType::Reasoning(thinking),
+                                    ));
+                                }
+                            }
+                        }
+                        StreamEvent::ToolPlanDelta { delta } => {
+                            if let Some(plan) = delta.message.tool_plan {
+                                state.tool_plan.push_str(&plan);
+                                chunks.push(LanguageModelStreamChunk::Delta(
+                                    LanguageModelStreamChunkType::Reasoning(plan),
+                                ));
+                            }
+                        }
+                        StreamEvent::ToolCallStart { index, delta }
+                        | StreamEvent::ToolCallDelta { index, delta } => {
+                            if let Some(fragment) = delta.message.tool_calls {
+                                let call = state.tool_calls.entry(index).or_default();
+                                if !fragment.id.is_empty() {
+                                    call.id = fragment.id;
+                                }
+                                if !fragment.function.name.is_empty() {
+                                    call.function.name = fragment.function.name;
+                                }
+                                let arguments = fragment.function.arguments;
+                                call.function.arguments.push_str(&arguments);
+                                if !arguments.is_empty() {
+                                    chunks.push(LanguageModelStreamChunk::ToolCallDelta(
+                                        ToolCallDelta {
+                                            tool: ToolDetails {
+                                                id: call.id.clone(),
+                                                name: call.function.name.clone(),
+                                            },
+                                            delta: arguments,
+                                        },
+                                    ));
+                                }
+                            }
+                        }
+                        StreamEvent::CitationStart { delta, .. } => {
+                            if let Some(citation) = delta.message.citations {
+                                state.citations.push(citation);
+                            }
+                        }
+                        StreamEvent::MessageEnd { delta } => {
+                            if let Some(reason) = &delta.finish_reason {
+                                chunks.push(LanguageModelStreamChunk::FinishReason(
+                                    conversions::finish_reason(reason),
+                                ));
+                            }
+                            if let Some(error) = delta.error {
+                                chunks.push(LanguageModelStreamChunk::Delta(
+                                    LanguageModelStreamChunkType::Failed(error),
+                                ));
+                            }
+
+                            let usage = delta.usage.as_ref().and_then(conversions::usage);
+                            if let Some(usage) = &usage {
+                                chunks.push(LanguageModelStreamChunk::Usage(usage.clone()));
+                            }
+                            *citations.lock().unwrap_or_else(|p| p.into_inner()) =
+                                std::mem::take(&mut state.citations);
+
+                            let mut contents = Vec::new();
+                            if !state.thinking.is_empty() {
+                                contents.push(LanguageModelResponseContentType::Reasoning {
+                                    content: std::mem::take(&mut state.thinking),
+                                    extensions: Extensions::default(),
+                                });
+                            }
+                            if !state.tool_plan.is_empty() {
+                                contents.push(conversions::tool_plan(std::mem::take(
+                                    &mut state.tool_plan,
+                                )));
+                            }
+                            if !state.text.is_empty() || state.tool_calls.is_empty() {
+                                contents.push(LanguageModelResponseContentType::Text(
+                                    std::mem::take(&mut state.text),
+                                ));
+                            }
+                            contents.extend(
+                                std::mem::take(&mut state.tool_calls)
+                                    .values()
+                                    .map(conversions::tool_call),
+                            );
+
+                            chunks.extend(contents.into_iter().map(|content| {
+                                LanguageModelStreamChunk::Done(AssistantMessage {
+                                    content,
+                                    usage: usage.clone(),
+                                })
+                            }));
+                        }
+                        StreamEvent::ContentEnd { .. }
+                        | StreamEvent::ToolCallEnd { .. }
+                        | StreamEvent::CitationEnd { .. }
+                        | StreamEvent::NotSupported => {}
+                    }
+                    Some(Ok(chunks))
+                }
+                Err(e) => Some(Err(e)),
+            })
+        });
+
+        Ok(stream_with_rate_limit(rate_limit, Box::pin(stream)))
+    }
+}
@@ -0,0 +1,268 @@
+//! This module provides the Cohere provider, which implements the `LanguageModel`,
+//! `RerankModel`, and `Provider` traits for Cohere's Command and Rerank models.
+//!
+//! The provider speaks Cohere's native v2 API (`/chat` and `/rerank`) rather than its
+//! OpenAI-compatible shim, so responses can be grounded in documents and the
+//! citations linking the response text to those documents are available through
+//! [`Cohere::citations`].
+
+pub mod capabilities;
+pub mod client;
+pub mod conversions;
+pub(crate) mod extensions;
+pub mod language_model;
+pub mod rerank_model;
+pub mod settings;
+
+use crate::core::DynamicModel;
+use crate::core::capabilities::ModelName;
+use crate::core::client::RetryPolicy;
+use crate::core::utils::validate_base_url;
+use crate::error::{Error, Result};
+use crate::providers::cohere::client::{CohereOptions, CohereRerankOptions};
+use crate::providers::cohere::settings::{CohereDocument, CohereProviderSettings};
+use serde::Serialize;
+use std::sync::{Arc, Mutex};
+
+pub use client::types::{CohereCitation, CohereCitationSource};
+
+/// The Cohere provider.
+#[derive(Debug, Serialize, Clone)]
+pub struct Cohere<M: ModelName> {
+    /// Configuration settings for the Cohere provider.
+    pub settings: CohereProviderSettings,
+    pub(crate) lm_options: CohereOptions,
+    pub(crate) rerank_options: CohereRerankOptions,
+    /// Citations of the latest chat response, shared between clones.
+    #[serde(skip)]
+    pub(crate) citations: Arc<Mutex<Vec<CohereCitation>>>,
+    _phantom: std::marker::PhantomData<M>,
+}
+
+impl<M: ModelName> Cohere<M> {
+    /// Cohere provider setting builder.
+    pub fn builder() -> CohereBuilder<M> {
+        CohereBuilder::default()
+    }
+
+    /// Returns the citations of the latest chat response generated by this provider
+    /// or any of its clones.
+    ///
+    /// Each citation links a span of the response text to the documents or tool
+    /// outputs it was drawn from. For streamed responses, citations are available once
+    /// the stream has finished.
+    pub fn citations(&self) -> Vec<CohereCitation> {
+        self.citations
+            .lock()
+            .unwrap_or_else(|p| p.into_inner())
+            .clone()
+    }
+
+    pub(crate) fn set_citations(&self, citations: Vec<CohereCitation>) {
+        *self.citations.lock().unwrap_or_else(|p| p.into_inner()) = citations;
+    }
+
+    fn with_model(settings: CohereProviderSettings, model_name: String) -> Self {
+        let options = CohereOptions::builder()
+            .model(model_name.clone())
+            .build()
+            .unwrap();
+        let rerank_options = CohereRerankOptions {
+            model: model_name,
+            ..Default::default()
+        };
+
+        Self {
+            settings,
+            lm_options: options,
+            rerank_options,
+            citations: Arc::default(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl Cohere<DynamicModel> {
+    /// Creates a Cohere provider with a dynamic model name using default settings.
+    ///
+    /// This allows you to specify the model name as a string rather than
+    /// using methods like `Cohere::command_a_03_2025()`.
+    ///
+    /// **WARNING**: when using `DynamicModel`, model capabilities are not validated.
+    /// This means there is no compile-time guarantee that the model supports requested features.
+    ///
+    /// For custom configuration (API key, base URL, etc.), use the builder pattern:
+    /// `Cohere::<DynamicModel>::builder().model_name(...).api_key(...).build()`
+    ///
+    /// # Parameters
+    ///
+    /// * `model_name` - The Cohere model identifier (e.g., "command-a-03-2025", "rerank-v3.5")
+    ///
+    /// # Returns
+    ///
+    /// A configured `Cohere<DynamicModel>` provider instance with default settings.
+    pub fn model_name(name: impl Into<String>) -> Self {
+        Self::with_model(CohereProviderSettings::default(), name.into())
+    }
+}
+
+impl<M: ModelName> Default for Cohere<M> {
+    /// Creates a new Cohere provider with default settings.
+    fn default() -> Self {
+        Self::with_model(CohereProviderSettings::default(), M::MODEL_NAME.to_string())
+    }
+}
+
+/// Cohere Provider Builder
+pub struct CohereBuilder<M: ModelName> {
+    settings: CohereProviderSettings,
+    model_name: String,
+    _phantom: std::marker::PhantomData<M>,
+}
+
+impl<M: ModelName> Default for CohereBuilder<M> {
+    /// Creates a new Cohere provider builder with default settings.
+    fn default() -> Self {
+        Self {
+            settings: CohereProviderSettings::default(),
+            model_name: M::MODEL_NAME.to_string(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl CohereBuilder<DynamicModel> {
+    /// Sets the model name from a string. e.g., "command-a-03-2025", "rerank-v3.5"
+    ///
+    /// **WARNING**: when using `DynamicModel`, model capabilities are not validated.
+    /// This means there is no compile-time guarantee that the model supports requested features.
+    ///
+    /// # Parameters
+    ///
+    /// * `model_name` - The Cohere model identifier.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the model name set.
+    pub fn model_name(mut self, model_name: impl Into<String>) -> Self {
+        self.model_name = model_name.into();
+        self
+    }
+}
+
+impl<M: ModelName> CohereBuilder<M> {
+    /// Sets the base URL for the Cohere API.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.settings.base_url = base_url.into();
+        self
+    }
+
+    /// Sets the API key for the Cohere API.
+    pub fn api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.settings.api_key = api_key.into();
+        self
+    }
+
+    /// Sets the retry policy applied to requests made by this provider.
+    ///
+    /// # Parameters
+    ///
+    /// * `retry_policy` - The [`RetryPolicy`] to use for retryable failures.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the retry policy set.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.settings.retry_policy = retry_policy;
+        self
+    }
+
+    crate::core::client::transport_builder_methods!();
+
+    /// Sets the name of the provider. Defaults to "cohere".
+    pub fn provider_name(mut self, provider_name: impl Into<String>) -> Self {
+        self.settings.provider_name = provider_name.into();
+        self
+    }
+
+    /// Sets the documents sent with every chat request to ground the response. The
+    /// response cites them, see [`Cohere::citations`].
+    pub fn documents(
+        mut self,
+        documents: impl IntoIterator<Item = impl Into<CohereDocument>>,
+    ) -> Self {
+        self.settings.documents = documents.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Builds the Cohere provider.
+    pub fn build(self) -> Result<Cohere<M>> {
+        // validate base url
+        let base_url = validate_base_url(&self.settings.base_url)?;
+
+        // check api key exists
+        if self.settings.api_key.is_empty() {
+            return Err(Error::MissingField("api_key".to_string()));
+        }
+
+        if self.model_name.is_empty() {
+            return Err(Error::MissingField("model_name".to_string()));
+        }
+
+        Ok(Cohere::with_model(
+            CohereProviderSettings {
+                base_url,
+                ..self.settings
+            },
+            self.model_name,
+        ))
+    }
+}
+
+// Re-exports for convenience
+pub use capabilities::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_sets_documents() {
+        let cohere = Cohere::<DynamicModel>::builder()
+            .model_name("command-a-03-2025")
+            .api_key("key")
+            .documents(["Rust is a systems language"])
+            .build()
+            .unwrap();
+
+        assert_eq!(cohere.lm_options.model, "command-a-03-2025");
+        assert_eq!(cohere.rerank_options.model, "command-a-03-2025");
+        assert_eq!(
+            cohere.settings.documents,
+            vec![CohereDocument::new("Rust is a systems language")]
+        );
+    }
+
+    #[test]
+    fn test_builder_requires_api_key() {
+        let result = Cohere::<DynamicModel>::builder()
+            .model_name("command-a-03-2025")
+            .api_key("")
+            .build();
+        assert!(matches!(result, Err(Error::MissingField(_))));
+    }
+
+    #[test]
+    fn test_citations_are_shared_between_clones() {
+        let cohere = Cohere::<DynamicModel>::model_name("command-a-03-2025");
+        let clone = cohere.clone();
+        clone.set_citations(vec![CohereCitation {
+            start: 0,
+            end: 4,
+            text: "Rust".to_string(),
+            sources: Vec::new(),
+        }]);
+
+        assert_eq!(cohere.citations().len(), 1);
+    }
+}
@@ -0,0 +1,39 @@
+//! Rerank model implementation for the Cohere provider.
+
+use crate::{
+    core::{
+        capabilities::ModelName,
+        client::RerankClient,
+        rerank_model::{RerankModel, RerankModelOptions, RerankModelResponse, RerankResult},
+    },
+    error::Result,
+    providers::cohere::Cohere,
+};
+use async_trait::async_trait;
+
+#[async_trait]
+impl<M: ModelName> RerankModel for Cohere<M> {
+    async fn rerank(&self, input: RerankModelOptions) -> Result<RerankModelResponse> {
+        // Clone self to allow mutation
+        let mut model = self.clone();
+
+        let documents = input.documents.clone();
+        let mut request: crate::providers::cohere::client::types::RerankRequest = input.into();
+        request.model = model.rerank_options.model.clone();
+        model.rerank_options.request = request;
+
+        let response = model.send(&model.settings.base_url).await?;
+
+        Ok(response
+            .results
+            .into_iter()
+            .filter_map(|result| {
+                Some(RerankResult {
+                    index: result.index,
+                    relevance_score: result.relevance_score,
+                    document: documents.get(result.index)?.clone(),
+                })
+            })
+            .collect())
+    }
+}
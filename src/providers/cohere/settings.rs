@@ -0,0 +1,110 @@
+//! Defines the settings for the Cohere provider.
+
+use crate::core::client::{RetryPolicy, TransportSettings};
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// A document that grounds a Cohere chat response. Responses cite the documents they
+/// draw from, see [`CohereCitation`](crate::providers::cohere::CohereCitation).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CohereDocument {
+    /// An identifier referenced by citations. Cohere assigns one when unset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The document fields, e.g. `title` and `snippet`.
+    pub data: Map<String, Value>,
+}
+
+impl CohereDocument {
+    /// Creates a document with a single `text` field.
+    pub fn new(text: impl Into<String>) -> Self {
+        let mut data = Map::new();
+        data.insert("text".to_string(), Value::String(text.into()));
+        Self { id: None, data }
+    }
+
+    /// Sets the identifier referenced by citations.
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Adds a field to the document.
+    pub fn field(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.data.insert(key.into(), value.into());
+        self
+    }
+}
+
+impl From<&str> for CohereDocument {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<String> for CohereDocument {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
+
+/// Settings for the Cohere provider.
+#[derive(Clone, Serialize, Deserialize, Builder)]
+#[builder(setter(into), default)]
+pub struct CohereProviderSettings {
+    /// The name of the provider.
+    pub provider_name: String,
+
+    /// The base URL of the Cohere API.
+    pub base_url: String,
+
+    /// The API key for the Cohere API.
+    pub api_key: String,
+
+    /// Documents sent with every chat request to ground the response.
+    pub documents: Vec<CohereDocument>,
+
+    /// The retry policy applied to requests.
+    pub retry_policy: RetryPolicy,
+
+    /// The HTTP client, middleware and rate limiter requests go through.
+    #[serde(skip)]
+    pub transport: TransportSettings,
+}
+
+impl std::fmt::Debug for CohereProviderSettings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CohereProviderSettings")
+            .field("provider_name", &self.provider_name)
+            .field("base_url", &self.base_url)
+            .field("api_key", &"<redacted>")
+            .field("documents", &self.documents)
+            .field("retry_policy", &self.retry_policy)
+            .field("transport", &self.transport)
+            .finish()
+    }
+}
+
+impl Default for CohereProviderSettings {
+    /// Returns the default settings for the Cohere provider.
+    fn default() -> Self {
+        Self {
+            provider_name: "cohere".to_string(),
+            base_url: "https://api.cohere.com/v2/".to_string(),
+            api_key: std::env::var("COHERE_API_KEY")
+                .or_else(|_| std::env::var("CO_API_KEY"))
+                .unwrap_or_default(),
+            documents: Vec::new(),
+            retry_policy: RetryPolicy::default(),
+            transport: TransportSettings::default(),
+        }
+    }
+}
+
+impl CohereProviderSettings {
+    /// Creates a new builder for `CohereProviderSettings`.
+    pub fn builder() -> CohereProviderSettingsBuilder {
+        CohereProviderSettingsBuilder::default()
+    }
+}
@@ -0,0 +1,33 @@
+//! Capabilities for Deepgram models.
+//!
+//! This module defines model types for Deepgram's speech-to-text models. Any other
+//! model can be used through `Deepgram::model_name`. Users can implement additional
+//! traits on custom models.
+
+use crate::core::capabilities::*;
+use crate::model_capabilities;
+use crate::providers::deepgram::Deepgram;
+
+model_capabilities! {
+    provider: Deepgram,
+    models: {
+        Nova2 {
+            model_name: "nova-2",
+            constructor_name: nova_2,
+            display_name: "Nova-2",
+            capabilities: [AudioInputSupport, TextOutputSupport]
+        },
+        Nova3 {
+            model_name: "nova-3",
+            constructor_name: nova_3,
+            display_name: "Nova-3",
+            capabilities: [AudioInputSupport, TextOutputSupport]
+        },
+        Nova3Medical {
+            model_name: "nova-3-medical",
+            constructor_name: nova_3_medical,
+            display_name: "Nova-3 Medical",
+            capabilities: [AudioInputSupport, TextOutputSupport]
+        }
+    }
+}
@@ -0,0 +1,81 @@
+//! Client implementation for the Deepgram provider.
+use crate::core::client::{RetryPolicy, TranscriptionClient};
+use crate::providers::deepgram::{Deepgram, ModelName};
+use reqwest::header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE};
+use serde::Serialize;
+
+pub(crate) mod types;
+
+#[derive(Debug, Default, Clone, Serialize)]
+pub(crate) struct DeepgramOptions {
+    pub(crate) model: String,
+    pub(crate) query: Vec<(&'static str, String)>,
+    pub(crate) content_type: String,
+    #[serde(skip)]
+    pub(crate) body: Vec<u8>,
+}
+
+impl<M: ModelName> Deepgram<M> {
+    /// Returns the query parameters shared by pre-recorded and live requests.
+    pub(crate) fn listen_query(&self) -> Vec<(&'static str, String)> {
+        let mut query = vec![("model", self.options.model.clone())];
+        for (key, enabled) in [
+            ("smart_format", self.settings.smart_format),
+            ("diarize", self.settings.diarize),
+            ("utterances", self.settings.utterances),
+        ] {
+            if enabled {
+                query.push((key, "true".to_string()));
+            }
+        }
+        query
+    }
+
+    pub(crate) fn authorization(&self) -> String {
+        format!("Token {}", self.settings.api_key)
+    }
+}
+
+impl<M: ModelName> TranscriptionClient for Deepgram<M> {
+    type Response = types::ListenResponse;
+
+    fn path(&self) -> String {
+        "listen".to_string()
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn headers(&self) -> reqwest::header::HeaderMap {
+        let mut headers = reqwest::header::HeaderMap::new();
+        if let Ok(value) = self.options.content_type.parse() {
+            headers.insert(CONTENT_TYPE, value);
+        }
+        headers.insert(ACCEPT, "application/json".parse().unwrap());
+        if let Ok(value) = self.authorization().parse() {
+            headers.insert(AUTHORIZATION, value);
+        }
+        headers
+    }
+
+    fn query_params(&self) -> Vec<(&str, &str)> {
+        self.options
+            .query
+            .iter()
+            .map(|(key, value)| (*key, value.as_str()))
+            .collect()
+    }
+
+    fn retry_policy(&self) -> RetryPolicy {
+        self.settings.retry_policy.clone()
+    }
+
+    fn transport(&self) -> crate::core::client::TransportSettings {
+        self.settings.transport.clone()
+    }
+
+    fn body(&self) -> reqwest::Body {
+        reqwest::Body::from(self.options.body.clone())
+    }
+}
@@ -0,0 +1,101 @@
+//! Request and response types for the Deepgram `/listen` API.
+
+use serde::{Deserialize, Serialize};
+
+// ============================================================================
+// Section: pre-recorded
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct UrlSource {
+    pub(crate) url: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct ListenResponse {
+    #[serde(default)]
+    pub(crate) metadata: Option<Metadata>,
+    pub(crate) results: Results,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct Metadata {
+    #[serde(default)]
+    pub(crate) duration: Option<f32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct Results {
+    #[serde(default)]
+    pub(crate) channels: Vec<Channel>,
+    #[serde(default)]
+    pub(crate) utterances: Vec<Utterance>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct Channel {
+    #[serde(default)]
+    pub(crate) alternatives: Vec<Alternative>,
+    #[serde(default)]
+    pub(crate) detected_language: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct Alternative {
+    pub(crate) transcript: String,
+    #[serde(default)]
+    pub(crate) confidence: Option<f32>,
+    #[serde(default)]
+    pub(crate) words: Vec<Word>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct Word {
+    pub(crate) word: String,
+    pub(crate) start: f32,
+    pub(crate) end: f32,
+    #[serde(default)]
+    pub(crate) confidence: Option<f32>,
+    #[serde(default)]
+    pub(crate) speaker: Option<usize>,
+    #[serde(default)]
+    pub(crate) punctuated_word: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct Utterance {
+    pub(crate) transcript: String,
+    pub(crate) start: f32,
+    pub(crate) end: f32,
+    #[serde(default)]
+    pub(crate) speaker: Option<usize>,
+}
+
+// ============================================================================
+// Section: live
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub(crate) enum LiveMessage {
+    Results {
+        start: f32,
+        duration: f32,
+        #[serde(default)]
+        is_final: bool,
+        #[serde(default)]
+        speech_final: bool,
+        channel: Channel,
+    },
+    SpeechStarted {
+        timestamp: f32,
+    },
+    UtteranceEnd {
+        last_word_end: f32,
+    },
+    Metadata {
+        request_id: String,
+    },
+    #[serde(other)]
+    NotSupported,
+}
@@ -0,0 +1,105 @@
+//! This module provides conversions between the core transcription types and the
+//! Deepgram API types.
+
+use crate::core::transcription_model::{
+    TranscriptionModelResponse, TranscriptionSegment, TranscriptionWord,
+};
+use crate::providers::deepgram::client::types::{ListenResponse, Utterance, Word};
+
+impl From<Word> for TranscriptionWord {
+    fn from(value: Word) -> Self {
+        TranscriptionWord {
+            word: value.punctuated_word.unwrap_or(value.word),
+            start: value.start,
+            end: value.end,
+            confidence: value.confidence,
+            speaker: value.speaker,
+        }
+    }
+}
+
+impl From<Utterance> for TranscriptionSegment {
+    fn from(value: Utterance) -> Self {
+        TranscriptionSegment {
+            text: value.transcript,
+            start: value.start,
+            end: value.end,
+            speaker: value.speaker,
+        }
+    }
+}
+
+impl From<ListenResponse> for TranscriptionModelResponse {
+    fn from(value: ListenResponse) -> Self {
+        // Multichannel audio is transcribed per channel, the first one is returned
+        let channel = value.results.channels.into_iter().next();
+        let language = channel
+            .as_ref()
+            .and_then(|channel| channel.detected_language.clone());
+        let alternative = channel.and_then(|channel| channel.alternatives.into_iter().next());
+
+        let mut response = TranscriptionModelResponse {
+            language,
+            duration: value.metadata.and_then(|metadata| metadata.duration),
+            segments: value
+                .results
+                .utterances
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            ..Default::default()
+        };
+        if let Some(alternative) = alternative {
+            response.text = alternative.transcript;
+            response.confidence = alternative.confidence;
+            response.words = alternative.words.into_iter().map(Into::into).collect();
+        }
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_listen_response_conversion() {
+        let response: ListenResponse = serde_json::from_value(serde_json::json!({
+            "metadata": { "request_id": "abc", "duration": 1.5, "channels": 1 },
+            "results": {
+                "channels": [{
+                    "detected_language": "en",
+                    "alternatives": [{
+                        "transcript": "hello there",
+                        "confidence": 0.98,
+                        "words": [
+                            { "word": "hello", "start": 0.1, "end": 0.5, "confidence": 0.99, "speaker": 0, "punctuated_word": "Hello" },
+                            { "word": "there", "start": 0.6, "end": 1.0, "confidence": 0.97, "speaker": 1 }
+                        ]
+                    }]
+                }],
+                "utterances": [
+                    { "id": "u1", "transcript": "Hello", "start": 0.1, "end": 0.5, "speaker": 0, "channel": 0, "confidence": 0.99 }
+                ]
+            }
+        }))
+        .unwrap();
+
+        let response: TranscriptionModelResponse = response.into();
+        assert_eq!(response.text, "hello there");
+        assert_eq!(response.language.as_deref(), Some("en"));
+        assert_eq!(response.duration, Some(1.5));
+        assert_eq!(response.confidence, Some(0.98));
+        assert_eq!(response.words[0].word, "Hello");
+        assert_eq!(response.words[1].speaker, Some(1));
+        assert_eq!(
+            response.segments,
+            vec![TranscriptionSegment {
+                text: "Hello".to_string(),
+                start: 0.1,
+                end: 0.5,
+                speaker: Some(0),
+            }]
+        );
+    }
+}
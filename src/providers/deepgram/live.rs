@@ -0,0 +1,282 @@
+//! Live transcription over Deepgram's WebSocket endpoint.
+//!
+//! Audio is sent as it is produced, e.g. from a microphone, and transcripts are
+//! received while the audio is still streaming. Interim transcripts may be revised
+//! until a transcript marked final is received for the same span of audio.
+
+use crate::core::capabilities::ModelName;
+use crate::core::client::Transport;
+use crate::core::transcription_model::TranscriptionWord;
+use crate::error::{Error, Result};
+use crate::providers::deepgram::Deepgram;
+use crate::providers::deepgram::client::types::LiveMessage;
+use crate::providers::deepgram::websocket::{self, Message};
+use futures::{Stream, StreamExt};
+use std::pin::Pin;
+
+/// The message that asks Deepgram to flush the remaining transcripts and close the
+/// connection.
+const CLOSE_STREAM: &str = r#"{"type":"CloseStream"}"#;
+
+/// Options for live transcription.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DeepgramLiveOptions {
+    /// The encoding of raw audio, e.g. `linear16`. Required for raw audio, containerized
+    /// audio such as wav or webm is detected.
+    pub encoding: Option<String>,
+    /// The sample rate of raw audio in Hz.
+    pub sample_rate: Option<u32>,
+    /// The number of channels of raw audio.
+    pub channels: Option<u32>,
+    /// The language spoken in the audio, e.g. `en`, or `multi` for code switching.
+    pub language: Option<String>,
+    /// Whether interim transcripts are sent before a span of audio is final.
+    pub interim_results: bool,
+    /// Milliseconds of silence after which speech is considered finished, marking
+    /// transcripts with `speech_final`.
+    pub endpointing: Option<u32>,
+}
+
+/// A transcript of a span of live audio.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeepgramLiveTranscript {
+    /// The transcript of the span.
+    pub text: String,
+    /// The confidence in the transcript, from 0 to 1.
+    pub confidence: Option<f32>,
+    /// The words of the transcript.
+    pub words: Vec<TranscriptionWord>,
+    /// The time the span starts at, in seconds from the start of the stream.
+    pub start: f32,
+    /// The duration of the span in seconds.
+    pub duration: f32,
+    /// Whether the transcript of the span will not be revised.
+    pub is_final: bool,
+    /// Whether the speaker finished speaking at the end of the span.
+    pub speech_final: bool,
+}
+
+/// An event received during live transcription.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DeepgramLiveEvent {
+    /// A transcript of a span of audio.
+    Transcript(DeepgramLiveTranscript),
+    /// Speech was detected, at the given time in seconds.
+    SpeechStarted {
+        /// The time speech started at, in seconds from the start of the stream.
+        timestamp: f32,
+    },
+    /// An utterance ended, after the word ending at the given time in seconds.
+    UtteranceEnd {
+        /// The time the last word of the utterance ends at.
+        last_word_end: f32,
+    },
+    /// Metadata of the connection, sent before it closes.
+    Metadata {
+        /// The ID of the request, for support requests.
+        request_id: String,
+    },
+}
+
+/// A stream of live transcription events.
+pub type DeepgramLiveStream = Pin<Box<dyn Stream<Item = Result<DeepgramLiveEvent>> + Send>>;
+
+impl From<LiveMessage> for Option<DeepgramLiveEvent> {
+    fn from(value: LiveMessage) -> Self {
+        match value {
+            LiveMessage::Results {
+                start,
+                duration,
+                is_final,
+                speech_final,
+                channel,
+            } => {
+                let alternative = channel.alternatives.into_iter().next()?;
+                Some(DeepgramLiveEvent::Transcript(DeepgramLiveTranscript {
+                    text: alternative.transcript,
+                    confidence: alternative.confidence,
+                    words: alternative.words.into_iter().map(Into::into).collect(),
+                    start,
+                    duration,
+                    is_final,
+                    speech_final,
+                }))
+            }
+            LiveMessage::SpeechStarted { timestamp } => {
+                Some(DeepgramLiveEvent::SpeechStarted { timestamp })
+            }
+            LiveMessage::UtteranceEnd { last_word_end } => {
+                Some(DeepgramLiveEvent::UtteranceEnd { last_word_end })
+            }
+            LiveMessage::Metadata { request_id } => {
+                Some(DeepgramLiveEvent::Metadata { request_id })
+            }
+            LiveMessage::NotSupported => None,
+        }
+    }
+}
+
+impl<M: ModelName> Deepgram<M> {
+    /// Returns the URL of the live endpoint for `options`.
+    pub(crate) fn live_url(&self, options: &DeepgramLiveOptions) -> Result<reqwest::Url> {
+        let mut url = crate::core::utils::join_url(self.settings.base_url.as_str(), "listen")?;
+        let scheme = if url.scheme() == "http" { "ws" } else { "wss" };
+        url.set_scheme(scheme)
+            .map_err(|_| Error::InvalidInput("Invalid base URL".into()))?;
+
+        let mut query = self.listen_query();
+        if let Some(encoding) = &options.encoding {
+            query.push(("encoding", encoding.clone()));
+        }
+        if let Some(sample_rate) = options.sample_rate {
+            query.push(("sample_rate", sample_rate.to_string()));
+        }
+        if let Some(channels) = options.channels {
+            query.push(("channels", channels.to_string()));
+        }
+        if let Some(language) = &options.language {
+            query.push(("language", language.clone()));
+        }
+        if options.interim_results {
+            query.push(("interim_results", "true".to_string()));
+        }
+        if let Some(endpointing) = options.endpointing {
+            query.push(("endpointing", endpointing.to_string()));
+        }
+        url.query_pairs_mut().extend_pairs(query);
+
+        Ok(url)
+    }
+
+    /// Transcribes live audio over Deepgram's WebSocket endpoint.
+    ///
+    /// The chunks of `audio` are sent as they are produced. Once `audio` ends, Deepgram
+    /// flushes the remaining transcripts and the returned stream ends when the
+    /// connection closes.
+    ///
+    /// # Parameters
+    ///
+    /// * `options` - The encoding of the audio and the transcripts to send.
+    /// * `audio` - The audio to transcribe, in chunks of encoded bytes.
+    ///
+    /// # Returns
+    ///
+    /// A stream of transcripts and speech events.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the connection cannot be opened, e.g. because the API key
+    /// is rejected.
+    pub async fn transcribe_live<S>(
+        &self,
+        options: DeepgramLiveOptions,
+        audio: S,
+    ) -> Result<DeepgramLiveStream>
+    where
+        S: Stream<Item = Vec<u8>> + Send + 'static,
+    {
+        let url = self.live_url(&options)?;
+        let transport = Transport::new(self.settings.transport.clone());
+        let (reader, mut writer) =
+            websocket::connect(&transport, &url, &[("Authorization", self.authorization())])
+                .await?;
+
+        let mut audio = Box::pin(audio);
+        tokio::spawn(async move {
+            while let Some(chunk) = audio.next().await {
+                if writer.send_binary(chunk).await.is_err() {
+                    return;
+                }
+            }
+            let _ = writer.send_text(CLOSE_STREAM).await;
+        });
+
+        // The stream ends after the first error
+        let events = futures::stream::unfold(Some(reader), |state| async move {
+            let mut reader = state?;
+            loop {
+                match reader.next().await {
+                    Ok(Some(Message::Text(text))) => {
+                        let message: LiveMessage = match serde_json::from_str(&text) {
+                            Ok(message) => message,
+                            Err(e) => {
+                                let error = Error::ApiError {
+                                    status_code: None,
+                                    details: format!("Invalid JSON in live message: {e}"),
+                                };
+                                return Some((Err(error), None));
+                            }
+                        };
+                        if let Some(event) = Option::<DeepgramLiveEvent>::from(message) {
+                            return Some((Ok(event), Some(reader)));
+                        }
+                    }
+                    Ok(Some(_)) => {}
+                    Ok(None) => return None,
+                    Err(e) => return Some((Err(e), None)),
+                }
+            }
+        });
+
+        Ok(Box::pin(events))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::DynamicModel;
+
+    #[test]
+    fn test_live_url() {
+        let deepgram = Deepgram::<DynamicModel>::builder()
+            .model_name("nova-3")
+            .api_key("key")
+            .build()
+            .unwrap();
+        let url = deepgram
+            .live_url(&DeepgramLiveOptions {
+                encoding: Some("linear16".to_string()),
+                sample_rate: Some(16000),
+                interim_results: true,
+                ..Default::default()
+            })
+            .unwrap();
+
+        assert_eq!(
+            url.as_str(),
+            "wss://api.deepgram.com/v1/listen?model=nova-3&smart_format=true&encoding=linear16&sample_rate=16000&interim_results=true"
+        );
+    }
+
+    #[test]
+    fn test_live_results_conversion() {
+        let message: LiveMessage = serde_json::from_value(serde_json::json!({
+            "type": "Results",
+            "channel_index": [0, 1],
+            "start": 1.0,
+            "duration": 0.5,
+            "is_final": true,
+            "speech_final": false,
+            "channel": {
+                "alternatives": [{
+                    "transcript": "hi",
+                    "confidence": 0.9,
+                    "words": [{ "word": "hi", "start": 1.0, "end": 1.2, "confidence": 0.9 }]
+                }]
+            }
+        }))
+        .unwrap();
+
+        let Some(DeepgramLiveEvent::Transcript(transcript)) = message.into() else {
+            panic!("expected a transcript");
+        };
+        assert_eq!(transcript.text, "hi");
+        assert!(transcript.is_final);
+        assert_eq!(transcript.words.len(), 1);
+
+        let message: LiveMessage =
+            serde_json::from_value(serde_json::json!({ "type": "KeepAlive" })).unwrap();
+        assert_eq!(Option::<DeepgramLiveEvent>::from(message), None);
+    }
+}
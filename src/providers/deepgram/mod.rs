@@ -0,0 +1,273 @@
+//! This module provides the Deepgram provider, which implements the
+//! `TranscriptionModel` trait for Deepgram's speech-to-text models.
+//!
+//! Pre-recorded audio, sent as bytes or fetched by Deepgram from a URL, is transcribed
+//! through `/listen`, with word timestamps, confidence, and optionally speaker
+//! diarization. Live audio is transcribed over the WebSocket endpoint with
+//! [`Deepgram::transcribe_live`].
+
+pub mod capabilities;
+pub mod client;
+pub mod conversions;
+pub mod live;
+pub mod settings;
+pub mod transcription_model;
+
+pub(crate) mod websocket;
+
+use crate::core::DynamicModel;
+use crate::core::capabilities::ModelName;
+use crate::core::client::RetryPolicy;
+use crate::core::utils::validate_base_url;
+use crate::error::{Error, Result};
+use crate::providers::deepgram::client::DeepgramOptions;
+use crate::providers::deepgram::settings::DeepgramProviderSettings;
+use serde::Serialize;
+
+pub use live::{
+    DeepgramLiveEvent, DeepgramLiveOptions, DeepgramLiveStream, DeepgramLiveTranscript,
+};
+
+/// The Deepgram provider.
+#[derive(Debug, Serialize, Clone)]
+pub struct Deepgram<M: ModelName> {
+    /// Configuration settings for the Deepgram provider.
+    pub settings: DeepgramProviderSettings,
+    pub(crate) options: DeepgramOptions,
+    _phantom: std::marker::PhantomData<M>,
+}
+
+impl<M: ModelName> Deepgram<M> {
+    /// Deepgram provider setting builder.
+    pub fn builder() -> DeepgramBuilder<M> {
+        DeepgramBuilder::default()
+    }
+
+    fn with_model(settings: DeepgramProviderSettings, model_name: String) -> Self {
+        let options = DeepgramOptions {
+            model: model_name,
+            ..Default::default()
+        };
+
+        Self {
+            settings,
+            options,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl Deepgram<DynamicModel> {
+    /// Creates a Deepgram provider with a dynamic model name using default settings.
+    ///
+    /// This allows you to specify the model name as a string rather than
+    /// using methods like `Deepgram::nova_3()`.
+    ///
+    /// **WARNING**: when using `DynamicModel`, model capabilities are not validated.
+    /// This means there is no compile-time guarantee that the model supports requested features.
+    ///
+    /// For custom configuration (API key, diarization, etc.), use the builder pattern:
+    /// `Deepgram::<DynamicModel>::builder().model_name(...).api_key(...).build()`
+    ///
+    /// # Parameters
+    ///
+    /// * `model_name` - The Deepgram model identifier (e.g., "nova-3", "nova-2")
+    ///
+    /// # Returns
+    ///
+    /// A configured `Deepgram<DynamicModel>` provider instance with default settings.
+    pub fn model_name(name: impl Into<String>) -> Self {
+        Self::with_model(DeepgramProviderSettings::default(), name.into())
+    }
+}
+
+impl<M: ModelName> Default for Deepgram<M> {
+    /// Creates a new Deepgram provider with default settings.
+    fn default() -> Self {
+        Self::with_model(
+            DeepgramProviderSettings::default(),
+            M::MODEL_NAME.to_string(),
+        )
+    }
+}
+
+/// Deepgram Provider Builder
+pub struct DeepgramBuilder<M: ModelName> {
+    settings: DeepgramProviderSettings,
+    model_name: String,
+    _phantom: std::marker::PhantomData<M>,
+}
+
+impl<M: ModelName> Default for DeepgramBuilder<M> {
+    /// Creates a new Deepgram provider builder with default settings.
+    fn default() -> Self {
+        Self {
+            settings: DeepgramProviderSettings::default(),
+            model_name: M::MODEL_NAME.to_string(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl DeepgramBuilder<DynamicModel> {
+    /// Sets the model name from a string. e.g., "nova-3", "nova-2"
+    ///
+    /// **WARNING**: when using `DynamicModel`, model capabilities are not validated.
+    /// This means there is no compile-time guarantee that the model supports requested features.
+    ///
+    /// # Parameters
+    ///
+    /// * `model_name` - The Deepgram model identifier.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the model name set.
+    pub fn model_name(mut self, model_name: impl Into<String>) -> Self {
+        self.model_name = model_name.into();
+        self
+    }
+}
+
+impl<M: ModelName> DeepgramBuilder<M> {
+    /// Sets the base URL for the Deepgram API.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.settings.base_url = base_url.into();
+        self
+    }
+
+    /// Sets the API key for the Deepgram API.
+    pub fn api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.settings.api_key = api_key.into();
+        self
+    }
+
+    /// Sets whether entities are formatted and punctuation is added. Defaults to true.
+    pub fn smart_format(mut self, smart_format: bool) -> Self {
+        self.settings.smart_format = smart_format;
+        self
+    }
+
+    /// Sets whether speakers are separated, so words and utterances carry a speaker.
+    pub fn diarize(mut self, diarize: bool) -> Self {
+        self.settings.diarize = diarize;
+        self
+    }
+
+    /// Sets whether the transcript is split into utterances, returned as segments.
+    pub fn utterances(mut self, utterances: bool) -> Self {
+        self.settings.utterances = utterances;
+        self
+    }
+
+    /// Sets the retry policy applied to requests made by this provider.
+    ///
+    /// # Parameters
+    ///
+    /// * `retry_policy` - The [`RetryPolicy`] to use for retryable failures.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the retry policy set.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.settings.retry_policy = retry_policy;
+        self
+    }
+
+    crate::core::client::transport_builder_methods!();
+
+    /// Sets the name of the provider. Defaults to "deepgram".
+    pub fn provider_name(mut self, provider_name: impl Into<String>) -> Self {
+        self.settings.provider_name = provider_name.into();
+        self
+    }
+
+    /// Builds the Deepgram provider.
+    pub fn build(self) -> Result<Deepgram<M>> {
+        // validate base url
+        let base_url = validate_base_url(&self.settings.base_url)?;
+
+        // check api key exists
+        if self.settings.api_key.is_empty() {
+            return Err(Error::MissingField("api_key".to_string()));
+        }
+
+        if self.model_name.is_empty() {
+            return Err(Error::MissingField("model_name".to_string()));
+        }
+
+        Ok(Deepgram::with_model(
+            DeepgramProviderSettings {
+                base_url,
+                ..self.settings
+            },
+            self.model_name,
+        ))
+    }
+}
+
+// Re-exports for convenience
+pub use capabilities::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::client::TranscriptionClient;
+    use crate::core::transcription_model::{TranscriptionAudio, TranscriptionModelOptions};
+    use reqwest::header::CONTENT_TYPE;
+
+    fn provider() -> Deepgram<DynamicModel> {
+        Deepgram::<DynamicModel>::builder()
+            .model_name("nova-3")
+            .api_key("key")
+            .diarize(true)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_request_from_bytes() {
+        let options = TranscriptionModelOptions::builder()
+            .audio(TranscriptionAudio::Bytes {
+                data: vec![1, 2, 3],
+                media_type: Some("audio/wav".to_string()),
+            })
+            .language("en".to_string())
+            .build()
+            .unwrap();
+        let model = provider().with_request(options);
+
+        assert_eq!(
+            TranscriptionClient::query_params(&model),
+            vec![
+                ("model", "nova-3"),
+                ("smart_format", "true"),
+                ("diarize", "true"),
+                ("language", "en")
+            ]
+        );
+        assert_eq!(
+            TranscriptionClient::headers(&model)[CONTENT_TYPE],
+            "audio/wav"
+        );
+        assert_eq!(model.options.body, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_request_from_url() {
+        let options = TranscriptionModelOptions::builder()
+            .audio(TranscriptionAudio::url("https://example.com/audio.mp3"))
+            .build()
+            .unwrap();
+        let model = provider().with_request(options);
+
+        assert!(TranscriptionClient::query_params(&model).contains(&("detect_language", "true")));
+        assert_eq!(
+            TranscriptionClient::headers(&model)[CONTENT_TYPE],
+            "application/json"
+        );
+        assert_eq!(
+            model.options.body,
+            br#"{"url":"https://example.com/audio.mp3"}"#.to_vec()
+        );
+    }
+}
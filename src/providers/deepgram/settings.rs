@@ -0,0 +1,75 @@
+//! Defines the settings for the Deepgram provider.
+
+use crate::core::client::{RetryPolicy, TransportSettings};
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+/// Settings for the Deepgram provider.
+#[derive(Clone, Serialize, Deserialize, Builder)]
+#[builder(setter(into), default)]
+pub struct DeepgramProviderSettings {
+    /// The name of the provider.
+    pub provider_name: String,
+
+    /// The base URL of the Deepgram API. The live endpoint is derived from it by
+    /// switching to the WebSocket scheme.
+    pub base_url: String,
+
+    /// The API key for the Deepgram API.
+    pub api_key: String,
+
+    /// Whether numbers, dates, and other entities are formatted and punctuation is
+    /// added. Defaults to true.
+    pub smart_format: bool,
+
+    /// Whether speakers are separated, so words and utterances carry a speaker.
+    pub diarize: bool,
+
+    /// Whether the transcript is split into utterances, returned as segments.
+    pub utterances: bool,
+
+    /// The retry policy applied to requests.
+    pub retry_policy: RetryPolicy,
+
+    /// The HTTP client, middleware and rate limiter requests go through.
+    #[serde(skip)]
+    pub transport: TransportSettings,
+}
+
+impl std::fmt::Debug for DeepgramProviderSettings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DeepgramProviderSettings")
+            .field("provider_name", &self.provider_name)
+            .field("base_url", &self.base_url)
+            .field("api_key", &"<redacted>")
+            .field("smart_format", &self.smart_format)
+            .field("diarize", &self.diarize)
+            .field("utterances", &self.utterances)
+            .field("retry_policy", &self.retry_policy)
+            .field("transport", &self.transport)
+            .finish()
+    }
+}
+
+impl Default for DeepgramProviderSettings {
+    /// Returns the default settings for the Deepgram provider.
+    fn default() -> Self {
+        Self {
+            provider_name: "deepgram".to_string(),
+            base_url: "https://api.deepgram.com/v1/".to_string(),
+            api_key: std::env::var("DEEPGRAM_API_KEY").unwrap_or_default(),
+            smart_format: true,
+            diarize: false,
+            utterances: false,
+            retry_policy: RetryPolicy::default(),
+            transport: TransportSettings::default(),
+        }
+    }
+}
+
+impl DeepgramProviderSettings {
+    /// Creates a new builder for `DeepgramProviderSettings`.
+    pub fn builder() -> DeepgramProviderSettingsBuilder {
+        DeepgramProviderSettingsBuilder::default()
+    }
+}
@@ -0,0 +1,56 @@
+//! Transcription model implementation for the Deepgram provider.
+
+use crate::{
+    core::{
+        capabilities::ModelName,
+        client::TranscriptionClient,
+        transcription_model::{
+            TranscriptionAudio, TranscriptionModel, TranscriptionModelOptions,
+            TranscriptionModelResponse,
+        },
+    },
+    error::Result,
+    providers::deepgram::{Deepgram, client::types::UrlSource},
+};
+use async_trait::async_trait;
+
+impl<M: ModelName> Deepgram<M> {
+    /// Returns a copy of the provider prepared to transcribe `input`.
+    pub(crate) fn with_request(&self, input: TranscriptionModelOptions) -> Self {
+        let mut model = self.clone();
+
+        let mut query = model.listen_query();
+        match input.language {
+            Some(language) => query.push(("language", language)),
+            None => query.push(("detect_language", "true".to_string())),
+        }
+        model.options.query = query;
+
+        match input.audio {
+            TranscriptionAudio::Bytes { data, media_type } => {
+                model.options.content_type = media_type.unwrap_or_else(|| "audio/*".to_string());
+                model.options.body = data;
+            }
+            TranscriptionAudio::Url(url) => {
+                model.options.content_type = "application/json".to_string();
+                model.options.body = serde_json::to_vec(&UrlSource { url }).unwrap();
+            }
+        }
+
+        model
+    }
+}
+
+#[async_trait]
+impl<M: ModelName> TranscriptionModel for Deepgram<M> {
+    async fn transcribe(
+        &self,
+        input: TranscriptionModelOptions,
+    ) -> Result<TranscriptionModelResponse> {
+        let model = self.with_request(input);
+
+        let response = model.send(&model.settings.base_url).await?;
+
+        Ok(response.into())
+    }
+}
@@ -0,0 +1,229 @@
+//! The WebSocket client of the live transcription endpoint.
+//!
+//! The opening handshake is an HTTP request sent through the provider's [`Transport`],
+//! so it uses the configured HTTP client with its proxy and TLS settings, goes through
+//! the middleware, and waits for the rate limiter. Once the server accepts the upgrade,
+//! the connection is driven by `tokio-tungstenite`, which answers pings by itself.
+
+use crate::core::client::{Transport, response_error};
+use crate::error::{Error, Result};
+use futures::stream::{SplitSink, SplitStream};
+use futures::{SinkExt, StreamExt};
+use reqwest::header::{
+    CONNECTION, SEC_WEBSOCKET_ACCEPT, SEC_WEBSOCKET_KEY, SEC_WEBSOCKET_VERSION, UPGRADE,
+};
+use tokio_tungstenite::WebSocketStream;
+use tokio_tungstenite::tungstenite::handshake::client::generate_key;
+use tokio_tungstenite::tungstenite::handshake::derive_accept_key;
+use tokio_tungstenite::tungstenite::protocol::Role;
+
+pub(crate) use tokio_tungstenite::tungstenite::Message;
+
+type Socket = WebSocketStream<reqwest::Upgraded>;
+
+fn websocket_error(e: impl std::fmt::Display) -> Error {
+    Error::ApiError {
+        status_code: None,
+        details: format!("WebSocket error: {e}"),
+    }
+}
+
+/// Opens a WebSocket connection to a `ws://` or `wss://` URL through `transport`.
+pub(crate) async fn connect(
+    transport: &Transport,
+    url: &reqwest::Url,
+    headers: &[(&str, String)],
+) -> Result<(Reader, Writer)> {
+    // The handshake is a plain HTTP request to the same host
+    let mut http_url = url.clone();
+    let scheme = if url.scheme() == "ws" {
+        "http"
+    } else {
+        "https"
+    };
+    http_url
+        .set_scheme(scheme)
+        .map_err(|_| Error::InvalidInput("Invalid WebSocket URL".into()))?;
+
+    let key = generate_key();
+    let mut request = transport
+        .client
+        .get(http_url)
+        .version(reqwest::Version::HTTP_11)
+        .header(CONNECTION, "Upgrade")
+        .header(UPGRADE, "websocket")
+        .header(SEC_WEBSOCKET_VERSION, "13")
+        .header(SEC_WEBSOCKET_KEY, &key);
+    for (name, value) in headers {
+        request = request.header(*name, value);
+    }
+
+    let response = transport.send(request).await?.map_err(websocket_error)?;
+    if response.status() != reqwest::StatusCode::SWITCHING_PROTOCOLS {
+        return Err(response_error(response).await);
+    }
+    let accept = response.headers().get(SEC_WEBSOCKET_ACCEPT);
+    if accept.map(|accept| accept.as_bytes()) != Some(derive_accept_key(key.as_bytes()).as_bytes())
+    {
+        return Err(websocket_error(
+            "the server answered the handshake with an invalid Sec-WebSocket-Accept",
+        ));
+    }
+    let upgraded = response.upgrade().await.map_err(websocket_error)?;
+
+    let socket = WebSocketStream::from_raw_socket(upgraded, Role::Client, None).await;
+    let (sink, stream) = socket.split();
+    Ok((Reader(stream), Writer(sink)))
+}
+
+/// The receiving half of a connection.
+pub(crate) struct Reader(SplitStream<Socket>);
+
+impl Reader {
+    /// Returns the next text or binary message, or `None` once the connection is closed.
+    pub(crate) async fn next(&mut self) -> Result<Option<Message>> {
+        while let Some(message) = self.0.next().await {
+            match message.map_err(websocket_error)? {
+                Message::Close(_) => return Ok(None),
+                message @ (Message::Text(_) | Message::Binary(_)) => return Ok(Some(message)),
+                Message::Ping(_) | Message::Pong(_) | Message::Frame(_) => {}
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// The sending half of a connection.
+pub(crate) struct Writer(SplitSink<Socket, Message>);
+
+impl Writer {
+    /// Sends a text message.
+    pub(crate) async fn send_text(&mut self, text: &str) -> Result<()> {
+        self.0
+            .send(Message::text(text))
+            .await
+            .map_err(websocket_error)
+    }
+
+    /// Sends a binary message.
+    pub(crate) async fn send_binary(&mut self, data: Vec<u8>) -> Result<()> {
+        self.0
+            .send(Message::binary(data))
+            .await
+            .map_err(websocket_error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::client::{Middleware, MiddlewareChain, TransportSettings};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::tungstenite::handshake::server::{Request, Response};
+
+    /// Adds a header to every request.
+    struct TenantHeader;
+
+    #[async_trait::async_trait]
+    impl Middleware for TenantHeader {
+        async fn on_request(&self, request: &mut reqwest::Request) -> Result<()> {
+            request
+                .headers_mut()
+                .insert("x-tenant", "acme".parse().unwrap());
+            Ok(())
+        }
+    }
+
+    fn transport() -> Transport {
+        let mut middleware = MiddlewareChain::new();
+        middleware.push(TenantHeader);
+        Transport::new(TransportSettings {
+            http_client: Some(reqwest::Client::new()),
+            middleware,
+            rate_limiter: None,
+        })
+    }
+
+    /// Answers the first connection with `response` and returns the request it read.
+    async fn raw_server(response: &'static str) -> (reqwest::Url, tokio::task::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}/v1/listen", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut request = vec![0u8; 4096];
+            let len = stream.read(&mut request).await.unwrap();
+            stream.write_all(response.as_bytes()).await.unwrap();
+            String::from_utf8_lossy(&request[..len]).to_string()
+        });
+        (reqwest::Url::parse(&url).unwrap(), server)
+    }
+
+    // The error type of the handshake callback is the one of tungstenite
+    #[allow(clippy::result_large_err)]
+    #[tokio::test]
+    async fn test_connect_goes_through_the_transport() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}/v1/listen", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut headers = None;
+            let check = |request: &Request, response: Response| {
+                headers = Some(request.headers().clone());
+                Ok(response)
+            };
+            let mut socket = tokio_tungstenite::accept_hdr_async(stream, check)
+                .await
+                .unwrap();
+            socket.send(Message::text("hello")).await.unwrap();
+            let received = socket.next().await.unwrap().unwrap();
+            socket.close(None).await.unwrap();
+            (headers.unwrap(), received)
+        });
+
+        let (mut reader, mut writer) = connect(
+            &transport(),
+            &reqwest::Url::parse(&url).unwrap(),
+            &[("Authorization", "Token key".to_string())],
+        )
+        .await
+        .unwrap();
+        assert_eq!(reader.next().await.unwrap(), Some(Message::text("hello")));
+        writer.send_binary(vec![1, 2, 3]).await.unwrap();
+        assert_eq!(reader.next().await.unwrap(), None);
+
+        let (headers, received) = server.await.unwrap();
+        assert_eq!(headers["authorization"], "Token key");
+        assert_eq!(headers["x-tenant"], "acme");
+        assert_eq!(received, Message::binary(vec![1, 2, 3]));
+    }
+
+    #[tokio::test]
+    async fn test_connect_rejects_an_invalid_accept_key() {
+        let (url, server) = raw_server(
+            "HTTP/1.1 101 Switching Protocols\r\nConnection: Upgrade\r\nUpgrade: websocket\r\n\
+             Sec-WebSocket-Accept: aW52YWxpZA==\r\n\r\n",
+        )
+        .await;
+
+        let result = connect(&transport(), &url, &[]).await;
+        let request = server.await.unwrap();
+
+        assert!(request.starts_with("GET /v1/listen HTTP/1.1\r\n"));
+        assert!(matches!(
+            result,
+            Err(Error::ApiError { details, .. }) if details.contains("Sec-WebSocket-Accept")
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_connect_rejects_a_non_upgrade_response() {
+        let (url, server) =
+            raw_server("HTTP/1.1 401 Unauthorized\r\nContent-Length: 7\r\n\r\ninvalid").await;
+
+        let result = connect(&transport(), &url, &[]).await;
+        server.await.unwrap();
+
+        assert!(matches!(result, Err(Error::AuthenticationFailed { .. })));
+    }
+}
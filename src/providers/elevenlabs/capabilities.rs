@@ -0,0 +1,39 @@
+//! Capabilities for ElevenLabs models.
+//!
+//! This module defines model types for ElevenLabs' text-to-speech models. Any other
+//! model can be used through `ElevenLabs::model_name`. Users can implement additional
+//! traits on custom models.
+
+use crate::core::capabilities::*;
+use crate::model_capabilities;
+use crate::providers::elevenlabs::ElevenLabs;
+
+model_capabilities! {
+    provider: ElevenLabs,
+    models: {
+        ElevenFlashV25 {
+            model_name: "eleven_flash_v2_5",
+            constructor_name: eleven_flash_v2_5,
+            display_name: "Eleven Flash v2.5",
+            capabilities: [AudioOutputSupport, TextInputSupport]
+        },
+        ElevenMultilingualV2 {
+            model_name: "eleven_multilingual_v2",
+            constructor_name: eleven_multilingual_v2,
+            display_name: "Eleven Multilingual v2",
+            capabilities: [AudioOutputSupport, TextInputSupport]
+        },
+        ElevenTurboV25 {
+            model_name: "eleven_turbo_v2_5",
+            constructor_name: eleven_turbo_v2_5,
+            display_name: "Eleven Turbo v2.5",
+            capabilities: [AudioOutputSupport, TextInputSupport]
+        },
+        ElevenV3 {
+            model_name: "eleven_v3",
+            constructor_name: eleven_v3,
+            display_name: "Eleven v3",
+            capabilities: [AudioOutputSupport, TextInputSupport]
+        }
+    }
+}
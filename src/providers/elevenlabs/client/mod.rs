@@ -0,0 +1,60 @@
+//! Client implementation for the ElevenLabs provider.
+use crate::core::client::{RetryPolicy, SpeechClient};
+use crate::providers::elevenlabs::{ElevenLabs, ModelName};
+use reqwest::header::{ACCEPT, CONTENT_TYPE};
+use serde::{Deserialize, Serialize};
+
+pub(crate) mod types;
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub(crate) struct ElevenLabsSpeechOptions {
+    pub(crate) model: String,
+    pub(crate) voice_id: String,
+    pub(crate) output_format: Option<String>,
+    pub(crate) streaming: bool,
+    pub(crate) request: types::SpeechRequest,
+}
+
+impl<M: ModelName> SpeechClient for ElevenLabs<M> {
+    fn path(&self) -> String {
+        let path = format!("text-to-speech/{}", self.speech_options.voice_id);
+        if self.speech_options.streaming {
+            return format!("{path}/stream");
+        }
+        path
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn headers(&self) -> reqwest::header::HeaderMap {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(CONTENT_TYPE, "application/json".parse().unwrap());
+        headers.insert(ACCEPT, "audio/*".parse().unwrap());
+        if let Ok(value) = self.settings.api_key.parse() {
+            headers.insert("xi-api-key", value);
+        }
+        headers
+    }
+
+    fn query_params(&self) -> Vec<(&str, &str)> {
+        match &self.speech_options.output_format {
+            Some(format) => vec![("output_format", format.as_str())],
+            None => Vec::new(),
+        }
+    }
+
+    fn retry_policy(&self) -> RetryPolicy {
+        self.settings.retry_policy.clone()
+    }
+
+    fn transport(&self) -> crate::core::client::TransportSettings {
+        self.settings.transport.clone()
+    }
+
+    fn body(&self) -> reqwest::Body {
+        let body = serde_json::to_string(&self.speech_options.request).unwrap();
+        reqwest::Body::from(body)
+    }
+}
@@ -0,0 +1,12 @@
+//! Request types for the ElevenLabs text-to-speech API.
+
+use crate::providers::elevenlabs::settings::ElevenLabsVoiceSettings;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct SpeechRequest {
+    pub(crate) text: String,
+    pub(crate) model_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) voice_settings: Option<ElevenLabsVoiceSettings>,
+}
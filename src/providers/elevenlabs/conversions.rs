@@ -0,0 +1,15 @@
+//! This module provides conversions between the core speech types and the ElevenLabs
+//! API types.
+
+use crate::core::speech_model::SpeechModelOptions;
+use crate::providers::elevenlabs::client::types::SpeechRequest;
+
+impl From<SpeechModelOptions> for SpeechRequest {
+    fn from(value: SpeechModelOptions) -> Self {
+        SpeechRequest {
+            text: value.text,
+            model_id: String::new(), // will be set in speech_model.rs
+            voice_settings: None,
+        }
+    }
+}
@@ -0,0 +1,303 @@
+//! This module provides the ElevenLabs provider, which implements the `SpeechModel`
+//! trait for ElevenLabs' text-to-speech models.
+//!
+//! Speech is synthesized with a voice, chosen per request or configured on the
+//! provider, and returned either as complete audio from `/text-to-speech/{voice_id}`
+//! or in chunks as it is generated from `/text-to-speech/{voice_id}/stream`.
+
+pub mod capabilities;
+pub mod client;
+pub mod conversions;
+pub mod settings;
+pub mod speech_model;
+
+use crate::core::DynamicModel;
+use crate::core::capabilities::ModelName;
+use crate::core::client::RetryPolicy;
+use crate::core::speech_model::SpeechModelOptions;
+use crate::core::utils::validate_base_url;
+use crate::error::{Error, Result};
+use crate::providers::elevenlabs::client::ElevenLabsSpeechOptions;
+use crate::providers::elevenlabs::settings::ElevenLabsProviderSettings;
+use serde::Serialize;
+
+pub use settings::ElevenLabsVoiceSettings;
+
+/// The ElevenLabs provider.
+#[derive(Debug, Serialize, Clone)]
+pub struct ElevenLabs<M: ModelName> {
+    /// Configuration settings for the ElevenLabs provider.
+    pub settings: ElevenLabsProviderSettings,
+    pub(crate) speech_options: ElevenLabsSpeechOptions,
+    _phantom: std::marker::PhantomData<M>,
+}
+
+impl<M: ModelName> ElevenLabs<M> {
+    /// ElevenLabs provider setting builder.
+    pub fn builder() -> ElevenLabsBuilder<M> {
+        ElevenLabsBuilder::default()
+    }
+
+    /// Returns a copy of the provider prepared to send `input`. The voice and output
+    /// format of the request take precedence over the ones configured on the provider.
+    pub(crate) fn with_request(&self, input: SpeechModelOptions, streaming: bool) -> Result<Self> {
+        let mut model = self.clone();
+
+        model.speech_options.voice_id = input
+            .voice
+            .clone()
+            .or_else(|| model.settings.voice_id.clone())
+            .ok_or_else(|| Error::MissingField("voice_id".to_string()))?;
+        model.speech_options.output_format = input
+            .format
+            .clone()
+            .or_else(|| model.settings.output_format.clone());
+        model.speech_options.streaming = streaming;
+
+        let mut request: client::types::SpeechRequest = input.into();
+        request.model_id = model.speech_options.model.clone();
+        request.voice_settings = model.settings.voice_settings.clone();
+        model.speech_options.request = request;
+
+        Ok(model)
+    }
+
+    fn with_model(settings: ElevenLabsProviderSettings, model_name: String) -> Self {
+        let speech_options = ElevenLabsSpeechOptions {
+            model: model_name,
+            ..Default::default()
+        };
+
+        Self {
+            settings,
+            speech_options,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl ElevenLabs<DynamicModel> {
+    /// Creates an ElevenLabs provider with a dynamic model name using default settings.
+    ///
+    /// This allows you to specify the model name as a string rather than
+    /// using methods like `ElevenLabs::eleven_multilingual_v2()`.
+    ///
+    /// **WARNING**: when using `DynamicModel`, model capabilities are not validated.
+    /// This means there is no compile-time guarantee that the model supports requested features.
+    ///
+    /// For custom configuration (API key, voice, etc.), use the builder pattern:
+    /// `ElevenLabs::<DynamicModel>::builder().model_name(...).voice_id(...).build()`
+    ///
+    /// # Parameters
+    ///
+    /// * `model_name` - The ElevenLabs model identifier (e.g., "eleven_multilingual_v2",
+    ///   "eleven_flash_v2_5")
+    ///
+    /// # Returns
+    ///
+    /// A configured `ElevenLabs<DynamicModel>` provider instance with default settings.
+    pub fn model_name(name: impl Into<String>) -> Self {
+        Self::with_model(ElevenLabsProviderSettings::default(), name.into())
+    }
+}
+
+impl<M: ModelName> Default for ElevenLabs<M> {
+    /// Creates a new ElevenLabs provider with default settings.
+    fn default() -> Self {
+        Self::with_model(
+            ElevenLabsProviderSettings::default(),
+            M::MODEL_NAME.to_string(),
+        )
+    }
+}
+
+/// ElevenLabs Provider Builder
+pub struct ElevenLabsBuilder<M: ModelName> {
+    settings: ElevenLabsProviderSettings,
+    model_name: String,
+    _phantom: std::marker::PhantomData<M>,
+}
+
+impl<M: ModelName> Default for ElevenLabsBuilder<M> {
+    /// Creates a new ElevenLabs provider builder with default settings.
+    fn default() -> Self {
+        Self {
+            settings: ElevenLabsProviderSettings::default(),
+            model_name: M::MODEL_NAME.to_string(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl ElevenLabsBuilder<DynamicModel> {
+    /// Sets the model name from a string. e.g., "eleven_multilingual_v2", "eleven_flash_v2_5"
+    ///
+    /// **WARNING**: when using `DynamicModel`, model capabilities are not validated.
+    /// This means there is no compile-time guarantee that the model supports requested features.
+    ///
+    /// # Parameters
+    ///
+    /// * `model_name` - The ElevenLabs model identifier.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the model name set.
+    pub fn model_name(mut self, model_name: impl Into<String>) -> Self {
+        self.model_name = model_name.into();
+        self
+    }
+}
+
+impl<M: ModelName> ElevenLabsBuilder<M> {
+    /// Sets the base URL for the ElevenLabs API.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.settings.base_url = base_url.into();
+        self
+    }
+
+    /// Sets the API key for the ElevenLabs API.
+    pub fn api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.settings.api_key = api_key.into();
+        self
+    }
+
+    /// Sets the ID of the voice used when a request does not name one. Defaults to
+    /// `ELEVENLABS_VOICE_ID`.
+    pub fn voice_id(mut self, voice_id: impl Into<String>) -> Self {
+        self.settings.voice_id = Some(voice_id.into());
+        self
+    }
+
+    /// Sets the format of the returned audio used when a request does not set one,
+    /// e.g. `mp3_44100_128` or `pcm_16000`.
+    pub fn output_format(mut self, output_format: impl Into<String>) -> Self {
+        self.settings.output_format = Some(output_format.into());
+        self
+    }
+
+    /// Sets the settings that tune how the voice sounds.
+    pub fn voice_settings(mut self, voice_settings: ElevenLabsVoiceSettings) -> Self {
+        self.settings.voice_settings = Some(voice_settings);
+        self
+    }
+
+    /// Sets the retry policy applied to requests made by this provider.
+    ///
+    /// # Parameters
+    ///
+    /// * `retry_policy` - The [`RetryPolicy`] to use for retryable failures.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the retry policy set.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.settings.retry_policy = retry_policy;
+        self
+    }
+
+    crate::core::client::transport_builder_methods!();
+
+    /// Sets the name of the provider. Defaults to "elevenlabs".
+    pub fn provider_name(mut self, provider_name: impl Into<String>) -> Self {
+        self.settings.provider_name = provider_name.into();
+        self
+    }
+
+    /// Builds the ElevenLabs provider.
+    pub fn build(self) -> Result<ElevenLabs<M>> {
+        // validate base url
+        let base_url = validate_base_url(&self.settings.base_url)?;
+
+        // check api key exists
+        if self.settings.api_key.is_empty() {
+            return Err(Error::MissingField("api_key".to_string()));
+        }
+
+        if self.model_name.is_empty() {
+            return Err(Error::MissingField("model_name".to_string()));
+        }
+
+        Ok(ElevenLabs::with_model(
+            ElevenLabsProviderSettings {
+                base_url,
+                ..self.settings
+            },
+            self.model_name,
+        ))
+    }
+}
+
+// Re-exports for convenience
+pub use capabilities::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::client::SpeechClient;
+
+    fn provider() -> ElevenLabs<DynamicModel> {
+        ElevenLabs::<DynamicModel>::builder()
+            .model_name("eleven_flash_v2_5")
+            .api_key("key")
+            .voice_id("default-voice")
+            .output_format("pcm_16000")
+            .voice_settings(ElevenLabsVoiceSettings {
+                stability: Some(0.5),
+                ..Default::default()
+            })
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_request_uses_provider_voice_and_format() {
+        let options = SpeechModelOptions::builder().text("Hello").build().unwrap();
+        let model = provider().with_request(options, true).unwrap();
+
+        assert_eq!(
+            SpeechClient::path(&model),
+            "text-to-speech/default-voice/stream"
+        );
+        assert_eq!(
+            SpeechClient::query_params(&model),
+            vec![("output_format", "pcm_16000")]
+        );
+        assert_eq!(
+            serde_json::to_value(&model.speech_options.request).unwrap(),
+            serde_json::json!({
+                "text": "Hello",
+                "model_id": "eleven_flash_v2_5",
+                "voice_settings": { "stability": 0.5 }
+            })
+        );
+    }
+
+    #[test]
+    fn test_request_voice_and_format_take_precedence() {
+        let options = SpeechModelOptions::builder()
+            .text("Hello")
+            .voice("other-voice".to_string())
+            .format("mp3_22050_32".to_string())
+            .build()
+            .unwrap();
+        let model = provider().with_request(options, false).unwrap();
+
+        assert_eq!(SpeechClient::path(&model), "text-to-speech/other-voice");
+        assert_eq!(
+            SpeechClient::query_params(&model),
+            vec![("output_format", "mp3_22050_32")]
+        );
+    }
+
+    #[test]
+    fn test_request_requires_voice() {
+        let mut provider = provider();
+        provider.settings.voice_id = None;
+
+        let options = SpeechModelOptions::builder().text("Hello").build().unwrap();
+        assert!(matches!(
+            provider.with_request(options, false),
+            Err(Error::MissingField(field)) if field == "voice_id"
+        ));
+    }
+}
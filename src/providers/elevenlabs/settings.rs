@@ -0,0 +1,96 @@
+//! Defines the settings for the ElevenLabs provider.
+
+use crate::core::client::{RetryPolicy, TransportSettings};
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+/// Settings that tune how a voice sounds. Unset fields use the voice's stored
+/// settings.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ElevenLabsVoiceSettings {
+    /// How stable the voice is between generations, from 0 to 1. Lower values are
+    /// more expressive.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stability: Option<f32>,
+    /// How closely the voice adheres to the original voice, from 0 to 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub similarity_boost: Option<f32>,
+    /// How much the style of the original speaker is exaggerated, from 0 to 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<f32>,
+    /// Whether the similarity to the original speaker is boosted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_speaker_boost: Option<bool>,
+    /// The speed of the speech, where 1 is the normal speed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub speed: Option<f32>,
+}
+
+/// Settings for the ElevenLabs provider.
+#[derive(Clone, Serialize, Deserialize, Builder)]
+#[builder(setter(into), default)]
+pub struct ElevenLabsProviderSettings {
+    /// The name of the provider.
+    pub provider_name: String,
+
+    /// The base URL of the ElevenLabs API.
+    pub base_url: String,
+
+    /// The API key for the ElevenLabs API.
+    pub api_key: String,
+
+    /// The ID of the voice used when a request does not name one.
+    pub voice_id: Option<String>,
+
+    /// The format of the returned audio used when a request does not set one, e.g.
+    /// `mp3_44100_128` or `pcm_16000`. ElevenLabs returns `mp3_44100_128` when unset.
+    pub output_format: Option<String>,
+
+    /// Settings that tune how the voice sounds.
+    pub voice_settings: Option<ElevenLabsVoiceSettings>,
+
+    /// The retry policy applied to requests.
+    pub retry_policy: RetryPolicy,
+
+    /// The HTTP client, middleware and rate limiter requests go through.
+    #[serde(skip)]
+    pub transport: TransportSettings,
+}
+
+impl std::fmt::Debug for ElevenLabsProviderSettings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ElevenLabsProviderSettings")
+            .field("provider_name", &self.provider_name)
+            .field("base_url", &self.base_url)
+            .field("api_key", &"<redacted>")
+            .field("voice_id", &self.voice_id)
+            .field("output_format", &self.output_format)
+            .field("voice_settings", &self.voice_settings)
+            .field("retry_policy", &self.retry_policy)
+            .field("transport", &self.transport)
+            .finish()
+    }
+}
+
+impl Default for ElevenLabsProviderSettings {
+    /// Returns the default settings for the ElevenLabs provider.
+    fn default() -> Self {
+        Self {
+            provider_name: "elevenlabs".to_string(),
+            base_url: "https://api.elevenlabs.io/v1/".to_string(),
+            api_key: std::env::var("ELEVENLABS_API_KEY").unwrap_or_default(),
+            voice_id: std::env::var("ELEVENLABS_VOICE_ID").ok(),
+            output_format: None,
+            voice_settings: None,
+            retry_policy: RetryPolicy::default(),
+            transport: TransportSettings::default(),
+        }
+    }
+}
+
+impl ElevenLabsProviderSettings {
+    /// Creates a new builder for `ElevenLabsProviderSettings`.
+    pub fn builder() -> ElevenLabsProviderSettingsBuilder {
+        ElevenLabsProviderSettingsBuilder::default()
+    }
+}
@@ -0,0 +1,29 @@
+//! Speech model implementation for the ElevenLabs provider.
+
+use crate::{
+    core::{
+        capabilities::ModelName,
+        client::SpeechClient,
+        speech_model::{SpeechModel, SpeechModelOptions, SpeechModelResponse, SpeechStream},
+    },
+    error::Result,
+    providers::elevenlabs::ElevenLabs,
+};
+use async_trait::async_trait;
+
+#[async_trait]
+impl<M: ModelName> SpeechModel for ElevenLabs<M> {
+    async fn generate_speech(&self, input: SpeechModelOptions) -> Result<SpeechModelResponse> {
+        let model = self.with_request(input, false)?;
+
+        let (audio, media_type) = model.send(&model.settings.base_url).await?;
+
+        Ok(SpeechModelResponse { audio, media_type })
+    }
+
+    async fn stream_speech(&self, input: SpeechModelOptions) -> Result<SpeechStream> {
+        let model = self.with_request(input, true)?;
+
+        model.send_and_stream(&model.settings.base_url).await
+    }
+}
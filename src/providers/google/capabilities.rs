@@ -14,7 +14,11 @@ model_capabilities! {
             model_name: "gemini-1.5-flash",
             constructor_name: gemini_1_5_flash,
             display_name: "Gemini 1.5 Flash",
-            capabilities: [AudioInputSupport, ImageInputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport, VideoInputSupport]
+            capabilities: [AudioInputSupport, ImageInputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport, VideoInputSupport],
+            context_window: 1_048_576,
+            max_output_tokens: 8192,
+            input_price: 0.075,
+            output_price: 0.3
         },
         Gemini15Flash8b {
             model_name: "gemini-1.5-flash-8b",
@@ -26,25 +30,41 @@ model_capabilities! {
             model_name: "gemini-1.5-pro",
             constructor_name: gemini_1_5_pro,
             display_name: "Gemini 1.5 Pro",
-            capabilities: [AudioInputSupport, ImageInputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport, VideoInputSupport]
+            capabilities: [AudioInputSupport, ImageInputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport, VideoInputSupport],
+            context_window: 2_097_152,
+            max_output_tokens: 8192,
+            input_price: 1.25,
+            output_price: 5.0
         },
         Gemini20Flash {
             model_name: "gemini-2.0-flash",
             constructor_name: gemini_2_0_flash,
             display_name: "Gemini 2.0 Flash",
-            capabilities: [AudioInputSupport, ImageInputSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport, VideoInputSupport]
+            capabilities: [AudioInputSupport, ImageInputSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport, VideoInputSupport],
+            context_window: 1_048_576,
+            max_output_tokens: 8192,
+            input_price: 0.1,
+            output_price: 0.4
         },
         Gemini20FlashLite {
             model_name: "gemini-2.0-flash-lite",
             constructor_name: gemini_2_0_flash_lite,
             display_name: "Gemini 2.0 Flash Lite",
-            capabilities: [AudioInputSupport, ImageInputSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport, VideoInputSupport]
+            capabilities: [AudioInputSupport, ImageInputSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport, VideoInputSupport],
+            context_window: 1_048_576,
+            max_output_tokens: 8192,
+            input_price: 0.075,
+            output_price: 0.3
         },
         Gemini25Flash {
             model_name: "gemini-2.5-flash",
             constructor_name: gemini_2_5_flash,
             display_name: "Gemini 2.5 Flash",
-            capabilities: [AudioInputSupport, ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport, VideoInputSupport]
+            capabilities: [AudioInputSupport, ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport, VideoInputSupport],
+            context_window: 1_048_576,
+            max_output_tokens: 65_536,
+            input_price: 0.3,
+            output_price: 2.5
         },
         Gemini25FlashImage {
             model_name: "gemini-2.5-flash-image",
@@ -62,7 +82,11 @@ model_capabilities! {
             model_name: "gemini-2.5-flash-lite",
             constructor_name: gemini_2_5_flash_lite,
             display_name: "Gemini 2.5 Flash Lite",
-            capabilities: [AudioInputSupport, ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport, VideoInputSupport]
+            capabilities: [AudioInputSupport, ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport, VideoInputSupport],
+            context_window: 1_048_576,
+            max_output_tokens: 65_536,
+            input_price: 0.1,
+            output_price: 0.4
         },
         Gemini25FlashLitePreview0617 {
             model_name: "gemini-2.5-flash-lite-preview-06-17",
@@ -104,7 +128,11 @@ model_capabilities! {
             model_name: "gemini-2.5-pro",
             constructor_name: gemini_2_5_pro,
             display_name: "Gemini 2.5 Pro",
-            capabilities: [AudioInputSupport, ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport, VideoInputSupport]
+            capabilities: [AudioInputSupport, ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport, VideoInputSupport],
+            context_window: 1_048_576,
+            max_output_tokens: 65_536,
+            input_price: 1.25,
+            output_price: 10.0
         },
         Gemini25ProPreview0506 {
             model_name: "gemini-2.5-pro-preview-05-06",
@@ -128,13 +156,21 @@ model_capabilities! {
             model_name: "gemini-3-flash-preview",
             constructor_name: gemini_3_flash_preview,
             display_name: "Gemini 3 Flash Preview",
-            capabilities: [AudioInputSupport, ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport, VideoInputSupport]
+            capabilities: [AudioInputSupport, ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport, VideoInputSupport],
+            context_window: 1_048_576,
+            max_output_tokens: 65_536,
+            input_price: 0.5,
+            output_price: 3.0
         },
         Gemini3ProPreview {
             model_name: "gemini-3-pro-preview",
             constructor_name: gemini_3_pro_preview,
             display_name: "Gemini 3 Pro Preview",
-            capabilities: [AudioInputSupport, ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport, VideoInputSupport]
+            capabilities: [AudioInputSupport, ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport, VideoInputSupport],
+            context_window: 1_048_576,
+            max_output_tokens: 65_536,
+            input_price: 2.0,
+            output_price: 12.0
         },
         GeminiEmbedding001 {
             model_name: "gemini-embedding-001",
@@ -166,5 +202,23 @@ model_capabilities! {
             display_name: "Gemini Live 2.5 Flash Preview Native Audio",
             capabilities: [AudioInputSupport, AudioOutputSupport, ReasoningSupport, TextInputSupport, TextOutputSupport, ToolCallSupport, VideoInputSupport]
         },
+        Veo20Generate001 {
+            model_name: "veo-2.0-generate-001",
+            constructor_name: veo_2_0_generate_001,
+            display_name: "Veo 2",
+            capabilities: [TextInputSupport, VideoOutputSupport]
+        },
+        Veo30FastGenerate001 {
+            model_name: "veo-3.0-fast-generate-001",
+            constructor_name: veo_3_0_fast_generate_001,
+            display_name: "Veo 3 Fast",
+            capabilities: [TextInputSupport, VideoOutputSupport]
+        },
+        Veo30Generate001 {
+            model_name: "veo-3.0-generate-001",
+            constructor_name: veo_3_0_generate_001,
+            display_name: "Veo 3",
+            capabilities: [TextInputSupport, VideoOutputSupport]
+        },
     }
 }
@@ -1,5 +1,9 @@
 //! Client implementation for the Google provider.
-use crate::core::client::{EmbeddingClient, LanguageModelClient};
+use crate::core::client::{
+    EmbeddingClient, LanguageModelClient, ModelListClient, RetryPolicy, TokenCountClient,
+    VideoClient, sse_error,
+};
+use crate::core::provider::ModelDescriptor;
 use crate::error::{Error, Result};
 use crate::providers::google::{Google, ModelName};
 use derive_builder::Builder;
@@ -34,6 +38,12 @@ pub(crate) struct GoogleEmbeddingOptions {
     pub(crate) requests: Vec<types::EmbedContentRequest>,
 }
 
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub(crate) struct GoogleVideoOptions {
+    pub(crate) model: String,
+    pub(crate) request: types::PredictLongRunningRequest,
+}
+
 impl<M: ModelName> LanguageModelClient for Google<M> {
     type Response = types::GenerateContentResponse;
     type StreamEvent = types::GoogleStreamEvent;
@@ -69,6 +79,14 @@ impl<M: ModelName> LanguageModelClient for Google<M> {
         Vec::new()
     }
 
+    fn retry_policy(&self) -> RetryPolicy {
+        self.settings.retry_policy.clone()
+    }
+
+    fn transport(&self) -> crate::core::client::TransportSettings {
+        self.settings.transport.clone()
+    }
+
     fn body(&self) -> reqwest::Body {
         if let Some(request) = &self.lm_options.request {
             let body = serde_json::to_string(request).unwrap();
@@ -80,44 +98,47 @@ impl<M: ModelName> LanguageModelClient for Google<M> {
     fn parse_stream_sse(
         event: std::result::Result<Event, reqwest_eventsource::Error>,
     ) -> Result<Self::StreamEvent> {
-        match event {
-            Ok(event) => match event {
-                Event::Open => Ok(types::GoogleStreamEvent::NotSupported("{}".to_string())),
-                Event::Message(msg) => {
-                    let value: serde_json::Value =
-                        serde_json::from_str(&msg.data).map_err(|e| Error::ApiError {
-                            status_code: None,
-                            details: format!("Invalid JSON in SSE data: {e}"),
-                        })?;
-
-                    Ok(
-                        serde_json::from_value::<types::GenerateContentResponse>(value)
-                            .map(types::GoogleStreamEvent::Response)
-                            .unwrap_or(types::GoogleStreamEvent::NotSupported(msg.data)),
-                    )
-                }
-            },
-            Err(e) => {
-                // Extract status code if it's an InvalidStatusCode error
-                let status_code = match &e {
-                    reqwest_eventsource::Error::InvalidStatusCode(status, _) => Some(*status),
-                    _ => None,
-                };
-                Err(Error::ApiError {
-                    status_code,
-                    details: e.to_string(),
-                })
-            }
-        }
+        parse_stream_event(event)
     }
 
     fn end_stream(event: &Self::StreamEvent) -> bool {
-        match event {
-            types::GoogleStreamEvent::Response(resp) => {
-                resp.candidates.iter().any(|c| c.finish_reason.is_some())
+        is_stream_end(event)
+    }
+}
+
+/// Parses a `streamGenerateContent` SSE event. Shared with the Vertex AI provider, which
+/// streams the same responses.
+pub(crate) fn parse_stream_event(
+    event: std::result::Result<Event, reqwest_eventsource::Error>,
+) -> Result<types::GoogleStreamEvent> {
+    match event {
+        Ok(event) => match event {
+            Event::Open => Ok(types::GoogleStreamEvent::NotSupported("{}".to_string())),
+            Event::Message(msg) => {
+                let value: serde_json::Value =
+                    serde_json::from_str(&msg.data).map_err(|e| Error::ApiError {
+                        status_code: None,
+                        details: format!("Invalid JSON in SSE data: {e}"),
+                    })?;
+
+                Ok(
+                    serde_json::from_value::<types::GenerateContentResponse>(value)
+                        .map(types::GoogleStreamEvent::Response)
+                        .unwrap_or(types::GoogleStreamEvent::NotSupported(msg.data)),
+                )
             }
-            _ => false,
+        },
+        Err(e) => Err(sse_error(e)),
+    }
+}
+
+/// Returns true once a candidate of the streamed response has finished.
+pub(crate) fn is_stream_end(event: &types::GoogleStreamEvent) -> bool {
+    match event {
+        types::GoogleStreamEvent::Response(resp) => {
+            resp.candidates.iter().any(|c| c.finish_reason.is_some())
         }
+        _ => false,
     }
 }
 
@@ -146,6 +167,14 @@ impl<M: ModelName> EmbeddingClient for Google<M> {
         Vec::new()
     }
 
+    fn retry_policy(&self) -> RetryPolicy {
+        self.settings.retry_policy.clone()
+    }
+
+    fn transport(&self) -> crate::core::client::TransportSettings {
+        self.settings.transport.clone()
+    }
+
     fn body(&self) -> reqwest::Body {
         let request = types::BatchEmbedContentsRequest {
             requests: self.embedding_options.requests.clone(),
@@ -154,3 +183,140 @@ impl<M: ModelName> EmbeddingClient for Google<M> {
         reqwest::Body::from(body)
     }
 }
+
+impl<M: ModelName> TokenCountClient for Google<M> {
+    type Response = types::CountTokensResponse;
+
+    fn path(&self) -> String {
+        format!("/v1beta/models/{}:countTokens", self.lm_options.model)
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn headers(&self) -> reqwest::header::HeaderMap {
+        LanguageModelClient::headers(self)
+    }
+
+    fn query_params(&self) -> Vec<(&str, &str)> {
+        Vec::new()
+    }
+
+    fn retry_policy(&self) -> RetryPolicy {
+        self.settings.retry_policy.clone()
+    }
+
+    fn transport(&self) -> crate::core::client::TransportSettings {
+        self.settings.transport.clone()
+    }
+
+    fn body(&self) -> reqwest::Body {
+        let Some(request) = &self.lm_options.request else {
+            return reqwest::Body::from("{}");
+        };
+        let request = types::CountTokensRequest {
+            generate_content_request: types::CountTokensContentRequest {
+                model: format!("models/{}", self.lm_options.model),
+                request: request.clone(),
+            },
+        };
+        let body = serde_json::to_string(&request).unwrap();
+        reqwest::Body::from(body)
+    }
+}
+
+impl<M: ModelName> VideoClient for Google<M> {
+    type Job = types::Operation;
+
+    fn path(&self) -> String {
+        format!(
+            "/v1beta/models/{}:predictLongRunning",
+            self.video_options.model
+        )
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn headers(&self) -> reqwest::header::HeaderMap {
+        let mut headers = self.auth_headers();
+        headers.insert(CONTENT_TYPE, "application/json".parse().unwrap());
+        headers
+    }
+
+    fn auth_headers(&self) -> reqwest::header::HeaderMap {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert("x-goog-api-key", self.settings.api_key.parse().unwrap());
+        headers
+    }
+
+    fn query_params(&self) -> Vec<(&str, &str)> {
+        Vec::new()
+    }
+
+    fn retry_policy(&self) -> RetryPolicy {
+        self.settings.retry_policy.clone()
+    }
+
+    fn transport(&self) -> crate::core::client::TransportSettings {
+        self.settings.transport.clone()
+    }
+
+    fn body(&self) -> reqwest::Body {
+        let body = serde_json::to_string(&self.video_options.request).unwrap();
+        reqwest::Body::from(body)
+    }
+}
+
+impl<M: ModelName> ModelListClient for Google<M> {
+    type Response = types::ModelList;
+
+    fn path(&self) -> String {
+        "/v1beta/models".to_string()
+    }
+
+    fn query_params(&self) -> Vec<(&str, &str)> {
+        vec![("pageSize", "1000")]
+    }
+
+    fn headers(&self) -> reqwest::header::HeaderMap {
+        EmbeddingClient::headers(self)
+    }
+
+    fn retry_policy(&self) -> RetryPolicy {
+        self.settings.retry_policy.clone()
+    }
+
+    fn transport(&self) -> crate::core::client::TransportSettings {
+        self.settings.transport.clone()
+    }
+
+    fn next_page(response: &Self::Response) -> Option<(&'static str, String)> {
+        response
+            .next_page_token
+            .clone()
+            .filter(|token| !token.is_empty())
+            .map(|token| ("pageToken", token))
+    }
+
+    fn descriptors(response: Self::Response) -> Vec<ModelDescriptor> {
+        response
+            .models
+            .into_iter()
+            .map(|model| ModelDescriptor {
+                id: model
+                    .name
+                    .strip_prefix("models/")
+                    .unwrap_or(&model.name)
+                    .to_string(),
+                display_name: model.display_name,
+                description: model.description,
+                owned_by: Some("google".to_string()),
+                context_window: model.input_token_limit,
+                max_output_tokens: model.output_token_limit,
+            })
+            .collect()
+    }
+}
@@ -16,6 +16,28 @@ pub(crate) struct GenerateContentRequest {
     pub(crate) generation_config: Option<GenerationConfig>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) cached_content: Option<String>,
+    /// Provider-specific fields merged into the request body.
+    #[serde(flatten, skip_serializing_if = "serde_json::Map::is_empty")]
+    pub(crate) provider_options: serde_json::Map<String, serde_json::Value>,
+}
+
+impl GenerateContentRequest {
+    /// Applies the thinking settings of the provider over the ones derived from the
+    /// reasoning effort.
+    pub(crate) fn set_thinking(
+        &mut self,
+        thinking_budget: Option<i32>,
+        include_thoughts: Option<bool>,
+    ) {
+        if thinking_budget.is_none() && include_thoughts.is_none() {
+            return;
+        }
+        if let Some(config) = self.generation_config.as_mut() {
+            let thinking = config.thinking_config.get_or_insert_with(Default::default);
+            thinking.thinking_budget = thinking_budget.or(thinking.thinking_budget);
+            thinking.include_thoughts = include_thoughts.or(thinking.include_thoughts);
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -52,6 +74,9 @@ pub(crate) struct Part {
     pub(crate) code_execution_result: Option<CodeExecutionResult>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) thought_signature: Option<String>,
+    /// Whether the part is a summary of the model's thoughts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) thought: Option<bool>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -62,6 +87,7 @@ pub(crate) struct Blob {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub(crate) struct FileData {
     pub(crate) mime_type: String,
     pub(crate) file_uri: String,
@@ -129,6 +155,7 @@ pub(crate) struct ToolConfig {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub(crate) struct FunctionCallingConfig {
     pub(crate) mode: FunctionCallingMode,
     pub(crate) allowed_function_names: Option<Vec<String>>,
@@ -158,6 +185,18 @@ pub(crate) struct GenerationConfig {
     pub(crate) frequency_penalty: Option<f32>,
     pub(crate) response_logprobs: Option<bool>,
     pub(crate) logprobs: Option<i32>,
+    pub(crate) seed: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) thinking_config: Option<ThinkingConfig>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ThinkingConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) thinking_budget: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) include_thoughts: Option<bool>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -224,6 +263,8 @@ pub(crate) enum FinishReason {
     ProhibitedContent,
     Spii,
     MalformedFunctionCall,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -349,6 +390,26 @@ pub(crate) struct EmbedContentRequest {
     pub(crate) output_dimensionality: Option<usize>,
 }
 
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CountTokensRequest {
+    pub(crate) generate_content_request: CountTokensContentRequest,
+}
+
+/// A `generateContent` request naming its model, as `countTokens` expects it.
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct CountTokensContentRequest {
+    pub(crate) model: String,
+    #[serde(flatten)]
+    pub(crate) request: GenerateContentRequest,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CountTokensResponse {
+    pub(crate) total_tokens: usize,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub(crate) struct BatchEmbedContentsResponse {
     pub(crate) embeddings: Vec<ContentEmbedding>,
@@ -358,3 +419,105 @@ pub(crate) struct BatchEmbedContentsResponse {
 pub(crate) struct ContentEmbedding {
     pub(crate) values: Vec<f32>,
 }
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct PredictLongRunningRequest {
+    pub(crate) instances: Vec<VideoInstance>,
+    pub(crate) parameters: VideoParameters,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct VideoInstance {
+    pub(crate) prompt: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct VideoParameters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) aspect_ratio: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) negative_prompt: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) duration_seconds: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) resolution: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) seed: Option<u64>,
+}
+
+/// A long running operation, see
+/// [Operations](https://ai.google.dev/api/batch-mode#Operation).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Operation {
+    pub(crate) name: String,
+    #[serde(default)]
+    pub(crate) done: bool,
+    #[serde(default)]
+    pub(crate) error: Option<OperationError>,
+    #[serde(default)]
+    pub(crate) response: Option<OperationResponse>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct OperationError {
+    #[serde(default)]
+    pub(crate) code: Option<i32>,
+    #[serde(default)]
+    pub(crate) message: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct OperationResponse {
+    #[serde(default)]
+    pub(crate) generate_video_response: Option<GenerateVideoResponse>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct GenerateVideoResponse {
+    #[serde(default)]
+    pub(crate) generated_samples: Vec<GeneratedSample>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct GeneratedSample {
+    #[serde(default)]
+    pub(crate) video: Option<VideoFile>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct VideoFile {
+    pub(crate) uri: String,
+    #[serde(default)]
+    pub(crate) mime_type: Option<String>,
+}
+
+/// A page of the `models.list` endpoint.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ModelList {
+    #[serde(default)]
+    pub(crate) models: Vec<Model>,
+    #[serde(default)]
+    pub(crate) next_page_token: Option<String>,
+}
+
+/// A model of the `models.list` endpoint.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Model {
+    /// The resource name, e.g. `models/gemini-2.5-pro`.
+    pub(crate) name: String,
+    #[serde(default)]
+    pub(crate) display_name: Option<String>,
+    #[serde(default)]
+    pub(crate) description: Option<String>,
+    #[serde(default)]
+    pub(crate) input_token_limit: Option<usize>,
+    #[serde(default)]
+    pub(crate) output_token_limit: Option<usize>,
+}
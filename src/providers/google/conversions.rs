@@ -1,13 +1,18 @@
 //! Conversions between types used by the Google provider and the types used by the core library.
 use crate::core::embedding_model::EmbeddingModelOptions;
-use crate::core::language_model::{LanguageModelOptions, LanguageModelResponseContentType, Usage};
-use crate::core::messages::{Message, TaggedMessage};
-use crate::core::tools::Tool;
+use crate::core::image_model::{GeneratedImage, ImageData};
+use crate::core::language_model::{
+    FinishReason, LanguageModelOptions, LanguageModelResponseContentType, ReasoningEffort, Usage,
+};
+use crate::core::messages::{ContentPart, MediaSource, Message, TaggedMessage};
+use crate::core::tools::{Tool, ToolChoice};
 use crate::providers::google::client::GoogleEmbeddingOptions;
 use crate::providers::google::client::types::{
     self, Content, FunctionDeclaration, GenerateContentRequest, Part, Role,
 };
 use crate::providers::google::extensions::GoogleToolMetadata;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
 use serde_json::Value;
 
 impl From<Tool> for FunctionDeclaration {
@@ -25,9 +30,92 @@ impl From<Tool> for FunctionDeclaration {
     }
 }
 
+impl From<types::Blob> for GeneratedImage {
+    fn from(blob: types::Blob) -> Self {
+        Self {
+            data: ImageData::Base64(blob.data),
+            media_type: Some(blob.mime_type),
+            seed: None,
+            revised_prompt: None,
+        }
+    }
+}
+
+// Sends a generated image back to the model, e.g. to edit it in a later turn
+impl From<GeneratedImage> for Part {
+    fn from(image: GeneratedImage) -> Self {
+        let mime_type = image.media_type.unwrap_or_else(|| "image/png".to_string());
+        match image.data {
+            ImageData::Base64(data) => Part {
+                inline_data: Some(types::Blob { mime_type, data }),
+                ..Default::default()
+            },
+            ImageData::Bytes(bytes) => Part {
+                inline_data: Some(types::Blob {
+                    mime_type,
+                    data: STANDARD.encode(bytes),
+                }),
+                ..Default::default()
+            },
+            ImageData::Url(file_uri) => Part {
+                file_data: Some(types::FileData {
+                    mime_type,
+                    file_uri,
+                }),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl From<ContentPart> for Part {
+    fn from(part: ContentPart) -> Self {
+        let (source, mime_type) = match part {
+            ContentPart::Text(text) => {
+                return Part {
+                    text: Some(text),
+                    ..Default::default()
+                };
+            }
+            ContentPart::Image(image) => {
+                let mime_type = image.media_type().to_string();
+                (image.source, mime_type)
+            }
+            ContentPart::File(file) => (file.source, file.media_type),
+            ContentPart::Document(document) => (document.source, document.media_type),
+            ContentPart::Audio(audio) => (audio.source, audio.media_type),
+        };
+        match source {
+            MediaSource::Base64(data) => Part {
+                inline_data: Some(types::Blob { mime_type, data }),
+                ..Default::default()
+            },
+            MediaSource::Url(file_uri) => Part {
+                file_data: Some(types::FileData {
+                    mime_type,
+                    file_uri,
+                }),
+                ..Default::default()
+            },
+        }
+    }
+}
+
 impl From<LanguageModelOptions> for GenerateContentRequest {
     fn from(options: LanguageModelOptions) -> Self {
-        let contents = options.messages.into_iter().map(|m| m.into()).collect();
+        // Gemini keeps its reasoning through thought signatures, the thoughts are not sent back
+        let contents = options
+            .messages
+            .into_iter()
+            .filter(|m| {
+                !matches!(
+                    &m.message,
+                    Message::Assistant(a)
+                        if matches!(a.content, LanguageModelResponseContentType::Reasoning { .. })
+                )
+            })
+            .map(|m| m.into())
+            .collect();
 
         let system_instruction = options.system.map(|s| Content {
             role: Role::User, // System instructions are often text-only content
@@ -70,16 +158,46 @@ impl From<LanguageModelOptions> for GenerateContentRequest {
             frequency_penalty: options.frequency_penalty,
             response_logprobs: None,
             logprobs: None,
+            seed: options.seed,
+            thinking_config: options
+                .reasoning_effort
+                .map(|effort| types::ThinkingConfig {
+                    thinking_budget: Some(match effort {
+                        // The smallest budget Gemini Pro models accept
+                        ReasoningEffort::Minimal => 128,
+                        ReasoningEffort::Low => 1024,
+                        ReasoningEffort::Medium => 8192,
+                        ReasoningEffort::High => 24576,
+                    }),
+                    include_thoughts: Some(true),
+                }),
+        });
+
+        // Without a tool choice, the model decides whether to call tools
+        let tool_config = tools.as_ref().and(options.tool_choice).map(|choice| {
+            let (mode, allowed_function_names) = match choice {
+                ToolChoice::Auto => (types::FunctionCallingMode::Auto, None),
+                ToolChoice::Required => (types::FunctionCallingMode::Any, None),
+                ToolChoice::None => (types::FunctionCallingMode::None, None),
+                ToolChoice::Tool(name) => (types::FunctionCallingMode::Any, Some(vec![name])),
+            };
+            types::ToolConfig {
+                function_calling_config: Some(types::FunctionCallingConfig {
+                    mode,
+                    allowed_function_names,
+                }),
+            }
         });
 
         Self {
             contents,
             tools,
-            tool_config: None, // Default to auto
+            tool_config,
             safety_settings: None,
             system_instruction,
             generation_config,
             cached_content: None,
+            provider_options: options.provider_options,
         }
     }
 }
@@ -95,10 +213,7 @@ impl From<Message> for Content {
         match message {
             Message::User(u) => Content {
                 role: Role::User,
-                parts: vec![Part {
-                    text: Some(u.content),
-                    ..Default::default()
-                }],
+                parts: u.content_parts().into_iter().map(Into::into).collect(),
             },
             Message::Assistant(a) => {
                 let part = match a.content {
@@ -125,6 +240,7 @@ impl From<Message> for Content {
                         }
                         part
                     }
+                    LanguageModelResponseContentType::Image(image) => image.into(),
                     _ => Part::default(),
                 };
                 Content {
@@ -166,6 +282,60 @@ impl From<Message> for Content {
     }
 }
 
+/// Maps a Gemini finish reason. Gemini stops with `STOP` when it calls tools, so
+/// `calls_tools` tells the two apart.
+pub(crate) fn finish_reason(reason: types::FinishReason, calls_tools: bool) -> FinishReason {
+    match reason {
+        types::FinishReason::Stop if calls_tools => FinishReason::ToolCalls,
+        types::FinishReason::Stop => FinishReason::Stop,
+        types::FinishReason::MaxTokens => FinishReason::Length,
+        types::FinishReason::Safety
+        | types::FinishReason::Recitation
+        | types::FinishReason::Blocklist
+        | types::FinishReason::ProhibitedContent
+        | types::FinishReason::Spii => FinishReason::ContentFilter,
+        types::FinishReason::MalformedFunctionCall => FinishReason::Error,
+        types::FinishReason::FinishReasonUnspecified | types::FinishReason::Other => {
+            FinishReason::Other("OTHER".to_string())
+        }
+        types::FinishReason::Unknown(reason) => FinishReason::Other(reason),
+    }
+}
+
+/// Returns the refusal content for a candidate Gemini blocked, with the finish reason
+/// as its message.
+pub(crate) fn refusal(reason: &types::FinishReason) -> Option<LanguageModelResponseContentType> {
+    match reason {
+        types::FinishReason::Safety
+        | types::FinishReason::Recitation
+        | types::FinishReason::Blocklist
+        | types::FinishReason::ProhibitedContent
+        | types::FinishReason::Spii => Some(LanguageModelResponseContentType::Refusal(
+            reason_name(reason),
+        )),
+        _ => None,
+    }
+}
+
+/// Returns the refusal content for a prompt Gemini blocked, with the block reason as
+/// its message.
+pub(crate) fn prompt_refusal(
+    feedback: Option<&types::PromptFeedback>,
+) -> Option<LanguageModelResponseContentType> {
+    let reason = feedback?.block_reason.as_ref()?;
+    Some(LanguageModelResponseContentType::Refusal(reason_name(
+        reason,
+    )))
+}
+
+/// Returns the wire name of a Gemini enum, such as `SAFETY`.
+fn reason_name(reason: &impl serde::Serialize) -> String {
+    match serde_json::to_value(reason) {
+        Ok(Value::String(name)) => name,
+        _ => String::new(),
+    }
+}
+
 impl From<types::UsageMetadata> for Usage {
     fn from(value: types::UsageMetadata) -> Self {
         Self {
@@ -1,7 +1,10 @@
 //! Google tool-specific metadata for extensions.
 
+use crate::extensions::Extension;
+use serde::{Deserialize, Serialize};
+
 /// Metadata specific to Google provider tool functionality.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub(crate) struct GoogleToolMetadata {
     /// The thought signature returned by Gemini 3 models.
     ///
@@ -9,3 +12,7 @@ pub(crate) struct GoogleToolMetadata {
     /// for tool calls to work correctly.
     pub thought_signature: Option<String>,
 }
+
+impl Extension for GoogleToolMetadata {
+    const NAME: &'static str = "google.tool_metadata";
+}
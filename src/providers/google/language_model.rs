@@ -1,18 +1,24 @@
 //! Language model implementation for the Google provider.
 use crate::core::capabilities::ModelName;
 use crate::core::client::LanguageModelClient;
+use crate::core::image_model::GeneratedImage;
 use crate::core::language_model::{
-    LanguageModelOptions, LanguageModelResponse, LanguageModelResponseContentType,
+    FinishReason, LanguageModelOptions, LanguageModelResponse, LanguageModelResponseContentType,
     LanguageModelStreamChunk, LanguageModelStreamChunkType, ProviderStream, Usage,
+    stream_with_rate_limit,
 };
 use crate::core::messages::AssistantMessage;
-use crate::providers::google::{Google, client::types, extensions};
+use crate::providers::google::{Google, client::types, conversions, extensions};
 use crate::{
-    core::{language_model::LanguageModel, tools::ToolCallInfo},
+    core::{
+        language_model::LanguageModel,
+        tools::{ToolCallDelta, ToolCallInfo},
+    },
     error::Result,
 };
 use async_trait::async_trait;
-use futures::StreamExt;
+use futures::{Stream, StreamExt};
+use std::pin::Pin;
 
 #[async_trait]
 impl<M: ModelName> LanguageModel for Google<M> {
@@ -20,140 +26,488 @@ impl<M: ModelName> LanguageModel for Google<M> {
         self.lm_options.model.clone()
     }
 
+    fn capabilities(&self) -> crate::core::capabilities::Capabilities {
+        M::CAPABILITIES
+    }
+
+    fn info(&self) -> crate::core::capabilities::ModelInfo {
+        Self::model_info(&self.name())
+    }
+
+    /// Counts tokens with the `countTokens` method.
+    async fn count_tokens(&self, options: &LanguageModelOptions) -> Result<usize> {
+        use crate::core::client::TokenCountClient;
+
+        let mut model = self.clone();
+        model.lm_options.request = Some(options.clone().into());
+
+        let response = TokenCountClient::send(&model, &model.settings.base_url).await?;
+        Ok(response.total_tokens)
+    }
+
     async fn generate_text(
         &mut self,
         options: LanguageModelOptions,
     ) -> Result<LanguageModelResponse> {
-        let request: types::GenerateContentRequest = options.into();
+        let mut request: types::GenerateContentRequest = options.into();
+        request.set_thinking(
+            self.settings.thinking_budget,
+            self.settings.include_thoughts,
+        );
         self.lm_options.request = Some(request);
         self.lm_options.streaming = false;
 
-        let response: types::GenerateContentResponse = self.send(&self.settings.base_url).await?;
+        let (response, rate_limit): (types::GenerateContentResponse, _) =
+            self.send_with_rate_limit(&self.settings.base_url).await?;
+
+        Ok(response_from(response).with_rate_limit(rate_limit))
+    }
 
-        let mut collected = Vec::new();
-        let usage = response.usage_metadata.map(|u| u.into());
+    async fn stream_text(&mut self, options: LanguageModelOptions) -> Result<ProviderStream> {
+        let mut request: types::GenerateContentRequest = options.into();
+        request.set_thinking(
+            self.settings.thinking_budget,
+            self.settings.include_thoughts,
+        );
+        self.lm_options.request = Some(request);
+        self.lm_options.streaming = true;
 
-        for candidate in response.candidates {
-            for part in candidate.content.parts {
-                if let Some(t) = part.text {
+        let (google_stream, rate_limit) = self
+            .send_and_stream_with_rate_limit(&self.settings.base_url)
+            .await?;
+
+        Ok(stream_with_rate_limit(
+            rate_limit,
+            stream_from(google_stream),
+        ))
+    }
+}
+
+/// Converts a `generateContent` response. Shared with the Vertex AI provider.
+pub(crate) fn response_from(response: types::GenerateContentResponse) -> LanguageModelResponse {
+    let mut images = Vec::new();
+    let mut collected = Vec::new();
+    let usage = response.usage_metadata.map(|u| u.into());
+    let mut finish_reason = None;
+
+    for candidate in response.candidates {
+        let calls_tools = candidate
+            .content
+            .parts
+            .iter()
+            .any(|part| part.function_call.is_some());
+        let refusal = candidate
+            .finish_reason
+            .as_ref()
+            .and_then(conversions::refusal);
+        if let Some(reason) = candidate.finish_reason {
+            finish_reason = Some(conversions::finish_reason(reason, calls_tools));
+        }
+        for part in candidate.content.parts {
+            if let Some(blob) = part.inline_data {
+                images.push(LanguageModelResponseContentType::Image(blob.into()));
+            }
+            if let Some(t) = part.text {
+                if part.thought == Some(true) {
+                    collected.push(LanguageModelResponseContentType::Reasoning {
+                        content: t,
+                        extensions: Default::default(),
+                    });
+                } else {
                     collected.push(LanguageModelResponseContentType::Text(t));
                 }
-                if let Some(fc) = part.function_call {
-                    let mut tool_info = ToolCallInfo::new(fc.name);
-                    tool_info.input(fc.args);
-                    if let Some(sig) = part.thought_signature {
-                        tool_info
-                            .extensions
-                            .get_mut::<extensions::GoogleToolMetadata>()
-                            .thought_signature = Some(sig);
-                    }
-                    collected.push(LanguageModelResponseContentType::ToolCall(tool_info));
+            }
+            if let Some(fc) = part.function_call {
+                let mut tool_info = ToolCallInfo::new(fc.name);
+                // Gemini does not identify function calls
+                tool_info.id(format!("call_{}", uuid::Uuid::new_v4().simple()));
+                tool_info.input(fc.args);
+                if let Some(sig) = part.thought_signature {
+                    tool_info
+                        .extensions
+                        .get_mut::<extensions::GoogleToolMetadata>()
+                        .thought_signature = Some(sig);
                 }
+                collected.push(LanguageModelResponseContentType::ToolCall(tool_info));
             }
         }
+        collected.extend(refusal);
+    }
 
-        Ok(LanguageModelResponse {
-            contents: collected,
-            usage,
-        })
+    if let Some(refusal) = conversions::prompt_refusal(response.prompt_feedback.as_ref()) {
+        finish_reason = Some(FinishReason::ContentFilter);
+        collected.push(refusal);
     }
 
-    async fn stream_text(&mut self, options: LanguageModelOptions) -> Result<ProviderStream> {
-        let request: types::GenerateContentRequest = options.into();
-        self.lm_options.request = Some(request);
-        self.lm_options.streaming = true;
+    // Images go first so the text stays the last message of the step
+    images.extend(collected);
 
-        // Retry logic for rate limiting
-        let max_retries = 5;
-        let mut retry_count = 0;
-        let mut wait_time = std::time::Duration::from_secs(1);
-
-        let google_stream = loop {
-            match self.send_and_stream(&self.settings.base_url).await {
-                Ok(stream) => break stream,
-                Err(crate::error::Error::ApiError {
-                    status_code: Some(status),
-                    ..
-                }) if status == reqwest::StatusCode::TOO_MANY_REQUESTS
-                    && retry_count < max_retries =>
-                {
-                    retry_count += 1;
-                    tokio::time::sleep(wait_time).await;
-                    wait_time *= 2; // Exponential backoff
-                    continue;
-                }
-                Err(e) => return Err(e),
-            }
-        };
+    LanguageModelResponse {
+        contents: images,
+        usage,
+        stop_sequence: None,
+        finish_reason,
+        logprobs: Vec::new(),
+        extensions: Default::default(),
+    }
+}
 
-        #[derive(Default)]
-        struct StreamState {
-            accumulated_text: String,
-            accumulated_tool_call: Option<ToolCallInfo>,
-            usage: Option<Usage>,
-        }
+/// Converts a stream of `streamGenerateContent` events into stream chunks. Shared with
+/// the Vertex AI provider.
+pub(crate) fn stream_from(
+    google_stream: Pin<Box<dyn Stream<Item = Result<types::GoogleStreamEvent>> + Send>>,
+) -> ProviderStream {
+    #[derive(Default)]
+    struct StreamState {
+        accumulated_text: String,
+        accumulated_reasoning: String,
+        accumulated_tool_call: Option<ToolCallInfo>,
+        accumulated_images: Vec<GeneratedImage>,
+        usage: Option<Usage>,
+    }
 
-        let stream = google_stream.scan(StreamState::default(), |state, evt_res| {
-            futures::future::ready(match evt_res {
-                Ok(types::GoogleStreamEvent::Response(response)) => {
-                    let mut chunks = Vec::new();
+    let stream = google_stream.scan(StreamState::default(), |state, evt_res| {
+        futures::future::ready(match evt_res {
+            Ok(types::GoogleStreamEvent::Response(response)) => {
+                let mut chunks = Vec::new();
 
-                    if let Some(usage) = response.usage_metadata.clone().map(Usage::from) {
-                        state.usage = Some(usage);
-                    }
+                if let Some(usage) = response.usage_metadata.clone().map(Usage::from) {
+                    state.usage = Some(usage);
+                }
 
-                    for candidate in &response.candidates {
-                        for part in &candidate.content.parts {
-                            if let Some(t) = &part.text {
+                for candidate in &response.candidates {
+                    for part in &candidate.content.parts {
+                        if let Some(blob) = &part.inline_data {
+                            state.accumulated_images.push(blob.clone().into());
+                        }
+                        if let Some(t) = &part.text {
+                            if part.thought == Some(true) {
+                                state.accumulated_reasoning.push_str(t);
+                                chunks.push(LanguageModelStreamChunk::Delta(
+                                    LanguageModelStreamChunkType::Reasoning(t.clone()),
+                                ));
+                            } else {
                                 state.accumulated_text.push_str(t);
                                 chunks.push(LanguageModelStreamChunk::Delta(
                                     LanguageModelStreamChunkType::Text(t.clone()),
                                 ));
                             }
-                            if let Some(fc) = &part.function_call {
-                                let mut tool_info = ToolCallInfo::new(fc.name.clone());
-                                tool_info.input(fc.args.clone());
-                                if let Some(sig) = &part.thought_signature {
-                                    tool_info
-                                        .extensions
-                                        .get_mut::<extensions::GoogleToolMetadata>()
-                                        .thought_signature = Some(sig.clone());
-                                }
-                                state.accumulated_tool_call = Some(tool_info);
-
-                                chunks.push(LanguageModelStreamChunk::Delta(
-                                    LanguageModelStreamChunkType::ToolCall(
-                                        serde_json::to_string(&fc).unwrap_or_default(),
-                                    ),
-                                ));
+                        }
+                        if let Some(fc) = &part.function_call {
+                            let mut tool_info = ToolCallInfo::new(fc.name.clone());
+                            tool_info.id(format!("call_{}", uuid::Uuid::new_v4().simple()));
+                            tool_info.input(fc.args.clone());
+                            if let Some(sig) = &part.thought_signature {
+                                tool_info
+                                    .extensions
+                                    .get_mut::<extensions::GoogleToolMetadata>()
+                                    .thought_signature = Some(sig.clone());
                             }
+                            // Gemini sends each function call whole, in a single part
+                            chunks.push(LanguageModelStreamChunk::ToolCallDelta(ToolCallDelta {
+                                tool: tool_info.tool.clone(),
+                                delta: fc.args.to_string(),
+                            }));
+                            state.accumulated_tool_call = Some(tool_info);
                         }
+                    }
 
-                        if candidate.finish_reason.is_some() {
-                            let content = if let Some(tc) = state.accumulated_tool_call.take() {
-                                LanguageModelResponseContentType::ToolCall(tc)
-                            } else {
-                                let text = std::mem::take(&mut state.accumulated_text);
-                                LanguageModelResponseContentType::Text(text)
-                            };
+                    if let Some(reason) = &candidate.finish_reason {
+                        chunks.push(LanguageModelStreamChunk::FinishReason(
+                            conversions::finish_reason(
+                                reason.clone(),
+                                state.accumulated_tool_call.is_some(),
+                            ),
+                        ));
+                        // The usage is reported once, with the text
+                        if !state.accumulated_reasoning.is_empty() {
+                            chunks.push(LanguageModelStreamChunk::Done(AssistantMessage {
+                                content: LanguageModelResponseContentType::Reasoning {
+                                    content: std::mem::take(&mut state.accumulated_reasoning),
+                                    extensions: Default::default(),
+                                },
+                                usage: None,
+                            }));
+                        }
+                        chunks.extend(state.accumulated_images.drain(..).map(|image| {
+                            LanguageModelStreamChunk::Done(AssistantMessage {
+                                content: LanguageModelResponseContentType::Image(image),
+                                usage: None,
+                            })
+                        }));
 
+                        let content = if let Some(tc) = state.accumulated_tool_call.take() {
+                            LanguageModelResponseContentType::ToolCall(tc)
+                        } else {
+                            let text = std::mem::take(&mut state.accumulated_text);
+                            LanguageModelResponseContentType::Text(text)
+                        };
+
+                        chunks.push(LanguageModelStreamChunk::Done(AssistantMessage {
+                            content,
+                            usage: state.usage.clone(),
+                        }));
+                        if let Some(content) = conversions::refusal(reason) {
                             chunks.push(LanguageModelStreamChunk::Done(AssistantMessage {
                                 content,
-                                usage: state.usage.clone(),
+                                usage: None,
                             }));
                         }
                     }
-                    Some(Ok(chunks))
                 }
-                Ok(types::GoogleStreamEvent::NotSupported(msg)) => {
-                    Some(Ok(vec![LanguageModelStreamChunk::Delta(
-                        LanguageModelStreamChunkType::NotSupported(msg),
-                    )]))
+
+                if let Some(content) =
+                    conversions::prompt_refusal(response.prompt_feedback.as_ref())
+                {
+                    chunks.push(LanguageModelStreamChunk::FinishReason(
+                        FinishReason::ContentFilter,
+                    ));
+                    chunks.push(LanguageModelStreamChunk::Done(AssistantMessage {
+                        content,
+                        usage: state.usage.clone(),
+                    }));
                 }
-                Err(e) => Some(Err(e)),
-            })
-        });
+                Some(Ok(chunks))
+            }
+            Ok(types::GoogleStreamEvent::NotSupported(msg)) => {
+                Some(Ok(vec![LanguageModelStreamChunk::Delta(
+                    LanguageModelStreamChunkType::NotSupported(msg),
+                )]))
+            }
+            Err(e) => Some(Err(e)),
+        })
+    });
+
+    Box::pin(stream)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::image_model::ImageData;
+
+    #[test]
+    fn test_response_with_inline_image() {
+        let response: types::GenerateContentResponse = serde_json::from_str(
+            r#"{
+                "candidates": [{
+                    "content": {
+                        "role": "model",
+                        "parts": [
+                            {"text": "Here is your cat"},
+                            {"inlineData": {"mimeType": "image/png", "data": "iVBORw0KGgo="}}
+                        ]
+                    },
+                    "finishReason": "STOP"
+                }]
+            }"#,
+        )
+        .unwrap();
+
+        let contents = response_from(response).contents;
+
+        let LanguageModelResponseContentType::Image(image) = &contents[0] else {
+            panic!("expected an image, got {:?}", contents[0]);
+        };
+        assert_eq!(image.data, ImageData::Base64("iVBORw0KGgo=".to_string()));
+        assert_eq!(image.media_type.as_deref(), Some("image/png"));
+        assert!(
+            matches!(&contents[1], LanguageModelResponseContentType::Text(text) if text == "Here is your cat")
+        );
+    }
+
+    #[test]
+    fn test_response_with_thought_summary() {
+        let response: types::GenerateContentResponse = serde_json::from_str(
+            r#"{
+                "candidates": [{
+                    "content": {
+                        "role": "model",
+                        "parts": [
+                            {"text": "The user greets me", "thought": true},
+                            {"text": "Hello!"}
+                        ]
+                    },
+                    "finishReason": "STOP"
+                }]
+            }"#,
+        )
+        .unwrap();
+
+        let contents = response_from(response).contents;
+
+        assert!(matches!(
+            &contents[0],
+            LanguageModelResponseContentType::Reasoning { content, .. } if content == "The user greets me"
+        ));
+        assert!(
+            matches!(&contents[1], LanguageModelResponseContentType::Text(text) if text == "Hello!")
+        );
+    }
+
+    #[test]
+    fn test_response_finish_reason_with_function_call() {
+        let response: types::GenerateContentResponse = serde_json::from_str(
+            r#"{
+                "candidates": [{
+                    "content": {
+                        "role": "model",
+                        "parts": [
+                            {"functionCall": {"name": "weather", "args": {"city": "Paris"}}}
+                        ]
+                    },
+                    "finishReason": "STOP"
+                }]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            response_from(response).finish_reason,
+            Some(FinishReason::ToolCalls)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stream_function_call_delta_is_attributed() {
+        let response: types::GenerateContentResponse = serde_json::from_str(
+            r#"{
+                "candidates": [{
+                    "content": {
+                        "role": "model",
+                        "parts": [
+                            {"functionCall": {"name": "weather", "args": {"city": "Paris"}}}
+                        ]
+                    },
+                    "finishReason": "STOP"
+                }]
+            }"#,
+        )
+        .unwrap();
+        let events = futures::stream::iter([Ok(types::GoogleStreamEvent::Response(response))]);
+
+        let chunks: Vec<LanguageModelStreamChunk> =
+            stream_from(Box::pin(events)).next().await.unwrap().unwrap();
+
+        let Some(LanguageModelStreamChunk::ToolCallDelta(delta)) = chunks.first() else {
+            panic!("expected a tool call delta, got {chunks:?}");
+        };
+        let Some(LanguageModelStreamChunk::Done(AssistantMessage {
+            content: LanguageModelResponseContentType::ToolCall(tool_info),
+            ..
+        })) = chunks.last()
+        else {
+            panic!("expected a tool call, got {chunks:?}");
+        };
+        assert_eq!(delta.tool, tool_info.tool);
+        assert!(!delta.tool.id.is_empty());
+        assert_eq!(delta.delta, r#"{"city":"Paris"}"#);
+    }
+
+    #[test]
+    fn test_response_blocked_by_safety() {
+        let response: types::GenerateContentResponse = serde_json::from_str(
+            r#"{
+                "candidates": [{
+                    "content": {"role": "model", "parts": []},
+                    "finishReason": "SAFETY"
+                }]
+            }"#,
+        )
+        .unwrap();
+
+        let response = response_from(response);
+        assert_eq!(response.finish_reason, Some(FinishReason::ContentFilter));
+        assert!(matches!(
+            response.contents.as_slice(),
+            [LanguageModelResponseContentType::Refusal(reason)] if reason == "SAFETY"
+        ));
+    }
+
+    #[test]
+    fn test_request_with_thinking_config() {
+        use crate::core::language_model::ReasoningEffort;
+
+        let mut request: types::GenerateContentRequest = LanguageModelOptions {
+            reasoning_effort: Some(ReasoningEffort::Low),
+            ..Default::default()
+        }
+        .into();
+        let body = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            body["generationConfig"]["thinkingConfig"],
+            serde_json::json!({"thinkingBudget": 1024, "includeThoughts": true})
+        );
+
+        request.set_thinking(Some(0), None);
+        let body = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            body["generationConfig"]["thinkingConfig"],
+            serde_json::json!({"thinkingBudget": 0, "includeThoughts": true})
+        );
+    }
+
+    #[test]
+    fn test_request_with_seed() {
+        let request: types::GenerateContentRequest = LanguageModelOptions {
+            seed: Some(7),
+            ..Default::default()
+        }
+        .into();
+
+        let body = serde_json::to_value(request).unwrap();
+        assert_eq!(body["generationConfig"]["seed"], 7);
+    }
+
+    #[test]
+    fn test_request_with_tool_choice() {
+        use crate::core::tools::{Tool, ToolChoice, ToolList};
+
+        let request: types::GenerateContentRequest = LanguageModelOptions {
+            tools: Some(ToolList::new(vec![Tool {
+                name: "weather".to_string(),
+                ..Default::default()
+            }])),
+            tool_choice: Some(ToolChoice::Tool("weather".to_string())),
+            ..Default::default()
+        }
+        .into();
+
+        let body = serde_json::to_value(request).unwrap();
+        assert_eq!(
+            body["toolConfig"]["functionCallingConfig"],
+            serde_json::json!({"mode": "ANY", "allowedFunctionNames": ["weather"]})
+        );
+    }
+
+    #[test]
+    fn test_count_tokens_request() {
+        use crate::core::DynamicModel;
+        use crate::core::client::TokenCountClient;
+        use crate::core::messages::{Message, TaggedMessage};
+
+        let mut google = Google::<DynamicModel>::model_name("gemini-2.5-flash");
+        google.lm_options.request = Some(
+            LanguageModelOptions {
+                messages: vec![TaggedMessage::initial_step_msg(Message::User("Hi".into()))],
+                ..Default::default()
+            }
+            .into(),
+        );
 
-        Ok(Box::pin(stream))
+        let body: serde_json::Value =
+            serde_json::from_slice(TokenCountClient::body(&google).as_bytes().unwrap()).unwrap();
+        assert_eq!(
+            TokenCountClient::path(&google),
+            "/v1beta/models/gemini-2.5-flash:countTokens"
+        );
+        assert_eq!(
+            body["generateContentRequest"]["model"],
+            "models/gemini-2.5-flash"
+        );
+        assert_eq!(
+            body["generateContentRequest"]["contents"][0]["parts"][0]["text"],
+            "Hi"
+        );
     }
 }
@@ -8,12 +8,15 @@ pub mod embedding_model;
 pub mod extensions;
 pub mod language_model;
 pub mod settings;
+pub mod video_model;
 
 use crate::core::DynamicModel;
 use crate::core::capabilities::ModelName;
+use crate::core::client::RetryPolicy;
+use crate::core::provider::ModelDescriptor;
 use crate::core::utils::validate_base_url;
 use crate::error::Error;
-use crate::providers::google::client::{GoogleEmbeddingOptions, GoogleOptions};
+use crate::providers::google::client::{GoogleEmbeddingOptions, GoogleOptions, GoogleVideoOptions};
 use crate::providers::google::settings::GoogleProviderSettings;
 use serde::Serialize;
 
@@ -24,6 +27,7 @@ pub struct Google<M: ModelName> {
     pub settings: GoogleProviderSettings,
     pub(crate) lm_options: GoogleOptions,
     pub(crate) embedding_options: GoogleEmbeddingOptions,
+    pub(crate) video_options: GoogleVideoOptions,
     _phantom: std::marker::PhantomData<M>,
 }
 
@@ -32,6 +36,15 @@ impl<M: ModelName> Google<M> {
     pub fn builder() -> GoogleBuilder<M> {
         GoogleBuilder::default()
     }
+
+    /// Lists the models available to the API key, e.g. to populate a model picker.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the API cannot be reached or returns an invalid response.
+    pub async fn list_models(&self) -> crate::error::Result<Vec<ModelDescriptor>> {
+        crate::core::client::ModelListClient::list(self, self.settings.base_url.clone()).await
+    }
 }
 
 impl Google<DynamicModel> {
@@ -64,11 +77,16 @@ impl Google<DynamicModel> {
             model: model_name.clone(),
             requests: Vec::new(),
         };
+        let video_options = GoogleVideoOptions {
+            model: model_name.clone(),
+            ..Default::default()
+        };
 
         Self {
             settings,
             lm_options: options,
             embedding_options,
+            video_options,
             _phantom: std::marker::PhantomData,
         }
     }
@@ -86,11 +104,16 @@ impl<M: ModelName> Default for Google<M> {
             model: M::MODEL_NAME.to_string(),
             requests: Vec::new(),
         };
+        let video_options = GoogleVideoOptions {
+            model: M::MODEL_NAME.to_string(),
+            ..Default::default()
+        };
 
         Self {
             settings,
             lm_options: options,
             embedding_options,
+            video_options,
             _phantom: std::marker::PhantomData,
         }
     }
@@ -154,12 +177,59 @@ impl<M: ModelName> GoogleBuilder<M> {
         self
     }
 
+    /// Sets the retry policy applied to requests made by this provider.
+    ///
+    /// # Parameters
+    ///
+    /// * `retry_policy` - The [`RetryPolicy`] to use for retryable failures.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the retry policy set.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.settings.retry_policy = retry_policy;
+        self
+    }
+
+    crate::core::client::transport_builder_methods!();
+
     /// Sets the name of the provider. Defaults to "google".
     pub fn provider_name(mut self, provider_name: impl Into<String>) -> Self {
         self.settings.provider_name = provider_name.into();
         self
     }
 
+    /// Sets the maximum number of tokens the model thinks for, overriding the budget
+    /// derived from the reasoning effort.
+    ///
+    /// # Parameters
+    ///
+    /// * `thinking_budget` - The budget in tokens. `0` disables thinking and `-1` lets
+    ///   the model decide.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the thinking budget set.
+    pub fn thinking_budget(mut self, thinking_budget: i32) -> Self {
+        self.settings.thinking_budget = Some(thinking_budget);
+        self
+    }
+
+    /// Sets whether summaries of the model's thoughts are returned as reasoning
+    /// content.
+    ///
+    /// # Parameters
+    ///
+    /// * `include_thoughts` - Whether thought summaries are returned.
+    ///
+    /// # Returns
+    ///
+    /// The builder with thought summaries enabled or disabled.
+    pub fn include_thoughts(mut self, include_thoughts: bool) -> Self {
+        self.settings.include_thoughts = Some(include_thoughts);
+        self
+    }
+
     /// Sets a custom API path, overriding the default dynamic path.
     pub fn path(mut self, path: impl Into<String>) -> Self {
         self.settings.path = Some(path.into());
@@ -180,9 +250,13 @@ impl<M: ModelName> GoogleBuilder<M> {
         let model_name = options.model.clone();
 
         let embedding_options = GoogleEmbeddingOptions {
-            model: model_name,
+            model: model_name.clone(),
             requests: Vec::new(),
         };
+        let video_options = GoogleVideoOptions {
+            model: model_name,
+            ..Default::default()
+        };
 
         Ok(Google {
             settings: GoogleProviderSettings {
@@ -191,6 +265,7 @@ impl<M: ModelName> GoogleBuilder<M> {
             },
             lm_options: options,
             embedding_options,
+            video_options,
             _phantom: std::marker::PhantomData,
         })
     }
@@ -1,10 +1,11 @@
 //! Defines the settings for the Google provider.
 
+use crate::core::client::{RetryPolicy, TransportSettings};
 use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 
 /// Settings for the Google provider.
-#[derive(Debug, Clone, Serialize, Deserialize, Builder)]
+#[derive(Clone, Serialize, Deserialize, Builder)]
 #[builder(setter(into), default)]
 pub struct GoogleProviderSettings {
     /// The name of the provider.
@@ -19,6 +20,36 @@ pub struct GoogleProviderSettings {
     /// Custom API path override. When set, this path is used instead of the
     /// default dynamic path (e.g., "/v1beta/models/{model}:generateContent").
     pub path: Option<String>,
+
+    /// The retry policy applied to requests.
+    pub retry_policy: RetryPolicy,
+
+    /// The HTTP client, middleware and rate limiter requests go through.
+    #[serde(skip)]
+    pub transport: TransportSettings,
+
+    /// Maximum number of tokens Gemini thinks for. `0` disables thinking and `-1`
+    /// lets the model decide. Overrides the budget derived from the reasoning effort.
+    pub thinking_budget: Option<i32>,
+
+    /// Whether summaries of the model's thoughts are returned as reasoning content.
+    /// They are returned whenever a reasoning effort is set.
+    pub include_thoughts: Option<bool>,
+}
+
+impl std::fmt::Debug for GoogleProviderSettings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GoogleProviderSettings")
+            .field("provider_name", &self.provider_name)
+            .field("base_url", &self.base_url)
+            .field("api_key", &"<redacted>")
+            .field("path", &self.path)
+            .field("retry_policy", &self.retry_policy)
+            .field("transport", &self.transport)
+            .field("thinking_budget", &self.thinking_budget)
+            .field("include_thoughts", &self.include_thoughts)
+            .finish()
+    }
 }
 
 impl Default for GoogleProviderSettings {
@@ -29,6 +60,10 @@ impl Default for GoogleProviderSettings {
             base_url: "https://generativelanguage.googleapis.com".to_string(),
             api_key: std::env::var("GOOGLE_API_KEY").unwrap_or_default(),
             path: None,
+            retry_policy: RetryPolicy::default(),
+            transport: TransportSettings::default(),
+            thinking_budget: None,
+            include_thoughts: None,
         }
     }
 }
@@ -0,0 +1,157 @@
+//! Video model implementation for the Google provider.
+
+use crate::{
+    core::{
+        capabilities::ModelName,
+        client::VideoClient,
+        utils::join_url,
+        video_model::{GeneratedVideo, VideoJob, VideoJobStatus, VideoModel, VideoModelOptions},
+    },
+    error::{Error, Result},
+    providers::google::{Google, client::types},
+};
+use async_trait::async_trait;
+
+impl<M: ModelName> Google<M> {
+    /// Returns a copy of the provider prepared to send `input` to the
+    /// `predictLongRunning` endpoint of the model.
+    pub(crate) fn with_video_request(&self, input: VideoModelOptions) -> Self {
+        let mut model = self.clone();
+
+        model.video_options.request = types::PredictLongRunningRequest {
+            instances: vec![types::VideoInstance {
+                prompt: input.prompt,
+            }],
+            parameters: types::VideoParameters {
+                aspect_ratio: input.aspect_ratio.map(|ratio| ratio.to_string()),
+                negative_prompt: input.negative_prompt,
+                duration_seconds: input.duration,
+                // Veo names resolutions by their short side, e.g. 720p
+                resolution: input
+                    .size
+                    .map(|size| format!("{}p", size.width.min(size.height))),
+                seed: input.seed,
+            },
+        };
+        model
+    }
+}
+
+impl From<types::Operation> for VideoJob {
+    fn from(operation: types::Operation) -> Self {
+        let status = match (operation.done, &operation.error) {
+            (_, Some(_)) => VideoJobStatus::Failed,
+            (true, None) => VideoJobStatus::Completed,
+            (false, None) => VideoJobStatus::InProgress,
+        };
+        let videos = operation
+            .response
+            .and_then(|response| response.generate_video_response)
+            .map(|response| response.generated_samples)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|sample| sample.video)
+            .map(|video| GeneratedVideo {
+                url: video.uri,
+                media_type: video.mime_type.or_else(|| Some("video/mp4".to_string())),
+            })
+            .collect();
+
+        VideoJob {
+            id: operation.name,
+            status,
+            progress: None,
+            error: operation.error.map(|error| error.message),
+            videos,
+        }
+    }
+}
+
+#[async_trait]
+impl<M: ModelName> VideoModel for Google<M> {
+    async fn submit_video(&self, input: VideoModelOptions) -> Result<VideoJob> {
+        let model = self.with_video_request(input);
+
+        let operation = model.submit(&model.settings.base_url).await?;
+
+        Ok(operation.into())
+    }
+
+    async fn poll_video(&self, job: &VideoJob) -> Result<VideoJob> {
+        let url = join_url(
+            self.settings.base_url.as_str(),
+            &format!("/v1beta/{}", job.id),
+        )?;
+
+        Ok(self.fetch_job(url).await?.into())
+    }
+
+    async fn download_video(&self, video: &GeneratedVideo) -> Result<Vec<u8>> {
+        let url = reqwest::Url::parse(&video.url)
+            .map_err(|_| Error::InvalidInput(format!("Invalid video URL: {}", video.url)))?;
+
+        self.download(url).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::DynamicModel;
+    use crate::core::image_model::{AspectRatio, ImageSize};
+
+    #[test]
+    fn test_video_request_body() {
+        let google = Google::<DynamicModel>::model_name("veo-3.0-generate-001");
+        let input = VideoModelOptions::builder()
+            .prompt("A cat surfing")
+            .aspect_ratio(Some(AspectRatio::new(16, 9)))
+            .size(Some(ImageSize::new(1920, 1080)))
+            .build()
+            .unwrap();
+
+        let model = google.with_video_request(input);
+        let body = serde_json::to_value(&model.video_options.request).unwrap();
+
+        assert_eq!(
+            VideoClient::path(&model),
+            "/v1beta/models/veo-3.0-generate-001:predictLongRunning"
+        );
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "instances": [{"prompt": "A cat surfing"}],
+                "parameters": {"aspectRatio": "16:9", "resolution": "1080p"}
+            })
+        );
+    }
+
+    #[test]
+    fn test_operation_conversion() {
+        let operation: types::Operation =
+            serde_json::from_str(r#"{"name": "models/veo-3.0-generate-001/operations/abc"}"#)
+                .unwrap();
+        let job: VideoJob = operation.into();
+        assert_eq!(job.status, VideoJobStatus::InProgress);
+
+        let operation: types::Operation = serde_json::from_str(
+            r#"{
+                "name": "models/veo-3.0-generate-001/operations/abc",
+                "done": true,
+                "response": {
+                    "@type": "type.googleapis.com/google.ai.generativelanguage.v1beta.PredictLongRunningResponse",
+                    "generateVideoResponse": {
+                        "generatedSamples": [{"video": {"uri": "https://generativelanguage.googleapis.com/v1beta/files/xyz:download?alt=media"}}]
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+        let job: VideoJob = operation.into();
+        assert_eq!(job.status, VideoJobStatus::Completed);
+        assert_eq!(
+            job.videos[0].url,
+            "https://generativelanguage.googleapis.com/v1beta/files/xyz:download?alt=media"
+        );
+    }
+}
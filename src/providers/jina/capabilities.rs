@@ -0,0 +1,45 @@
+//! Capabilities for Jina AI models.
+//!
+//! This module defines model types for Jina AI's embedding and reranker models. Any
+//! other model can be used through `Jina::model_name`. Users can implement additional
+//! traits on custom models.
+
+use crate::core::capabilities::*;
+use crate::model_capabilities;
+use crate::providers::jina::Jina;
+
+model_capabilities! {
+    provider: Jina,
+    models: {
+        JinaEmbeddingsV2BaseCode {
+            model_name: "jina-embeddings-v2-base-code",
+            constructor_name: jina_embeddings_v2_base_code,
+            display_name: "Jina Embeddings v2 Base Code",
+            capabilities: [TextInputSupport]
+        },
+        JinaEmbeddingsV2BaseEn {
+            model_name: "jina-embeddings-v2-base-en",
+            constructor_name: jina_embeddings_v2_base_en,
+            display_name: "Jina Embeddings v2 Base English",
+            capabilities: [TextInputSupport]
+        },
+        JinaEmbeddingsV3 {
+            model_name: "jina-embeddings-v3",
+            constructor_name: jina_embeddings_v3,
+            display_name: "Jina Embeddings v3",
+            capabilities: [TextInputSupport]
+        },
+        JinaRerankerM0 {
+            model_name: "jina-reranker-m0",
+            constructor_name: jina_reranker_m0,
+            display_name: "Jina Reranker m0",
+            capabilities: [TextInputSupport]
+        },
+        JinaRerankerV2BaseMultilingual {
+            model_name: "jina-reranker-v2-base-multilingual",
+            constructor_name: jina_reranker_v2_base_multilingual,
+            display_name: "Jina Reranker v2 Base Multilingual",
+            capabilities: [TextInputSupport]
+        }
+    }
+}
@@ -0,0 +1,56 @@
+//! Request and response types for the Jina AI embeddings and rerank APIs.
+
+use crate::providers::jina::settings::JinaTask;
+use serde::{Deserialize, Serialize};
+
+// ============================================================================
+// Section: embeddings
+// ============================================================================
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct EmbeddingsRequest {
+    pub(crate) model: String,
+    pub(crate) input: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) task: Option<JinaTask>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) dimensions: Option<usize>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub(crate) late_chunking: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EmbeddingsResponse {
+    pub(crate) data: Vec<Embedding>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct Embedding {
+    pub(crate) embedding: Vec<f32>,
+    pub(crate) index: usize,
+}
+
+// ============================================================================
+// Section: rerank
+// ============================================================================
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct RerankRequest {
+    pub(crate) model: String,
+    pub(crate) query: String,
+    pub(crate) documents: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) top_n: Option<usize>,
+    pub(crate) return_documents: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct RerankResponse {
+    pub(crate) results: Vec<RerankResult>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct RerankResult {
+    pub(crate) index: usize,
+    pub(crate) relevance_score: f32,
+}
@@ -0,0 +1,40 @@
+//! This module provides conversions between the core embedding and rerank types and
+//! the Jina AI API types.
+
+use crate::core::embedding_model::{EmbeddingModelOptions, EmbeddingModelResponse};
+use crate::core::rerank_model::RerankModelOptions;
+use crate::providers::jina::client::types::{EmbeddingsRequest, EmbeddingsResponse, RerankRequest};
+
+impl From<EmbeddingModelOptions> for EmbeddingsRequest {
+    fn from(value: EmbeddingModelOptions) -> Self {
+        EmbeddingsRequest {
+            model: String::new(), // will be set in embedding_model.rs
+            input: value.input,
+            dimensions: value.dimensions,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<EmbeddingsResponse> for EmbeddingModelResponse {
+    fn from(value: EmbeddingsResponse) -> Self {
+        let mut data = value.data;
+        data.sort_by_key(|embedding| embedding.index);
+        data.into_iter()
+            .map(|embedding| embedding.embedding)
+            .collect()
+    }
+}
+
+impl From<RerankModelOptions> for RerankRequest {
+    fn from(value: RerankModelOptions) -> Self {
+        RerankRequest {
+            model: String::new(), // will be set in rerank_model.rs
+            query: value.query,
+            documents: value.documents,
+            top_n: value.top_n,
+            // the documents are known, so they are not echoed back
+            return_documents: false,
+        }
+    }
+}
@@ -0,0 +1,30 @@
+//! Embedding model implementation for the Jina AI provider.
+
+use crate::{
+    core::{
+        capabilities::ModelName,
+        client::EmbeddingClient,
+        embedding_model::{EmbeddingModel, EmbeddingModelOptions, EmbeddingModelResponse},
+    },
+    error::Result,
+    providers::jina::Jina,
+};
+use async_trait::async_trait;
+
+#[async_trait]
+impl<M: ModelName> EmbeddingModel for Jina<M> {
+    async fn embed(&self, input: EmbeddingModelOptions) -> Result<EmbeddingModelResponse> {
+        // Clone self to allow mutation
+        let mut model = self.clone();
+
+        let mut request: crate::providers::jina::client::types::EmbeddingsRequest = input.into();
+        request.model = model.embedding_options.model.clone();
+        request.task = model.settings.task;
+        request.late_chunking = model.settings.late_chunking;
+        model.embedding_options.request = request;
+
+        let response = model.send(&model.settings.base_url).await?;
+
+        Ok(response.into())
+    }
+}
@@ -0,0 +1,256 @@
+//! This module provides the Jina AI provider, which implements the `EmbeddingModel`
+//! and `RerankModel` traits for Jina AI's embedding and reranker models.
+//!
+//! Jina AI only serves retrieval models, so `Jina` is not a `LanguageModel`. The
+//! embeddings endpoint supports task-specific LoRA adapters through [`JinaTask`] and
+//! late chunking, which embeds the inputs of a request as chunks of one document.
+
+pub mod capabilities;
+pub mod client;
+pub mod conversions;
+pub mod embedding_model;
+pub mod rerank_model;
+pub mod settings;
+
+use crate::core::DynamicModel;
+use crate::core::capabilities::ModelName;
+use crate::core::client::RetryPolicy;
+use crate::core::utils::validate_base_url;
+use crate::error::{Error, Result};
+use crate::providers::jina::client::{JinaEmbeddingOptions, JinaRerankOptions};
+use crate::providers::jina::settings::JinaProviderSettings;
+use serde::Serialize;
+
+pub use settings::JinaTask;
+
+/// The Jina AI provider.
+#[derive(Debug, Serialize, Clone)]
+pub struct Jina<M: ModelName> {
+    /// Configuration settings for the Jina AI provider.
+    pub settings: JinaProviderSettings,
+    pub(crate) embedding_options: JinaEmbeddingOptions,
+    pub(crate) rerank_options: JinaRerankOptions,
+    _phantom: std::marker::PhantomData<M>,
+}
+
+impl<M: ModelName> Jina<M> {
+    /// Jina AI provider setting builder.
+    pub fn builder() -> JinaBuilder<M> {
+        JinaBuilder::default()
+    }
+
+    fn with_model(settings: JinaProviderSettings, model_name: String) -> Self {
+        let embedding_options = JinaEmbeddingOptions {
+            model: model_name.clone(),
+            ..Default::default()
+        };
+        let rerank_options = JinaRerankOptions {
+            model: model_name,
+            ..Default::default()
+        };
+
+        Self {
+            settings,
+            embedding_options,
+            rerank_options,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl Jina<DynamicModel> {
+    /// Creates a Jina AI provider with a dynamic model name using default settings.
+    ///
+    /// This allows you to specify the model name as a string rather than
+    /// using methods like `Jina::jina_embeddings_v3()`.
+    ///
+    /// **WARNING**: when using `DynamicModel`, model capabilities are not validated.
+    /// This means there is no compile-time guarantee that the model supports requested features.
+    ///
+    /// For custom configuration (API key, task, etc.), use the builder pattern:
+    /// `Jina::<DynamicModel>::builder().model_name(...).api_key(...).build()`
+    ///
+    /// # Parameters
+    ///
+    /// * `model_name` - The Jina AI model identifier (e.g., "jina-embeddings-v3",
+    ///   "jina-reranker-m0")
+    ///
+    /// # Returns
+    ///
+    /// A configured `Jina<DynamicModel>` provider instance with default settings.
+    pub fn model_name(name: impl Into<String>) -> Self {
+        Self::with_model(JinaProviderSettings::default(), name.into())
+    }
+}
+
+impl<M: ModelName> Default for Jina<M> {
+    /// Creates a new Jina AI provider with default settings.
+    fn default() -> Self {
+        Self::with_model(JinaProviderSettings::default(), M::MODEL_NAME.to_string())
+    }
+}
+
+/// Jina AI Provider Builder
+pub struct JinaBuilder<M: ModelName> {
+    settings: JinaProviderSettings,
+    model_name: String,
+    _phantom: std::marker::PhantomData<M>,
+}
+
+impl<M: ModelName> Default for JinaBuilder<M> {
+    /// Creates a new Jina AI provider builder with default settings.
+    fn default() -> Self {
+        Self {
+            settings: JinaProviderSettings::default(),
+            model_name: M::MODEL_NAME.to_string(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl JinaBuilder<DynamicModel> {
+    /// Sets the model name from a string. e.g., "jina-embeddings-v3", "jina-reranker-m0"
+    ///
+    /// **WARNING**: when using `DynamicModel`, model capabilities are not validated.
+    /// This means there is no compile-time guarantee that the model supports requested features.
+    ///
+    /// # Parameters
+    ///
+    /// * `model_name` - The Jina AI model identifier.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the model name set.
+    pub fn model_name(mut self, model_name: impl Into<String>) -> Self {
+        self.model_name = model_name.into();
+        self
+    }
+}
+
+impl<M: ModelName> JinaBuilder<M> {
+    /// Sets the base URL for the Jina AI API.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.settings.base_url = base_url.into();
+        self
+    }
+
+    /// Sets the API key for the Jina AI API.
+    pub fn api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.settings.api_key = api_key.into();
+        self
+    }
+
+    /// Sets the task embeddings are generated for, which selects the matching LoRA
+    /// adapter of `jina-embeddings-v3`.
+    pub fn task(mut self, task: JinaTask) -> Self {
+        self.settings.task = Some(task);
+        self
+    }
+
+    /// Sets whether the inputs of a request are embedded together as chunks of one
+    /// document. Defaults to false.
+    pub fn late_chunking(mut self, late_chunking: bool) -> Self {
+        self.settings.late_chunking = late_chunking;
+        self
+    }
+
+    /// Sets the retry policy applied to requests made by this provider.
+    ///
+    /// # Parameters
+    ///
+    /// * `retry_policy` - The [`RetryPolicy`] to use for retryable failures.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the retry policy set.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.settings.retry_policy = retry_policy;
+        self
+    }
+
+    crate::core::client::transport_builder_methods!();
+
+    /// Sets the name of the provider. Defaults to "jina".
+    pub fn provider_name(mut self, provider_name: impl Into<String>) -> Self {
+        self.settings.provider_name = provider_name.into();
+        self
+    }
+
+    /// Builds the Jina AI provider.
+    pub fn build(self) -> Result<Jina<M>> {
+        // validate base url
+        let base_url = validate_base_url(&self.settings.base_url)?;
+
+        // check api key exists
+        if self.settings.api_key.is_empty() {
+            return Err(Error::MissingField("api_key".to_string()));
+        }
+
+        if self.model_name.is_empty() {
+            return Err(Error::MissingField("model_name".to_string()));
+        }
+
+        Ok(Jina::with_model(
+            JinaProviderSettings {
+                base_url,
+                ..self.settings
+            },
+            self.model_name,
+        ))
+    }
+}
+
+// Re-exports for convenience
+pub use capabilities::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::client::EmbeddingClient;
+    use crate::providers::jina::client::types::EmbeddingsRequest;
+
+    #[test]
+    fn test_task_and_late_chunking_are_serialized() {
+        let request = EmbeddingsRequest {
+            model: "jina-embeddings-v3".to_string(),
+            input: vec!["Rust".to_string()],
+            task: Some(JinaTask::RetrievalPassage),
+            dimensions: None,
+            late_chunking: true,
+        };
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            serde_json::json!({
+                "model": "jina-embeddings-v3",
+                "input": ["Rust"],
+                "task": "retrieval.passage",
+                "late_chunking": true
+            })
+        );
+
+        let request = EmbeddingsRequest {
+            late_chunking: false,
+            ..request
+        };
+        assert!(
+            serde_json::to_value(&request)
+                .unwrap()
+                .get("late_chunking")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_builder_sets_model_for_both_endpoints() {
+        let jina = Jina::<DynamicModel>::builder()
+            .model_name("jina-reranker-m0")
+            .api_key("key")
+            .task(JinaTask::TextMatching)
+            .build()
+            .unwrap();
+
+        assert_eq!(jina.embedding_options.model, "jina-reranker-m0");
+        assert_eq!(jina.rerank_options.model, "jina-reranker-m0");
+        assert_eq!(EmbeddingClient::path(&jina), "embeddings");
+    }
+}
@@ -0,0 +1,90 @@
+//! Defines the settings for the Jina AI provider.
+
+use crate::core::client::{RetryPolicy, TransportSettings};
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+/// The downstream task embeddings are generated for. `jina-embeddings-v3` selects
+/// the task-specific LoRA adapter matching the task.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum JinaTask {
+    /// Queries in asymmetric retrieval.
+    #[serde(rename = "retrieval.query")]
+    RetrievalQuery,
+    /// Documents in asymmetric retrieval.
+    #[serde(rename = "retrieval.passage")]
+    RetrievalPassage,
+    /// Semantic similarity, e.g. deduplication or symmetric retrieval.
+    #[serde(rename = "text-matching")]
+    TextMatching,
+    /// Text classification.
+    #[serde(rename = "classification")]
+    Classification,
+    /// Clustering and reranking.
+    #[serde(rename = "separation")]
+    Separation,
+}
+
+/// Settings for the Jina AI provider.
+#[derive(Clone, Serialize, Deserialize, Builder)]
+#[builder(setter(into), default)]
+pub struct JinaProviderSettings {
+    /// The name of the provider.
+    pub provider_name: String,
+
+    /// The base URL of the Jina AI API.
+    pub base_url: String,
+
+    /// The API key for the Jina AI API.
+    pub api_key: String,
+
+    /// The task embeddings are generated for. The model's default is used when unset.
+    pub task: Option<JinaTask>,
+
+    /// Whether the inputs of a request are embedded together as chunks of one document
+    /// before being pooled, so each embedding carries the context of its neighbours.
+    pub late_chunking: bool,
+
+    /// The retry policy applied to requests.
+    pub retry_policy: RetryPolicy,
+
+    /// The HTTP client, middleware and rate limiter requests go through.
+    #[serde(skip)]
+    pub transport: TransportSettings,
+}
+
+impl std::fmt::Debug for JinaProviderSettings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JinaProviderSettings")
+            .field("provider_name", &self.provider_name)
+            .field("base_url", &self.base_url)
+            .field("api_key", &"<redacted>")
+            .field("task", &self.task)
+            .field("late_chunking", &self.late_chunking)
+            .field("retry_policy", &self.retry_policy)
+            .field("transport", &self.transport)
+            .finish()
+    }
+}
+
+impl Default for JinaProviderSettings {
+    /// Returns the default settings for the Jina AI provider.
+    fn default() -> Self {
+        Self {
+            provider_name: "jina".to_string(),
+            base_url: "https://api.jina.ai/v1/".to_string(),
+            api_key: std::env::var("JINA_API_KEY").unwrap_or_default(),
+            task: None,
+            late_chunking: false,
+            retry_policy: RetryPolicy::default(),
+            transport: TransportSettings::default(),
+        }
+    }
+}
+
+impl JinaProviderSettings {
+    /// Creates a new builder for `JinaProviderSettings`.
+    pub fn builder() -> JinaProviderSettingsBuilder {
+        JinaProviderSettingsBuilder::default()
+    }
+}
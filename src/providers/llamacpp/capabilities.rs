@@ -0,0 +1,15 @@
+//! Capabilities for llama.cpp models.
+//!
+//! A llama.cpp server serves the model it was started with, so there is no model
+//! catalog: use `LlamaCpp::<DynamicModel>`, optionally with the alias given to the
+//! server with `--alias` as model name. Users can define custom models with the
+//! capabilities of the model they serve.
+
+use crate::core::capabilities::*;
+use crate::model_capabilities;
+use crate::providers::llamacpp::LlamaCpp;
+
+model_capabilities! {
+    provider: LlamaCpp,
+    models: {}
+}
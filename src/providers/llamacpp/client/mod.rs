@@ -0,0 +1,178 @@
+//! Client implementation for the llama.cpp provider.
+//!
+//! Only the native `/completion` endpoint goes through this client. Requests to
+//! `/v1/chat/completions` are sent with the OpenAI Chat Completions client, see
+//! [`LlamaCpp::chat_model`].
+use crate::core::client::{LanguageModelClient, RetryPolicy, Transport, response_error, sse_error};
+use crate::core::runtime::assume_send;
+use crate::core::utils::join_url;
+use crate::error::{Error, Result};
+use crate::providers::llamacpp::{LlamaCpp, ModelName};
+use derive_builder::Builder;
+use reqwest::header::{AUTHORIZATION, CONTENT_TYPE};
+use reqwest_eventsource::Event;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+pub(crate) mod types;
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Builder)]
+#[builder(setter(into), build_fn(error = "Error"))]
+pub(crate) struct LlamaCppOptions {
+    pub(crate) model: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub(crate) request: Option<types::CompletionRequest>,
+}
+
+impl LlamaCppOptions {
+    pub(crate) fn builder() -> LlamaCppOptionsBuilder {
+        LlamaCppOptionsBuilder::default()
+    }
+}
+
+impl<M: ModelName> LlamaCpp<M> {
+    fn request_headers(&self) -> reqwest::header::HeaderMap {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(CONTENT_TYPE, "application/json".parse().unwrap());
+        if !self.settings.api_key.is_empty()
+            && let Ok(value) = format!("Bearer {}", self.settings.api_key).parse()
+        {
+            headers.insert(AUTHORIZATION, value);
+        }
+        headers
+    }
+
+    /// Fetches the server properties from `/props`.
+    pub(crate) async fn fetch_props(&self) -> Result<types::Props> {
+        let url = join_url(self.settings.base_url.as_str(), "/props")?;
+        let transport = Transport::new(LanguageModelClient::transport(self));
+        let request = transport.client.get(url).headers(self.request_headers());
+        let response = transport
+            .send(request)
+            .await?
+            .map_err(|e| Error::ApiError {
+                status_code: e.status(),
+                details: e.to_string(),
+            })?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(response_error(response).await);
+        }
+        let text = assume_send(response.text())
+            .await
+            .map_err(|e| Error::ApiError {
+                status_code: Some(status),
+                details: format!("Failed to read response: {e}"),
+            })?;
+
+        serde_json::from_str(&text).map_err(|e| Error::ApiError {
+            status_code: Some(status),
+            details: format!("Failed to parse response: {e}"),
+        })
+    }
+}
+
+/// Parses the data of an SSE message, which is either the next completion chunk or an
+/// error the server hit while generating.
+fn parse_stream_data(data: &str) -> Result<types::CompletionStreamEvent> {
+    #[derive(Deserialize)]
+    struct ErrorData {
+        error: Value,
+    }
+    if let Ok(ErrorData { error }) = serde_json::from_str(data) {
+        let details = error
+            .get("message")
+            .and_then(Value::as_str)
+            .map_or_else(|| error.to_string(), str::to_string);
+        return Err(Error::ApiError {
+            status_code: None,
+            details,
+        });
+    }
+
+    let chunk: types::CompletionResponse =
+        serde_json::from_str(data).map_err(|e| Error::ApiError {
+            status_code: None,
+            details: format!("Invalid JSON in SSE: {e}"),
+        })?;
+    Ok(types::CompletionStreamEvent::Chunk(chunk))
+}
+
+impl<M: ModelName> LanguageModelClient for LlamaCpp<M> {
+    type Response = types::CompletionResponse;
+    type StreamEvent = types::CompletionStreamEvent;
+
+    fn path(&self) -> String {
+        "/completion".to_string()
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn headers(&self) -> reqwest::header::HeaderMap {
+        self.request_headers()
+    }
+
+    fn query_params(&self) -> Vec<(&str, &str)> {
+        Vec::new()
+    }
+
+    fn retry_policy(&self) -> RetryPolicy {
+        self.settings.retry_policy.clone()
+    }
+
+    fn transport(&self) -> crate::core::client::TransportSettings {
+        self.settings.transport.clone()
+    }
+
+    fn body(&self) -> reqwest::Body {
+        if let Some(request) = &self.lm_options.request {
+            let body = serde_json::to_string(request).unwrap();
+            return reqwest::Body::from(body);
+        };
+        reqwest::Body::from("{}")
+    }
+
+    fn parse_stream_sse(
+        event: std::result::Result<Event, reqwest_eventsource::Error>,
+    ) -> Result<Self::StreamEvent> {
+        match event {
+            Ok(Event::Open) => Ok(types::CompletionStreamEvent::Open),
+            Ok(Event::Message(msg)) => parse_stream_data(&msg.data),
+            Err(e) => Err(sse_error(e)),
+        }
+    }
+
+    fn end_stream(event: &Self::StreamEvent) -> bool {
+        matches!(event, types::CompletionStreamEvent::Chunk(chunk) if chunk.stop)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::DynamicModel;
+
+    #[test]
+    fn test_parse_stream_tokens_and_errors() {
+        let event = parse_stream_data(r#"{"content":" world","stop":false,"id_slot":0}"#).unwrap();
+        assert!(!LlamaCpp::<DynamicModel>::end_stream(&event));
+
+        let event = parse_stream_data(
+            r#"{"content":"","stop":true,"stop_type":"eos","tokens_predicted":2,"tokens_evaluated":5}"#,
+        )
+        .unwrap();
+        assert!(LlamaCpp::<DynamicModel>::end_stream(&event));
+
+        let error = parse_stream_data(
+            r#"{"error":{"code":400,"message":"the request exceeds the available context size","type":"exceed_context_size_error"}}"#,
+        );
+        assert!(matches!(
+            error,
+            Err(Error::ApiError { details, .. }) if details.contains("context size")
+        ));
+    }
+}
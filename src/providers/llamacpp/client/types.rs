@@ -0,0 +1,74 @@
+//! Type definitions for the native llama.cpp server API.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// A request to the `/completion` endpoint.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct CompletionRequest {
+    pub prompt: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub n_predict: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_k: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u32>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub stop: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub presence_penalty: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frequency_penalty: Option<f32>,
+    /// A JSON schema the output is constrained to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub json_schema: Option<Value>,
+    #[serde(default)]
+    pub stream: bool,
+    /// llama.cpp specific options, see
+    /// [`LlamaCppExtensions`](crate::providers::llamacpp::LlamaCppExtensions).
+    #[serde(flatten)]
+    pub extensions: Map<String, Value>,
+}
+
+/// A response of the `/completion` endpoint. When streaming, each event carries the
+/// next token and the last one, with `stop` set, carries the token counts.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct CompletionResponse {
+    #[serde(default)]
+    pub content: String,
+    #[serde(default)]
+    pub stop: bool,
+    /// Why generation stopped: `eos`, `word`, `limit` or `none`.
+    #[serde(default)]
+    pub stop_type: Option<String>,
+    #[serde(default)]
+    pub tokens_predicted: Option<usize>,
+    #[serde(default)]
+    pub tokens_evaluated: Option<usize>,
+    #[serde(default)]
+    pub tokens_cached: Option<usize>,
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub(crate) enum CompletionStreamEvent {
+    Chunk(CompletionResponse),
+    Open,
+}
+
+/// The server properties returned by `/props`.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub(crate) struct Props {
+    #[serde(default)]
+    pub default_generation_settings: GenerationSettings,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub(crate) struct GenerationSettings {
+    #[serde(default)]
+    pub n_ctx: Option<u32>,
+}
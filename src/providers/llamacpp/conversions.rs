@@ -0,0 +1,86 @@
+//! Conversions between types used by the llama.cpp provider and the types used by the core library.
+use crate::core::language_model::{
+    FinishReason, LanguageModelOptions, LanguageModelResponse, LanguageModelResponseContentType,
+    Usage,
+};
+use crate::core::messages::Message;
+use crate::providers::llamacpp::client::types::{CompletionRequest, CompletionResponse};
+
+impl From<LanguageModelOptions> for CompletionRequest {
+    fn from(options: LanguageModelOptions) -> Self {
+        let mut parts: Vec<String> = options.system.into_iter().collect();
+        for tagged in options.messages {
+            match tagged.message {
+                Message::System(s) => parts.push(s.content),
+                Message::Developer(d) => parts.push(d),
+                Message::User(u) => parts.push(u.content),
+                Message::Assistant(a) => {
+                    if let LanguageModelResponseContentType::Text(text) = a.content {
+                        parts.push(text);
+                    }
+                }
+                Message::Tool(_) => {}
+            }
+        }
+
+        let json_schema = options.schema.map(|s| {
+            let mut schema = serde_json::to_value(s).unwrap_or_default();
+            if let Some(obj) = schema.as_object_mut() {
+                obj.remove("$schema");
+            }
+            schema
+        });
+
+        Self {
+            prompt: parts.join("\n"),
+            n_predict: options.max_output_tokens,
+            temperature: options.temperature.map(|t| t as f32 / 100.0),
+            top_k: options.top_k,
+            top_p: options.top_p.map(|t| t as f32 / 100.0),
+            seed: options.seed,
+            stop: options.stop_sequences.unwrap_or_default(),
+            presence_penalty: options.presence_penalty,
+            frequency_penalty: options.frequency_penalty,
+            json_schema,
+            stream: false,
+            extensions: options.provider_options,
+        }
+    }
+}
+
+pub(crate) fn usage(response: &CompletionResponse) -> Option<Usage> {
+    if response.tokens_evaluated.is_none() && response.tokens_predicted.is_none() {
+        return None;
+    }
+    Some(Usage {
+        input_tokens: response.tokens_evaluated,
+        output_tokens: response.tokens_predicted,
+        reasoning_tokens: None,
+        cached_tokens: response.tokens_cached,
+    })
+}
+
+/// Maps the `stop_type` of a finished completion.
+pub(crate) fn finish_reason(response: &CompletionResponse) -> Option<FinishReason> {
+    response
+        .stop_type
+        .as_deref()
+        .map(|stop_type| match stop_type {
+            "eos" | "word" => FinishReason::Stop,
+            "limit" => FinishReason::Length,
+            other => FinishReason::Other(other.to_string()),
+        })
+}
+
+impl From<CompletionResponse> for LanguageModelResponse {
+    fn from(response: CompletionResponse) -> Self {
+        Self {
+            usage: usage(&response),
+            finish_reason: finish_reason(&response),
+            contents: vec![LanguageModelResponseContentType::Text(response.content)],
+            stop_sequence: None,
+            logprobs: Vec::new(),
+            extensions: Default::default(),
+        }
+    }
+}
@@ -0,0 +1,88 @@
+//! llama.cpp specific request options.
+
+use serde::{Deserialize, Serialize};
+
+/// llama.cpp server options that the OpenAI-compatible API has no equivalent for.
+///
+/// They are sent with every request, to both the native `/completion` endpoint and
+/// `/v1/chat/completions`, which accepts them as extensions.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LlamaCppExtensions {
+    /// Mirostat sampling: `0` disables it, `1` selects Mirostat and `2` Mirostat 2.0.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mirostat: Option<u8>,
+    /// The target entropy of Mirostat sampling.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mirostat_tau: Option<f32>,
+    /// The learning rate of Mirostat sampling.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mirostat_eta: Option<f32>,
+    /// A GBNF grammar the output is constrained to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grammar: Option<String>,
+    /// Reuses the KV cache of a previous request sharing the same prompt prefix.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_prompt: Option<bool>,
+    /// The number of prompt tokens kept when the context is exceeded, `-1` for all.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub n_keep: Option<i32>,
+    /// The minimum probability of a token relative to the most likely one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_p: Option<f32>,
+    /// The penalty applied to repeated tokens.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repeat_penalty: Option<f32>,
+    /// The number of last tokens considered for `repeat_penalty`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repeat_last_n: Option<i32>,
+    /// The server slot the request is assigned to, `-1` for any idle slot.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id_slot: Option<i32>,
+}
+
+impl LlamaCppExtensions {
+    /// Sets the Mirostat mode, `1` or `2`, with its target entropy and learning rate.
+    pub fn mirostat(mut self, mode: u8, tau: f32, eta: f32) -> Self {
+        self.mirostat = Some(mode);
+        self.mirostat_tau = Some(tau);
+        self.mirostat_eta = Some(eta);
+        self
+    }
+
+    /// Sets a GBNF grammar the output is constrained to.
+    pub fn grammar(mut self, grammar: impl Into<String>) -> Self {
+        self.grammar = Some(grammar.into());
+        self
+    }
+
+    /// Sets whether the KV cache of a previous request is reused.
+    pub fn cache_prompt(mut self, cache_prompt: bool) -> Self {
+        self.cache_prompt = Some(cache_prompt);
+        self
+    }
+
+    /// Sets the number of prompt tokens kept when the context is exceeded.
+    pub fn n_keep(mut self, n_keep: i32) -> Self {
+        self.n_keep = Some(n_keep);
+        self
+    }
+
+    /// Sets the minimum probability of a token relative to the most likely one.
+    pub fn min_p(mut self, min_p: f32) -> Self {
+        self.min_p = Some(min_p);
+        self
+    }
+
+    /// Sets the penalty applied to tokens among the last `last_n`.
+    pub fn repeat_penalty(mut self, penalty: f32, last_n: i32) -> Self {
+        self.repeat_penalty = Some(penalty);
+        self.repeat_last_n = Some(last_n);
+        self
+    }
+
+    /// Sets the server slot the request is assigned to.
+    pub fn id_slot(mut self, id_slot: i32) -> Self {
+        self.id_slot = Some(id_slot);
+        self
+    }
+}
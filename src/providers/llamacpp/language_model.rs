@@ -0,0 +1,132 @@
+//! Language model implementation for the llama.cpp provider.
+use crate::core::DynamicModel;
+use crate::core::capabilities::ModelName;
+use crate::core::client::LanguageModelClient;
+use crate::core::language_model::{
+    LanguageModel, LanguageModelOptions, LanguageModelResponse, LanguageModelResponseContentType,
+    LanguageModelStreamChunk, LanguageModelStreamChunkType, ProviderStream,
+};
+use crate::core::messages::AssistantMessage;
+use crate::error::Result;
+use crate::providers::llamacpp::LlamaCpp;
+use crate::providers::llamacpp::client::types::{self, CompletionStreamEvent};
+use crate::providers::llamacpp::conversions;
+use crate::providers::llamacpp::settings::LlamaCppEndpoint;
+use crate::providers::openai_chat_completions::OpenAIChatCompletions;
+use crate::providers::openai_chat_completions::settings::OpenAIChatCompletionsSettings;
+use async_trait::async_trait;
+use futures::StreamExt;
+
+impl<M: ModelName> LlamaCpp<M> {
+    /// Returns a Chat Completions model for the server's `/v1/chat/completions`
+    /// endpoint, sending the llama.cpp extensions in the request body.
+    pub(crate) fn chat_model(&self) -> OpenAIChatCompletions<DynamicModel> {
+        let mut chat = OpenAIChatCompletions::model_name(self.lm_options.model.clone());
+        chat.settings = OpenAIChatCompletionsSettings {
+            provider_name: self.settings.provider_name.clone(),
+            base_url: self.settings.base_url.clone(),
+            api_key: self.settings.api_key.clone(),
+            path: Some("/v1/chat/completions".to_string()),
+            retry_policy: self.settings.retry_policy.clone(),
+            transport: self.settings.transport.clone(),
+            extra_body: self.extensions_body(),
+        };
+        chat
+    }
+
+    /// Builds the `/completion` request for `options`, applying the provider settings.
+    pub(crate) fn completion_request(
+        &self,
+        options: LanguageModelOptions,
+        stream: bool,
+    ) -> types::CompletionRequest {
+        let mut request: types::CompletionRequest = options.into();
+        request.stream = stream;
+        let mut extensions = self.extensions_body();
+        extensions.extend(std::mem::take(&mut request.extensions));
+        request.extensions = extensions;
+        request
+    }
+
+    fn extensions_body(&self) -> serde_json::Map<String, serde_json::Value> {
+        match serde_json::to_value(&self.settings.extensions) {
+            Ok(serde_json::Value::Object(fields)) => fields,
+            _ => Default::default(),
+        }
+    }
+}
+
+#[async_trait]
+impl<M: ModelName> LanguageModel for LlamaCpp<M> {
+    fn name(&self) -> String {
+        self.lm_options.model.clone()
+    }
+
+    fn capabilities(&self) -> crate::core::capabilities::Capabilities {
+        M::CAPABILITIES
+    }
+
+    fn info(&self) -> crate::core::capabilities::ModelInfo {
+        Self::model_info(&self.name())
+    }
+
+    async fn generate_text(
+        &mut self,
+        options: LanguageModelOptions,
+    ) -> Result<LanguageModelResponse> {
+        if self.settings.endpoint == LlamaCppEndpoint::ChatCompletions {
+            return self.chat_model().generate_text(options).await;
+        }
+
+        self.lm_options.request = Some(self.completion_request(options, false));
+
+        let response: types::CompletionResponse = self.send(&self.settings.base_url).await?;
+
+        Ok(response.into())
+    }
+
+    /// Streams the response token by token.
+    async fn stream_text(&mut self, options: LanguageModelOptions) -> Result<ProviderStream> {
+        if self.settings.endpoint == LlamaCppEndpoint::ChatCompletions {
+            return self.chat_model().stream_text(options).await;
+        }
+
+        self.lm_options.request = Some(self.completion_request(options, true));
+
+        let llamacpp_stream = self.send_and_stream(&self.settings.base_url).await?;
+
+        let stream = llamacpp_stream.scan(String::new(), |text, evt_res| {
+            futures::future::ready(match evt_res {
+                Ok(CompletionStreamEvent::Open) => Some(Ok(vec![LanguageModelStreamChunk::Delta(
+                    LanguageModelStreamChunkType::Start,
+                )])),
+                Ok(CompletionStreamEvent::Chunk(chunk)) => {
+                    let mut chunks = Vec::new();
+                    if !chunk.content.is_empty() {
+                        text.push_str(&chunk.content);
+                        chunks.push(LanguageModelStreamChunk::Delta(
+                            LanguageModelStreamChunkType::Text(chunk.content.clone()),
+                        ));
+                    }
+                    if chunk.stop {
+                        let usage = conversions::usage(&chunk);
+                        if let Some(usage) = &usage {
+                            chunks.push(LanguageModelStreamChunk::Usage(usage.clone()));
+                        }
+                        if let Some(reason) = conversions::finish_reason(&chunk) {
+                            chunks.push(LanguageModelStreamChunk::FinishReason(reason));
+                        }
+                        chunks.push(LanguageModelStreamChunk::Done(AssistantMessage {
+                            content: LanguageModelResponseContentType::Text(std::mem::take(text)),
+                            usage,
+                        }));
+                    }
+                    Some(Ok(chunks))
+                }
+                Err(e) => Some(Err(e)),
+            })
+        });
+
+        Ok(Box::pin(stream))
+    }
+}
@@ -0,0 +1,262 @@
+//! This module provides the llama.cpp provider, which implements the `LanguageModel`
+//! and `Provider` traits for models served by a llama.cpp server (`llama-server`).
+//!
+//! Requests go to `/v1/chat/completions` by default, or to the native `/completion`
+//! endpoint for raw prompts and token by token streaming. Either way, llama.cpp
+//! specific options such as `mirostat`, `grammar` and `cache_prompt` are sent with
+//! [`LlamaCppExtensions`].
+
+pub mod capabilities;
+pub mod client;
+pub mod conversions;
+pub mod extensions;
+pub mod language_model;
+pub mod settings;
+
+use crate::core::DynamicModel;
+use crate::core::capabilities::ModelName;
+use crate::core::client::RetryPolicy;
+use crate::core::utils::validate_base_url;
+use crate::error::Result;
+use crate::providers::llamacpp::client::LlamaCppOptions;
+use crate::providers::llamacpp::settings::LlamaCppProviderSettings;
+use serde::Serialize;
+
+pub use extensions::LlamaCppExtensions;
+pub use settings::LlamaCppEndpoint;
+
+/// The llama.cpp provider.
+#[derive(Debug, Serialize, Clone)]
+pub struct LlamaCpp<M: ModelName> {
+    /// Configuration settings for the llama.cpp provider.
+    pub settings: LlamaCppProviderSettings,
+    pub(crate) lm_options: LlamaCppOptions,
+    _phantom: std::marker::PhantomData<M>,
+}
+
+impl<M: ModelName> LlamaCpp<M> {
+    /// llama.cpp provider setting builder.
+    pub fn builder() -> LlamaCppBuilder<M> {
+        LlamaCppBuilder::default()
+    }
+
+    /// Returns the size of the context window (`n_ctx`) of the server.
+    ///
+    /// llama.cpp sets the context size when the server starts, with `--ctx-size`,
+    /// rather than per request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the server cannot be reached or returns an invalid response.
+    pub async fn context_size(&self) -> Result<Option<u32>> {
+        Ok(self.fetch_props().await?.default_generation_settings.n_ctx)
+    }
+
+    fn with_model(settings: LlamaCppProviderSettings, model_name: String) -> Self {
+        let options = LlamaCppOptions::builder()
+            .model(model_name)
+            .build()
+            .unwrap();
+
+        Self {
+            settings,
+            lm_options: options,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl LlamaCpp<DynamicModel> {
+    /// Creates a llama.cpp provider with a dynamic model name using default settings.
+    ///
+    /// llama.cpp serves the model it was started with, so the name is only used to
+    /// tell servers that route between several models apart.
+    ///
+    /// **WARNING**: when using `DynamicModel`, model capabilities are not validated.
+    /// This means there is no compile-time guarantee that the model supports requested features.
+    ///
+    /// For custom configuration (base URL, extensions, etc.), use the builder pattern:
+    /// `LlamaCpp::<DynamicModel>::builder().base_url(...).extensions(...).build()`
+    ///
+    /// # Parameters
+    ///
+    /// * `model_name` - The model name, e.g. the alias given with `--alias`
+    ///
+    /// # Returns
+    ///
+    /// A configured `LlamaCpp<DynamicModel>` provider instance with default settings.
+    pub fn model_name(name: impl Into<String>) -> Self {
+        Self::with_model(LlamaCppProviderSettings::default(), name.into())
+    }
+}
+
+impl<M: ModelName> Default for LlamaCpp<M> {
+    /// Creates a new llama.cpp provider with default settings.
+    fn default() -> Self {
+        Self::with_model(
+            LlamaCppProviderSettings::default(),
+            M::MODEL_NAME.to_string(),
+        )
+    }
+}
+
+/// llama.cpp Provider Builder
+pub struct LlamaCppBuilder<M: ModelName> {
+    settings: LlamaCppProviderSettings,
+    model_name: String,
+    _phantom: std::marker::PhantomData<M>,
+}
+
+impl<M: ModelName> Default for LlamaCppBuilder<M> {
+    /// Creates a new llama.cpp provider builder with default settings.
+    fn default() -> Self {
+        Self {
+            settings: LlamaCppProviderSettings::default(),
+            model_name: M::MODEL_NAME.to_string(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl LlamaCppBuilder<DynamicModel> {
+    /// Sets the model name from a string.
+    ///
+    /// **WARNING**: when using `DynamicModel`, model capabilities are not validated.
+    /// This means there is no compile-time guarantee that the model supports requested features.
+    ///
+    /// # Parameters
+    ///
+    /// * `model_name` - The model name, e.g. the alias given with `--alias`.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the model name set.
+    pub fn model_name(mut self, model_name: impl Into<String>) -> Self {
+        self.model_name = model_name.into();
+        self
+    }
+}
+
+impl<M: ModelName> LlamaCppBuilder<M> {
+    /// Sets the base URL of the llama.cpp server. Defaults to `http://localhost:8080`.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.settings.base_url = base_url.into();
+        self
+    }
+
+    /// Sets the API key of servers started with `--api-key`. Defaults to
+    /// `LLAMA_API_KEY`.
+    pub fn api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.settings.api_key = api_key.into();
+        self
+    }
+
+    /// Sets the endpoint language model requests are sent to. Defaults to
+    /// [`LlamaCppEndpoint::ChatCompletions`].
+    pub fn endpoint(mut self, endpoint: LlamaCppEndpoint) -> Self {
+        self.settings.endpoint = endpoint;
+        self
+    }
+
+    /// Sets llama.cpp specific options, such as `mirostat` and `grammar`, sent with
+    /// every request.
+    ///
+    /// # Parameters
+    ///
+    /// * `extensions` - The [`LlamaCppExtensions`] to send.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the options set.
+    pub fn extensions(mut self, extensions: LlamaCppExtensions) -> Self {
+        self.settings.extensions = extensions;
+        self
+    }
+
+    /// Sets the retry policy applied to requests made by this provider.
+    ///
+    /// # Parameters
+    ///
+    /// * `retry_policy` - The [`RetryPolicy`] to use for retryable failures.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the retry policy set.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.settings.retry_policy = retry_policy;
+        self
+    }
+
+    crate::core::client::transport_builder_methods!();
+
+    /// Sets the name of the provider. Defaults to "llamacpp".
+    pub fn provider_name(mut self, provider_name: impl Into<String>) -> Self {
+        self.settings.provider_name = provider_name.into();
+        self
+    }
+
+    /// Builds the llama.cpp provider.
+    pub fn build(self) -> Result<LlamaCpp<M>> {
+        // validate base url
+        let base_url = validate_base_url(&self.settings.base_url)?;
+
+        Ok(LlamaCpp::with_model(
+            LlamaCppProviderSettings {
+                base_url,
+                ..self.settings
+            },
+            self.model_name,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::language_model::LanguageModelOptions;
+    use crate::core::messages::{Message, TaggedMessage};
+
+    fn llamacpp() -> LlamaCpp<DynamicModel> {
+        LlamaCpp::<DynamicModel>::builder()
+            .base_url("http://localhost:8080")
+            .extensions(
+                LlamaCppExtensions::default()
+                    .mirostat(2, 5.0, 0.1)
+                    .grammar("root ::= \"yes\" | \"no\"")
+                    .cache_prompt(true),
+            )
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_completion_request_includes_extensions() {
+        let options = LanguageModelOptions {
+            system: Some("Answer yes or no.".to_string()),
+            messages: vec![TaggedMessage::initial_step_msg(Message::User(
+                "Is the sky blue?".into(),
+            ))],
+            max_output_tokens: Some(8),
+            ..Default::default()
+        };
+        let request = llamacpp().completion_request(options, true);
+        let body = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(body["prompt"], "Answer yes or no.\nIs the sky blue?");
+        assert_eq!(body["n_predict"], 8);
+        assert_eq!(body["stream"], true);
+        assert_eq!(body["mirostat"], 2);
+        assert_eq!(body["grammar"], "root ::= \"yes\" | \"no\"");
+        assert_eq!(body["cache_prompt"], true);
+        assert!(body.get("min_p").is_none());
+    }
+
+    #[test]
+    fn test_chat_model_targets_chat_completions() {
+        let chat = llamacpp().chat_model();
+
+        assert_eq!(chat.settings.path.as_deref(), Some("/v1/chat/completions"));
+        assert_eq!(chat.settings.extra_body["mirostat_tau"], 5.0);
+        assert_eq!(chat.settings.extra_body["cache_prompt"], true);
+    }
+}
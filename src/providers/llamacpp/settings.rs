@@ -0,0 +1,81 @@
+//! Defines the settings for the llama.cpp provider.
+
+use crate::core::client::{RetryPolicy, TransportSettings};
+use crate::providers::llamacpp::extensions::LlamaCppExtensions;
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+/// The llama.cpp server endpoint language model requests are sent to.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LlamaCppEndpoint {
+    /// `/v1/chat/completions`, which applies the model's chat template and supports
+    /// tool calls.
+    #[default]
+    ChatCompletions,
+    /// The native `/completion` endpoint, which takes a raw prompt. No chat template
+    /// is applied: the system prompt and messages are joined into the prompt as is.
+    Completion,
+}
+
+/// Settings for the llama.cpp provider.
+#[derive(Clone, Serialize, Deserialize, Builder)]
+#[builder(setter(into), default)]
+pub struct LlamaCppProviderSettings {
+    /// The name of the provider.
+    pub provider_name: String,
+
+    /// The base URL of the llama.cpp server.
+    pub base_url: String,
+
+    /// An optional API key, for servers started with `--api-key`.
+    pub api_key: String,
+
+    /// The endpoint language model requests are sent to.
+    pub endpoint: LlamaCppEndpoint,
+
+    /// llama.cpp specific options sent with every request.
+    pub extensions: LlamaCppExtensions,
+
+    /// The retry policy applied to requests.
+    pub retry_policy: RetryPolicy,
+
+    /// The HTTP client, middleware and rate limiter requests go through.
+    #[serde(skip)]
+    pub transport: TransportSettings,
+}
+
+impl std::fmt::Debug for LlamaCppProviderSettings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LlamaCppProviderSettings")
+            .field("provider_name", &self.provider_name)
+            .field("base_url", &self.base_url)
+            .field("api_key", &"<redacted>")
+            .field("endpoint", &self.endpoint)
+            .field("extensions", &self.extensions)
+            .field("retry_policy", &self.retry_policy)
+            .field("transport", &self.transport)
+            .finish()
+    }
+}
+
+impl Default for LlamaCppProviderSettings {
+    /// Returns the default settings for the llama.cpp provider.
+    fn default() -> Self {
+        Self {
+            provider_name: "llamacpp".to_string(),
+            base_url: "http://localhost:8080/".to_string(),
+            api_key: std::env::var("LLAMA_API_KEY").unwrap_or_default(),
+            endpoint: LlamaCppEndpoint::default(),
+            extensions: LlamaCppExtensions::default(),
+            retry_policy: RetryPolicy::default(),
+            transport: TransportSettings::default(),
+        }
+    }
+}
+
+impl LlamaCppProviderSettings {
+    /// Creates a new builder for `LlamaCppProviderSettings`.
+    pub fn builder() -> LlamaCppProviderSettingsBuilder {
+        LlamaCppProviderSettingsBuilder::default()
+    }
+}
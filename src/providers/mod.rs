@@ -40,7 +40,7 @@ pub use google::Google;
 #[cfg(feature = "vercel")]
 pub mod vercel;
 #[cfg(feature = "vercel")]
-pub use vercel::Vercel;
+pub use vercel::{Gateway, Vercel};
 
 #[cfg(feature = "openrouter")]
 pub mod openrouter;
@@ -67,6 +67,81 @@ pub mod xai;
 #[cfg(feature = "xai")]
 pub use xai::XAI;
 
+#[cfg(feature = "ollama")]
+pub mod ollama;
+#[cfg(feature = "ollama")]
+pub use ollama::Ollama;
+
+#[cfg(feature = "cohere")]
+pub mod cohere;
+#[cfg(feature = "cohere")]
+pub use cohere::Cohere;
+
+#[cfg(feature = "bedrock")]
+pub mod bedrock;
+#[cfg(feature = "bedrock")]
+pub use bedrock::Bedrock;
+
+#[cfg(feature = "vertex")]
+pub mod vertex;
+#[cfg(feature = "vertex")]
+pub use vertex::Vertex;
+
+#[cfg(feature = "perplexity")]
+pub mod perplexity;
+#[cfg(feature = "perplexity")]
+pub use perplexity::Perplexity;
+
+#[cfg(feature = "replicate")]
+pub mod replicate;
+#[cfg(feature = "replicate")]
+pub use replicate::Replicate;
+
+#[cfg(feature = "llamacpp")]
+pub mod llamacpp;
+#[cfg(feature = "llamacpp")]
+pub use llamacpp::LlamaCpp;
+
+#[cfg(feature = "candle")]
+pub mod candle;
+#[cfg(feature = "candle")]
+pub use candle::Candle;
+
+#[cfg(feature = "vllm")]
+pub mod vllm;
+#[cfg(feature = "vllm")]
+pub use vllm::VLLM;
+
+#[cfg(feature = "watsonx")]
+pub mod watsonx;
+#[cfg(feature = "watsonx")]
+pub use watsonx::Watsonx;
+
+#[cfg(feature = "voyage")]
+pub mod voyage;
+#[cfg(feature = "voyage")]
+pub use voyage::Voyage;
+
+#[cfg(feature = "jina")]
+pub mod jina;
+#[cfg(feature = "jina")]
+pub use jina::Jina;
+
+#[cfg(feature = "elevenlabs")]
+pub mod elevenlabs;
+#[cfg(feature = "elevenlabs")]
+pub use elevenlabs::ElevenLabs;
+
+#[cfg(feature = "deepgram")]
+pub mod deepgram;
+#[cfg(feature = "deepgram")]
+pub use deepgram::Deepgram;
+
+#[cfg(feature = "stability")]
+pub mod stability;
+#[cfg(feature = "stability")]
+pub use stability::Stability;
+
 // Internal module for OpenAI Chat Completions API compatible providers
 #[cfg(feature = "openaichatcompletions")]
 pub(crate) mod openai_chat_completions;
@@ -0,0 +1,69 @@
+//! Capabilities for Ollama models.
+//!
+//! This module defines model types and their capabilities for popular models in the
+//! Ollama library. Any other model pulled to the server can be used through
+//! `Ollama::model_name`. Users can implement additional traits on custom models.
+
+use crate::core::capabilities::*;
+use crate::model_capabilities;
+use crate::providers::ollama::Ollama;
+
+model_capabilities! {
+    provider: Ollama,
+    models: {
+        DeepseekR1 {
+            model_name: "deepseek-r1",
+            constructor_name: deepseek_r1,
+            display_name: "DeepSeek-R1",
+            capabilities: [ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport]
+        },
+        Gemma3 {
+            model_name: "gemma3",
+            constructor_name: gemma3,
+            display_name: "Gemma 3",
+            capabilities: [ImageInputSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport]
+        },
+        GptOss {
+            model_name: "gpt-oss",
+            constructor_name: gpt_oss,
+            display_name: "GPT-OSS",
+            capabilities: [ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+        },
+        Llama31 {
+            model_name: "llama3.1",
+            constructor_name: llama3_1,
+            display_name: "Llama 3.1",
+            capabilities: [StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+        },
+        Llama32 {
+            model_name: "llama3.2",
+            constructor_name: llama3_2,
+            display_name: "Llama 3.2",
+            capabilities: [StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+        },
+        Mistral {
+            model_name: "mistral",
+            constructor_name: mistral,
+            display_name: "Mistral",
+            capabilities: [StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+        },
+        NomicEmbedText {
+            model_name: "nomic-embed-text",
+            constructor_name: nomic_embed_text,
+            display_name: "Nomic Embed Text",
+            capabilities: [TextInputSupport]
+        },
+        Qwen25 {
+            model_name: "qwen2.5",
+            constructor_name: qwen2_5,
+            display_name: "Qwen 2.5",
+            capabilities: [StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+        },
+        Qwen3 {
+            model_name: "qwen3",
+            constructor_name: qwen3,
+            display_name: "Qwen 3",
+            capabilities: [ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+        }
+    }
+}
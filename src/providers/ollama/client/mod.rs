@@ -0,0 +1,331 @@
+//! Client implementation for the Ollama provider.
+//!
+//! Ollama streams chat responses as newline-delimited JSON rather than server-sent
+//! events, so streaming goes through [`Ollama::send_and_stream_ndjson`] instead of the
+//! SSE-based `send_and_stream`.
+use crate::core::client::{
+    EmbeddingClient, LanguageModelClient, RetryPolicy, Transport, response_error,
+};
+use crate::core::runtime::assume_send;
+use crate::core::utils::join_url;
+use crate::error::{Error, Result};
+use crate::providers::ollama::{ModelName, Ollama};
+use derive_builder::Builder;
+use futures::{Stream, StreamExt};
+use reqwest::header::{AUTHORIZATION, CONTENT_TYPE};
+use reqwest_eventsource::Event;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::pin::Pin;
+
+pub(crate) mod types;
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Builder)]
+#[builder(setter(into), build_fn(error = "Error"))]
+pub(crate) struct OllamaOptions {
+    pub(crate) model: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub(crate) request: Option<types::ChatRequest>,
+}
+
+impl OllamaOptions {
+    pub(crate) fn builder() -> OllamaOptionsBuilder {
+        OllamaOptionsBuilder::default()
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub(crate) struct OllamaEmbeddingOptions {
+    pub(crate) model: String,
+    pub(crate) request: types::EmbedRequest,
+}
+
+/// A stream of parsed NDJSON chat responses.
+pub(crate) type ChatResponseStream =
+    Pin<Box<dyn Stream<Item = Result<types::ChatResponse>> + Send>>;
+
+impl<M: ModelName> Ollama<M> {
+    fn request_headers(&self) -> reqwest::header::HeaderMap {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(CONTENT_TYPE, "application/json".parse().unwrap());
+        if !self.settings.api_key.is_empty()
+            && let Ok(value) = format!("Bearer {}", self.settings.api_key).parse()
+        {
+            headers.insert(AUTHORIZATION, value);
+        }
+        headers
+    }
+
+    /// Sends the chat request and streams the NDJSON response line by line.
+    pub(crate) async fn send_and_stream_ndjson(&self) -> Result<ChatResponseStream> {
+        let url = join_url(
+            self.settings.base_url.as_str(),
+            &LanguageModelClient::path(self),
+        )?;
+        let transport = Transport::new(LanguageModelClient::transport(self));
+        let request = transport
+            .client
+            .request(LanguageModelClient::method(self), url)
+            .headers(LanguageModelClient::headers(self))
+            .body(LanguageModelClient::body(self));
+        // A rejected response is held while its body is read, and is not `Send` on wasm32
+        let response = assume_send(async {
+            let response = transport
+                .send(request)
+                .await?
+                .map_err(|e| Error::ApiError {
+                    status_code: e.status(),
+                    details: e.to_string(),
+                })?;
+            if !response.status().is_success() {
+                return Err(response_error(response).await);
+            }
+            Ok(response)
+        })
+        .await?;
+
+        let state = (
+            assume_send(response.bytes_stream()),
+            Vec::new(),
+            VecDeque::new(),
+            false,
+        );
+        let stream = futures::stream::unfold(
+            state,
+            |(mut bytes, mut buffer, mut pending, mut finished)| async move {
+                loop {
+                    if let Some(item) = pending.pop_front() {
+                        let item: Result<types::ChatResponse> = item;
+                        // Stop after the final response or the first error
+                        if item.as_ref().map_or(true, |r| r.done) {
+                            pending.clear();
+                            finished = true;
+                        }
+                        return Some((item, (bytes, buffer, pending, finished)));
+                    }
+                    if finished {
+                        return None;
+                    }
+                    match bytes.next().await {
+                        Some(Ok(chunk)) => {
+                            buffer.extend_from_slice(&chunk);
+                            pending.extend(drain_ndjson_lines(&mut buffer));
+                        }
+                        Some(Err(e)) => {
+                            finished = true;
+                            pending.push_back(Err(Error::ApiError {
+                                status_code: e.status(),
+                                details: format!("NDJSON stream error: {e}"),
+                            }));
+                        }
+                        None => {
+                            finished = true;
+                            buffer.push(b'\n');
+                            pending.extend(drain_ndjson_lines(&mut buffer));
+                        }
+                    }
+                }
+            },
+        );
+
+        Ok(Box::pin(stream))
+    }
+
+    /// Fetches the models available on the server from `/api/tags`.
+    pub(crate) async fn fetch_tags(&self) -> Result<types::TagsResponse> {
+        let url = join_url(self.settings.base_url.as_str(), "/api/tags")?;
+        let transport = Transport::new(LanguageModelClient::transport(self));
+        let request = transport.client.get(url).headers(self.request_headers());
+        let response = transport
+            .send(request)
+            .await?
+            .map_err(|e| Error::ApiError {
+                status_code: e.status(),
+                details: e.to_string(),
+            })?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(response_error(response).await);
+        }
+        let text = assume_send(response.text())
+            .await
+            .map_err(|e| Error::ApiError {
+                status_code: Some(status),
+                details: format!("Failed to read response: {e}"),
+            })?;
+
+        serde_json::from_str(&text).map_err(|e| Error::ApiError {
+            status_code: Some(status),
+            details: format!("Failed to parse response: {e}"),
+        })
+    }
+}
+
+/// Removes every complete line from `buffer` and parses it as a chat response. A
+/// trailing partial line is kept for the next chunk.
+pub(crate) fn drain_ndjson_lines(buffer: &mut Vec<u8>) -> Vec<Result<types::ChatResponse>> {
+    let Some(end) = buffer.iter().rposition(|b| *b == b'\n') else {
+        return Vec::new();
+    };
+    let complete: Vec<u8> = buffer.drain(..=end).collect();
+
+    complete
+        .split(|b| *b == b'\n')
+        .filter(|line| !line.iter().all(u8::is_ascii_whitespace))
+        .map(|line| {
+            let response: types::ChatResponse =
+                serde_json::from_slice(line).map_err(|e| Error::ApiError {
+                    status_code: None,
+                    details: format!("Invalid JSON in NDJSON line: {e}"),
+                })?;
+            match response.error {
+                Some(error) => Err(Error::ApiError {
+                    status_code: None,
+                    details: error,
+                }),
+                None => Ok(response),
+            }
+        })
+        .collect()
+}
+
+impl<M: ModelName> LanguageModelClient for Ollama<M> {
+    type Response = types::ChatResponse;
+    type StreamEvent = types::ChatResponse;
+
+    fn path(&self) -> String {
+        "/api/chat".to_string()
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn headers(&self) -> reqwest::header::HeaderMap {
+        self.request_headers()
+    }
+
+    fn query_params(&self) -> Vec<(&str, &str)> {
+        Vec::new()
+    }
+
+    fn retry_policy(&self) -> RetryPolicy {
+        self.settings.retry_policy.clone()
+    }
+
+    fn transport(&self) -> crate::core::client::TransportSettings {
+        self.settings.transport.clone()
+    }
+
+    fn body(&self) -> reqwest::Body {
+        if let Some(request) = &self.lm_options.request {
+            let body = serde_json::to_string(request).unwrap();
+            return reqwest::Body::from(body);
+        };
+        reqwest::Body::from("{}")
+    }
+
+    /// Ollama does not stream over SSE, see [`Ollama::send_and_stream_ndjson`]. SSE
+    /// messages are still accepted in case a proxy re-encodes the stream.
+    fn parse_stream_sse(
+        event: std::result::Result<Event, reqwest_eventsource::Error>,
+    ) -> Result<Self::StreamEvent> {
+        match event {
+            Ok(Event::Open) => Ok(types::ChatResponse::default()),
+            Ok(Event::Message(msg)) => {
+                serde_json::from_str(&msg.data).map_err(|e| Error::ApiError {
+                    status_code: None,
+                    details: format!("Invalid JSON in SSE data: {e}"),
+                })
+            }
+            Err(e) => Err(Error::ApiError {
+                status_code: None,
+                details: e.to_string(),
+            }),
+        }
+    }
+
+    fn end_stream(event: &Self::StreamEvent) -> bool {
+        event.done
+    }
+}
+
+impl<M: ModelName> EmbeddingClient for Ollama<M> {
+    type Response = types::EmbedResponse;
+
+    fn path(&self) -> String {
+        "/api/embed".to_string()
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn headers(&self) -> reqwest::header::HeaderMap {
+        self.request_headers()
+    }
+
+    fn query_params(&self) -> Vec<(&str, &str)> {
+        Vec::new()
+    }
+
+    fn retry_policy(&self) -> RetryPolicy {
+        self.settings.retry_policy.clone()
+    }
+
+    fn transport(&self) -> crate::core::client::TransportSettings {
+        self.settings.transport.clone()
+    }
+
+    fn body(&self) -> reqwest::Body {
+        let body = serde_json::to_string(&self.embedding_options.request).unwrap();
+        reqwest::Body::from(body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_drain_ndjson_keeps_partial_line() {
+        let mut buffer = br#"{"message":{"role":"assistant","content":"Hel"},"done":false}
+{"message":{"role":"assistant","con"#
+            .to_vec();
+        let parsed = drain_ndjson_lines(&mut buffer);
+        assert_eq!(parsed.len(), 1);
+        assert_eq!(
+            parsed[0]
+                .as_ref()
+                .unwrap()
+                .message
+                .as_ref()
+                .unwrap()
+                .content,
+            "Hel"
+        );
+
+        buffer.extend_from_slice(
+            br#"tent":"lo"},"done":true,"prompt_eval_count":3,"eval_count":2}
+"#,
+        );
+        let parsed = drain_ndjson_lines(&mut buffer);
+        assert_eq!(parsed.len(), 1);
+        let last = parsed[0].as_ref().unwrap();
+        assert!(last.done);
+        assert_eq!(last.eval_count, Some(2));
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn test_drain_ndjson_surfaces_errors() {
+        let mut buffer = b"{\"error\":\"model not found\"}\n\n".to_vec();
+        let parsed = drain_ndjson_lines(&mut buffer);
+        assert_eq!(parsed.len(), 1);
+        assert!(
+            matches!(&parsed[0], Err(Error::ApiError { details, .. }) if details == "model not found")
+        );
+    }
+}
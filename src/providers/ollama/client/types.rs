@@ -0,0 +1,166 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::providers::ollama::settings::KeepAlive;
+
+// ============================================================================
+// Section: chat
+// ============================================================================
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct ChatRequest {
+    pub(crate) model: String,
+    pub(crate) messages: Vec<ChatMessage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) tools: Option<Vec<Tool>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) format: Option<Value>,
+    #[serde(skip_serializing_if = "Map::is_empty")]
+    pub(crate) options: Map<String, Value>,
+    pub(crate) stream: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) keep_alive: Option<KeepAlive>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) think: Option<bool>,
+    /// Provider-specific fields merged into the request body.
+    #[serde(flatten, skip_serializing_if = "Map::is_empty")]
+    pub(crate) provider_options: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum Role {
+    System,
+    #[default]
+    User,
+    Assistant,
+    Tool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct ChatMessage {
+    pub(crate) role: Role,
+    #[serde(default)]
+    pub(crate) content: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) thinking: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) images: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) tool_calls: Vec<ToolCall>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) tool_name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct ToolCall {
+    pub(crate) function: FunctionCall,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct FunctionCall {
+    pub(crate) name: String,
+    #[serde(default)]
+    pub(crate) arguments: Value,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct Tool {
+    #[serde(rename = "type")]
+    pub(crate) type_: String,
+    pub(crate) function: FunctionDefinition,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct FunctionDefinition {
+    pub(crate) name: String,
+    pub(crate) description: String,
+    pub(crate) parameters: Value,
+}
+
+/// A response from `/api/chat`. When streaming, every NDJSON line has this shape and
+/// the final one has `done` set along with the token counts.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct ChatResponse {
+    #[serde(default)]
+    pub(crate) model: String,
+    #[serde(default)]
+    pub(crate) message: Option<ChatMessage>,
+    #[serde(default)]
+    pub(crate) done: bool,
+    #[serde(default)]
+    pub(crate) done_reason: Option<String>,
+    #[serde(default)]
+    pub(crate) prompt_eval_count: Option<usize>,
+    #[serde(default)]
+    pub(crate) eval_count: Option<usize>,
+    #[serde(default)]
+    pub(crate) error: Option<String>,
+}
+
+// ============================================================================
+// Section: embeddings
+// ============================================================================
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct EmbedRequest {
+    pub(crate) model: String,
+    pub(crate) input: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) dimensions: Option<usize>,
+    #[serde(skip_serializing_if = "Map::is_empty")]
+    pub(crate) options: Map<String, Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) keep_alive: Option<KeepAlive>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EmbedResponse {
+    pub(crate) embeddings: Vec<Vec<f32>>,
+}
+
+// ============================================================================
+// Section: models
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct TagsResponse {
+    #[serde(default)]
+    pub(crate) models: Vec<OllamaModel>,
+}
+
+/// A model available on the Ollama server, as returned by `/api/tags`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OllamaModel {
+    /// The model name, e.g. `llama3.2:latest`.
+    pub name: String,
+    /// The time the model was last modified.
+    #[serde(default)]
+    pub modified_at: Option<String>,
+    /// The size of the model on disk, in bytes.
+    #[serde(default)]
+    pub size: Option<u64>,
+    /// The digest of the model.
+    #[serde(default)]
+    pub digest: Option<String>,
+    /// Details about the model.
+    #[serde(default)]
+    pub details: Option<OllamaModelDetails>,
+}
+
+/// Details about a model available on the Ollama server.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct OllamaModelDetails {
+    /// The model file format, e.g. `gguf`.
+    #[serde(default)]
+    pub format: Option<String>,
+    /// The model family, e.g. `llama`.
+    #[serde(default)]
+    pub family: Option<String>,
+    /// The number of parameters, e.g. `3.2B`.
+    #[serde(default)]
+    pub parameter_size: Option<String>,
+    /// The quantization level, e.g. `Q4_K_M`.
+    #[serde(default)]
+    pub quantization_level: Option<String>,
+}
@@ -0,0 +1,301 @@
+//! Conversions between types used by the Ollama provider and the types used by the core library.
+use crate::core::embedding_model::EmbeddingModelOptions;
+use crate::core::language_model::{
+    FinishReason, LanguageModelOptions, LanguageModelResponseContentType, Usage,
+};
+use crate::core::messages::{Message, TaggedMessage};
+use crate::core::tools::{Tool, ToolCallInfo};
+use crate::extensions::Extensions;
+use crate::providers::ollama::client::types::{
+    self, ChatMessage, ChatRequest, ChatResponse, EmbedRequest, FunctionDefinition, Role,
+};
+use serde_json::{Map, Value, json};
+
+impl From<Tool> for types::Tool {
+    fn from(value: Tool) -> Self {
+        let mut parameters = value.input_schema.to_value();
+        if let Some(obj) = parameters.as_object_mut() {
+            obj.remove("$schema");
+        }
+
+        Self {
+            type_: "function".to_string(),
+            function: FunctionDefinition {
+                name: value.name,
+                description: value.description,
+                parameters,
+            },
+        }
+    }
+}
+
+impl From<LanguageModelOptions> for ChatRequest {
+    fn from(options: LanguageModelOptions) -> Self {
+        let mut messages: Vec<ChatMessage> = Vec::new();
+        if let Some(system) = options.system {
+            messages.push(ChatMessage {
+                role: Role::System,
+                content: system,
+                ..Default::default()
+            });
+        }
+        messages.extend(options.messages.into_iter().map(ChatMessage::from));
+
+        let tools = options.tools.map(|t| {
+            let tools_list = t.tools.lock().unwrap_or_else(|p| p.into_inner());
+            tools_list.iter().map(|tool| tool.clone().into()).collect()
+        });
+
+        let format = options.schema.map(|s| {
+            let mut v = serde_json::to_value(s).unwrap_or_default();
+            if let Some(obj) = v.as_object_mut() {
+                obj.remove("$schema");
+            }
+            v
+        });
+
+        let mut model_options = Map::new();
+        let mut set = |key: &str, value: Option<Value>| {
+            if let Some(value) = value {
+                model_options.insert(key.to_string(), value);
+            }
+        };
+        set("seed", options.seed.map(|v| json!(v)));
+        set(
+            "temperature",
+            options.temperature.map(|t| json!(t as f32 / 100.0)),
+        );
+        set("top_p", options.top_p.map(|t| json!(t as f32 / 100.0)));
+        set("top_k", options.top_k.map(|v| json!(v)));
+        set("num_predict", options.max_output_tokens.map(|v| json!(v)));
+        set("stop", options.stop_sequences.map(|v| json!(v)));
+        set(
+            "presence_penalty",
+            options.presence_penalty.map(|v| json!(v)),
+        );
+        set(
+            "frequency_penalty",
+            options.frequency_penalty.map(|v| json!(v)),
+        );
+
+        Self {
+            model: String::new(), // will be set in language_model.rs
+            messages,
+            tools,
+            format,
+            options: model_options,
+            stream: false,
+            keep_alive: None,
+            think: options.reasoning_effort.map(|_| true),
+            provider_options: options.provider_options,
+        }
+    }
+}
+
+impl From<TaggedMessage> for ChatMessage {
+    fn from(tagged: TaggedMessage) -> Self {
+        tagged.message.into()
+    }
+}
+
+impl From<Message> for ChatMessage {
+    fn from(message: Message) -> Self {
+        match message {
+            Message::System(s) => ChatMessage {
+                role: Role::System,
+                content: s.content,
+                ..Default::default()
+            },
+            Message::Developer(d) => ChatMessage {
+                role: Role::System,
+                content: d,
+                ..Default::default()
+            },
+            Message::User(u) => ChatMessage {
+                role: Role::User,
+                content: u.content,
+                ..Default::default()
+            },
+            Message::Assistant(a) => match a.content {
+                LanguageModelResponseContentType::Text(text) => ChatMessage {
+                    role: Role::Assistant,
+                    content: text,
+                    ..Default::default()
+                },
+                LanguageModelResponseContentType::Reasoning { content, .. } => ChatMessage {
+                    role: Role::Assistant,
+                    thinking: Some(content),
+                    ..Default::default()
+                },
+                LanguageModelResponseContentType::ToolCall(tool_info) => ChatMessage {
+                    role: Role::Assistant,
+                    tool_calls: vec![types::ToolCall {
+                        function: types::FunctionCall {
+                            name: tool_info.tool.name,
+                            arguments: tool_info.input,
+                        },
+                    }],
+                    ..Default::default()
+                },
+                _ => ChatMessage {
+                    role: Role::Assistant,
+                    ..Default::default()
+                },
+            },
+            Message::Tool(tool_result) => ChatMessage {
+                role: Role::Tool,
+                content: match tool_result.output {
+                    Ok(Value::String(text)) => text,
+                    Ok(value) => value.to_string(),
+                    Err(e) => e.to_string(),
+                },
+                images: tool_result.images.into_iter().map(|i| i.data).collect(),
+                tool_name: Some(tool_result.tool.name),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Maps the `done_reason` of the final chat response. Ollama stops with `stop` when
+/// it calls tools, so `calls_tools` tells the two apart.
+pub(crate) fn finish_reason(response: &ChatResponse, calls_tools: bool) -> Option<FinishReason> {
+    response.done_reason.as_deref().map(|reason| match reason {
+        "stop" if calls_tools => FinishReason::ToolCalls,
+        "stop" => FinishReason::Stop,
+        "length" => FinishReason::Length,
+        other => FinishReason::Other(other.to_string()),
+    })
+}
+
+/// Extracts the token usage reported on the final chat response.
+pub(crate) fn usage(response: &ChatResponse) -> Option<Usage> {
+    if response.prompt_eval_count.is_none() && response.eval_count.is_none() {
+        return None;
+    }
+    Some(Usage {
+        input_tokens: response.prompt_eval_count,
+        output_tokens: response.eval_count,
+        reasoning_tokens: None,
+        cached_tokens: None,
+    })
+}
+
+/// Converts the tool calls of a chat message. Ollama does not assign ids to tool calls,
+/// so one is generated for each to pair it with its result.
+pub(crate) fn tool_calls(message: &ChatMessage) -> Vec<ToolCallInfo> {
+    message
+        .tool_calls
+        .iter()
+        .map(|call| {
+            let mut info = ToolCallInfo::new(call.function.name.clone());
+            info.id(format!("call_{}", uuid::Uuid::new_v4().simple()));
+            info.input(call.function.arguments.clone());
+            info
+        })
+        .collect()
+}
+
+/// Converts a chat message to response contents, in the order reasoning, text, tool calls.
+pub(crate) fn message_contents(message: &ChatMessage) -> Vec<LanguageModelResponseContentType> {
+    let mut contents = Vec::new();
+    if let Some(thinking) = message.thinking.as_ref().filter(|t| !t.is_empty()) {
+        contents.push(LanguageModelResponseContentType::Reasoning {
+            content: thinking.clone(),
+            extensions: Extensions::default(),
+        });
+    }
+    if !message.content.is_empty() {
+        contents.push(LanguageModelResponseContentType::Text(
+            message.content.clone(),
+        ));
+    }
+    contents.extend(
+        tool_calls(message)
+            .into_iter()
+            .map(LanguageModelResponseContentType::ToolCall),
+    );
+    contents
+}
+
+impl From<EmbeddingModelOptions> for EmbedRequest {
+    fn from(value: EmbeddingModelOptions) -> Self {
+        EmbedRequest {
+            model: String::new(), // will be set in embedding_model.rs
+            input: value.input,
+            dimensions: value.dimensions,
+            options: Map::new(),
+            keep_alive: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::messages::AssistantMessage;
+    use crate::core::tools::ToolResultInfo;
+
+    #[test]
+    fn test_request_maps_sampling_to_options() {
+        let options = LanguageModelOptions {
+            system: Some("Be brief".to_string()),
+            temperature: Some(70),
+            max_output_tokens: Some(128),
+            messages: vec![TaggedMessage::new(0, Message::User("hi".into()))],
+            ..Default::default()
+        };
+        let request: ChatRequest = options.into();
+
+        assert_eq!(request.messages.len(), 2);
+        assert_eq!(request.messages[0].role, Role::System);
+        assert_eq!(request.options["num_predict"], json!(128));
+        assert!((request.options["temperature"].as_f64().unwrap() - 0.7).abs() < 1e-6);
+        assert!(!request.options.contains_key("top_k"));
+    }
+
+    #[test]
+    fn test_tool_messages_round_trip() {
+        let mut call = ToolCallInfo::new("search");
+        call.id("call_1");
+        call.input(json!({ "q": "rust" }));
+        let assistant: ChatMessage = Message::Assistant(AssistantMessage::new(
+            LanguageModelResponseContentType::ToolCall(call),
+            None,
+        ))
+        .into();
+        assert_eq!(
+            assistant.tool_calls[0].function.arguments,
+            json!({ "q": "rust" })
+        );
+
+        let mut result = ToolResultInfo::new("search");
+        result.output(json!("found"));
+        let tool: ChatMessage = Message::Tool(result).into();
+        assert_eq!(tool.role, Role::Tool);
+        assert_eq!(tool.content, "found");
+        assert_eq!(tool.tool_name.as_deref(), Some("search"));
+    }
+
+    #[test]
+    fn test_message_contents() {
+        let message: ChatMessage = serde_json::from_value(json!({
+            "role": "assistant",
+            "content": "",
+            "thinking": "Let me look",
+            "tool_calls": [{ "function": { "name": "search", "arguments": { "q": "rust" } } }]
+        }))
+        .unwrap();
+        let contents = message_contents(&message);
+
+        assert_eq!(contents.len(), 2);
+        assert!(
+            matches!(&contents[0], LanguageModelResponseContentType::Reasoning { content, .. } if content == "Let me look")
+        );
+        let LanguageModelResponseContentType::ToolCall(info) = &contents[1] else {
+            panic!("expected a tool call");
+        };
+        assert_eq!(info.tool.name, "search");
+        assert!(info.tool.id.starts_with("call_"));
+    }
+}
@@ -0,0 +1,30 @@
+//! Embedding model implementation for the Ollama provider.
+
+use crate::{
+    core::{
+        capabilities::ModelName,
+        client::EmbeddingClient,
+        embedding_model::{EmbeddingModel, EmbeddingModelOptions, EmbeddingModelResponse},
+    },
+    error::Result,
+    providers::ollama::Ollama,
+};
+use async_trait::async_trait;
+
+#[async_trait]
+impl<M: ModelName> EmbeddingModel for Ollama<M> {
+    async fn embed(&self, input: EmbeddingModelOptions) -> Result<EmbeddingModelResponse> {
+        // Clone self to allow mutation
+        let mut model = self.clone();
+
+        let mut request: crate::providers::ollama::client::types::EmbedRequest = input.into();
+        request.model = model.embedding_options.model.clone();
+        request.keep_alive = model.settings.keep_alive.clone();
+        request.options = model.settings.options.clone();
+        model.embedding_options.request = request;
+
+        let response = model.send(&model.settings.base_url).await?;
+
+        Ok(response.embeddings)
+    }
+}
@@ -0,0 +1,156 @@
+//! Language model implementation for the Ollama provider.
+use crate::core::capabilities::ModelName;
+use crate::core::client::LanguageModelClient;
+use crate::core::language_model::{
+    LanguageModel, LanguageModelOptions, LanguageModelResponse, LanguageModelResponseContentType,
+    LanguageModelStreamChunk, LanguageModelStreamChunkType, ProviderStream,
+};
+use crate::core::messages::AssistantMessage;
+use crate::core::tools::{ToolCallDelta, ToolCallInfo};
+use crate::error::Result;
+use crate::extensions::Extensions;
+use crate::providers::ollama::client::types;
+use crate::providers::ollama::{Ollama, conversions};
+use async_trait::async_trait;
+use futures::StreamExt;
+
+impl<M: ModelName> Ollama<M> {
+    /// Builds the chat request for `options`, applying the provider settings.
+    fn chat_request(&self, options: LanguageModelOptions, stream: bool) -> types::ChatRequest {
+        let mut request: types::ChatRequest = options.into();
+        request.model = self.lm_options.model.clone();
+        request.stream = stream;
+        request.keep_alive = self.settings.keep_alive.clone();
+        for (key, value) in &self.settings.options {
+            request
+                .options
+                .entry(key.clone())
+                .or_insert_with(|| value.clone());
+        }
+        request
+    }
+}
+
+#[async_trait]
+impl<M: ModelName> LanguageModel for Ollama<M> {
+    fn name(&self) -> String {
+        self.lm_options.model.clone()
+    }
+
+    fn capabilities(&self) -> crate::core::capabilities::Capabilities {
+        M::CAPABILITIES
+    }
+
+    fn info(&self) -> crate::core::capabilities::ModelInfo {
+        Self::model_info(&self.name())
+    }
+
+    async fn generate_text(
+        &mut self,
+        options: LanguageModelOptions,
+    ) -> Result<LanguageModelResponse> {
+        self.lm_options.request = Some(self.chat_request(options, false));
+
+        let response: types::ChatResponse = self.send(&self.settings.base_url).await?;
+
+        let calls_tools = response
+            .message
+            .as_ref()
+            .is_some_and(|message| !message.tool_calls.is_empty());
+        Ok(LanguageModelResponse {
+            contents: response
+                .message
+                .as_ref()
+                .map(conversions::message_contents)
+                .unwrap_or_default(),
+            usage: conversions::usage(&response),
+            stop_sequence: None,
+            finish_reason: conversions::finish_reason(&response, calls_tools),
+            logprobs: Vec::new(),
+            extensions: Default::default(),
+        })
+    }
+
+    async fn stream_text(&mut self, options: LanguageModelOptions) -> Result<ProviderStream> {
+        self.lm_options.request = Some(self.chat_request(options, true));
+
+        let ollama_stream = self.send_and_stream_ndjson().await?;
+
+        #[derive(Default)]
+        struct StreamState {
+            text: String,
+            thinking: String,
+            tool_calls: Vec<ToolCallInfo>,
+        }
+
+        let stream = ollama_stream.scan(StreamState::default(), |state, evt_res| {
+            futures::future::ready(match evt_res {
+                Ok(response) => {
+                    let mut chunks = Vec::new();
+
+                    if let Some(message) = &response.message {
+                        if let Some(thinking) = message.thinking.as_ref().filter(|t| !t.is_empty())
+                        {
+                            state.thinking.push_str(thinking);
+                            chunks.push(LanguageModelStreamChunk::Delta(
+                                LanguageModelStreamChunkType::Reasoning(thinking.clone()),
+                            ));
+                        }
+                        if !message.content.is_empty() {
+                            state.text.push_str(&message.content);
+                            chunks.push(LanguageModelStreamChunk::Delta(
+                                LanguageModelStreamChunkType::Text(message.content.clone()),
+                            ));
+                        }
+                        // Ollama sends each tool call whole, in a single line
+                        for tool_info in conversions::tool_calls(message) {
+                            chunks.push(LanguageModelStreamChunk::ToolCallDelta(ToolCallDelta {
+                                tool: tool_info.tool.clone(),
+                                delta: tool_info.input.to_string(),
+                            }));
+                            state.tool_calls.push(tool_info);
+                        }
+                    }
+
+                    if response.done {
+                        let usage = conversions::usage(&response);
+                        if let Some(reason) =
+                            conversions::finish_reason(&response, !state.tool_calls.is_empty())
+                        {
+                            chunks.push(LanguageModelStreamChunk::FinishReason(reason));
+                        }
+                        if !state.thinking.is_empty() {
+                            chunks.push(LanguageModelStreamChunk::Done(AssistantMessage {
+                                content: LanguageModelResponseContentType::Reasoning {
+                                    content: std::mem::take(&mut state.thinking),
+                                    extensions: Extensions::default(),
+                                },
+                                usage: usage.clone(),
+                            }));
+                        }
+                        if state.tool_calls.is_empty() {
+                            chunks.push(LanguageModelStreamChunk::Done(AssistantMessage {
+                                content: LanguageModelResponseContentType::Text(std::mem::take(
+                                    &mut state.text,
+                                )),
+                                usage,
+                            }));
+                        } else {
+                            for tool_info in std::mem::take(&mut state.tool_calls) {
+                                chunks.push(LanguageModelStreamChunk::Done(AssistantMessage {
+                                    content: LanguageModelResponseContentType::ToolCall(tool_info),
+                                    usage: usage.clone(),
+                                }));
+                            }
+                        }
+                    }
+
+                    Some(Ok(chunks))
+                }
+                Err(e) => Some(Err(e)),
+            })
+        });
+
+        Ok(Box::pin(stream))
+    }
+}
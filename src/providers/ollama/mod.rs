@@ -0,0 +1,259 @@
+//! This module provides the Ollama provider, which implements the `LanguageModel`,
+//! `EmbeddingModel`, and `Provider` traits for local models served by Ollama.
+//!
+//! The provider speaks Ollama's native API (`/api/chat`, `/api/embed`, and `/api/tags`)
+//! rather than its OpenAI-compatible shim, so Ollama specific options such as
+//! `keep_alive` and `num_ctx` are available.
+
+pub mod capabilities;
+pub mod client;
+pub mod conversions;
+pub mod embedding_model;
+pub mod language_model;
+pub mod settings;
+
+use crate::core::DynamicModel;
+use crate::core::capabilities::ModelName;
+use crate::core::client::RetryPolicy;
+use crate::core::utils::validate_base_url;
+use crate::error::{Error, Result};
+use crate::providers::ollama::client::{OllamaEmbeddingOptions, OllamaOptions};
+use crate::providers::ollama::settings::{KeepAlive, OllamaProviderSettings};
+use serde::Serialize;
+use serde_json::Value;
+
+pub use client::types::{OllamaModel, OllamaModelDetails};
+
+/// The Ollama provider.
+#[derive(Debug, Serialize, Clone)]
+pub struct Ollama<M: ModelName> {
+    /// Configuration settings for the Ollama provider.
+    pub settings: OllamaProviderSettings,
+    pub(crate) lm_options: OllamaOptions,
+    pub(crate) embedding_options: OllamaEmbeddingOptions,
+    _phantom: std::marker::PhantomData<M>,
+}
+
+impl<M: ModelName> Ollama<M> {
+    /// Ollama provider setting builder.
+    pub fn builder() -> OllamaBuilder<M> {
+        OllamaBuilder::default()
+    }
+
+    /// Lists the models available on the Ollama server.
+    ///
+    /// # Returns
+    ///
+    /// The models that have been pulled to the server.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the server cannot be reached or returns an invalid response.
+    pub async fn list_models(&self) -> Result<Vec<OllamaModel>> {
+        Ok(self.fetch_tags().await?.models)
+    }
+
+    fn with_model(settings: OllamaProviderSettings, model_name: String) -> Self {
+        let options = OllamaOptions::builder()
+            .model(model_name.clone())
+            .build()
+            .unwrap();
+        let embedding_options = OllamaEmbeddingOptions {
+            model: model_name,
+            ..Default::default()
+        };
+
+        Self {
+            settings,
+            lm_options: options,
+            embedding_options,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl Ollama<DynamicModel> {
+    /// Creates an Ollama provider with a dynamic model name using default settings.
+    ///
+    /// This allows you to use any model pulled to the Ollama server, specified as a
+    /// string rather than with methods like `Ollama::llama3_2()`.
+    ///
+    /// **WARNING**: when using `DynamicModel`, model capabilities are not validated.
+    /// This means there is no compile-time guarantee that the model supports requested features.
+    ///
+    /// For custom configuration (base URL, keep alive, etc.), use the builder pattern:
+    /// `Ollama::<DynamicModel>::builder().model_name(...).keep_alive(...).build()`
+    ///
+    /// # Parameters
+    ///
+    /// * `model_name` - The Ollama model name (e.g., "llama3.2", "qwen3:8b")
+    ///
+    /// # Returns
+    ///
+    /// A configured `Ollama<DynamicModel>` provider instance with default settings.
+    pub fn model_name(name: impl Into<String>) -> Self {
+        Self::with_model(OllamaProviderSettings::default(), name.into())
+    }
+}
+
+impl<M: ModelName> Default for Ollama<M> {
+    /// Creates a new Ollama provider with default settings.
+    fn default() -> Self {
+        Self::with_model(OllamaProviderSettings::default(), M::MODEL_NAME.to_string())
+    }
+}
+
+/// Ollama Provider Builder
+pub struct OllamaBuilder<M: ModelName> {
+    settings: OllamaProviderSettings,
+    model_name: String,
+    _phantom: std::marker::PhantomData<M>,
+}
+
+impl<M: ModelName> Default for OllamaBuilder<M> {
+    /// Creates a new Ollama provider builder with default settings.
+    fn default() -> Self {
+        Self {
+            settings: OllamaProviderSettings::default(),
+            model_name: M::MODEL_NAME.to_string(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl OllamaBuilder<DynamicModel> {
+    /// Sets the model name from a string. e.g., "llama3.2", "qwen3:8b"
+    ///
+    /// **WARNING**: when using `DynamicModel`, model capabilities are not validated.
+    /// This means there is no compile-time guarantee that the model supports requested features.
+    ///
+    /// # Parameters
+    ///
+    /// * `model_name` - The Ollama model name.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the model name set.
+    pub fn model_name(mut self, model_name: impl Into<String>) -> Self {
+        self.model_name = model_name.into();
+        self
+    }
+}
+
+impl<M: ModelName> OllamaBuilder<M> {
+    /// Sets the base URL of the Ollama server. Defaults to `OLLAMA_HOST` or
+    /// `http://localhost:11434`.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.settings.base_url = base_url.into();
+        self
+    }
+
+    /// Sets an API key, sent as a bearer token to servers behind an authenticating proxy.
+    pub fn api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.settings.api_key = api_key.into();
+        self
+    }
+
+    /// Sets the retry policy applied to requests made by this provider.
+    ///
+    /// # Parameters
+    ///
+    /// * `retry_policy` - The [`RetryPolicy`] to use for retryable failures.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the retry policy set.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.settings.retry_policy = retry_policy;
+        self
+    }
+
+    crate::core::client::transport_builder_methods!();
+
+    /// Sets the name of the provider. Defaults to "ollama".
+    pub fn provider_name(mut self, provider_name: impl Into<String>) -> Self {
+        self.settings.provider_name = provider_name.into();
+        self
+    }
+
+    /// Sets how long the model stays loaded after a request, e.g. `"10m"`, or `-1` to
+    /// keep it loaded indefinitely.
+    pub fn keep_alive(mut self, keep_alive: impl Into<KeepAlive>) -> Self {
+        self.settings.keep_alive = Some(keep_alive.into());
+        self
+    }
+
+    /// Sets the size of the context window in tokens (`num_ctx`).
+    pub fn num_ctx(self, num_ctx: u32) -> Self {
+        self.option("num_ctx", num_ctx)
+    }
+
+    /// Sets a model runtime option sent with every request, e.g. `num_gpu` or
+    /// `repeat_penalty`.
+    pub fn option(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.settings.options.insert(key.into(), value.into());
+        self
+    }
+
+    /// Builds the Ollama provider.
+    pub fn build(self) -> Result<Ollama<M>> {
+        // validate base url
+        let base_url = validate_base_url(&self.settings.base_url)?;
+
+        if self.model_name.is_empty() {
+            return Err(Error::MissingField("model_name".to_string()));
+        }
+
+        Ok(Ollama::with_model(
+            OllamaProviderSettings {
+                base_url,
+                ..self.settings
+            },
+            self.model_name,
+        ))
+    }
+}
+
+// Re-exports for convenience
+pub use capabilities::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_sets_runtime_options() {
+        let ollama = Ollama::<DynamicModel>::builder()
+            .model_name("llama3.2")
+            .base_url("http://localhost:11434")
+            .keep_alive("10m")
+            .num_ctx(8192)
+            .build()
+            .unwrap();
+
+        assert_eq!(ollama.lm_options.model, "llama3.2");
+        assert_eq!(
+            ollama.settings.keep_alive,
+            Some(KeepAlive::Duration("10m".to_string()))
+        );
+        assert_eq!(ollama.settings.options["num_ctx"], 8192);
+    }
+
+    #[test]
+    fn test_builder_requires_model_name() {
+        let result = Ollama::<DynamicModel>::builder().build();
+        assert!(matches!(result, Err(Error::MissingField(_))));
+    }
+
+    #[test]
+    fn test_keep_alive_serialization() {
+        assert_eq!(
+            serde_json::to_value(KeepAlive::from(-1)).unwrap(),
+            serde_json::json!(-1)
+        );
+        assert_eq!(
+            serde_json::to_value(KeepAlive::from("5m")).unwrap(),
+            serde_json::json!("5m")
+        );
+    }
+}
@@ -0,0 +1,118 @@
+//! Defines the settings for the Ollama provider.
+
+use crate::core::client::{RetryPolicy, TransportSettings};
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::time::Duration;
+
+/// How long Ollama keeps a model loaded in memory after a request.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum KeepAlive {
+    /// A duration string such as `"10m"` or `"24h"`.
+    Duration(String),
+    /// A number of seconds. `0` unloads the model immediately and a negative value
+    /// keeps it loaded indefinitely.
+    Seconds(i64),
+}
+
+impl From<&str> for KeepAlive {
+    fn from(value: &str) -> Self {
+        Self::Duration(value.to_string())
+    }
+}
+
+impl From<String> for KeepAlive {
+    fn from(value: String) -> Self {
+        Self::Duration(value)
+    }
+}
+
+impl From<i64> for KeepAlive {
+    fn from(value: i64) -> Self {
+        Self::Seconds(value)
+    }
+}
+
+impl From<Duration> for KeepAlive {
+    fn from(value: Duration) -> Self {
+        Self::Seconds(value.as_secs().try_into().unwrap_or(i64::MAX))
+    }
+}
+
+/// Settings for the Ollama provider.
+#[derive(Clone, Serialize, Deserialize, Builder)]
+#[builder(setter(into), default)]
+pub struct OllamaProviderSettings {
+    /// The name of the provider.
+    pub provider_name: String,
+
+    /// The base URL of the Ollama server.
+    pub base_url: String,
+
+    /// An optional API key, sent as a bearer token. Local servers do not need one, but
+    /// servers behind an authenticating proxy may.
+    pub api_key: String,
+
+    /// How long the model stays loaded after a request. Uses the server default when
+    /// unset.
+    pub keep_alive: Option<KeepAlive>,
+
+    /// Model runtime options sent with every request, such as `num_ctx` or `num_gpu`.
+    /// Sampling settings from the request take precedence over the same keys here.
+    pub options: Map<String, Value>,
+
+    /// The retry policy applied to requests.
+    pub retry_policy: RetryPolicy,
+
+    /// The HTTP client, middleware and rate limiter requests go through.
+    #[serde(skip)]
+    pub transport: TransportSettings,
+}
+
+impl std::fmt::Debug for OllamaProviderSettings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OllamaProviderSettings")
+            .field("provider_name", &self.provider_name)
+            .field("base_url", &self.base_url)
+            .field("api_key", &"<redacted>")
+            .field("keep_alive", &self.keep_alive)
+            .field("options", &self.options)
+            .field("retry_policy", &self.retry_policy)
+            .field("transport", &self.transport)
+            .finish()
+    }
+}
+
+impl Default for OllamaProviderSettings {
+    /// Returns the default settings for the Ollama provider.
+    fn default() -> Self {
+        let base_url = std::env::var("OLLAMA_HOST")
+            .map(|host| {
+                if host.starts_with("http://") || host.starts_with("https://") {
+                    host
+                } else {
+                    format!("http://{host}")
+                }
+            })
+            .unwrap_or_else(|_| "http://localhost:11434".to_string());
+
+        Self {
+            provider_name: "ollama".to_string(),
+            base_url,
+            api_key: std::env::var("OLLAMA_API_KEY").unwrap_or_default(),
+            keep_alive: None,
+            options: Map::new(),
+            retry_policy: RetryPolicy::default(),
+            transport: TransportSettings::default(),
+        }
+    }
+}
+
+impl OllamaProviderSettings {
+    /// Creates a new builder for `OllamaProviderSettings`.
+    pub fn builder() -> OllamaProviderSettingsBuilder {
+        OllamaProviderSettingsBuilder::default()
+    }
+}
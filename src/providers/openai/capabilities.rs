@@ -16,95 +16,185 @@ model_capabilities! {
             display_name: "Codex Mini",
             capabilities: [ImageInputSupport, ReasoningSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
         },
+        DallE2 {
+            model_name: "dall-e-2",
+            constructor_name: dall_e_2,
+            display_name: "DALL·E 2",
+            capabilities: [ImageOutputSupport, TextInputSupport]
+        },
+        DallE3 {
+            model_name: "dall-e-3",
+            constructor_name: dall_e_3,
+            display_name: "DALL·E 3",
+            capabilities: [ImageOutputSupport, TextInputSupport]
+        },
         Gpt35Turbo {
             model_name: "gpt-3.5-turbo",
             constructor_name: gpt_3_5_turbo,
             display_name: "GPT-3.5-turbo",
-            capabilities: [TextInputSupport, TextOutputSupport]
+            capabilities: [TextInputSupport, TextOutputSupport],
+            context_window: 16_385,
+            max_output_tokens: 4096,
+            input_price: 0.5,
+            output_price: 1.5
         },
         Gpt4 {
             model_name: "gpt-4",
             constructor_name: gpt_4,
             display_name: "GPT-4",
-            capabilities: [ImageInputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [ImageInputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport],
+            context_window: 8192,
+            max_output_tokens: 8192,
+            input_price: 30.0,
+            output_price: 60.0
         },
         Gpt41 {
             model_name: "gpt-4.1",
             constructor_name: gpt_4_1,
             display_name: "GPT-4.1",
-            capabilities: [ImageInputSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [ImageInputSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport],
+            context_window: 1_047_576,
+            max_output_tokens: 32_768,
+            input_price: 2.0,
+            output_price: 8.0
         },
         Gpt41Mini {
             model_name: "gpt-4.1-mini",
             constructor_name: gpt_4_1_mini,
             display_name: "GPT-4.1 mini",
-            capabilities: [ImageInputSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [ImageInputSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport],
+            context_window: 1_047_576,
+            max_output_tokens: 32_768,
+            input_price: 0.4,
+            output_price: 1.6
         },
         Gpt41Nano {
             model_name: "gpt-4.1-nano",
             constructor_name: gpt_4_1_nano,
             display_name: "GPT-4.1 nano",
-            capabilities: [ImageInputSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [ImageInputSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport],
+            context_window: 1_047_576,
+            max_output_tokens: 32_768,
+            input_price: 0.1,
+            output_price: 0.4
         },
         Gpt4Turbo {
             model_name: "gpt-4-turbo",
             constructor_name: gpt_4_turbo,
             display_name: "GPT-4 Turbo",
-            capabilities: [ImageInputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [ImageInputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport],
+            context_window: 128_000,
+            max_output_tokens: 4096,
+            input_price: 10.0,
+            output_price: 30.0
         },
         Gpt4o {
             model_name: "gpt-4o",
             constructor_name: gpt_4o,
             display_name: "GPT-4o",
-            capabilities: [ImageInputSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [ImageInputSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport],
+            context_window: 128_000,
+            max_output_tokens: 16_384,
+            input_price: 2.5,
+            output_price: 10.0
         },
         Gpt4o20240513 {
             model_name: "gpt-4o-2024-05-13",
             constructor_name: gpt_4o_2024_05_13,
             display_name: "GPT-4o (2024-05-13)",
-            capabilities: [ImageInputSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [ImageInputSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport],
+            context_window: 128_000,
+            max_output_tokens: 4096,
+            input_price: 5.0,
+            output_price: 15.0
         },
         Gpt4o20240806 {
             model_name: "gpt-4o-2024-08-06",
             constructor_name: gpt_4o_2024_08_06,
             display_name: "GPT-4o (2024-08-06)",
-            capabilities: [ImageInputSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [ImageInputSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport],
+            context_window: 128_000,
+            max_output_tokens: 16_384,
+            input_price: 2.5,
+            output_price: 10.0
         },
         Gpt4o20241120 {
             model_name: "gpt-4o-2024-11-20",
             constructor_name: gpt_4o_2024_11_20,
             display_name: "GPT-4o (2024-11-20)",
-            capabilities: [ImageInputSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [ImageInputSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport],
+            context_window: 128_000,
+            max_output_tokens: 16_384,
+            input_price: 2.5,
+            output_price: 10.0
         },
         Gpt4oMini {
             model_name: "gpt-4o-mini",
             constructor_name: gpt_4o_mini,
             display_name: "GPT-4o mini",
-            capabilities: [ImageInputSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [ImageInputSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport],
+            context_window: 128_000,
+            max_output_tokens: 16_384,
+            input_price: 0.15,
+            output_price: 0.6
+        },
+        Gpt4oMiniTranscribe {
+            model_name: "gpt-4o-mini-transcribe",
+            constructor_name: gpt_4o_mini_transcribe,
+            display_name: "GPT-4o mini Transcribe",
+            capabilities: [AudioInputSupport, TextOutputSupport]
+        },
+        Gpt4oMiniTts {
+            model_name: "gpt-4o-mini-tts",
+            constructor_name: gpt_4o_mini_tts,
+            display_name: "GPT-4o mini TTS",
+            capabilities: [AudioOutputSupport, TextInputSupport]
+        },
+        Gpt4oTranscribe {
+            model_name: "gpt-4o-transcribe",
+            constructor_name: gpt_4o_transcribe,
+            display_name: "GPT-4o Transcribe",
+            capabilities: [AudioInputSupport, TextOutputSupport]
         },
         Gpt5 {
             model_name: "gpt-5",
             constructor_name: gpt_5,
             display_name: "GPT-5",
-            capabilities: [ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport],
+            context_window: 400_000,
+            max_output_tokens: 128_000,
+            input_price: 1.25,
+            output_price: 10.0
         },
         Gpt51 {
             model_name: "gpt-5.1",
             constructor_name: gpt_5_1,
             display_name: "GPT-5.1",
-            capabilities: [ImageInputSupport, ReasoningSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [ImageInputSupport, ReasoningSupport, TextInputSupport, TextOutputSupport, ToolCallSupport],
+            context_window: 400_000,
+            max_output_tokens: 128_000,
+            input_price: 1.25,
+            output_price: 10.0
         },
         Gpt51ChatLatest {
             model_name: "gpt-5.1-chat-latest",
             constructor_name: gpt_5_1_chat_latest,
             display_name: "GPT-5.1 Chat",
-            capabilities: [ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport],
+            context_window: 128_000,
+            max_output_tokens: 16_384,
+            input_price: 1.25,
+            output_price: 10.0
         },
         Gpt51Codex {
             model_name: "gpt-5.1-codex",
             constructor_name: gpt_5_1_codex,
             display_name: "GPT-5.1 Codex",
-            capabilities: [ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport],
+            context_window: 400_000,
+            max_output_tokens: 128_000,
+            input_price: 1.25,
+            output_price: 10.0
         },
         Gpt51CodexMax {
             model_name: "gpt-5.1-codex-max",
@@ -116,25 +206,41 @@ model_capabilities! {
             model_name: "gpt-5.1-codex-mini",
             constructor_name: gpt_5_1_codex_mini,
             display_name: "GPT-5.1 Codex mini",
-            capabilities: [ImageInputSupport, ImageOutputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [ImageInputSupport, ImageOutputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport],
+            context_window: 400_000,
+            max_output_tokens: 128_000,
+            input_price: 0.25,
+            output_price: 2.0
         },
         Gpt52 {
             model_name: "gpt-5.2",
             constructor_name: gpt_5_2,
             display_name: "GPT-5.2",
-            capabilities: [ImageInputSupport, ReasoningSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [ImageInputSupport, ReasoningSupport, TextInputSupport, TextOutputSupport, ToolCallSupport],
+            context_window: 400_000,
+            max_output_tokens: 128_000,
+            input_price: 1.75,
+            output_price: 14.0
         },
         Gpt52ChatLatest {
             model_name: "gpt-5.2-chat-latest",
             constructor_name: gpt_5_2_chat_latest,
             display_name: "GPT-5.2 Chat",
-            capabilities: [ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport],
+            context_window: 128_000,
+            max_output_tokens: 16_384,
+            input_price: 1.75,
+            output_price: 14.0
         },
         Gpt52Pro {
             model_name: "gpt-5.2-pro",
             constructor_name: gpt_5_2_pro,
             display_name: "GPT-5.2 Pro",
-            capabilities: [ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport],
+            context_window: 400_000,
+            max_output_tokens: 128_000,
+            input_price: 21.0,
+            output_price: 168.0
         },
         Gpt52Codex {
             model_name: "gpt-5.2-codex",
@@ -152,43 +258,77 @@ model_capabilities! {
             model_name: "gpt-5-chat-latest",
             constructor_name: gpt_5_chat_latest,
             display_name: "GPT-5 Chat (latest)",
-            capabilities: [ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport]
+            capabilities: [ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport],
+            context_window: 128_000,
+            max_output_tokens: 16_384,
+            input_price: 1.25,
+            output_price: 10.0
         },
         Gpt5Codex {
             model_name: "gpt-5-codex",
             constructor_name: gpt_5_codex,
             display_name: "GPT-5-Codex",
-            capabilities: [ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport],
+            context_window: 400_000,
+            max_output_tokens: 128_000,
+            input_price: 1.25,
+            output_price: 10.0
         },
         Gpt5Mini {
             model_name: "gpt-5-mini",
             constructor_name: gpt_5_mini,
             display_name: "GPT-5 Mini",
-            capabilities: [ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport],
+            context_window: 400_000,
+            max_output_tokens: 128_000,
+            input_price: 0.25,
+            output_price: 2.0
         },
         Gpt5Nano {
             model_name: "gpt-5-nano",
             constructor_name: gpt_5_nano,
             display_name: "GPT-5 Nano",
-            capabilities: [ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport],
+            context_window: 400_000,
+            max_output_tokens: 128_000,
+            input_price: 0.05,
+            output_price: 0.4
         },
         Gpt5Pro {
             model_name: "gpt-5-pro",
             constructor_name: gpt_5_pro,
             display_name: "GPT-5 Pro",
-            capabilities: [ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport],
+            context_window: 400_000,
+            max_output_tokens: 272_000,
+            input_price: 15.0,
+            output_price: 120.0
+        },
+        GptImage1 {
+            model_name: "gpt-image-1",
+            constructor_name: gpt_image_1,
+            display_name: "GPT Image 1",
+            capabilities: [ImageInputSupport, ImageOutputSupport, TextInputSupport]
         },
         O1 {
             model_name: "o1",
             constructor_name: o1,
             display_name: "o1",
-            capabilities: [ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport],
+            context_window: 200_000,
+            max_output_tokens: 100_000,
+            input_price: 15.0,
+            output_price: 60.0
         },
         O1Mini {
             model_name: "o1-mini",
             constructor_name: o1_mini,
             display_name: "o1-mini",
-            capabilities: [ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport]
+            capabilities: [ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport],
+            context_window: 128_000,
+            max_output_tokens: 65_536,
+            input_price: 1.1,
+            output_price: 4.4
         },
         O1Preview {
             model_name: "o1-preview",
@@ -200,13 +340,21 @@ model_capabilities! {
             model_name: "o1-pro",
             constructor_name: o1_pro,
             display_name: "o1-pro",
-            capabilities: [ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport],
+            context_window: 200_000,
+            max_output_tokens: 100_000,
+            input_price: 150.0,
+            output_price: 600.0
         },
         O3 {
             model_name: "o3",
             constructor_name: o3,
             display_name: "o3",
-            capabilities: [ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport],
+            context_window: 200_000,
+            max_output_tokens: 100_000,
+            input_price: 2.0,
+            output_price: 8.0
         },
         O3DeepResearch {
             model_name: "o3-deep-research",
@@ -218,19 +366,31 @@ model_capabilities! {
             model_name: "o3-mini",
             constructor_name: o3_mini,
             display_name: "o3-mini",
-            capabilities: [ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport],
+            context_window: 200_000,
+            max_output_tokens: 100_000,
+            input_price: 1.1,
+            output_price: 4.4
         },
         O3Pro {
             model_name: "o3-pro",
             constructor_name: o3_pro,
             display_name: "o3-pro",
-            capabilities: [ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport],
+            context_window: 200_000,
+            max_output_tokens: 100_000,
+            input_price: 20.0,
+            output_price: 80.0
         },
         O4Mini {
             model_name: "o4-mini",
             constructor_name: o4_mini,
             display_name: "o4-mini",
-            capabilities: [ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+            capabilities: [ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport],
+            context_window: 200_000,
+            max_output_tokens: 100_000,
+            input_price: 1.1,
+            output_price: 4.4
         },
         O4MiniDeepResearch {
             model_name: "o4-mini-deep-research",
@@ -238,6 +398,24 @@ model_capabilities! {
             display_name: "o4-mini-deep-research",
             capabilities: [ImageInputSupport, ReasoningSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
         },
+        OmniModerationLatest {
+            model_name: "omni-moderation-latest",
+            constructor_name: omni_moderation_latest,
+            display_name: "omni-moderation",
+            capabilities: [ImageInputSupport, TextInputSupport]
+        },
+        Sora2 {
+            model_name: "sora-2",
+            constructor_name: sora_2,
+            display_name: "Sora 2",
+            capabilities: [TextInputSupport, VideoOutputSupport]
+        },
+        Sora2Pro {
+            model_name: "sora-2-pro",
+            constructor_name: sora_2_pro,
+            display_name: "Sora 2 Pro",
+            capabilities: [TextInputSupport, VideoOutputSupport]
+        },
         TextEmbedding3Large {
             model_name: "text-embedding-3-large",
             constructor_name: text_embedding_3_large,
@@ -256,5 +434,23 @@ model_capabilities! {
             display_name: "text-embedding-ada-002",
             capabilities: [TextInputSupport, TextOutputSupport]
         },
+        Tts1 {
+            model_name: "tts-1",
+            constructor_name: tts_1,
+            display_name: "TTS-1",
+            capabilities: [AudioOutputSupport, TextInputSupport]
+        },
+        Tts1Hd {
+            model_name: "tts-1-hd",
+            constructor_name: tts_1_hd,
+            display_name: "TTS-1 HD",
+            capabilities: [AudioOutputSupport, TextInputSupport]
+        },
+        Whisper1 {
+            model_name: "whisper-1",
+            constructor_name: whisper_1,
+            display_name: "Whisper",
+            capabilities: [AudioInputSupport, TextOutputSupport]
+        },
     }
 }
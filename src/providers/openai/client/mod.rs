@@ -6,12 +6,42 @@ pub(crate) mod types;
 
 pub(crate) use types::*;
 
-use crate::core::client::{EmbeddingClient, LanguageModelClient};
+use crate::core::client::{
+    EmbeddingClient, ImageClient, LanguageModelClient, ModelListClient, ModerationClient,
+    RetryPolicy, SpeechClient, StreamPosition, TranscriptionClient, VideoClient, sse_error,
+};
+use crate::core::provider::ModelDescriptor;
+use crate::core::utils::join_url;
 use crate::error::Error;
 use crate::providers::openai::{ModelName, OpenAI};
 use reqwest::header::CONTENT_TYPE;
 use reqwest_eventsource::Event;
 
+/// A prepared request to one of the image endpoints, which take either JSON or a
+/// multipart form.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct OpenAIImageOptions {
+    pub(crate) path: String,
+    pub(crate) content_type: String,
+    pub(crate) body: Vec<u8>,
+}
+
+/// A prepared request to the transcription or translation endpoint, sent as a
+/// multipart form.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct OpenAITranscriptionOptions {
+    pub(crate) path: String,
+    pub(crate) content_type: String,
+    pub(crate) body: Vec<u8>,
+}
+
+/// A prepared video generation request, sent as a multipart form.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct OpenAIVideoOptions {
+    pub(crate) content_type: String,
+    pub(crate) body: Vec<u8>,
+}
+
 impl<M: ModelName> LanguageModelClient for OpenAI<M> {
     type Response = types::OpenAIResponse;
     type StreamEvent = types::OpenAiStreamEvent;
@@ -46,6 +76,14 @@ impl<M: ModelName> LanguageModelClient for OpenAI<M> {
         Vec::new()
     }
 
+    fn retry_policy(&self) -> RetryPolicy {
+        self.settings.retry_policy.clone()
+    }
+
+    fn transport(&self) -> crate::core::client::TransportSettings {
+        self.settings.transport.clone()
+    }
+
     fn body(&self) -> reqwest::Body {
         let body = serde_json::to_string(&self.lm_options).unwrap();
         reqwest::Body::from(body)
@@ -72,17 +110,7 @@ impl<M: ModelName> LanguageModelClient for OpenAI<M> {
                         .unwrap_or(types::OpenAiStreamEvent::NotSupported(msg.data)))
                 }
             },
-            Err(e) => {
-                // Extract status code if it's an InvalidStatusCode error
-                let status_code = match &e {
-                    reqwest_eventsource::Error::InvalidStatusCode(status, _) => Some(*status),
-                    _ => None,
-                };
-                Err(Error::ApiError {
-                    status_code,
-                    details: e.to_string(),
-                })
-            }
+            Err(e) => Err(sse_error(e)),
         }
     }
 
@@ -91,6 +119,37 @@ impl<M: ModelName> LanguageModelClient for OpenAI<M> {
             || matches!(event, types::OpenAiStreamEvent::NotSupported(json) if json == "[END]")
             || matches!(event, types::OpenAiStreamEvent::ResponseError { .. })
     }
+
+    /// Streams a background response again from the last received event. Other
+    /// responses cannot be streamed again.
+    fn resume_request(
+        &self,
+        client: &reqwest::Client,
+        url: &reqwest::Url,
+        position: &StreamPosition<Self::StreamEvent>,
+    ) -> Option<reqwest::RequestBuilder> {
+        let background = self.lm_options.provider_options.get("background");
+        if background != Some(&serde_json::Value::Bool(true)) {
+            return None;
+        }
+        let Some(types::OpenAiStreamEvent::ResponseCreated { response, .. }) =
+            &position.first_event
+        else {
+            return None;
+        };
+        let starting_after = position.last_event.as_ref()?.sequence_number()?;
+
+        let url = join_url(url.clone(), &response.id).ok()?;
+        Some(
+            client
+                .get(url)
+                .headers(LanguageModelClient::headers(self))
+                .query(&[
+                    ("stream", "true".to_string()),
+                    ("starting_after", starting_after.to_string()),
+                ]),
+        )
+    }
 }
 
 impl<M: ModelName> EmbeddingClient for OpenAI<M> {
@@ -123,8 +182,325 @@ impl<M: ModelName> EmbeddingClient for OpenAI<M> {
         Vec::new()
     }
 
+    fn retry_policy(&self) -> RetryPolicy {
+        self.settings.retry_policy.clone()
+    }
+
+    fn transport(&self) -> crate::core::client::TransportSettings {
+        self.settings.transport.clone()
+    }
+
     fn body(&self) -> reqwest::Body {
         let body = serde_json::to_string(&self.embedding_options).unwrap();
         reqwest::Body::from(body)
     }
 }
+
+impl<M: ModelName> ModelListClient for OpenAI<M> {
+    type Response = types::ModelList;
+
+    fn path(&self) -> String {
+        "/v1/models".to_string()
+    }
+
+    fn query_params(&self) -> Vec<(&str, &str)> {
+        Vec::new()
+    }
+
+    fn headers(&self) -> reqwest::header::HeaderMap {
+        EmbeddingClient::headers(self)
+    }
+
+    fn retry_policy(&self) -> RetryPolicy {
+        self.settings.retry_policy.clone()
+    }
+
+    fn transport(&self) -> crate::core::client::TransportSettings {
+        self.settings.transport.clone()
+    }
+
+    fn descriptors(response: Self::Response) -> Vec<ModelDescriptor> {
+        response
+            .data
+            .into_iter()
+            .map(|model| ModelDescriptor {
+                id: model.id,
+                owned_by: model.owned_by,
+                ..Default::default()
+            })
+            .collect()
+    }
+}
+
+impl<M: ModelName> ImageClient for OpenAI<M> {
+    fn path(&self) -> String {
+        self.image_options.path.clone()
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn headers(&self) -> reqwest::header::HeaderMap {
+        let mut default_headers = reqwest::header::HeaderMap::new();
+        if let Ok(value) = self.image_options.content_type.parse() {
+            default_headers.insert(CONTENT_TYPE, value);
+        }
+        default_headers.insert(
+            "Authorization",
+            format!("Bearer {}", self.settings.api_key.clone())
+                .parse()
+                .unwrap(),
+        );
+
+        default_headers
+    }
+
+    fn query_params(&self) -> Vec<(&str, &str)> {
+        Vec::new()
+    }
+
+    fn retry_policy(&self) -> RetryPolicy {
+        self.settings.retry_policy.clone()
+    }
+
+    fn transport(&self) -> crate::core::client::TransportSettings {
+        self.settings.transport.clone()
+    }
+
+    fn body(&self) -> reqwest::Body {
+        reqwest::Body::from(self.image_options.body.clone())
+    }
+}
+
+impl<M: ModelName> SpeechClient for OpenAI<M> {
+    fn path(&self) -> String {
+        "/v1/audio/speech".to_string()
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn headers(&self) -> reqwest::header::HeaderMap {
+        let mut default_headers = reqwest::header::HeaderMap::new();
+        default_headers.insert(CONTENT_TYPE, "application/json".parse().unwrap());
+        default_headers.insert(
+            "Authorization",
+            format!("Bearer {}", self.settings.api_key.clone())
+                .parse()
+                .unwrap(),
+        );
+
+        default_headers
+    }
+
+    fn query_params(&self) -> Vec<(&str, &str)> {
+        Vec::new()
+    }
+
+    fn retry_policy(&self) -> RetryPolicy {
+        self.settings.retry_policy.clone()
+    }
+
+    fn transport(&self) -> crate::core::client::TransportSettings {
+        self.settings.transport.clone()
+    }
+
+    fn body(&self) -> reqwest::Body {
+        let body = serde_json::to_string(&self.speech_options).unwrap();
+        reqwest::Body::from(body)
+    }
+}
+
+impl<M: ModelName> TranscriptionClient for OpenAI<M> {
+    type Response = types::TranscriptionResponse;
+
+    fn path(&self) -> String {
+        self.transcription_options.path.clone()
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn headers(&self) -> reqwest::header::HeaderMap {
+        let mut default_headers = reqwest::header::HeaderMap::new();
+        if let Ok(value) = self.transcription_options.content_type.parse() {
+            default_headers.insert(CONTENT_TYPE, value);
+        }
+        default_headers.insert(
+            "Authorization",
+            format!("Bearer {}", self.settings.api_key.clone())
+                .parse()
+                .unwrap(),
+        );
+
+        default_headers
+    }
+
+    fn query_params(&self) -> Vec<(&str, &str)> {
+        Vec::new()
+    }
+
+    fn retry_policy(&self) -> RetryPolicy {
+        self.settings.retry_policy.clone()
+    }
+
+    fn transport(&self) -> crate::core::client::TransportSettings {
+        self.settings.transport.clone()
+    }
+
+    fn body(&self) -> reqwest::Body {
+        reqwest::Body::from(self.transcription_options.body.clone())
+    }
+}
+
+impl<M: ModelName> ModerationClient for OpenAI<M> {
+    type Response = types::ModerationResponse;
+
+    fn path(&self) -> String {
+        "/v1/moderations".to_string()
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn headers(&self) -> reqwest::header::HeaderMap {
+        let mut default_headers = reqwest::header::HeaderMap::new();
+        default_headers.insert(CONTENT_TYPE, "application/json".parse().unwrap());
+        default_headers.insert(
+            "Authorization",
+            format!("Bearer {}", self.settings.api_key.clone())
+                .parse()
+                .unwrap(),
+        );
+
+        default_headers
+    }
+
+    fn query_params(&self) -> Vec<(&str, &str)> {
+        Vec::new()
+    }
+
+    fn retry_policy(&self) -> RetryPolicy {
+        self.settings.retry_policy.clone()
+    }
+
+    fn transport(&self) -> crate::core::client::TransportSettings {
+        self.settings.transport.clone()
+    }
+
+    fn body(&self) -> reqwest::Body {
+        let body = serde_json::to_string(&self.moderation_options).unwrap();
+        reqwest::Body::from(body)
+    }
+}
+
+impl<M: ModelName> VideoClient for OpenAI<M> {
+    type Job = types::VideoObject;
+
+    fn path(&self) -> String {
+        "/v1/videos".to_string()
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn headers(&self) -> reqwest::header::HeaderMap {
+        let mut default_headers = self.auth_headers();
+        if let Ok(value) = self.video_options.content_type.parse() {
+            default_headers.insert(CONTENT_TYPE, value);
+        }
+
+        default_headers
+    }
+
+    fn auth_headers(&self) -> reqwest::header::HeaderMap {
+        let mut default_headers = reqwest::header::HeaderMap::new();
+        default_headers.insert(
+            "Authorization",
+            format!("Bearer {}", self.settings.api_key.clone())
+                .parse()
+                .unwrap(),
+        );
+
+        default_headers
+    }
+
+    fn query_params(&self) -> Vec<(&str, &str)> {
+        Vec::new()
+    }
+
+    fn retry_policy(&self) -> RetryPolicy {
+        self.settings.retry_policy.clone()
+    }
+
+    fn transport(&self) -> crate::core::client::TransportSettings {
+        self.settings.transport.clone()
+    }
+
+    fn body(&self) -> reqwest::Body {
+        reqwest::Body::from(self.video_options.body.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::DynamicModel;
+
+    fn position() -> StreamPosition<OpenAiStreamEvent> {
+        let created = r#"{"type": "response.created", "sequence_number": 0, "response": {"id": "resp_1", "status": "queued"}}"#;
+        let in_progress = r#"{"type": "response.in_progress", "sequence_number": 1}"#;
+        StreamPosition {
+            last_event_id: None,
+            first_event: Some(serde_json::from_str(created).unwrap()),
+            last_event: Some(OpenAiStreamEvent::NotSupported(in_progress.to_string())),
+        }
+    }
+
+    #[test]
+    fn test_resume_request_streams_background_response() {
+        let mut openai = OpenAI::<DynamicModel>::builder()
+            .model_name("gpt-5")
+            .api_key("key")
+            .build()
+            .unwrap();
+        openai
+            .lm_options
+            .provider_options
+            .insert("background".to_string(), serde_json::Value::Bool(true));
+        let url = reqwest::Url::parse("https://api.openai.com/v1/responses").unwrap();
+
+        let request = openai
+            .resume_request(&reqwest::Client::new(), &url, &position())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        assert_eq!(request.method(), reqwest::Method::GET);
+        assert_eq!(
+            request.url().as_str(),
+            "https://api.openai.com/v1/responses/resp_1?stream=true&starting_after=1"
+        );
+    }
+
+    #[test]
+    fn test_resume_request_requires_background_response() {
+        let openai = OpenAI::<DynamicModel>::builder()
+            .model_name("gpt-5")
+            .api_key("key")
+            .build()
+            .unwrap();
+        let url = reqwest::Url::parse("https://api.openai.com/v1/responses").unwrap();
+
+        assert!(
+            openai
+                .resume_request(&reqwest::Client::new(), &url, &position())
+                .is_none()
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use crate::error::Error;
 use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// Configuration options for OpenAI API requests.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, Builder)]
@@ -30,6 +31,16 @@ pub(crate) struct OpenAILanguageModelOptions {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default)]
     pub(crate) tools: Option<Vec<ToolParams>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub(crate) tool_choice: Option<ToolChoiceParam>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub(crate) parallel_tool_calls: Option<bool>,
+    /// Provider-specific fields merged into the request body.
+    #[serde(flatten, skip_serializing_if = "serde_json::Map::is_empty")]
+    #[builder(default)]
+    pub(crate) provider_options: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Response structure from the OpenAI API.
@@ -70,12 +81,25 @@ impl OpenAILanguageModelOptions {
     pub(crate) fn builder() -> OpenAILanguageModelOptionsBuilder {
         OpenAILanguageModelOptionsBuilder::default()
     }
+
+    /// Sets the detail of the reasoning summary, if reasoning is requested.
+    pub(crate) fn set_reasoning_summary(&mut self, summary: Option<SummaryType>) {
+        if let (Some(reasoning), Some(summary)) = (self.reasoning.as_mut(), summary) {
+            reasoning.summary = Some(summary);
+        }
+    }
 }
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[non_exhaustive]
 #[serde(tag = "type")]
 /// Events emitted during streaming from OpenAI.
 pub(crate) enum OpenAiStreamEvent {
+    /// Emitted when the response is created.
+    #[serde(rename = "response.created")]
+    ResponseCreated {
+        sequence_number: u64,
+        response: CreatedResponse,
+    },
     /// Emitted when the model response is complete.
     #[serde(rename = "response.completed")]
     ResponseCompleted {
@@ -115,6 +139,13 @@ pub(crate) enum OpenAiStreamEvent {
         message: String,
         param: Option<String>,
     },
+    /// Emitted when an output item, such as a function call, is added to the response.
+    #[serde(rename = "response.output_item.added")]
+    ResponseOutputItemAdded {
+        sequence_number: u64,
+        output_index: u32,
+        item: MessageItem,
+    },
     /// Emitted when a function call argument delta arrives during streaming.
     #[serde(rename = "response.function_call_arguments.delta")]
     ResponseFunctionCallArgumentsDelta {
@@ -134,6 +165,51 @@ pub(crate) enum OpenAiStreamEvent {
     NotSupported(String),
 }
 
+impl OpenAiStreamEvent {
+    /// Returns the position of the event in the stream of its response.
+    pub(crate) fn sequence_number(&self) -> Option<u64> {
+        match self {
+            Self::ResponseCreated {
+                sequence_number, ..
+            }
+            | Self::ResponseCompleted {
+                sequence_number, ..
+            }
+            | Self::ResponseIncomplete {
+                sequence_number, ..
+            }
+            | Self::ResponseOutputTextDelta {
+                sequence_number, ..
+            }
+            | Self::ResponseReasoningSummaryTextDelta {
+                sequence_number, ..
+            }
+            | Self::ResponseError {
+                sequence_number, ..
+            }
+            | Self::ResponseOutputItemAdded {
+                sequence_number, ..
+            }
+            | Self::ResponseFunctionCallArgumentsDelta {
+                sequence_number, ..
+            }
+            | Self::ResponseFunctionCallArgumentsDone {
+                sequence_number, ..
+            } => Some(*sequence_number),
+            Self::NotSupported(json) => serde_json::from_str::<serde_json::Value>(json)
+                .ok()?
+                .get("sequence_number")?
+                .as_u64(),
+        }
+    }
+}
+
+/// The response announced by a `response.created` event, before any output.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct CreatedResponse {
+    pub id: String,
+}
+
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
 /// Token usage statistics from OpenAI response.
 pub(crate) struct ResponseUsage {
@@ -193,6 +269,18 @@ pub(crate) enum ReasoningEffort {
     XHigh,
 }
 
+/// `auto`, `required`, `none`, or a specific function.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub(crate) enum ToolChoiceParam {
+    Mode(String),
+    Function {
+        #[serde(rename = "type")]
+        type_: String,
+        name: String,
+    },
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(tag = "type", rename_all = "snake_case")]
 #[non_exhaustive]
@@ -343,6 +431,7 @@ pub(crate) enum MessageItem {
 pub(crate) enum FunctionCallOutput {
     Text(String),
     Other(ContentType),
+    Parts(Vec<ContentType>),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -367,18 +456,36 @@ pub(crate) enum ContentType {
     },
     InputImage {
         detail: ImageDetail,
+        #[serde(skip_serializing_if = "Option::is_none")]
         file_id: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
         image_url: Option<String>,
     },
     InputFile {
+        #[serde(skip_serializing_if = "Option::is_none")]
         file_data: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
         filename: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
         file_url: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
         file_id: Option<String>,
     },
+    InputAudio {
+        input_audio: InputAudio,
+    },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct InputAudio {
+    /// The base64 encoded audio.
+    pub(crate) data: String,
+    /// `wav` or `mp3`.
+    pub(crate) format: String,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
 pub(crate) enum ImageDetail {
     #[default]
     Auto,
@@ -471,3 +578,128 @@ pub(crate) struct OpenAIEmbeddingOptions {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub encoding_format: Option<String>,
 }
+
+/// See [OpenAI Images API](https://platform.openai.com/docs/api-reference/images/create)
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct ImageGenerationRequest {
+    pub model: String,
+    pub prompt: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub n: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quality: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_format: Option<String>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct ImageObject {
+    pub b64_json: Option<String>,
+    pub url: Option<String>,
+    pub revised_prompt: Option<String>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct ImageResponse {
+    pub created: Option<u64>,
+    pub data: Vec<ImageObject>,
+    /// The encoding of the images. Only reported by `gpt-image-1`.
+    pub output_format: Option<String>,
+}
+
+/// See [OpenAI Audio API](https://platform.openai.com/docs/api-reference/audio/createSpeech)
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct SpeechRequest {
+    pub model: String,
+    pub input: String,
+    pub voice: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub speed: Option<f32>,
+}
+
+/// See [OpenAI Audio API](https://platform.openai.com/docs/api-reference/audio/verbose-json-object)
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct TranscriptionResponse {
+    pub text: String,
+    #[serde(default)]
+    pub language: Option<String>,
+    #[serde(default)]
+    pub duration: Option<f32>,
+    #[serde(default)]
+    pub segments: Vec<TranscriptionSegment>,
+    #[serde(default)]
+    pub words: Vec<TranscriptionWord>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct TranscriptionSegment {
+    pub text: String,
+    pub start: f32,
+    pub end: f32,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct TranscriptionWord {
+    pub word: String,
+    pub start: f32,
+    pub end: f32,
+}
+
+/// See [OpenAI Moderations API](https://platform.openai.com/docs/api-reference/moderations)
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct ModerationRequest {
+    pub model: String,
+    pub input: Vec<String>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct ModerationResponse {
+    pub results: Vec<ModerationObject>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct ModerationObject {
+    pub flagged: bool,
+    #[serde(default)]
+    pub categories: BTreeMap<String, bool>,
+    #[serde(default)]
+    pub category_scores: BTreeMap<String, f32>,
+}
+
+/// See [OpenAI Videos API](https://platform.openai.com/docs/api-reference/videos/object)
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct VideoObject {
+    pub id: String,
+    pub status: String,
+    #[serde(default)]
+    pub progress: Option<f32>,
+    #[serde(default)]
+    pub error: Option<VideoError>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct VideoError {
+    #[serde(default)]
+    pub code: Option<String>,
+    pub message: String,
+}
+
+/// A page of the models endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct ModelList {
+    pub data: Vec<Model>,
+}
+
+/// A model of the models endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct Model {
+    pub id: String,
+    #[serde(default)]
+    pub owned_by: Option<String>,
+}
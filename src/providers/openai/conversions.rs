@@ -1,15 +1,68 @@
 //! Helper functions and conversions for the OpenAI provider.
 
 use crate::core::embedding_model::EmbeddingModelOptions;
+use crate::core::image_model::{GeneratedImage, ImageData, ImageModelResponse};
 use crate::core::language_model::{
-    LanguageModelOptions, LanguageModelResponseContentType, ReasoningEffort, Usage,
+    FinishReason, LanguageModelOptions, LanguageModelResponseContentType, ReasoningEffort, Usage,
 };
-use crate::core::messages::Message;
-use crate::core::tools::Tool;
+use crate::core::messages::{ContentPart, MediaSource, Message};
+use crate::core::moderation_model::{ModerationModelResponse, ModerationResult};
+use crate::core::tools::{Tool, ToolChoice, ToolResultInfo};
+use crate::core::transcription_model::{
+    TranscriptionModelResponse, TranscriptionSegment, TranscriptionWord,
+};
+use crate::providers::openai::OpenAIReasoningSummary;
 use crate::providers::openai::client::{self, types};
 use schemars::Schema;
 use serde_json::Value;
 
+/// Converts a part of a multi-part user message. Audio is only accepted as base64
+/// data, so audio URLs are left out.
+fn input_content(part: ContentPart) -> Option<types::ContentType> {
+    let content = match part {
+        ContentPart::Text(text) => types::ContentType::InputText { text },
+        ContentPart::Image(image) => types::ContentType::InputImage {
+            detail: types::ImageDetail::Auto,
+            file_id: None,
+            image_url: Some(image.source.to_url(image.media_type())),
+        },
+        ContentPart::File(file) => input_file(file.source, &file.media_type, file.filename),
+        ContentPart::Document(document) => {
+            input_file(document.source, &document.media_type, document.title)
+        }
+        ContentPart::Audio(audio) => {
+            let format = audio.format().to_string();
+            let MediaSource::Base64(data) = audio.source else {
+                return None;
+            };
+            types::ContentType::InputAudio {
+                input_audio: types::InputAudio { data, format },
+            }
+        }
+    };
+    Some(content)
+}
+
+fn input_file(
+    source: MediaSource,
+    media_type: &str,
+    filename: Option<String>,
+) -> types::ContentType {
+    let (file_data, file_url) = match source {
+        MediaSource::Url(url) => (None, Some(url)),
+        data => (Some(data.to_url(media_type)), None),
+    };
+    types::ContentType::InputFile {
+        // OpenAI requires a file name with inline file data
+        filename: file_data
+            .as_ref()
+            .map(|_| filename.unwrap_or_else(|| "file".to_string())),
+        file_data,
+        file_url,
+        file_id: None,
+    }
+}
+
 impl From<Tool> for types::ToolParams {
     fn from(value: Tool) -> Self {
         let mut params = value.input_schema.to_value();
@@ -74,11 +127,28 @@ impl From<LanguageModelOptions> for client::OpenAILanguageModelOptions {
             max_output_tokens: options.max_output_tokens.map(|t| t as usize),
             stream: Some(false),
             top_p: options.top_p.map(|t| t as f32 / 100.0),
+            tool_choice: tools.as_ref().and(options.tool_choice).map(Into::into),
+            parallel_tool_calls: tools.as_ref().and(options.parallel_tool_calls),
+            provider_options: options.provider_options,
             tools,
         }
     }
 }
 
+impl From<ToolChoice> for types::ToolChoiceParam {
+    fn from(choice: ToolChoice) -> Self {
+        match choice {
+            ToolChoice::Auto => Self::Mode("auto".to_string()),
+            ToolChoice::Required => Self::Mode("required".to_string()),
+            ToolChoice::None => Self::Mode("none".to_string()),
+            ToolChoice::Tool(name) => Self::Function {
+                type_: "function".to_string(),
+                name,
+            },
+        }
+    }
+}
+
 impl From<Message> for Option<types::InputItem> {
     fn from(m: Message) -> Self {
         match m {
@@ -88,13 +158,7 @@ impl From<Message> for Option<types::InputItem> {
                     type_: "function_call_output".to_string(),
                     status: None,
                     call_id: tool_info.tool.id.clone(),
-                    output: types::FunctionCallOutput::Text(
-                        tool_info
-                            .output
-                            .clone()
-                            .unwrap_or_else(|e| Value::String(e.to_string()))
-                            .to_string(),
-                    ),
+                    output: function_call_output(tool_info),
                 },
             )),
             Message::Assistant(ref assistant_msg) => match assistant_msg.content {
@@ -111,6 +175,17 @@ impl From<Message> for Option<types::InputItem> {
                         }],
                     }))
                 }
+                LanguageModelResponseContentType::Refusal(ref refusal) => {
+                    Some(types::InputItem::Item(types::MessageItem::OutputMessage {
+                        id: None,
+                        type_: "message".to_string(),
+                        status: None,
+                        role: types::Role::Assistant,
+                        content: vec![types::OutputContent::Refusal {
+                            refusal: refusal.to_owned(),
+                        }],
+                    }))
+                }
                 LanguageModelResponseContentType::ToolCall(ref tool_info) => {
                     Some(types::InputItem::Item(types::MessageItem::FunctionCall {
                         id: None,
@@ -137,7 +212,11 @@ impl From<Message> for Option<types::InputItem> {
                 _ => None,
             },
             Message::User(u) => Some(types::InputItem::Item(types::MessageItem::InputMessage {
-                content: vec![types::ContentType::InputText { text: u.content }],
+                content: u
+                    .content_parts()
+                    .into_iter()
+                    .filter_map(input_content)
+                    .collect(),
                 role: types::Role::User,
                 type_: "message".to_string(),
             })),
@@ -157,6 +236,40 @@ impl From<Message> for Option<types::InputItem> {
     }
 }
 
+/// Derives the finish reason of a response, which the Responses API reports through
+/// its status, incomplete details, and output items.
+pub(crate) fn finish_reason(response: &types::OpenAIResponse) -> FinishReason {
+    if let Some(details) = &response.incomplete_details {
+        return match details.reason.as_str() {
+            "max_output_tokens" => FinishReason::Length,
+            "content_filter" => FinishReason::ContentFilter,
+            other => FinishReason::Other(other.to_string()),
+        };
+    }
+    if response.error.is_some() {
+        return FinishReason::Error;
+    }
+    let calls_tools = response
+        .output
+        .iter()
+        .flatten()
+        .any(|item| matches!(item, types::MessageItem::FunctionCall { .. }));
+    if calls_tools {
+        FinishReason::ToolCalls
+    } else {
+        FinishReason::Stop
+    }
+}
+
+impl From<types::OutputContent> for LanguageModelResponseContentType {
+    fn from(content: types::OutputContent) -> Self {
+        match content {
+            types::OutputContent::OutputText { text, .. } => Self::Text(text),
+            types::OutputContent::Refusal { refusal } => Self::Refusal(refusal),
+        }
+    }
+}
+
 impl From<types::ResponseUsage> for Usage {
     fn from(value: types::ResponseUsage) -> Self {
         Self {
@@ -171,13 +284,24 @@ impl From<types::ResponseUsage> for Usage {
 impl From<ReasoningEffort> for types::ReasoningEffort {
     fn from(value: ReasoningEffort) -> Self {
         match value {
-            ReasoningEffort::Low => client::ReasoningEffort::Minimal,
+            ReasoningEffort::Minimal => client::ReasoningEffort::Minimal,
+            ReasoningEffort::Low => client::ReasoningEffort::Low,
             ReasoningEffort::Medium => client::ReasoningEffort::Medium,
             ReasoningEffort::High => client::ReasoningEffort::High,
         }
     }
 }
 
+impl From<OpenAIReasoningSummary> for types::SummaryType {
+    fn from(value: OpenAIReasoningSummary) -> Self {
+        match value {
+            OpenAIReasoningSummary::Auto => Self::Auto,
+            OpenAIReasoningSummary::Concise => Self::Concise,
+            OpenAIReasoningSummary::Detailed => Self::Detailed,
+        }
+    }
+}
+
 impl From<EmbeddingModelOptions> for types::OpenAIEmbeddingOptions {
     fn from(value: EmbeddingModelOptions) -> Self {
         types::OpenAIEmbeddingOptions {
@@ -190,6 +314,102 @@ impl From<EmbeddingModelOptions> for types::OpenAIEmbeddingOptions {
     }
 }
 
+impl From<types::ImageResponse> for ImageModelResponse {
+    fn from(value: types::ImageResponse) -> Self {
+        let media_type = format!("image/{}", value.output_format.as_deref().unwrap_or("png"));
+        let images = value
+            .data
+            .into_iter()
+            .filter_map(|image| {
+                let data = match (image.b64_json, image.url) {
+                    (Some(b64_json), _) => ImageData::Base64(b64_json),
+                    (None, Some(url)) => ImageData::Url(url),
+                    (None, None) => return None,
+                };
+                Some(GeneratedImage {
+                    data,
+                    media_type: Some(media_type.clone()),
+                    seed: None,
+                    revised_prompt: image.revised_prompt,
+                })
+            })
+            .collect();
+
+        ImageModelResponse { images }
+    }
+}
+
+impl From<types::TranscriptionResponse> for TranscriptionModelResponse {
+    fn from(value: types::TranscriptionResponse) -> Self {
+        TranscriptionModelResponse {
+            text: value.text,
+            language: value.language,
+            duration: value.duration,
+            confidence: None,
+            segments: value
+                .segments
+                .into_iter()
+                .map(|segment| TranscriptionSegment {
+                    text: segment.text.trim().to_string(),
+                    start: segment.start,
+                    end: segment.end,
+                    speaker: None,
+                })
+                .collect(),
+            words: value
+                .words
+                .into_iter()
+                .map(|word| TranscriptionWord {
+                    word: word.word,
+                    start: word.start,
+                    end: word.end,
+                    confidence: None,
+                    speaker: None,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<types::ModerationResponse> for ModerationModelResponse {
+    fn from(value: types::ModerationResponse) -> Self {
+        value
+            .results
+            .into_iter()
+            .map(|result| ModerationResult {
+                flagged: result.flagged,
+                categories: result.categories,
+                category_scores: result.category_scores,
+            })
+            .collect()
+    }
+}
+
+fn function_call_output(tool_info: &ToolResultInfo) -> types::FunctionCallOutput {
+    let text = tool_info
+        .output
+        .clone()
+        .unwrap_or_else(|e| Value::String(e.to_string()))
+        .to_string();
+
+    if tool_info.images.is_empty() {
+        return types::FunctionCallOutput::Text(text);
+    }
+
+    let mut parts = vec![types::ContentType::InputText { text }];
+    parts.extend(
+        tool_info
+            .images
+            .iter()
+            .map(|image| types::ContentType::InputImage {
+                detail: types::ImageDetail::Auto,
+                file_id: None,
+                image_url: Some(image.data_url()),
+            }),
+    );
+    types::FunctionCallOutput::Parts(parts)
+}
+
 fn from_schema_to_response_format(schema: Schema) -> types::TextResponseFormat {
     let json = serde_json::to_value(schema).expect("Failed to serialize schema");
     types::TextResponseFormat::JsonSchema {
@@ -214,12 +434,18 @@ mod tests {
         LanguageModelOptions, ReasoningEffort as LMReasoningEffort, Usage,
     };
 
+    #[test]
+    fn test_reasoning_effort_conversion_minimal() {
+        let effort = LMReasoningEffort::Minimal;
+        let openai_effort: ReasoningEffort = effort.into();
+        assert_eq!(openai_effort, ReasoningEffort::Minimal);
+    }
+
     #[test]
     fn test_reasoning_effort_conversion_low() {
         let effort = LMReasoningEffort::Low;
         let openai_effort: ReasoningEffort = effort.into();
-        assert_eq!(openai_effort, ReasoningEffort::Minimal);
-        let _ = openai_effort;
+        assert_eq!(openai_effort, ReasoningEffort::Low);
     }
 
     #[test]
@@ -245,10 +471,30 @@ mod tests {
         let lm_options: OpenAILanguageModelOptions = options.into();
         assert!(lm_options.reasoning.is_some());
         let reasoning = lm_options.reasoning.unwrap();
-        assert_eq!(reasoning.effort, Some(ReasoningEffort::Minimal));
+        assert_eq!(reasoning.effort, Some(ReasoningEffort::Low));
         assert_eq!(reasoning.summary, Some(SummaryType::Auto));
     }
 
+    #[test]
+    fn test_reasoning_summary_is_only_set_with_reasoning() {
+        let options = LanguageModelOptions {
+            reasoning_effort: Some(LMReasoningEffort::High),
+            ..Default::default()
+        };
+        let mut lm_options: OpenAILanguageModelOptions = options.into();
+        lm_options.set_reasoning_summary(Some(
+            crate::providers::openai::OpenAIReasoningSummary::Detailed.into(),
+        ));
+        assert_eq!(
+            lm_options.reasoning.unwrap().summary,
+            Some(SummaryType::Detailed)
+        );
+
+        let mut lm_options: OpenAILanguageModelOptions = LanguageModelOptions::default().into();
+        lm_options.set_reasoning_summary(Some(SummaryType::Concise));
+        assert!(lm_options.reasoning.is_none());
+    }
+
     #[test]
     fn test_language_model_options_to_create_response_with_reasoning_effort_medium() {
         let options = LanguageModelOptions {
@@ -285,6 +531,31 @@ mod tests {
         assert!(lm_options.reasoning.is_none());
     }
 
+    #[test]
+    fn test_tool_result_with_images_converts_to_parts() {
+        let mut info = crate::core::tools::ToolResultInfo::new("screenshot");
+        info.output(serde_json::Value::String("[image attached]".to_string()));
+        info.images.push(crate::core::tools::ToolResultImage {
+            media_type: "image/png".to_string(),
+            data: "AAAA".to_string(),
+        });
+
+        match super::function_call_output(&info) {
+            FunctionCallOutput::Parts(parts) => {
+                assert_eq!(parts.len(), 2);
+                assert_eq!(
+                    parts[1],
+                    ContentType::InputImage {
+                        detail: ImageDetail::Auto,
+                        file_id: None,
+                        image_url: Some("data:image/png;base64,AAAA".to_string()),
+                    }
+                );
+            }
+            other => panic!("expected parts, got {other:?}"),
+        }
+    }
+
     #[test]
     fn test_openai_usage_to_usage_conversion() {
         let openai_usage = types::ResponseUsage {
@@ -302,4 +573,130 @@ mod tests {
         assert_eq!(usage.cached_tokens, Some(0));
         assert_eq!(usage.reasoning_tokens, Some(0));
     }
+
+    #[test]
+    fn test_image_response_conversion() {
+        use crate::core::image_model::{ImageData, ImageModelResponse};
+
+        let response: ImageResponse = serde_json::from_str(
+            r#"{
+                "created": 1713833628,
+                "data": [
+                    {"url": "https://example.com/a.png", "revised_prompt": "A red fox"},
+                    {"b64_json": "aGVsbG8="}
+                ],
+                "output_format": "webp"
+            }"#,
+        )
+        .unwrap();
+
+        let response: ImageModelResponse = response.into();
+        assert_eq!(response.images.len(), 2);
+        assert_eq!(
+            response.images[0].data,
+            ImageData::Url("https://example.com/a.png".to_string())
+        );
+        assert_eq!(
+            response.images[0].revised_prompt.as_deref(),
+            Some("A red fox")
+        );
+        assert_eq!(
+            response.images[1].data,
+            ImageData::Base64("aGVsbG8=".to_string())
+        );
+        assert_eq!(response.images[1].media_type.as_deref(), Some("image/webp"));
+    }
+
+    #[test]
+    fn test_transcription_response_conversion() {
+        use crate::core::transcription_model::TranscriptionModelResponse;
+
+        let response: TranscriptionResponse = serde_json::from_str(
+            r#"{
+                "task": "transcribe",
+                "language": "english",
+                "duration": 1.5,
+                "text": "Hello there.",
+                "segments": [{"id": 0, "start": 0.0, "end": 1.5, "text": " Hello there.", "avg_logprob": -0.2}],
+                "words": [{"word": "Hello", "start": 0.0, "end": 0.6}, {"word": "there", "start": 0.7, "end": 1.2}]
+            }"#,
+        )
+        .unwrap();
+
+        let response: TranscriptionModelResponse = response.into();
+        assert_eq!(response.text, "Hello there.");
+        assert_eq!(response.language.as_deref(), Some("english"));
+        assert_eq!(response.segments[0].text, "Hello there.");
+        assert_eq!(response.words.len(), 2);
+        assert_eq!(response.words[1].word, "there");
+    }
+
+    #[test]
+    fn test_moderation_response_conversion() {
+        use crate::core::moderation_model::ModerationModelResponse;
+
+        let response: ModerationResponse = serde_json::from_str(
+            r#"{
+                "id": "modr-1",
+                "model": "omni-moderation-latest",
+                "results": [{
+                    "flagged": true,
+                    "categories": {"harassment": false, "violence": true},
+                    "category_scores": {"harassment": 0.01, "violence": 0.92},
+                    "category_applied_input_types": {"violence": ["text"]}
+                }]
+            }"#,
+        )
+        .unwrap();
+
+        let response: ModerationModelResponse = response.into();
+        assert!(response[0].flagged);
+        assert_eq!(response[0].flagged_categories(), vec!["violence"]);
+        assert_eq!(response[0].category_scores["violence"], 0.92);
+    }
+
+    #[test]
+    fn test_multi_part_user_message_conversion() {
+        use crate::core::messages::{ContentPart, FilePart, ImagePart, Message, UserMessage};
+
+        let message = Message::User(UserMessage::with_parts([
+            ContentPart::text("Compare these"),
+            ImagePart::base64("aGk=", "image/png").into(),
+            FilePart::url("https://example.com/report.pdf", "application/pdf").into(),
+        ]));
+
+        let item: Option<InputItem> = message.into();
+        let value = serde_json::to_value(item.unwrap()).unwrap();
+        assert_eq!(
+            value["content"],
+            serde_json::json!([
+                {"type": "input_text", "text": "Compare these"},
+                {"type": "input_image", "detail": "auto", "image_url": "data:image/png;base64,aGk="},
+                {"type": "input_file", "file_url": "https://example.com/report.pdf"}
+            ])
+        );
+    }
+
+    #[test]
+    fn test_refusal_round_trip() {
+        use crate::core::language_model::LanguageModelResponseContentType;
+        use crate::core::messages::{AssistantMessage, Message};
+
+        let content: OutputContent = serde_json::from_value(
+            serde_json::json!({"type": "refusal", "refusal": "I can't help"}),
+        )
+        .unwrap();
+        let content: LanguageModelResponseContentType = content.into();
+        assert!(
+            matches!(&content, LanguageModelResponseContentType::Refusal(refusal) if refusal == "I can't help")
+        );
+
+        let item: Option<InputItem> =
+            Message::Assistant(AssistantMessage::new(content, None)).into();
+        let value = serde_json::to_value(item.unwrap()).unwrap();
+        assert_eq!(
+            value["content"],
+            serde_json::json!([{"type": "refusal", "refusal": "I can't help"}])
+        );
+    }
 }
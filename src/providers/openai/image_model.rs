@@ -0,0 +1,298 @@
+//! Image model implementation for the OpenAI provider.
+
+use crate::{
+    core::{
+        capabilities::ModelName,
+        client::ImageClient,
+        image_model::{ImageGenerationModel, ImageModelOptions, ImageModelResponse, ImageSize},
+        utils::MultipartForm,
+    },
+    error::{Error, Result},
+    providers::openai::{OpenAI, client::types},
+};
+use async_trait::async_trait;
+
+/// An image sent to the image edit and variation endpoints.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpenAIImageFile {
+    /// The encoded image.
+    pub data: Vec<u8>,
+    /// The media type of the image, e.g. `image/png`.
+    pub media_type: String,
+}
+
+impl OpenAIImageFile {
+    /// Creates an image file from its encoded bytes and media type.
+    pub fn new(data: impl Into<Vec<u8>>, media_type: impl Into<String>) -> Self {
+        Self {
+            data: data.into(),
+            media_type: media_type.into(),
+        }
+    }
+
+    /// Creates a PNG image file.
+    pub fn png(data: impl Into<Vec<u8>>) -> Self {
+        Self::new(data, "image/png")
+    }
+
+    fn filename(&self) -> String {
+        let extension = self.media_type.rsplit('/').next().unwrap_or("png");
+        format!("image.{extension}")
+    }
+
+    fn add_to(&self, form: MultipartForm, name: &str) -> MultipartForm {
+        form.file(name, &self.filename(), &self.media_type, &self.data)
+    }
+}
+
+impl<M: ModelName> OpenAI<M> {
+    /// Returns a copy of the provider prepared to send `input` to `/v1/images/generations`.
+    pub(crate) fn with_generation_request(&self, input: ImageModelOptions) -> Self {
+        let mut model = self.clone();
+
+        let request = types::ImageGenerationRequest {
+            model: model.lm_options.model.clone(),
+            prompt: input.prompt,
+            n: input.n,
+            size: input.size.map(|size| size.to_string()),
+            quality: input.quality,
+            response_format: model.response_format().map(str::to_string),
+        };
+
+        model.image_options.path = "/v1/images/generations".to_string();
+        model.image_options.content_type = "application/json".to_string();
+        model.image_options.body = serde_json::to_vec(&request).unwrap_or_default();
+        model
+    }
+
+    /// Returns a copy of the provider prepared to send an edit of `images` to
+    /// `/v1/images/edits` as a multipart form.
+    pub(crate) fn with_edit_request(
+        &self,
+        images: &[OpenAIImageFile],
+        mask: Option<&OpenAIImageFile>,
+        input: ImageModelOptions,
+    ) -> Self {
+        let mut model = self.clone();
+
+        let mut form = MultipartForm::new()
+            .text("model", &model.lm_options.model)
+            .text("prompt", &input.prompt);
+        // `gpt-image-1` accepts several images, sent as an array field
+        let field = if images.len() > 1 { "image[]" } else { "image" };
+        for image in images {
+            form = image.add_to(form, field);
+        }
+        if let Some(mask) = mask {
+            form = mask.add_to(form, "mask");
+        }
+        if let Some(quality) = &input.quality {
+            form = form.text("quality", quality);
+        }
+        form = model.common_fields(form, input.n, input.size);
+
+        model.image_options.path = "/v1/images/edits".to_string();
+        model.image_options.content_type = form.content_type();
+        model.image_options.body = form.finish();
+        model
+    }
+
+    /// Returns a copy of the provider prepared to send a variation request for
+    /// `image` to `/v1/images/variations` as a multipart form.
+    pub(crate) fn with_variation_request(
+        &self,
+        image: &OpenAIImageFile,
+        n: Option<u32>,
+        size: Option<ImageSize>,
+    ) -> Self {
+        let mut model = self.clone();
+
+        let form = MultipartForm::new().text("model", &model.lm_options.model);
+        let form = image.add_to(form, "image");
+        let form = model.common_fields(form, n, size);
+
+        model.image_options.path = "/v1/images/variations".to_string();
+        model.image_options.content_type = form.content_type();
+        model.image_options.body = form.finish();
+        model
+    }
+
+    /// Edits or extends images from a text prompt.
+    ///
+    /// `gpt-image-1` accepts up to 16 images, DALL·E 2 a single square PNG. Transparent
+    /// areas of `mask` mark where the first image is edited.
+    ///
+    /// # Parameters
+    ///
+    /// * `images` - The images to edit.
+    /// * `mask` - An optional mask the size of the first image.
+    /// * `input` - The prompt and the `n`, `size` and `quality` options.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if `images` is empty or the request fails.
+    pub async fn edit_image(
+        &self,
+        images: Vec<OpenAIImageFile>,
+        mask: Option<OpenAIImageFile>,
+        input: ImageModelOptions,
+    ) -> Result<ImageModelResponse> {
+        if images.is_empty() {
+            return Err(Error::MissingField("images".to_string()));
+        }
+        let model = self.with_edit_request(&images, mask.as_ref(), input);
+        model.send_image_request().await
+    }
+
+    /// Creates variations of an image. Only supported by DALL·E 2.
+    ///
+    /// # Parameters
+    ///
+    /// * `image` - A square PNG image.
+    /// * `n` - The number of variations to create.
+    /// * `size` - The size of the variations.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the request fails.
+    pub async fn create_variation(
+        &self,
+        image: OpenAIImageFile,
+        n: Option<u32>,
+        size: Option<ImageSize>,
+    ) -> Result<ImageModelResponse> {
+        let model = self.with_variation_request(&image, n, size);
+        model.send_image_request().await
+    }
+
+    fn response_format(&self) -> Option<&'static str> {
+        self.settings
+            .image_response_format
+            .map(|format| format.as_str())
+    }
+
+    fn common_fields(
+        &self,
+        mut form: MultipartForm,
+        n: Option<u32>,
+        size: Option<ImageSize>,
+    ) -> MultipartForm {
+        if let Some(n) = n {
+            form = form.text("n", n.to_string());
+        }
+        if let Some(size) = size {
+            form = form.text("size", size.to_string());
+        }
+        if let Some(response_format) = self.response_format() {
+            form = form.text("response_format", response_format);
+        }
+        form
+    }
+
+    async fn send_image_request(&self) -> Result<ImageModelResponse> {
+        let (body, _) = self.send(&self.settings.base_url).await?;
+        let response: types::ImageResponse =
+            serde_json::from_slice(&body).map_err(|e| Error::ApiError {
+                status_code: None,
+                details: format!("Failed to parse response: {e}"),
+            })?;
+        Ok(response.into())
+    }
+}
+
+#[async_trait]
+impl<M: ModelName> ImageGenerationModel for OpenAI<M> {
+    async fn generate_image(&self, input: ImageModelOptions) -> Result<ImageModelResponse> {
+        self.with_generation_request(input)
+            .send_image_request()
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::DynamicModel;
+    use crate::providers::openai::OpenAIImageResponseFormat;
+
+    fn provider(model: &str) -> OpenAI<DynamicModel> {
+        OpenAI::<DynamicModel>::builder()
+            .model_name(model)
+            .api_key("key")
+            .image_response_format(OpenAIImageResponseFormat::B64Json)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_generation_request_body() {
+        let input = ImageModelOptions::builder()
+            .prompt("A red fox")
+            .size(Some(ImageSize::new(1024, 1792)))
+            .quality(Some("hd".to_string()))
+            .n(Some(2))
+            .build()
+            .unwrap();
+
+        let model = provider("dall-e-3").with_generation_request(input);
+        let body: serde_json::Value = serde_json::from_slice(&model.image_options.body).unwrap();
+
+        assert_eq!(model.image_options.path, "/v1/images/generations");
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "model": "dall-e-3",
+                "prompt": "A red fox",
+                "n": 2,
+                "size": "1024x1792",
+                "quality": "hd",
+                "response_format": "b64_json"
+            })
+        );
+    }
+
+    #[test]
+    fn test_edit_request_form() {
+        let input = ImageModelOptions::builder()
+            .prompt("Add a hat")
+            .build()
+            .unwrap();
+        let images = [
+            OpenAIImageFile::png(b"first".to_vec()),
+            OpenAIImageFile::new(b"second".to_vec(), "image/webp"),
+        ];
+        let mask = OpenAIImageFile::png(b"mask".to_vec());
+
+        let model = provider("gpt-image-1").with_edit_request(&images, Some(&mask), input);
+        let body = String::from_utf8(model.image_options.body.clone()).unwrap();
+
+        assert_eq!(model.image_options.path, "/v1/images/edits");
+        assert!(
+            model
+                .image_options
+                .content_type
+                .starts_with("multipart/form-data; boundary=")
+        );
+        assert!(body.contains("name=\"image[]\"; filename=\"image.png\""));
+        assert!(body.contains("name=\"image[]\"; filename=\"image.webp\""));
+        assert!(body.contains("name=\"mask\"; filename=\"image.png\""));
+        assert!(body.contains("name=\"prompt\"\r\n\r\nAdd a hat\r\n"));
+    }
+
+    #[test]
+    fn test_variation_request_form() {
+        let model = provider("dall-e-2").with_variation_request(
+            &OpenAIImageFile::png(b"image".to_vec()),
+            Some(3),
+            Some(ImageSize::new(512, 512)),
+        );
+        let body = String::from_utf8(model.image_options.body.clone()).unwrap();
+
+        assert_eq!(model.image_options.path, "/v1/images/variations");
+        assert!(body.contains("name=\"image\"; filename=\"image.png\""));
+        assert!(body.contains("name=\"n\"\r\n\r\n3\r\n"));
+        assert!(body.contains("name=\"size\"\r\n\r\n512x512\r\n"));
+        assert!(body.contains("name=\"response_format\"\r\n\r\nb64_json\r\n"));
+        assert!(!body.contains("name=\"prompt\""));
+    }
+}
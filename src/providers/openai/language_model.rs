@@ -4,11 +4,13 @@ use crate::core::capabilities::ModelName;
 use crate::core::client::LanguageModelClient;
 use crate::core::language_model::{
     LanguageModelOptions, LanguageModelResponse, LanguageModelResponseContentType,
-    LanguageModelStreamChunk, LanguageModelStreamChunkType, ProviderStream, Usage,
+    LanguageModelStreamChunk, LanguageModelStreamChunkType, ProviderStream, SamplingOption, Usage,
+    stream_with_rate_limit,
 };
 use crate::core::messages::AssistantMessage;
+use crate::core::tokens::{self, Encoding};
 use crate::providers::openai::client::{OpenAILanguageModelOptions, types};
-use crate::providers::openai::{OpenAI, client};
+use crate::providers::openai::{OpenAI, client, conversions};
 use crate::{
     core::{language_model::LanguageModel, tools::ToolCallInfo},
     error::Result,
@@ -23,6 +25,33 @@ impl<M: ModelName> LanguageModel for OpenAI<M> {
         self.lm_options.model.clone()
     }
 
+    fn capabilities(&self) -> crate::core::capabilities::Capabilities {
+        M::CAPABILITIES
+    }
+
+    fn info(&self) -> crate::core::capabilities::ModelInfo {
+        Self::model_info(&self.name())
+    }
+
+    fn unsupported_options(&self) -> &'static [SamplingOption] {
+        &[
+            SamplingOption::Seed,
+            SamplingOption::TopK,
+            SamplingOption::StopSequences,
+            SamplingOption::PresencePenalty,
+            SamplingOption::FrequencyPenalty,
+        ]
+    }
+
+    /// Counts tokens locally with the tiktoken encoding of the model, falling back to an
+    /// estimate for models without a known encoding.
+    async fn count_tokens(&self, options: &LanguageModelOptions) -> Result<usize> {
+        match Encoding::for_model(&self.lm_options.model) {
+            Some(encoding) => Ok(encoding.tokenizer().await?.count_request(options)),
+            None => Ok(tokens::estimate(options)),
+        }
+    }
+
     /// Generates text using the OpenAI provider.
     async fn generate_text(
         &mut self,
@@ -31,21 +60,20 @@ impl<M: ModelName> LanguageModel for OpenAI<M> {
         let mut options: OpenAILanguageModelOptions = options.into();
 
         options.model = self.lm_options.model.clone();
+        options.set_reasoning_summary(self.settings.reasoning_summary.map(Into::into));
 
         self.lm_options = options;
 
-        let response: client::OpenAIResponse = self.send(&self.settings.base_url).await?;
+        let (response, rate_limit): (client::OpenAIResponse, _) =
+            self.send_with_rate_limit(&self.settings.base_url).await?;
 
+        let finish_reason = conversions::finish_reason(&response);
         let mut collected: Vec<LanguageModelResponseContentType> = Vec::new();
 
         for out in response.output.unwrap_or_default() {
             match out {
                 types::MessageItem::OutputMessage { content, .. } => {
-                    for c in content {
-                        if let types::OutputContent::OutputText { text, .. } = c {
-                            collected.push(LanguageModelResponseContentType::new(text))
-                        }
-                    }
+                    collected.extend(content.into_iter().map(Into::into))
                 }
                 types::MessageItem::FunctionCall {
                     arguments,
@@ -62,10 +90,15 @@ impl<M: ModelName> LanguageModel for OpenAI<M> {
             }
         }
 
-        Ok(LanguageModelResponse {
+        let response = LanguageModelResponse {
             contents: collected,
             usage: response.usage.map(|usage| usage.into()),
-        })
+            stop_sequence: None,
+            finish_reason: Some(finish_reason),
+            logprobs: Vec::new(),
+            extensions: Default::default(),
+        };
+        Ok(response.with_rate_limit(rate_limit))
     }
 
     /// Streams text using the OpenAI provider.
@@ -74,31 +107,13 @@ impl<M: ModelName> LanguageModel for OpenAI<M> {
 
         options.model = self.lm_options.model.to_string();
         options.stream = Some(true);
+        options.set_reasoning_summary(self.settings.reasoning_summary.map(Into::into));
 
         self.lm_options = options;
 
-        // Retry logic for rate limiting
-        let max_retries = 5;
-        let mut retry_count = 0;
-        let mut wait_time = std::time::Duration::from_secs(1);
-
-        let openai_stream = loop {
-            match self.send_and_stream(&self.settings.base_url).await {
-                Ok(stream) => break stream,
-                Err(crate::error::Error::ApiError {
-                    status_code: Some(status),
-                    ..
-                }) if status == reqwest::StatusCode::TOO_MANY_REQUESTS
-                    && retry_count < max_retries =>
-                {
-                    retry_count += 1;
-                    tokio::time::sleep(wait_time).await;
-                    wait_time *= 2; // Exponential backoff
-                    continue;
-                }
-                Err(e) => return Err(e),
-            }
-        };
+        let (openai_stream, rate_limit) = self
+            .send_and_stream_with_rate_limit(&self.settings.base_url)
+            .await?;
 
         let stream = openai_stream.map(|evt_res| match evt_res {
             Ok(client::OpenAiStreamEvent::ResponseOutputTextDelta { delta, .. }) => {
@@ -112,7 +127,10 @@ impl<M: ModelName> LanguageModel for OpenAI<M> {
                 )])
             }
             Ok(client::OpenAiStreamEvent::ResponseCompleted { response, .. }) => {
-                let mut result: Vec<LanguageModelStreamChunk> = Vec::new();
+                let mut result: Vec<LanguageModelStreamChunk> =
+                    vec![LanguageModelStreamChunk::FinishReason(
+                        conversions::finish_reason(&response),
+                    )];
 
                 let usage: Usage = response.usage.unwrap_or_default().into();
                 let output = response.output.unwrap_or_default();
@@ -121,11 +139,9 @@ impl<M: ModelName> LanguageModel for OpenAI<M> {
                     match &msg {
                         // ---- Final OutputMessage ----
                         types::MessageItem::OutputMessage { content, .. } => {
-                            if let Some(types::OutputContent::OutputText { text, .. }) =
-                                content.first()
-                            {
+                            if let Some(content) = content.first() {
                                 result.push(LanguageModelStreamChunk::Done(AssistantMessage {
-                                    content: LanguageModelResponseContentType::new(text.clone()),
+                                    content: content.clone().into(),
                                     usage: Some(usage.clone()),
                                 }));
                             }
@@ -167,16 +183,15 @@ impl<M: ModelName> LanguageModel for OpenAI<M> {
 
                 Ok(result)
             }
-            Ok(client::OpenAiStreamEvent::ResponseIncomplete { response, .. }) => {
-                Ok(vec![LanguageModelStreamChunk::Delta(
-                    LanguageModelStreamChunkType::Incomplete(
-                        response
-                            .incomplete_details
-                            .map(|d| d.reason)
-                            .unwrap_or("Unknown".to_string()),
-                    ),
-                )])
-            }
+            Ok(client::OpenAiStreamEvent::ResponseIncomplete { response, .. }) => Ok(vec![
+                LanguageModelStreamChunk::FinishReason(conversions::finish_reason(&response)),
+                LanguageModelStreamChunk::Delta(LanguageModelStreamChunkType::Incomplete(
+                    response
+                        .incomplete_details
+                        .map(|d| d.reason)
+                        .unwrap_or("Unknown".to_string()),
+                )),
+            ]),
             Ok(client::OpenAiStreamEvent::ResponseError { code, message, .. }) => {
                 let reason = format!("{}: {}", code.unwrap_or("unknown".to_string()), message);
                 Ok(vec![LanguageModelStreamChunk::Delta(
@@ -189,6 +204,6 @@ impl<M: ModelName> LanguageModel for OpenAI<M> {
             Err(e) => Err(e),
         });
 
-        Ok(Box::pin(stream))
+        Ok(stream_with_rate_limit(rate_limit, Box::pin(stream)))
     }
 }
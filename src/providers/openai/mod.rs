@@ -4,16 +4,29 @@ pub mod capabilities;
 pub mod client;
 pub mod conversions;
 pub mod embedding_model;
+pub mod image_model;
 pub mod language_model;
+pub mod moderation_model;
 pub mod settings;
+pub mod speech_model;
+pub mod transcription_model;
+pub mod video_model;
 
 use crate::core::DynamicModel;
 use crate::core::capabilities::ModelName;
+use crate::core::client::{ModelListClient, RetryPolicy};
+use crate::core::provider::ModelDescriptor;
 use crate::core::utils::validate_base_url;
 use crate::error::Error;
-use crate::providers::openai::client::{OpenAIEmbeddingOptions, OpenAILanguageModelOptions};
+use crate::providers::openai::client::{
+    ModerationRequest, OpenAIEmbeddingOptions, OpenAIImageOptions, OpenAILanguageModelOptions,
+    OpenAITranscriptionOptions, OpenAIVideoOptions, SpeechRequest,
+};
 use crate::providers::openai::settings::OpenAIProviderSettings;
 
+pub use image_model::OpenAIImageFile;
+pub use settings::{OpenAIImageResponseFormat, OpenAIReasoningSummary};
+
 /// The OpenAI provider.
 #[derive(Debug, Clone)]
 pub struct OpenAI<M: ModelName> {
@@ -23,6 +36,16 @@ pub struct OpenAI<M: ModelName> {
     pub(crate) lm_options: OpenAILanguageModelOptions,
     /// Options for Embedding Model
     pub(crate) embedding_options: OpenAIEmbeddingOptions,
+    /// Options for Image Model
+    pub(crate) image_options: OpenAIImageOptions,
+    /// Options for Speech Model
+    pub(crate) speech_options: SpeechRequest,
+    /// Options for Transcription Model
+    pub(crate) transcription_options: OpenAITranscriptionOptions,
+    /// Options for Moderation Model
+    pub(crate) moderation_options: ModerationRequest,
+    /// Options for Video Model
+    pub(crate) video_options: OpenAIVideoOptions,
     pub(crate) _phantom: std::marker::PhantomData<M>,
 }
 
@@ -31,6 +54,15 @@ impl<M: ModelName> OpenAI<M> {
     pub fn builder() -> OpenAIBuilder<M> {
         OpenAIBuilder::default()
     }
+
+    /// Lists the models available to the API key, e.g. to populate a model picker.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the API cannot be reached or returns an invalid response.
+    pub async fn list_models(&self) -> crate::error::Result<Vec<ModelDescriptor>> {
+        ModelListClient::list(self, self.settings.base_url.clone()).await
+    }
 }
 
 impl<M: ModelName> Default for OpenAI<M> {
@@ -54,6 +86,11 @@ impl<M: ModelName> Default for OpenAI<M> {
             settings,
             lm_options,
             embedding_options,
+            image_options: OpenAIImageOptions::default(),
+            speech_options: SpeechRequest::default(),
+            transcription_options: OpenAITranscriptionOptions::default(),
+            moderation_options: ModerationRequest::default(),
+            video_options: OpenAIVideoOptions::default(),
             _phantom: std::marker::PhantomData,
         }
     }
@@ -98,6 +135,11 @@ impl OpenAI<DynamicModel> {
             settings,
             lm_options,
             embedding_options,
+            image_options: OpenAIImageOptions::default(),
+            speech_options: SpeechRequest::default(),
+            transcription_options: OpenAITranscriptionOptions::default(),
+            moderation_options: ModerationRequest::default(),
+            video_options: OpenAIVideoOptions::default(),
             _phantom: std::marker::PhantomData,
         }
     }
@@ -180,6 +222,22 @@ impl<M: ModelName> OpenAIBuilder<M> {
         self
     }
 
+    /// Sets the retry policy applied to requests made by this provider.
+    ///
+    /// # Parameters
+    ///
+    /// * `retry_policy` - The [`RetryPolicy`] to use for retryable failures.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the retry policy set.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.settings.retry_policy = retry_policy;
+        self
+    }
+
+    crate::core::client::transport_builder_methods!();
+
     /// Sets the name of the provider. Defaults to "openai".
     ///
     /// # Parameters
@@ -194,6 +252,64 @@ impl<M: ModelName> OpenAIBuilder<M> {
         self
     }
 
+    /// Sets how the image endpoints return images. Only DALL·E models accept this
+    /// option.
+    ///
+    /// # Parameters
+    ///
+    /// * `format` - The [`OpenAIImageResponseFormat`] to request.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the image response format set.
+    pub fn image_response_format(mut self, format: OpenAIImageResponseFormat) -> Self {
+        self.settings.image_response_format = Some(format);
+        self
+    }
+
+    /// Sets instructions on how speech is spoken, e.g. its tone or accent. Only
+    /// `gpt-4o-mini-tts` accepts this option.
+    ///
+    /// # Parameters
+    ///
+    /// * `instructions` - The speaking instructions.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the speech instructions set.
+    pub fn speech_instructions(mut self, instructions: impl Into<String>) -> Self {
+        self.settings.speech_instructions = Some(instructions.into());
+        self
+    }
+
+    /// Sets the speed of generated speech, from `0.25` to `4.0`.
+    ///
+    /// # Parameters
+    ///
+    /// * `speed` - The speed multiplier.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the speech speed set.
+    pub fn speech_speed(mut self, speed: f32) -> Self {
+        self.settings.speech_speed = Some(speed);
+        self
+    }
+
+    /// Sets how detailed the reasoning summary of reasoning models is.
+    ///
+    /// # Parameters
+    ///
+    /// * `summary` - The [`OpenAIReasoningSummary`] to request.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the reasoning summary set.
+    pub fn reasoning_summary(mut self, summary: OpenAIReasoningSummary) -> Self {
+        self.settings.reasoning_summary = Some(summary);
+        self
+    }
+
     /// Sets a custom API path, overriding the default (e.g., "/v1/responses").
     pub fn path(mut self, path: impl Into<String>) -> Self {
         self.settings.path = Some(path.into());
@@ -234,6 +350,11 @@ impl<M: ModelName> OpenAIBuilder<M> {
             },
             lm_options,
             embedding_options,
+            image_options: OpenAIImageOptions::default(),
+            speech_options: SpeechRequest::default(),
+            transcription_options: OpenAITranscriptionOptions::default(),
+            moderation_options: ModerationRequest::default(),
+            video_options: OpenAIVideoOptions::default(),
             _phantom: std::marker::PhantomData,
         })
     }
@@ -0,0 +1,29 @@
+//! Moderation model implementation for the OpenAI provider.
+
+use crate::{
+    core::{
+        capabilities::ModelName,
+        client::ModerationClient,
+        moderation_model::{ModerationModel, ModerationModelOptions, ModerationModelResponse},
+    },
+    error::Result,
+    providers::openai::{OpenAI, client::types},
+};
+use async_trait::async_trait;
+
+#[async_trait]
+impl<M: ModelName> ModerationModel for OpenAI<M> {
+    async fn moderate(&self, input: ModerationModelOptions) -> Result<ModerationModelResponse> {
+        // Clone self to allow mutation
+        let mut model = self.clone();
+
+        model.moderation_options = types::ModerationRequest {
+            model: model.lm_options.model.clone(),
+            input: input.input,
+        };
+
+        let response = model.send(&model.settings.base_url).await?;
+
+        Ok(response.into())
+    }
+}
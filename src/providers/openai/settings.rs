@@ -1,8 +1,46 @@
 //! Defines the settings for the OpenAI provider.
 
+use crate::core::client::{RetryPolicy, TransportSettings};
 use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Builder)]
+/// How the image endpoints return generated images.
+///
+/// Only DALL·E models accept this option; `gpt-image-1` always returns base64
+/// encoded images.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OpenAIImageResponseFormat {
+    /// A URL the image can be downloaded from, valid for 60 minutes.
+    Url,
+    /// The image, base64 encoded.
+    B64Json,
+}
+
+impl OpenAIImageResponseFormat {
+    /// Returns the format's API value.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Url => "url",
+            Self::B64Json => "b64_json",
+        }
+    }
+}
+
+/// How detailed the summary of the model's reasoning is.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OpenAIReasoningSummary {
+    /// The most detailed summary the model supports.
+    #[default]
+    Auto,
+    /// A short summary.
+    Concise,
+    /// A thorough summary.
+    Detailed,
+}
+
+#[derive(Clone, Builder)]
 #[builder(setter(into), default)]
 /// Settings for the OpenAI provider.
 pub struct OpenAIProviderSettings {
@@ -20,6 +58,43 @@ pub struct OpenAIProviderSettings {
     /// This is useful for connecting to endpoints that use a different path,
     /// such as OpenAI Codex (`/responses`).
     pub path: Option<String>,
+
+    /// The retry policy applied to requests.
+    pub retry_policy: RetryPolicy,
+
+    /// The HTTP client, middleware and rate limiter requests go through.
+    pub transport: TransportSettings,
+
+    /// How the image endpoints return images. The endpoint's default is used when unset.
+    pub image_response_format: Option<OpenAIImageResponseFormat>,
+
+    /// Instructions on how speech is spoken, e.g. its tone or accent. Only
+    /// `gpt-4o-mini-tts` accepts this option.
+    pub speech_instructions: Option<String>,
+
+    /// The speed of generated speech, from `0.25` to `4.0`. Defaults to `1.0`.
+    pub speech_speed: Option<f32>,
+
+    /// How detailed the reasoning summary of reasoning models is. Only sent when a
+    /// reasoning effort is set, defaults to [`OpenAIReasoningSummary::Auto`].
+    pub reasoning_summary: Option<OpenAIReasoningSummary>,
+}
+
+impl std::fmt::Debug for OpenAIProviderSettings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OpenAIProviderSettings")
+            .field("provider_name", &self.provider_name)
+            .field("base_url", &self.base_url)
+            .field("api_key", &"<redacted>")
+            .field("path", &self.path)
+            .field("retry_policy", &self.retry_policy)
+            .field("transport", &self.transport)
+            .field("image_response_format", &self.image_response_format)
+            .field("speech_instructions", &self.speech_instructions)
+            .field("speech_speed", &self.speech_speed)
+            .field("reasoning_summary", &self.reasoning_summary)
+            .finish()
+    }
 }
 
 impl Default for OpenAIProviderSettings {
@@ -30,6 +105,12 @@ impl Default for OpenAIProviderSettings {
             base_url: "https://api.openai.com".to_string(),
             api_key: std::env::var("OPENAI_API_KEY").unwrap_or_default(),
             path: None,
+            retry_policy: RetryPolicy::default(),
+            transport: TransportSettings::default(),
+            image_response_format: None,
+            speech_instructions: None,
+            speech_speed: None,
+            reasoning_summary: None,
         }
     }
 }
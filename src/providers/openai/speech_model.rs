@@ -0,0 +1,84 @@
+//! Speech model implementation for the OpenAI provider.
+
+use crate::{
+    core::{
+        capabilities::ModelName,
+        client::SpeechClient,
+        speech_model::{SpeechModel, SpeechModelOptions, SpeechModelResponse, SpeechStream},
+    },
+    error::Result,
+    providers::openai::{OpenAI, client::types},
+};
+use async_trait::async_trait;
+
+/// The voice used when the request does not name one.
+const DEFAULT_VOICE: &str = "alloy";
+
+impl<M: ModelName> OpenAI<M> {
+    /// Returns a copy of the provider prepared to send `input` to `/v1/audio/speech`.
+    pub(crate) fn with_speech_request(&self, input: SpeechModelOptions) -> Self {
+        let mut model = self.clone();
+
+        model.speech_options = types::SpeechRequest {
+            model: model.lm_options.model.clone(),
+            input: input.text,
+            voice: input.voice.unwrap_or_else(|| DEFAULT_VOICE.to_string()),
+            response_format: input.format,
+            instructions: model.settings.speech_instructions.clone(),
+            speed: model.settings.speech_speed,
+        };
+        model
+    }
+}
+
+#[async_trait]
+impl<M: ModelName> SpeechModel for OpenAI<M> {
+    async fn generate_speech(&self, input: SpeechModelOptions) -> Result<SpeechModelResponse> {
+        let model = self.with_speech_request(input);
+
+        let (audio, media_type) = model.send(&model.settings.base_url).await?;
+
+        Ok(SpeechModelResponse { audio, media_type })
+    }
+
+    async fn stream_speech(&self, input: SpeechModelOptions) -> Result<SpeechStream> {
+        let model = self.with_speech_request(input);
+
+        model.send_and_stream(&model.settings.base_url).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::DynamicModel;
+
+    #[test]
+    fn test_speech_request_body() {
+        let openai = OpenAI::<DynamicModel>::builder()
+            .model_name("gpt-4o-mini-tts")
+            .api_key("key")
+            .speech_instructions("Speak cheerfully")
+            .build()
+            .unwrap();
+        let input = SpeechModelOptions::builder()
+            .text("Hello")
+            .format(Some("opus".to_string()))
+            .build()
+            .unwrap();
+
+        let model = openai.with_speech_request(input);
+        let body: serde_json::Value = serde_json::to_value(&model.speech_options).unwrap();
+
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "model": "gpt-4o-mini-tts",
+                "input": "Hello",
+                "voice": "alloy",
+                "response_format": "opus",
+                "instructions": "Speak cheerfully"
+            })
+        );
+    }
+}
@@ -0,0 +1,183 @@
+//! Transcription model implementation for the OpenAI provider.
+
+use crate::{
+    core::{
+        capabilities::ModelName,
+        client::TranscriptionClient,
+        transcription_model::{
+            TranscriptionAudio, TranscriptionModel, TranscriptionModelOptions,
+            TranscriptionModelResponse,
+        },
+        utils::MultipartForm,
+    },
+    error::{Error, Result},
+    providers::openai::OpenAI,
+};
+use async_trait::async_trait;
+
+impl<M: ModelName> OpenAI<M> {
+    /// Returns a copy of the provider prepared to send `input` to
+    /// `/v1/audio/transcriptions`, or to `/v1/audio/translations` if `translate` is
+    /// set, as a multipart form.
+    ///
+    /// `whisper-1` is asked for `verbose_json`, which carries the language, duration,
+    /// segments, and words. Other models only return the text.
+    pub(crate) fn with_transcription_request(
+        &self,
+        input: TranscriptionModelOptions,
+        translate: bool,
+    ) -> Result<Self> {
+        let mut model = self.clone();
+
+        let TranscriptionAudio::Bytes { data, media_type } = &input.audio else {
+            return Err(Error::InvalidInput(
+                "OpenAI transcription requires audio bytes, not a URL".to_string(),
+            ));
+        };
+        let media_type = media_type
+            .clone()
+            .unwrap_or_else(|| sniff_media_type(data).to_string());
+        let extension = media_type
+            .rsplit('/')
+            .next()
+            .map(|subtype| subtype.trim_start_matches("x-"))
+            .unwrap_or("mp3");
+
+        let mut form = MultipartForm::new()
+            .text("model", &model.lm_options.model)
+            .file("file", &format!("audio.{extension}"), &media_type, data);
+        if !translate && let Some(language) = &input.language {
+            form = form.text("language", language);
+        }
+        if model.lm_options.model.starts_with("whisper") {
+            form = form.text("response_format", "verbose_json");
+            if !translate {
+                form = form
+                    .text("timestamp_granularities[]", "segment")
+                    .text("timestamp_granularities[]", "word");
+            }
+        } else {
+            form = form.text("response_format", "json");
+        }
+
+        model.transcription_options.path = if translate {
+            "/v1/audio/translations".to_string()
+        } else {
+            "/v1/audio/transcriptions".to_string()
+        };
+        model.transcription_options.content_type = form.content_type();
+        model.transcription_options.body = form.finish();
+        Ok(model)
+    }
+
+    /// Translates the audio into English text. Only supported by `whisper-1`.
+    ///
+    /// # Parameters
+    ///
+    /// * `input` - The audio to translate. The language option is ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the audio is a URL or the request fails.
+    pub async fn translate(
+        &self,
+        input: TranscriptionModelOptions,
+    ) -> Result<TranscriptionModelResponse> {
+        let model = self.with_transcription_request(input, true)?;
+
+        Ok(model.send(&model.settings.base_url).await?.into())
+    }
+}
+
+/// Guesses the media type of encoded audio from its leading bytes, so the upload
+/// carries a file extension the API accepts. Falls back to mp3.
+fn sniff_media_type(data: &[u8]) -> &'static str {
+    let tag = |range: std::ops::Range<usize>| data.get(range).unwrap_or_default();
+    match (tag(0..4), tag(4..8), tag(8..12)) {
+        (b"RIFF", _, b"WAVE") => "audio/wav",
+        (b"fLaC", _, _) => "audio/flac",
+        (b"OggS", _, _) => "audio/ogg",
+        ([0x1A, 0x45, 0xDF, 0xA3], _, _) => "audio/webm",
+        (_, b"ftyp", _) => "audio/m4a",
+        _ => "audio/mp3",
+    }
+}
+
+#[async_trait]
+impl<M: ModelName> TranscriptionModel for OpenAI<M> {
+    async fn transcribe(
+        &self,
+        input: TranscriptionModelOptions,
+    ) -> Result<TranscriptionModelResponse> {
+        let model = self.with_transcription_request(input, false)?;
+
+        Ok(model.send(&model.settings.base_url).await?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::DynamicModel;
+
+    fn provider(model: &str) -> OpenAI<DynamicModel> {
+        OpenAI::<DynamicModel>::builder()
+            .model_name(model)
+            .api_key("key")
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_transcription_request_form() {
+        let input = TranscriptionModelOptions::builder()
+            .audio(TranscriptionAudio::bytes(b"RIFF\0\0\0\0WAVEfmt ".to_vec()))
+            .language(Some("en".to_string()))
+            .build()
+            .unwrap();
+
+        let model = provider("whisper-1")
+            .with_transcription_request(input, false)
+            .unwrap();
+        let body = String::from_utf8_lossy(&model.transcription_options.body).into_owned();
+
+        assert_eq!(model.transcription_options.path, "/v1/audio/transcriptions");
+        assert!(body.contains("name=\"file\"; filename=\"audio.wav\""));
+        assert!(body.contains("name=\"language\"\r\n\r\nen\r\n"));
+        assert!(body.contains("name=\"response_format\"\r\n\r\nverbose_json\r\n"));
+        assert!(body.contains("name=\"timestamp_granularities[]\"\r\n\r\nword\r\n"));
+    }
+
+    #[test]
+    fn test_translation_request_form() {
+        let input = TranscriptionModelOptions::builder()
+            .audio(TranscriptionAudio::Bytes {
+                data: b"audio".to_vec(),
+                media_type: Some("audio/mpeg".to_string()),
+            })
+            .language(Some("de".to_string()))
+            .build()
+            .unwrap();
+
+        let model = provider("whisper-1")
+            .with_transcription_request(input, true)
+            .unwrap();
+        let body = String::from_utf8_lossy(&model.transcription_options.body).into_owned();
+
+        assert_eq!(model.transcription_options.path, "/v1/audio/translations");
+        assert!(body.contains("name=\"file\"; filename=\"audio.mpeg\""));
+        assert!(!body.contains("name=\"language\""));
+        assert!(!body.contains("timestamp_granularities"));
+    }
+
+    #[test]
+    fn test_transcription_rejects_urls() {
+        let input = TranscriptionModelOptions::builder()
+            .audio(TranscriptionAudio::url("https://example.com/audio.mp3"))
+            .build()
+            .unwrap();
+
+        let result = provider("gpt-4o-transcribe").with_transcription_request(input, false);
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+}
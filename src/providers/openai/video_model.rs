@@ -0,0 +1,149 @@
+//! Video model implementation for the OpenAI provider.
+
+use crate::{
+    core::{
+        capabilities::ModelName,
+        client::VideoClient,
+        utils::{MultipartForm, join_url},
+        video_model::{GeneratedVideo, VideoJob, VideoJobStatus, VideoModel, VideoModelOptions},
+    },
+    error::{Error, Result},
+    providers::openai::{OpenAI, client::types},
+};
+use async_trait::async_trait;
+
+impl<M: ModelName> OpenAI<M> {
+    /// Returns a copy of the provider prepared to send `input` to `/v1/videos` as a
+    /// multipart form.
+    pub(crate) fn with_video_request(&self, input: VideoModelOptions) -> Self {
+        let mut model = self.clone();
+
+        let mut form = MultipartForm::new()
+            .text("model", &model.lm_options.model)
+            .text("prompt", &input.prompt);
+        if let Some(duration) = input.duration {
+            form = form.text("seconds", duration.to_string());
+        }
+        if let Some(size) = input.size {
+            form = form.text("size", size.to_string());
+        }
+
+        model.video_options.content_type = form.content_type();
+        model.video_options.body = form.finish();
+        model
+    }
+
+    /// Converts a video object into a job. Completed videos are downloaded from the
+    /// `content` endpoint of the video.
+    pub(crate) fn video_job(&self, video: types::VideoObject) -> Result<VideoJob> {
+        let status = match video.status.as_str() {
+            "queued" => VideoJobStatus::Queued,
+            "in_progress" => VideoJobStatus::InProgress,
+            "completed" => VideoJobStatus::Completed,
+            _ => VideoJobStatus::Failed,
+        };
+        let videos = match status {
+            VideoJobStatus::Completed => vec![GeneratedVideo {
+                url: self.video_url(&video.id, "/content")?.to_string(),
+                media_type: Some("video/mp4".to_string()),
+            }],
+            _ => Vec::new(),
+        };
+
+        Ok(VideoJob {
+            id: video.id,
+            status,
+            progress: video.progress,
+            error: video.error.map(|error| error.message),
+            videos,
+        })
+    }
+
+    fn video_url(&self, id: &str, suffix: &str) -> Result<reqwest::Url> {
+        join_url(
+            self.settings.base_url.as_str(),
+            &format!("/v1/videos/{id}{suffix}"),
+        )
+    }
+}
+
+#[async_trait]
+impl<M: ModelName> VideoModel for OpenAI<M> {
+    async fn submit_video(&self, input: VideoModelOptions) -> Result<VideoJob> {
+        let model = self.with_video_request(input);
+
+        let video = model.submit(&model.settings.base_url).await?;
+
+        model.video_job(video)
+    }
+
+    async fn poll_video(&self, job: &VideoJob) -> Result<VideoJob> {
+        let video = self.fetch_job(self.video_url(&job.id, "")?).await?;
+
+        self.video_job(video)
+    }
+
+    async fn download_video(&self, video: &GeneratedVideo) -> Result<Vec<u8>> {
+        let url = reqwest::Url::parse(&video.url)
+            .map_err(|_| Error::InvalidInput(format!("Invalid video URL: {}", video.url)))?;
+
+        self.download(url).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::DynamicModel;
+    use crate::core::image_model::ImageSize;
+
+    fn provider() -> OpenAI<DynamicModel> {
+        OpenAI::<DynamicModel>::builder()
+            .model_name("sora-2")
+            .api_key("key")
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_video_request_form() {
+        let input = VideoModelOptions::builder()
+            .prompt("A cat surfing")
+            .size(Some(ImageSize::new(1280, 720)))
+            .duration(Some(8))
+            .build()
+            .unwrap();
+
+        let model = provider().with_video_request(input);
+        let body = String::from_utf8(model.video_options.body.clone()).unwrap();
+
+        assert!(body.contains("name=\"model\"\r\n\r\nsora-2\r\n"));
+        assert!(body.contains("name=\"seconds\"\r\n\r\n8\r\n"));
+        assert!(body.contains("name=\"size\"\r\n\r\n1280x720\r\n"));
+    }
+
+    #[test]
+    fn test_video_job_conversion() {
+        let video: types::VideoObject = serde_json::from_str(
+            r#"{"id": "video_123", "object": "video", "status": "completed", "progress": 100}"#,
+        )
+        .unwrap();
+
+        let job = provider().video_job(video).unwrap();
+        assert_eq!(job.status, VideoJobStatus::Completed);
+        assert_eq!(
+            job.videos[0].url,
+            "https://api.openai.com/v1/videos/video_123/content"
+        );
+
+        let video: types::VideoObject = serde_json::from_str(
+            r#"{"id": "video_456", "status": "failed", "error": {"code": "moderation", "message": "Blocked"}}"#,
+        )
+        .unwrap();
+
+        let job = provider().video_job(video).unwrap();
+        assert_eq!(job.status, VideoJobStatus::Failed);
+        assert_eq!(job.error.as_deref(), Some("Blocked"));
+        assert!(job.videos.is_empty());
+    }
+}
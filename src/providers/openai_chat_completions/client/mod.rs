@@ -5,7 +5,7 @@ pub(crate) mod types;
 pub(crate) use types::ChatCompletionsOptions;
 
 use crate::core::capabilities::ModelName;
-use crate::core::client::LanguageModelClient;
+use crate::core::client::{LanguageModelClient, RetryPolicy, sse_error};
 use crate::error::Error;
 use crate::providers::openai_chat_completions::OpenAIChatCompletions;
 use reqwest::header::CONTENT_TYPE;
@@ -41,6 +41,14 @@ impl<M: ModelName> LanguageModelClient for OpenAIChatCompletions<M> {
         Vec::new()
     }
 
+    fn retry_policy(&self) -> RetryPolicy {
+        self.settings.retry_policy.clone()
+    }
+
+    fn transport(&self) -> crate::core::client::TransportSettings {
+        self.settings.transport.clone()
+    }
+
     fn body(&self) -> reqwest::Body {
         let body = serde_json::to_string(&self.options).unwrap();
         reqwest::Body::from(body)
@@ -66,16 +74,7 @@ impl<M: ModelName> LanguageModelClient for OpenAIChatCompletions<M> {
                     Ok(ChatCompletionsStreamEvent::Chunk(chunk))
                 }
             },
-            Err(e) => {
-                let status_code = match &e {
-                    reqwest_eventsource::Error::InvalidStatusCode(status, _) => Some(*status),
-                    _ => None,
-                };
-                Err(Error::ApiError {
-                    status_code,
-                    details: e.to_string(),
-                })
-            }
+            Err(e) => Err(sse_error(e)),
         }
     }
 
@@ -68,6 +68,10 @@ pub(crate) struct ChatCompletionsOptions {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub verbosity: Option<String>,
+
+    /// Provider-specific fields merged into the request body.
+    #[serde(flatten, skip_serializing_if = "serde_json::Map::is_empty")]
+    pub extra_body: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -75,7 +79,7 @@ pub(crate) struct ChatMessage {
     pub role: Role,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub content: Option<String>,
+    pub content: Option<ChatContent>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
@@ -85,6 +89,61 @@ pub(crate) struct ChatMessage {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tool_call_id: Option<String>,
+
+    /// The refusal message, when the model refused to answer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refusal: Option<String>,
+}
+
+/// The content of a message, either plain text or a list of parts for multi-part user
+/// messages.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub(crate) enum ChatContent {
+    Text(String),
+    Parts(Vec<ChatContentPart>),
+}
+
+impl From<String> for ChatContent {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<&str> for ChatContent {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+/// See <https://platform.openai.com/docs/api-reference/chat/create#chat-create-messages>
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub(crate) enum ChatContentPart {
+    Text { text: String },
+    ImageUrl { image_url: ImageUrl },
+    InputAudio { input_audio: InputAudio },
+    File { file: FileContent },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct ImageUrl {
+    pub url: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct InputAudio {
+    /// The base64 encoded audio.
+    pub data: String,
+    /// `wav` or `mp3`.
+    pub format: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct FileContent {
+    /// The file as a base64 `data:` URL.
+    pub file_data: String,
+    pub filename: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -240,7 +299,8 @@ pub(crate) struct CompletionTokensDetails {
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub(crate) struct LogProbs {
-    pub content: Vec<ContentLogProb>,
+    #[serde(default)]
+    pub content: Option<Vec<ContentLogProb>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub refusal: Option<Vec<ContentLogProb>>,
 }
@@ -250,6 +310,7 @@ pub(crate) struct ContentLogProb {
     pub token: String,
     pub logprob: f64,
     pub bytes: Option<Vec<u8>>,
+    #[serde(default)]
     pub top_logprobs: Vec<TopLogProb>,
 }
 
@@ -1,10 +1,13 @@
 //! Helper functions and conversions for the OpenAI Chat Completions provider.
 
 use crate::core::language_model::{
-    LanguageModelOptions, LanguageModelResponseContentType, ReasoningEffort, Usage,
+    FinishReason, LanguageModelOptions, LanguageModelResponseContentType, ReasoningEffort,
+    TokenLogprob, Usage,
 };
-use crate::core::messages::Message;
-use crate::core::tools::Tool as SdkTool;
+use crate::core::messages::{
+    AssistantMessage, ContentPart, DocumentPart, FilePart, MediaSource, Message, UserMessage,
+};
+use crate::core::tools::{Tool as SdkTool, ToolChoice};
 use crate::providers::openai_chat_completions::client::{self, types};
 
 // ============================================================================
@@ -18,17 +21,31 @@ impl From<LanguageModelOptions> for client::ChatCompletionsOptions {
         if let Some(system_prompt) = options.system {
             messages.push(types::ChatMessage {
                 role: types::Role::System,
-                content: Some(system_prompt),
+                content: Some(system_prompt.into()),
                 name: None,
                 tool_calls: None,
                 tool_call_id: None,
+                refusal: None,
             });
         }
 
+        // Sources are response metadata, not part of the conversation, and generated
+        // images and refusals cannot be sent back as assistant content
         messages.extend(
             options
                 .messages
                 .into_iter()
+                .filter(|tagged| {
+                    !matches!(
+                        tagged.message,
+                        Message::Assistant(AssistantMessage {
+                            content: LanguageModelResponseContentType::Source(_)
+                                | LanguageModelResponseContentType::Image(_)
+                                | LanguageModelResponseContentType::Refusal(_),
+                            ..
+                        })
+                    )
+                })
                 .map(|tagged| tagged.message.into()),
         );
 
@@ -72,6 +89,7 @@ impl From<LanguageModelOptions> for client::ChatCompletionsOptions {
 
         let reasoning_effort = options.reasoning_effort.map(|effort| {
             match effort {
+                ReasoningEffort::Minimal => "minimal",
                 ReasoningEffort::Low => "low",
                 ReasoningEffort::Medium => "medium",
                 ReasoningEffort::High => "high",
@@ -79,21 +97,21 @@ impl From<LanguageModelOptions> for client::ChatCompletionsOptions {
             .to_string()
         });
 
-        let tool_choice = if tools.is_some() {
-            Some(types::ToolChoice::String("auto".to_string()))
-        } else {
-            None
-        };
+        let tool_choice = tools
+            .as_ref()
+            .map(|_| options.tool_choice.unwrap_or_default().into());
 
-        let parallel_tool_calls = if tools.is_some() { Some(true) } else { None };
+        let parallel_tool_calls = tools
+            .as_ref()
+            .map(|_| options.parallel_tool_calls.unwrap_or(true));
 
         client::ChatCompletionsOptions {
             model: "".to_string(),
             messages,
             frequency_penalty: options.frequency_penalty,
             logit_bias: None,
-            logprobs: None,
-            top_logprobs: None,
+            logprobs: options.logprobs.or(options.top_logprobs.map(|_| true)),
+            top_logprobs: options.top_logprobs,
             max_completion_tokens: options.max_output_tokens,
             n: None,
             presence_penalty: options.presence_penalty,
@@ -115,6 +133,7 @@ impl From<LanguageModelOptions> for client::ChatCompletionsOptions {
             parallel_tool_calls,
             reasoning_effort,
             verbosity: None,
+            extra_body: options.provider_options,
         }
     }
 }
@@ -128,29 +147,32 @@ impl From<Message> for types::ChatMessage {
         match msg {
             Message::System(s) => types::ChatMessage {
                 role: types::Role::System,
-                content: Some(s.content),
+                content: Some(s.content.into()),
                 name: None,
                 tool_calls: None,
                 tool_call_id: None,
+                refusal: None,
             },
             Message::User(u) => types::ChatMessage {
                 role: types::Role::User,
-                content: Some(u.content),
+                content: Some(user_content(u)),
                 name: None,
                 tool_calls: None,
                 tool_call_id: None,
+                refusal: None,
             },
             Message::Assistant(a) => match a.content {
                 LanguageModelResponseContentType::Text(text) => types::ChatMessage {
                     role: types::Role::Assistant,
-                    content: Some(text),
+                    content: Some(text.into()),
                     name: None,
                     tool_calls: None,
                     tool_call_id: None,
+                    refusal: None,
                 },
                 LanguageModelResponseContentType::ToolCall(tool_info) => types::ChatMessage {
                     role: types::Role::Assistant,
-                    content: Some("".to_string()),
+                    content: Some("".into()),
                     name: None,
                     tool_calls: Some(vec![types::ToolCall {
                         id: tool_info.tool.id.clone(),
@@ -161,16 +183,18 @@ impl From<Message> for types::ChatMessage {
                         },
                     }]),
                     tool_call_id: None,
+                    refusal: None,
                 },
                 LanguageModelResponseContentType::Reasoning { content, .. } => {
                     // Chat Completions doesn't have separate reasoning messages
                     // Include as text with prefix
                     types::ChatMessage {
                         role: types::Role::Assistant,
-                        content: Some(format!("[Reasoning]: {content}")),
+                        content: Some(format!("[Reasoning]: {content}").into()),
                         name: None,
                         tool_calls: None,
                         tool_call_id: None,
+                        refusal: None,
                     }
                 }
                 _ => types::ChatMessage {
@@ -179,6 +203,7 @@ impl From<Message> for types::ChatMessage {
                     name: None,
                     tool_calls: None,
                     tool_call_id: None,
+                    refusal: None,
                 },
             },
             Message::Tool(tool_result) => types::ChatMessage {
@@ -187,23 +212,72 @@ impl From<Message> for types::ChatMessage {
                     tool_result
                         .output
                         .unwrap_or_else(|e| serde_json::Value::String(e.to_string()))
-                        .to_string(),
+                        .to_string()
+                        .into(),
                 ),
                 name: Some(tool_result.tool.name),
                 tool_calls: None,
                 tool_call_id: Some(tool_result.tool.id),
+                refusal: None,
             },
             Message::Developer(d) => types::ChatMessage {
                 role: types::Role::Developer,
-                content: Some(d),
+                content: Some(d.into()),
                 name: None,
                 tool_calls: None,
                 tool_call_id: None,
+                refusal: None,
             },
         }
     }
 }
 
+/// Converts the content of a user message. Plain text messages stay a string, and
+/// parts that cannot be sent inline, such as audio URLs, are left out.
+fn user_content(message: UserMessage) -> types::ChatContent {
+    if message.parts.is_empty() {
+        return message.content.into();
+    }
+    let parts = message
+        .parts
+        .into_iter()
+        .filter_map(|part| match part {
+            ContentPart::Text(text) => Some(types::ChatContentPart::Text { text }),
+            ContentPart::Image(image) => Some(types::ChatContentPart::ImageUrl {
+                image_url: types::ImageUrl {
+                    url: image.source.to_url(image.media_type()),
+                },
+            }),
+            ContentPart::Audio(audio) => {
+                let format = audio.format().to_string();
+                match audio.source {
+                    MediaSource::Base64(data) => Some(types::ChatContentPart::InputAudio {
+                        input_audio: types::InputAudio { data, format },
+                    }),
+                    MediaSource::Url(_) => None,
+                }
+            }
+            ContentPart::File(FilePart {
+                source: MediaSource::Base64(data),
+                media_type,
+                filename,
+            })
+            | ContentPart::Document(DocumentPart {
+                source: MediaSource::Base64(data),
+                media_type,
+                title: filename,
+            }) => Some(types::ChatContentPart::File {
+                file: types::FileContent {
+                    file_data: format!("data:{media_type};base64,{data}"),
+                    filename: filename.unwrap_or_else(|| "file".to_string()),
+                },
+            }),
+            _ => None,
+        })
+        .collect();
+    types::ChatContent::Parts(parts)
+}
+
 // ============================================================================
 // SDK Tool -> ChatCompletions Tool
 // ============================================================================
@@ -242,6 +316,20 @@ impl From<SdkTool> for types::Tool {
     }
 }
 
+impl From<ToolChoice> for types::ToolChoice {
+    fn from(choice: ToolChoice) -> Self {
+        match choice {
+            ToolChoice::Auto => Self::String("auto".to_string()),
+            ToolChoice::Required => Self::String("required".to_string()),
+            ToolChoice::None => Self::String("none".to_string()),
+            ToolChoice::Tool(name) => Self::Specific(types::ToolChoiceSpecific {
+                type_: "function".to_string(),
+                function: types::FunctionChoice { name },
+            }),
+        }
+    }
+}
+
 // ============================================================================
 // ChatCompletions Usage -> SDK Usage
 // ============================================================================
@@ -261,6 +349,51 @@ impl From<types::Usage> for Usage {
     }
 }
 
+// ============================================================================
+// ChatCompletions LogProbs -> SDK TokenLogprob
+// ============================================================================
+
+/// Maps a Chat Completions `finish_reason`.
+pub(crate) fn finish_reason(reason: &str) -> FinishReason {
+    match reason {
+        "stop" => FinishReason::Stop,
+        "length" => FinishReason::Length,
+        "tool_calls" | "function_call" => FinishReason::ToolCalls,
+        "content_filter" => FinishReason::ContentFilter,
+        other => FinishReason::Other(other.to_string()),
+    }
+}
+
+/// Returns the log probabilities of the content tokens, if any were returned.
+pub(crate) fn logprobs(logprobs: Option<types::LogProbs>) -> Vec<TokenLogprob> {
+    logprobs
+        .and_then(|logprobs| logprobs.content)
+        .unwrap_or_default()
+        .into_iter()
+        .map(Into::into)
+        .collect()
+}
+
+impl From<types::ContentLogProb> for TokenLogprob {
+    fn from(logprob: types::ContentLogProb) -> Self {
+        Self {
+            token: logprob.token,
+            logprob: logprob.logprob,
+            top_logprobs: logprob.top_logprobs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<types::TopLogProb> for TokenLogprob {
+    fn from(logprob: types::TopLogProb) -> Self {
+        Self {
+            token: logprob.token,
+            logprob: logprob.logprob,
+            top_logprobs: Vec::new(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -271,7 +404,7 @@ mod tests {
         let chat_msg: types::ChatMessage = msg.into();
 
         assert_eq!(chat_msg.role, types::Role::System);
-        assert_eq!(chat_msg.content, Some("You are helpful".to_string()));
+        assert_eq!(chat_msg.content, Some("You are helpful".into()));
         assert!(chat_msg.tool_calls.is_none());
     }
 
@@ -281,7 +414,64 @@ mod tests {
         let chat_msg: types::ChatMessage = msg.into();
 
         assert_eq!(chat_msg.role, types::Role::User);
-        assert_eq!(chat_msg.content, Some("Hello".to_string()));
+        assert_eq!(chat_msg.content, Some("Hello".into()));
+    }
+
+    #[test]
+    fn test_tool_choice() {
+        let options = LanguageModelOptions {
+            tools: Some(crate::core::tools::ToolList::new(vec![SdkTool {
+                name: "weather".to_string(),
+                ..Default::default()
+            }])),
+            tool_choice: Some(ToolChoice::Tool("weather".to_string())),
+            ..Default::default()
+        };
+        let completions_opts: client::ChatCompletionsOptions = options.into();
+        assert_eq!(
+            serde_json::to_value(completions_opts.tool_choice).unwrap(),
+            serde_json::json!({"type": "function", "function": {"name": "weather"}})
+        );
+
+        let completions_opts: client::ChatCompletionsOptions = LanguageModelOptions {
+            tool_choice: Some(ToolChoice::None),
+            ..Default::default()
+        }
+        .into();
+        assert!(completions_opts.tool_choice.is_none());
+    }
+
+    #[test]
+    fn test_parallel_tool_calls() {
+        let tools = Some(crate::core::tools::ToolList::new(vec![SdkTool {
+            name: "weather".to_string(),
+            ..Default::default()
+        }]));
+        let completions_opts: client::ChatCompletionsOptions = LanguageModelOptions {
+            tools: tools.clone(),
+            ..Default::default()
+        }
+        .into();
+        assert_eq!(completions_opts.parallel_tool_calls, Some(true));
+
+        let completions_opts: client::ChatCompletionsOptions = LanguageModelOptions {
+            tools,
+            parallel_tool_calls: Some(false),
+            ..Default::default()
+        }
+        .into();
+        assert_eq!(completions_opts.parallel_tool_calls, Some(false));
+    }
+
+    #[test]
+    fn test_top_logprobs_request_logprobs() {
+        let options = LanguageModelOptions {
+            top_logprobs: Some(3),
+            ..Default::default()
+        };
+        let completions_opts: client::ChatCompletionsOptions = options.into();
+        assert_eq!(completions_opts.logprobs, Some(true));
+        assert_eq!(completions_opts.top_logprobs, Some(3));
     }
 
     #[test]
@@ -317,6 +507,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_finish_reason() {
+        assert_eq!(finish_reason("stop"), FinishReason::Stop);
+        assert_eq!(finish_reason("length"), FinishReason::Length);
+        assert_eq!(finish_reason("tool_calls"), FinishReason::ToolCalls);
+        assert_eq!(finish_reason("content_filter"), FinishReason::ContentFilter);
+        assert_eq!(
+            finish_reason("insufficient_system_resource"),
+            FinishReason::Other("insufficient_system_resource".to_string())
+        );
+    }
+
     #[test]
     fn test_usage_conversion() {
         let usage = types::Usage {
@@ -341,4 +543,39 @@ mod tests {
         assert_eq!(sdk_usage.cached_tokens, Some(20));
         assert_eq!(sdk_usage.reasoning_tokens, Some(10));
     }
+
+    #[test]
+    fn test_extra_body_is_flattened() {
+        let mut completions_opts: client::ChatCompletionsOptions =
+            LanguageModelOptions::default().into();
+        completions_opts
+            .extra_body
+            .insert("repetition_penalty".to_string(), serde_json::json!(1.1));
+
+        let body = serde_json::to_value(&completions_opts).unwrap();
+        assert_eq!(body["repetition_penalty"], serde_json::json!(1.1));
+        assert!(body.get("extra_body").is_none());
+    }
+
+    #[test]
+    fn test_message_conversion_user_with_audio() {
+        use crate::core::messages::{AudioPart, ImagePart};
+
+        let msg = Message::User(UserMessage::with_parts([
+            ContentPart::text("What does this say?"),
+            AudioPart::base64("UklGRg==", "audio/wav").into(),
+            ImagePart::url("https://example.com/cat.png").into(),
+            AudioPart::url("https://example.com/note.mp3", "audio/mpeg").into(),
+        ]));
+        let chat_msg: types::ChatMessage = msg.into();
+
+        assert_eq!(
+            serde_json::to_value(chat_msg.content).unwrap(),
+            serde_json::json!([
+                {"type": "text", "text": "What does this say?"},
+                {"type": "input_audio", "input_audio": {"data": "UklGRg==", "format": "wav"}},
+                {"type": "image_url", "image_url": {"url": "https://example.com/cat.png"}}
+            ])
+        );
+    }
 }
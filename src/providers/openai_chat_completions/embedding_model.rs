@@ -3,7 +3,7 @@
 use crate::{
     core::{
         capabilities::ModelName,
-        client::EmbeddingClient,
+        client::{EmbeddingClient, RetryPolicy},
         embedding_model::{EmbeddingModel, EmbeddingModelOptions, EmbeddingModelResponse},
     },
     error::Result,
@@ -42,6 +42,14 @@ impl<M: ModelName> EmbeddingClient for OpenAIChatCompletions<M> {
         Vec::new()
     }
 
+    fn retry_policy(&self) -> RetryPolicy {
+        self.settings.retry_policy.clone()
+    }
+
+    fn transport(&self) -> crate::core::client::TransportSettings {
+        self.settings.transport.clone()
+    }
+
     fn body(&self) -> reqwest::Body {
         // This will be set when embedding is called
         reqwest::Body::from("") // Placeholder, will be replaced
@@ -111,6 +119,14 @@ impl EmbeddingClient for EmbeddingClientWrapper {
         Vec::new()
     }
 
+    fn retry_policy(&self) -> RetryPolicy {
+        self.settings.retry_policy.clone()
+    }
+
+    fn transport(&self) -> crate::core::client::TransportSettings {
+        self.settings.transport.clone()
+    }
+
     fn body(&self) -> reqwest::Body {
         let body = serde_json::to_string(&self.options).unwrap();
         reqwest::Body::from(body)
@@ -0,0 +1,18 @@
+//! Response metadata of OpenAI-compatible providers.
+
+use crate::extensions::Extension;
+use serde::{Deserialize, Serialize};
+
+/// Metadata of a Chat Completions response, read with
+/// `response.response_extensions().get::<ChatCompletionsResponseMetadata>()`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ChatCompletionsResponseMetadata {
+    /// The backend configuration the model ran with. Responses to requests with the
+    /// same [`seed`](crate::core::language_model::LanguageModelOptions::seed) are only
+    /// expected to be the same while it does not change.
+    pub system_fingerprint: Option<String>,
+}
+
+impl Extension for ChatCompletionsResponseMetadata {
+    const NAME: &'static str = "openai_chat_completions.response_metadata";
+}
@@ -4,15 +4,32 @@ use crate::core::capabilities::ModelName;
 use crate::core::client::LanguageModelClient;
 use crate::core::language_model::{
     LanguageModel, LanguageModelOptions, LanguageModelResponse, LanguageModelResponseContentType,
-    LanguageModelStreamChunk, LanguageModelStreamChunkType, ProviderStream,
+    LanguageModelStreamChunk, LanguageModelStreamChunkType, ProviderStream, SamplingOption,
+    stream_with_rate_limit,
 };
 use crate::core::messages::AssistantMessage;
-use crate::core::tools::ToolCallInfo;
+use crate::core::tools::{ToolCallDelta, ToolCallInfo, ToolDetails};
 use crate::error::Result;
+use crate::extensions::Extensions;
 use crate::providers::openai_chat_completions::OpenAIChatCompletions;
 use crate::providers::openai_chat_completions::client::{self, types};
+use crate::providers::openai_chat_completions::conversions;
+use crate::providers::openai_chat_completions::extensions::ChatCompletionsResponseMetadata;
 use async_trait::async_trait;
 use futures::StreamExt;
+use std::collections::HashMap;
+
+impl<M: ModelName> OpenAIChatCompletions<M> {
+    /// Returns the extra body of the settings, with the given request fields on top.
+    fn extra_body(
+        &self,
+        request_fields: serde_json::Map<String, serde_json::Value>,
+    ) -> serde_json::Map<String, serde_json::Value> {
+        let mut extra_body = self.settings.extra_body.clone();
+        extra_body.extend(request_fields);
+        extra_body
+    }
+}
 
 #[async_trait]
 impl<M: ModelName> LanguageModel for OpenAIChatCompletions<M> {
@@ -20,50 +37,37 @@ impl<M: ModelName> LanguageModel for OpenAIChatCompletions<M> {
         self.options.model.clone()
     }
 
+    fn capabilities(&self) -> crate::core::capabilities::Capabilities {
+        M::CAPABILITIES
+    }
+
+    fn info(&self) -> crate::core::capabilities::ModelInfo {
+        M::INFO
+    }
+
+    fn unsupported_options(&self) -> &'static [SamplingOption] {
+        &[SamplingOption::TopK]
+    }
+
     async fn generate_text(
         &mut self,
         options: LanguageModelOptions,
     ) -> Result<LanguageModelResponse> {
         let mut options: client::ChatCompletionsOptions = options.into();
         options.model = self.options.model.clone();
+        options.extra_body = self.extra_body(options.extra_body);
         self.options = options;
 
-        let response: types::ChatCompletionsResponse = self.send(&self.settings.base_url).await?;
-
-        // Convert choices to LanguageModelResponse
-        let mut contents = Vec::new();
-
-        for choice in response.choices {
-            // Handle text content
-            if let Some(text) = choice.message.content
-                && !text.is_empty()
-            {
-                contents.push(LanguageModelResponseContentType::Text(text));
-            }
-
-            // Handle tool calls
-            if let Some(tool_calls) = choice.message.tool_calls {
-                for tool_call in tool_calls {
-                    let mut tool_info = ToolCallInfo::new(tool_call.function.name);
-                    tool_info.id(tool_call.id);
-                    tool_info.input(
-                        serde_json::from_str(&tool_call.function.arguments)
-                            .unwrap_or_else(|_| serde_json::Value::Object(serde_json::Map::new())),
-                    );
-                    contents.push(LanguageModelResponseContentType::ToolCall(tool_info));
-                }
-            }
-        }
+        let (response, rate_limit): (types::ChatCompletionsResponse, _) =
+            self.send_with_rate_limit(&self.settings.base_url).await?;
 
-        Ok(LanguageModelResponse {
-            contents,
-            usage: response.usage.map(|u| u.into()),
-        })
+        Ok(response_from(response).with_rate_limit(rate_limit))
     }
 
     async fn stream_text(&mut self, options: LanguageModelOptions) -> Result<ProviderStream> {
         let mut options: client::ChatCompletionsOptions = options.into();
         options.model = self.options.model.clone();
+        options.extra_body = self.extra_body(options.extra_body);
         options.stream = Some(true);
         // Note: stream_options is not sent to maintain compatibility with
         // OpenAI-compatible providers that don't support this field (e.g., Z.ai)
@@ -71,116 +75,17 @@ impl<M: ModelName> LanguageModel for OpenAIChatCompletions<M> {
         // open ai compatible providers
         self.options = options;
 
-        let stream = self.send_and_stream(&self.settings.base_url).await?;
+        let (stream, rate_limit) = self
+            .send_and_stream_with_rate_limit(&self.settings.base_url)
+            .await?;
 
         // State for accumulating tool calls across chunks
-        use std::collections::HashMap;
-        let mut accumulated_tool_calls: HashMap<u32, (String, String, String)> = HashMap::new();
+        let mut accumulated_tool_calls = AccumulatedToolCalls::new();
 
         // Map stream events to SDK stream chunks
         let stream = stream.map(move |evt_res| match evt_res {
             Ok(types::ChatCompletionsStreamEvent::Chunk(chunk)) => {
-                let mut results = Vec::new();
-
-                for choice in chunk.choices {
-                    // Reasoning delta (for reasoning models like o1, DeepSeek R1)
-                    if let Some(reasoning) = choice.delta.reasoning_content
-                        && !reasoning.is_empty()
-                    {
-                        results.push(LanguageModelStreamChunk::Delta(
-                            LanguageModelStreamChunkType::Reasoning(reasoning),
-                        ));
-                    }
-
-                    // Text delta
-                    if let Some(content) = choice.delta.content
-                        && !content.is_empty()
-                    {
-                        results.push(LanguageModelStreamChunk::Delta(
-                            LanguageModelStreamChunkType::Text(content),
-                        ));
-                    }
-
-                    // Accumulate tool call deltas
-                    if let Some(tool_calls) = choice.delta.tool_calls {
-                        for tool_call in tool_calls {
-                            let entry = accumulated_tool_calls.entry(tool_call.index).or_insert((
-                                String::new(),
-                                String::new(),
-                                String::new(),
-                            ));
-
-                            // Accumulate ID
-                            if let Some(id) = tool_call.id {
-                                entry.0 = id;
-                            }
-
-                            // Accumulate name and arguments
-                            if let Some(function) = tool_call.function {
-                                if let Some(name) = function.name {
-                                    entry.1 = name;
-                                }
-                                if let Some(args) = function.arguments {
-                                    entry.2.push_str(&args);
-                                    results.push(LanguageModelStreamChunk::Delta(
-                                        LanguageModelStreamChunkType::ToolCall(args),
-                                    ));
-                                }
-                            }
-                        }
-                    }
-
-                    if let Some(finish_reason) = choice.finish_reason {
-                        let usage = chunk.usage.clone().map(|u| u.into());
-
-                        match finish_reason.as_str() {
-                            "stop" | "length" => {
-                                results.push(LanguageModelStreamChunk::Done(AssistantMessage {
-                                    content: LanguageModelResponseContentType::Text(String::new()),
-                                    usage,
-                                }));
-                            }
-                            "tool_calls" | "function_call" => {
-                                // Send accumulated tool calls
-                                for (id, name, args) in accumulated_tool_calls.values() {
-                                    let mut tool_info = ToolCallInfo::new(name.clone());
-                                    tool_info.id(id.clone());
-                                    tool_info.input(serde_json::from_str(args).unwrap_or_else(
-                                        |_| serde_json::Value::Object(serde_json::Map::new()),
-                                    ));
-                                    results.push(LanguageModelStreamChunk::Done(
-                                        AssistantMessage {
-                                            content: LanguageModelResponseContentType::ToolCall(
-                                                tool_info,
-                                            ),
-                                            usage: usage.clone(),
-                                        },
-                                    ));
-                                }
-                            }
-                            "content_filter" => {
-                                results.push(LanguageModelStreamChunk::Done(AssistantMessage {
-                                    content: LanguageModelResponseContentType::Text(String::new()),
-                                    usage,
-                                }));
-                                results.push(LanguageModelStreamChunk::Delta(
-                                    LanguageModelStreamChunkType::Failed(
-                                        "Content filtered".to_string(),
-                                    ),
-                                ));
-                            }
-                            // For any unknown finish reason, treat as normal completion
-                            _ => {
-                                results.push(LanguageModelStreamChunk::Done(AssistantMessage {
-                                    content: LanguageModelResponseContentType::Text(String::new()),
-                                    usage,
-                                }));
-                            }
-                        }
-                    }
-                }
-
-                Ok(results)
+                Ok(stream_chunks(chunk, &mut accumulated_tool_calls))
             }
             Ok(types::ChatCompletionsStreamEvent::Open) => Ok(vec![]),
             Ok(types::ChatCompletionsStreamEvent::Done) => Ok(vec![]),
@@ -192,6 +97,314 @@ impl<M: ModelName> LanguageModel for OpenAIChatCompletions<M> {
             Err(e) => Err(e),
         });
 
-        Ok(Box::pin(stream))
+        Ok(stream_with_rate_limit(rate_limit, Box::pin(stream)))
+    }
+}
+
+/// Tool call deltas accumulated across stream chunks, keyed by index, as
+/// `(id, name, arguments)`.
+pub(crate) type AccumulatedToolCalls = HashMap<u32, (String, String, String)>;
+
+/// Converts a Chat Completions response. Shared with providers that extend the
+/// response format, such as Perplexity.
+pub(crate) fn response_from(response: types::ChatCompletionsResponse) -> LanguageModelResponse {
+    // Convert choices to LanguageModelResponse
+    let mut contents = Vec::new();
+    let mut logprobs = Vec::new();
+    let mut finish_reason = None;
+
+    for choice in response.choices {
+        logprobs.extend(conversions::logprobs(choice.logprobs));
+        if let Some(reason) = &choice.finish_reason {
+            finish_reason = Some(conversions::finish_reason(reason));
+        }
+        // Handle text content
+        if let Some(types::ChatContent::Text(text)) = choice.message.content
+            && !text.is_empty()
+        {
+            contents.push(LanguageModelResponseContentType::Text(text));
+        }
+
+        if let Some(refusal) = choice.message.refusal {
+            contents.push(LanguageModelResponseContentType::Refusal(refusal));
+        }
+
+        // Handle tool calls
+        if let Some(tool_calls) = choice.message.tool_calls {
+            for tool_call in tool_calls {
+                let mut tool_info = ToolCallInfo::new(tool_call.function.name);
+                tool_info.id(tool_call.id);
+                tool_info.input(
+                    serde_json::from_str(&tool_call.function.arguments)
+                        .unwrap_or_else(|_| serde_json::Value::Object(serde_json::Map::new())),
+                );
+                contents.push(LanguageModelResponseContentType::ToolCall(tool_info));
+            }
+        }
+    }
+
+    let extensions = Extensions::default();
+    if response.system_fingerprint.is_some() {
+        extensions.insert(ChatCompletionsResponseMetadata {
+            system_fingerprint: response.system_fingerprint,
+        });
+    }
+
+    LanguageModelResponse {
+        contents,
+        usage: response.usage.map(|u| u.into()),
+        stop_sequence: None,
+        finish_reason,
+        logprobs,
+        extensions,
+    }
+}
+
+/// Converts a streamed Chat Completions chunk into stream chunks. Shared with providers
+/// that extend the chunk format, such as Perplexity.
+pub(crate) fn stream_chunks(
+    chunk: types::ChatCompletionsStreamChunk,
+    accumulated_tool_calls: &mut AccumulatedToolCalls,
+) -> Vec<LanguageModelStreamChunk> {
+    let mut results = Vec::new();
+
+    for choice in chunk.choices {
+        // Reasoning delta (for reasoning models like o1, DeepSeek R1)
+        if let Some(reasoning) = choice.delta.reasoning_content
+            && !reasoning.is_empty()
+        {
+            results.push(LanguageModelStreamChunk::Delta(
+                LanguageModelStreamChunkType::Reasoning(reasoning),
+            ));
+        }
+
+        // Text delta
+        if let Some(content) = choice.delta.content
+            && !content.is_empty()
+        {
+            results.push(LanguageModelStreamChunk::Delta(
+                LanguageModelStreamChunkType::Text(content),
+            ));
+        }
+
+        let logprobs = conversions::logprobs(choice.logprobs);
+        if !logprobs.is_empty() {
+            results.push(LanguageModelStreamChunk::Delta(
+                LanguageModelStreamChunkType::Logprobs(logprobs),
+            ));
+        }
+
+        // Accumulate tool call deltas
+        if let Some(tool_calls) = choice.delta.tool_calls {
+            for tool_call in tool_calls {
+                let entry = accumulated_tool_calls.entry(tool_call.index).or_insert((
+                    String::new(),
+                    String::new(),
+                    String::new(),
+                ));
+
+                // Accumulate ID
+                if let Some(id) = tool_call.id {
+                    entry.0 = id;
+                }
+
+                // Accumulate name and arguments
+                if let Some(function) = tool_call.function {
+                    if let Some(name) = function.name {
+                        entry.1 = name;
+                    }
+                    if let Some(args) = function.arguments {
+                        entry.2.push_str(&args);
+                        results.push(LanguageModelStreamChunk::ToolCallDelta(ToolCallDelta {
+                            tool: ToolDetails {
+                                name: entry.1.clone(),
+                                id: entry.0.clone(),
+                            },
+                            delta: args,
+                        }));
+                    }
+                }
+            }
+        }
+
+        if let Some(finish_reason) = choice.finish_reason {
+            let usage = chunk.usage.clone().map(|u| u.into());
+            results.push(LanguageModelStreamChunk::FinishReason(
+                conversions::finish_reason(&finish_reason),
+            ));
+
+            match finish_reason.as_str() {
+                "stop" | "length" => {
+                    results.push(LanguageModelStreamChunk::Done(AssistantMessage {
+                        content: LanguageModelResponseContentType::Text(String::new()),
+                        usage,
+                    }));
+                }
+                "tool_calls" | "function_call" => {
+                    // Send accumulated tool calls
+                    for (id, name, args) in accumulated_tool_calls.values() {
+                        let mut tool_info = ToolCallInfo::new(name.clone());
+                        tool_info.id(id.clone());
+                        tool_info.input(
+                            serde_json::from_str(args).unwrap_or_else(|_| {
+                                serde_json::Value::Object(serde_json::Map::new())
+                            }),
+                        );
+                        results.push(LanguageModelStreamChunk::Done(AssistantMessage {
+                            content: LanguageModelResponseContentType::ToolCall(tool_info),
+                            usage: usage.clone(),
+                        }));
+                    }
+                }
+                "content_filter" => {
+                    results.push(LanguageModelStreamChunk::Done(AssistantMessage {
+                        content: LanguageModelResponseContentType::Text(String::new()),
+                        usage,
+                    }));
+                    results.push(LanguageModelStreamChunk::Delta(
+                        LanguageModelStreamChunkType::Failed("Content filtered".to_string()),
+                    ));
+                }
+                // For any unknown finish reason, treat as normal completion
+                _ => {
+                    results.push(LanguageModelStreamChunk::Done(AssistantMessage {
+                        content: LanguageModelResponseContentType::Text(String::new()),
+                        usage,
+                    }));
+                }
+            }
+        }
+    }
+
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_provider_options_override_extra_body() {
+        let mut model = OpenAIChatCompletions::model_name("gpt-4o".to_string());
+        model.settings.extra_body = serde_json::json!({"service_tier": "auto", "store": true})
+            .as_object()
+            .cloned()
+            .unwrap();
+
+        let options = LanguageModelOptions {
+            provider_options: serde_json::json!({"service_tier": "flex"})
+                .as_object()
+                .cloned()
+                .unwrap(),
+            ..Default::default()
+        };
+        let request: client::ChatCompletionsOptions = options.into();
+        let extra_body = model.extra_body(request.extra_body);
+
+        assert_eq!(extra_body["service_tier"], "flex");
+        assert_eq!(extra_body["store"], true);
+    }
+
+    #[test]
+    fn test_response_keeps_system_fingerprint() {
+        let response: types::ChatCompletionsResponse = serde_json::from_value(serde_json::json!({
+            "id": "chatcmpl-1",
+            "object": "chat.completion",
+            "created": 0,
+            "model": "gpt-4o",
+            "choices": [{
+                "index": 0,
+                "message": {"role": "assistant", "content": "Hi"},
+                "finish_reason": "stop"
+            }],
+            "system_fingerprint": "fp_44709d6fcb"
+        }))
+        .unwrap();
+
+        let response = response_from(response);
+        assert_eq!(
+            response
+                .extensions
+                .get::<ChatCompletionsResponseMetadata>()
+                .system_fingerprint
+                .as_deref(),
+            Some("fp_44709d6fcb")
+        );
+    }
+
+    #[test]
+    fn test_response_with_refusal() {
+        let response: types::ChatCompletionsResponse = serde_json::from_value(serde_json::json!({
+            "id": "chatcmpl-1",
+            "object": "chat.completion",
+            "created": 0,
+            "model": "gpt-4o",
+            "choices": [{
+                "index": 0,
+                "message": {"role": "assistant", "content": null, "refusal": "I can't help"},
+                "finish_reason": "stop"
+            }]
+        }))
+        .unwrap();
+
+        let contents = response_from(response).contents;
+        assert!(matches!(
+            contents.as_slice(),
+            [LanguageModelResponseContentType::Refusal(refusal)] if refusal == "I can't help"
+        ));
+    }
+
+    #[test]
+    fn test_response_and_stream_logprobs() {
+        let logprobs = serde_json::json!({
+            "content": [{
+                "token": "Yes",
+                "logprob": -0.1,
+                "bytes": [89, 101, 115],
+                "top_logprobs": [
+                    {"token": "Yes", "logprob": -0.1, "bytes": null},
+                    {"token": "No", "logprob": -2.4, "bytes": null}
+                ]
+            }]
+        });
+        let response: types::ChatCompletionsResponse = serde_json::from_value(serde_json::json!({
+            "id": "chatcmpl-1",
+            "object": "chat.completion",
+            "created": 0,
+            "model": "gpt-4o",
+            "choices": [{
+                "index": 0,
+                "message": {"role": "assistant", "content": "Yes"},
+                "logprobs": logprobs,
+                "finish_reason": "stop"
+            }]
+        }))
+        .unwrap();
+
+        let response = response_from(response);
+        assert_eq!(response.logprobs.len(), 1);
+        assert_eq!(response.logprobs[0].token, "Yes");
+        assert_eq!(response.logprobs[0].top_logprobs[1].token, "No");
+
+        let chunk: types::ChatCompletionsStreamChunk = serde_json::from_value(serde_json::json!({
+            "id": "chatcmpl-1",
+            "object": "chat.completion.chunk",
+            "created": 0,
+            "model": "gpt-4o",
+            "choices": [{
+                "index": 0,
+                "delta": {"content": "Yes"},
+                "logprobs": logprobs,
+                "finish_reason": null
+            }]
+        }))
+        .unwrap();
+
+        let chunks = stream_chunks(chunk, &mut AccumulatedToolCalls::new());
+        assert!(matches!(
+            &chunks[1],
+            LanguageModelStreamChunk::Delta(LanguageModelStreamChunkType::Logprobs(logprobs))
+                if logprobs[0].logprob == -0.1
+        ));
     }
 }
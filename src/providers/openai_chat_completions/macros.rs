@@ -26,7 +26,7 @@ macro_rules! openai_compatible_settings {
             use derive_builder::Builder;
 
             /// Settings for this provider (delegates to OpenAI Chat Completions).
-            #[derive(Debug, Clone, Builder)]
+            #[derive(Clone, Builder)]
             #[builder(setter(into), default)]
             pub struct $settings_struct {
                 /// The name of the provider.
@@ -40,6 +40,25 @@ macro_rules! openai_compatible_settings {
 
                 /// Custom API path override.
                 pub path: Option<String>,
+
+                /// The retry policy applied to requests.
+                pub retry_policy: $crate::core::client::RetryPolicy,
+
+                /// The HTTP client, middleware and rate limiter requests go through.
+                pub transport: $crate::core::client::TransportSettings,
+            }
+
+            impl std::fmt::Debug for $settings_struct {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    f.debug_struct(stringify!($settings_struct))
+                        .field("provider_name", &self.provider_name)
+                        .field("base_url", &self.base_url)
+                        .field("api_key", &"<redacted>")
+                        .field("path", &self.path)
+                        .field("retry_policy", &self.retry_policy)
+                        .field("transport", &self.transport)
+                        .finish()
+                }
             }
 
             impl Default for $settings_struct {
@@ -49,6 +68,8 @@ macro_rules! openai_compatible_settings {
                         base_url: $default_base_url.to_string(),
                         api_key: std::env::var($api_key_env).unwrap_or_default(),
                         path: None,
+                        retry_policy: $crate::core::client::RetryPolicy::default(),
+                        transport: Default::default(),
                     }
                 }
             }
@@ -82,7 +103,10 @@ macro_rules! openai_compatible_language_model {
                 core::{
                     LanguageModel,
                     capabilities::ModelName,
-                    language_model::{LanguageModelOptions, LanguageModelResponse, ProviderStream},
+                    language_model::{
+                        LanguageModelOptions, LanguageModelResponse, ProviderStream,
+                        SamplingOption,
+                    },
                 },
             };
 
@@ -93,6 +117,18 @@ macro_rules! openai_compatible_language_model {
                     self.inner.name()
                 }
 
+                fn unsupported_options(&self) -> &'static [SamplingOption] {
+                    self.inner.unsupported_options()
+                }
+
+                fn capabilities(&self) -> $crate::core::capabilities::Capabilities {
+                    M::CAPABILITIES
+                }
+
+                fn info(&self) -> $crate::core::capabilities::ModelInfo {
+                    Self::model_info(&self.name())
+                }
+
                 #[doc = concat!("Generates text using the ", stringify!($provider_struct), " provider.")]
                 async fn generate_text(
                     &mut self,
@@ -282,6 +318,21 @@ macro_rules! openai_compatible_provider {
                 self
             }
 
+            #[doc = concat!(
+                "Sets the retry policy applied to requests made by the ", stringify!($provider_struct), " provider.\n\n",
+                "# Parameters\n\n",
+                "* `retry_policy` - The retry policy to use for retryable failures.\n\n",
+                "# Returns\n\n",
+                "The builder with the retry policy set."
+            )]
+            pub fn retry_policy(mut self, retry_policy: $crate::core::client::RetryPolicy) -> Self {
+                self.settings.retry_policy = retry_policy.clone();
+                self.inner.settings.retry_policy = retry_policy;
+                self
+            }
+
+            $crate::core::client::transport_builder_methods!();
+
             #[doc = concat!(
                 "Sets a custom API path for the ", stringify!($provider_struct), " provider, ",
                 "overriding the default \"chat/completions\"."
@@ -308,9 +359,10 @@ macro_rules! openai_compatible_provider {
                     return Err(Error::MissingField("api_key".to_string()));
                 }
 
-                // Update the inner provider with the validated base_url
+                // Update the inner provider with the validated base_url and the transport settings
                 self.inner.settings.base_url = base_url.to_string();
                 self.settings.base_url = base_url.to_string();
+                self.inner.settings.transport = self.settings.transport.clone();
 
                 Ok($provider_struct {
                     settings: self.settings,
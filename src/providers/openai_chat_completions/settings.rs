@@ -1,8 +1,10 @@
 //! Settings for the OpenAI Chat Completions API compatible providers.
 
+use crate::core::client::{RetryPolicy, TransportSettings};
 use derive_builder::Builder;
+use serde_json::{Map, Value};
 
-#[derive(Debug, Clone, Builder)]
+#[derive(Clone, Builder)]
 #[builder(setter(into), default)]
 /// Settings for OpenAI Chat Completions API compatible providers.
 ///
@@ -21,6 +23,30 @@ pub struct OpenAIChatCompletionsSettings {
     /// Custom API path override. When set, this path is used instead of the
     /// default "chat/completions".
     pub path: Option<String>,
+
+    /// The retry policy applied to requests.
+    pub retry_policy: RetryPolicy,
+
+    /// The HTTP client, middleware and rate limiter requests go through.
+    pub transport: TransportSettings,
+
+    /// Provider-specific fields merged into every request body, for options the
+    /// Chat Completions API does not define (e.g. Together AI's `repetition_penalty`).
+    pub extra_body: Map<String, Value>,
+}
+
+impl std::fmt::Debug for OpenAIChatCompletionsSettings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OpenAIChatCompletionsSettings")
+            .field("provider_name", &self.provider_name)
+            .field("base_url", &self.base_url)
+            .field("api_key", &"<redacted>")
+            .field("path", &self.path)
+            .field("retry_policy", &self.retry_policy)
+            .field("transport", &self.transport)
+            .field("extra_body", &self.extra_body)
+            .finish()
+    }
 }
 
 impl Default for OpenAIChatCompletionsSettings {
@@ -30,6 +56,9 @@ impl Default for OpenAIChatCompletionsSettings {
             base_url: "https://api.openai.com/v1".to_string(),
             api_key: std::env::var("OPENAI_API_KEY").unwrap_or_default(),
             path: None,
+            retry_policy: RetryPolicy::default(),
+            transport: TransportSettings::default(),
+            extra_body: Map::new(),
         }
     }
 }
@@ -22,6 +22,12 @@ impl<M: ModelName> EmbeddingModel for OpenAICompatible<M> {
                 api_key: self.inner.settings.api_key.clone(),
                 provider_name: self.inner.settings.provider_name.clone(),
                 path: self.inner.settings.path.clone(),
+                retry_policy: self.inner.settings.retry_policy.clone(),
+                transport: self.inner.settings.transport.clone(),
+                image_response_format: None,
+                speech_instructions: None,
+                speech_speed: None,
+                reasoning_summary: None,
             },
             lm_options: Default::default(),
             embedding_options: crate::providers::openai::client::OpenAIEmbeddingOptions {
@@ -31,6 +37,11 @@ impl<M: ModelName> EmbeddingModel for OpenAICompatible<M> {
                 dimensions: input.dimensions,
                 encoding_format: None,
             },
+            image_options: Default::default(),
+            speech_options: Default::default(),
+            transcription_options: Default::default(),
+            moderation_options: Default::default(),
+            video_options: Default::default(),
             _phantom: std::marker::PhantomData,
         };
 
@@ -7,7 +7,9 @@ use crate::{
     core::{
         LanguageModel,
         capabilities::ModelName,
-        language_model::{LanguageModelOptions, LanguageModelResponse, ProviderStream},
+        language_model::{
+            LanguageModelOptions, LanguageModelResponse, ProviderStream, SamplingOption,
+        },
     },
     providers::openai_compatible::OpenAICompatible,
 };
@@ -19,6 +21,18 @@ impl<M: ModelName> LanguageModel for OpenAICompatible<M> {
         self.inner.name()
     }
 
+    fn capabilities(&self) -> crate::core::capabilities::Capabilities {
+        M::CAPABILITIES
+    }
+
+    fn info(&self) -> crate::core::capabilities::ModelInfo {
+        Self::model_info(&self.name())
+    }
+
+    fn unsupported_options(&self) -> &'static [SamplingOption] {
+        self.inner.unsupported_options()
+    }
+
     /// Generates text using the OpenAI-compatible provider.
     async fn generate_text(
         &mut self,
@@ -26,6 +26,7 @@ pub mod settings;
 use crate::Error;
 use crate::core::DynamicModel;
 use crate::core::capabilities::ModelName;
+use crate::core::client::RetryPolicy;
 use crate::core::utils::validate_base_url;
 use crate::error::Result;
 use crate::providers::openai_chat_completions::OpenAIChatCompletions;
@@ -167,6 +168,23 @@ impl<M: ModelName> OpenAICompatibleBuilder<M> {
         self
     }
 
+    /// Sets the retry policy applied to requests made by this provider.
+    ///
+    /// # Parameters
+    ///
+    /// * `retry_policy` - The [`RetryPolicy`] to use for retryable failures.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the retry policy set.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.settings.retry_policy = retry_policy.clone();
+        self.inner.settings.retry_policy = retry_policy;
+        self
+    }
+
+    crate::core::client::transport_builder_methods!();
+
     /// Sets a custom API path, overriding the default "chat/completions".
     pub fn path(mut self, path: impl Into<String>) -> Self {
         let p = Some(path.into());
@@ -191,9 +209,10 @@ impl<M: ModelName> OpenAICompatibleBuilder<M> {
             return Err(Error::MissingField("api_key".to_string()));
         }
 
-        // Update the inner provider with the validated base_url
+        // Update the inner provider with the validated base_url and the transport settings
         self.inner.settings.base_url = base_url.to_string();
         self.settings.base_url = base_url.to_string();
+        self.inner.settings.transport = self.settings.transport.clone();
 
         Ok(OpenAICompatible {
             settings: self.settings,
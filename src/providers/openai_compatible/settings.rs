@@ -1,9 +1,10 @@
 //! Defines the settings for the OpenAI-compatible provider.
 
+use crate::core::client::{RetryPolicy, TransportSettings};
 use derive_builder::Builder;
 
 /// Settings for the OpenAI-compatible provider (delegates to OpenAI).
-#[derive(Debug, Clone, Builder)]
+#[derive(Clone, Builder)]
 #[builder(setter(into), default)]
 pub struct OpenAICompatibleSettings {
     /// The name of the provider. Defaults to "OpenAICompatible".
@@ -17,6 +18,25 @@ pub struct OpenAICompatibleSettings {
 
     /// Custom API path override.
     pub path: Option<String>,
+
+    /// The retry policy applied to requests.
+    pub retry_policy: RetryPolicy,
+
+    /// The HTTP client, middleware and rate limiter requests go through.
+    pub transport: TransportSettings,
+}
+
+impl std::fmt::Debug for OpenAICompatibleSettings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OpenAICompatibleSettings")
+            .field("provider_name", &self.provider_name)
+            .field("base_url", &self.base_url)
+            .field("api_key", &"<redacted>")
+            .field("path", &self.path)
+            .field("retry_policy", &self.retry_policy)
+            .field("transport", &self.transport)
+            .finish()
+    }
 }
 
 impl Default for OpenAICompatibleSettings {
@@ -27,6 +47,8 @@ impl Default for OpenAICompatibleSettings {
             base_url: "https://api.openai.com/v1".to_string(),
             api_key: std::env::var("OPENAI_API_KEY").unwrap_or_default(),
             path: None,
+            retry_policy: RetryPolicy::default(),
+            transport: TransportSettings::default(),
         }
     }
 }
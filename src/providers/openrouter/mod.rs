@@ -1,10 +1,12 @@
 //! This module provides the Openrouter provider, wrapping OpenAI Chat Completions for Openrouter requests.
 //!
+//! The models available through OpenRouter are listed with [`Openrouter::list_models`].
 
 // NOTE: OpenRouter might not be fully compatible with the OpenAI API. Please refer
 // to the OpenRouter documentation for more information.
 
 pub mod capabilities;
+mod models;
 
 // Generate the settings module
 crate::openai_compatible_settings!(
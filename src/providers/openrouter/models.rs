@@ -0,0 +1,126 @@
+//! Model listing of the OpenRouter API.
+
+use reqwest::header::{AUTHORIZATION, HeaderMap};
+use serde::{Deserialize, Serialize};
+
+use super::Openrouter;
+use crate::core::{
+    capabilities::ModelName,
+    client::{ModelListClient, RetryPolicy},
+    provider::ModelDescriptor,
+};
+use crate::error::Result;
+
+/// A page of the models endpoint.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct OpenrouterModelList {
+    pub(crate) data: Vec<OpenrouterModel>,
+}
+
+/// A model of the models endpoint.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct OpenrouterModel {
+    /// The `provider/model` slug, e.g. `"anthropic/claude-sonnet-4"`.
+    pub(crate) id: String,
+    #[serde(default)]
+    pub(crate) name: Option<String>,
+    #[serde(default)]
+    pub(crate) description: Option<String>,
+    #[serde(default)]
+    pub(crate) context_length: Option<usize>,
+    #[serde(default)]
+    pub(crate) top_provider: Option<OpenrouterTopProvider>,
+}
+
+/// The provider serving a model by default.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct OpenrouterTopProvider {
+    #[serde(default)]
+    pub(crate) max_completion_tokens: Option<usize>,
+}
+
+impl<M: ModelName> ModelListClient for Openrouter<M> {
+    type Response = OpenrouterModelList;
+
+    fn path(&self) -> String {
+        "/models".to_string()
+    }
+
+    fn query_params(&self) -> Vec<(&str, &str)> {
+        Vec::new()
+    }
+
+    fn headers(&self) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        if let Ok(value) = format!("Bearer {}", self.inner.settings.api_key).parse() {
+            headers.insert(AUTHORIZATION, value);
+        }
+        headers
+    }
+
+    fn retry_policy(&self) -> RetryPolicy {
+        self.inner.settings.retry_policy.clone()
+    }
+
+    fn transport(&self) -> crate::core::client::TransportSettings {
+        self.inner.settings.transport.clone()
+    }
+
+    fn descriptors(response: Self::Response) -> Vec<ModelDescriptor> {
+        response
+            .data
+            .into_iter()
+            .map(|model| ModelDescriptor {
+                owned_by: model.id.split_once('/').map(|(owner, _)| owner.to_string()),
+                id: model.id,
+                display_name: model.name,
+                description: model.description,
+                context_window: model.context_length,
+                max_output_tokens: model.top_provider.and_then(|p| p.max_completion_tokens),
+            })
+            .collect()
+    }
+}
+
+impl<M: ModelName> Openrouter<M> {
+    /// Lists the models available through OpenRouter, e.g. to populate a model picker.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the API cannot be reached or returns an invalid response.
+    pub async fn list_models(&self) -> Result<Vec<ModelDescriptor>> {
+        ModelListClient::list(self, self.inner.settings.base_url.clone()).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_descriptors() {
+        let response: OpenrouterModelList = serde_json::from_value(serde_json::json!({
+            "data": [{
+                "id": "anthropic/claude-sonnet-4",
+                "name": "Anthropic: Claude Sonnet 4",
+                "context_length": 200000,
+                "top_provider": {"max_completion_tokens": 64000}
+            }]
+        }))
+        .unwrap();
+
+        let models = Openrouter::<crate::core::DynamicModel>::descriptors(response);
+
+        assert_eq!(
+            models,
+            [ModelDescriptor {
+                id: "anthropic/claude-sonnet-4".to_string(),
+                display_name: Some("Anthropic: Claude Sonnet 4".to_string()),
+                description: None,
+                owned_by: Some("anthropic".to_string()),
+                context_window: Some(200_000),
+                max_output_tokens: Some(64_000),
+            }]
+        );
+    }
+}
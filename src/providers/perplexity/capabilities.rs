@@ -0,0 +1,44 @@
+//! Capabilities for perplexity models.
+//!
+//! This module defines model types and their capabilities for the Perplexity provider.
+//! Users can implement additional traits on custom models.
+
+use crate::core::capabilities::*;
+use crate::model_capabilities;
+use crate::providers::perplexity::Perplexity;
+
+model_capabilities! {
+    provider: Perplexity,
+    models: {
+        Sonar {
+            model_name: "sonar",
+            constructor_name: sonar,
+            display_name: "Sonar",
+            capabilities: [ImageInputSupport, TextInputSupport, TextOutputSupport]
+        },
+        SonarDeepResearch {
+            model_name: "sonar-deep-research",
+            constructor_name: sonar_deep_research,
+            display_name: "Sonar Deep Research",
+            capabilities: [ReasoningSupport, TextInputSupport, TextOutputSupport]
+        },
+        SonarPro {
+            model_name: "sonar-pro",
+            constructor_name: sonar_pro,
+            display_name: "Sonar Pro",
+            capabilities: [ImageInputSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport]
+        },
+        SonarReasoning {
+            model_name: "sonar-reasoning",
+            constructor_name: sonar_reasoning,
+            display_name: "Sonar Reasoning",
+            capabilities: [ImageInputSupport, ReasoningSupport, TextInputSupport, TextOutputSupport]
+        },
+        SonarReasoningPro {
+            model_name: "sonar-reasoning-pro",
+            constructor_name: sonar_reasoning_pro,
+            display_name: "Sonar Reasoning Pro",
+            capabilities: [ImageInputSupport, ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport]
+        },
+    }
+}
@@ -0,0 +1,84 @@
+//! Client implementation for the Perplexity provider.
+use crate::core::client::{LanguageModelClient, RetryPolicy, sse_error};
+use crate::error::{Error, Result};
+use crate::providers::perplexity::{ModelName, Perplexity};
+use reqwest::header::CONTENT_TYPE;
+use reqwest_eventsource::Event;
+
+pub(crate) mod types;
+
+impl<M: ModelName> LanguageModelClient for Perplexity<M> {
+    type Response = types::PerplexityResponse;
+    type StreamEvent = types::PerplexityStreamEvent;
+
+    fn path(&self) -> String {
+        "chat/completions".to_string()
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn headers(&self) -> reqwest::header::HeaderMap {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(CONTENT_TYPE, "application/json".parse().unwrap());
+        headers.insert(
+            "Authorization",
+            format!("Bearer {}", self.settings.api_key).parse().unwrap(),
+        );
+        headers
+    }
+
+    fn query_params(&self) -> Vec<(&str, &str)> {
+        Vec::new()
+    }
+
+    fn retry_policy(&self) -> RetryPolicy {
+        self.settings.retry_policy.clone()
+    }
+
+    fn transport(&self) -> crate::core::client::TransportSettings {
+        self.settings.transport.clone()
+    }
+
+    fn body(&self) -> reqwest::Body {
+        let body = serde_json::to_string(&self.lm_options).unwrap();
+        reqwest::Body::from(body)
+    }
+
+    fn parse_stream_sse(
+        event: std::result::Result<Event, reqwest_eventsource::Error>,
+    ) -> Result<Self::StreamEvent> {
+        match event {
+            Ok(Event::Open) => Ok(types::PerplexityStreamEvent::Open),
+            Ok(Event::Message(msg)) => {
+                if msg.data.trim() == "[DONE]" || msg.data.is_empty() {
+                    return Ok(types::PerplexityStreamEvent::Done);
+                }
+
+                let chunk: types::PerplexityStreamChunk =
+                    serde_json::from_str(&msg.data).map_err(|e| Error::ApiError {
+                        status_code: None,
+                        details: format!("Invalid JSON in SSE: {e}"),
+                    })?;
+
+                Ok(types::PerplexityStreamEvent::Chunk(Box::new(chunk)))
+            }
+            Err(e) => Err(sse_error(e)),
+        }
+    }
+
+    /// Perplexity does not always send `[DONE]`, so the stream also ends with the
+    /// chunk that carries the finish reason.
+    fn end_stream(event: &Self::StreamEvent) -> bool {
+        match event {
+            types::PerplexityStreamEvent::Done => true,
+            types::PerplexityStreamEvent::Chunk(chunk) => chunk
+                .chunk
+                .choices
+                .iter()
+                .any(|choice| choice.finish_reason.is_some()),
+            types::PerplexityStreamEvent::Open => false,
+        }
+    }
+}
@@ -0,0 +1,57 @@
+//! Type definitions for the Perplexity API.
+//!
+//! Perplexity extends the OpenAI Chat Completions response format with the sources
+//! the response was grounded in, so these types wrap the Chat Completions ones.
+
+use crate::providers::openai_chat_completions::client::types::{
+    ChatCompletionsResponse, ChatCompletionsStreamChunk,
+};
+use serde::{Deserialize, Serialize};
+
+/// A web search result the response was grounded in.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct SearchResult {
+    pub url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_updated: Option<String>,
+}
+
+/// The search metadata Perplexity adds to responses and stream chunks.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct SearchMetadata {
+    /// The URLs of the sources, in the order the `[n]` markers in the text refer to.
+    #[serde(default)]
+    pub citations: Vec<String>,
+    #[serde(default)]
+    pub search_results: Vec<SearchResult>,
+    #[serde(default)]
+    pub related_questions: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct PerplexityResponse {
+    #[serde(flatten)]
+    pub completion: ChatCompletionsResponse,
+    #[serde(flatten)]
+    pub search: SearchMetadata,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct PerplexityStreamChunk {
+    #[serde(flatten)]
+    pub chunk: ChatCompletionsStreamChunk,
+    #[serde(flatten)]
+    pub search: SearchMetadata,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum PerplexityStreamEvent {
+    Chunk(Box<PerplexityStreamChunk>),
+    Done,
+    Open,
+}
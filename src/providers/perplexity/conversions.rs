@@ -0,0 +1,85 @@
+//! Helper functions and conversions for the Perplexity provider.
+
+use crate::core::language_model::{LanguageModelResponseContentType, Source};
+use crate::providers::perplexity::client::types::{SearchMetadata, SearchResult};
+use crate::providers::perplexity::extensions::PerplexitySourceMetadata;
+
+impl From<SearchResult> for Source {
+    fn from(result: SearchResult) -> Self {
+        let source = Source {
+            url: result.url,
+            title: result.title,
+            ..Default::default()
+        };
+        source.extensions.insert(PerplexitySourceMetadata {
+            date: result.date,
+            last_updated: result.last_updated,
+        });
+        source
+    }
+}
+
+/// Returns the sources of a response as content. Search results carry titles and dates,
+/// so they are preferred over the bare citation URLs.
+pub(crate) fn source_contents(search: SearchMetadata) -> Vec<LanguageModelResponseContentType> {
+    let sources: Vec<Source> = if search.search_results.is_empty() {
+        search.citations.into_iter().map(Source::new).collect()
+    } else {
+        search.search_results.into_iter().map(Into::into).collect()
+    };
+    sources
+        .into_iter()
+        .map(LanguageModelResponseContentType::Source)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search_results_are_preferred_over_citations() {
+        let search: SearchMetadata = serde_json::from_value(serde_json::json!({
+            "citations": ["https://example.com/a"],
+            "search_results": [{
+                "title": "Example",
+                "url": "https://example.com/a",
+                "date": "2025-01-02"
+            }]
+        }))
+        .unwrap();
+
+        let contents = source_contents(search);
+        assert_eq!(contents.len(), 1);
+        let LanguageModelResponseContentType::Source(source) = &contents[0] else {
+            panic!("expected a source");
+        };
+        assert_eq!(source.url, "https://example.com/a");
+        assert_eq!(source.title.as_deref(), Some("Example"));
+        assert_eq!(
+            source
+                .extensions
+                .get::<PerplexitySourceMetadata>()
+                .date
+                .as_deref(),
+            Some("2025-01-02")
+        );
+    }
+
+    #[test]
+    fn test_citations_without_search_results() {
+        let search = SearchMetadata {
+            citations: vec!["https://a.example".into(), "https://b.example".into()],
+            ..Default::default()
+        };
+
+        let urls: Vec<String> = source_contents(search)
+            .into_iter()
+            .filter_map(|content| match content {
+                LanguageModelResponseContentType::Source(source) => Some(source.url),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(urls, ["https://a.example", "https://b.example"]);
+    }
+}
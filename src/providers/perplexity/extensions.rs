@@ -0,0 +1,63 @@
+//! Perplexity specific search options and source metadata.
+
+use crate::extensions::Extension;
+use serde::{Deserialize, Serialize};
+
+/// Options controlling the web search Perplexity grounds responses in, sent with every
+/// request.
+///
+/// Set with [`PerplexityBuilder::search_options`](crate::providers::perplexity::PerplexityBuilder::search_options).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PerplexitySearchOptions {
+    /// Domains to limit the search to, e.g. `"wikipedia.org"`. Prefix a domain with
+    /// `-` to exclude it instead.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub search_domain_filter: Vec<String>,
+
+    /// Only searches sources published within this period: `"hour"`, `"day"`,
+    /// `"week"`, `"month"`, or `"year"`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub search_recency_filter: Option<String>,
+
+    /// Whether to return follow-up questions related to the query, see
+    /// [`Perplexity::related_questions`](crate::providers::perplexity::Perplexity::related_questions).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub return_related_questions: Option<bool>,
+}
+
+impl PerplexitySearchOptions {
+    /// Limits the search to, or with a `-` prefix excludes, the given domains.
+    pub fn search_domain_filter(
+        mut self,
+        domains: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.search_domain_filter = domains.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Limits the search to sources published within a period, e.g. `"week"`.
+    pub fn search_recency_filter(mut self, recency: impl Into<String>) -> Self {
+        self.search_recency_filter = Some(recency.into());
+        self
+    }
+
+    /// Sets whether to return related questions.
+    pub fn return_related_questions(mut self, return_related_questions: bool) -> Self {
+        self.return_related_questions = Some(return_related_questions);
+        self
+    }
+}
+
+/// Perplexity specific metadata of a [`Source`](crate::core::language_model::Source),
+/// read with `source.extensions.get::<PerplexitySourceMetadata>()`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PerplexitySourceMetadata {
+    /// The publication date of the source, if known.
+    pub date: Option<String>,
+    /// The date the source was last updated, if known.
+    pub last_updated: Option<String>,
+}
+
+impl Extension for PerplexitySourceMetadata {
+    const NAME: &'static str = "perplexity.source_metadata";
+}
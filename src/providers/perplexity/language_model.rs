@@ -0,0 +1,133 @@
+//! Language model implementation for the Perplexity provider.
+use crate::core::capabilities::ModelName;
+use crate::core::client::LanguageModelClient;
+use crate::core::language_model::{
+    LanguageModel, LanguageModelOptions, LanguageModelResponse, LanguageModelStreamChunk,
+    ProviderStream, SamplingOption, stream_with_rate_limit,
+};
+use crate::core::messages::AssistantMessage;
+use crate::error::Result;
+use crate::providers::openai_chat_completions::client::ChatCompletionsOptions;
+use crate::providers::openai_chat_completions::language_model::{
+    AccumulatedToolCalls, response_from, stream_chunks,
+};
+use crate::providers::perplexity::Perplexity;
+use crate::providers::perplexity::client::types::{self, SearchMetadata};
+use crate::providers::perplexity::conversions::source_contents;
+use async_trait::async_trait;
+use futures::StreamExt;
+
+impl<M: ModelName> Perplexity<M> {
+    pub(crate) fn chat_request(&self, options: LanguageModelOptions) -> ChatCompletionsOptions {
+        let mut request: ChatCompletionsOptions = options.into();
+        request.model = self.lm_options.model.clone();
+        if let Ok(serde_json::Value::Object(mut fields)) =
+            serde_json::to_value(&self.settings.search_options)
+        {
+            fields.extend(std::mem::take(&mut request.extra_body));
+            request.extra_body = fields;
+        }
+        request
+    }
+}
+
+#[async_trait]
+impl<M: ModelName> LanguageModel for Perplexity<M> {
+    fn name(&self) -> String {
+        self.lm_options.model.clone()
+    }
+
+    fn capabilities(&self) -> crate::core::capabilities::Capabilities {
+        M::CAPABILITIES
+    }
+
+    fn info(&self) -> crate::core::capabilities::ModelInfo {
+        Self::model_info(&self.name())
+    }
+
+    fn unsupported_options(&self) -> &'static [SamplingOption] {
+        &[SamplingOption::TopK]
+    }
+
+    async fn generate_text(
+        &mut self,
+        options: LanguageModelOptions,
+    ) -> Result<LanguageModelResponse> {
+        self.lm_options = self.chat_request(options);
+
+        let (response, rate_limit): (types::PerplexityResponse, _) =
+            self.send_with_rate_limit(&self.settings.base_url).await?;
+
+        self.set_related_questions(response.search.related_questions.clone());
+
+        // Sources come first so the text stays the last content of the step
+        let mut contents = source_contents(response.search);
+        let completion = response_from(response.completion);
+        contents.extend(completion.contents);
+
+        let response = LanguageModelResponse {
+            contents,
+            usage: completion.usage,
+            stop_sequence: None,
+            finish_reason: completion.finish_reason,
+            logprobs: Vec::new(),
+            extensions: Default::default(),
+        };
+        Ok(response.with_rate_limit(rate_limit))
+    }
+
+    async fn stream_text(&mut self, options: LanguageModelOptions) -> Result<ProviderStream> {
+        self.lm_options = self.chat_request(options);
+        self.lm_options.stream = Some(true);
+
+        let (perplexity_stream, rate_limit) = self
+            .send_and_stream_with_rate_limit(&self.settings.base_url)
+            .await?;
+
+        let provider = self.clone();
+        let mut accumulated_tool_calls = AccumulatedToolCalls::new();
+        let mut search = SearchMetadata::default();
+
+        let stream = perplexity_stream.map(move |evt_res| match evt_res {
+            Ok(types::PerplexityStreamEvent::Chunk(chunk)) => {
+                let types::PerplexityStreamChunk {
+                    chunk,
+                    search: chunk_search,
+                } = *chunk;
+
+                // Chunks repeat the search metadata seen so far, keep the latest
+                if !chunk_search.citations.is_empty() || !chunk_search.search_results.is_empty() {
+                    search.citations = chunk_search.citations;
+                    search.search_results = chunk_search.search_results;
+                }
+                if !chunk_search.related_questions.is_empty() {
+                    search.related_questions = chunk_search.related_questions;
+                }
+
+                let mut results = Vec::new();
+                if chunk.choices.iter().any(|c| c.finish_reason.is_some()) {
+                    provider.set_related_questions(std::mem::take(&mut search.related_questions));
+                    results.extend(
+                        source_contents(std::mem::take(&mut search))
+                            .into_iter()
+                            .map(|content| {
+                                // The usage is reported once, with the text
+                                LanguageModelStreamChunk::Done(AssistantMessage {
+                                    content,
+                                    usage: None,
+                                })
+                            }),
+                    );
+                }
+                results.extend(stream_chunks(chunk, &mut accumulated_tool_calls));
+                Ok(results)
+            }
+            Ok(types::PerplexityStreamEvent::Open) | Ok(types::PerplexityStreamEvent::Done) => {
+                Ok(vec![])
+            }
+            Err(e) => Err(e),
+        });
+
+        Ok(stream_with_rate_limit(rate_limit, Box::pin(stream)))
+    }
+}
@@ -0,0 +1,260 @@
+//! This module provides the Perplexity provider, which implements the `LanguageModel`
+//! and `Provider` traits for Perplexity's Sonar models.
+//!
+//! Perplexity speaks the OpenAI Chat Completions format, but grounds responses in a
+//! web search and returns the sources it found alongside the text. Rather than dropping
+//! them like an OpenAI-compatible provider would, this provider returns them as
+//! [`Source`](crate::core::language_model::Source) contents, and the follow-up
+//! questions it suggests through [`Perplexity::related_questions`].
+
+pub mod capabilities;
+pub mod client;
+pub mod conversions;
+pub mod extensions;
+pub mod language_model;
+pub mod settings;
+
+use crate::core::DynamicModel;
+use crate::core::capabilities::ModelName;
+use crate::core::client::RetryPolicy;
+use crate::core::utils::validate_base_url;
+use crate::error::{Error, Result};
+use crate::providers::openai_chat_completions::client::ChatCompletionsOptions;
+use crate::providers::perplexity::settings::PerplexityProviderSettings;
+use std::sync::{Arc, Mutex};
+
+pub use extensions::{PerplexitySearchOptions, PerplexitySourceMetadata};
+
+/// The Perplexity provider.
+#[derive(Debug, Clone)]
+pub struct Perplexity<M: ModelName> {
+    /// Configuration settings for the Perplexity provider.
+    pub settings: PerplexityProviderSettings,
+    pub(crate) lm_options: ChatCompletionsOptions,
+    /// Related questions of the latest response, shared between clones.
+    pub(crate) related_questions: Arc<Mutex<Vec<String>>>,
+    _phantom: std::marker::PhantomData<M>,
+}
+
+impl<M: ModelName> Perplexity<M> {
+    /// Perplexity provider setting builder.
+    pub fn builder() -> PerplexityBuilder<M> {
+        PerplexityBuilder::default()
+    }
+
+    /// Returns the follow-up questions Perplexity suggested for the latest response
+    /// generated by this provider or any of its clones.
+    ///
+    /// Questions are only returned when enabled with
+    /// [`PerplexitySearchOptions::return_related_questions`]. For streamed responses,
+    /// they are available once the stream has finished.
+    pub fn related_questions(&self) -> Vec<String> {
+        self.related_questions
+            .lock()
+            .unwrap_or_else(|p| p.into_inner())
+            .clone()
+    }
+
+    pub(crate) fn set_related_questions(&self, questions: Vec<String>) {
+        *self
+            .related_questions
+            .lock()
+            .unwrap_or_else(|p| p.into_inner()) = questions;
+    }
+
+    fn with_model(settings: PerplexityProviderSettings, model_name: String) -> Self {
+        let options = ChatCompletionsOptions {
+            model: model_name,
+            ..Default::default()
+        };
+
+        Self {
+            settings,
+            lm_options: options,
+            related_questions: Arc::default(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl Perplexity<DynamicModel> {
+    /// Creates a Perplexity provider with a dynamic model name using default settings.
+    ///
+    /// This allows you to specify the model name as a string rather than
+    /// using methods like `Perplexity::sonar_pro()`.
+    ///
+    /// **WARNING**: when using `DynamicModel`, model capabilities are not validated.
+    /// This means there is no compile-time guarantee that the model supports requested features.
+    ///
+    /// For custom configuration (API key, search options, etc.), use the builder pattern:
+    /// `Perplexity::<DynamicModel>::builder().model_name(...).api_key(...).build()`
+    ///
+    /// # Parameters
+    ///
+    /// * `model_name` - The Perplexity model identifier (e.g., "sonar", "sonar-pro")
+    ///
+    /// # Returns
+    ///
+    /// A configured `Perplexity<DynamicModel>` provider instance with default settings.
+    pub fn model_name(name: impl Into<String>) -> Self {
+        Self::with_model(PerplexityProviderSettings::default(), name.into())
+    }
+}
+
+impl<M: ModelName> Default for Perplexity<M> {
+    /// Creates a new Perplexity provider with default settings.
+    fn default() -> Self {
+        Self::with_model(
+            PerplexityProviderSettings::default(),
+            M::MODEL_NAME.to_string(),
+        )
+    }
+}
+
+/// Perplexity Provider Builder
+pub struct PerplexityBuilder<M: ModelName> {
+    settings: PerplexityProviderSettings,
+    model_name: String,
+    _phantom: std::marker::PhantomData<M>,
+}
+
+impl<M: ModelName> Default for PerplexityBuilder<M> {
+    /// Creates a new Perplexity provider builder with default settings.
+    fn default() -> Self {
+        Self {
+            settings: PerplexityProviderSettings::default(),
+            model_name: M::MODEL_NAME.to_string(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl PerplexityBuilder<DynamicModel> {
+    /// Sets the model name from a string. e.g., "sonar", "sonar-pro"
+    ///
+    /// **WARNING**: when using `DynamicModel`, model capabilities are not validated.
+    /// This means there is no compile-time guarantee that the model supports requested features.
+    ///
+    /// # Parameters
+    ///
+    /// * `model_name` - The Perplexity model identifier.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the model name set.
+    pub fn model_name(mut self, model_name: impl Into<String>) -> Self {
+        self.model_name = model_name.into();
+        self
+    }
+}
+
+impl<M: ModelName> PerplexityBuilder<M> {
+    /// Sets the base URL for the Perplexity API.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.settings.base_url = base_url.into();
+        self
+    }
+
+    /// Sets the API key for the Perplexity API.
+    pub fn api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.settings.api_key = api_key.into();
+        self
+    }
+
+    /// Sets the retry policy applied to requests made by this provider.
+    ///
+    /// # Parameters
+    ///
+    /// * `retry_policy` - The [`RetryPolicy`] to use for retryable failures.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the retry policy set.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.settings.retry_policy = retry_policy;
+        self
+    }
+
+    crate::core::client::transport_builder_methods!();
+
+    /// Sets the name of the provider. Defaults to "perplexity".
+    pub fn provider_name(mut self, provider_name: impl Into<String>) -> Self {
+        self.settings.provider_name = provider_name.into();
+        self
+    }
+
+    /// Sets the web search options sent with every request, such as
+    /// `search_domain_filter` and `return_related_questions`.
+    pub fn search_options(mut self, search_options: PerplexitySearchOptions) -> Self {
+        self.settings.search_options = search_options;
+        self
+    }
+
+    /// Builds the Perplexity provider.
+    pub fn build(self) -> Result<Perplexity<M>> {
+        // validate base url
+        let base_url = validate_base_url(&self.settings.base_url)?;
+
+        // check api key exists
+        if self.settings.api_key.is_empty() {
+            return Err(Error::MissingField("api_key".to_string()));
+        }
+
+        if self.model_name.is_empty() {
+            return Err(Error::MissingField("model_name".to_string()));
+        }
+
+        Ok(Perplexity::with_model(
+            PerplexityProviderSettings {
+                base_url,
+                ..self.settings
+            },
+            self.model_name,
+        ))
+    }
+}
+
+// Re-exports for convenience
+pub use capabilities::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::language_model::LanguageModelOptions;
+
+    #[test]
+    fn test_debug_redacts_api_key() {
+        let perplexity = Perplexity::<DynamicModel>::builder()
+            .model_name("sonar-pro")
+            .api_key("pplx-secret")
+            .build()
+            .unwrap();
+
+        assert!(!format!("{perplexity:?}").contains("pplx-secret"));
+    }
+
+    #[test]
+    fn test_search_options_are_sent_in_request_body() {
+        let perplexity = Perplexity::<DynamicModel>::builder()
+            .model_name("sonar-pro")
+            .api_key("key")
+            .search_options(
+                PerplexitySearchOptions::default()
+                    .search_domain_filter(["wikipedia.org", "-reddit.com"])
+                    .return_related_questions(true),
+            )
+            .build()
+            .unwrap();
+
+        let request = perplexity.chat_request(LanguageModelOptions::default());
+        let body = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(body["model"], "sonar-pro");
+        assert_eq!(
+            body["search_domain_filter"],
+            serde_json::json!(["wikipedia.org", "-reddit.com"])
+        );
+        assert_eq!(body["return_related_questions"], true);
+        assert!(body.get("search_recency_filter").is_none());
+    }
+}
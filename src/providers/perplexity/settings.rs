@@ -0,0 +1,64 @@
+//! Defines the settings for the Perplexity provider.
+
+use crate::core::client::{RetryPolicy, TransportSettings};
+use crate::providers::perplexity::extensions::PerplexitySearchOptions;
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+/// Settings for the Perplexity provider.
+#[derive(Clone, Serialize, Deserialize, Builder)]
+#[builder(setter(into), default)]
+pub struct PerplexityProviderSettings {
+    /// The name of the provider.
+    pub provider_name: String,
+
+    /// The base URL of the Perplexity API.
+    pub base_url: String,
+
+    /// The API key for the Perplexity API.
+    pub api_key: String,
+
+    /// The web search options sent with every request.
+    pub search_options: PerplexitySearchOptions,
+
+    /// The retry policy applied to requests.
+    pub retry_policy: RetryPolicy,
+
+    /// The HTTP client, middleware and rate limiter requests go through.
+    #[serde(skip)]
+    pub transport: TransportSettings,
+}
+
+impl std::fmt::Debug for PerplexityProviderSettings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PerplexityProviderSettings")
+            .field("provider_name", &self.provider_name)
+            .field("base_url", &self.base_url)
+            .field("api_key", &"<redacted>")
+            .field("search_options", &self.search_options)
+            .field("retry_policy", &self.retry_policy)
+            .field("transport", &self.transport)
+            .finish()
+    }
+}
+
+impl Default for PerplexityProviderSettings {
+    /// Returns the default settings for the Perplexity provider.
+    fn default() -> Self {
+        Self {
+            provider_name: "perplexity".to_string(),
+            base_url: "https://api.perplexity.ai/".to_string(),
+            api_key: std::env::var("PERPLEXITY_API_KEY").unwrap_or_default(),
+            search_options: PerplexitySearchOptions::default(),
+            retry_policy: RetryPolicy::default(),
+            transport: TransportSettings::default(),
+        }
+    }
+}
+
+impl PerplexityProviderSettings {
+    /// Creates a new builder for `PerplexityProviderSettings`.
+    pub fn builder() -> PerplexityProviderSettingsBuilder {
+        PerplexityProviderSettingsBuilder::default()
+    }
+}
@@ -0,0 +1,50 @@
+//! Capabilities for replicate models.
+//!
+//! This module defines model types and their capabilities for the Replicate provider.
+//! Users can implement additional traits on custom models.
+
+use crate::core::capabilities::*;
+use crate::model_capabilities;
+use crate::providers::replicate::Replicate;
+
+model_capabilities! {
+    provider: Replicate,
+    models: {
+        DeepseekAiDeepseekR1 {
+            model_name: "deepseek-ai/deepseek-r1",
+            constructor_name: deepseek_ai_deepseek_r1,
+            display_name: "DeepSeek R1",
+            capabilities: [TextInputSupport, TextOutputSupport]
+        },
+        IbmGraniteGranite338bInstruct {
+            model_name: "ibm-granite/granite-3.3-8b-instruct",
+            constructor_name: ibm_granite_granite_3_3_8b_instruct,
+            display_name: "Granite 3.3 8B Instruct",
+            capabilities: [TextInputSupport, TextOutputSupport]
+        },
+        MetaMetaLlama370bInstruct {
+            model_name: "meta/meta-llama-3-70b-instruct",
+            constructor_name: meta_meta_llama_3_70b_instruct,
+            display_name: "Llama 3 70B Instruct",
+            capabilities: [TextInputSupport, TextOutputSupport]
+        },
+        MetaMetaLlama38bInstruct {
+            model_name: "meta/meta-llama-3-8b-instruct",
+            constructor_name: meta_meta_llama_3_8b_instruct,
+            display_name: "Llama 3 8B Instruct",
+            capabilities: [TextInputSupport, TextOutputSupport]
+        },
+        MetaMetaLlama31405bInstruct {
+            model_name: "meta/meta-llama-3.1-405b-instruct",
+            constructor_name: meta_meta_llama_3_1_405b_instruct,
+            display_name: "Llama 3.1 405B Instruct",
+            capabilities: [TextInputSupport, TextOutputSupport]
+        },
+        MistralaiMixtral8x7bInstructV01 {
+            model_name: "mistralai/mixtral-8x7b-instruct-v0.1",
+            constructor_name: mistralai_mixtral_8x7b_instruct_v0_1,
+            display_name: "Mixtral 8x7B Instruct v0.1",
+            capabilities: [TextInputSupport, TextOutputSupport]
+        },
+    }
+}
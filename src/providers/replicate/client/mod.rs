@@ -0,0 +1,187 @@
+//! Client implementation for the Replicate provider.
+//!
+//! Replicate runs requests as predictions: a prediction is created, then polled until
+//! it settles, or its output is streamed from the URL returned when it was created.
+use crate::core::client::{PollingClient, RetryPolicy, sse_error};
+use crate::error::{Error, Result};
+use crate::providers::replicate::{ModelName, Replicate};
+use derive_builder::Builder;
+use reqwest::header::{AUTHORIZATION, CONTENT_TYPE};
+use reqwest_eventsource::Event;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+pub(crate) mod types;
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Builder)]
+#[builder(setter(into), build_fn(error = "Error"))]
+pub(crate) struct ReplicateOptions {
+    /// The model, as `owner/name` or `owner/name:version`.
+    pub(crate) model: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub(crate) request: Option<types::PredictionRequest>,
+}
+
+impl ReplicateOptions {
+    pub(crate) fn builder() -> ReplicateOptionsBuilder {
+        ReplicateOptionsBuilder::default()
+    }
+
+    /// Splits the model into its name and, if pinned, its version.
+    pub(crate) fn model_version(&self) -> (&str, Option<&str>) {
+        match self.model.split_once(':') {
+            Some((name, version)) => (name, Some(version)),
+            None => (&self.model, None),
+        }
+    }
+}
+
+impl<M: ModelName> PollingClient for Replicate<M> {
+    type Job = types::Prediction;
+    type StreamEvent = types::ReplicateStreamEvent;
+
+    /// Pinned versions are created through the generic predictions endpoint, while
+    /// official models always run their latest version.
+    fn path(&self) -> String {
+        match self.lm_options.model_version() {
+            (_, Some(_)) => "predictions".to_string(),
+            (name, None) => format!("models/{name}/predictions"),
+        }
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn headers(&self) -> reqwest::header::HeaderMap {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(CONTENT_TYPE, "application/json".parse().unwrap());
+        headers.insert(
+            AUTHORIZATION,
+            format!("Bearer {}", self.settings.api_key).parse().unwrap(),
+        );
+        headers
+    }
+
+    fn query_params(&self) -> Vec<(&str, &str)> {
+        Vec::new()
+    }
+
+    fn retry_policy(&self) -> RetryPolicy {
+        self.settings.retry_policy.clone()
+    }
+
+    fn transport(&self) -> crate::core::client::TransportSettings {
+        self.settings.transport.clone()
+    }
+
+    fn poll_interval(&self) -> Duration {
+        self.settings.poll_interval
+    }
+
+    fn body(&self) -> reqwest::Body {
+        if let Some(request) = &self.lm_options.request {
+            let body = serde_json::to_string(request).unwrap();
+            return reqwest::Body::from(body);
+        }
+        reqwest::Body::from("{}")
+    }
+
+    fn poll_url(job: &Self::Job) -> Option<String> {
+        job.urls.get.clone()
+    }
+
+    fn stream_url(job: &Self::Job) -> Option<String> {
+        job.urls.stream.clone()
+    }
+
+    fn is_settled(job: &Self::Job) -> bool {
+        !matches!(
+            job.status,
+            types::PredictionStatus::Starting | types::PredictionStatus::Processing
+        )
+    }
+
+    fn parse_stream_sse(
+        event: std::result::Result<Event, reqwest_eventsource::Error>,
+    ) -> Result<Self::StreamEvent> {
+        match event {
+            Ok(Event::Open) => Ok(types::ReplicateStreamEvent::Open),
+            Ok(Event::Message(msg)) => match msg.event.as_str() {
+                "output" => Ok(types::ReplicateStreamEvent::Output(msg.data)),
+                "error" => {
+                    #[derive(Deserialize)]
+                    struct ErrorData {
+                        detail: String,
+                    }
+                    let detail = serde_json::from_str::<ErrorData>(&msg.data)
+                        .map(|e| e.detail)
+                        .unwrap_or(msg.data);
+                    Ok(types::ReplicateStreamEvent::Error(detail))
+                }
+                "done" => {
+                    #[derive(Deserialize)]
+                    struct DoneData {
+                        #[serde(default)]
+                        reason: Option<String>,
+                    }
+                    let reason = serde_json::from_str::<DoneData>(&msg.data)
+                        .ok()
+                        .and_then(|d| d.reason)
+                        .filter(|r| !r.is_empty());
+                    Ok(types::ReplicateStreamEvent::Done { reason })
+                }
+                _ => Ok(types::ReplicateStreamEvent::NotSupported),
+            },
+            Err(e) => Err(sse_error(e)),
+        }
+    }
+
+    fn end_stream(event: &Self::StreamEvent) -> bool {
+        matches!(event, types::ReplicateStreamEvent::Done { .. })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::DynamicModel;
+
+    #[test]
+    fn test_path_depends_on_pinned_version() {
+        let replicate = Replicate::<DynamicModel>::model_name("meta/meta-llama-3-8b-instruct");
+        assert_eq!(
+            PollingClient::path(&replicate),
+            "models/meta/meta-llama-3-8b-instruct/predictions"
+        );
+
+        let replicate = Replicate::<DynamicModel>::model_name("acme/llama-ft:5c7d5dc6dd8b");
+        assert_eq!(PollingClient::path(&replicate), "predictions");
+        assert_eq!(
+            replicate.lm_options.model_version(),
+            ("acme/llama-ft", Some("5c7d5dc6dd8b"))
+        );
+    }
+
+    #[test]
+    fn test_prediction_is_settled() {
+        let prediction: types::Prediction = serde_json::from_value(serde_json::json!({
+            "id": "gm3qorzdhgbfurvjtvhg6dckhu",
+            "status": "processing",
+            "urls": {
+                "get": "https://api.replicate.com/v1/predictions/gm3qorzdhgbfurvjtvhg6dckhu",
+                "stream": "https://stream.replicate.com/v1/files/bcwr-3okdfv3o2wehstv5f2okyftwxy57hhypqsi6osiim5iaq5yzfp6a"
+            }
+        }))
+        .unwrap();
+        assert!(!Replicate::<DynamicModel>::is_settled(&prediction));
+        assert!(Replicate::<DynamicModel>::stream_url(&prediction).is_some());
+
+        let prediction = types::Prediction {
+            status: types::PredictionStatus::Failed,
+            ..prediction
+        };
+        assert!(Replicate::<DynamicModel>::is_settled(&prediction));
+    }
+}
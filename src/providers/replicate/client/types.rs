@@ -0,0 +1,105 @@
+//! Type definitions for the Replicate predictions API.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// A request creating a prediction.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct PredictionRequest {
+    /// The model version, only set when a specific version is requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    pub input: PredictionInput,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub stream: bool,
+}
+
+/// The inputs of a language model. Replicate language models share these names by
+/// convention, model specific inputs are flattened in from `extra`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct PredictionInput {
+    pub prompt: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub system_prompt: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_k: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u32>,
+    /// A comma separated list of sequences to stop generation at.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_sequences: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub presence_penalty: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frequency_penalty: Option<f32>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum PredictionStatus {
+    Starting,
+    Processing,
+    Succeeded,
+    Failed,
+    Canceled,
+    Aborted,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct PredictionUrls {
+    #[serde(default)]
+    pub get: Option<String>,
+    #[serde(default)]
+    pub cancel: Option<String>,
+    #[serde(default)]
+    pub stream: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct PredictionMetrics {
+    #[serde(default)]
+    pub input_token_count: Option<usize>,
+    #[serde(default)]
+    pub output_token_count: Option<usize>,
+}
+
+/// A prediction, as returned when it is created and each time it is polled.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct Prediction {
+    pub id: String,
+    pub status: PredictionStatus,
+    /// Language models output the generated tokens as an array of strings.
+    #[serde(default)]
+    pub output: Option<Value>,
+    #[serde(default)]
+    pub error: Option<Value>,
+    #[serde(default)]
+    pub urls: PredictionUrls,
+    #[serde(default)]
+    pub metrics: Option<PredictionMetrics>,
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub(crate) enum ReplicateStreamEvent {
+    Open,
+    /// Text generated by the model.
+    Output(String),
+    /// The prediction failed.
+    Error(String),
+    /// The prediction settled. The reason is set when it did not succeed, e.g.
+    /// `canceled`.
+    Done {
+        reason: Option<String>,
+    },
+    /// Events not used by the provider, such as `logs`.
+    NotSupported,
+}
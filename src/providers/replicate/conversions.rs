@@ -0,0 +1,180 @@
+//! Conversions between types used by the Replicate provider and the types used by the core library.
+use crate::core::language_model::{
+    FinishReason, LanguageModelOptions, LanguageModelResponse, LanguageModelResponseContentType,
+    Usage,
+};
+use crate::core::messages::Message;
+use crate::error::{Error, Result};
+use crate::providers::replicate::client::types::{
+    Prediction, PredictionInput, PredictionMetrics, PredictionStatus,
+};
+use serde_json::Value;
+
+impl From<LanguageModelOptions> for PredictionInput {
+    fn from(options: LanguageModelOptions) -> Self {
+        // Replicate language models take a single prompt, so system messages are
+        // merged into the system prompt and the turns are rendered into the prompt.
+        let mut system: Vec<String> = options.system.into_iter().collect();
+        let mut turns: Vec<(&str, String)> = Vec::new();
+        for tagged in options.messages {
+            match tagged.message {
+                Message::System(s) => system.push(s.content),
+                Message::Developer(d) => system.push(d),
+                Message::User(u) => turns.push(("User", u.content)),
+                Message::Assistant(a) => {
+                    if let LanguageModelResponseContentType::Text(text) = a.content {
+                        turns.push(("Assistant", text));
+                    }
+                }
+                Message::Tool(_) => {}
+            }
+        }
+
+        Self {
+            prompt: prompt(turns),
+            system_prompt: (!system.is_empty()).then(|| system.join("\n\n")),
+            max_tokens: options.max_output_tokens,
+            temperature: options.temperature.map(|t| t as f32 / 100.0),
+            top_p: options.top_p.map(|t| t as f32 / 100.0),
+            top_k: options.top_k,
+            seed: options.seed,
+            stop_sequences: options.stop_sequences.map(|s| s.join(",")),
+            presence_penalty: options.presence_penalty,
+            frequency_penalty: options.frequency_penalty,
+            extra: options.provider_options,
+        }
+    }
+}
+
+/// Renders the conversation as a prompt. A lone user message is sent as is, longer
+/// conversations are written out with role labels for the model's template to wrap.
+fn prompt(mut turns: Vec<(&str, String)>) -> String {
+    if let [("User", _)] = turns.as_slice() {
+        return turns.pop().map(|(_, text)| text).unwrap_or_default();
+    }
+    turns
+        .into_iter()
+        .map(|(role, text)| format!("{role}: {text}"))
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// Joins the output of a language model, which is an array of tokens, or a string for
+/// some models.
+pub(crate) fn output_text(output: &Value) -> String {
+    match output {
+        Value::String(text) => text.clone(),
+        Value::Array(tokens) => tokens.iter().filter_map(Value::as_str).collect(),
+        _ => String::new(),
+    }
+}
+
+pub(crate) fn usage(metrics: &PredictionMetrics) -> Option<Usage> {
+    if metrics.input_token_count.is_none() && metrics.output_token_count.is_none() {
+        return None;
+    }
+    Some(Usage {
+        input_tokens: metrics.input_token_count,
+        output_tokens: metrics.output_token_count,
+        reasoning_tokens: None,
+        cached_tokens: None,
+    })
+}
+
+impl TryFrom<Prediction> for LanguageModelResponse {
+    type Error = Error;
+
+    fn try_from(prediction: Prediction) -> Result<Self> {
+        match prediction.status {
+            PredictionStatus::Succeeded => Ok(LanguageModelResponse {
+                contents: vec![LanguageModelResponseContentType::Text(
+                    prediction
+                        .output
+                        .as_ref()
+                        .map(output_text)
+                        .unwrap_or_default(),
+                )],
+                usage: prediction.metrics.as_ref().and_then(usage),
+                stop_sequence: None,
+                finish_reason: Some(FinishReason::Stop),
+                logprobs: Vec::new(),
+                extensions: Default::default(),
+            }),
+            status => {
+                let details = match prediction.error {
+                    Some(Value::String(error)) => error,
+                    Some(error) => error.to_string(),
+                    None => format!("Prediction {} ended with status {status:?}", prediction.id),
+                };
+                Err(Error::ApiError {
+                    status_code: None,
+                    details,
+                })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::messages::{AssistantMessage, TaggedMessage};
+
+    #[test]
+    fn test_conversation_is_rendered_into_prompt() {
+        let options = LanguageModelOptions {
+            system: Some("Be brief.".to_string()),
+            messages: vec![
+                TaggedMessage::initial_step_msg(Message::User("Hi".into())),
+                TaggedMessage::initial_step_msg(Message::Assistant(AssistantMessage::from(
+                    "Hello!".to_string(),
+                ))),
+                TaggedMessage::initial_step_msg(Message::User("Who are you?".into())),
+            ],
+            stop_sequences: Some(vec!["</s>".to_string(), "User:".to_string()]),
+            ..Default::default()
+        };
+
+        let input = PredictionInput::from(options);
+        assert_eq!(
+            input.prompt,
+            "User: Hi\n\nAssistant: Hello!\n\nUser: Who are you?"
+        );
+        assert_eq!(input.system_prompt.as_deref(), Some("Be brief."));
+        assert_eq!(input.stop_sequences.as_deref(), Some("</s>,User:"));
+
+        let options = LanguageModelOptions {
+            messages: vec![TaggedMessage::initial_step_msg(Message::User("Hi".into()))],
+            ..Default::default()
+        };
+        assert_eq!(PredictionInput::from(options).prompt, "Hi");
+    }
+
+    #[test]
+    fn test_response_from_prediction() {
+        let prediction: Prediction = serde_json::from_value(serde_json::json!({
+            "id": "p1",
+            "status": "succeeded",
+            "output": ["The", " sky", " is", " blue."],
+            "metrics": { "input_token_count": 12, "output_token_count": 4, "predict_time": 0.4 }
+        }))
+        .unwrap();
+        let response = LanguageModelResponse::try_from(prediction).unwrap();
+        assert!(matches!(
+            &response.contents[..],
+            [LanguageModelResponseContentType::Text(text)] if text == "The sky is blue."
+        ));
+        assert_eq!(response.usage.unwrap().output_tokens, Some(4));
+
+        let prediction: Prediction = serde_json::from_value(serde_json::json!({
+            "id": "p2",
+            "status": "failed",
+            "error": "CUDA out of memory"
+        }))
+        .unwrap();
+        assert!(matches!(
+            LanguageModelResponse::try_from(prediction),
+            Err(Error::ApiError { details, .. }) if details == "CUDA out of memory"
+        ));
+    }
+}
@@ -0,0 +1,109 @@
+//! Language model implementation for the Replicate provider.
+use crate::core::capabilities::ModelName;
+use crate::core::client::PollingClient;
+use crate::core::language_model::{
+    FinishReason, LanguageModel, LanguageModelOptions, LanguageModelResponse,
+    LanguageModelResponseContentType, LanguageModelStreamChunk, LanguageModelStreamChunkType,
+    ProviderStream,
+};
+use crate::core::messages::AssistantMessage;
+use crate::error::Result;
+use crate::providers::replicate::Replicate;
+use crate::providers::replicate::client::types::{self, ReplicateStreamEvent};
+use async_trait::async_trait;
+use futures::StreamExt;
+
+impl<M: ModelName> Replicate<M> {
+    /// Builds the prediction request for `options`, applying the provider settings.
+    pub(crate) fn prediction_request(
+        &self,
+        options: LanguageModelOptions,
+        stream: bool,
+    ) -> types::PredictionRequest {
+        let mut input: types::PredictionInput = options.into();
+        let mut extra = self.settings.input.clone();
+        extra.extend(std::mem::take(&mut input.extra));
+        input.extra = extra;
+        types::PredictionRequest {
+            version: self.lm_options.model_version().1.map(str::to_string),
+            input,
+            stream,
+        }
+    }
+}
+
+#[async_trait]
+impl<M: ModelName> LanguageModel for Replicate<M> {
+    fn name(&self) -> String {
+        self.lm_options.model.clone()
+    }
+
+    fn capabilities(&self) -> crate::core::capabilities::Capabilities {
+        M::CAPABILITIES
+    }
+
+    fn info(&self) -> crate::core::capabilities::ModelInfo {
+        Self::model_info(&self.name())
+    }
+
+    async fn generate_text(
+        &mut self,
+        options: LanguageModelOptions,
+    ) -> Result<LanguageModelResponse> {
+        self.lm_options.request = Some(self.prediction_request(options, false));
+
+        let prediction = self.send_and_poll(&self.settings.base_url).await?;
+
+        prediction.try_into()
+    }
+
+    /// Streams the output of the prediction as it is generated. Replicate does not
+    /// report token usage on the stream, so streamed responses carry no usage.
+    async fn stream_text(&mut self, options: LanguageModelOptions) -> Result<ProviderStream> {
+        self.lm_options.request = Some(self.prediction_request(options, true));
+
+        let replicate_stream = self.send_and_stream(&self.settings.base_url).await?;
+
+        let stream = replicate_stream.scan(String::new(), |text, evt_res| {
+            futures::future::ready(match evt_res {
+                Ok(event) => {
+                    let chunks = match event {
+                        ReplicateStreamEvent::Open => vec![LanguageModelStreamChunk::Delta(
+                            LanguageModelStreamChunkType::Start,
+                        )],
+                        ReplicateStreamEvent::Output(delta) => {
+                            text.push_str(&delta);
+                            vec![LanguageModelStreamChunk::Delta(
+                                LanguageModelStreamChunkType::Text(delta),
+                            )]
+                        }
+                        ReplicateStreamEvent::Error(error) => {
+                            vec![LanguageModelStreamChunk::Delta(
+                                LanguageModelStreamChunkType::Failed(error),
+                            )]
+                        }
+                        ReplicateStreamEvent::Done {
+                            reason: Some(reason),
+                        } => vec![LanguageModelStreamChunk::Delta(
+                            LanguageModelStreamChunkType::Incomplete(reason),
+                        )],
+                        ReplicateStreamEvent::Done { reason: None } => vec![
+                            LanguageModelStreamChunk::FinishReason(FinishReason::Stop),
+                            LanguageModelStreamChunk::Done(AssistantMessage {
+                                content: LanguageModelResponseContentType::Text(std::mem::take(
+                                    text,
+                                )),
+                                usage: None,
+                            }),
+                        ],
+                        ReplicateStreamEvent::NotSupported => Vec::new(),
+                    };
+                    Some(Ok(chunks))
+                }
+                Err(e) => Some(Err(e)),
+            })
+        });
+
+        Ok(Box::pin(stream))
+    }
+}
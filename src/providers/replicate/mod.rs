@@ -0,0 +1,242 @@
+//! This module provides the Replicate provider, which implements the `LanguageModel`
+//! and `Provider` traits for language models hosted on Replicate.
+//!
+//! Unlike other providers, Replicate does not answer a request directly. Each request
+//! creates a prediction, which is polled until it settles, or whose output is streamed
+//! from a separate server-sent events URL while it runs.
+
+pub mod capabilities;
+pub mod client;
+pub mod conversions;
+pub mod language_model;
+pub mod settings;
+
+use crate::core::DynamicModel;
+use crate::core::capabilities::ModelName;
+use crate::core::client::RetryPolicy;
+use crate::core::utils::validate_base_url;
+use crate::error::{Error, Result};
+use crate::providers::replicate::client::ReplicateOptions;
+use crate::providers::replicate::settings::ReplicateProviderSettings;
+use serde::Serialize;
+use serde_json::Value;
+use std::time::Duration;
+
+/// The Replicate provider.
+#[derive(Debug, Serialize, Clone)]
+pub struct Replicate<M: ModelName> {
+    /// Configuration settings for the Replicate provider.
+    pub settings: ReplicateProviderSettings,
+    pub(crate) lm_options: ReplicateOptions,
+    _phantom: std::marker::PhantomData<M>,
+}
+
+impl<M: ModelName> Replicate<M> {
+    /// Replicate provider setting builder.
+    pub fn builder() -> ReplicateBuilder<M> {
+        ReplicateBuilder::default()
+    }
+
+    fn with_model(settings: ReplicateProviderSettings, model_name: String) -> Self {
+        let options = ReplicateOptions::builder()
+            .model(model_name)
+            .build()
+            .unwrap();
+
+        Self {
+            settings,
+            lm_options: options,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl Replicate<DynamicModel> {
+    /// Creates a Replicate provider with a dynamic model name using default settings.
+    ///
+    /// This allows you to specify the model name as a string rather than
+    /// using methods like `Replicate::meta_meta_llama_3_70b_instruct()`, etc.
+    ///
+    /// **WARNING**: when using `DynamicModel`, model capabilities are not validated.
+    /// This means there is no compile-time guarantee that the model supports requested features.
+    ///
+    /// For custom configuration (API token, model inputs, etc.), use the builder pattern:
+    /// `Replicate::<DynamicModel>::builder().model_name(...).api_key(...).build()`
+    ///
+    /// # Parameters
+    ///
+    /// * `model_name` - The Replicate model, as `owner/name` to run its latest version
+    ///   or `owner/name:version` to pin a version
+    ///
+    /// # Returns
+    ///
+    /// A configured `Replicate<DynamicModel>` provider instance with default settings.
+    pub fn model_name(name: impl Into<String>) -> Self {
+        Self::with_model(ReplicateProviderSettings::default(), name.into())
+    }
+}
+
+impl<M: ModelName> Default for Replicate<M> {
+    /// Creates a new Replicate provider with default settings.
+    fn default() -> Self {
+        Self::with_model(
+            ReplicateProviderSettings::default(),
+            M::MODEL_NAME.to_string(),
+        )
+    }
+}
+
+/// Replicate Provider Builder
+pub struct ReplicateBuilder<M: ModelName> {
+    settings: ReplicateProviderSettings,
+    model_name: String,
+    _phantom: std::marker::PhantomData<M>,
+}
+
+impl<M: ModelName> Default for ReplicateBuilder<M> {
+    /// Creates a new Replicate provider builder with default settings.
+    fn default() -> Self {
+        Self {
+            settings: ReplicateProviderSettings::default(),
+            model_name: M::MODEL_NAME.to_string(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl ReplicateBuilder<DynamicModel> {
+    /// Sets the model name from a string. e.g., "meta/meta-llama-3-70b-instruct"
+    ///
+    /// **WARNING**: when using `DynamicModel`, model capabilities are not validated.
+    /// This means there is no compile-time guarantee that the model supports requested features.
+    ///
+    /// # Parameters
+    ///
+    /// * `model_name` - The Replicate model, as `owner/name` or `owner/name:version`.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the model name set.
+    pub fn model_name(mut self, model_name: impl Into<String>) -> Self {
+        self.model_name = model_name.into();
+        self
+    }
+}
+
+impl<M: ModelName> ReplicateBuilder<M> {
+    /// Sets the base URL for the Replicate API.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.settings.base_url = base_url.into();
+        self
+    }
+
+    /// Sets the API token for the Replicate API.
+    pub fn api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.settings.api_key = api_key.into();
+        self
+    }
+
+    /// Adds a model specific input sent with every prediction, e.g. `prompt_template`
+    /// or `min_tokens`.
+    pub fn input(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.settings.input.insert(key.into(), value.into());
+        self
+    }
+
+    /// Sets the time waited between two polls of a pending prediction. Defaults to one
+    /// second.
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.settings.poll_interval = poll_interval;
+        self
+    }
+
+    /// Sets the retry policy applied to requests made by this provider.
+    ///
+    /// # Parameters
+    ///
+    /// * `retry_policy` - The [`RetryPolicy`] to use for retryable failures.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the retry policy set.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.settings.retry_policy = retry_policy;
+        self
+    }
+
+    crate::core::client::transport_builder_methods!();
+
+    /// Sets the name of the provider. Defaults to "replicate".
+    pub fn provider_name(mut self, provider_name: impl Into<String>) -> Self {
+        self.settings.provider_name = provider_name.into();
+        self
+    }
+
+    /// Builds the Replicate provider.
+    pub fn build(self) -> Result<Replicate<M>> {
+        // validate base url
+        let base_url = validate_base_url(&self.settings.base_url)?;
+
+        // check api key exists
+        if self.settings.api_key.is_empty() {
+            return Err(Error::MissingField("api_key".to_string()));
+        }
+
+        if self.model_name.is_empty() {
+            return Err(Error::MissingField("model_name".to_string()));
+        }
+
+        Ok(Replicate::with_model(
+            ReplicateProviderSettings {
+                base_url,
+                ..self.settings
+            },
+            self.model_name,
+        ))
+    }
+}
+
+// Re-exports for convenience
+pub use capabilities::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::language_model::LanguageModelOptions;
+
+    #[test]
+    fn test_prediction_request_includes_model_inputs() {
+        let replicate = Replicate::<DynamicModel>::builder()
+            .model_name("acme/llama-ft:5c7d5dc6dd8b")
+            .api_key("token")
+            .input("prompt_template", "<s>[INST] {prompt} [/INST]")
+            .build()
+            .unwrap();
+
+        let request = replicate.prediction_request(
+            LanguageModelOptions {
+                max_output_tokens: Some(256),
+                ..Default::default()
+            },
+            true,
+        );
+        let body = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(body["version"], "5c7d5dc6dd8b");
+        assert_eq!(body["stream"], true);
+        assert_eq!(body["input"]["max_tokens"], 256);
+        assert_eq!(
+            body["input"]["prompt_template"],
+            "<s>[INST] {prompt} [/INST]"
+        );
+    }
+
+    #[test]
+    fn test_builder_requires_api_key() {
+        let result = Replicate::<DynamicModel>::builder()
+            .model_name("meta/meta-llama-3-8b-instruct")
+            .api_key("")
+            .build();
+        assert!(matches!(result, Err(Error::MissingField(field)) if field == "api_key"));
+    }
+}
@@ -0,0 +1,70 @@
+//! Defines the settings for the Replicate provider.
+
+use crate::core::client::{RetryPolicy, TransportSettings};
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::time::Duration;
+
+/// Settings for the Replicate provider.
+#[derive(Clone, Serialize, Deserialize, Builder)]
+#[builder(setter(into), default)]
+pub struct ReplicateProviderSettings {
+    /// The name of the provider.
+    pub provider_name: String,
+
+    /// The base URL of the Replicate API.
+    pub base_url: String,
+
+    /// The API token for the Replicate API.
+    pub api_key: String,
+
+    /// Model specific inputs sent with every prediction, e.g. `prompt_template`.
+    pub input: Map<String, Value>,
+
+    /// The time waited between two polls of a pending prediction.
+    pub poll_interval: Duration,
+
+    /// The retry policy applied to requests.
+    pub retry_policy: RetryPolicy,
+
+    /// The HTTP client, middleware and rate limiter requests go through.
+    #[serde(skip)]
+    pub transport: TransportSettings,
+}
+
+impl std::fmt::Debug for ReplicateProviderSettings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ReplicateProviderSettings")
+            .field("provider_name", &self.provider_name)
+            .field("base_url", &self.base_url)
+            .field("api_key", &"<redacted>")
+            .field("input", &self.input)
+            .field("poll_interval", &self.poll_interval)
+            .field("retry_policy", &self.retry_policy)
+            .field("transport", &self.transport)
+            .finish()
+    }
+}
+
+impl Default for ReplicateProviderSettings {
+    /// Returns the default settings for the Replicate provider.
+    fn default() -> Self {
+        Self {
+            provider_name: "replicate".to_string(),
+            base_url: "https://api.replicate.com/v1/".to_string(),
+            api_key: std::env::var("REPLICATE_API_TOKEN").unwrap_or_default(),
+            input: Map::new(),
+            poll_interval: Duration::from_secs(1),
+            retry_policy: RetryPolicy::default(),
+            transport: TransportSettings::default(),
+        }
+    }
+}
+
+impl ReplicateProviderSettings {
+    /// Creates a new builder for `ReplicateProviderSettings`.
+    pub fn builder() -> ReplicateProviderSettingsBuilder {
+        ReplicateProviderSettingsBuilder::default()
+    }
+}
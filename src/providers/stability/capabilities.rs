@@ -0,0 +1,45 @@
+//! Capabilities for Stability AI models.
+//!
+//! This module defines model types for Stability AI's image generation models. Any
+//! other model can be used through `Stability::model_name`. Users can implement
+//! additional traits on custom models.
+
+use crate::core::capabilities::*;
+use crate::model_capabilities;
+use crate::providers::stability::Stability;
+
+model_capabilities! {
+    provider: Stability,
+    models: {
+        Sd35Large {
+            model_name: "sd3.5-large",
+            constructor_name: sd3_5_large,
+            display_name: "Stable Diffusion 3.5 Large",
+            capabilities: [ImageOutputSupport, TextInputSupport]
+        },
+        Sd35LargeTurbo {
+            model_name: "sd3.5-large-turbo",
+            constructor_name: sd3_5_large_turbo,
+            display_name: "Stable Diffusion 3.5 Large Turbo",
+            capabilities: [ImageOutputSupport, TextInputSupport]
+        },
+        Sd35Medium {
+            model_name: "sd3.5-medium",
+            constructor_name: sd3_5_medium,
+            display_name: "Stable Diffusion 3.5 Medium",
+            capabilities: [ImageOutputSupport, TextInputSupport]
+        },
+        StableImageCore {
+            model_name: "stable-image-core",
+            constructor_name: stable_image_core,
+            display_name: "Stable Image Core",
+            capabilities: [ImageOutputSupport, TextInputSupport]
+        },
+        StableImageUltra {
+            model_name: "stable-image-ultra",
+            constructor_name: stable_image_ultra,
+            display_name: "Stable Image Ultra",
+            capabilities: [ImageOutputSupport, TextInputSupport]
+        }
+    }
+}
@@ -0,0 +1,73 @@
+//! Client implementation for the Stability AI provider.
+use crate::core::client::{ImageClient, RetryPolicy};
+use crate::providers::stability::settings::StabilityResponseMode;
+use crate::providers::stability::{ModelName, Stability};
+use reqwest::header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE};
+use serde::Serialize;
+
+pub(crate) mod types;
+
+#[derive(Debug, Default, Clone, Serialize)]
+pub(crate) struct StabilityImageOptions {
+    pub(crate) model: String,
+    pub(crate) content_type: String,
+    #[serde(skip)]
+    pub(crate) form: Vec<u8>,
+}
+
+impl StabilityImageOptions {
+    /// Returns the generation endpoint of the model, and the `model` form field for
+    /// endpoints that serve several models.
+    pub(crate) fn endpoint(&self) -> (String, Option<&str>) {
+        if self.model.starts_with("sd3") {
+            return ("stable-image/generate/sd3".to_string(), Some(&self.model));
+        }
+        let name = self
+            .model
+            .strip_prefix("stable-image-")
+            .unwrap_or(&self.model);
+        (format!("stable-image/generate/{name}"), None)
+    }
+}
+
+impl<M: ModelName> ImageClient for Stability<M> {
+    fn path(&self) -> String {
+        self.image_options.endpoint().0
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn headers(&self) -> reqwest::header::HeaderMap {
+        let mut headers = reqwest::header::HeaderMap::new();
+        if let Ok(value) = self.image_options.content_type.parse() {
+            headers.insert(CONTENT_TYPE, value);
+        }
+        let accept = match self.settings.response_mode {
+            StabilityResponseMode::Binary => "image/*",
+            StabilityResponseMode::Json => "application/json",
+        };
+        headers.insert(ACCEPT, accept.parse().unwrap());
+        if let Ok(value) = format!("Bearer {}", self.settings.api_key).parse() {
+            headers.insert(AUTHORIZATION, value);
+        }
+        headers
+    }
+
+    fn query_params(&self) -> Vec<(&str, &str)> {
+        Vec::new()
+    }
+
+    fn retry_policy(&self) -> RetryPolicy {
+        self.settings.retry_policy.clone()
+    }
+
+    fn transport(&self) -> crate::core::client::TransportSettings {
+        self.settings.transport.clone()
+    }
+
+    fn body(&self) -> reqwest::Body {
+        reqwest::Body::from(self.image_options.form.clone())
+    }
+}
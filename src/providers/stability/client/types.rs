@@ -0,0 +1,13 @@
+//! Response types for the Stability AI image generation API.
+
+use serde::{Deserialize, Serialize};
+
+/// The response of a request made with `Accept: application/json`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct GenerateResponse {
+    pub(crate) image: String,
+    #[serde(default)]
+    pub(crate) seed: Option<u64>,
+    #[serde(default)]
+    pub(crate) finish_reason: Option<String>,
+}
@@ -0,0 +1,102 @@
+//! This module provides conversions between the core image types and the Stability AI
+//! API types.
+
+use crate::core::image_model::{GeneratedImage, ImageData, ImageModelResponse};
+use crate::error::{Error, Result};
+use crate::providers::stability::client::types::GenerateResponse;
+use crate::providers::stability::settings::{StabilityOutputFormat, StabilityResponseMode};
+use reqwest::header::{CONTENT_TYPE, HeaderMap};
+
+/// Builds the response from the body and headers of a generation request.
+///
+/// Binary responses carry the seed in a `seed` header, JSON responses in the body.
+pub(crate) fn response_from(
+    body: Vec<u8>,
+    headers: &HeaderMap,
+    mode: StabilityResponseMode,
+    format: StabilityOutputFormat,
+) -> Result<ImageModelResponse> {
+    let image = match mode {
+        StabilityResponseMode::Binary => GeneratedImage {
+            media_type: headers
+                .get(CONTENT_TYPE)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string)
+                .or_else(|| Some(format.media_type().to_string())),
+            seed: headers
+                .get("seed")
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.parse().ok()),
+            data: ImageData::Bytes(body),
+            revised_prompt: None,
+        },
+        StabilityResponseMode::Json => {
+            let response: GenerateResponse =
+                serde_json::from_slice(&body).map_err(|e| Error::ApiError {
+                    status_code: None,
+                    details: format!("Failed to parse response: {e}"),
+                })?;
+            GeneratedImage {
+                data: ImageData::Base64(response.image),
+                media_type: Some(format.media_type().to_string()),
+                seed: response.seed,
+                revised_prompt: None,
+            }
+        }
+    };
+
+    Ok(ImageModelResponse {
+        images: vec![image],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_binary_response() {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, "image/webp".parse().unwrap());
+        headers.insert("seed", "42".parse().unwrap());
+
+        let response = response_from(
+            b"RIFF".to_vec(),
+            &headers,
+            StabilityResponseMode::Binary,
+            StabilityOutputFormat::Webp,
+        )
+        .unwrap();
+        assert_eq!(
+            response.images,
+            vec![GeneratedImage {
+                data: ImageData::Bytes(b"RIFF".to_vec()),
+                media_type: Some("image/webp".to_string()),
+                seed: Some(42),
+                revised_prompt: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_json_response() {
+        let body = br#"{"image":"aGVsbG8=","finish_reason":"SUCCESS","seed":7}"#.to_vec();
+
+        let response = response_from(
+            body,
+            &HeaderMap::new(),
+            StabilityResponseMode::Json,
+            StabilityOutputFormat::Jpeg,
+        )
+        .unwrap();
+        assert_eq!(
+            response.images,
+            vec![GeneratedImage {
+                data: ImageData::Base64("aGVsbG8=".to_string()),
+                media_type: Some("image/jpeg".to_string()),
+                seed: Some(7),
+                revised_prompt: None,
+            }]
+        );
+    }
+}
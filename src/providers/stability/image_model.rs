@@ -0,0 +1,58 @@
+//! Image model implementation for the Stability AI provider.
+
+use crate::{
+    core::{
+        capabilities::ModelName,
+        client::ImageClient,
+        image_model::{ImageGenerationModel, ImageModelOptions, ImageModelResponse},
+        utils::MultipartForm,
+    },
+    error::Result,
+    providers::stability::{Stability, conversions::response_from},
+};
+use async_trait::async_trait;
+
+impl<M: ModelName> Stability<M> {
+    /// Returns a copy of the provider prepared to send `input` as a multipart form.
+    pub(crate) fn with_request(&self, input: ImageModelOptions) -> Self {
+        let mut model = self.clone();
+
+        let mut form = MultipartForm::new().text("prompt", &input.prompt);
+        if let (_, Some(name)) = model.image_options.endpoint() {
+            form = form.text("model", name);
+        }
+        if let Some(negative_prompt) = &input.negative_prompt {
+            form = form.text("negative_prompt", negative_prompt);
+        }
+        if let Some(aspect_ratio) = input.aspect_ratio {
+            form = form.text("aspect_ratio", aspect_ratio.to_string());
+        }
+        if let Some(seed) = input.seed {
+            form = form.text("seed", seed.to_string());
+        }
+        if let Some(style_preset) = &model.settings.style_preset {
+            form = form.text("style_preset", style_preset);
+        }
+        form = form.text("output_format", model.settings.output_format.as_str());
+
+        model.image_options.content_type = form.content_type();
+        model.image_options.form = form.finish();
+        model
+    }
+}
+
+#[async_trait]
+impl<M: ModelName> ImageGenerationModel for Stability<M> {
+    async fn generate_image(&self, input: ImageModelOptions) -> Result<ImageModelResponse> {
+        let model = self.with_request(input);
+
+        let (body, headers) = model.send(&model.settings.base_url).await?;
+
+        response_from(
+            body,
+            &headers,
+            model.settings.response_mode,
+            model.settings.output_format,
+        )
+    }
+}
@@ -0,0 +1,261 @@
+//! This module provides the Stability AI provider, which implements the
+//! `ImageGenerationModel` trait for Stability AI's Stable Image and Stable Diffusion
+//! models.
+//!
+//! Requests are sent as multipart forms to the `/stable-image/generate` endpoints.
+//! Images are returned either as the encoded image or as base64 encoded JSON,
+//! depending on the configured [`StabilityResponseMode`].
+
+pub mod capabilities;
+pub mod client;
+pub mod conversions;
+pub mod image_model;
+pub mod settings;
+
+use crate::core::DynamicModel;
+use crate::core::capabilities::ModelName;
+use crate::core::client::RetryPolicy;
+use crate::core::utils::validate_base_url;
+use crate::error::{Error, Result};
+use crate::providers::stability::client::StabilityImageOptions;
+use crate::providers::stability::settings::StabilityProviderSettings;
+use serde::Serialize;
+
+pub use settings::{StabilityOutputFormat, StabilityResponseMode};
+
+/// The Stability AI provider.
+#[derive(Debug, Serialize, Clone)]
+pub struct Stability<M: ModelName> {
+    /// Configuration settings for the Stability AI provider.
+    pub settings: StabilityProviderSettings,
+    pub(crate) image_options: StabilityImageOptions,
+    _phantom: std::marker::PhantomData<M>,
+}
+
+impl<M: ModelName> Stability<M> {
+    /// Stability AI provider setting builder.
+    pub fn builder() -> StabilityBuilder<M> {
+        StabilityBuilder::default()
+    }
+
+    fn with_model(settings: StabilityProviderSettings, model_name: String) -> Self {
+        let image_options = StabilityImageOptions {
+            model: model_name,
+            ..Default::default()
+        };
+
+        Self {
+            settings,
+            image_options,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl Stability<DynamicModel> {
+    /// Creates a Stability AI provider with a dynamic model name using default settings.
+    ///
+    /// This allows you to specify the model name as a string rather than
+    /// using methods like `Stability::stable_image_core()`.
+    ///
+    /// **WARNING**: when using `DynamicModel`, model capabilities are not validated.
+    /// This means there is no compile-time guarantee that the model supports requested features.
+    ///
+    /// For custom configuration (API key, output format, etc.), use the builder pattern:
+    /// `Stability::<DynamicModel>::builder().model_name(...).api_key(...).build()`
+    ///
+    /// # Parameters
+    ///
+    /// * `model_name` - The Stability AI model identifier (e.g., "stable-image-core",
+    ///   "sd3.5-large")
+    ///
+    /// # Returns
+    ///
+    /// A configured `Stability<DynamicModel>` provider instance with default settings.
+    pub fn model_name(name: impl Into<String>) -> Self {
+        Self::with_model(StabilityProviderSettings::default(), name.into())
+    }
+}
+
+impl<M: ModelName> Default for Stability<M> {
+    /// Creates a new Stability AI provider with default settings.
+    fn default() -> Self {
+        Self::with_model(
+            StabilityProviderSettings::default(),
+            M::MODEL_NAME.to_string(),
+        )
+    }
+}
+
+/// Stability AI Provider Builder
+pub struct StabilityBuilder<M: ModelName> {
+    settings: StabilityProviderSettings,
+    model_name: String,
+    _phantom: std::marker::PhantomData<M>,
+}
+
+impl<M: ModelName> Default for StabilityBuilder<M> {
+    /// Creates a new Stability AI provider builder with default settings.
+    fn default() -> Self {
+        Self {
+            settings: StabilityProviderSettings::default(),
+            model_name: M::MODEL_NAME.to_string(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl StabilityBuilder<DynamicModel> {
+    /// Sets the model name from a string. e.g., "stable-image-core", "sd3.5-large"
+    ///
+    /// **WARNING**: when using `DynamicModel`, model capabilities are not validated.
+    /// This means there is no compile-time guarantee that the model supports requested features.
+    ///
+    /// # Parameters
+    ///
+    /// * `model_name` - The Stability AI model identifier.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the model name set.
+    pub fn model_name(mut self, model_name: impl Into<String>) -> Self {
+        self.model_name = model_name.into();
+        self
+    }
+}
+
+impl<M: ModelName> StabilityBuilder<M> {
+    /// Sets the base URL for the Stability AI API.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.settings.base_url = base_url.into();
+        self
+    }
+
+    /// Sets the API key for the Stability AI API.
+    pub fn api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.settings.api_key = api_key.into();
+        self
+    }
+
+    /// Sets the format of generated images. Defaults to PNG.
+    pub fn output_format(mut self, output_format: StabilityOutputFormat) -> Self {
+        self.settings.output_format = output_format;
+        self
+    }
+
+    /// Sets how generated images are returned. Defaults to
+    /// [`StabilityResponseMode::Binary`].
+    pub fn response_mode(mut self, response_mode: StabilityResponseMode) -> Self {
+        self.settings.response_mode = response_mode;
+        self
+    }
+
+    /// Sets a style the image is guided towards, e.g. `photographic` or `anime`. Only
+    /// supported by Stable Image Core.
+    pub fn style_preset(mut self, style_preset: impl Into<String>) -> Self {
+        self.settings.style_preset = Some(style_preset.into());
+        self
+    }
+
+    /// Sets the retry policy applied to requests made by this provider.
+    ///
+    /// # Parameters
+    ///
+    /// * `retry_policy` - The [`RetryPolicy`] to use for retryable failures.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the retry policy set.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.settings.retry_policy = retry_policy;
+        self
+    }
+
+    crate::core::client::transport_builder_methods!();
+
+    /// Sets the name of the provider. Defaults to "stability".
+    pub fn provider_name(mut self, provider_name: impl Into<String>) -> Self {
+        self.settings.provider_name = provider_name.into();
+        self
+    }
+
+    /// Builds the Stability AI provider.
+    pub fn build(self) -> Result<Stability<M>> {
+        // validate base url
+        let base_url = validate_base_url(&self.settings.base_url)?;
+
+        // check api key exists
+        if self.settings.api_key.is_empty() {
+            return Err(Error::MissingField("api_key".to_string()));
+        }
+
+        if self.model_name.is_empty() {
+            return Err(Error::MissingField("model_name".to_string()));
+        }
+
+        Ok(Stability::with_model(
+            StabilityProviderSettings {
+                base_url,
+                ..self.settings
+            },
+            self.model_name,
+        ))
+    }
+}
+
+// Re-exports for convenience
+pub use capabilities::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::client::ImageClient;
+    use crate::core::image_model::{AspectRatio, ImageModelOptions};
+
+    #[test]
+    fn test_request_is_multipart_form() {
+        let stability = Stability::<DynamicModel>::builder()
+            .model_name("sd3.5-large")
+            .api_key("key")
+            .output_format(StabilityOutputFormat::Webp)
+            .build()
+            .unwrap();
+        let options = ImageModelOptions::builder()
+            .prompt("a lighthouse at dusk")
+            .negative_prompt("people".to_string())
+            .aspect_ratio(AspectRatio::new(16, 9))
+            .seed(42u64)
+            .build()
+            .unwrap();
+        let model = stability.with_request(options);
+
+        assert_eq!(ImageClient::path(&model), "stable-image/generate/sd3");
+        assert!(
+            model
+                .image_options
+                .content_type
+                .starts_with("multipart/form-data; boundary=")
+        );
+        let form = String::from_utf8(model.image_options.form.clone()).unwrap();
+        for (name, value) in [
+            ("prompt", "a lighthouse at dusk"),
+            ("model", "sd3.5-large"),
+            ("negative_prompt", "people"),
+            ("aspect_ratio", "16:9"),
+            ("seed", "42"),
+            ("output_format", "webp"),
+        ] {
+            assert!(form.contains(&format!("name=\"{name}\"\r\n\r\n{value}\r\n")));
+        }
+    }
+
+    #[test]
+    fn test_stable_image_endpoints() {
+        let core = Stability::stable_image_core();
+        assert_eq!(ImageClient::path(&core), "stable-image/generate/core");
+        assert_eq!(core.image_options.endpoint().1, None);
+
+        let ultra = Stability::stable_image_ultra();
+        assert_eq!(ImageClient::path(&ultra), "stable-image/generate/ultra");
+    }
+}
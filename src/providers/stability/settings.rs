@@ -0,0 +1,119 @@
+//! Defines the settings for the Stability AI provider.
+
+use crate::core::client::{RetryPolicy, TransportSettings};
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+/// The format of generated images.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StabilityOutputFormat {
+    /// PNG, the default.
+    #[default]
+    Png,
+    /// JPEG.
+    Jpeg,
+    /// WebP.
+    Webp,
+}
+
+impl StabilityOutputFormat {
+    /// Returns the value sent in requests.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Png => "png",
+            Self::Jpeg => "jpeg",
+            Self::Webp => "webp",
+        }
+    }
+
+    /// Returns the media type of images in this format.
+    pub fn media_type(&self) -> &'static str {
+        match self {
+            Self::Png => "image/png",
+            Self::Jpeg => "image/jpeg",
+            Self::Webp => "image/webp",
+        }
+    }
+}
+
+/// How generated images are returned.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StabilityResponseMode {
+    /// The response body is the encoded image, returned as
+    /// [`ImageData::Bytes`](crate::core::image_model::ImageData::Bytes).
+    #[default]
+    Binary,
+    /// The response body is JSON with the base64 encoded image, returned as
+    /// [`ImageData::Base64`](crate::core::image_model::ImageData::Base64).
+    Json,
+}
+
+/// Settings for the Stability AI provider.
+#[derive(Clone, Serialize, Deserialize, Builder)]
+#[builder(setter(into), default)]
+pub struct StabilityProviderSettings {
+    /// The name of the provider.
+    pub provider_name: String,
+
+    /// The base URL of the Stability AI API.
+    pub base_url: String,
+
+    /// The API key for the Stability AI API.
+    pub api_key: String,
+
+    /// The format of generated images.
+    pub output_format: StabilityOutputFormat,
+
+    /// How generated images are returned.
+    pub response_mode: StabilityResponseMode,
+
+    /// A style the image is guided towards, e.g. `photographic` or `anime`. Only
+    /// supported by Stable Image Core.
+    pub style_preset: Option<String>,
+
+    /// The retry policy applied to requests.
+    pub retry_policy: RetryPolicy,
+
+    /// The HTTP client, middleware and rate limiter requests go through.
+    #[serde(skip)]
+    pub transport: TransportSettings,
+}
+
+impl std::fmt::Debug for StabilityProviderSettings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StabilityProviderSettings")
+            .field("provider_name", &self.provider_name)
+            .field("base_url", &self.base_url)
+            .field("api_key", &"<redacted>")
+            .field("output_format", &self.output_format)
+            .field("response_mode", &self.response_mode)
+            .field("style_preset", &self.style_preset)
+            .field("retry_policy", &self.retry_policy)
+            .field("transport", &self.transport)
+            .finish()
+    }
+}
+
+impl Default for StabilityProviderSettings {
+    /// Returns the default settings for the Stability AI provider.
+    fn default() -> Self {
+        Self {
+            provider_name: "stability".to_string(),
+            base_url: "https://api.stability.ai/v2beta/".to_string(),
+            api_key: std::env::var("STABILITY_API_KEY").unwrap_or_default(),
+            output_format: StabilityOutputFormat::default(),
+            response_mode: StabilityResponseMode::default(),
+            style_preset: None,
+            retry_policy: RetryPolicy::default(),
+            transport: TransportSettings::default(),
+        }
+    }
+}
+
+impl StabilityProviderSettings {
+    /// Creates a new builder for `StabilityProviderSettings`.
+    pub fn builder() -> StabilityProviderSettingsBuilder {
+        StabilityProviderSettingsBuilder::default()
+    }
+}
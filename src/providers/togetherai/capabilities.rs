@@ -10,6 +10,36 @@ use crate::providers::togetherai::TogetherAI;
 model_capabilities! {
     provider: TogetherAI,
     models: {
+        QwenQwq32b {
+            model_name: "Qwen/QwQ-32B",
+            constructor_name: qwen_qwq_32b,
+            display_name: "QwQ 32B",
+            capabilities: [ReasoningSupport, TextInputSupport, TextOutputSupport]
+        },
+        QwenQwen2572bInstructTurbo {
+            model_name: "Qwen/Qwen2.5-72B-Instruct-Turbo",
+            constructor_name: qwen_qwen2_5_72b_instruct_turbo,
+            display_name: "Qwen2.5 72B Instruct Turbo",
+            capabilities: [TextInputSupport, TextOutputSupport, ToolCallSupport]
+        },
+        QwenQwen25Coder32bInstruct {
+            model_name: "Qwen/Qwen2.5-Coder-32B-Instruct",
+            constructor_name: qwen_qwen2_5_coder_32b_instruct,
+            display_name: "Qwen2.5 Coder 32B Instruct",
+            capabilities: [TextInputSupport, TextOutputSupport]
+        },
+        QwenQwen3235bA22bInstruct2507Tput {
+            model_name: "Qwen/Qwen3-235B-A22B-Instruct-2507-tput",
+            constructor_name: qwen_qwen3_235b_a22b_instruct_2507_tput,
+            display_name: "Qwen3 235B A22B Instruct 2507",
+            capabilities: [TextInputSupport, TextOutputSupport, ToolCallSupport]
+        },
+        QwenQwen3Coder480bA35bInstructFp8 {
+            model_name: "Qwen/Qwen3-Coder-480B-A35B-Instruct-FP8",
+            constructor_name: qwen_qwen3_coder_480b_a35b_instruct_fp8,
+            display_name: "Qwen3 Coder 480B A35B Instruct",
+            capabilities: [TextInputSupport, TextOutputSupport, ToolCallSupport]
+        },
         DeepseekAiDeepseekR1 {
             model_name: "deepseek-ai/DeepSeek-R1",
             constructor_name: deepseek_ai_deepseek_r1,
@@ -40,6 +70,42 @@ model_capabilities! {
             display_name: "Llama 3.3 70B",
             capabilities: [TextInputSupport, TextOutputSupport, ToolCallSupport]
         },
+        MetaLlamaLlama4Maverick17b128eInstructFp8 {
+            model_name: "meta-llama/Llama-4-Maverick-17B-128E-Instruct-FP8",
+            constructor_name: meta_llama_llama_4_maverick_17b_128e_instruct_fp8,
+            display_name: "Llama 4 Maverick 17B 128E Instruct",
+            capabilities: [ImageInputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+        },
+        MetaLlamaLlama4Scout17b16eInstruct {
+            model_name: "meta-llama/Llama-4-Scout-17B-16E-Instruct",
+            constructor_name: meta_llama_llama_4_scout_17b_16e_instruct,
+            display_name: "Llama 4 Scout 17B 16E Instruct",
+            capabilities: [ImageInputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+        },
+        MetaLlamaMetaLlama31405bInstructTurbo {
+            model_name: "meta-llama/Meta-Llama-3.1-405B-Instruct-Turbo",
+            constructor_name: meta_llama_meta_llama_3_1_405b_instruct_turbo,
+            display_name: "Llama 3.1 405B Instruct Turbo",
+            capabilities: [TextInputSupport, TextOutputSupport, ToolCallSupport]
+        },
+        MetaLlamaMetaLlama318bInstructTurbo {
+            model_name: "meta-llama/Meta-Llama-3.1-8B-Instruct-Turbo",
+            constructor_name: meta_llama_meta_llama_3_1_8b_instruct_turbo,
+            display_name: "Llama 3.1 8B Instruct Turbo",
+            capabilities: [TextInputSupport, TextOutputSupport, ToolCallSupport]
+        },
+        MistralaiMistral7bInstructV03 {
+            model_name: "mistralai/Mistral-7B-Instruct-v0.3",
+            constructor_name: mistralai_mistral_7b_instruct_v0_3,
+            display_name: "Mistral 7B Instruct v0.3",
+            capabilities: [TextInputSupport, TextOutputSupport]
+        },
+        MistralaiMixtral8x7bInstructV01 {
+            model_name: "mistralai/Mixtral-8x7B-Instruct-v0.1",
+            constructor_name: mistralai_mixtral_8x7b_instruct_v0_1,
+            display_name: "Mixtral 8x7B Instruct v0.1",
+            capabilities: [TextInputSupport, TextOutputSupport]
+        },
         MoonshotaiKimiK2Instruct {
             model_name: "moonshotai/Kimi-K2-Instruct",
             constructor_name: moonshotai_kimi_k2_instruct,
@@ -58,12 +124,6 @@ model_capabilities! {
             display_name: "GPT OSS 120B",
             capabilities: [ReasoningSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
         },
-        QwenQwen3Coder480bA35bInstructFp8 {
-            model_name: "Qwen/Qwen3-Coder-480B-A35B-Instruct-FP8",
-            constructor_name: qwen_qwen3_coder_480b_a35b_instruct_fp8,
-            display_name: "Qwen3 Coder 480B A35B Instruct",
-            capabilities: [TextInputSupport, TextOutputSupport, ToolCallSupport]
-        },
         ZaiOrgGlm46 {
             model_name: "zai-org/GLM-4.6",
             constructor_name: zai_org_glm_4_6,
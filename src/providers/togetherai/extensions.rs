@@ -0,0 +1,43 @@
+//! Together AI specific request options.
+
+use serde::{Deserialize, Serialize};
+
+/// Sampling and moderation options specific to Together AI, sent with every request.
+///
+/// Set with [`TogetherAIBuilder::extensions`](crate::providers::togetherai::TogetherAIBuilder::extensions).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TogetherAIExtensions {
+    /// Penalizes repeated tokens. `1.0` means no penalty.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repetition_penalty: Option<f32>,
+
+    /// The minimum probability, relative to the most likely token, for a token to be
+    /// sampled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_p: Option<f32>,
+
+    /// A moderation model run over the input and output, e.g.
+    /// `"meta-llama/Meta-Llama-Guard-3-8B"`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub safety_model: Option<String>,
+}
+
+impl TogetherAIExtensions {
+    /// Sets the repetition penalty.
+    pub fn repetition_penalty(mut self, repetition_penalty: f32) -> Self {
+        self.repetition_penalty = Some(repetition_penalty);
+        self
+    }
+
+    /// Sets the minimum relative token probability.
+    pub fn min_p(mut self, min_p: f32) -> Self {
+        self.min_p = Some(min_p);
+        self
+    }
+
+    /// Sets the moderation model.
+    pub fn safety_model(mut self, safety_model: impl Into<String>) -> Self {
+        self.safety_model = Some(safety_model.into());
+        self
+    }
+}
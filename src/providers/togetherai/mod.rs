@@ -5,6 +5,9 @@
 // to the Together AI documentation for more information.
 
 pub mod capabilities;
+pub mod extensions;
+
+pub use extensions::TogetherAIExtensions;
 
 // Generate the settings module
 crate::openai_compatible_settings!(
@@ -28,3 +31,49 @@ crate::openai_compatible_language_model!(TogetherAI);
 
 // Generate the embedding model implementation
 crate::openai_compatible_embedding_model!(TogetherAI);
+
+impl<M: ModelName> TogetherAIBuilder<M> {
+    /// Sets Together AI specific options, such as `repetition_penalty` and
+    /// `safety_model`, sent with every request.
+    ///
+    /// # Parameters
+    ///
+    /// * `extensions` - The [`TogetherAIExtensions`] to send.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the options set.
+    pub fn extensions(mut self, extensions: TogetherAIExtensions) -> Self {
+        if let Ok(serde_json::Value::Object(fields)) = serde_json::to_value(extensions) {
+            self.inner.settings.extra_body.extend(fields);
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extensions_are_sent_in_request_body() {
+        let together = TogetherAI::<DynamicModel>::builder()
+            .model_name("meta-llama/Llama-3.3-70B-Instruct-Turbo")
+            .api_key("key")
+            .extensions(
+                TogetherAIExtensions::default()
+                    .repetition_penalty(1.1)
+                    .safety_model("meta-llama/Meta-Llama-Guard-3-8B"),
+            )
+            .build()
+            .unwrap();
+
+        let extra_body = &together.inner.settings.extra_body;
+        assert_eq!(
+            extra_body["safety_model"],
+            "meta-llama/Meta-Llama-Guard-3-8B"
+        );
+        assert!(extra_body.contains_key("repetition_penalty"));
+        assert!(!extra_body.contains_key("min_p"));
+    }
+}
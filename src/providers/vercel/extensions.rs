@@ -0,0 +1,54 @@
+//! Vercel AI Gateway specific request options.
+
+use serde::{Deserialize, Serialize};
+
+/// Provider routing preferences for the Vercel AI Gateway, sent with every request.
+///
+/// Providers are named by their gateway slug, e.g. `"anthropic"`, `"bedrock"` or
+/// `"vertex"`. Set with [`VercelBuilder::routing`](crate::providers::vercel::VercelBuilder::routing).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GatewayRouting {
+    /// Providers to try, in order, before falling back to the gateway's default order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<Vec<String>>,
+
+    /// Restricts routing to these providers only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub only: Option<Vec<String>>,
+
+    /// Fallback models, as `provider/model` slugs, tried if the requested model fails.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub models: Option<Vec<String>>,
+}
+
+impl GatewayRouting {
+    /// Sets the preferred provider order.
+    pub fn order<I, S>(mut self, providers: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.order = Some(providers.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Sets the providers requests may be routed to.
+    pub fn only<I, S>(mut self, providers: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.only = Some(providers.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Sets the fallback models.
+    pub fn models<I, S>(mut self, models: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.models = Some(models.into_iter().map(Into::into).collect());
+        self
+    }
+}
@@ -0,0 +1,132 @@
+//! Metadata endpoints of the Vercel AI Gateway.
+
+use reqwest::header::{AUTHORIZATION, HeaderMap};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+
+use super::Vercel;
+use crate::{
+    Error,
+    core::{
+        capabilities::ModelName,
+        client::{Transport, response_error},
+        runtime::assume_send,
+        utils::join_url,
+    },
+    error::Result,
+};
+
+/// The credit balance of a Vercel AI Gateway account, in US dollars.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GatewayCredits {
+    /// The remaining balance.
+    pub balance: String,
+    /// The total amount spent.
+    pub total_used: String,
+}
+
+/// Per-token pricing of a gateway model, in US dollars.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GatewayModelPricing {
+    /// Price per input token.
+    #[serde(default)]
+    pub input: Option<String>,
+    /// Price per output token.
+    #[serde(default)]
+    pub output: Option<String>,
+    /// Price per cached input token read.
+    #[serde(default)]
+    pub input_cache_read: Option<String>,
+    /// Price per input token written to the cache.
+    #[serde(default)]
+    pub input_cache_write: Option<String>,
+}
+
+/// A model available through the Vercel AI Gateway.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GatewayModel {
+    /// The `provider/model` slug, e.g. `"anthropic/claude-sonnet-4"`.
+    pub id: String,
+    /// The display name.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// A short description of the model.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// The provider that owns the model.
+    #[serde(default)]
+    pub owned_by: Option<String>,
+    /// The kind of model, e.g. `"language"` or `"embedding"`.
+    #[serde(default, rename = "type")]
+    pub model_type: Option<String>,
+    /// The maximum number of input tokens.
+    #[serde(default)]
+    pub context_window: Option<u64>,
+    /// The maximum number of output tokens.
+    #[serde(default)]
+    pub max_tokens: Option<u64>,
+    /// Feature tags, e.g. `"tool-use"` or `"reasoning"`.
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// The model's pricing, if published.
+    #[serde(default)]
+    pub pricing: Option<GatewayModelPricing>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct GatewayModelList {
+    pub(crate) data: Vec<GatewayModel>,
+}
+
+impl<M: ModelName> Vercel<M> {
+    /// Returns the credit balance of the account the API key belongs to.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the gateway cannot be reached or returns an invalid response.
+    pub async fn get_credits(&self) -> Result<GatewayCredits> {
+        self.fetch_json("credits").await
+    }
+
+    /// Lists the models available through the gateway, with their pricing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the gateway cannot be reached or returns an invalid response.
+    pub async fn get_available_models(&self) -> Result<Vec<GatewayModel>> {
+        Ok(self.fetch_json::<GatewayModelList>("models").await?.data)
+    }
+
+    async fn fetch_json<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
+        let url = join_url(self.inner.settings.base_url.as_str(), path)?;
+        let mut headers = HeaderMap::new();
+        if let Ok(value) = format!("Bearer {}", self.inner.settings.api_key).parse() {
+            headers.insert(AUTHORIZATION, value);
+        }
+
+        let transport = Transport::new(self.inner.settings.transport.clone());
+        let request = transport.client.get(url).headers(headers);
+        let response = transport
+            .send(request)
+            .await?
+            .map_err(|e| Error::ApiError {
+                status_code: e.status(),
+                details: e.to_string(),
+            })?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(response_error(response).await);
+        }
+        let text = assume_send(response.text())
+            .await
+            .map_err(|e| Error::ApiError {
+                status_code: Some(status),
+                details: format!("Failed to read response: {e}"),
+            })?;
+
+        serde_json::from_str(&text).map_err(|e| Error::ApiError {
+            status_code: Some(status),
+            details: format!("Failed to parse response: {e}"),
+        })
+    }
+}
@@ -1,10 +1,20 @@
-//! This module provides the Vercel provider, wrapping OpenAI Chat Completions for Vercel requests.
+//! This module provides the Vercel provider, wrapping OpenAI Chat Completions for Vercel AI
+//! Gateway requests.
 //!
+//! Models are addressed by `provider/model` slugs, e.g. `"anthropic/claude-sonnet-4"`.
+//! Provider routing preferences are set with [`GatewayRouting`], and the account's credits
+//! and the gateway's model list are available through [`Vercel::get_credits`] and
+//! [`Vercel::get_available_models`].
 
 // NOTE: Vercel might not be fully compatible with the OpenAI API. Please refer to
 // the Vercel documentation for more information.
 
 pub mod capabilities;
+pub mod extensions;
+pub mod gateway;
+
+pub use extensions::GatewayRouting;
+pub use gateway::{GatewayCredits, GatewayModel, GatewayModelPricing};
 
 // Generate the settings module
 crate::openai_compatible_settings!(
@@ -20,7 +30,7 @@ crate::openai_compatible_provider!(
     Vercel,
     VercelBuilder,
     VercelProviderSettings,
-    "claude-3-5-haiku-2024-10-22"
+    "anthropic/claude-3.5-haiku"
 );
 
 // Generate the language model implementation
@@ -28,3 +38,85 @@ crate::openai_compatible_language_model!(Vercel);
 
 // Generate the embedding model implementation
 crate::openai_compatible_embedding_model!(Vercel);
+
+/// The Vercel AI Gateway provider. An alias of [`Vercel`].
+pub type Gateway<M> = Vercel<M>;
+
+impl<M: ModelName> VercelBuilder<M> {
+    /// Sets the gateway's provider routing preferences, sent with every request as
+    /// `providerOptions.gateway`.
+    ///
+    /// # Parameters
+    ///
+    /// * `routing` - The [`GatewayRouting`] preferences to send.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the routing preferences set.
+    pub fn routing(mut self, routing: GatewayRouting) -> Self {
+        if let Ok(gateway) = serde_json::to_value(routing) {
+            self.inner.settings.extra_body.insert(
+                "providerOptions".to_string(),
+                serde_json::json!({ "gateway": gateway }),
+            );
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_routing_is_sent_in_request_body() {
+        let gateway = Gateway::<DynamicModel>::builder()
+            .model_name("anthropic/claude-sonnet-4")
+            .api_key("key")
+            .routing(
+                GatewayRouting::default()
+                    .order(["bedrock", "anthropic"])
+                    .models(["openai/gpt-4o"]),
+            )
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            gateway.inner.settings.extra_body["providerOptions"],
+            serde_json::json!({
+                "gateway": {
+                    "order": ["bedrock", "anthropic"],
+                    "models": ["openai/gpt-4o"]
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_models_response_parsing() {
+        let json = r#"{
+            "object": "list",
+            "data": [{
+                "id": "anthropic/claude-sonnet-4",
+                "object": "model",
+                "owned_by": "anthropic",
+                "name": "Claude Sonnet 4",
+                "type": "language",
+                "context_window": 200000,
+                "max_tokens": 64000,
+                "tags": ["tool-use", "reasoning"],
+                "pricing": {"input": "0.000003", "output": "0.000015"}
+            }]
+        }"#;
+
+        let list: gateway::GatewayModelList = serde_json::from_str(json).unwrap();
+        let model = &list.data[0];
+        assert_eq!(model.id, "anthropic/claude-sonnet-4");
+        assert_eq!(model.model_type.as_deref(), Some("language"));
+        assert_eq!(model.context_window, Some(200000));
+        assert_eq!(
+            model.pricing.as_ref().unwrap().output.as_deref(),
+            Some("0.000015")
+        );
+    }
+}
@@ -0,0 +1,122 @@
+//! Client implementation for the Vertex AI provider.
+//!
+//! Vertex AI serves the same `generateContent` API as the Gemini API, so requests and
+//! responses reuse the Google provider's types. Only the URL scheme, which is scoped
+//! to a project and location, and the OAuth2 bearer authorization differ.
+use crate::core::client::{LanguageModelClient, RetryPolicy};
+use crate::error::Result;
+use crate::providers::google::client::{self as google, types};
+use crate::providers::vertex::credentials::VertexCredentials;
+use crate::providers::vertex::{ModelName, Vertex};
+use reqwest::header::{AUTHORIZATION, CONTENT_TYPE};
+use reqwest_eventsource::Event;
+
+impl<M: ModelName> LanguageModelClient for Vertex<M> {
+    type Response = types::GenerateContentResponse;
+    type StreamEvent = types::GoogleStreamEvent;
+
+    fn path(&self) -> String {
+        let method = if self.lm_options.streaming {
+            "streamGenerateContent"
+        } else {
+            "generateContent"
+        };
+        format!(
+            "/v1/projects/{}/locations/{}/publishers/google/models/{}:{method}",
+            self.settings.project, self.settings.location, self.lm_options.model
+        )
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn headers(&self) -> reqwest::header::HeaderMap {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(CONTENT_TYPE, "application/json".parse().unwrap());
+
+        let token = match &self.settings.credentials {
+            Some(VertexCredentials::AccessToken(token)) => Some(token.clone()),
+            Some(VertexCredentials::ServiceAccount(_)) => {
+                self.token().as_ref().map(|token| token.token.clone())
+            }
+            None => None,
+        };
+        if let Some(token) = token
+            && let Ok(value) = format!("Bearer {token}").parse()
+        {
+            headers.insert(AUTHORIZATION, value);
+        }
+        headers
+    }
+
+    fn query_params(&self) -> Vec<(&str, &str)> {
+        if self.lm_options.streaming {
+            return vec![("alt", "sse")];
+        }
+        Vec::new()
+    }
+
+    fn retry_policy(&self) -> RetryPolicy {
+        self.settings.retry_policy.clone()
+    }
+
+    fn transport(&self) -> crate::core::client::TransportSettings {
+        self.settings.transport.clone()
+    }
+
+    fn body(&self) -> reqwest::Body {
+        if let Some(request) = &self.lm_options.request {
+            let body = serde_json::to_string(request).unwrap();
+            return reqwest::Body::from(body);
+        };
+        reqwest::Body::from("{}")
+    }
+
+    fn parse_stream_sse(
+        event: std::result::Result<Event, reqwest_eventsource::Error>,
+    ) -> Result<Self::StreamEvent> {
+        google::parse_stream_event(event)
+    }
+
+    fn end_stream(event: &Self::StreamEvent) -> bool {
+        google::is_stream_end(event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::DynamicModel;
+
+    #[test]
+    fn test_path_is_scoped_to_project_and_location() {
+        let mut vertex = Vertex::<DynamicModel>::builder()
+            .model_name("gemini-2.5-flash")
+            .project("my-project")
+            .location("europe-west4")
+            .access_token("token")
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            LanguageModelClient::path(&vertex),
+            "/v1/projects/my-project/locations/europe-west4/publishers/google/models/gemini-2.5-flash:generateContent"
+        );
+        assert_eq!(
+            vertex.settings.endpoint(),
+            "https://europe-west4-aiplatform.googleapis.com/"
+        );
+
+        vertex.lm_options.streaming = true;
+        assert!(LanguageModelClient::path(&vertex).ends_with(":streamGenerateContent"));
+        assert_eq!(
+            LanguageModelClient::query_params(&vertex),
+            vec![("alt", "sse")]
+        );
+        assert_eq!(
+            LanguageModelClient::headers(&vertex)[AUTHORIZATION],
+            "Bearer token"
+        );
+    }
+}
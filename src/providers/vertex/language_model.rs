@@ -0,0 +1,58 @@
+//! Language model implementation for the Vertex AI provider.
+use crate::core::capabilities::ModelName;
+use crate::core::client::LanguageModelClient;
+use crate::core::language_model::{
+    LanguageModel, LanguageModelOptions, LanguageModelResponse, ProviderStream,
+    stream_with_rate_limit,
+};
+use crate::error::Result;
+use crate::providers::google::client::types;
+use crate::providers::google::language_model::{response_from, stream_from};
+use crate::providers::vertex::Vertex;
+use async_trait::async_trait;
+
+#[async_trait]
+impl<M: ModelName> LanguageModel for Vertex<M> {
+    fn name(&self) -> String {
+        self.lm_options.model.clone()
+    }
+
+    fn capabilities(&self) -> crate::core::capabilities::Capabilities {
+        M::CAPABILITIES
+    }
+
+    fn info(&self) -> crate::core::capabilities::ModelInfo {
+        Self::model_info(&self.name())
+    }
+
+    async fn generate_text(
+        &mut self,
+        options: LanguageModelOptions,
+    ) -> Result<LanguageModelResponse> {
+        let request: types::GenerateContentRequest = options.into();
+        self.lm_options.request = Some(request);
+        self.lm_options.streaming = false;
+        self.refresh_token().await?;
+
+        let (response, rate_limit): (types::GenerateContentResponse, _) =
+            self.send_with_rate_limit(self.settings.endpoint()).await?;
+
+        Ok(response_from(response).with_rate_limit(rate_limit))
+    }
+
+    async fn stream_text(&mut self, options: LanguageModelOptions) -> Result<ProviderStream> {
+        let request: types::GenerateContentRequest = options.into();
+        self.lm_options.request = Some(request);
+        self.lm_options.streaming = true;
+        self.refresh_token().await?;
+
+        let (vertex_stream, rate_limit) = self
+            .send_and_stream_with_rate_limit(self.settings.endpoint())
+            .await?;
+
+        Ok(stream_with_rate_limit(
+            rate_limit,
+            stream_from(vertex_stream),
+        ))
+    }
+}
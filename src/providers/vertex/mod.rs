@@ -0,0 +1,290 @@
+//! This module provides the Vertex AI provider, which implements the `LanguageModel`
+//! and `Provider` traits for Gemini models served by Google Cloud Vertex AI.
+//!
+//! Vertex AI speaks the same `generateContent` API as the Gemini API used by
+//! [`Google`](crate::providers::Google), but is scoped to a Google Cloud project and
+//! location and is authorized with OAuth2 access tokens, typically minted from a
+//! service account key and refreshed before they expire.
+
+pub mod capabilities;
+pub mod client;
+pub mod credentials;
+pub mod language_model;
+pub mod settings;
+
+pub(crate) mod auth;
+
+use crate::core::DynamicModel;
+use crate::core::capabilities::ModelName;
+use crate::core::client::RetryPolicy;
+use crate::core::runtime::SystemTime;
+use crate::core::utils::validate_base_url;
+use crate::error::{Error, Result};
+use crate::providers::google::client::GoogleOptions;
+use crate::providers::vertex::auth::AccessToken;
+use crate::providers::vertex::settings::VertexProviderSettings;
+use serde::Serialize;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+pub use credentials::{ServiceAccountKey, VertexCredentials};
+
+/// The Vertex AI provider.
+#[derive(Debug, Serialize, Clone)]
+pub struct Vertex<M: ModelName> {
+    /// Configuration settings for the Vertex AI provider.
+    pub settings: VertexProviderSettings,
+    pub(crate) lm_options: GoogleOptions,
+    /// The access token minted from a service account, shared between clones.
+    #[serde(skip)]
+    token: Arc<Mutex<Option<AccessToken>>>,
+    _phantom: std::marker::PhantomData<M>,
+}
+
+impl<M: ModelName> Vertex<M> {
+    /// Vertex AI provider setting builder.
+    pub fn builder() -> VertexBuilder<M> {
+        VertexBuilder::default()
+    }
+
+    fn with_model(settings: VertexProviderSettings, model_name: String) -> Self {
+        let options = GoogleOptions::builder().model(model_name).build().unwrap();
+
+        Self {
+            settings,
+            lm_options: options,
+            token: Arc::default(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+
+    pub(crate) fn token(&self) -> MutexGuard<'_, Option<AccessToken>> {
+        self.token.lock().unwrap_or_else(|p| p.into_inner())
+    }
+
+    /// Mints a new access token from the service account key, unless the current one
+    /// is still fresh.
+    pub(crate) async fn refresh_token(&self) -> Result<()> {
+        let Some(VertexCredentials::ServiceAccount(key)) = &self.settings.credentials else {
+            return Ok(());
+        };
+        if self
+            .token()
+            .as_ref()
+            .is_some_and(|token| token.is_fresh(SystemTime::now()))
+        {
+            return Ok(());
+        }
+
+        let token = auth::fetch_token(&self.settings.transport.client(), key).await?;
+        *self.token() = Some(token);
+        Ok(())
+    }
+}
+
+impl Vertex<DynamicModel> {
+    /// Creates a Vertex AI provider with a dynamic model name using default settings.
+    ///
+    /// This allows you to specify the model name as a string rather than
+    /// using methods like `Vertex::gemini_2_5_pro()`, etc.
+    ///
+    /// **WARNING**: when using `DynamicModel`, model capabilities are not validated.
+    /// This means there is no compile-time guarantee that the model supports requested features.
+    ///
+    /// For custom configuration (project, location, credentials, etc.), use the builder
+    /// pattern: `Vertex::<DynamicModel>::builder().model_name(...).project(...).build()`
+    ///
+    /// # Parameters
+    ///
+    /// * `model_name` - The Gemini model identifier (e.g., "gemini-2.5-pro", "gemini-2.5-flash")
+    ///
+    /// # Returns
+    ///
+    /// A configured `Vertex<DynamicModel>` provider instance with default settings.
+    pub fn model_name(name: impl Into<String>) -> Self {
+        Self::with_model(VertexProviderSettings::default(), name.into())
+    }
+}
+
+impl<M: ModelName> Default for Vertex<M> {
+    /// Creates a new Vertex AI provider with default settings.
+    fn default() -> Self {
+        Self::with_model(VertexProviderSettings::default(), M::MODEL_NAME.to_string())
+    }
+}
+
+/// Vertex AI Provider Builder
+pub struct VertexBuilder<M: ModelName> {
+    settings: VertexProviderSettings,
+    model_name: String,
+    _phantom: std::marker::PhantomData<M>,
+}
+
+impl<M: ModelName> Default for VertexBuilder<M> {
+    /// Creates a new Vertex AI provider builder with default settings.
+    fn default() -> Self {
+        Self {
+            settings: VertexProviderSettings::default(),
+            model_name: M::MODEL_NAME.to_string(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl VertexBuilder<DynamicModel> {
+    /// Sets the model name from a string. e.g., "gemini-2.5-pro", "gemini-2.5-flash"
+    ///
+    /// **WARNING**: when using `DynamicModel`, model capabilities are not validated.
+    /// This means there is no compile-time guarantee that the model supports requested features.
+    ///
+    /// # Parameters
+    ///
+    /// * `model_name` - The Gemini model identifier.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the model name set.
+    pub fn model_name(mut self, model_name: impl Into<String>) -> Self {
+        self.model_name = model_name.into();
+        self
+    }
+}
+
+impl<M: ModelName> VertexBuilder<M> {
+    /// Sets the Google Cloud project ID. Defaults to `GOOGLE_VERTEX_PROJECT`,
+    /// `GOOGLE_CLOUD_PROJECT`, or the project of the service account.
+    pub fn project(mut self, project: impl Into<String>) -> Self {
+        self.settings.project = project.into();
+        self
+    }
+
+    /// Sets the Google Cloud location. Defaults to `GOOGLE_VERTEX_LOCATION`,
+    /// `GOOGLE_CLOUD_LOCATION`, or `us-central1`.
+    pub fn location(mut self, location: impl Into<String>) -> Self {
+        self.settings.location = location.into();
+        self
+    }
+
+    /// Overrides the endpoint derived from the location.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.settings.base_url = Some(base_url.into());
+        self
+    }
+
+    /// Authorizes requests with access tokens minted from a service account key.
+    /// Defaults to the key file referenced by `GOOGLE_APPLICATION_CREDENTIALS`.
+    pub fn service_account(mut self, key: ServiceAccountKey) -> Self {
+        if self.settings.project.is_empty()
+            && let Some(project) = &key.project_id
+        {
+            self.settings.project = project.clone();
+        }
+        self.settings.credentials = Some(VertexCredentials::ServiceAccount(key));
+        self
+    }
+
+    /// Authorizes requests with an access token obtained elsewhere. The token is not
+    /// refreshed. Defaults to `GOOGLE_VERTEX_ACCESS_TOKEN`.
+    pub fn access_token(mut self, token: impl Into<String>) -> Self {
+        self.settings.credentials = Some(VertexCredentials::AccessToken(token.into()));
+        self
+    }
+
+    /// Sets the retry policy applied to requests made by this provider.
+    ///
+    /// # Parameters
+    ///
+    /// * `retry_policy` - The [`RetryPolicy`] to use for retryable failures.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the retry policy set.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.settings.retry_policy = retry_policy;
+        self
+    }
+
+    crate::core::client::transport_builder_methods!();
+
+    /// Sets the name of the provider. Defaults to "vertex".
+    pub fn provider_name(mut self, provider_name: impl Into<String>) -> Self {
+        self.settings.provider_name = provider_name.into();
+        self
+    }
+
+    /// Builds the Vertex AI provider.
+    pub fn build(self) -> Result<Vertex<M>> {
+        // validate base url
+        let base_url = self
+            .settings
+            .base_url
+            .as_deref()
+            .map(validate_base_url)
+            .transpose()?;
+
+        if self.settings.project.is_empty() {
+            return Err(Error::MissingField("project".to_string()));
+        }
+
+        if self.settings.location.is_empty() {
+            return Err(Error::MissingField("location".to_string()));
+        }
+
+        if self.settings.credentials.is_none() {
+            return Err(Error::MissingField("credentials".to_string()));
+        }
+
+        if self.model_name.is_empty() {
+            return Err(Error::MissingField("model_name".to_string()));
+        }
+
+        Ok(Vertex::with_model(
+            VertexProviderSettings {
+                base_url,
+                ..self.settings
+            },
+            self.model_name,
+        ))
+    }
+}
+
+// Re-exports for convenience
+pub use capabilities::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_takes_project_from_service_account() {
+        let key = ServiceAccountKey {
+            client_email: "sa@key-project.iam.gserviceaccount.com".to_string(),
+            private_key: String::new(),
+            private_key_id: None,
+            token_uri: "https://oauth2.googleapis.com/token".to_string(),
+            project_id: Some("key-project".to_string()),
+        };
+        let vertex = Vertex::<DynamicModel>::builder()
+            .model_name("gemini-2.5-pro")
+            .project("")
+            .location("global")
+            .service_account(key)
+            .build()
+            .unwrap();
+
+        assert_eq!(vertex.settings.project, "key-project");
+        assert_eq!(
+            vertex.settings.endpoint(),
+            "https://aiplatform.googleapis.com/"
+        );
+    }
+
+    #[test]
+    fn test_builder_requires_project() {
+        let result = Vertex::<DynamicModel>::builder()
+            .model_name("gemini-2.5-pro")
+            .project("")
+            .access_token("token")
+            .build();
+        assert!(matches!(result, Err(Error::MissingField(field)) if field == "project"));
+    }
+}
@@ -0,0 +1,82 @@
+//! Defines the settings for the Vertex AI provider.
+
+use crate::core::client::{RetryPolicy, TransportSettings};
+use crate::providers::vertex::credentials::VertexCredentials;
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+/// The location used when none is configured.
+pub(crate) const DEFAULT_LOCATION: &str = "us-central1";
+
+/// Settings for the Vertex AI provider.
+#[derive(Debug, Clone, Serialize, Deserialize, Builder)]
+#[builder(setter(into), default)]
+pub struct VertexProviderSettings {
+    /// The name of the provider.
+    pub provider_name: String,
+
+    /// The Google Cloud project ID.
+    pub project: String,
+
+    /// The Google Cloud location, e.g. `us-central1` or `global`.
+    pub location: String,
+
+    /// Overrides the endpoint derived from the location, e.g. for Private Service
+    /// Connect.
+    pub base_url: Option<String>,
+
+    /// The credentials used to authorize requests.
+    pub credentials: Option<VertexCredentials>,
+
+    /// The retry policy applied to requests.
+    pub retry_policy: RetryPolicy,
+
+    /// The HTTP client, middleware and rate limiter requests go through.
+    #[serde(skip)]
+    pub transport: TransportSettings,
+}
+
+impl Default for VertexProviderSettings {
+    /// Returns the default settings for the Vertex AI provider.
+    fn default() -> Self {
+        let credentials = VertexCredentials::resolve();
+        let project = std::env::var("GOOGLE_VERTEX_PROJECT")
+            .or_else(|_| std::env::var("GOOGLE_CLOUD_PROJECT"))
+            .ok()
+            .or_else(|| match &credentials {
+                Some(VertexCredentials::ServiceAccount(key)) => key.project_id.clone(),
+                _ => None,
+            })
+            .unwrap_or_default();
+
+        Self {
+            provider_name: "vertex".to_string(),
+            project,
+            location: std::env::var("GOOGLE_VERTEX_LOCATION")
+                .or_else(|_| std::env::var("GOOGLE_CLOUD_LOCATION"))
+                .unwrap_or_else(|_| DEFAULT_LOCATION.to_string()),
+            base_url: None,
+            credentials,
+            retry_policy: RetryPolicy::default(),
+            transport: TransportSettings::default(),
+        }
+    }
+}
+
+impl VertexProviderSettings {
+    /// Creates a new builder for `VertexProviderSettings`.
+    pub fn builder() -> VertexProviderSettingsBuilder {
+        VertexProviderSettingsBuilder::default()
+    }
+
+    /// Returns the Vertex AI endpoint requests are sent to.
+    pub fn endpoint(&self) -> String {
+        if let Some(base_url) = &self.base_url {
+            return base_url.clone();
+        }
+        match self.location.as_str() {
+            "global" => "https://aiplatform.googleapis.com/".to_string(),
+            location => format!("https://{location}-aiplatform.googleapis.com/"),
+        }
+    }
+}
@@ -0,0 +1,39 @@
+//! Capabilities for vLLM models.
+//!
+//! This module defines model types and their capabilities for popular models served
+//! with vLLM, under their Hugging Face names. Any other served model can be used
+//! through `VLLM::model_name`. Users can implement additional traits on custom models.
+
+use crate::core::capabilities::*;
+use crate::model_capabilities;
+use crate::providers::vllm::VLLM;
+
+model_capabilities! {
+    provider: VLLM,
+    models: {
+        MetaLlamaLlama318bInstruct {
+            model_name: "meta-llama/Llama-3.1-8B-Instruct",
+            constructor_name: meta_llama_llama_3_1_8b_instruct,
+            display_name: "Llama 3.1 8B Instruct",
+            capabilities: [StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+        },
+        MistralaiMistral7bInstructV03 {
+            model_name: "mistralai/Mistral-7B-Instruct-v0.3",
+            constructor_name: mistralai_mistral_7b_instruct_v0_3,
+            display_name: "Mistral 7B Instruct v0.3",
+            capabilities: [StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+        },
+        QwenQwen257bInstruct {
+            model_name: "Qwen/Qwen2.5-7B-Instruct",
+            constructor_name: qwen_qwen2_5_7b_instruct,
+            display_name: "Qwen2.5 7B Instruct",
+            capabilities: [StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+        },
+        QwenQwen38b {
+            model_name: "Qwen/Qwen3-8B",
+            constructor_name: qwen_qwen3_8b,
+            display_name: "Qwen3 8B",
+            capabilities: [ReasoningSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+        },
+    }
+}
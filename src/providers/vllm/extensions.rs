@@ -0,0 +1,74 @@
+//! vLLM specific request options.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Guided decoding and sampling options specific to vLLM, sent with every request.
+///
+/// The guided decoding options constrain the output and are mutually exclusive: set at
+/// most one of `guided_json`, `guided_regex` and `guided_choice`.
+///
+/// Set with [`VLLMBuilder::extensions`](crate::providers::vllm::VLLMBuilder::extensions).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct VLLMExtensions {
+    /// A JSON schema the output is constrained to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guided_json: Option<Value>,
+
+    /// A regular expression the output is constrained to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guided_regex: Option<String>,
+
+    /// A list of strings, exactly one of which is output.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guided_choice: Option<Vec<String>>,
+
+    /// The number of sequences generated, of which the best is returned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub best_of: Option<u32>,
+
+    /// Uses beam search instead of sampling.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_beam_search: Option<bool>,
+}
+
+impl VLLMExtensions {
+    /// Constrains the output to a JSON schema.
+    pub fn guided_json(mut self, schema: impl Into<Value>) -> Self {
+        self.guided_json = Some(schema.into());
+        self
+    }
+
+    /// Constrains the output to the JSON schema of `T`.
+    pub fn guided_json_for<T: schemars::JsonSchema>(self) -> Self {
+        self.guided_json(schemars::schema_for!(T).to_value())
+    }
+
+    /// Constrains the output to a regular expression.
+    pub fn guided_regex(mut self, regex: impl Into<String>) -> Self {
+        self.guided_regex = Some(regex.into());
+        self
+    }
+
+    /// Constrains the output to one of `choices`.
+    pub fn guided_choice<I, S>(mut self, choices: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.guided_choice = Some(choices.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Sets the number of sequences generated to pick the best from.
+    pub fn best_of(mut self, best_of: u32) -> Self {
+        self.best_of = Some(best_of);
+        self
+    }
+
+    /// Sets whether beam search is used instead of sampling.
+    pub fn use_beam_search(mut self, use_beam_search: bool) -> Self {
+        self.use_beam_search = Some(use_beam_search);
+        self
+    }
+}
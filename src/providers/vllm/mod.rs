@@ -0,0 +1,94 @@
+//! This module provides the vLLM provider, wrapping OpenAI Chat Completions for vLLM requests.
+//!
+//! vLLM's OpenAI-compatible server accepts extra sampling parameters, such as guided
+//! decoding and beam search, which are set with [`VLLMExtensions`].
+
+pub mod capabilities;
+pub mod extensions;
+
+pub use extensions::VLLMExtensions;
+
+// Generate the settings module
+crate::openai_compatible_settings!(
+    VLLMProviderSettings,
+    VLLMProviderSettingsBuilder,
+    "vLLM",
+    "http://localhost:8000/v1/",
+    "VLLM_API_KEY"
+);
+
+// Generate the provider struct and builder
+crate::openai_compatible_provider!(
+    VLLM,
+    VLLMBuilder,
+    VLLMProviderSettings,
+    "meta-llama/Llama-3.1-8B-Instruct"
+);
+
+// Generate the language model implementation
+crate::openai_compatible_language_model!(VLLM);
+
+// Generate the embedding model implementation
+crate::openai_compatible_embedding_model!(VLLM);
+
+impl<M: ModelName> VLLMBuilder<M> {
+    /// Sets vLLM specific options, such as `guided_json` and `best_of`, sent with
+    /// every request.
+    ///
+    /// # Parameters
+    ///
+    /// * `extensions` - The [`VLLMExtensions`] to send.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the options set.
+    pub fn extensions(mut self, extensions: VLLMExtensions) -> Self {
+        if let Ok(serde_json::Value::Object(fields)) = serde_json::to_value(extensions) {
+            self.inner.settings.extra_body.extend(fields);
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extensions_are_sent_in_request_body() {
+        let vllm = VLLM::<DynamicModel>::builder()
+            .model_name("meta-llama/Llama-3.1-8B-Instruct")
+            .api_key("key")
+            .extensions(
+                VLLMExtensions::default()
+                    .guided_choice(["positive", "negative"])
+                    .best_of(3),
+            )
+            .build()
+            .unwrap();
+
+        let extra_body = &vllm.inner.settings.extra_body;
+        assert_eq!(
+            extra_body["guided_choice"],
+            serde_json::json!(["positive", "negative"])
+        );
+        assert_eq!(extra_body["best_of"], 3);
+        assert!(!extra_body.contains_key("guided_json"));
+        assert!(!extra_body.contains_key("use_beam_search"));
+    }
+
+    #[test]
+    fn test_guided_json_for_type() {
+        #[derive(schemars::JsonSchema)]
+        #[allow(dead_code)]
+        struct Sentiment {
+            label: String,
+            score: f32,
+        }
+
+        let extensions = VLLMExtensions::default().guided_json_for::<Sentiment>();
+        let schema = extensions.guided_json.unwrap();
+        assert_eq!(schema["type"], "object");
+        assert!(schema["properties"].get("label").is_some());
+    }
+}
@@ -0,0 +1,63 @@
+//! Capabilities for Voyage AI models.
+//!
+//! This module defines model types for Voyage AI's embedding and rerank models. Any
+//! other model can be used through `Voyage::model_name`. Users can implement additional
+//! traits on custom models.
+
+use crate::core::capabilities::*;
+use crate::model_capabilities;
+use crate::providers::voyage::Voyage;
+
+model_capabilities! {
+    provider: Voyage,
+    models: {
+        Rerank25 {
+            model_name: "rerank-2.5",
+            constructor_name: rerank_2_5,
+            display_name: "Rerank 2.5",
+            capabilities: [TextInputSupport]
+        },
+        Rerank25Lite {
+            model_name: "rerank-2.5-lite",
+            constructor_name: rerank_2_5_lite,
+            display_name: "Rerank 2.5 Lite",
+            capabilities: [TextInputSupport]
+        },
+        Voyage3Large {
+            model_name: "voyage-3-large",
+            constructor_name: voyage_3_large,
+            display_name: "Voyage 3 Large",
+            capabilities: [TextInputSupport]
+        },
+        Voyage35 {
+            model_name: "voyage-3.5",
+            constructor_name: voyage_3_5,
+            display_name: "Voyage 3.5",
+            capabilities: [TextInputSupport]
+        },
+        Voyage35Lite {
+            model_name: "voyage-3.5-lite",
+            constructor_name: voyage_3_5_lite,
+            display_name: "Voyage 3.5 Lite",
+            capabilities: [TextInputSupport]
+        },
+        VoyageCode3 {
+            model_name: "voyage-code-3",
+            constructor_name: voyage_code_3,
+            display_name: "Voyage Code 3",
+            capabilities: [TextInputSupport]
+        },
+        VoyageFinance2 {
+            model_name: "voyage-finance-2",
+            constructor_name: voyage_finance_2,
+            display_name: "Voyage Finance 2",
+            capabilities: [TextInputSupport]
+        },
+        VoyageLaw2 {
+            model_name: "voyage-law-2",
+            constructor_name: voyage_law_2,
+            display_name: "Voyage Law 2",
+            capabilities: [TextInputSupport]
+        }
+    }
+}
@@ -0,0 +1,98 @@
+//! Client implementation for the Voyage AI provider.
+use crate::core::client::{EmbeddingClient, RerankClient, RetryPolicy};
+use crate::providers::voyage::{ModelName, Voyage};
+use reqwest::header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE};
+use serde::{Deserialize, Serialize};
+
+pub(crate) mod types;
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub(crate) struct VoyageEmbeddingOptions {
+    pub(crate) model: String,
+    pub(crate) request: types::EmbeddingsRequest,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub(crate) struct VoyageRerankOptions {
+    pub(crate) model: String,
+    pub(crate) request: types::RerankRequest,
+}
+
+impl<M: ModelName> Voyage<M> {
+    fn request_headers(&self) -> reqwest::header::HeaderMap {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(CONTENT_TYPE, "application/json".parse().unwrap());
+        headers.insert(ACCEPT, "application/json".parse().unwrap());
+        headers.insert(
+            AUTHORIZATION,
+            format!("Bearer {}", self.settings.api_key).parse().unwrap(),
+        );
+        headers
+    }
+}
+
+impl<M: ModelName> EmbeddingClient for Voyage<M> {
+    type Response = types::EmbeddingsResponse;
+
+    fn path(&self) -> String {
+        "embeddings".to_string()
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn headers(&self) -> reqwest::header::HeaderMap {
+        self.request_headers()
+    }
+
+    fn query_params(&self) -> Vec<(&str, &str)> {
+        Vec::new()
+    }
+
+    fn retry_policy(&self) -> RetryPolicy {
+        self.settings.retry_policy.clone()
+    }
+
+    fn transport(&self) -> crate::core::client::TransportSettings {
+        self.settings.transport.clone()
+    }
+
+    fn body(&self) -> reqwest::Body {
+        let body = serde_json::to_string(&self.embedding_options.request).unwrap();
+        reqwest::Body::from(body)
+    }
+}
+
+impl<M: ModelName> RerankClient for Voyage<M> {
+    type Response = types::RerankResponse;
+
+    fn path(&self) -> String {
+        "rerank".to_string()
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn headers(&self) -> reqwest::header::HeaderMap {
+        self.request_headers()
+    }
+
+    fn query_params(&self) -> Vec<(&str, &str)> {
+        Vec::new()
+    }
+
+    fn retry_policy(&self) -> RetryPolicy {
+        self.settings.retry_policy.clone()
+    }
+
+    fn transport(&self) -> crate::core::client::TransportSettings {
+        self.settings.transport.clone()
+    }
+
+    fn body(&self) -> reqwest::Body {
+        let body = serde_json::to_string(&self.rerank_options.request).unwrap();
+        reqwest::Body::from(body)
+    }
+}
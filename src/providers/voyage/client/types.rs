@@ -0,0 +1,60 @@
+//! Request and response types for the Voyage AI embeddings and rerank APIs.
+
+use crate::providers::voyage::settings::VoyageInputType;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct EmbeddingsRequest {
+    pub(crate) model: String,
+    pub(crate) input: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) input_type: Option<VoyageInputType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) output_dimension: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) truncation: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EmbeddingsResponse {
+    pub(crate) data: Vec<Embedding>,
+    #[serde(default)]
+    pub(crate) usage: Option<VoyageUsage>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct Embedding {
+    pub(crate) embedding: Vec<f32>,
+    pub(crate) index: usize,
+}
+
+/// The tokens billed for an embedding or rerank request.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VoyageUsage {
+    /// The number of tokens in the request's inputs.
+    pub total_tokens: usize,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct RerankRequest {
+    pub(crate) model: String,
+    pub(crate) query: String,
+    pub(crate) documents: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) top_k: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) truncation: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct RerankResponse {
+    pub(crate) data: Vec<RerankResult>,
+    #[serde(default)]
+    pub(crate) usage: Option<VoyageUsage>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct RerankResult {
+    pub(crate) index: usize,
+    pub(crate) relevance_score: f32,
+}
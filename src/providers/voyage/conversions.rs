@@ -0,0 +1,82 @@
+//! This module provides conversions between the core embedding and rerank types and
+//! the Voyage AI API types.
+
+use crate::core::embedding_model::{EmbeddingModelOptions, EmbeddingModelResponse};
+use crate::core::rerank_model::RerankModelOptions;
+use crate::providers::voyage::client::types::{
+    EmbeddingsRequest, EmbeddingsResponse, RerankRequest,
+};
+
+impl From<EmbeddingModelOptions> for EmbeddingsRequest {
+    fn from(value: EmbeddingModelOptions) -> Self {
+        EmbeddingsRequest {
+            model: String::new(), // will be set in embedding_model.rs
+            input: value.input,
+            output_dimension: value.dimensions,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<RerankModelOptions> for RerankRequest {
+    fn from(value: RerankModelOptions) -> Self {
+        RerankRequest {
+            model: String::new(), // will be set in rerank_model.rs
+            query: value.query,
+            documents: value.documents,
+            top_k: value.top_n,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<EmbeddingsResponse> for EmbeddingModelResponse {
+    fn from(value: EmbeddingsResponse) -> Self {
+        let mut data = value.data;
+        data.sort_by_key(|embedding| embedding.index);
+        data.into_iter()
+            .map(|embedding| embedding.embedding)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::voyage::client::types::Embedding;
+
+    #[test]
+    fn test_embeddings_are_ordered_by_index() {
+        let response = EmbeddingsResponse {
+            data: vec![
+                Embedding {
+                    embedding: vec![0.2],
+                    index: 1,
+                },
+                Embedding {
+                    embedding: vec![0.1],
+                    index: 0,
+                },
+            ],
+            usage: None,
+        };
+
+        let embeddings: EmbeddingModelResponse = response.into();
+        assert_eq!(embeddings, vec![vec![0.1], vec![0.2]]);
+    }
+
+    #[test]
+    fn test_rerank_request_maps_top_n() {
+        let options = RerankModelOptions::builder()
+            .query("What is Rust?".to_string())
+            .documents(vec!["A language".to_string(), "A fungus".to_string()])
+            .top_n(Some(1))
+            .build()
+            .unwrap();
+
+        let body = serde_json::to_value(RerankRequest::from(options)).unwrap();
+        assert_eq!(body["top_k"], 1);
+        assert_eq!(body["documents"][1], "A fungus");
+        assert!(body.get("truncation").is_none());
+    }
+}
@@ -0,0 +1,26 @@
+//! Embedding model implementation for the Voyage AI provider.
+
+use crate::{
+    core::{
+        capabilities::ModelName,
+        client::EmbeddingClient,
+        embedding_model::{EmbeddingModel, EmbeddingModelOptions, EmbeddingModelResponse},
+    },
+    error::Result,
+    providers::voyage::Voyage,
+};
+use async_trait::async_trait;
+
+#[async_trait]
+impl<M: ModelName> EmbeddingModel for Voyage<M> {
+    async fn embed(&self, input: EmbeddingModelOptions) -> Result<EmbeddingModelResponse> {
+        // Clone self to allow mutation
+        let mut model = self.clone();
+        model.embedding_options.request = model.request_from(input);
+
+        let response = model.send(&model.settings.base_url).await?;
+        model.set_usage(response.usage);
+
+        Ok(response.into())
+    }
+}
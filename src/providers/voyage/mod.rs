@@ -0,0 +1,298 @@
+//! This module provides the Voyage AI provider, which implements the `EmbeddingModel`
+//! trait for Voyage AI's embedding models and the `RerankModel` trait for its rerankers.
+//!
+//! Voyage AI only serves embeddings and reranking, so unlike other providers `Voyage`
+//! is not a `LanguageModel`. Retrieval quality improves when queries and documents are embedded
+//! with their respective [`VoyageInputType`], and the tokens billed for the latest
+//! request are available through [`Voyage::usage`].
+
+pub mod capabilities;
+pub mod client;
+pub mod conversions;
+pub mod embedding_model;
+pub mod rerank_model;
+pub mod settings;
+
+use crate::core::DynamicModel;
+use crate::core::capabilities::ModelName;
+use crate::core::client::RetryPolicy;
+use crate::core::embedding_model::EmbeddingModelOptions;
+use crate::core::utils::validate_base_url;
+use crate::error::{Error, Result};
+use crate::providers::voyage::client::types::EmbeddingsRequest;
+use crate::providers::voyage::client::{VoyageEmbeddingOptions, VoyageRerankOptions};
+use crate::providers::voyage::settings::VoyageProviderSettings;
+use serde::Serialize;
+use std::sync::{Arc, Mutex};
+
+pub use client::types::VoyageUsage;
+pub use settings::VoyageInputType;
+
+/// The Voyage AI provider.
+#[derive(Debug, Serialize, Clone)]
+pub struct Voyage<M: ModelName> {
+    /// Configuration settings for the Voyage AI provider.
+    pub settings: VoyageProviderSettings,
+    pub(crate) embedding_options: VoyageEmbeddingOptions,
+    pub(crate) rerank_options: VoyageRerankOptions,
+    /// Usage of the latest embedding or rerank request, shared between clones.
+    #[serde(skip)]
+    pub(crate) usage: Arc<Mutex<Option<VoyageUsage>>>,
+    _phantom: std::marker::PhantomData<M>,
+}
+
+impl<M: ModelName> Voyage<M> {
+    /// Voyage AI provider setting builder.
+    pub fn builder() -> VoyageBuilder<M> {
+        VoyageBuilder::default()
+    }
+
+    /// Returns the tokens billed for the latest embedding or rerank request made by this
+    /// provider or any of its clones, if Voyage reported them.
+    pub fn usage(&self) -> Option<VoyageUsage> {
+        *self.usage.lock().unwrap_or_else(|p| p.into_inner())
+    }
+
+    pub(crate) fn set_usage(&self, usage: Option<VoyageUsage>) {
+        *self.usage.lock().unwrap_or_else(|p| p.into_inner()) = usage;
+    }
+
+    /// Builds the embeddings request, filling in the options configured on the
+    /// provider. Dimensions set on the request take precedence.
+    pub(crate) fn request_from(&self, input: EmbeddingModelOptions) -> EmbeddingsRequest {
+        let mut request: EmbeddingsRequest = input.into();
+        request.model = self.embedding_options.model.clone();
+        request.input_type = self.settings.input_type;
+        request.output_dimension = request.output_dimension.or(self.settings.output_dimension);
+        // Voyage truncates by default, so only the opt-out is sent
+        request.truncation = (!self.settings.truncation).then_some(false);
+        request
+    }
+
+    fn with_model(settings: VoyageProviderSettings, model_name: String) -> Self {
+        let embedding_options = VoyageEmbeddingOptions {
+            model: model_name.clone(),
+            ..Default::default()
+        };
+        let rerank_options = VoyageRerankOptions {
+            model: model_name,
+            ..Default::default()
+        };
+
+        Self {
+            settings,
+            embedding_options,
+            rerank_options,
+            usage: Arc::default(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl Voyage<DynamicModel> {
+    /// Creates a Voyage AI provider with a dynamic model name using default settings.
+    ///
+    /// This allows you to specify the model name as a string rather than
+    /// using methods like `Voyage::voyage_3_5()`.
+    ///
+    /// **WARNING**: when using `DynamicModel`, model capabilities are not validated.
+    /// This means there is no compile-time guarantee that the model supports requested features.
+    ///
+    /// For custom configuration (API key, input type, etc.), use the builder pattern:
+    /// `Voyage::<DynamicModel>::builder().model_name(...).api_key(...).build()`
+    ///
+    /// # Parameters
+    ///
+    /// * `model_name` - The Voyage AI model identifier (e.g., "voyage-3.5", "voyage-code-3")
+    ///
+    /// # Returns
+    ///
+    /// A configured `Voyage<DynamicModel>` provider instance with default settings.
+    pub fn model_name(name: impl Into<String>) -> Self {
+        Self::with_model(VoyageProviderSettings::default(), name.into())
+    }
+}
+
+impl<M: ModelName> Default for Voyage<M> {
+    /// Creates a new Voyage AI provider with default settings.
+    fn default() -> Self {
+        Self::with_model(VoyageProviderSettings::default(), M::MODEL_NAME.to_string())
+    }
+}
+
+/// Voyage AI Provider Builder
+pub struct VoyageBuilder<M: ModelName> {
+    settings: VoyageProviderSettings,
+    model_name: String,
+    _phantom: std::marker::PhantomData<M>,
+}
+
+impl<M: ModelName> Default for VoyageBuilder<M> {
+    /// Creates a new Voyage AI provider builder with default settings.
+    fn default() -> Self {
+        Self {
+            settings: VoyageProviderSettings::default(),
+            model_name: M::MODEL_NAME.to_string(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl VoyageBuilder<DynamicModel> {
+    /// Sets the model name from a string. e.g., "voyage-3.5", "voyage-code-3"
+    ///
+    /// **WARNING**: when using `DynamicModel`, model capabilities are not validated.
+    /// This means there is no compile-time guarantee that the model supports requested features.
+    ///
+    /// # Parameters
+    ///
+    /// * `model_name` - The Voyage AI model identifier.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the model name set.
+    pub fn model_name(mut self, model_name: impl Into<String>) -> Self {
+        self.model_name = model_name.into();
+        self
+    }
+}
+
+impl<M: ModelName> VoyageBuilder<M> {
+    /// Sets the base URL for the Voyage AI API.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.settings.base_url = base_url.into();
+        self
+    }
+
+    /// Sets the API key for the Voyage AI API.
+    pub fn api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.settings.api_key = api_key.into();
+        self
+    }
+
+    /// Sets the kind of text being embedded, a search query or a document.
+    pub fn input_type(mut self, input_type: VoyageInputType) -> Self {
+        self.settings.input_type = Some(input_type);
+        self
+    }
+
+    /// Sets the number of dimensions of the returned embeddings. Dimensions set on a
+    /// request take precedence.
+    pub fn output_dimension(mut self, output_dimension: usize) -> Self {
+        self.settings.output_dimension = Some(output_dimension);
+        self
+    }
+
+    /// Sets whether inputs over the model's context length are truncated instead of
+    /// rejected, for both embedding and rerank requests. Defaults to true.
+    pub fn truncation(mut self, truncation: bool) -> Self {
+        self.settings.truncation = truncation;
+        self
+    }
+
+    /// Sets the retry policy applied to requests made by this provider.
+    ///
+    /// # Parameters
+    ///
+    /// * `retry_policy` - The [`RetryPolicy`] to use for retryable failures.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the retry policy set.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.settings.retry_policy = retry_policy;
+        self
+    }
+
+    crate::core::client::transport_builder_methods!();
+
+    /// Sets the name of the provider. Defaults to "voyage".
+    pub fn provider_name(mut self, provider_name: impl Into<String>) -> Self {
+        self.settings.provider_name = provider_name.into();
+        self
+    }
+
+    /// Builds the Voyage AI provider.
+    pub fn build(self) -> Result<Voyage<M>> {
+        // validate base url
+        let base_url = validate_base_url(&self.settings.base_url)?;
+
+        // check api key exists
+        if self.settings.api_key.is_empty() {
+            return Err(Error::MissingField("api_key".to_string()));
+        }
+
+        if self.model_name.is_empty() {
+            return Err(Error::MissingField("model_name".to_string()));
+        }
+
+        Ok(Voyage::with_model(
+            VoyageProviderSettings {
+                base_url,
+                ..self.settings
+            },
+            self.model_name,
+        ))
+    }
+}
+
+// Re-exports for convenience
+pub use capabilities::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(dimensions: Option<usize>) -> EmbeddingModelOptions {
+        EmbeddingModelOptions::builder()
+            .input(vec!["What is Rust?".to_string()])
+            .dimensions(dimensions)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_request_uses_provider_options() {
+        let voyage = Voyage::<DynamicModel>::builder()
+            .model_name("voyage-3.5")
+            .api_key("key")
+            .input_type(VoyageInputType::Query)
+            .output_dimension(512)
+            .truncation(false)
+            .build()
+            .unwrap();
+
+        let body = serde_json::to_value(voyage.request_from(options(None))).unwrap();
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "model": "voyage-3.5",
+                "input": ["What is Rust?"],
+                "input_type": "query",
+                "output_dimension": 512,
+                "truncation": false
+            })
+        );
+
+        let request = voyage.request_from(options(Some(256)));
+        assert_eq!(request.output_dimension, Some(256));
+    }
+
+    #[test]
+    fn test_default_request_omits_options() {
+        let voyage = Voyage::<DynamicModel>::model_name("voyage-3.5");
+        let body = serde_json::to_value(voyage.request_from(options(None))).unwrap();
+        assert!(body.get("input_type").is_none());
+        assert!(body.get("output_dimension").is_none());
+        assert!(body.get("truncation").is_none());
+    }
+
+    #[test]
+    fn test_usage_is_shared_between_clones() {
+        let voyage = Voyage::<DynamicModel>::model_name("voyage-3.5");
+        let clone = voyage.clone();
+        clone.set_usage(Some(VoyageUsage { total_tokens: 4 }));
+
+        assert_eq!(voyage.usage(), Some(VoyageUsage { total_tokens: 4 }));
+    }
+}
@@ -0,0 +1,42 @@
+//! Rerank model implementation for the Voyage AI provider.
+
+use crate::{
+    core::{
+        capabilities::ModelName,
+        client::RerankClient,
+        rerank_model::{RerankModel, RerankModelOptions, RerankModelResponse, RerankResult},
+    },
+    error::Result,
+    providers::voyage::Voyage,
+};
+use async_trait::async_trait;
+
+#[async_trait]
+impl<M: ModelName> RerankModel for Voyage<M> {
+    async fn rerank(&self, input: RerankModelOptions) -> Result<RerankModelResponse> {
+        // Clone self to allow mutation
+        let mut model = self.clone();
+
+        let documents = input.documents.clone();
+        let mut request: crate::providers::voyage::client::types::RerankRequest = input.into();
+        request.model = model.rerank_options.model.clone();
+        // Voyage truncates by default, so only the opt-out is sent
+        request.truncation = (!model.settings.truncation).then_some(false);
+        model.rerank_options.request = request;
+
+        let response = model.send(&model.settings.base_url).await?;
+        model.set_usage(response.usage);
+
+        Ok(response
+            .data
+            .into_iter()
+            .filter_map(|result| {
+                Some(RerankResult {
+                    index: result.index,
+                    relevance_score: result.relevance_score,
+                    document: documents.get(result.index)?.clone(),
+                })
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,87 @@
+//! Defines the settings for the Voyage AI provider.
+
+use crate::core::client::{RetryPolicy, TransportSettings};
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+/// The kind of text being embedded. Voyage prepends a retrieval prompt for the
+/// given kind, which improves search quality when queries and documents are
+/// embedded with their respective types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VoyageInputType {
+    /// A search query.
+    Query,
+    /// A document to be retrieved.
+    Document,
+}
+
+/// Settings for the Voyage AI provider.
+#[derive(Clone, Serialize, Deserialize, Builder)]
+#[builder(setter(into), default)]
+pub struct VoyageProviderSettings {
+    /// The name of the provider.
+    pub provider_name: String,
+
+    /// The base URL of the Voyage AI API.
+    pub base_url: String,
+
+    /// The API key for the Voyage AI API.
+    pub api_key: String,
+
+    /// The kind of text being embedded. Inputs are embedded as is when unset.
+    pub input_type: Option<VoyageInputType>,
+
+    /// The number of dimensions of the returned embeddings, used when a request does
+    /// not set its own. The model's default is used when unset.
+    pub output_dimension: Option<usize>,
+
+    /// Whether inputs over the model's context length are truncated. Voyage rejects
+    /// them with an error otherwise. Defaults to true.
+    pub truncation: bool,
+
+    /// The retry policy applied to requests.
+    pub retry_policy: RetryPolicy,
+
+    /// The HTTP client, middleware and rate limiter requests go through.
+    #[serde(skip)]
+    pub transport: TransportSettings,
+}
+
+impl std::fmt::Debug for VoyageProviderSettings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("VoyageProviderSettings")
+            .field("provider_name", &self.provider_name)
+            .field("base_url", &self.base_url)
+            .field("api_key", &"<redacted>")
+            .field("input_type", &self.input_type)
+            .field("output_dimension", &self.output_dimension)
+            .field("truncation", &self.truncation)
+            .field("retry_policy", &self.retry_policy)
+            .field("transport", &self.transport)
+            .finish()
+    }
+}
+
+impl Default for VoyageProviderSettings {
+    /// Returns the default settings for the Voyage AI provider.
+    fn default() -> Self {
+        Self {
+            provider_name: "voyage".to_string(),
+            base_url: "https://api.voyageai.com/v1/".to_string(),
+            api_key: std::env::var("VOYAGE_API_KEY").unwrap_or_default(),
+            input_type: None,
+            output_dimension: None,
+            truncation: true,
+            retry_policy: RetryPolicy::default(),
+            transport: TransportSettings::default(),
+        }
+    }
+}
+
+impl VoyageProviderSettings {
+    /// Creates a new builder for `VoyageProviderSettings`.
+    pub fn builder() -> VoyageProviderSettingsBuilder {
+        VoyageProviderSettingsBuilder::default()
+    }
+}
@@ -0,0 +1,95 @@
+//! IAM access tokens for IBM Cloud API keys.
+//!
+//! Implements the API key grant, see
+//! <https://cloud.ibm.com/docs/account?topic=account-iamtoken_from_apikey>.
+
+use crate::core::runtime::{SystemTime, assume_send};
+use crate::core::utils::join_url;
+use crate::error::{Error, Result};
+use serde::Deserialize;
+use std::time::Duration;
+
+/// Tokens are refreshed this long before they expire.
+const REFRESH_MARGIN: Duration = Duration::from_secs(60);
+
+/// An access token and the time it expires.
+#[derive(Debug, Clone)]
+pub(crate) struct AccessToken {
+    pub(crate) token: String,
+    pub(crate) expires_at: SystemTime,
+}
+
+impl AccessToken {
+    /// Returns true if the token is still valid at `now`, with a margin to spare.
+    pub(crate) fn is_fresh(&self, now: SystemTime) -> bool {
+        now + REFRESH_MARGIN < self.expires_at
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    #[serde(default)]
+    expires_in: Option<u64>,
+}
+
+/// Exchanges an API key for an access token at the IAM endpoint `iam_url`.
+pub(crate) async fn fetch_token(
+    client: &reqwest::Client,
+    iam_url: &str,
+    api_key: &str,
+) -> Result<AccessToken> {
+    let now = SystemTime::now();
+    let url = join_url(iam_url, "identity/token")?;
+
+    let request = client
+        .post(url)
+        .header(reqwest::header::ACCEPT, "application/json")
+        .form(&[
+            ("grant_type", "urn:ibm:params:oauth:grant-type:apikey"),
+            ("apikey", api_key),
+        ]);
+    // The response is not `Send` on wasm32
+    let (status, body) = assume_send(async {
+        let response = request.send().await?;
+        let status = response.status();
+        Ok::<_, reqwest::Error>((status, response.text().await.unwrap_or_default()))
+    })
+    .await
+    .map_err(|e| Error::ApiError {
+        status_code: e.status(),
+        details: format!("Failed to fetch access token: {e}"),
+    })?;
+    if !status.is_success() {
+        return Err(Error::ApiError {
+            status_code: Some(status),
+            details: format!("Failed to fetch access token: {body}"),
+        });
+    }
+
+    let response: TokenResponse = serde_json::from_str(&body).map_err(|e| Error::ApiError {
+        status_code: Some(status),
+        details: format!("Invalid token response: {e}"),
+    })?;
+
+    Ok(AccessToken {
+        token: response.access_token,
+        expires_at: now + Duration::from_secs(response.expires_in.unwrap_or(3600)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_freshness() {
+        let now = SystemTime::now();
+        let token = AccessToken {
+            token: "token".to_string(),
+            expires_at: now + Duration::from_secs(3600),
+        };
+        assert!(token.is_fresh(now));
+        assert!(!token.is_fresh(now + Duration::from_secs(3550)));
+    }
+}
@@ -0,0 +1,50 @@
+//! Capabilities for watsonx models.
+//!
+//! This module defines model types and their capabilities for the watsonx.ai provider.
+//! Users can implement additional traits on custom models.
+
+use crate::core::capabilities::*;
+use crate::model_capabilities;
+use crate::providers::watsonx::Watsonx;
+
+model_capabilities! {
+    provider: Watsonx,
+    models: {
+        IbmGranite338bInstruct {
+            model_name: "ibm/granite-3-3-8b-instruct",
+            constructor_name: ibm_granite_3_3_8b_instruct,
+            display_name: "Granite 3.3 8B Instruct",
+            capabilities: [TextInputSupport, TextOutputSupport, ToolCallSupport]
+        },
+        IbmGranite4HSmall {
+            model_name: "ibm/granite-4-h-small",
+            constructor_name: ibm_granite_4_h_small,
+            display_name: "Granite 4.0 H Small",
+            capabilities: [StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+        },
+        MetaLlamaLlama3370bInstruct {
+            model_name: "meta-llama/llama-3-3-70b-instruct",
+            constructor_name: meta_llama_llama_3_3_70b_instruct,
+            display_name: "Llama 3.3 70B Instruct",
+            capabilities: [StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+        },
+        MetaLlamaLlama4Maverick17b128eInstructFp8 {
+            model_name: "meta-llama/llama-4-maverick-17b-128e-instruct-fp8",
+            constructor_name: meta_llama_llama_4_maverick_17b_128e_instruct_fp8,
+            display_name: "Llama 4 Maverick 17B 128E Instruct",
+            capabilities: [ImageInputSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+        },
+        MistralaiMistralMedium2505 {
+            model_name: "mistralai/mistral-medium-2505",
+            constructor_name: mistralai_mistral_medium_2505,
+            display_name: "Mistral Medium 3",
+            capabilities: [ImageInputSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+        },
+        MistralaiMistralSmall3124bInstruct2503 {
+            model_name: "mistralai/mistral-small-3-1-24b-instruct-2503",
+            constructor_name: mistralai_mistral_small_3_1_24b_instruct_2503,
+            display_name: "Mistral Small 3.1 24B Instruct",
+            capabilities: [ImageInputSupport, StructuredOutputSupport, TextInputSupport, TextOutputSupport, ToolCallSupport]
+        },
+    }
+}
@@ -0,0 +1,127 @@
+//! Client implementation for the watsonx.ai provider.
+use crate::core::client::{LanguageModelClient, RetryPolicy, sse_error};
+use crate::error::{Error, Result};
+use crate::providers::openai_chat_completions::client::ChatCompletionsOptions;
+use crate::providers::watsonx::{ModelName, Watsonx};
+use reqwest::header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE};
+use reqwest_eventsource::Event;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+pub(crate) mod types;
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub(crate) struct WatsonxOptions {
+    pub(crate) model: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) request: Option<ChatCompletionsOptions>,
+    pub(crate) streaming: bool,
+}
+
+impl<M: ModelName> Watsonx<M> {
+    /// Returns the request body: the Chat Completions request, with the model named by
+    /// `model_id` and scoped to the configured project or deployment space.
+    pub(crate) fn request_body(&self) -> Value {
+        let Some(Value::Object(mut body)) = self
+            .lm_options
+            .request
+            .as_ref()
+            .and_then(|r| serde_json::to_value(r).ok())
+        else {
+            return Value::Object(Default::default());
+        };
+
+        // Streaming is selected by the endpoint rather than the body
+        body.remove("stream");
+        body.remove("stream_options");
+        body.remove("model");
+        body.insert("model_id".to_string(), self.lm_options.model.clone().into());
+        if let Some(project_id) = &self.settings.project_id {
+            body.insert("project_id".to_string(), project_id.clone().into());
+        }
+        if let Some(space_id) = &self.settings.space_id {
+            body.insert("space_id".to_string(), space_id.clone().into());
+        }
+        Value::Object(body)
+    }
+}
+
+impl<M: ModelName> LanguageModelClient for Watsonx<M> {
+    type Response = types::WatsonxChatResponse;
+    type StreamEvent = types::WatsonxStreamEvent;
+
+    fn path(&self) -> String {
+        if self.lm_options.streaming {
+            "/ml/v1/text/chat_stream".to_string()
+        } else {
+            "/ml/v1/text/chat".to_string()
+        }
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn headers(&self) -> reqwest::header::HeaderMap {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(CONTENT_TYPE, "application/json".parse().unwrap());
+        headers.insert(ACCEPT, "application/json".parse().unwrap());
+        if let Some(token) = self.token().as_ref()
+            && let Ok(value) = format!("Bearer {}", token.token).parse()
+        {
+            headers.insert(AUTHORIZATION, value);
+        }
+        headers
+    }
+
+    fn query_params(&self) -> Vec<(&str, &str)> {
+        vec![("version", self.settings.version.as_str())]
+    }
+
+    fn retry_policy(&self) -> RetryPolicy {
+        self.settings.retry_policy.clone()
+    }
+
+    fn transport(&self) -> crate::core::client::TransportSettings {
+        self.settings.transport.clone()
+    }
+
+    fn body(&self) -> reqwest::Body {
+        reqwest::Body::from(self.request_body().to_string())
+    }
+
+    fn parse_stream_sse(
+        event: std::result::Result<Event, reqwest_eventsource::Error>,
+    ) -> Result<Self::StreamEvent> {
+        match event {
+            Ok(Event::Open) => Ok(types::WatsonxStreamEvent::Open),
+            Ok(Event::Message(msg)) => {
+                if msg.data.trim() == "[DONE]" || msg.data.is_empty() {
+                    return Ok(types::WatsonxStreamEvent::Done);
+                }
+
+                let chunk: types::WatsonxStreamChunk =
+                    serde_json::from_str(&msg.data).map_err(|e| Error::ApiError {
+                        status_code: None,
+                        details: format!("Invalid JSON in SSE: {e}"),
+                    })?;
+
+                Ok(types::WatsonxStreamEvent::Chunk(chunk))
+            }
+            Err(e) => Err(sse_error(e)),
+        }
+    }
+
+    /// watsonx.ai closes the stream without a `[DONE]` message, so the stream ends with
+    /// the chunk that carries the finish reason.
+    fn end_stream(event: &Self::StreamEvent) -> bool {
+        match event {
+            types::WatsonxStreamEvent::Done => true,
+            types::WatsonxStreamEvent::Chunk(chunk) => chunk
+                .choices
+                .iter()
+                .any(|choice| choice.finish_reason.is_some()),
+            types::WatsonxStreamEvent::Open => false,
+        }
+    }
+}
@@ -0,0 +1,74 @@
+//! Type definitions for the watsonx.ai chat API.
+//!
+//! The chat API follows the OpenAI Chat Completions format, except that models are
+//! named by `model_id` and requests are scoped to a project or deployment space, so
+//! these types wrap the Chat Completions ones.
+
+use crate::providers::openai_chat_completions::client::types::{
+    ChatCompletionsResponse, ChatCompletionsStreamChunk, Choice, StreamChoice, Usage,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct WatsonxChatResponse {
+    #[serde(default)]
+    pub id: String,
+    #[serde(default)]
+    pub model_id: String,
+    #[serde(default)]
+    pub created: u64,
+    pub choices: Vec<Choice>,
+    #[serde(default)]
+    pub usage: Option<Usage>,
+}
+
+impl From<WatsonxChatResponse> for ChatCompletionsResponse {
+    fn from(response: WatsonxChatResponse) -> Self {
+        Self {
+            id: response.id,
+            object: "chat.completion".to_string(),
+            created: response.created,
+            model: response.model_id,
+            choices: response.choices,
+            usage: response.usage,
+            system_fingerprint: None,
+            service_tier: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct WatsonxStreamChunk {
+    #[serde(default)]
+    pub id: String,
+    #[serde(default)]
+    pub model_id: String,
+    #[serde(default)]
+    pub created: u64,
+    #[serde(default)]
+    pub choices: Vec<StreamChoice>,
+    #[serde(default)]
+    pub usage: Option<Usage>,
+}
+
+impl From<WatsonxStreamChunk> for ChatCompletionsStreamChunk {
+    fn from(chunk: WatsonxStreamChunk) -> Self {
+        Self {
+            id: chunk.id,
+            object: "chat.completion.chunk".to_string(),
+            created: chunk.created,
+            model: chunk.model_id,
+            choices: chunk.choices,
+            system_fingerprint: None,
+            usage: chunk.usage,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub(crate) enum WatsonxStreamEvent {
+    Chunk(WatsonxStreamChunk),
+    Done,
+    Open,
+}
@@ -0,0 +1,71 @@
+//! Language model implementation for the watsonx.ai provider.
+use crate::core::capabilities::ModelName;
+use crate::core::client::LanguageModelClient;
+use crate::core::language_model::{
+    LanguageModel, LanguageModelOptions, LanguageModelResponse, ProviderStream, SamplingOption,
+    stream_with_rate_limit,
+};
+use crate::error::Result;
+use crate::providers::openai_chat_completions::language_model::{
+    AccumulatedToolCalls, response_from, stream_chunks,
+};
+use crate::providers::watsonx::Watsonx;
+use crate::providers::watsonx::client::types::{self, WatsonxStreamEvent};
+use async_trait::async_trait;
+use futures::StreamExt;
+
+#[async_trait]
+impl<M: ModelName> LanguageModel for Watsonx<M> {
+    fn name(&self) -> String {
+        self.lm_options.model.clone()
+    }
+
+    fn capabilities(&self) -> crate::core::capabilities::Capabilities {
+        M::CAPABILITIES
+    }
+
+    fn info(&self) -> crate::core::capabilities::ModelInfo {
+        Self::model_info(&self.name())
+    }
+
+    fn unsupported_options(&self) -> &'static [SamplingOption] {
+        &[SamplingOption::TopK]
+    }
+
+    async fn generate_text(
+        &mut self,
+        options: LanguageModelOptions,
+    ) -> Result<LanguageModelResponse> {
+        self.lm_options.request = Some(options.into());
+        self.lm_options.streaming = false;
+        self.refresh_token().await?;
+
+        let (response, rate_limit): (types::WatsonxChatResponse, _) =
+            self.send_with_rate_limit(&self.settings.base_url).await?;
+
+        Ok(response_from(response.into()).with_rate_limit(rate_limit))
+    }
+
+    async fn stream_text(&mut self, options: LanguageModelOptions) -> Result<ProviderStream> {
+        self.lm_options.request = Some(options.into());
+        self.lm_options.streaming = true;
+        self.refresh_token().await?;
+
+        let (watsonx_stream, rate_limit) = self
+            .send_and_stream_with_rate_limit(&self.settings.base_url)
+            .await?;
+
+        // State for accumulating tool calls across chunks
+        let mut accumulated_tool_calls = AccumulatedToolCalls::new();
+
+        let stream = watsonx_stream.map(move |evt_res| match evt_res {
+            Ok(WatsonxStreamEvent::Chunk(chunk)) => {
+                Ok(stream_chunks(chunk.into(), &mut accumulated_tool_calls))
+            }
+            Ok(WatsonxStreamEvent::Open) | Ok(WatsonxStreamEvent::Done) => Ok(vec![]),
+            Err(e) => Err(e),
+        });
+
+        Ok(stream_with_rate_limit(rate_limit, Box::pin(stream)))
+    }
+}
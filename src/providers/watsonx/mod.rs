@@ -0,0 +1,316 @@
+//! This module provides the watsonx.ai provider, which implements the `LanguageModel`
+//! and `Provider` traits for foundation models served by IBM watsonx.ai.
+//!
+//! The watsonx.ai chat API (`/ml/v1/text/chat`) follows the OpenAI Chat Completions
+//! format, but requests are scoped to a project or deployment space, carry an API
+//! `version` date, and are authorized with IAM access tokens exchanged from an IBM
+//! Cloud API key and refreshed before they expire.
+
+pub mod capabilities;
+pub mod client;
+pub mod language_model;
+pub mod settings;
+
+pub(crate) mod auth;
+
+use crate::core::DynamicModel;
+use crate::core::capabilities::ModelName;
+use crate::core::client::RetryPolicy;
+use crate::core::runtime::SystemTime;
+use crate::core::utils::validate_base_url;
+use crate::error::{Error, Result};
+use crate::providers::watsonx::auth::AccessToken;
+use crate::providers::watsonx::client::WatsonxOptions;
+use crate::providers::watsonx::settings::WatsonxProviderSettings;
+use serde::Serialize;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// The watsonx.ai provider.
+#[derive(Debug, Serialize, Clone)]
+pub struct Watsonx<M: ModelName> {
+    /// Configuration settings for the watsonx.ai provider.
+    pub settings: WatsonxProviderSettings,
+    pub(crate) lm_options: WatsonxOptions,
+    /// The IAM access token exchanged from the API key, shared between clones.
+    #[serde(skip)]
+    token: Arc<Mutex<Option<AccessToken>>>,
+    _phantom: std::marker::PhantomData<M>,
+}
+
+impl<M: ModelName> Watsonx<M> {
+    /// watsonx.ai provider setting builder.
+    pub fn builder() -> WatsonxBuilder<M> {
+        WatsonxBuilder::default()
+    }
+
+    fn with_model(settings: WatsonxProviderSettings, model_name: String) -> Self {
+        let options = WatsonxOptions {
+            model: model_name,
+            ..Default::default()
+        };
+
+        Self {
+            settings,
+            lm_options: options,
+            token: Arc::default(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+
+    pub(crate) fn token(&self) -> MutexGuard<'_, Option<AccessToken>> {
+        self.token.lock().unwrap_or_else(|p| p.into_inner())
+    }
+
+    /// Exchanges the API key for a new access token, unless the current one is still
+    /// fresh.
+    pub(crate) async fn refresh_token(&self) -> Result<()> {
+        if self
+            .token()
+            .as_ref()
+            .is_some_and(|token| token.is_fresh(SystemTime::now()))
+        {
+            return Ok(());
+        }
+
+        let token = auth::fetch_token(
+            &self.settings.transport.client(),
+            &self.settings.iam_url,
+            &self.settings.api_key,
+        )
+        .await?;
+        *self.token() = Some(token);
+        Ok(())
+    }
+}
+
+impl Watsonx<DynamicModel> {
+    /// Creates a watsonx.ai provider with a dynamic model name using default settings.
+    ///
+    /// This allows you to specify the model name as a string rather than
+    /// using methods like `Watsonx::ibm_granite_3_3_8b_instruct()`, etc.
+    ///
+    /// **WARNING**: when using `DynamicModel`, model capabilities are not validated.
+    /// This means there is no compile-time guarantee that the model supports requested features.
+    ///
+    /// For custom configuration (API key, project, etc.), use the builder pattern:
+    /// `Watsonx::<DynamicModel>::builder().model_name(...).project_id(...).build()`
+    ///
+    /// # Parameters
+    ///
+    /// * `model_name` - The watsonx.ai model ID (e.g., "ibm/granite-3-3-8b-instruct")
+    ///
+    /// # Returns
+    ///
+    /// A configured `Watsonx<DynamicModel>` provider instance with default settings.
+    pub fn model_name(name: impl Into<String>) -> Self {
+        Self::with_model(WatsonxProviderSettings::default(), name.into())
+    }
+}
+
+impl<M: ModelName> Default for Watsonx<M> {
+    /// Creates a new watsonx.ai provider with default settings.
+    fn default() -> Self {
+        Self::with_model(
+            WatsonxProviderSettings::default(),
+            M::MODEL_NAME.to_string(),
+        )
+    }
+}
+
+/// watsonx.ai Provider Builder
+pub struct WatsonxBuilder<M: ModelName> {
+    settings: WatsonxProviderSettings,
+    model_name: String,
+    _phantom: std::marker::PhantomData<M>,
+}
+
+impl<M: ModelName> Default for WatsonxBuilder<M> {
+    /// Creates a new watsonx.ai provider builder with default settings.
+    fn default() -> Self {
+        Self {
+            settings: WatsonxProviderSettings::default(),
+            model_name: M::MODEL_NAME.to_string(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl WatsonxBuilder<DynamicModel> {
+    /// Sets the model name from a string. e.g., "ibm/granite-3-3-8b-instruct"
+    ///
+    /// **WARNING**: when using `DynamicModel`, model capabilities are not validated.
+    /// This means there is no compile-time guarantee that the model supports requested features.
+    ///
+    /// # Parameters
+    ///
+    /// * `model_name` - The watsonx.ai model ID.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the model name set.
+    pub fn model_name(mut self, model_name: impl Into<String>) -> Self {
+        self.model_name = model_name.into();
+        self
+    }
+}
+
+impl<M: ModelName> WatsonxBuilder<M> {
+    /// Sets the regional watsonx.ai endpoint. Defaults to `WATSONX_URL` or
+    /// `https://us-south.ml.cloud.ibm.com`.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.settings.base_url = base_url.into();
+        self
+    }
+
+    /// Sets the IBM Cloud API key. Defaults to `WATSONX_API_KEY`.
+    pub fn api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.settings.api_key = api_key.into();
+        self
+    }
+
+    /// Sets the IAM endpoint API keys are exchanged at, e.g. for IBM Cloud
+    /// Pak for Data or a private endpoint.
+    pub fn iam_url(mut self, iam_url: impl Into<String>) -> Self {
+        self.settings.iam_url = iam_url.into();
+        self
+    }
+
+    /// Scopes requests to a project. Defaults to `WATSONX_PROJECT_ID`.
+    pub fn project_id(mut self, project_id: impl Into<String>) -> Self {
+        self.settings.project_id = Some(project_id.into());
+        self.settings.space_id = None;
+        self
+    }
+
+    /// Scopes requests to a deployment space. Defaults to `WATSONX_SPACE_ID`.
+    pub fn space_id(mut self, space_id: impl Into<String>) -> Self {
+        self.settings.space_id = Some(space_id.into());
+        self.settings.project_id = None;
+        self
+    }
+
+    /// Sets the API version date sent with every request, e.g. `"2024-10-08"`.
+    pub fn version(mut self, version: impl Into<String>) -> Self {
+        self.settings.version = version.into();
+        self
+    }
+
+    /// Sets the retry policy applied to requests made by this provider.
+    ///
+    /// # Parameters
+    ///
+    /// * `retry_policy` - The [`RetryPolicy`] to use for retryable failures.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the retry policy set.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.settings.retry_policy = retry_policy;
+        self
+    }
+
+    crate::core::client::transport_builder_methods!();
+
+    /// Sets the name of the provider. Defaults to "watsonx".
+    pub fn provider_name(mut self, provider_name: impl Into<String>) -> Self {
+        self.settings.provider_name = provider_name.into();
+        self
+    }
+
+    /// Builds the watsonx.ai provider.
+    pub fn build(self) -> Result<Watsonx<M>> {
+        // validate base urls
+        let base_url = validate_base_url(&self.settings.base_url)?;
+        let iam_url = validate_base_url(&self.settings.iam_url)?;
+
+        // check api key exists
+        if self.settings.api_key.is_empty() {
+            return Err(Error::MissingField("api_key".to_string()));
+        }
+
+        match (&self.settings.project_id, &self.settings.space_id) {
+            (None, None) => return Err(Error::MissingField("project_id".to_string())),
+            (Some(_), Some(_)) => {
+                return Err(Error::InvalidInput(
+                    "Only one of project_id and space_id can be set".to_string(),
+                ));
+            }
+            _ => {}
+        }
+
+        if self.model_name.is_empty() {
+            return Err(Error::MissingField("model_name".to_string()));
+        }
+
+        Ok(Watsonx::with_model(
+            WatsonxProviderSettings {
+                base_url,
+                iam_url,
+                ..self.settings
+            },
+            self.model_name,
+        ))
+    }
+}
+
+// Re-exports for convenience
+pub use capabilities::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::client::LanguageModelClient;
+    use crate::core::language_model::LanguageModelOptions;
+    use crate::core::messages::{Message, TaggedMessage};
+
+    #[test]
+    fn test_request_is_scoped_to_project() {
+        let mut watsonx = Watsonx::<DynamicModel>::builder()
+            .model_name("ibm/granite-3-3-8b-instruct")
+            .api_key("key")
+            .project_id("my-project")
+            .version("2025-02-11")
+            .build()
+            .unwrap();
+
+        watsonx.lm_options.request = Some(
+            LanguageModelOptions {
+                messages: vec![TaggedMessage::initial_step_msg(Message::User(
+                    "Hello".into(),
+                ))],
+                ..Default::default()
+            }
+            .into(),
+        );
+        watsonx.lm_options.streaming = true;
+        let body = watsonx.request_body();
+
+        assert_eq!(body["model_id"], "ibm/granite-3-3-8b-instruct");
+        assert_eq!(body["project_id"], "my-project");
+        assert!(body.get("model").is_none());
+        assert!(body.get("space_id").is_none());
+        assert!(body.get("stream").is_none());
+        assert_eq!(
+            LanguageModelClient::path(&watsonx),
+            "/ml/v1/text/chat_stream"
+        );
+        assert_eq!(
+            LanguageModelClient::query_params(&watsonx),
+            vec![("version", "2025-02-11")]
+        );
+    }
+
+    #[test]
+    fn test_builder_requires_project_or_space() {
+        let result = Watsonx::<DynamicModel>::builder()
+            .model_name("ibm/granite-3-3-8b-instruct")
+            .api_key("key")
+            .build();
+        // Skip when the environment provides a default scope
+        if std::env::var("WATSONX_PROJECT_ID").is_err()
+            && std::env::var("WATSONX_SPACE_ID").is_err()
+        {
+            assert!(matches!(result, Err(Error::MissingField(field)) if field == "project_id"));
+        }
+    }
+}
@@ -0,0 +1,83 @@
+//! Defines the settings for the watsonx.ai provider.
+
+use crate::core::client::{RetryPolicy, TransportSettings};
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+/// The API version sent with every request when none is configured.
+pub(crate) const DEFAULT_VERSION: &str = "2024-10-08";
+
+/// Settings for the watsonx.ai provider.
+#[derive(Clone, Serialize, Deserialize, Builder)]
+#[builder(setter(into), default)]
+pub struct WatsonxProviderSettings {
+    /// The name of the provider.
+    pub provider_name: String,
+
+    /// The regional watsonx.ai endpoint, e.g. `https://us-south.ml.cloud.ibm.com/`.
+    pub base_url: String,
+
+    /// The IBM Cloud API key, exchanged for IAM access tokens.
+    pub api_key: String,
+
+    /// The IBM Cloud IAM endpoint API keys are exchanged at.
+    pub iam_url: String,
+
+    /// The project requests are scoped to. Either this or `space_id` is required.
+    pub project_id: Option<String>,
+
+    /// The deployment space requests are scoped to. Either this or `project_id` is
+    /// required.
+    pub space_id: Option<String>,
+
+    /// The API version date, sent as the `version` query parameter.
+    pub version: String,
+
+    /// The retry policy applied to requests.
+    pub retry_policy: RetryPolicy,
+
+    /// The HTTP client, middleware and rate limiter requests go through.
+    #[serde(skip)]
+    pub transport: TransportSettings,
+}
+
+impl std::fmt::Debug for WatsonxProviderSettings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WatsonxProviderSettings")
+            .field("provider_name", &self.provider_name)
+            .field("base_url", &self.base_url)
+            .field("api_key", &"<redacted>")
+            .field("iam_url", &self.iam_url)
+            .field("project_id", &self.project_id)
+            .field("space_id", &self.space_id)
+            .field("version", &self.version)
+            .field("retry_policy", &self.retry_policy)
+            .field("transport", &self.transport)
+            .finish()
+    }
+}
+
+impl Default for WatsonxProviderSettings {
+    /// Returns the default settings for the watsonx.ai provider.
+    fn default() -> Self {
+        Self {
+            provider_name: "watsonx".to_string(),
+            base_url: std::env::var("WATSONX_URL")
+                .unwrap_or_else(|_| "https://us-south.ml.cloud.ibm.com/".to_string()),
+            api_key: std::env::var("WATSONX_API_KEY").unwrap_or_default(),
+            iam_url: "https://iam.cloud.ibm.com/".to_string(),
+            project_id: std::env::var("WATSONX_PROJECT_ID").ok(),
+            space_id: std::env::var("WATSONX_SPACE_ID").ok(),
+            version: DEFAULT_VERSION.to_string(),
+            retry_policy: RetryPolicy::default(),
+            transport: TransportSettings::default(),
+        }
+    }
+}
+
+impl WatsonxProviderSettings {
+    /// Creates a new builder for `WatsonxProviderSettings`.
+    pub fn builder() -> WatsonxProviderSettingsBuilder {
+        WatsonxProviderSettingsBuilder::default()
+    }
+}
@@ -58,7 +58,7 @@ macro_rules! generate_language_model_tests {
         use aisdk::core::{
             language_model::{LanguageModel, LanguageModelResponseContentType, StopReason},
             tools::Tool,
-            DynamicModel, LanguageModelRequest, LanguageModelStreamChunkType, Message,
+            DynamicModel, LanguageModelRequest, Message, StreamEvent,
         };
         use aisdk::macros::tool;
         use dotenv::dotenv;
@@ -380,7 +380,7 @@ macro_rules! generate_language_model_streaming_tests {
             let mut buf = String::new();
             while let Some(chunk) = stream.next().await {
                 // println!("chunk: {:?}", chunk);
-                if let LanguageModelStreamChunkType::Text(text) = chunk {
+                if let StreamEvent::TextDelta(text) = chunk {
                     buf.push_str(&text);
                 }
             }
@@ -404,7 +404,7 @@ macro_rules! generate_language_model_streaming_tests {
             let mut stream = response.stream;
             let mut chunks_received = 0;
             while let Some(chunk) = stream.next().await {
-                if let LanguageModelStreamChunkType::Text(_) = chunk {
+                if let StreamEvent::TextDelta(_) = chunk {
                     chunks_received += 1;
                 }
             }
@@ -494,7 +494,7 @@ macro_rules! generate_language_model_tool_tests {
 
             let mut buf = String::new();
             while let Some(chunk) = stream.next().await {
-                if let LanguageModelStreamChunkType::Text(text) = chunk {
+                if let StreamEvent::TextDelta(text) = chunk {
                     buf.push_str(&text);
                 }
             }
@@ -529,7 +529,7 @@ macro_rules! generate_language_model_tool_tests {
 
             let mut buf = String::new();
             while let Some(chunk) = stream.next().await {
-                if let LanguageModelStreamChunkType::Text(text) = chunk {
+                if let StreamEvent::TextDelta(text) = chunk {
                     buf.push_str(&text);
                 }
             }
@@ -654,7 +654,7 @@ macro_rules! generate_language_model_schema_tests {
 
             let mut buf = String::new();
             while let Some(chunk) = stream.next().await {
-                if let LanguageModelStreamChunkType::Text(text) = chunk {
+                if let StreamEvent::TextDelta(text) = chunk {
                     buf.push_str(&text);
                 }
             }
@@ -817,14 +817,17 @@ macro_rules! generate_language_model_hook_tests {
                 .unwrap();
 
             let mut stream = response.stream;
-            let mut found_incomplete = false;
+            let mut stopped_by_hook = false;
             while let Some(chunk) = stream.next().await {
-                if let LanguageModelStreamChunkType::Incomplete(_) = chunk {
-                    found_incomplete = true;
-                    break;
+                if let StreamEvent::Finish {
+                    stop_reason: StopReason::Hook,
+                    ..
+                } = chunk
+                {
+                    stopped_by_hook = true;
                 }
             }
-            assert!(found_incomplete);
+            assert!(stopped_by_hook);
         }
 
         #[tokio::test]